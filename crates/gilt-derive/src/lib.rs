@@ -1,7 +1,7 @@
 //! Derive macros for the gilt terminal formatting library.
 //!
 //! This crate provides the `#[derive(Table)]`, `#[derive(Panel)]`, `#[derive(Tree)]`,
-//! `#[derive(Columns)]`, `#[derive(Rule)]`, `#[derive(Inspect)]`, and `#[derive(Renderable)]` macros that generate widget
+//! `#[derive(Columns)]`, `#[derive(Rule)]`, `#[derive(Inspect)]`, `#[derive(InspectInfo)]`, and `#[derive(Renderable)]` macros that generate widget
 //! conversion methods and trait implementations for structs.
 //!
 //! # Table Example
@@ -65,6 +65,24 @@
 //! };
 //! let panel = status.to_panel();
 //! ```
+//!
+//! # Theme Tokens
+//!
+//! Style attributes that the widget keeps as a style name (table and column
+//! styles, panel `border_style` and `style`, tree and rule styles) accept a
+//! theme token such as `"$accent"`. The token is looked up in the console's
+//! theme when the widget is rendered, so the same derived widget follows
+//! whatever theme is pushed at the time:
+//!
+//! ```ignore
+//! #[derive(Table)]
+//! #[table(border_style = "$border")]
+//! struct Service {
+//!     #[column(style = "$accent")]
+//!     name: String,
+//!     status: String,
+//! }
+//! ```
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
@@ -100,6 +118,36 @@ fn snake_to_title_case(s: &str) -> String {
         .join(" ")
 }
 
+// ---------------------------------------------------------------------------
+// Computed values: `value = "..."`
+// ---------------------------------------------------------------------------
+
+/// Parse a `value = "..."` expression, evaluated with `self` bound to the
+/// value being displayed and shown through its `Display` impl.
+fn value_expr(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let expr: syn::Expr = lit.parse()?;
+    Ok(quote! { (#expr).to_string() })
+}
+
+/// Replace every `self` keyword in `tokens` with `with`, for expressions
+/// evaluated outside a method (e.g. once per row of `to_table`).
+fn replace_self(tokens: proc_macro2::TokenStream, with: &Ident) -> proc_macro2::TokenStream {
+    use proc_macro2::{Group, TokenTree};
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ident) if ident == "self" => TokenTree::Ident(with.clone()),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), with));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            other => other,
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Struct-level attribute: #[table(...)]
 // ---------------------------------------------------------------------------
@@ -122,6 +170,8 @@ struct TableAttrs {
     expand: Option<LitBool>,
     highlight: Option<LitBool>,
     row_styles: Option<LitStr>,
+    /// Computed columns from `extra_column(header = "...", value = "...")`.
+    extra_columns: Vec<ColumnAttrs>,
 }
 
 /// A single key=value (or standalone bool key) inside `#[table(...)]`.
@@ -135,12 +185,21 @@ enum TableAttrValue {
     Bool(LitBool),
     /// Standalone flag like `expand` (no `= ...`), treated as `true`.
     Flag,
+    /// Nested column attributes, as in `extra_column(header = "...", ...)`.
+    List(Punctuated<ColumnAttr, Token![,]>),
 }
 
 impl Parse for TableAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let key: Ident = input.parse()?;
-        if input.peek(Token![=]) {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(TableAttr {
+                key,
+                value: TableAttrValue::List(Punctuated::parse_terminated(&content)?),
+            })
+        } else if input.peek(Token![=]) {
             let _eq: Token![=] = input.parse()?;
             if input.peek(LitStr) {
                 let lit: LitStr = input.parse()?;
@@ -226,6 +285,28 @@ fn parse_table_attrs(input: &DeriveInput) -> syn::Result<TableAttrs> {
                 "row_styles" => {
                     attrs.row_styles = Some(expect_str(&item, "row_styles")?);
                 }
+                "extra_column" => {
+                    let TableAttrValue::List(items) = item.value else {
+                        return Err(syn::Error::new_spanned(
+                            &item.key,
+                            "`extra_column` expects `extra_column(header = \"...\", value = \"...\")`",
+                        ));
+                    };
+                    let column = column_attrs_from_items(items)?;
+                    if column.header.is_none() || column.value.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &item.key,
+                            "`extra_column` requires both `header` and `value`",
+                        ));
+                    }
+                    if column.skip.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            &item.key,
+                            "`extra_column` does not support `skip`",
+                        ));
+                    }
+                    attrs.extra_columns.push(column);
+                }
                 _ => {
                     return Err(syn::Error::new_spanned(
                         &item.key,
@@ -277,6 +358,8 @@ struct ColumnAttrs {
     no_wrap: Option<LitBool>,
     skip: Option<LitBool>,
     ratio: Option<LitInt>,
+    /// Expression shown instead of the field, with `self` as the row.
+    value: Option<LitStr>,
 }
 
 /// A single key=value (or standalone flag) inside `#[column(...)]`.
@@ -331,54 +414,67 @@ impl Parse for ColumnAttr {
 
 /// Parse all `#[column(...)]` attributes from a field.
 fn parse_column_attrs(field: &syn::Field) -> syn::Result<ColumnAttrs> {
-    let mut attrs = ColumnAttrs::default();
-
+    let mut items = Vec::new();
     for attr in &field.attrs {
         if !attr.path().is_ident("column") {
             continue;
         }
-        let items: Punctuated<ColumnAttr, Token![,]> =
+        let parsed: Punctuated<ColumnAttr, Token![,]> =
             attr.parse_args_with(Punctuated::parse_terminated)?;
+        items.extend(parsed);
+    }
+    column_attrs_from_items(items)
+}
 
-        for item in items {
-            let key_str = item.key.to_string();
-            match key_str.as_str() {
-                "header" => {
-                    attrs.header = Some(col_expect_str(&item, "header")?);
-                }
-                "style" => {
-                    attrs.style = Some(col_expect_str(&item, "style")?);
-                }
-                "header_style" => {
-                    attrs.header_style = Some(col_expect_str(&item, "header_style")?);
-                }
-                "justify" => {
-                    attrs.justify = Some(col_expect_str(&item, "justify")?);
-                }
-                "width" => {
-                    attrs.width = Some(col_expect_int(&item, "width")?);
-                }
-                "min_width" => {
-                    attrs.min_width = Some(col_expect_int(&item, "min_width")?);
-                }
-                "max_width" => {
-                    attrs.max_width = Some(col_expect_int(&item, "max_width")?);
-                }
-                "no_wrap" => {
-                    attrs.no_wrap = Some(col_expect_bool(&item, "no_wrap")?);
-                }
-                "skip" => {
-                    attrs.skip = Some(col_expect_bool(&item, "skip")?);
-                }
-                "ratio" => {
-                    attrs.ratio = Some(col_expect_int(&item, "ratio")?);
-                }
-                _ => {
-                    return Err(syn::Error::new_spanned(
-                        &item.key,
-                        format!("unknown column attribute `{}`", key_str),
-                    ));
-                }
+/// Collect column attributes, shared by `#[column(...)]` and
+/// `#[table(extra_column(...))]`.
+fn column_attrs_from_items(
+    items: impl IntoIterator<Item = ColumnAttr>,
+) -> syn::Result<ColumnAttrs> {
+    let mut attrs = ColumnAttrs::default();
+    for item in items {
+        let key_str = item.key.to_string();
+        match key_str.as_str() {
+            "header" => {
+                attrs.header = Some(col_expect_str(&item, "header")?);
+            }
+            "style" => {
+                attrs.style = Some(col_expect_str(&item, "style")?);
+            }
+            "header_style" => {
+                attrs.header_style = Some(col_expect_str(&item, "header_style")?);
+            }
+            "justify" => {
+                attrs.justify = Some(col_expect_str(&item, "justify")?);
+            }
+            "width" => {
+                attrs.width = Some(col_expect_int(&item, "width")?);
+            }
+            "min_width" => {
+                attrs.min_width = Some(col_expect_int(&item, "min_width")?);
+            }
+            "max_width" => {
+                attrs.max_width = Some(col_expect_int(&item, "max_width")?);
+            }
+            "no_wrap" => {
+                attrs.no_wrap = Some(col_expect_bool(&item, "no_wrap")?);
+            }
+            "skip" => {
+                attrs.skip = Some(col_expect_bool(&item, "skip")?);
+            }
+            "ratio" => {
+                attrs.ratio = Some(col_expect_int(&item, "ratio")?);
+            }
+            "value" => {
+                let lit = col_expect_str(&item, "value")?;
+                value_expr(&lit)?;
+                attrs.value = Some(lit);
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &item.key,
+                    format!("unknown column attribute `{}`", key_str),
+                ));
             }
         }
     }
@@ -421,10 +517,25 @@ fn col_expect_int(attr: &ColumnAttr, name: &str) -> syn::Result<LitInt> {
 // box_style -> token mapping
 // ---------------------------------------------------------------------------
 
-/// Map a `box_style` string literal to a token stream referencing the
-/// corresponding `gilt::box_chars::*` static.
+/// Map a `box_style` string literal to an `Option<&'static BoxChars>`
+/// expression: the `gilt::box_chars::*` static for built-in names, or for
+/// `"registered:NAME"` a runtime `gilt::box_chars::get` lookup that panics
+/// if no box was registered under `NAME`.
 fn box_style_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
     let val = lit.value();
+    if let Some(name) = val.strip_prefix("registered:") {
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("invalid registered box name `{name}`"),
+            ));
+        }
+        return Ok(quote! {
+            Some(gilt::box_chars::get(#name).unwrap_or_else(|| {
+                panic!("box_style `registered:{}`: no box registered under that name", #name)
+            }))
+        });
+    }
     let ident_str = match val.as_str() {
         "ASCII" => "ASCII",
         "ASCII2" => "ASCII2",
@@ -453,7 +564,8 @@ fn box_style_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
                      ASCII_DOUBLE_HEAD, SQUARE, SQUARE_DOUBLE_HEAD, MINIMAL, \
                      MINIMAL_HEAVY_HEAD, MINIMAL_DOUBLE_HEAD, SIMPLE, SIMPLE_HEAD, \
                      SIMPLE_HEAVY, HORIZONTALS, ROUNDED, HEAVY, HEAVY_EDGE, HEAVY_HEAD, \
-                     DOUBLE, DOUBLE_EDGE, MARKDOWN"
+                     DOUBLE, DOUBLE_EDGE, MARKDOWN, or \"registered:NAME\" for a box \
+                     registered with gilt::box_chars::register"
                 ),
             ));
         }
@@ -462,6 +574,32 @@ fn box_style_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
     Ok(quote! { Some(&*gilt::box_chars::#ident) })
 }
 
+// ---------------------------------------------------------------------------
+// Theme tokens
+// ---------------------------------------------------------------------------
+
+/// Reject a `$name` theme token in an attribute whose style is resolved when
+/// the widget is built (as markup or a parsed `Style`), before any console
+/// theme is known.
+///
+/// Attributes stored as style names (table styles, panel `border_style` and
+/// `style`, tree and rule styles) resolve `$name` through the console theme
+/// at render time instead.
+fn reject_theme_token(lit: &LitStr, name: &str) -> syn::Result<()> {
+    let val = lit.value();
+    if val.starts_with('$') {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "`{name}` does not support theme tokens such as `{val}`: it is \
+                 resolved when the widget is built. Use a style \
+                 definition such as \"bold cyan\" instead"
+            ),
+        ));
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // justify -> token mapping
 // ---------------------------------------------------------------------------
@@ -485,7 +623,12 @@ fn justify_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
 // Derive macro
 // ---------------------------------------------------------------------------
 
-/// Derive macro that generates a `to_table(items: &[Self]) -> gilt::table::Table` method.
+/// Derive macro that generates `to_table(items: &[Self]) -> gilt::table::Table`
+/// and `to_table_from_iter(rows: impl IntoIterator<Item = Self>)` methods.
+///
+/// Works on structs with named fields and on tuple structs, including ones
+/// with lifetimes. Tuple fields are headed "Column 1", "Column 2", ... unless
+/// given `#[column(header = "...")]`.
 ///
 /// # Struct-level attributes (`#[table(...)]`)
 ///
@@ -493,7 +636,7 @@ fn justify_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
 /// |-----------|------|-------------|
 /// | `title` | string | Custom table title (default: struct name) |
 /// | `caption` | string | Table caption |
-/// | `box_style` | string | Box chars preset (e.g. "ROUNDED", "HEAVY") |
+/// | `box_style` | string | Box chars preset (e.g. "ROUNDED", "HEAVY"), or `"registered:NAME"` for a box registered with `gilt::box_chars::register` |
 /// | `style` | string | Table-level style string |
 /// | `border_style` | string | Border style |
 /// | `header_style` | string | Header row style |
@@ -506,6 +649,7 @@ fn justify_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
 /// | `expand` | bool | Expand to fill width |
 /// | `highlight` | bool | Enable highlighting |
 /// | `row_styles` | string | Comma-separated alternating row styles |
+/// | `extra_column(header = "...", value = "...")` | list | Computed column after the fields (repeatable; also takes column options) |
 ///
 /// # Field-level attributes (`#[column(...)]`)
 ///
@@ -521,6 +665,10 @@ fn justify_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
 /// | `no_wrap` | bool | Disable wrapping |
 /// | `skip` | bool | Exclude field from table |
 /// | `ratio` | int | Column width ratio |
+/// | `value` | string | Expression shown instead of the field, with `self` as the row |
+///
+/// Every style attribute also accepts a theme token such as `"$accent"`,
+/// looked up in the console theme when the table is rendered.
 ///
 /// # Example
 ///
@@ -549,6 +697,18 @@ fn justify_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
 ///     },
 /// ];
 /// let table = Employee::to_table(&employees);
+///
+/// // A join view over borrowed data, with no owned row type.
+/// #[derive(Table)]
+/// struct Assignment<'a>(
+///     #[column(header = "Name")] &'a str,
+///     #[column(header = "Department")] &'a str,
+/// );
+///
+/// let departments = ["Engineering", "Marketing"];
+/// let table = Assignment::to_table_from_iter(
+///     employees.iter().map(|e| Assignment(&e.name, departments[0])),
+/// );
 /// ```
 #[proc_macro_derive(Table, attributes(table, column))]
 pub fn derive_table(input: TokenStream) -> TokenStream {
@@ -563,16 +723,12 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     let struct_name = &input.ident;
     let struct_name_str = struct_name.to_string();
 
-    // Only support structs with named fields.
+    // Support structs with named fields, and tuple structs for ad-hoc views
+    // such as `struct Row<'a>(&'a str, &'a str)`.
     let fields = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(named) => &named.named,
-            Fields::Unnamed(_) => {
-                return Err(syn::Error::new_spanned(
-                    struct_name,
-                    "Table derive only supports structs with named fields",
-                ));
-            }
+            Fields::Unnamed(unnamed) => &unnamed.unnamed,
             Fields::Unit => {
                 return Err(syn::Error::new_spanned(
                     struct_name,
@@ -593,24 +749,26 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
             ));
         }
     };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     // Parse struct-level #[table(...)] attributes.
     let table_attrs = parse_table_attrs(input)?;
 
-    // Collect field info, respecting `skip`.
+    // Collect field info, respecting `skip`. Each column's cell is an
+    // expression over the current row, bound as `item`.
+    let item_ident = Ident::new("item", Span::call_site());
     struct FieldInfo {
-        ident: Ident,
+        cell: proc_macro2::TokenStream,
         header: String,
         col_attrs: ColumnAttrs,
     }
     let mut field_infos: Vec<FieldInfo> = Vec::new();
 
-    for field in fields.iter() {
-        let ident = field
-            .ident
-            .as_ref()
-            .expect("named field must have ident")
-            .clone();
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(index)),
+        };
         let col_attrs = parse_column_attrs(field)?;
 
         // Check skip.
@@ -619,13 +777,39 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
             continue;
         }
 
-        let header = match &col_attrs.header {
-            Some(lit) => lit.value(),
-            None => snake_to_title_case(&ident.to_string()),
+        // Tuple fields have no name to derive a header from, so they are
+        // numbered from 1 unless given `#[column(header = "...")]`.
+        let header = match (&col_attrs.header, &field.ident) {
+            (Some(lit), _) => lit.value(),
+            (None, Some(ident)) => snake_to_title_case(&ident.to_string()),
+            (None, None) => format!("Column {}", index + 1),
+        };
+
+        let cell = match &col_attrs.value {
+            Some(lit) => replace_self(value_expr(lit)?, &item_ident),
+            None => quote! { #item_ident.#member.to_string() },
         };
 
         field_infos.push(FieldInfo {
-            ident,
+            cell,
+            header,
+            col_attrs,
+        });
+    }
+
+    // Computed columns follow the field columns.
+    for col_attrs in table_attrs.extra_columns {
+        let header = col_attrs
+            .header
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_default();
+        let cell = match &col_attrs.value {
+            Some(lit) => replace_self(value_expr(lit)?, &item_ident),
+            None => quote! { String::new() },
+        };
+        field_infos.push(FieldInfo {
+            cell,
             header,
             col_attrs,
         });
@@ -634,6 +818,7 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     // Build header string literals.
     let header_strs: Vec<&str> = field_infos.iter().map(|fi| fi.header.as_str()).collect();
     let header_tokens = header_strs.iter().map(|h| quote! { #h });
+    let header_tokens_iter = header_tokens.clone();
 
     // Build the title token -- use custom title or fall back to struct name.
     let title_value = match &table_attrs.title {
@@ -790,14 +975,17 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
         }
     }
 
-    // Build row expression: for each non-skipped field, push `&item.field.to_string()`.
-    let row_fields = field_infos.iter().map(|fi| {
-        let ident = &fi.ident;
-        quote! { &item.#ident.to_string() }
-    });
+    // Build row expression: for each column, push `&<cell expression>`.
+    let row_fields: Vec<_> = field_infos
+        .iter()
+        .map(|fi| {
+            let cell = &fi.cell;
+            quote! { &#cell }
+        })
+        .collect();
 
     let expanded = quote! {
-        impl #struct_name {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
             /// Creates a [`gilt::table::Table`] from a slice of items.
             ///
             /// Each non-skipped struct field becomes a column, with headers derived
@@ -813,6 +1001,25 @@ fn derive_table_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
                 }
                 table
             }
+
+            /// Creates a [`gilt::table::Table`] from an iterator of rows.
+            ///
+            /// Useful for views built on the fly, e.g. a tuple struct of
+            /// references joining two collections, without collecting them
+            /// into a `Vec` first.
+            pub fn to_table_from_iter<I>(rows: I) -> gilt::table::Table
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let mut table = gilt::table::Table::new(&[#(#header_tokens_iter),*]);
+                #(#table_config)*
+                #(#col_configs)*
+                for row in rows {
+                    let item = &row;
+                    table.add_row(&[#(#row_fields),*]);
+                }
+                table
+            }
         }
     };
 
@@ -838,6 +1045,8 @@ struct PanelAttrs {
     title_style: Option<LitStr>,
     expand: Option<LitBool>,
     highlight: Option<LitBool>,
+    /// Computed rows from `extra_field(label = "...", value = "...")`.
+    extra_fields: Vec<FieldAttrs>,
 }
 
 /// A single key=value (or standalone bool key) inside `#[panel(...)]`.
@@ -851,12 +1060,21 @@ enum PanelAttrValue {
     Bool(LitBool),
     /// Standalone flag like `expand` (no `= ...`), treated as `true`.
     Flag,
+    /// Nested field attributes, as in `extra_field(label = "...", ...)`.
+    List(Punctuated<FieldAttr, Token![,]>),
 }
 
 impl Parse for PanelAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let key: Ident = input.parse()?;
-        if input.peek(Token![=]) {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(PanelAttr {
+                key,
+                value: PanelAttrValue::List(Punctuated::parse_terminated(&content)?),
+            })
+        } else if input.peek(Token![=]) {
             let _eq: Token![=] = input.parse()?;
             if input.peek(LitStr) {
                 let lit: LitStr = input.parse()?;
@@ -921,6 +1139,15 @@ fn parse_panel_attrs(input: &DeriveInput) -> syn::Result<PanelAttrs> {
                 "highlight" => {
                     attrs.highlight = Some(panel_expect_bool(&item, "highlight")?);
                 }
+                "extra_field" => {
+                    let items = match item.value {
+                        PanelAttrValue::List(items) => Some(items),
+                        _ => None,
+                    };
+                    attrs
+                        .extra_fields
+                        .push(extra_field_attrs(&item.key, items)?);
+                }
                 _ => {
                     return Err(syn::Error::new_spanned(
                         &item.key,
@@ -965,6 +1192,8 @@ struct FieldAttrs {
     label: Option<LitStr>,
     style: Option<LitStr>,
     skip: Option<LitBool>,
+    /// Expression shown instead of the field, with `self` as the struct.
+    value: Option<LitStr>,
 }
 
 /// A single key=value (or standalone flag) inside `#[field(...)]`.
@@ -1012,40 +1241,105 @@ impl Parse for FieldAttr {
 
 /// Parse all `#[field(...)]` attributes from a field.
 fn parse_field_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
-    let mut attrs = FieldAttrs::default();
-
+    let mut items = Vec::new();
     for attr in &field.attrs {
         if !attr.path().is_ident("field") {
             continue;
         }
-        let items: Punctuated<FieldAttr, Token![,]> =
+        let parsed: Punctuated<FieldAttr, Token![,]> =
             attr.parse_args_with(Punctuated::parse_terminated)?;
+        items.extend(parsed);
+    }
+    field_attrs_from_items(items)
+}
 
-        for item in items {
-            let key_str = item.key.to_string();
-            match key_str.as_str() {
-                "label" => {
-                    attrs.label = Some(field_expect_str(&item, "label")?);
-                }
-                "style" => {
-                    attrs.style = Some(field_expect_str(&item, "style")?);
-                }
-                "skip" => {
-                    attrs.skip = Some(field_expect_bool(&item, "skip")?);
-                }
-                _ => {
-                    return Err(syn::Error::new_spanned(
-                        &item.key,
-                        format!("unknown field attribute `{}`", key_str),
-                    ));
-                }
+/// Collect field attributes, shared by `#[field(...)]` and `extra_field(...)`.
+fn field_attrs_from_items(items: impl IntoIterator<Item = FieldAttr>) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    for item in items {
+        let key_str = item.key.to_string();
+        match key_str.as_str() {
+            "label" => {
+                attrs.label = Some(field_expect_str(&item, "label")?);
+            }
+            "style" => {
+                attrs.style = Some(field_expect_str(&item, "style")?);
+            }
+            "skip" => {
+                attrs.skip = Some(field_expect_bool(&item, "skip")?);
+            }
+            "value" => {
+                let lit = field_expect_str(&item, "value")?;
+                value_expr(&lit)?;
+                attrs.value = Some(lit);
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &item.key,
+                    format!("unknown field attribute `{}`", key_str),
+                ));
             }
         }
     }
+    Ok(attrs)
+}
 
+/// Parse the items of a struct-level `extra_field(label = "...", value = "...")`.
+fn extra_field_attrs(
+    key: &Ident,
+    items: Option<Punctuated<FieldAttr, Token![,]>>,
+) -> syn::Result<FieldAttrs> {
+    let Some(items) = items else {
+        return Err(syn::Error::new_spanned(
+            key,
+            "`extra_field` expects `extra_field(label = \"...\", value = \"...\")`",
+        ));
+    };
+    let attrs = field_attrs_from_items(items)?;
+    if attrs.label.is_none() || attrs.value.is_none() {
+        return Err(syn::Error::new_spanned(
+            key,
+            "`extra_field` requires both `label` and `value`",
+        ));
+    }
+    if attrs.skip.is_some() {
+        return Err(syn::Error::new_spanned(
+            key,
+            "`extra_field` does not support `skip`",
+        ));
+    }
     Ok(attrs)
 }
 
+/// A labeled `KeyValue` row shown by the Panel and Columns derives.
+struct KvField {
+    label: String,
+    style: Option<String>,
+    value: proc_macro2::TokenStream,
+}
+
+/// Build the row for a struct field (`ident`) or an `extra_field` (`None`).
+fn kv_field(ident: Option<&Ident>, fa: &FieldAttrs) -> syn::Result<KvField> {
+    let label = match (&fa.label, ident) {
+        (Some(lit), _) => lit.value(),
+        (None, Some(ident)) => snake_to_title_case(&ident.to_string()),
+        (None, None) => String::new(),
+    };
+    if let Some(ref lit) = fa.style {
+        reject_theme_token(lit, "style")?;
+    }
+    let value = match (&fa.value, ident) {
+        (Some(lit), _) => value_expr(lit)?,
+        (None, Some(ident)) => quote! { self.#ident.to_string() },
+        (None, None) => quote! { String::new() },
+    };
+    Ok(KvField {
+        label,
+        style: fa.style.as_ref().map(LitStr::value),
+        value,
+    })
+}
+
 fn field_expect_str(attr: &FieldAttr, name: &str) -> syn::Result<LitStr> {
     match &attr.value {
         FieldAttrValue::Str(s) => Ok(s.clone()),
@@ -1079,20 +1373,27 @@ fn field_expect_bool(attr: &FieldAttr, _name: &str) -> syn::Result<LitBool> {
 /// |-----------|------|-------------|
 /// | `title` | string | Custom panel title (default: struct name) |
 /// | `subtitle` | string | Panel subtitle |
-/// | `box_style` | string | Box chars preset (e.g. "ROUNDED", "HEAVY") |
-/// | `border_style` | string | Border style |
-/// | `style` | string | Content area style string |
+/// | `box_style` | string | Box chars preset (e.g. "ROUNDED", "HEAVY"), or `"registered:NAME"` for a box registered with `gilt::box_chars::register` |
+/// | `border_style` | string | Border style or theme key (e.g. "blue") |
+/// | `style` | string | Content area style or theme key |
 /// | `title_style` | string | Title style |
 /// | `expand` | bool | Expand to fill width (default true) |
 /// | `highlight` | bool | Enable highlighting |
+/// | `extra_field(label = "...", value = "...")` | list | Computed row after the fields (repeatable; also takes `style`) |
 ///
 /// # Field-level attributes (`#[field(...)]`)
 ///
 /// | Attribute | Type | Description |
 /// |-----------|------|-------------|
 /// | `label` | string | Custom field label (default: Title Case field name) |
-/// | `style` | string | Style applied as markup around the label |
+/// | `style` | string | Style applied to the label |
 /// | `skip` | bool | Exclude field from panel |
+/// | `value` | string | Expression shown instead of the field, e.g. `"self.used * 100 / self.total"` |
+///
+/// `border_style` and `style` also accept a theme token such as
+/// `"$border"`, looked up in the console theme when the panel is rendered.
+/// `title_style` and field styles are resolved when the panel is built, so
+/// they reject theme tokens.
 ///
 /// # Example
 ///
@@ -1129,6 +1430,26 @@ pub fn derive_panel(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Tokens adding one field as a `KeyValue` pair, with an optional label style.
+fn kv_pair_tokens(field: &KvField) -> proc_macro2::TokenStream {
+    let label = &field.label;
+    let value = &field.value;
+    match field.style.as_deref() {
+        Some(sty) => quote! {
+            kv.add(
+                gilt::text::Text::styled(
+                    #label,
+                    gilt::style::Style::parse(#sty).unwrap_or_else(|_| gilt::style::Style::null()),
+                ),
+                #value,
+            );
+        },
+        None => quote! {
+            kv.add(#label, #value);
+        },
+    }
+}
+
 fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let struct_name = &input.ident;
     let struct_name_str = struct_name.to_string();
@@ -1167,20 +1488,10 @@ fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     // Parse struct-level #[panel(...)] attributes.
     let panel_attrs = parse_panel_attrs(input)?;
 
-    // Collect field info, respecting `skip`.
-    struct PanelFieldInfo {
-        ident: Ident,
-        label: String,
-        style: Option<String>,
-    }
-    let mut field_infos: Vec<PanelFieldInfo> = Vec::new();
-
+    // Collect field rows, respecting `skip`; computed rows follow.
+    let mut kv_fields: Vec<KvField> = Vec::new();
     for field in fields.iter() {
-        let ident = field
-            .ident
-            .as_ref()
-            .expect("named field must have ident")
-            .clone();
+        let ident = field.ident.as_ref().expect("named field must have ident");
         let fa = parse_field_attrs(field)?;
 
         // Check skip.
@@ -1188,45 +1499,14 @@ fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
         if skip {
             continue;
         }
-
-        let label = match &fa.label {
-            Some(lit) => lit.value(),
-            None => snake_to_title_case(&ident.to_string()),
-        };
-
-        let style = fa.style.as_ref().map(|lit| lit.value());
-
-        field_infos.push(PanelFieldInfo {
-            ident,
-            label,
-            style,
-        });
+        kv_fields.push(kv_field(Some(ident), &fa)?);
+    }
+    for fa in &panel_attrs.extra_fields {
+        kv_fields.push(kv_field(None, fa)?);
     }
 
-    // Build the line push expressions for each field.
-    let line_pushes: Vec<proc_macro2::TokenStream> = field_infos
-        .iter()
-        .map(|fi| {
-            let ident = &fi.ident;
-            let label = &fi.label;
-            match &fi.style {
-                Some(sty) => {
-                    // "[style]Label:[/style] {value}"
-                    let open_tag = format!("[{}]", sty);
-                    let close_tag = format!("[/{}]", sty);
-                    quote! {
-                        lines.push(format!("{}{}:{} {}", #open_tag, #label, #close_tag, self.#ident));
-                    }
-                }
-                None => {
-                    // "Label: {value}"
-                    quote! {
-                        lines.push(format!("{}: {}", #label, self.#ident));
-                    }
-                }
-            }
-        })
-        .collect();
+    // Build the KeyValue pair expressions for each field.
+    let pair_pushes: Vec<proc_macro2::TokenStream> = kv_fields.iter().map(kv_pair_tokens).collect();
 
     // Build the title -- use custom title or fall back to struct name.
     let title_value = match &panel_attrs.title {
@@ -1239,27 +1519,28 @@ fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
 
     // Title is always set (as Text with optional title_style markup).
     if let Some(ref lit) = panel_attrs.title_style {
+        reject_theme_token(lit, "title_style")?;
         let sty = lit.value();
         let styled_title = format!("[{}]{}[/{}]", sty, title_value, sty);
         panel_config.push(quote! {
-            panel.title = Some(gilt::text::Text::from_markup(#styled_title).unwrap_or_else(|_| gilt::text::Text::from(#title_value)));
+            panel.title = Some(gilt::text::Text::from_markup(#styled_title).unwrap_or_else(|_| gilt::text::Text::from(#title_value)).into());
         });
     } else {
         panel_config.push(quote! {
-            panel.title = Some(gilt::text::Text::from(#title_value));
+            panel.title = Some(gilt::text::Text::from(#title_value).into());
         });
     }
 
     if let Some(ref lit) = panel_attrs.subtitle {
         let val = lit.value();
         panel_config.push(quote! {
-            panel.subtitle = Some(gilt::text::Text::from(#val));
+            panel.subtitle = Some(gilt::text::Text::from(#val).into());
         });
     }
     if let Some(ref lit) = panel_attrs.box_style {
         let tokens = box_style_tokens(lit)?;
-        // box_style_tokens returns `Some(&*gilt::box_chars::IDENT)`, but panel.box_chars
-        // expects `&'static BoxChars` not `Option`. Unwrap the Some.
+        // box_style_tokens returns `Some(&'static BoxChars)`, but panel.box_chars
+        // expects `&'static BoxChars`. Unwrap the Some.
         panel_config.push(quote! {
             if let Some(bc) = #tokens {
                 panel.box_chars = bc;
@@ -1268,14 +1549,26 @@ fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     }
     if let Some(ref lit) = panel_attrs.border_style {
         let val = lit.value();
-        panel_config.push(quote! {
-            panel.border_style = gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null());
+        panel_config.push(if val.starts_with('$') {
+            quote! {
+                panel.border_style_name = Some(#val.to_string());
+            }
+        } else {
+            quote! {
+                panel.border_style = gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null());
+            }
         });
     }
     if let Some(ref lit) = panel_attrs.style {
         let val = lit.value();
-        panel_config.push(quote! {
-            panel.style = gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null());
+        panel_config.push(if val.starts_with('$') {
+            quote! {
+                panel.style_name = Some(#val.to_string());
+            }
+        } else {
+            quote! {
+                panel.style = gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null());
+            }
         });
     }
     if let Some(ref lit) = panel_attrs.expand {
@@ -1296,15 +1589,15 @@ fn derive_panel_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStrea
             /// Creates a [`gilt::panel::Panel`] displaying this struct's fields
             /// as labeled key-value pairs.
             ///
-            /// Each non-skipped field becomes a line `"Label: value"`. Field styles
-            /// are applied as markup tags around the label. The panel title defaults
-            /// to the struct name unless overridden via `#[panel(title = "...")]`.
+            /// Each non-skipped field becomes an aligned `"Label: value"` row of a
+            /// [`gilt::kv::KeyValue`] list. Field styles are applied to the label.
+            /// The panel title defaults to the struct name unless overridden via
+            /// `#[panel(title = "...")]`.
             pub fn to_panel(&self) -> gilt::panel::Panel {
-                let mut lines: Vec<String> = Vec::new();
-                #(#line_pushes)*
-                let content = gilt::text::Text::from_markup(&lines.join("\n"))
-                    .unwrap_or_else(|_| gilt::text::Text::from(lines.join("\n").as_str()));
-                let mut panel = gilt::panel::Panel::new(content);
+                let mut kv = gilt::kv::KeyValue::new()
+                    .with_key_style(gilt::style::Style::null());
+                #(#pair_pushes)*
+                let mut panel = gilt::panel::Panel::new(kv.to_text());
                 #(#panel_config)*
                 panel
             }
@@ -1484,8 +1777,8 @@ fn parse_tree_field_attrs(field: &syn::Field) -> syn::Result<TreeFieldKind> {
 ///
 /// | Attribute | Type | Description |
 /// |-----------|------|-------------|
-/// | `style` | string | Style string for tree nodes (e.g. "bold") |
-/// | `guide_style` | string | Style string for guide lines (e.g. "dim cyan") |
+/// | `style` | string | Style or theme key for tree nodes (e.g. "bold") |
+/// | `guide_style` | string | Style or theme key for guide lines (e.g. "dim cyan") |
 ///
 /// # Field-level attributes (`#[tree(...)]`)
 ///
@@ -1629,9 +1922,15 @@ fn derive_tree_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
     // Build style configuration.
     let style_setup = if let Some(ref lit) = tree_attrs.style {
         let val = lit.value();
-        quote! {
-            if let Ok(s) = gilt::style::Style::parse(#val) {
-                tree.style = s;
+        if val.starts_with('$') {
+            quote! {
+                tree.style_name = Some(#val.to_string());
+            }
+        } else {
+            quote! {
+                if let Ok(s) = gilt::style::Style::parse(#val) {
+                    tree.style = s;
+                }
             }
         }
     } else {
@@ -1640,9 +1939,15 @@ fn derive_tree_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
 
     let guide_style_setup = if let Some(ref lit) = tree_attrs.guide_style {
         let val = lit.value();
-        quote! {
-            if let Ok(s) = gilt::style::Style::parse(#val) {
-                tree.guide_style = s;
+        if val.starts_with('$') {
+            quote! {
+                tree.guide_style_name = Some(#val.to_string());
+            }
+        } else {
+            quote! {
+                if let Ok(s) = gilt::style::Style::parse(#val) {
+                    tree.guide_style = s;
+                }
             }
         }
     } else {
@@ -1698,10 +2003,11 @@ fn derive_tree_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
 // Struct-level attribute: #[renderable(...)]
 // ---------------------------------------------------------------------------
 
-/// Parsed struct-level `#[renderable(...)]` attributes.
+/// Parsed `#[renderable(...)]` attributes (on the type or on an enum variant).
 #[derive(Default)]
 struct RenderableAttrs {
-    /// Which widget to delegate to: "panel" or "tree". Defaults to "panel".
+    /// Which widget to delegate to: "panel", "tree", "table", "columns",
+    /// "rule" or "inspect". Defaults to "panel" for structs.
     via: Option<LitStr>,
 }
 
@@ -1735,11 +2041,11 @@ impl Parse for RenderableAttr {
     }
 }
 
-/// Parse all `#[renderable(...)]` attributes from a `DeriveInput`.
-fn parse_renderable_attrs(input: &DeriveInput) -> syn::Result<RenderableAttrs> {
+/// Parse all `#[renderable(...)]` attributes from a list of attributes.
+fn parse_renderable_attrs(attrs_in: &[syn::Attribute]) -> syn::Result<RenderableAttrs> {
     let mut attrs = RenderableAttrs::default();
 
-    for attr in &input.attrs {
+    for attr in attrs_in {
         if !attr.path().is_ident("renderable") {
             continue;
         }
@@ -1750,7 +2056,9 @@ fn parse_renderable_attrs(input: &DeriveInput) -> syn::Result<RenderableAttrs> {
             let key_str = item.key.to_string();
             match key_str.as_str() {
                 "via" => {
-                    attrs.via = Some(renderable_expect_str(&item, "via")?);
+                    let lit = renderable_expect_str(&item, "via")?;
+                    renderable_via_method(&lit)?;
+                    attrs.via = Some(lit);
                 }
                 _ => {
                     return Err(syn::Error::new_spanned(
@@ -1771,25 +2079,69 @@ fn renderable_expect_str(attr: &RenderableAttr, _name: &str) -> syn::Result<LitS
     }
 }
 
+/// Validate a `via` value, returning the widget it names.
+fn renderable_via_method(lit: &LitStr) -> syn::Result<String> {
+    let via = lit.value();
+    match via.as_str() {
+        "panel" | "tree" | "table" | "columns" | "rule" | "inspect" => Ok(via),
+        other => Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "unknown renderable via `{}`. Expected one of: panel, tree, table, columns, rule, inspect",
+                other
+            ),
+        )),
+    }
+}
+
+/// Build the expression converting `value` (of type `ty`) into its widget.
+///
+/// `table` and `columns` render the value as a one-item slice, so a single
+/// struct shows as one table row or one card.
+fn renderable_widget_expr(
+    via: &str,
+    value: &proc_macro2::TokenStream,
+    ty: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match via {
+        "tree" => quote! { #value.to_tree() },
+        "table" => quote! { <#ty>::to_table(std::slice::from_ref(#value)) },
+        "columns" => quote! { <#ty>::to_columns(std::slice::from_ref(#value)) },
+        "rule" => quote! { #value.to_rule() },
+        "inspect" => quote! { #value.to_inspect() },
+        _ => quote! { #value.to_panel() },
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Renderable derive entry point
 // ---------------------------------------------------------------------------
 
-/// Derive macro that generates a `gilt::console::Renderable` implementation for a struct.
+/// Derive macro that generates `gilt::console::Renderable` and
+/// `gilt::measure::Measure` implementations for a struct or enum.
 ///
-/// This delegates rendering to one of the existing widget derives (Panel or Tree).
+/// For structs this delegates rendering and measurement to one of the
+/// existing widget derives, so the struct sizes correctly inside fit panels,
+/// `Align` and other content-sized containers.
 /// The struct must also derive the corresponding widget macro.
 ///
-/// # Struct-level attributes (`#[renderable(...)]`)
+/// For enums each variant is rendered on its own:
+///
+/// - a unit variant renders its name as text;
+/// - a single-field variant delegates to the field's own `Renderable` and
+///   `Measure` impls, or, with a `via` (on the variant, or on the enum as a
+///   default), to that widget derive of the field's type.
+///
+/// # Attributes (`#[renderable(...)]`)
 ///
 /// | Attribute | Type | Description |
 /// |-----------|------|-------------|
-/// | `via` | string | Widget to delegate to: `"panel"` (default) or `"tree"` |
+/// | `via` | string | Widget to delegate to: `"panel"` (struct default), `"tree"`, `"table"` (a single row), `"columns"` (a single card), `"rule"` or `"inspect"` |
 ///
 /// # Example
 ///
 /// ```ignore
-/// use gilt_derive::{Panel, Renderable};
+/// use gilt_derive::{Panel, Renderable, Table};
 ///
 /// #[derive(Panel, Renderable)]
 /// #[renderable(via = "panel")]
@@ -1799,8 +2151,22 @@ fn renderable_expect_str(attr: &RenderableAttr, _name: &str) -> syn::Result<LitS
 ///     port: u16,
 /// }
 ///
-/// // Config now implements gilt::console::Renderable
+/// // Config now implements gilt::console::Renderable and gilt::measure::Measure,
 /// // and can be passed directly to console.print(&config)
+///
+/// #[derive(Table)]
+/// struct Job {
+///     name: String,
+///     state: String,
+/// }
+///
+/// #[derive(Renderable)]
+/// enum Entry {
+///     Config(Config),
+///     #[renderable(via = "table")]
+///     Job(Job),
+///     Separator,
+/// }
 /// ```
 #[proc_macro_derive(Renderable, attributes(renderable))]
 pub fn derive_renderable(input: TokenStream) -> TokenStream {
@@ -1812,63 +2178,126 @@ pub fn derive_renderable(input: TokenStream) -> TokenStream {
 }
 
 fn derive_renderable_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
-    let struct_name = &input.ident;
-
-    // Only support structs (not enums or unions).
-    match &input.data {
-        Data::Struct(_) => {}
-        Data::Enum(_) => {
-            return Err(syn::Error::new_spanned(
-                struct_name,
-                "Renderable derive does not support enums",
-            ));
-        }
-        Data::Union(_) => {
-            return Err(syn::Error::new_spanned(
-                struct_name,
-                "Renderable derive does not support unions",
-            ));
-        }
-    }
-
-    // Parse struct-level #[renderable(...)] attributes.
-    let renderable_attrs = parse_renderable_attrs(input)?;
+    let type_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    // Determine the delegation target. Default to "panel".
-    let via = renderable_attrs
+    // Parse type-level #[renderable(...)] attributes.
+    let renderable_attrs = parse_renderable_attrs(&input.attrs)?;
+    let default_via = renderable_attrs
         .via
         .as_ref()
-        .map(|lit| lit.value())
-        .unwrap_or_else(|| "panel".to_string());
-
-    let delegate_call = match via.as_str() {
-        "panel" => {
-            quote! { let widget = self.to_panel(); }
-        }
-        "tree" => {
-            quote! { let widget = self.to_tree(); }
-        }
-        other => {
-            let lit = renderable_attrs.via.as_ref().unwrap();
-            return Err(syn::Error::new_spanned(
-                lit,
-                format!(
-                    "unknown renderable via `{}`. Expected one of: panel, tree",
-                    other
-                ),
-            ));
+        .map(renderable_via_method)
+        .transpose()?;
+
+    // Each body binds `widget` to a reference to the widget and hands it
+    // to `call`.
+    let body = |call: proc_macro2::TokenStream| -> syn::Result<proc_macro2::TokenStream> {
+        match &input.data {
+            Data::Struct(_) => {
+                let via = default_via.as_deref().unwrap_or("panel");
+                let widget = renderable_widget_expr(via, &quote! { self }, &quote! { Self });
+                Ok(quote! {
+                    let widget = &#widget;
+                    #call
+                })
+            }
+            Data::Enum(data_enum) => {
+                let mut arms = Vec::new();
+                for variant in &data_enum.variants {
+                    let ident = &variant.ident;
+                    let variant_via = parse_renderable_attrs(&variant.attrs)?
+                        .via
+                        .as_ref()
+                        .map(renderable_via_method)
+                        .transpose()?;
+                    let arm = match &variant.fields {
+                        Fields::Unit => {
+                            if variant_via.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    ident,
+                                    "unit variants render their name and take no `via`",
+                                ));
+                            }
+                            let name = ident.to_string();
+                            quote! {
+                                Self::#ident => {
+                                    let widget = &gilt::text::Text::from(#name);
+                                    #call
+                                }
+                            }
+                        }
+                        fields if fields.len() == 1 => {
+                            let field = fields.iter().next().expect("one field");
+                            let ty = &field.ty;
+                            let pattern = match &field.ident {
+                                Some(name) => quote! { Self::#ident { #name: inner } },
+                                None => quote! { Self::#ident(inner) },
+                            };
+                            let widget = match variant_via.as_deref().or(default_via.as_deref()) {
+                                Some(via) => {
+                                    let expr = renderable_widget_expr(
+                                        via,
+                                        &quote! { inner },
+                                        &quote! { #ty },
+                                    );
+                                    quote! { &#expr }
+                                }
+                                None => quote! { inner },
+                            };
+                            quote! {
+                                #pattern => {
+                                    let widget = #widget;
+                                    #call
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                ident,
+                                "Renderable derive on enums supports unit and single-field variants",
+                            ));
+                        }
+                    };
+                    arms.push(arm);
+                }
+                Ok(quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                })
+            }
+            Data::Union(_) => Err(syn::Error::new_spanned(
+                type_name,
+                "Renderable derive does not support unions",
+            )),
         }
     };
 
+    let render_body = body(quote! {
+        gilt::console::Renderable::gilt_console(widget, console, options)
+    })?;
+    let measure_body = body(quote! {
+        gilt::measure::Measure::measure(widget, console, options)
+    })?;
+
     let expanded = quote! {
-        impl gilt::console::Renderable for #struct_name {
-            fn rich_console(
+        impl #impl_generics gilt::console::Renderable for #type_name #ty_generics #where_clause {
+            fn gilt_console(
                 &self,
                 console: &gilt::console::Console,
                 options: &gilt::console::ConsoleOptions,
             ) -> Vec<gilt::segment::Segment> {
-                #delegate_call
-                widget.rich_console(console, options)
+                #render_body
+            }
+        }
+
+        impl #impl_generics gilt::measure::Measure for #type_name #ty_generics #where_clause {
+            fn measure(
+                &self,
+                console: &gilt::console::Console,
+                options: &gilt::console::ConsoleOptions,
+            ) -> gilt::measure::Measurement {
+                #measure_body
             }
         }
     };
@@ -1892,6 +2321,8 @@ struct ColumnsAttrs {
     expand: Option<LitBool>,
     padding: Option<LitInt>,
     title: Option<LitStr>,
+    /// Computed card rows from `extra_field(label = "...", value = "...")`.
+    extra_fields: Vec<FieldAttrs>,
 }
 
 /// A single key=value (or standalone bool key) inside `#[columns(...)]`.
@@ -1906,12 +2337,21 @@ enum ColumnsAttrValue {
     Int(LitInt),
     /// Standalone flag like `expand` (no `= ...`), treated as `true`.
     Flag,
+    /// Nested field attributes, as in `extra_field(label = "...", ...)`.
+    List(Punctuated<FieldAttr, Token![,]>),
 }
 
 impl Parse for ColumnsAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let key: Ident = input.parse()?;
-        if input.peek(Token![=]) {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(ColumnsAttr {
+                key,
+                value: ColumnsAttrValue::List(Punctuated::parse_terminated(&content)?),
+            })
+        } else if input.peek(Token![=]) {
             let _eq: Token![=] = input.parse()?;
             if input.peek(LitStr) {
                 let lit: LitStr = input.parse()?;
@@ -1973,6 +2413,15 @@ fn parse_columns_attrs(input: &DeriveInput) -> syn::Result<ColumnsAttrs> {
                 "title" => {
                     attrs.title = Some(columns_expect_str(&item, "title")?);
                 }
+                "extra_field" => {
+                    let items = match item.value {
+                        ColumnsAttrValue::List(items) => Some(items),
+                        _ => None,
+                    };
+                    attrs
+                        .extra_fields
+                        .push(extra_field_attrs(&item.key, items)?);
+                }
                 _ => {
                     return Err(syn::Error::new_spanned(
                         &item.key,
@@ -2033,14 +2482,16 @@ fn columns_expect_int(attr: &ColumnsAttr, name: &str) -> syn::Result<LitInt> {
 /// | `expand` | bool | Expand to fill available width |
 /// | `padding` | int | Horizontal padding between columns |
 /// | `title` | string | Title displayed above the columns |
+/// | `extra_field(label = "...", value = "...")` | list | Computed card row after the fields (repeatable; also takes `style`) |
 ///
 /// # Field-level attributes (`#[field(...)]`)
 ///
 /// | Attribute | Type | Description |
 /// |-----------|------|-------------|
 /// | `label` | string | Custom field label (default: Title Case field name) |
-/// | `style` | string | Style applied as markup around the label |
+/// | `style` | string | Style applied to the label |
 /// | `skip` | bool | Exclude field from card |
+/// | `value` | string | Expression shown instead of the field, with `self` as the item |
 ///
 /// # Example
 ///
@@ -2117,64 +2568,26 @@ fn derive_columns_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStr
     // Parse struct-level #[columns(...)] attributes.
     let columns_attrs = parse_columns_attrs(input)?;
 
-    // Collect field info, respecting `skip`. Reuse FieldAttrs / parse_field_attrs.
-    struct ColFieldInfo {
-        ident: Ident,
-        label: String,
-        style: Option<String>,
-    }
-    let mut field_infos: Vec<ColFieldInfo> = Vec::new();
-
+    // Collect field rows, respecting `skip`; computed rows follow.
+    // Reuses FieldAttrs / parse_field_attrs from the Panel derive.
+    let mut kv_fields: Vec<KvField> = Vec::new();
     for field in fields.iter() {
-        let ident = field
-            .ident
-            .as_ref()
-            .expect("named field must have ident")
-            .clone();
+        let ident = field.ident.as_ref().expect("named field must have ident");
         let fa = parse_field_attrs(field)?;
 
         // Check skip.
-        let skip = fa.skip.as_ref().map(|b| b.value).unwrap_or(false);
-        if skip {
-            continue;
-        }
-
-        let label = match &fa.label {
-            Some(lit) => lit.value(),
-            None => snake_to_title_case(&ident.to_string()),
-        };
-
-        let style = fa.style.as_ref().map(|lit| lit.value());
-
-        field_infos.push(ColFieldInfo {
-            ident,
-            label,
-            style,
-        });
-    }
-
-    // Build the line push expressions for each field (same pattern as Panel derive).
-    let line_pushes: Vec<proc_macro2::TokenStream> = field_infos
-        .iter()
-        .map(|fi| {
-            let ident = &fi.ident;
-            let label = &fi.label;
-            match &fi.style {
-                Some(sty) => {
-                    let open_tag = format!("[{}]", sty);
-                    let close_tag = format!("[/{}]", sty);
-                    quote! {
-                        lines.push(format!("{}{}:{} {}", #open_tag, #label, #close_tag, self.#ident));
-                    }
-                }
-                None => {
-                    quote! {
-                        lines.push(format!("{}: {}", #label, self.#ident));
-                    }
-                }
-            }
-        })
-        .collect();
+        let skip = fa.skip.as_ref().map(|b| b.value).unwrap_or(false);
+        if skip {
+            continue;
+        }
+        kv_fields.push(kv_field(Some(ident), &fa)?);
+    }
+    for fa in &columns_attrs.extra_fields {
+        kv_fields.push(kv_field(None, fa)?);
+    }
+
+    // Build the KeyValue pair expressions for each field (same as Panel derive).
+    let pair_pushes: Vec<proc_macro2::TokenStream> = kv_fields.iter().map(kv_pair_tokens).collect();
 
     // Build columns-level configuration statements.
     let mut cols_config = Vec::new();
@@ -2182,7 +2595,7 @@ fn derive_columns_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStr
     if let Some(ref lit) = columns_attrs.column_count {
         let val: usize = lit.base10_parse()?;
         cols_config.push(quote! {
-            cols.width = Some(max_width / #val);
+            cols = cols.with_column_count(#val);
         });
     }
     if let Some(ref lit) = columns_attrs.equal {
@@ -2217,30 +2630,28 @@ fn derive_columns_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStr
         impl #struct_name {
             /// Renders this struct as a card (a Panel with labeled key-value fields).
             ///
-            /// Each non-skipped field becomes a line `"Label: value"`. Field styles
-            /// are applied as markup tags around the label.
+            /// Each non-skipped field becomes an aligned `"Label: value"` row of a
+            /// [`gilt::kv::KeyValue`] list. Field styles are applied to the label.
             pub fn to_card(&self) -> gilt::panel::Panel {
-                let mut lines: Vec<String> = Vec::new();
-                #(#line_pushes)*
-                let content = gilt::text::Text::from_markup(&lines.join("\n"))
-                    .unwrap_or_else(|_| gilt::text::Text::from(lines.join("\n").as_str()));
-                let mut panel = gilt::panel::Panel::new(content);
-                panel.title = Some(gilt::text::Text::from(#card_title));
+                let mut kv = gilt::kv::KeyValue::new()
+                    .with_key_style(gilt::style::Style::null());
+                #(#pair_pushes)*
+                let mut panel = gilt::panel::Panel::new(kv.to_text());
+                panel.title = Some(gilt::text::Text::from(#card_title).into());
                 panel
             }
 
             /// Creates a [`gilt::columns::Columns`] from a slice of items.
             ///
-            /// Each item is rendered as a Panel card and laid out in columns.
-            /// Struct-level `#[columns(...)]` attributes control the column layout.
+            /// Each item becomes a Panel card, stacked into height-balanced
+            /// (masonry) columns whose widths follow the console width at
+            /// render time. Struct-level `#[columns(...)]` attributes control
+            /// the column layout.
             pub fn to_columns(items: &[Self]) -> gilt::columns::Columns {
-                let mut cols = gilt::columns::Columns::new();
-                #[allow(unused_variables)]
-                let max_width: usize = 80;
+                let mut cols = gilt::columns::Columns::new().with_masonry(true);
                 #(#cols_config)*
                 for item in items {
-                    let card = item.to_card();
-                    cols.add_renderable(&format!("{}", card));
+                    cols.add_panel(item.to_card().with_expand(false));
                 }
                 cols
             }
@@ -2267,6 +2678,10 @@ struct RuleAttrs {
     align: Option<LitStr>,
     /// End string appended after the rule (default "\n").
     end: Option<LitStr>,
+    /// Right-aligned text drawn after the line.
+    right: Option<LitStr>,
+    /// Show the current time on the right (set by the `timestamp` flag).
+    timestamp: bool,
 }
 
 /// A single key=value inside `#[rule(...)]` at the struct level.
@@ -2277,6 +2692,8 @@ struct RuleAttr {
 
 enum RuleAttrValue {
     Str(LitStr),
+    /// Standalone flag like `timestamp` (no `= ...`).
+    Flag,
 }
 
 impl Parse for RuleAttr {
@@ -2294,7 +2711,10 @@ impl Parse for RuleAttr {
                 Err(input.error("expected string literal"))
             }
         } else {
-            Err(input.error("expected `= \"...\"`"))
+            Ok(RuleAttr {
+                key,
+                value: RuleAttrValue::Flag,
+            })
         }
     }
 }
@@ -2328,6 +2748,18 @@ fn parse_rule_attrs(input: &DeriveInput) -> syn::Result<RuleAttrs> {
                 "end" => {
                     attrs.end = Some(rule_expect_str(&item, "end")?);
                 }
+                "right" => {
+                    attrs.right = Some(rule_expect_str(&item, "right")?);
+                }
+                "timestamp" => {
+                    if !matches!(item.value, RuleAttrValue::Flag) {
+                        return Err(syn::Error::new_spanned(
+                            &item.key,
+                            "`timestamp` is a flag and takes no value",
+                        ));
+                    }
+                    attrs.timestamp = true;
+                }
                 _ => {
                     return Err(syn::Error::new_spanned(
                         &item.key,
@@ -2341,9 +2773,13 @@ fn parse_rule_attrs(input: &DeriveInput) -> syn::Result<RuleAttrs> {
     Ok(attrs)
 }
 
-fn rule_expect_str(attr: &RuleAttr, _name: &str) -> syn::Result<LitStr> {
+fn rule_expect_str(attr: &RuleAttr, name: &str) -> syn::Result<LitStr> {
     match &attr.value {
         RuleAttrValue::Str(s) => Ok(s.clone()),
+        RuleAttrValue::Flag => Err(syn::Error::new_spanned(
+            &attr.key,
+            format!("`{name}` expects a string value: `{name} = \"...\"`"),
+        )),
     }
 }
 
@@ -2361,22 +2797,25 @@ fn align_tokens(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
     }
 }
 
-/// Check whether a field has `#[rule(title)]`.
-fn has_rule_title_attr(field: &syn::Field) -> syn::Result<bool> {
+/// Return the field's `#[rule(title)]` / `#[rule(right)]` role, if any.
+fn rule_field_role(field: &syn::Field) -> syn::Result<Option<Ident>> {
     for attr in &field.attrs {
         if !attr.path().is_ident("rule") {
             continue;
         }
         let ident: Ident = attr.parse_args()?;
-        if ident == "title" {
-            return Ok(true);
+        if ident == "title" || ident == "right" {
+            return Ok(Some(ident));
         }
         return Err(syn::Error::new_spanned(
             &ident,
-            format!("unknown rule field attribute `{}`. Expected: title", ident),
+            format!(
+                "unknown rule field attribute `{}`. Expected one of: title, right",
+                ident
+            ),
         ));
     }
-    Ok(false)
+    Ok(None)
 }
 
 // ---------------------------------------------------------------------------
@@ -2391,15 +2830,18 @@ fn has_rule_title_attr(field: &syn::Field) -> syn::Result<bool> {
 /// |-----------|------|-------------|
 /// | `title` | string | Custom title text (default: struct name) |
 /// | `characters` | string | Character(s) for the rule line (default "━") |
-/// | `style` | string | Style string for the rule line |
+/// | `style` | string | Style or theme token (e.g. "$accent") for the rule line |
 /// | `align` | string | Title alignment: "left", "center", "right" |
 /// | `end` | string | String appended after the rule (default "\n") |
+/// | `right` | string | Right-aligned text; the title moves to the left |
+/// | `timestamp` | flag | Show the time of the `to_rule` call on the right |
 ///
 /// # Field-level attributes (`#[rule(...)]`)
 ///
 /// | Attribute | Description |
 /// |-----------|-------------|
 /// | `title` | Use this field's `.to_string()` as the rule title |
+/// | `right` | Use this field's `.to_string()` as the right-aligned text |
 ///
 /// # Example
 ///
@@ -2415,6 +2857,14 @@ fn has_rule_title_attr(field: &syn::Field) -> syn::Result<bool> {
 ///
 /// let br = SectionBreak { heading: "Results".into() };
 /// let rule = br.to_rule();
+///
+/// // A log separator: `api ━━━━━━━━━━━━━━━━━━━━ 14:05:09`
+/// #[derive(Rule)]
+/// #[rule(timestamp)]
+/// struct DeployStart {
+///     #[rule(title)]
+///     service: String,
+/// }
 /// ```
 #[proc_macro_derive(Rule, attributes(rule))]
 pub fn derive_rule(input: TokenStream) -> TokenStream {
@@ -2463,25 +2913,41 @@ fn derive_rule_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
     // Parse struct-level #[rule(...)] attributes.
     let rule_attrs = parse_rule_attrs(input)?;
 
-    // Find the field annotated with `#[rule(title)]`, if any.
+    // Find the fields annotated with `#[rule(title)]` / `#[rule(right)]`, if any.
     let mut title_field: Option<Ident> = None;
+    let mut right_field: Option<Ident> = None;
     for field in fields.iter() {
         let ident = field
             .ident
             .as_ref()
             .expect("named field must have ident")
             .clone();
-        if has_rule_title_attr(field)? {
-            if title_field.is_some() {
+        if let Some(role) = rule_field_role(field)? {
+            let slot = if role == "title" {
+                &mut title_field
+            } else {
+                &mut right_field
+            };
+            if slot.is_some() {
                 return Err(syn::Error::new_spanned(
                     &ident,
-                    "only one field may be annotated with `#[rule(title)]`",
+                    format!("only one field may be annotated with `#[rule({role})]`"),
                 ));
             }
-            title_field = Some(ident);
+            *slot = Some(ident);
         }
     }
 
+    let right_sources = usize::from(right_field.is_some())
+        + usize::from(rule_attrs.right.is_some())
+        + usize::from(rule_attrs.timestamp);
+    if right_sources > 1 {
+        return Err(syn::Error::new_spanned(
+            struct_name,
+            "use only one of `#[rule(right)]` on a field, `right = \"...\"`, or `timestamp`",
+        ));
+    }
+
     // Determine the title source.
     // Priority: field with #[rule(title)] > struct-level title attr > struct name.
     let title_expr = if let Some(ref field_ident) = title_field {
@@ -2504,8 +2970,14 @@ fn derive_rule_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
     }
     if let Some(ref lit) = rule_attrs.style {
         let val = lit.value();
-        rule_config.push(quote! {
-            rule = rule.with_style(gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null()));
+        rule_config.push(if val.starts_with('$') {
+            quote! {
+                rule = rule.with_style_name(#val);
+            }
+        } else {
+            quote! {
+                rule = rule.with_style(gilt::style::Style::parse(#val).unwrap_or_else(|_| gilt::style::Style::null()));
+            }
         });
     }
     if let Some(ref lit) = rule_attrs.align {
@@ -2520,6 +2992,20 @@ fn derive_rule_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream
             rule = rule.with_end(#val);
         });
     }
+    if let Some(ref field_ident) = right_field {
+        rule_config.push(quote! {
+            rule = rule.with_right(&self.#field_ident.to_string());
+        });
+    } else if let Some(ref lit) = rule_attrs.right {
+        let val = lit.value();
+        rule_config.push(quote! {
+            rule = rule.with_right(#val);
+        });
+    } else if rule_attrs.timestamp {
+        rule_config.push(quote! {
+            rule = rule.with_timestamp();
+        });
+    }
 
     let expanded = quote! {
         impl #struct_name {
@@ -2783,6 +3269,189 @@ fn derive_inspect_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStr
     Ok(expanded)
 }
 
+// ===========================================================================
+// InspectInfo derive macro
+// ===========================================================================
+
+/// Join the `///` doc comment lines in `attrs`, or `None` if there are none.
+fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let doc = lines.join(" ").trim().to_string();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Render a type as source-like text, e.g. `Vec<String>` rather than the
+/// token stream's `Vec < String >`.
+fn type_to_string(ty: &syn::Type) -> String {
+    let mut out = quote!(#ty).to_string();
+    for (from, to) in [
+        (" < ", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+        (" ;", ";"),
+        (" :: ", "::"),
+        (":: ", "::"),
+        ("[ ", "["),
+        (" ]", "]"),
+        ("( ", "("),
+        (" )", ")"),
+    ] {
+        out = out.replace(from, to);
+    }
+    out
+}
+
+/// Parsed `#[inspect_info(...)]` attributes: struct-level `method = "..."`
+/// entries, or the field-level `skip` flag.
+#[derive(Default)]
+struct InspectInfoAttrs {
+    methods: Vec<LitStr>,
+    skip: bool,
+}
+
+fn parse_inspect_info_attrs(attrs: &[syn::Attribute]) -> syn::Result<InspectInfoAttrs> {
+    let mut parsed = InspectInfoAttrs::default();
+    for attr in attrs {
+        if !attr.path().is_ident("inspect_info") {
+            continue;
+        }
+        let items: Punctuated<InspectAttr, Token![,]> =
+            attr.parse_args_with(Punctuated::parse_terminated)?;
+        for item in items {
+            match item.key.to_string().as_str() {
+                "method" => parsed.methods.push(inspect_expect_str(&item, "method")?),
+                "skip" => parsed.skip = inspect_expect_bool(&item, "skip")?.value,
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        &item.key,
+                        format!("unknown inspect_info attribute `{}`", other),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+/// Derive macro that implements `gilt::inspect::InspectInfo` from a struct's
+/// doc comments and fields.
+///
+/// The struct's `///` comment becomes the type doc, and each named field is
+/// listed with its type and `///` comment. Methods cannot be discovered by a
+/// derive, so list the ones worth showing with struct-level
+/// `#[inspect_info(method = "fn area(&self) -> f64")]` attributes.
+///
+/// # Attributes
+///
+/// | Attribute | Level | Description |
+/// |-----------|-------|-------------|
+/// | `method = "..."` | struct | Method signature to list (repeatable) |
+/// | `skip` | field | Leave the field out of the listing |
+///
+/// # Example
+///
+/// ```ignore
+/// use gilt::DeriveInspectInfo;
+///
+/// /// A circle centred on the origin.
+/// #[derive(Debug, DeriveInspectInfo)]
+/// #[inspect_info(method = "fn area(&self) -> f64")]
+/// struct Circle {
+///     /// Distance from the centre.
+///     radius: f64,
+/// }
+///
+/// gilt::inspect_info(&Circle { radius: 1.0 });
+/// ```
+#[proc_macro_derive(InspectInfo, attributes(inspect_info))]
+pub fn derive_inspect_info(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive_inspect_info_impl(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn derive_inspect_info_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                struct_name,
+                "InspectInfo derive only supports structs",
+            ));
+        }
+    };
+
+    let struct_attrs = parse_inspect_info_attrs(&input.attrs)?;
+
+    let doc_expr = match doc_comment(&input.attrs) {
+        Some(doc) => quote! { Some(#doc.to_string()) },
+        None => quote! { None },
+    };
+
+    let mut field_infos = Vec::new();
+    if let Fields::Named(named) = fields {
+        for field in &named.named {
+            if parse_inspect_info_attrs(&field.attrs)?.skip {
+                continue;
+            }
+            let name = field
+                .ident
+                .as_ref()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+            let ty = type_to_string(&field.ty);
+            let with_doc = doc_comment(&field.attrs).map(|doc| quote! { .with_doc(#doc) });
+            field_infos.push(quote! {
+                gilt::inspect::FieldInfo::new(#name, #ty) #with_doc
+            });
+        }
+    }
+
+    let methods = &struct_attrs.methods;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics gilt::inspect::InspectInfo for #struct_name #ty_generics #where_clause {
+            fn doc(&self) -> Option<String> {
+                #doc_expr
+            }
+
+            fn fields(&self) -> Vec<gilt::inspect::FieldInfo> {
+                vec![#(#field_infos),*]
+            }
+
+            fn methods(&self) -> Vec<gilt::inspect::MethodInfo> {
+                vec![#(gilt::inspect::MethodInfo::new(#methods)),*]
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -2791,6 +3460,19 @@ fn derive_inspect_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStr
 mod tests {
     use super::*;
 
+    // -- type_to_string ----------------------------------------------------
+
+    #[test]
+    fn test_type_to_string() {
+        let ty: syn::Type = syn::parse_quote!(Option<Vec<(String, &'static str)>>);
+        assert_eq!(type_to_string(&ty), "Option<Vec<(String, &'static str)>>");
+        let ty: syn::Type = syn::parse_quote!(std::collections::HashMap<u8, [u8; 4]>);
+        assert_eq!(
+            type_to_string(&ty),
+            "std::collections::HashMap<u8, [u8; 4]>"
+        );
+    }
+
     // -- snake_to_title_case -----------------------------------------------
 
     #[test]
@@ -2846,6 +3528,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_box_style_tokens_registered_name() {
+        let lit = LitStr::new("registered:DOTTED", Span::call_site());
+        let tokens = box_style_tokens(&lit).unwrap().to_string();
+        assert!(tokens.contains("box_chars :: get"), "got: {}", tokens);
+        assert!(tokens.contains("\"DOTTED\""), "got: {}", tokens);
+        assert!(tokens.contains("panic !"), "got: {}", tokens);
+
+        let empty = LitStr::new("registered:", Span::call_site());
+        assert!(box_style_tokens(&empty).is_err());
+    }
+
     #[test]
     fn test_box_style_tokens_invalid() {
         let lit = LitStr::new("NONEXISTENT", Span::call_site());
@@ -3222,6 +3916,42 @@ mod tests {
         assert!(tokens.contains("set_expand"));
     }
 
+    #[test]
+    fn test_derive_table_computed_values() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[table(extra_column(header = "Total", value = "self.price * self.qty as f64", justify = "right"))]
+            struct Line {
+                price: f64,
+                qty: u32,
+                #[column(value = "self.name.to_uppercase()")]
+                name: String,
+            }
+        };
+        let tokens = derive_table_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("\"Total\""));
+        assert!(tokens.contains("(item . price * item . qty as f64) . to_string ()"));
+        assert!(tokens.contains("(item . name . to_uppercase ()) . to_string ()"));
+        assert!(!tokens.contains("self"));
+    }
+
+    #[test]
+    fn test_derive_table_rejects_bad_extra_column() {
+        for attr in [
+            quote! { #[table(extra_column(header = "Total"))] },
+            quote! { #[table(extra_column(header = "T", value = "self.a", skip))] },
+            quote! { #[table(extra_column = "Total")] },
+            quote! { #[table(extra_column(header = "T", value = "self.a +"))] },
+        ] {
+            let input: DeriveInput = syn::parse_quote! {
+                #attr
+                struct Rec {
+                    a: u32,
+                }
+            };
+            assert!(derive_table_impl(&input).is_err(), "{attr}");
+        }
+    }
+
     // -- Panel derive tests ------------------------------------------------
 
     #[test]
@@ -3253,8 +3983,8 @@ mod tests {
         );
         assert!(tokens.contains("Panel"), "should reference Panel type");
         assert!(
-            tokens.contains("from_markup"),
-            "should use from_markup for content"
+            tokens.contains("KeyValue"),
+            "should lay out content with KeyValue"
         );
         // Default title should be the struct name.
         assert!(
@@ -3302,10 +4032,7 @@ mod tests {
         assert!(tokens.contains("expand"), "should set expand");
         assert!(tokens.contains("highlight"), "should set highlight");
         assert!(tokens.contains("\"Host\""), "should use custom label");
-        assert!(
-            tokens.contains("bold cyan"),
-            "should contain field style markup"
-        );
+        assert!(tokens.contains("bold cyan"), "should contain field style");
     }
 
     #[test]
@@ -3338,6 +4065,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_derive_panel_computed_values() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[panel(extra_field(label = "Usage", value = "format!(\"{}%\", self.used * 100 / self.total)", style = "bold"))]
+            struct Disk {
+                used: u64,
+                #[field(value = "self.total / 1024", label = "Total (KiB)")]
+                total: u64,
+            }
+        };
+        let tokens = derive_panel_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("\"Usage\""));
+        assert!(tokens.contains("self . used * 100 / self . total"));
+        assert!(tokens.contains("(self . total / 1024) . to_string ()"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[panel(extra_field(value = "self.a"))]
+            struct Rec {
+                a: u32,
+            }
+        };
+        assert!(derive_panel_impl(&input).is_err());
+    }
+
     #[test]
     fn test_derive_panel_custom_labels() {
         let input: DeriveInput = syn::parse_quote! {
@@ -3857,8 +4608,12 @@ mod tests {
             "should implement Renderable trait"
         );
         assert!(
-            tokens.contains("rich_console"),
-            "should generate rich_console method"
+            tokens.contains("gilt_console"),
+            "should generate gilt_console method"
+        );
+        assert!(
+            tokens.contains("gilt :: measure :: Measure"),
+            "should implement Measure trait"
         );
         assert!(tokens.contains("to_panel"), "should delegate to to_panel()");
         assert!(
@@ -3888,8 +4643,12 @@ mod tests {
             "should implement Renderable trait"
         );
         assert!(
-            tokens.contains("rich_console"),
-            "should generate rich_console method"
+            tokens.contains("gilt_console"),
+            "should generate gilt_console method"
+        );
+        assert!(
+            tokens.contains("gilt :: measure :: Measure"),
+            "should implement Measure trait"
         );
         assert!(tokens.contains("to_tree"), "should delegate to to_tree()");
         assert!(
@@ -3926,7 +4685,7 @@ mod tests {
     #[test]
     fn test_derive_renderable_rejects_unknown_via() {
         let input: DeriveInput = syn::parse_quote! {
-            #[renderable(via = "table")]
+            #[renderable(via = "banner")]
             struct Rec {
                 a: String,
             }
@@ -3942,16 +4701,86 @@ mod tests {
     }
 
     #[test]
-    fn test_derive_renderable_rejects_enum() {
+    fn test_derive_renderable_enum_variants() {
         let input: DeriveInput = syn::parse_quote! {
-            enum Foo { A, B }
+            #[renderable(via = "panel")]
+            enum Entry {
+                Config(Config),
+                #[renderable(via = "table")]
+                Job { job: Job },
+                Plain(Banner),
+                Separator,
+            }
         };
-        let result = derive_renderable_impl(&input);
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("does not support enums"));
+        let tokens = derive_renderable_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("match self"));
+        assert!(tokens.contains("Self :: Config (inner) => { let widget = & inner . to_panel () ;"));
+        assert!(tokens.contains("< Job > :: to_table (std :: slice :: from_ref (inner))"));
+        assert!(tokens.contains("Self :: Job { job : inner }"));
+        assert!(tokens.contains("Text :: from (\"Separator\")"));
+    }
+
+    #[test]
+    fn test_derive_renderable_enum_delegates_to_field() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Shown {
+                Text(gilt::text::Text),
+            }
+        };
+        let tokens = derive_renderable_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("Self :: Text (inner) => { let widget = inner ;"));
+    }
+
+    #[test]
+    fn test_derive_renderable_enum_rejects_bad_variants() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Foo { A(u8, u8) }
+        };
+        let err = derive_renderable_impl(&input).unwrap_err().to_string();
+        assert!(err.contains("unit and single-field variants"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            enum Foo {
+                #[renderable(via = "rule")]
+                A,
+            }
+        };
+        let err = derive_renderable_impl(&input).unwrap_err().to_string();
+        assert!(err.contains("take no `via`"), "{err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            enum Foo {
+                #[renderable(via = "banner")]
+                A(u8),
+            }
+        };
+        assert!(derive_renderable_impl(&input).is_err());
+    }
+
+    #[test]
+    fn test_derive_renderable_struct_vias() {
+        for (via, expected) in [
+            (
+                "table",
+                "Self > :: to_table (std :: slice :: from_ref (self))",
+            ),
+            (
+                "columns",
+                "Self > :: to_columns (std :: slice :: from_ref (self))",
+            ),
+            ("rule", "self . to_rule ()"),
+            ("inspect", "self . to_inspect ()"),
+        ] {
+            let lit = LitStr::new(via, proc_macro2::Span::call_site());
+            let input: DeriveInput = syn::parse_quote! {
+                #[renderable(via = #lit)]
+                struct Rec {
+                    a: String,
+                }
+            };
+            let tokens = derive_renderable_impl(&input).unwrap().to_string();
+            assert!(tokens.contains(expected), "{via}: {tokens}");
+        }
     }
 
     #[test]
@@ -4063,10 +4892,7 @@ mod tests {
             tokens.contains("\"Project\""),
             "should use custom label 'Project'"
         );
-        assert!(
-            tokens.contains("bold cyan"),
-            "should contain field style markup"
-        );
+        assert!(tokens.contains("bold cyan"), "should contain field style");
         assert!(
             tokens.contains("\"Status\""),
             "should use custom label 'Status'"
@@ -4074,9 +4900,11 @@ mod tests {
         assert!(tokens.contains("equal"), "should set equal");
         assert!(tokens.contains("expand"), "should set expand");
         assert!(
-            tokens.contains("width"),
-            "should set width from column_count"
+            tokens.contains("with_column_count (3usize)")
+                || tokens.contains("with_column_count (3)"),
+            "should set column_count: {tokens}"
         );
+        assert!(!tokens.contains("80"), "should not guess the width");
         assert!(tokens.contains("\"My Projects\""), "should contain title");
     }
 
@@ -4109,6 +4937,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_derive_columns_computed_values() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[columns(extra_field(label = "Load", value = "self.jobs / self.cores"))]
+            struct Node {
+                jobs: u32,
+                #[field(value = "self.cores * 2")]
+                cores: u32,
+            }
+        };
+        let tokens = derive_columns_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("\"Load\""));
+        assert!(tokens.contains("(self . jobs / self . cores) . to_string ()"));
+        assert!(tokens.contains("(self . cores * 2) . to_string ()"));
+    }
+
     #[test]
     fn test_derive_columns_rejects_enum() {
         let input: DeriveInput = syn::parse_quote! {
@@ -4277,6 +5121,48 @@ mod tests {
             tokens.contains("\"bold blue\""),
             "should contain style value"
         );
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[rule(style = "$accent")]
+            struct Divider {
+                label: String,
+            }
+        };
+        let tokens = derive_rule_impl(&input).unwrap().to_string();
+        assert!(
+            tokens.contains("with_style_name (\"$accent\")"),
+            "should resolve theme tokens when the rule is rendered"
+        );
+    }
+
+    #[test]
+    fn test_derive_panel_rejects_theme_token_in_markup_styles() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[panel(border_style = "$border")]
+            struct Server {
+                #[field(style = "$accent")]
+                name: String,
+            }
+        };
+        let err = derive_panel_impl(&input).unwrap_err().to_string();
+        assert!(err.contains("`$accent`"), "unexpected error: {err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[panel(title_style = "$title")]
+            struct Server {
+                name: String,
+            }
+        };
+        assert!(derive_panel_impl(&input).is_err());
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[panel(border_style = "$border", style = "$body")]
+            struct Server {
+                name: String,
+            }
+        };
+        let tokens = derive_panel_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("\"$border\""));
     }
 
     #[test]
@@ -4350,6 +5236,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_derive_rule_right_and_timestamp() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[rule(right = "v1.2")]
+            struct Release {
+                name: String,
+            }
+        };
+        let tokens = derive_rule_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("with_right (\"v1.2\")"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[rule(timestamp)]
+            struct Step {
+                #[rule(title)]
+                name: String,
+            }
+        };
+        let tokens = derive_rule_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("with_timestamp"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            struct Step {
+                #[rule(title)]
+                name: String,
+                #[rule(right)]
+                elapsed: String,
+            }
+        };
+        let tokens = derive_rule_impl(&input).unwrap().to_string();
+        assert!(tokens.contains("with_right (& self . elapsed . to_string ())"));
+    }
+
+    #[test]
+    fn test_derive_rule_rejects_conflicting_right() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[rule(right = "x", timestamp)]
+            struct Step {
+                name: String,
+            }
+        };
+        let err = derive_rule_impl(&input).unwrap_err().to_string();
+        assert!(err.contains("only one of"), "unexpected error: {err}");
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[rule(timestamp = "yes")]
+            struct Step {
+                name: String,
+            }
+        };
+        assert!(derive_rule_impl(&input).is_err());
+    }
+
     #[test]
     fn test_derive_rule_rejects_enum() {
         let input: DeriveInput = syn::parse_quote! {
@@ -4372,6 +5311,7 @@ mod tests {
         assert_eq!(attr.key, "style");
         match attr.value {
             RuleAttrValue::Str(s) => assert_eq!(s.value(), "bold red"),
+            RuleAttrValue::Flag => panic!("expected Str"),
         }
     }
 
@@ -5,7 +5,7 @@
 #[cfg(feature = "async")]
 #[tokio::main]
 async fn main() {
-    use futures::StreamExt;
+    use futures_util::StreamExt;
     use gilt::r#async::{LiveAsync, ProgressChannel, ProgressStreamExt};
     use gilt::style::Style;
     use gilt::text::Text;
@@ -16,7 +16,7 @@ async fn main() {
     // Demo 1: Progress stream
     println!("1. Progress Stream Demo");
     {
-        let stream = futures::stream::iter(0..50);
+        let stream = futures_util::stream::iter(0..50);
         let mut progress_stream = stream.track_progress("Processing items", Some(50.0));
 
         while let Some(i) = progress_stream.next().await {
@@ -5,7 +5,7 @@
 //!
 //! Run with: `cargo run --example badge`
 
-use gilt::badge::{Badge, BadgeStyle};
+use gilt::badge::{Badge, BadgeStyle, Badges};
 use gilt::console::Console;
 use gilt::rule::Rule;
 use gilt::style::Style;
@@ -116,6 +116,18 @@ fn main() {
     console.print(&plain_neutral);
     console.print_text("");
 
+    // -- Compact chips in a row -----------------------------------------------
+    console.print(&Rule::with_title("Compact Chips"));
+
+    let results = Badges::new(vec![
+        Badge::success("PASS").compact(true),
+        Badge::error("FAIL").compact(true),
+        Badge::warning("FLAKY").compact(true),
+        Badge::new("SKIP").compact(true),
+    ]);
+    console.print(&results);
+    console.print_text("");
+
     // -- Display trait --------------------------------------------------------
     console.print(&Rule::with_title("Display Trait (via println!)"));
     let display_badge = Badge::info("From Display");
@@ -178,6 +178,31 @@ fn main() {
         idle_timeout_secs: 120,
     };
     console.print(&gateway.to_inspect());
+
+    // ── 6. Fields, docs and methods via InspectInfo ──────────────────────
+    //
+    // `#[derive(DeriveInspectInfo)]` collects the doc comments and field
+    // types; methods are listed with `#[inspect_info(method = "...")]`.
+
+    console.rule(Some("6. InspectInfo"));
+
+    /// A rolling window of latency samples.
+    #[derive(Debug, gilt::DeriveInspectInfo)]
+    #[inspect_info(method = "fn push(&mut self, sample_ms: f64)")]
+    #[inspect_info(method = "fn p99(&self) -> Option<f64>")]
+    #[allow(dead_code)]
+    struct LatencyWindow {
+        /// Samples in arrival order, in milliseconds.
+        samples: Vec<f64>,
+        /// Number of samples kept before the oldest is dropped.
+        capacity: usize,
+    }
+
+    let window = LatencyWindow {
+        samples: vec![12.5, 9.1, 30.2],
+        capacity: 128,
+    };
+    console.inspect_info(&window);
 }
 
 #[cfg(not(feature = "derive"))]
@@ -4,7 +4,9 @@
 //! The Renderable derive implements `gilt::console::Renderable` for a struct by
 //! delegating to a widget conversion method. By default it calls `to_panel()`,
 //! so the struct must also derive Panel. Use `#[renderable(via = "tree")]` to
-//! delegate through `to_tree()` instead (requires Tree derive).
+//! delegate through `to_tree()` instead (requires Tree derive); `"table"`,
+//! `"columns"`, `"rule"` and `"inspect"` work the same way. Enums render each
+//! variant on its own, and each variant may choose its own `via`.
 //!
 //! Once a struct implements Renderable, it can be passed directly to
 //! `console.print()` without manual conversion.
@@ -212,6 +214,38 @@ fn main() {
     // Because they implement Renderable, they work with console.print() directly:
     console.print(&staging);
     console.print(&production);
+
+    // ── 5. Enums: each variant picks its own widget ──────────────────────
+    console.rule(Some("5. Enums with per-variant delegation"));
+
+    #[derive(gilt::Table)]
+    struct Job {
+        name: String,
+        state: String,
+    }
+
+    #[derive(RenderableDerive)]
+    enum Entry {
+        // Delegates to DeploymentInfo's own Renderable impl.
+        Deployment(DeploymentInfo),
+        // Renders the job as a single-row table.
+        #[renderable(via = "table")]
+        Job(Job),
+        // Unit variants render their name.
+        Done,
+    }
+
+    let entries = vec![
+        Entry::Job(Job {
+            name: "migrate-db".into(),
+            state: "succeeded".into(),
+        }),
+        Entry::Deployment(staging),
+        Entry::Done,
+    ];
+    for entry in &entries {
+        console.print(entry);
+    }
 }
 
 #[cfg(not(feature = "derive"))]
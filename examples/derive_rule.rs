@@ -145,6 +145,36 @@ fn main() {
         _placeholder: u8,
     }
     console.print(&(WaveDivider { _placeholder: 0 }).to_rule());
+
+    // ── 6. Right-aligned metadata ───────────────────────────────────────
+    console.print_text("\n[bold cyan]6. Right-aligned metadata — log separators[/]");
+
+    #[derive(DeriveRule)]
+    #[rule(characters = "─", style = "dim", timestamp)]
+    struct LogSection {
+        #[rule(title)]
+        stage: String,
+    }
+    console.print(
+        &(LogSection {
+            stage: "Deploy".into(),
+        })
+        .to_rule(),
+    );
+
+    #[derive(DeriveRule)]
+    #[rule(characters = "─", style = "dim")]
+    struct StepResult {
+        #[rule(title)]
+        step: String,
+        #[rule(right)]
+        elapsed: String,
+    }
+    let result = StepResult {
+        step: "Tests".into(),
+        elapsed: "2.4s".into(),
+    };
+    console.print(&result.to_rule());
 }
 
 #[cfg(not(feature = "derive"))]
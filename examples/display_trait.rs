@@ -39,7 +39,7 @@ fn main() {
         Style::null(),
     );
     let mut panel = Panel::new(content);
-    panel.title = Some(Text::new("Display Trait", Style::null()));
+    panel.title = Some(Text::new("Display Trait", Style::null()).into());
 
     println!("{panel}");
 
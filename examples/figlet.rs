@@ -1,13 +1,14 @@
-//! Demonstrates the Figlet widget -- large ASCII art text using block characters.
+//! Demonstrates the Figlet widget -- large ASCII art text in the block font
+//! and in the embedded FIGlet fonts.
 //!
 //! Run with: `cargo run --example figlet`
 
 use gilt::color::Color;
 use gilt::console::Console;
-use gilt::figlet::Figlet;
-use gilt::gradient::Gradient;
+use gilt::figlet::{Figlet, FigletFont};
 use gilt::rule::Rule;
 use gilt::style::Style;
+use gilt::text::JustifyMethod;
 
 fn main() {
     let mut console = Console::builder()
@@ -19,18 +20,31 @@ fn main() {
     // -- "GILT" with gradient coloring ------------------------------------
     console.print(&Rule::with_title("GILT Banner (Gradient)"));
 
-    // Render the figlet text, then apply a gradient across it
-    let banner = Figlet::new("GILT");
-    let banner_text = format!("{}", banner);
-    let gradient = Gradient::new(
-        &banner_text,
-        vec![
-            Color::from_rgb(255, 215, 0), // gold
-            Color::from_rgb(255, 140, 0), // dark orange
-            Color::from_rgb(255, 69, 0),  // red-orange
-        ],
-    );
-    console.print(&gradient);
+    let banner = Figlet::new("GILT").with_gradient(vec![
+        Color::from_rgb(255, 215, 0), // gold
+        Color::from_rgb(255, 140, 0), // dark orange
+        Color::from_rgb(255, 69, 0),  // red-orange
+    ]);
+    console.print(&banner);
+
+    // -- Embedded FIGlet fonts --------------------------------------------
+    for name in FigletFont::BUILTIN {
+        console.print(&Rule::with_title(&format!("FIGlet font: {}", name)));
+        let font = FigletFont::builtin(name).unwrap();
+        console.print(&Figlet::new("Hello, gilt!").with_font(font));
+    }
+
+    // -- Centered, with a gradient ----------------------------------------
+    console.print(&Rule::with_title("Centered Gradient (standard font)"));
+
+    let centered = Figlet::new("Rust")
+        .with_font(FigletFont::standard())
+        .with_justify(JustifyMethod::Center)
+        .with_gradient(vec![
+            Color::from_rgb(0, 200, 255),
+            Color::from_rgb(200, 0, 255),
+        ]);
+    console.print(&centered);
 
     // -- "Hello" in red ---------------------------------------------------
     console.print(&Rule::with_title("Styled Figlet Text"));
@@ -15,7 +15,7 @@ use gilt::text::Text;
 fn print_in_panel(console: &mut Console, content: impl Into<Text>, title: &str) {
     let text: Text = content.into();
     let mut panel = Panel::fit(text);
-    panel.title = Some(Text::new(title, Style::parse("bold").unwrap()));
+    panel.title = Some(Text::new(title, Style::parse("bold").unwrap()).into());
     console.print(&panel);
 }
 
@@ -9,11 +9,15 @@
 //! - ETA (estimated time remaining)
 //! - Visual progress bar
 
+#[cfg(feature = "http")]
 use std::env;
+#[cfg(feature = "http")]
 use std::path::Path;
 
+#[cfg(feature = "http")]
 use gilt::http::RequestBuilderProgress;
 
+#[cfg(feature = "http")]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Parse command line arguments
@@ -90,3 +94,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\nAll examples completed!");
     Ok(())
 }
+
+#[cfg(not(feature = "http"))]
+fn main() {
+    eprintln!("This example requires the 'http' feature enabled.");
+    eprintln!("Run with: cargo run --example http_demo --features http");
+    std::process::exit(1);
+}
@@ -34,8 +34,8 @@ fn main() {
         Style::null(),
     );
     let mut panel = Panel::fit(content);
-    panel.title = Some(Text::new("About Gilt", Style::parse("bold").unwrap()));
-    panel.subtitle = Some(Text::new("v0.1.0", Style::parse("dim").unwrap()));
+    panel.title = Some(Text::new("About Gilt", Style::parse("bold").unwrap()).into());
+    panel.subtitle = Some(Text::new("v0.1.0", Style::parse("dim").unwrap()).into());
 
     console.print(&panel);
 
@@ -21,14 +21,14 @@ fn main() {
     console.print(&Rule::with_title("1. Basic Prompt"));
 
     let mut input = Cursor::new(b"Alice\n" as &[u8]);
-    let result = Prompt::new("What is your name?").ask_with_input(&mut input);
+    let result: String = Prompt::new("What is your name?").ask_with_input(&mut input);
     console.print_text(&format!("  You entered: {}\n", result));
 
     // ── 2. Prompt with default ──────────────────────────────────────────
     console.print(&Rule::with_title("2. Prompt with Default"));
 
     let mut input = Cursor::new(b"\n" as &[u8]); // empty input -> uses default
-    let result = Prompt::new("Favorite color?")
+    let result: String = Prompt::new("Favorite color?")
         .with_default("blue")
         .ask_with_input(&mut input);
     console.print_text(&format!(
@@ -40,7 +40,7 @@ fn main() {
     console.print(&Rule::with_title("3. Prompt with Choices"));
 
     let mut input = Cursor::new(b"green\n" as &[u8]);
-    let result = Prompt::new("Pick a color")
+    let result: String = Prompt::new("Pick a color")
         .with_choices(vec!["red".into(), "green".into(), "blue".into()])
         .ask_with_input(&mut input);
     console.print_text(&format!("  Selected: {}\n", result));
@@ -100,7 +100,7 @@ fn main() {
     console.print(&Rule::with_title("9. Case-Insensitive Choices"));
 
     let mut input = Cursor::new(b"rust\n" as &[u8]);
-    let result = Prompt::new("Favorite language?")
+    let result: String = Prompt::new("Favorite language?")
         .with_choices(vec!["Rust".into(), "Python".into(), "Go".into()])
         .with_case_sensitive(false)
         .ask_with_input(&mut input);
@@ -119,6 +119,7 @@ struct LogEntry {
     source: String,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 enum LogLevel {
     Debug,
@@ -204,7 +205,7 @@ struct QuickStatus {
     value: i32,
 }
 
-gilt::rich_cast_impl! { QuickStatus => |s|
+gilt::gilt_cast_impl! { QuickStatus => |s|
     Box::new(Panel::new(Text::from(format!("{}: {}", s.label, s.value)))
         .with_title("Quick Status"))
 }
@@ -356,7 +357,7 @@ fn main() {
     let boxed: Box<dyn std::any::Any> = Box::new(original_text);
 
     // Try to cast it back to Text
-    match protocol::rich_cast::<Text>(boxed) {
+    match protocol::gilt_cast::<Text>(boxed) {
         Some(text) => {
             println!("✓ Successfully cast back to Text!");
             console.print(&*text);
@@ -371,7 +372,7 @@ fn main() {
     let text2 = Text::from("Another text");
     let boxed2: Box<dyn std::any::Any> = Box::new(text2);
 
-    match protocol::rich_cast::<Panel>(boxed2) {
+    match protocol::gilt_cast::<Panel>(boxed2) {
         Some(_) => println!("✓ Unexpectedly succeeded casting Text to Panel"),
         None => println!("✓ Correctly failed to cast Text to Panel (expected)"),
     }
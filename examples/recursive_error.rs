@@ -94,17 +94,16 @@ fn main() {
         );
     }
 
-    let tb = Traceback {
-        title: "RecursionError".to_string(),
-        message: "maximum recursion depth exceeded\n\
-                  \n\
-                  The above error was caused by mutual recursion between foo() and bar().\n\
-                  Rich (and gilt) can exclude frames in the middle to avoid huge tracebacks."
-            .to_string(),
-        frames,
-        max_frames: 20,
-        ..Traceback::new()
-    };
+    let tb = Traceback::new()
+        .with_title("RecursionError")
+        .with_message(
+            "maximum recursion depth exceeded\n\
+             \n\
+             The above error was caused by mutual recursion between foo() and bar().\n\
+             Rich (and gilt) can exclude frames in the middle to avoid huge tracebacks.",
+        )
+        .with_frames(frames)
+        .with_max_frames(20);
     console.print(&tb);
 
     // ── 3. Smaller chain with source context ────────────────────────────────
@@ -128,16 +127,15 @@ fn main() {
             .with_source_line("    compiler.compile(&source)?;"),
     ];
 
-    let tb = Traceback {
-        title: "StackOverflow".to_string(),
-        message: "thread 'main' has overflowed its stack\n\
-                  \n\
-                  Caused by:\n  \
-                  Infinite recursion in expression parser: \
-                  parse_expression -> parse_term -> parse_expression -> ..."
-            .to_string(),
-        frames,
-        ..Traceback::new()
-    };
+    let tb = Traceback::new()
+        .with_title("StackOverflow")
+        .with_message(
+            "thread 'main' has overflowed its stack\n\
+             \n\
+             Caused by:\n  \
+             Infinite recursion in expression parser: \
+             parse_expression -> parse_term -> parse_expression -> ...",
+        )
+        .with_frames(frames);
     console.print(&tb);
 }
@@ -968,12 +968,10 @@ Gilt supports **bold**, *italic*, and `inline code` in markdown.
         Frame::new("src/main.rs", Some(28), "main")
             .with_source_line("    server.run(handle_request).await?;"),
     ];
-    let tb = Traceback {
-        title: "ConnectionError".to_string(),
-        message: "failed to establish connection".to_string(),
-        frames,
-        ..Traceback::new()
-    };
+    let tb = Traceback::new()
+        .with_title("ConnectionError")
+        .with_message("failed to establish connection")
+        .with_frames(frames);
     console.print(&tb);
     pause();
 
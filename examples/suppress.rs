@@ -78,16 +78,15 @@ fn main() {
     all_frames.extend(app_frames());
     all_frames.extend(library_frames());
 
-    let tb_full = Traceback {
-        title: "ValueError".to_string(),
-        message: "invalid digit found in string\n\
-                  \n\
-                  Note: All frames shown, including 4 library frames from `clap`.\n\
-                  In a real traceback, library internals add noise and distract from the bug."
-            .to_string(),
-        frames: all_frames,
-        ..Traceback::new()
-    };
+    let tb_full = Traceback::new()
+        .with_title("ValueError")
+        .with_message(
+            "invalid digit found in string\n\
+             \n\
+             Note: All frames shown, including 4 library frames from `clap`.\n\
+             In a real traceback, library internals add noise and distract from the bug.",
+        )
+        .with_frames(all_frames);
     console.print(&tb_full);
 
     // ── 2. Suppressed traceback (application frames only) ───────────────────
@@ -103,17 +102,16 @@ fn main() {
         .filter(|f| !f.filename.contains(".cargo/registry"))
         .collect();
 
-    let tb_suppressed = Traceback {
-        title: "ValueError".to_string(),
-        message: "invalid digit found in string\n\
-                  \n\
-                  Note: Library frames from `clap` have been suppressed.\n\
-                  Only application frames are shown, making the error location clear.\n\
-                  (4 frames from clap::parser and clap::build were hidden)"
-            .to_string(),
-        frames: filtered_frames,
-        ..Traceback::new()
-    };
+    let tb_suppressed = Traceback::new()
+        .with_title("ValueError")
+        .with_message(
+            "invalid digit found in string\n\
+             \n\
+             Note: Library frames from `clap` have been suppressed.\n\
+             Only application frames are shown, making the error location clear.\n\
+             (4 frames from clap::parser and clap::build were hidden)",
+        )
+        .with_frames(filtered_frames);
     console.print(&tb_suppressed);
 
     // ── 3. Explanation panel ────────────────────────────────────────────────
@@ -176,16 +174,15 @@ fn main() {
         );
     }
 
-    let tb_deep = Traceback {
-        title: "TimeoutError".to_string(),
-        message: "request timed out after 30s\n\
-                  \n\
-                  Note: max_frames=8 truncates the middle of the 20-frame stack,\n\
-                  showing the first 4 and last 4 frames with an omission marker."
-            .to_string(),
-        frames: deep_frames,
-        max_frames: 8,
-        ..Traceback::new()
-    };
+    let tb_deep = Traceback::new()
+        .with_title("TimeoutError")
+        .with_message(
+            "request timed out after 30s\n\
+             \n\
+             Note: max_frames=8 truncates the middle of the 20-frame stack,\n\
+             showing the first 4 and last 4 frames with an omission marker.",
+        )
+        .with_frames(deep_frames)
+        .with_max_frames(8);
     console.print(&tb_deep);
 }
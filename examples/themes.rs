@@ -170,8 +170,8 @@ fn main() {
     println!("🎨 Using Theme Colors in Styles:");
     println!("   ──────────────────────────────────────────────────────────────");
     let red = Style::parse(&format!("bold {}", monokai::RED)).unwrap();
-    let green = Style::parse(&format!("{}", monokai::GREEN)).unwrap();
-    let blue = Style::parse(&format!("{}", monokai::BLUE)).unwrap();
+    let green = Style::parse(monokai::GREEN).unwrap();
+    let blue = Style::parse(monokai::BLUE).unwrap();
 
     println!(
         "   Red:   {} {}",
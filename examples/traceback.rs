@@ -1,7 +1,8 @@
 //! Traceback rendering demo — shows gilt's styled error display.
 //!
 //! Demonstrates rendering of error chains, backtraces, and custom tracebacks
-//! with syntax highlighting and source context.
+//! with syntax highlighting and source context. Pass `--panic` to install the
+//! panic hook and trigger a real panic.
 
 use gilt::console::Console;
 use gilt::rule::Rule;
@@ -72,15 +73,14 @@ fn main() {
             .with_source_line("    server.run(handle_request).await?;"),
     ];
 
-    let tb = Traceback {
-        title: "ConnectionError".to_string(),
-        message: "failed to establish database connection\n\
-                  Caused by:\n  \
-                  Connection refused (os error 111)"
-            .to_string(),
-        frames,
-        ..Traceback::new()
-    };
+    let tb = Traceback::new()
+        .with_title("ConnectionError")
+        .with_message(
+            "failed to establish database connection\n\
+             Caused by:\n  \
+             Connection refused (os error 111)",
+        )
+        .with_frames(frames);
     console.print(&tb);
 
     // ── 5. Customised width and extra context lines ────────────────────────
@@ -97,4 +97,13 @@ fn main() {
     .with_width(60)
     .with_word_wrap(true);
     console.print(&tb);
+
+    // ── 6. Installed panic hook ────────────────────────────────────────────
+    // Run with `-- --panic` to see a real panic rendered by the hook.
+    if std::env::args().any(|arg| arg == "--panic") {
+        console.print(&Rule::with_title("Installed Panic Hook"));
+        gilt::traceback::install();
+        let values: Vec<u32> = Vec::new();
+        let _ = values[3];
+    }
 }
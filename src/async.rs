@@ -6,6 +6,9 @@
 //! # Features
 //!
 //! - **Async Progress Tracking**: Track progress on async streams with [`ProgressStreamExt`]
+//!   or [`Progress::wrap_stream`]
+//! - **Tokio-driven refresh**: [`Live::run_async`], [`Live::start_async`] and their
+//!   `Progress`/`Status` counterparts refresh from a Tokio task instead of a thread
 //! - **Async-aware Live Display**: [`LiveAsync`] for live-updating content in async contexts
 //! - **Progress Channels**: [`ProgressChannel`] for cross-task progress updates
 //! - **Async File Operations**: [`fs`] module for file I/O with progress tracking
@@ -20,8 +23,8 @@
 //! #[tokio::main]
 //! async fn main() {
 //!     // Track async stream
-//!     let stream = tokio::fs::read_dir("./src").await.unwrap();
-//!     let progress_stream = stream.track_progress("Scanning files", None);
+//!     let stream = futures_util::stream::iter(0..100);
+//!     let progress_stream = stream.track_progress("Processing items", None);
 //!     
 //!     // Use the stream
 //! }
@@ -58,8 +61,8 @@ use crate::text::Text;
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     let stream = tokio::fs::read_dir("./src").await.unwrap();
-///     let progress_stream = stream.track_progress("Scanning files", None);
+///     let stream = futures_util::stream::iter(0..100);
+///     let progress_stream = stream.track_progress("Processing items", None);
 ///     
 ///     // Each item yielded advances the progress
 /// }
@@ -77,8 +80,8 @@ pub trait ProgressStreamExt: Stream {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let stream = tokio::fs::read_dir("./src").await.unwrap();
-    ///     let progress_stream = stream.track_progress("Scanning files", Some(100.0));
+    ///     let stream = futures_util::stream::iter(0..100);
+    ///     let progress_stream = stream.track_progress("Processing items", Some(100.0));
     /// }
     /// ```
     fn track_progress(self, description: &str, total: Option<f64>) -> ProgressStream<Self>
@@ -104,7 +107,7 @@ impl<S: Stream> ProgressStreamExt for S {
 /// # Examples
 ///
 /// ```rust,no_run
-/// use futures::stream::{self, StreamExt};
+/// use futures_util::stream::{self, StreamExt};
 /// use gilt::r#async::ProgressStreamExt;
 ///
 /// #[tokio::main]
@@ -203,6 +206,89 @@ impl<S> Drop for ProgressStream<S> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Progress::wrap_stream
+// ---------------------------------------------------------------------------
+
+impl Progress {
+    /// Wrap a stream so each yielded item advances a new task by one.
+    ///
+    /// This is the stream counterpart of [`Progress::track`]: the task is
+    /// added to this `Progress`, which stays borrowed while the stream is
+    /// consumed. Start the display with [`Progress::start_async`] so the
+    /// refresh loop runs on the Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use gilt::progress::Progress;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut progress = Progress::new(Progress::default_columns());
+    ///     progress.start_async();
+    ///     let items = futures_util::stream::iter(0..100);
+    ///     let total: i32 = progress
+    ///         .wrap_stream(items, "Summing", Some(100.0))
+    ///         .fold(0, |acc, n| async move { acc + n })
+    ///         .await;
+    ///     progress.stop();
+    ///     assert_eq!(total, 4950);
+    /// }
+    /// ```
+    pub fn wrap_stream<S: Stream>(
+        &mut self,
+        stream: S,
+        description: &str,
+        total: Option<f64>,
+    ) -> ProgressStreamTracker<'_, S> {
+        let task_id = self.add_task(description, total);
+        ProgressStreamTracker {
+            inner: stream,
+            progress: self,
+            task_id,
+        }
+    }
+}
+
+/// A stream wrapper that advances a task within a borrowed [`Progress`]
+/// on each yielded item.
+///
+/// Created by [`Progress::wrap_stream`].
+pub struct ProgressStreamTracker<'a, S> {
+    inner: S,
+    progress: &'a mut Progress,
+    task_id: TaskId,
+}
+
+impl<S> ProgressStreamTracker<'_, S> {
+    /// Return the task ID associated with this tracker.
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+}
+
+impl<S: Stream + Unpin> Stream for ProgressStreamTracker<'_, S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                this.progress.advance(this.task_id, 1.0);
+                this.progress.refresh();
+                Poll::Ready(Some(item))
+            }
+            other => other,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // LiveAsync
 // ---------------------------------------------------------------------------
@@ -822,7 +908,7 @@ pub mod fs {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use futures::stream::{self, StreamExt};
+    use futures_util::stream::{self, StreamExt};
 
     // Helper to create a test console
     fn test_console() -> crate::console::Console {
@@ -843,7 +929,7 @@ mod tests {
         let mut progress_stream = stream.track_progress("Testing", Some(5.0));
 
         let mut count = 0;
-        while let Some(_) = progress_stream.next().await {
+        while progress_stream.next().await.is_some() {
             count += 1;
         }
 
@@ -908,6 +994,66 @@ mod tests {
         let _ = tokio::join!(worker1, worker2, progress_handle);
     }
 
+    #[tokio::test]
+    async fn test_progress_wrap_stream_advances_task() {
+        let mut progress = Progress::new(Progress::default_columns()).with_disable(true);
+        let mut tracker = progress.wrap_stream(stream::iter(0..5), "Items", None);
+        let task_id = tracker.task_id();
+        let mut collected = Vec::new();
+        while let Some(n) = tracker.next().await {
+            collected.push(n);
+        }
+        assert_eq!(collected, vec![0, 1, 2, 3, 4]);
+        assert_eq!(progress.get_task(task_id).unwrap().completed, 5.0);
+    }
+
+    #[tokio::test]
+    async fn test_live_run_async_returns_output() {
+        let mut live = Live::new(Text::from("start"))
+            .with_console(test_console())
+            .with_refresh_per_second(100.0);
+        let output = live
+            .run_async(|handle| async move {
+                tokio::time::sleep(Duration::from_millis(30)).await;
+                handle.update(Text::from("done"));
+                7
+            })
+            .await;
+        assert_eq!(output, 7);
+        assert!(!live.is_started());
+        assert_eq!(live.renderable().plain(), "done");
+    }
+
+    #[tokio::test]
+    async fn test_live_start_async_refreshes_from_task() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = Arc::clone(&counter);
+        let mut live = Live::new(Text::empty())
+            .with_console(test_console())
+            .with_refresh_per_second(100.0)
+            .with_get_renderable(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                Text::from("tick")
+            });
+        live.start_async();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        live.stop();
+        let after_stop = counter.load(Ordering::SeqCst);
+        assert!(after_stop >= 2, "expected refreshes, got {}", after_stop);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), after_stop);
+    }
+
+    #[tokio::test]
+    async fn test_status_run_async() {
+        let mut status = crate::status::Status::new("Working").with_console(test_console());
+        let value = status.run_async(async { "finished" }).await;
+        assert_eq!(value, "finished");
+        assert!(!status.is_started());
+    }
+
     #[tokio::test]
     async fn test_live_async_lifecycle() {
         let mut live = LiveAsync::new(Text::new("Test", crate::style::Style::null()));
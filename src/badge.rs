@@ -1,11 +1,19 @@
 //! Badge/Tag widget for displaying status indicators.
 //!
-//! Compact visual badges like GitHub badges for modern CLIs.
+//! Compact visual badges like GitHub badges for modern CLIs. Badges render
+//! either as a boxed three-line tag or, in compact mode, as a single-line
+//! inverted chip such as ` ✓ PASS `. The semantic presets resolve their
+//! colors from the console theme (`badge.success`, `badge.error`,
+//! `badge.warning`, `badge.info`, `badge.neutral`), so a custom [`Theme`]
+//! can restyle every badge at once. [`Badges`] lays several badges out in a
+//! row, wrapping to the available width.
+//!
+//! [`Theme`]: crate::theme::Theme
 //!
 //! # Examples
 //!
 //! ```
-//! use gilt::badge::{Badge, BadgeStyle};
+//! use gilt::badge::{Badge, BadgeStyle, Badges};
 //!
 //! // Create a success badge
 //! let badge = Badge::success("Success");
@@ -15,6 +23,12 @@
 //!     .style(BadgeStyle::Info)
 //!     .icon("ℹ")
 //!     .rounded(true);
+//!
+//! // A row of compact chips
+//! let row = Badges::new(vec![
+//!     Badge::success("PASS").compact(true),
+//!     Badge::error("FAIL").compact(true),
+//! ]);
 //! ```
 
 use crate::console::{Console, ConsoleOptions, Renderable};
@@ -50,7 +64,7 @@ impl BadgeStyle {
             BadgeStyle::Error => Style::parse("on red").unwrap_or_else(|_| Style::null()),
             BadgeStyle::Warning => Style::parse("on yellow").unwrap_or_else(|_| Style::null()),
             BadgeStyle::Info => Style::parse("on blue").unwrap_or_else(|_| Style::null()),
-            BadgeStyle::Neutral => Style::parse("on grey").unwrap_or_else(|_| Style::null()),
+            BadgeStyle::Neutral => Style::parse("on grey50").unwrap_or_else(|_| Style::null()),
             BadgeStyle::Custom(style) => {
                 // Extract just the background style from the custom style
                 style.background_style()
@@ -73,6 +87,30 @@ impl BadgeStyle {
         }
     }
 
+    /// Get the theme style name for this badge style.
+    ///
+    /// Returns `None` for [`BadgeStyle::Custom`], which always uses its own style.
+    pub fn theme_key(&self) -> Option<&'static str> {
+        match self {
+            BadgeStyle::Success => Some("badge.success"),
+            BadgeStyle::Error => Some("badge.error"),
+            BadgeStyle::Warning => Some("badge.warning"),
+            BadgeStyle::Info => Some("badge.info"),
+            BadgeStyle::Neutral => Some("badge.neutral"),
+            BadgeStyle::Custom(_) => None,
+        }
+    }
+
+    /// Resolve the combined (foreground + background) chip style.
+    ///
+    /// Presets look up their theme style on the console, falling back to the
+    /// built-in colors when the theme does not define it.
+    fn resolve(&self, console: &Console) -> Style {
+        self.theme_key()
+            .and_then(|key| console.get_style(key).ok())
+            .unwrap_or_else(|| self.fg_style() + self.bg_style())
+    }
+
     /// Get the default icon for this badge style.
     fn default_icon(&self) -> Option<&'static str> {
         match self {
@@ -118,6 +156,7 @@ pub struct Badge {
     style: BadgeStyle,
     icon: Option<String>,
     rounded: bool,
+    compact: bool,
 }
 
 impl Badge {
@@ -138,6 +177,7 @@ impl Badge {
             style: BadgeStyle::Neutral,
             icon: None,
             rounded: false,
+            compact: false,
         }
     }
 
@@ -243,6 +283,25 @@ impl Badge {
         self
     }
 
+    /// Set whether to render as a single-line chip instead of a boxed tag.
+    ///
+    /// A compact badge is the padded content drawn in the badge's inverted
+    /// colors, e.g. ` ✓ PASS `.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::badge::Badge;
+    ///
+    /// let chip = Badge::success("PASS").compact(true);
+    /// assert_eq!(chip.to_string(), " ✓ PASS ");
+    /// ```
+    #[must_use]
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
     /// Get the text content of this badge.
     pub fn text(&self) -> &str {
         &self.text
@@ -263,11 +322,65 @@ impl Badge {
         self.rounded
     }
 
+    /// Check if this badge renders as a single-line chip.
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    /// Width of the rendered badge in terminal cells.
+    pub fn cell_width(&self) -> usize {
+        let inner = crate::cells::cell_len(&self.content()) + 2;
+        if self.compact {
+            inner
+        } else {
+            inner + 2
+        }
+    }
+
+    /// Number of lines the rendered badge occupies.
+    pub fn height(&self) -> usize {
+        if self.compact {
+            1
+        } else {
+            3
+        }
+    }
+
     /// Get the effective icon to display (explicit or default for style).
     fn effective_icon(&self) -> Option<&str> {
         self.icon.as_deref().or_else(|| self.style.default_icon())
     }
 
+    /// The icon and text shown inside the badge.
+    fn content(&self) -> String {
+        match self.effective_icon() {
+            Some(icon) if !icon.is_empty() => format!("{} {}", icon, self.text),
+            _ => self.text.clone(),
+        }
+    }
+
+    /// Render the badge as lines of segments, without line breaks.
+    fn render_lines(&self, console: &Console) -> Vec<Vec<Segment>> {
+        let style = self.style.resolve(console);
+        let padded = format!(" {} ", self.content());
+        if self.compact {
+            return vec![vec![Segment::styled(&padded, style)]];
+        }
+
+        let (tl, tr, bl, br, horiz, vert) = self.box_chars();
+        let border_style = style.background_style();
+        let rule = horiz.to_string().repeat(crate::cells::cell_len(&padded));
+
+        vec![
+            vec![Segment::styled(
+                &format!("{tl}{rule}{tr}"),
+                border_style.clone(),
+            )],
+            vec![Segment::styled(&format!("{vert}{padded}{vert}"), style)],
+            vec![Segment::styled(&format!("{bl}{rule}{br}"), border_style)],
+        ]
+    }
+
     /// Get the box characters based on rounded setting.
     fn box_chars(&self) -> (char, char, char, char, char, char) {
         if self.rounded {
@@ -281,61 +394,177 @@ impl Badge {
 }
 
 impl Renderable for Badge {
-    fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
+    fn gilt_console(&self, console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
         let mut segments = Vec::new();
+        for line in self.render_lines(console) {
+            segments.extend(line);
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
 
-        let (tl, tr, bl, br, horiz, vert) = self.box_chars();
-        let bg_style = self.style.bg_style();
-        let fg_style = self.style.fg_style();
-
-        // Build the content (icon + text)
-        let content = match self.effective_icon() {
-            Some(icon) => format!("{} {}", icon, self.text),
-            None => self.text.clone(),
-        };
-
-        let content_width = crate::cells::cell_len(&content);
-        let inner_width = content_width + 2; // +2 for padding spaces
-
-        // Build top border
-        let mut top = String::new();
-        top.push(tl);
-        for _ in 0..inner_width {
-            top.push(horiz);
+impl std::fmt::Display for Badge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Badges
+// -----------------------------------------------------------------------------
+
+/// A row of badges laid out left to right, wrapping to the available width.
+///
+/// Badges in the same row are aligned on their content line, so compact chips
+/// sit level with the text of boxed badges.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::badge::{Badge, Badges};
+///
+/// let row = Badges::new(vec![
+///     Badge::success("PASS").compact(true),
+///     Badge::error("FAIL").compact(true),
+///     Badge::new("SKIP").compact(true),
+/// ]);
+/// assert_eq!(row.to_string(), " ✓ PASS   ✗ FAIL   SKIP ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badges {
+    badges: Vec<Badge>,
+    gap: usize,
+}
+
+impl Badges {
+    /// Create a row from the given badges, separated by a single space.
+    pub fn new(badges: Vec<Badge>) -> Self {
+        Badges { badges, gap: 1 }
+    }
+
+    /// Append a badge to the row.
+    pub fn add(&mut self, badge: Badge) {
+        self.badges.push(badge);
+    }
+
+    /// Builder: append a badge to the row.
+    #[must_use]
+    pub fn with_badge(mut self, badge: Badge) -> Self {
+        self.badges.push(badge);
+        self
+    }
+
+    /// Set the number of blank cells between adjacent badges.
+    #[must_use]
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// The badges in this row.
+    pub fn badges(&self) -> &[Badge] {
+        &self.badges
+    }
+
+    /// Number of badges in the row.
+    pub fn len(&self) -> usize {
+        self.badges.len()
+    }
+
+    /// Whether the row contains no badges.
+    pub fn is_empty(&self) -> bool {
+        self.badges.is_empty()
+    }
+
+    /// Split the badges into rows that fit within `width` cells.
+    fn wrap(&self, width: usize) -> Vec<&[Badge]> {
+        let mut rows = Vec::new();
+        let mut start = 0;
+        let mut used = 0;
+        for (i, badge) in self.badges.iter().enumerate() {
+            let w = badge.cell_width();
+            if i > start && used + self.gap + w > width {
+                rows.push(&self.badges[start..i]);
+                start = i;
+                used = w;
+            } else if i > start {
+                used += self.gap + w;
+            } else {
+                used = w;
+            }
         }
-        top.push(tr);
-        segments.push(Segment::styled(&top, bg_style.clone()));
-        segments.push(Segment::line());
-
-        // Build middle row with content
-        let mut middle = String::new();
-        middle.push(vert);
-        middle.push(' ');
-        middle.push_str(&content);
-        middle.push(' ');
-        middle.push(vert);
-
-        // The middle row needs both background and foreground styling
-        // We create a combined style
-        let combined_style = fg_style + bg_style.clone();
-        segments.push(Segment::styled(&middle, combined_style));
-        segments.push(Segment::line());
-
-        // Build bottom border
-        let mut bottom = String::new();
-        bottom.push(bl);
-        for _ in 0..inner_width {
-            bottom.push(horiz);
+        if start < self.badges.len() {
+            rows.push(&self.badges[start..]);
         }
-        bottom.push(br);
-        segments.push(Segment::styled(&bottom, bg_style));
-        segments.push(Segment::line());
+        rows
+    }
+}
+
+impl Default for Badges {
+    fn default() -> Self {
+        Badges::new(Vec::new())
+    }
+}
+
+impl From<Vec<Badge>> for Badges {
+    fn from(badges: Vec<Badge>) -> Self {
+        Badges::new(badges)
+    }
+}
+
+impl FromIterator<Badge> for Badges {
+    fn from_iter<I: IntoIterator<Item = Badge>>(iter: I) -> Self {
+        Badges::new(iter.into_iter().collect())
+    }
+}
 
+impl Renderable for Badges {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let gap = " ".repeat(self.gap);
+
+        for row in self.wrap(options.max_width) {
+            let height = row.iter().map(Badge::height).max().unwrap_or(0);
+            let rendered: Vec<Vec<Vec<Segment>>> =
+                row.iter().map(|b| b.render_lines(console)).collect();
+
+            for y in 0..height {
+                let mut line: Vec<Segment> = Vec::new();
+                for (i, (badge, lines)) in row.iter().zip(&rendered).enumerate() {
+                    if i > 0 && !gap.is_empty() {
+                        line.push(Segment::text(&gap));
+                    }
+                    let top = (height - badge.height()) / 2;
+                    match y.checked_sub(top).and_then(|k| lines.get(k)) {
+                        Some(cells) => line.extend(cells.iter().cloned()),
+                        None => line.push(Segment::text(&" ".repeat(badge.cell_width()))),
+                    }
+                }
+                // Drop trailing padding from badges that do not reach this line
+                while line
+                    .last()
+                    .is_some_and(|s| s.style.is_none() && s.text.trim().is_empty())
+                {
+                    line.pop();
+                }
+                segments.extend(line);
+                segments.push(Segment::line());
+            }
+        }
         segments
     }
 }
 
-impl std::fmt::Display for Badge {
+impl std::fmt::Display for Badges {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut console = Console::builder()
             .width(f.width().unwrap_or(80))
@@ -613,4 +842,117 @@ mod tests {
         assert_eq!(badge.icon_str(), cloned.icon_str());
         assert_eq!(badge.is_rounded(), cloned.is_rounded());
     }
+
+    // -- Theme ----------------------------------------------------------------
+
+    #[test]
+    fn test_theme_keys() {
+        assert_eq!(BadgeStyle::Success.theme_key(), Some("badge.success"));
+        assert_eq!(BadgeStyle::Error.theme_key(), Some("badge.error"));
+        assert_eq!(BadgeStyle::Warning.theme_key(), Some("badge.warning"));
+        assert_eq!(BadgeStyle::Info.theme_key(), Some("badge.info"));
+        assert_eq!(BadgeStyle::Neutral.theme_key(), Some("badge.neutral"));
+        assert_eq!(BadgeStyle::Custom(Style::null()).theme_key(), None);
+    }
+
+    #[test]
+    fn test_preset_uses_default_theme() {
+        let console = make_console(80);
+        let opts = console.options();
+        let segments = Badge::error("FAIL")
+            .compact(true)
+            .gilt_console(&console, &opts);
+        let expected = Style::parse("bold white on red").unwrap();
+        assert_eq!(segments[0].style.as_ref(), Some(&expected));
+    }
+
+    #[test]
+    fn test_preset_theme_override() {
+        use crate::theme::Theme;
+        use std::collections::HashMap;
+
+        let mut console = make_console(80);
+        let mut styles = HashMap::new();
+        styles.insert(
+            "badge.success".to_string(),
+            Style::parse("black on magenta").unwrap(),
+        );
+        console.push_theme(Theme::new(Some(styles), true));
+
+        let opts = console.options();
+        let segments = Badge::success("OK").gilt_console(&console, &opts);
+        // Border rows carry only the background, the content row the full style
+        assert_eq!(
+            segments[0].style.as_ref(),
+            Some(&Style::parse("on magenta").unwrap())
+        );
+        assert_eq!(
+            segments[2].style.as_ref(),
+            Some(&Style::parse("black on magenta").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_custom_ignores_theme() {
+        let console = make_console(80);
+        let opts = console.options();
+        let custom = Style::parse("red on white").unwrap();
+        let segments = Badge::new("X")
+            .style(BadgeStyle::Custom(custom.clone()))
+            .compact(true)
+            .gilt_console(&console, &opts);
+        assert_eq!(segments[0].style.as_ref(), Some(&custom));
+    }
+
+    // -- Compact --------------------------------------------------------------
+
+    #[test]
+    fn test_compact_render() {
+        let console = make_console(80);
+        let badge = Badge::warning("SLOW").compact(true);
+        assert_eq!(render_badge(&console, &badge), " ⚠ SLOW \n");
+        assert_eq!(badge.height(), 1);
+        assert_eq!(badge.cell_width(), 8);
+    }
+
+    #[test]
+    fn test_boxed_cell_width() {
+        let badge = Badge::new("OK");
+        assert_eq!(badge.cell_width(), 6);
+        assert_eq!(badge.height(), 3);
+    }
+
+    // -- Badges ---------------------------------------------------------------
+
+    #[test]
+    fn test_badges_row() {
+        let row: Badges = vec![Badge::new("A").compact(true), Badge::new("B").compact(true)]
+            .into_iter()
+            .collect();
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.to_string(), " A   B ");
+        assert_eq!(row.gap(3).to_string(), " A     B ");
+    }
+
+    #[test]
+    fn test_badges_wrap() {
+        let mut row = Badges::default();
+        assert!(row.is_empty());
+        for label in ["one", "two", "three"] {
+            row.add(Badge::new(label).compact(true));
+        }
+        // " one " + " " + " two " = 11 cells; " three " does not fit in 16
+        assert_eq!(format!("{:16}", row), " one   two \n three ");
+    }
+
+    #[test]
+    fn test_badges_mixed_heights_align_on_content() {
+        let row = Badges::new(vec![Badge::new("OK")]).with_badge(Badge::new("X").compact(true));
+        let out = row.to_string();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "┌────┐");
+        assert_eq!(lines[1], "│ OK │  X ");
+        assert_eq!(lines[2], "└────┘");
+    }
 }
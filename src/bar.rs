@@ -226,6 +226,7 @@ mod tests {
             highlight: None,
             markup: None,
             height: None,
+            format: Default::default(),
         }
     }
 
@@ -0,0 +1,475 @@
+//! BarChart -- labeled horizontal bars for "top N" style displays.
+//!
+//! Each row shows a label, a [`Bar`] scaled against the largest value (or a
+//! fixed maximum), and optionally the value itself. Labels longer than the
+//! label column are truncated with an ellipsis so the bars always line up.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::barchart::{BarChart, BarSort};
+//!
+//! let chart = BarChart::new()
+//!     .with_bar("rust", 42.0)
+//!     .with_bar("python", 30.0)
+//!     .with_bar("go", 12.0)
+//!     .with_sort(BarSort::Descending)
+//!     .with_width(40);
+//! let output = format!("{}", chart);
+//! assert!(output.lines().next().unwrap().starts_with("rust"));
+//! assert!(output.lines().next().unwrap().ends_with("42"));
+//! ```
+
+use std::fmt;
+
+use crate::bar::Bar;
+use crate::cells::{cell_len, set_cell_size};
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::measure::Measurement;
+use crate::segment::Segment;
+use crate::style::Style;
+
+// ---------------------------------------------------------------------------
+// BarChartItem
+// ---------------------------------------------------------------------------
+
+/// One labeled bar in a [`BarChart`].
+#[derive(Debug, Clone)]
+pub struct BarChartItem {
+    /// Text shown before the bar.
+    pub label: String,
+    /// The bar's value; negative values draw an empty bar.
+    pub value: f64,
+    /// Style of this bar, or `None` for the chart's bar style.
+    pub style: Option<Style>,
+}
+
+impl BarChartItem {
+    /// Create an item with the chart's default bar style.
+    pub fn new(label: &str, value: f64) -> Self {
+        BarChartItem {
+            label: label.to_string(),
+            value,
+            style: None,
+        }
+    }
+
+    /// Set the bar style (builder pattern).
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// The order bars are shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarSort {
+    /// The order the bars were added.
+    #[default]
+    None,
+    /// Smallest value first.
+    Ascending,
+    /// Largest value first.
+    Descending,
+    /// Alphabetically by label.
+    Label,
+}
+
+// ---------------------------------------------------------------------------
+// BarChart
+// ---------------------------------------------------------------------------
+
+/// A chart of labeled horizontal bars.
+#[derive(Debug, Clone)]
+pub struct BarChart {
+    /// The bars, in insertion order.
+    pub items: Vec<BarChartItem>,
+    /// Value of a full-width bar, or `None` for the largest value.
+    pub max: Option<f64>,
+    /// Total width in cells, or `None` to fill the available width.
+    pub width: Option<usize>,
+    /// Widest the label column may be, or `None` for a third of the width.
+    pub max_label_width: Option<usize>,
+    /// Show only the first `limit` bars after sorting.
+    pub limit: Option<usize>,
+    /// The order bars are shown in.
+    pub sort: BarSort,
+    /// Style of bars without a style of their own.
+    pub bar_style: Style,
+    /// Style of the labels.
+    pub label_style: Style,
+    /// Style of the value annotations.
+    pub value_style: Style,
+    /// Whether to show each bar's value after it.
+    pub show_values: bool,
+    /// Decimal places for values, or `None` to show whole numbers without
+    /// decimals and others with two.
+    pub precision: Option<usize>,
+}
+
+impl Default for BarChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BarChart {
+    /// Create an empty bar chart.
+    pub fn new() -> Self {
+        BarChart {
+            items: Vec::new(),
+            max: None,
+            width: None,
+            max_label_width: None,
+            limit: None,
+            sort: BarSort::default(),
+            bar_style: Style::null(),
+            label_style: Style::null(),
+            value_style: Style::null(),
+            show_values: true,
+            precision: None,
+        }
+    }
+
+    /// Add a bar.
+    pub fn add_bar(&mut self, label: &str, value: f64) {
+        self.items.push(BarChartItem::new(label, value));
+    }
+
+    /// Add an item, which may carry its own style.
+    pub fn add_item(&mut self, item: BarChartItem) {
+        self.items.push(item);
+    }
+
+    /// Add a bar (builder pattern).
+    #[must_use]
+    pub fn with_bar(mut self, label: &str, value: f64) -> Self {
+        self.add_bar(label, value);
+        self
+    }
+
+    /// Add an item (builder pattern).
+    #[must_use]
+    pub fn with_item(mut self, item: BarChartItem) -> Self {
+        self.add_item(item);
+        self
+    }
+
+    /// Set the value of a full-width bar.
+    #[must_use]
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the total width in cells.
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the widest the label column may be.
+    #[must_use]
+    pub fn with_max_label_width(mut self, width: usize) -> Self {
+        self.max_label_width = Some(width);
+        self
+    }
+
+    /// Show only the first `limit` bars after sorting.
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the order bars are shown in.
+    #[must_use]
+    pub fn with_sort(mut self, sort: BarSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Set the default bar style.
+    #[must_use]
+    pub fn with_bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Set the label style.
+    #[must_use]
+    pub fn with_label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Set the value style.
+    #[must_use]
+    pub fn with_value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// Show or hide the value after each bar.
+    #[must_use]
+    pub fn with_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Set the number of decimal places shown for values.
+    #[must_use]
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// The items to show, sorted and limited.
+    pub fn rows(&self) -> Vec<&BarChartItem> {
+        let mut rows: Vec<&BarChartItem> = self.items.iter().collect();
+        match self.sort {
+            BarSort::None => {}
+            BarSort::Ascending => rows.sort_by(|a, b| a.value.total_cmp(&b.value)),
+            BarSort::Descending => rows.sort_by(|a, b| b.value.total_cmp(&a.value)),
+            BarSort::Label => rows.sort_by(|a, b| a.label.cmp(&b.label)),
+        }
+        if let Some(limit) = self.limit {
+            rows.truncate(limit);
+        }
+        rows
+    }
+
+    /// Format a value for its annotation.
+    fn format_value(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None if value.fract() == 0.0 => format!("{}", value),
+            None => format!("{:.2}", value),
+        }
+    }
+
+    /// Build the chart lines at `width` cells.
+    fn render_lines(&self, console: &Console, options: &ConsoleOptions) -> Vec<Vec<Segment>> {
+        let width = self
+            .width
+            .unwrap_or(options.max_width)
+            .min(options.max_width);
+        let rows = self.rows();
+        let values: Vec<String> = rows
+            .iter()
+            .map(|item| self.format_value(item.value))
+            .collect();
+
+        let value_width = if self.show_values {
+            values.iter().map(|v| cell_len(v)).max().unwrap_or(0)
+        } else {
+            0
+        };
+        let label_limit = self.max_label_width.unwrap_or(width / 3);
+        let label_width = rows
+            .iter()
+            .map(|item| cell_len(&item.label))
+            .max()
+            .unwrap_or(0)
+            .min(label_limit);
+        let gaps = usize::from(label_width > 0) + usize::from(value_width > 0);
+        let bar_width = width
+            .saturating_sub(label_width + value_width + gaps)
+            .max(1);
+
+        let max = self
+            .max
+            .unwrap_or_else(|| rows.iter().map(|item| item.value).fold(0.0, f64::max));
+
+        let mut lines = Vec::with_capacity(rows.len());
+        for (item, value) in rows.iter().zip(&values) {
+            let mut line = Vec::new();
+            if label_width > 0 {
+                line.push(Segment::styled(
+                    &format!("{} ", truncate(&item.label, label_width)),
+                    self.label_style.clone(),
+                ));
+            }
+
+            let style = item.style.clone().unwrap_or_else(|| self.bar_style.clone());
+            let end = if max > 0.0 {
+                item.value.clamp(0.0, max)
+            } else {
+                0.0
+            };
+            let bar = Bar::new(max.max(f64::MIN_POSITIVE), 0.0, end)
+                .with_width(bar_width)
+                .with_style(style);
+            line.extend(
+                bar.gilt_console(console, &options.update_width(bar_width))
+                    .into_iter()
+                    .filter(|segment| segment.text != "\n"),
+            );
+
+            if value_width > 0 {
+                line.push(Segment::styled(
+                    &format!(" {:>width$}", value, width = value_width),
+                    self.value_style.clone(),
+                ));
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Return the measurement for this chart.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let width = self
+            .width
+            .unwrap_or(options.max_width)
+            .min(options.max_width);
+        Measurement::new(width.min(4), width)
+    }
+}
+
+/// Fit `label` into `width` cells, ending in an ellipsis if it was cut.
+fn truncate(label: &str, width: usize) -> String {
+    if cell_len(label) <= width {
+        set_cell_size(label, width).into_owned()
+    } else if width == 0 {
+        String::new()
+    } else {
+        format!("{}…", set_cell_size(label, width - 1))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Display
+// ---------------------------------------------------------------------------
+
+impl fmt::Display for BarChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Renderable
+// ---------------------------------------------------------------------------
+
+impl Renderable for BarChart {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for line in self.render_lines(console, options) {
+            segments.extend(line);
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart() -> BarChart {
+        BarChart::new()
+            .with_bar("beta", 5.0)
+            .with_bar("alpha", 10.0)
+            .with_bar("gamma", 2.5)
+            .with_width(30)
+    }
+
+    fn labels(chart: &BarChart) -> Vec<&str> {
+        chart
+            .rows()
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_sorting_and_limit() {
+        assert_eq!(labels(&chart()), vec!["beta", "alpha", "gamma"]);
+        let ascending = chart().with_sort(BarSort::Ascending);
+        assert_eq!(labels(&ascending), vec!["gamma", "beta", "alpha"]);
+        let top = chart().with_sort(BarSort::Descending).with_limit(2);
+        assert_eq!(labels(&top), vec!["alpha", "beta"]);
+        let by_label = chart().with_sort(BarSort::Label);
+        assert_eq!(labels(&by_label), vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_bars_scale_to_largest_value() {
+        let output = chart().to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        // 30 cells: label 5 + gap + bar 19 + gap + value 4.
+        assert!(lines[0].starts_with(&format!("beta  {}▌ ", "█".repeat(9))));
+        assert!(lines[0].ends_with("    5"));
+        assert!(lines[1].starts_with(&format!("alpha {}", "█".repeat(19))));
+        assert!(lines[1].ends_with("  10"));
+        assert!(lines[2].ends_with("2.50"));
+        assert!(lines.iter().all(|line| cell_len(line) <= 30));
+    }
+
+    #[test]
+    fn test_fixed_max_and_negative_values() {
+        let output = BarChart::new()
+            .with_bar("a", 20.0)
+            .with_bar("b", -3.0)
+            .with_max(10.0)
+            .with_values(false)
+            .with_width(12)
+            .to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], format!("a {}", "█".repeat(10)));
+        assert_eq!(lines[1].trim_end(), "b");
+    }
+
+    #[test]
+    fn test_long_labels_are_truncated() {
+        let output = BarChart::new()
+            .with_bar("a very long label", 1.0)
+            .with_bar("short", 1.0)
+            .with_max_label_width(8)
+            .with_width(20)
+            .to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("a very … "));
+        assert!(lines[1].starts_with("short    "));
+        assert_eq!(truncate("abc", 0), "");
+    }
+
+    #[test]
+    fn test_item_styles_and_precision() {
+        let red = Style::parse("red").unwrap();
+        let chart = BarChart::new()
+            .with_item(BarChartItem::new("x", 1.0).with_style(red.clone()))
+            .with_bar("y", 0.5)
+            .with_bar_style(Style::parse("blue").unwrap())
+            .with_precision(1)
+            .with_width(20);
+        let console = Console::builder().width(20).build();
+        let lines = chart.render_lines(&console, &console.options());
+        assert_eq!(lines[0][1].style, Some(red));
+        assert_eq!(lines[1][1].style, Some(Style::parse("blue").unwrap()));
+        assert_eq!(lines[0][2].text, " 1.0");
+        assert_eq!(lines[1][2].text, " 0.5");
+    }
+
+    #[test]
+    fn test_measure() {
+        let console = Console::builder().width(50).build();
+        let options = console.options();
+        assert_eq!(chart().measure(&console, &options), Measurement::new(4, 30));
+    }
+}
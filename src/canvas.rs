@@ -4,6 +4,10 @@
 //! where each terminal character cell contains a 2x4 grid of dots, giving
 //! 2x horizontal and 4x vertical sub-character resolution.
 //!
+//! Dots can be colored with a pen style; each cell is drawn in the style of
+//! most of its dots. Where Braille glyphs render poorly, [`CanvasMode::Quadrant`]
+//! draws the same canvas with quadrant block characters instead.
+//!
 //! # Example
 //!
 //! ```
@@ -17,6 +21,7 @@
 //! assert!(!c.get(1, 1));
 //! ```
 
+use std::collections::HashMap;
 use std::fmt;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
@@ -43,6 +48,44 @@ const PIXEL_MAP: [[u8; 2]; 4] = [
 /// The Unicode code point for the empty braille pattern (no dots).
 const BRAILLE_BASE: u32 = 0x2800;
 
+/// Quadrant block characters indexed by filled quarters: top-left = 1,
+/// top-right = 2, bottom-left = 4, bottom-right = 8.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// The quadrant block character for braille dot bits: each quarter is
+/// filled if either of the two dots it covers is set.
+fn quadrant(bits: u8) -> char {
+    let quarter = |mask: u8, value: usize| if bits & mask != 0 { value } else { 0 };
+    QUADRANTS[quarter(0x01 | 0x02, 1)
+        | quarter(0x08 | 0x10, 2)
+        | quarter(0x04 | 0x40, 4)
+        | quarter(0x20 | 0x80, 8)]
+}
+
+/// The braille character with the given dot bits.
+pub(crate) fn braille(bits: u8) -> char {
+    // BRAILLE_BASE + bits is always a valid code point in U+2800..U+28FF.
+    char::from_u32(BRAILLE_BASE + bits as u32).unwrap_or(' ')
+}
+
+// ---------------------------------------------------------------------------
+// CanvasMode
+// ---------------------------------------------------------------------------
+
+/// The characters a [`Canvas`] is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CanvasMode {
+    /// Braille patterns: every dot is visible (2x4 per cell).
+    #[default]
+    Braille,
+    /// Quadrant block characters (2x2 per cell) for terminals or fonts with
+    /// poor Braille support. Each quarter is filled if either of the two
+    /// dots it covers is set.
+    Quadrant,
+}
+
 // ---------------------------------------------------------------------------
 // Canvas
 // ---------------------------------------------------------------------------
@@ -62,6 +105,12 @@ pub struct Canvas {
     pixels: Vec<Vec<u8>>,
     /// Visual style applied to the rendered braille text.
     style: Style,
+    /// Style of individual dots, keyed by pixel coordinates.
+    dot_styles: HashMap<(usize, usize), Style>,
+    /// Style given to dots drawn from now on, if any.
+    pen: Option<Style>,
+    /// Characters used to draw the cells.
+    mode: CanvasMode,
 }
 
 impl Canvas {
@@ -72,6 +121,9 @@ impl Canvas {
             height,
             pixels: vec![vec![0u8; width]; height],
             style: Style::null(),
+            dot_styles: HashMap::new(),
+            pen: None,
+            mode: CanvasMode::default(),
         }
     }
 
@@ -82,6 +134,37 @@ impl Canvas {
         self
     }
 
+    /// Set the drawing mode (builder pattern).
+    #[must_use]
+    pub fn with_mode(mut self, mode: CanvasMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the style given to dots drawn after this call, or `None` to draw
+    /// unstyled dots.
+    ///
+    /// A cell takes the style shared by most of its set dots, on top of the
+    /// canvas style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gilt::canvas::Canvas;
+    /// use gilt::style::Style;
+    ///
+    /// let mut c = Canvas::new(4, 1);
+    /// c.set_pen(Some(Style::parse("red").unwrap()));
+    /// c.line(0, 0, 3, 0);
+    /// c.set_pen(Some(Style::parse("blue").unwrap()));
+    /// c.line(4, 0, 7, 0);
+    /// assert_eq!(c.cell_style(0, 0), Some(Style::parse("red").unwrap()));
+    /// assert_eq!(c.cell_style(3, 0), Some(Style::parse("blue").unwrap()));
+    /// ```
+    pub fn set_pen(&mut self, style: Option<Style>) {
+        self.pen = style;
+    }
+
     /// Pixel width (horizontal resolution = terminal columns * 2).
     pub fn pixel_width(&self) -> usize {
         self.width * 2
@@ -105,6 +188,7 @@ impl Canvas {
         let row = y / 4;
         let bit = PIXEL_MAP[y % 4][x % 2];
         self.pixels[row][col] |= bit;
+        self.paint(x, y);
     }
 
     /// Clear a pixel at `(x, y)` in pixel coordinates.
@@ -118,6 +202,7 @@ impl Canvas {
         let row = y / 4;
         let bit = PIXEL_MAP[y % 4][x % 2];
         self.pixels[row][col] &= !bit;
+        self.dot_styles.remove(&(x, y));
     }
 
     /// Toggle a pixel at `(x, y)` in pixel coordinates.
@@ -131,6 +216,23 @@ impl Canvas {
         let row = y / 4;
         let bit = PIXEL_MAP[y % 4][x % 2];
         self.pixels[row][col] ^= bit;
+        if self.pixels[row][col] & bit != 0 {
+            self.paint(x, y);
+        } else {
+            self.dot_styles.remove(&(x, y));
+        }
+    }
+
+    /// Record the pen style, if any, for a newly set dot.
+    fn paint(&mut self, x: usize, y: usize) {
+        match &self.pen {
+            Some(style) => {
+                self.dot_styles.insert((x, y), style.clone());
+            }
+            None => {
+                self.dot_styles.remove(&(x, y));
+            }
+        }
     }
 
     /// Test whether the pixel at `(x, y)` is set.
@@ -242,20 +344,54 @@ impl Canvas {
         }
     }
 
+    /// Dot bits of the character cell at (`col`, `row`), or 0 out of bounds.
+    pub(crate) fn cell(&self, col: usize, row: usize) -> u8 {
+        self.pixels
+            .get(row)
+            .and_then(|cells| cells.get(col))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The style shared by most of the styled dots set in the cell at
+    /// (`col`, `row`), or `None` if none of its dots are styled. Ties go to
+    /// the dot nearest the top-left.
+    pub fn cell_style(&self, col: usize, row: usize) -> Option<Style> {
+        let mut counts: Vec<(&Style, usize)> = Vec::new();
+        for dy in 0..4 {
+            for dx in 0..2 {
+                let Some(style) = self.dot_styles.get(&(col * 2 + dx, row * 4 + dy)) else {
+                    continue;
+                };
+                match counts.iter_mut().find(|(seen, _)| *seen == style) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((style, 1)),
+                }
+            }
+        }
+        let most = counts.iter().map(|(_, count)| *count).max()?;
+        counts
+            .into_iter()
+            .find(|(_, count)| *count == most)
+            .map(|(style, _)| style.clone())
+    }
+
     // -- rendering ----------------------------------------------------------
 
-    /// Render the canvas to a multi-line string of braille characters.
+    /// The character drawn for a cell with the given dot bits.
+    fn glyph(&self, bits: u8) -> char {
+        match self.mode {
+            CanvasMode::Braille => braille(bits),
+            CanvasMode::Quadrant => quadrant(bits),
+        }
+    }
+
+    /// Render the canvas to a multi-line string of braille characters (or
+    /// quadrant blocks in [`CanvasMode::Quadrant`]).
     pub fn frame(&self) -> String {
         let mut lines: Vec<String> = Vec::with_capacity(self.height);
         for row in &self.pixels {
-            let line: String = row
-                .iter()
-                .map(|&bits| {
-                    // Safety: BRAILLE_BASE + bits is always a valid Unicode
-                    // code point in U+2800..U+28FF.
-                    char::from_u32(BRAILLE_BASE + bits as u32).unwrap_or(' ')
-                })
-                .collect();
+            let line: String = row.iter().map(|&bits| self.glyph(bits)).collect();
             lines.push(line);
         }
         lines.join("\n")
@@ -268,6 +404,7 @@ impl Canvas {
                 *cell = 0;
             }
         }
+        self.dot_styles.clear();
     }
 }
 
@@ -289,11 +426,24 @@ impl Renderable for Canvas {
     fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
         let mut segments = Vec::new();
         for (i, row) in self.pixels.iter().enumerate() {
-            let line: String = row
-                .iter()
-                .map(|&bits| char::from_u32(BRAILLE_BASE + bits as u32).unwrap_or(' '))
-                .collect();
-            segments.push(Segment::new(&line, Some(self.style.clone()), None));
+            // Group runs of cells that share a style into one segment.
+            let mut run = String::new();
+            let mut run_style = self.style.clone();
+            for (col, &bits) in row.iter().enumerate() {
+                let style = match self.cell_style(col, i) {
+                    Some(dots) if bits != 0 => self.style.clone() + dots,
+                    _ => self.style.clone(),
+                };
+                if style != run_style && !run.is_empty() {
+                    segments.push(Segment::new(&run, Some(run_style), None));
+                    run.clear();
+                }
+                run_style = style;
+                run.push(self.glyph(bits));
+            }
+            if !run.is_empty() || row.is_empty() {
+                segments.push(Segment::new(&run, Some(run_style), None));
+            }
             if i < self.height - 1 {
                 segments.push(Segment::line());
             }
@@ -341,6 +491,7 @@ mod tests {
             highlight: None,
             markup: None,
             height: None,
+            format: Default::default(),
         }
     }
 
@@ -353,9 +504,9 @@ mod tests {
         let empty_braille = '\u{2800}';
         let expected = format!(
             "{}{}{}",
-            std::iter::repeat(empty_braille).take(3).collect::<String>(),
+            std::iter::repeat_n(empty_braille, 3).collect::<String>(),
             "\n",
-            std::iter::repeat(empty_braille).take(3).collect::<String>(),
+            std::iter::repeat_n(empty_braille, 3).collect::<String>(),
         );
         assert_eq!(frame, expected);
     }
@@ -635,4 +786,57 @@ mod tests {
         let ch = char::from_u32(BRAILLE_BASE + 0xFF).unwrap();
         assert_eq!(c.frame(), ch.to_string());
     }
+
+    // 26. Pen styles: the dominant dot style colors the cell
+    #[test]
+    fn test_cell_style_dominant() {
+        let red = Style::parse("red").unwrap();
+        let blue = Style::parse("blue").unwrap();
+        let mut c = Canvas::new(2, 1);
+        c.set_pen(Some(red.clone()));
+        c.set(0, 0);
+        c.set_pen(Some(blue.clone()));
+        c.set(1, 0);
+        c.set(1, 1);
+        c.set_pen(None);
+        c.set(0, 3);
+        assert_eq!(c.cell_style(0, 0), Some(blue.clone()));
+        assert_eq!(c.cell_style(1, 0), None);
+
+        c.unset(1, 0);
+        c.unset(1, 1);
+        assert_eq!(c.cell_style(0, 0), Some(red));
+        c.clear();
+        assert_eq!(c.cell_style(0, 0), None);
+    }
+
+    // 27. Renderable splits lines into runs of equal style
+    #[test]
+    fn test_renderable_dot_styles() {
+        let base = Style::parse("bold").unwrap();
+        let red = Style::parse("red").unwrap();
+        let mut c = Canvas::new(3, 1).with_style(base.clone());
+        c.set_pen(Some(red.clone()));
+        c.set(2, 0);
+        c.set(4, 0);
+        let console = Console::builder().width(80).build();
+        let segments = c.gilt_console(&console, &make_options(80));
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].text, "\u{2800}");
+        assert_eq!(segments[0].style.as_ref(), Some(&base));
+        assert_eq!(segments[1].text, "\u{2801}\u{2801}");
+        assert_eq!(segments[1].style.as_ref(), Some(&(base + red)));
+    }
+
+    // 28. Quadrant mode collapses dot pairs into block quarters
+    #[test]
+    fn test_quadrant_mode() {
+        let mut c = Canvas::new(3, 1).with_mode(CanvasMode::Quadrant);
+        c.set(0, 1); // top-left quarter
+        c.set(3, 2); // bottom-right quarter of the second cell
+        c.fill_rect(4, 0, 2, 4);
+        assert_eq!(c.frame(), "▘▗█");
+        assert_eq!(quadrant(0), ' ');
+        assert_eq!(quadrant(0x01 | 0x80), '▚');
+    }
 }
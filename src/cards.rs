@@ -0,0 +1,531 @@
+//! Dashboard summary cards.
+//!
+//! [`StatCard`] is a small boxed KPI tile: a prominent value, a caption
+//! underneath, and an optional delta rendered as a colored arrow
+//! (`▲ 12.5%` / `▼ 3.0%`). [`StatRow`] lays several cards out side by side
+//! with equal widths, fitting as many per row as the terminal allows and
+//! wrapping the rest — the familiar "four KPI boxes at the top of a
+//! dashboard" pattern.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::cards::{StatCard, StatRow};
+//!
+//! let row = StatRow::new(vec![
+//!     StatCard::new("1,204", "Requests").with_delta(12.5),
+//!     StatCard::new("87 ms", "Latency").with_delta(-3.0).with_inverted(true),
+//! ]);
+//! let out = format!("{:40}", row);
+//! assert!(out.contains("▲ 12.5%"));
+//! assert!(out.contains("▼ 3.0%"));
+//! ```
+
+use crate::box_chars::{BoxChars, ROUNDED};
+use crate::columns::Columns;
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::kv::layered;
+use crate::measure::Measurement;
+use crate::panel::Panel;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{JustifyMethod, Text};
+
+/// Arrow shown for a positive delta.
+const UP: char = '▲';
+/// Arrow shown for a negative delta.
+const DOWN: char = '▼';
+/// Marker shown for a zero delta.
+const FLAT: char = '•';
+
+// ---------------------------------------------------------------------------
+// StatCard
+// ---------------------------------------------------------------------------
+
+/// A boxed KPI tile with a value, a caption and an optional delta.
+///
+/// The delta is shown as an arrow followed by its magnitude, colored with
+/// [`up_style`](Self::up_style) when it rises and
+/// [`down_style`](Self::down_style) when it falls. Metrics where lower is
+/// better (latency, error counts) can swap the colors with
+/// [`with_inverted`](Self::with_inverted).
+#[derive(Debug, Clone)]
+pub struct StatCard {
+    /// The headline value.
+    pub value: Text,
+    /// The label shown under the value.
+    pub caption: Text,
+    /// Change since the previous period, if any.
+    pub delta: Option<f64>,
+    /// Suffix appended to the delta magnitude.
+    pub delta_unit: String,
+    /// Decimal places used for the delta magnitude.
+    pub delta_precision: usize,
+    /// Treat a decrease as good news (swap the up/down colors).
+    pub inverted: bool,
+    /// Style of the value line.
+    pub value_style: Style,
+    /// Style of the caption line.
+    pub caption_style: Style,
+    /// Style of a favourable delta.
+    pub up_style: Style,
+    /// Style of an unfavourable delta.
+    pub down_style: Style,
+    /// Style of a zero delta.
+    pub flat_style: Style,
+    /// Box characters for the border.
+    pub box_chars: &'static BoxChars,
+    /// Style of the border.
+    pub border_style: Style,
+    /// Fixed width of the card, including the border.
+    pub width: Option<usize>,
+}
+
+impl StatCard {
+    /// Create a card showing `value` above `caption`.
+    pub fn new(value: impl Into<Text>, caption: impl Into<Text>) -> Self {
+        StatCard {
+            value: value.into(),
+            caption: caption.into(),
+            delta: None,
+            delta_unit: "%".to_string(),
+            delta_precision: 1,
+            inverted: false,
+            value_style: Style::parse("bold").unwrap_or_else(|_| Style::null()),
+            caption_style: Style::parse("dim").unwrap_or_else(|_| Style::null()),
+            up_style: Style::parse("green").unwrap_or_else(|_| Style::null()),
+            down_style: Style::parse("red").unwrap_or_else(|_| Style::null()),
+            flat_style: Style::parse("dim").unwrap_or_else(|_| Style::null()),
+            box_chars: &ROUNDED,
+            border_style: Style::null(),
+            width: None,
+        }
+    }
+
+    /// Set the delta shown under the caption.
+    #[must_use]
+    pub fn with_delta(mut self, delta: f64) -> Self {
+        self.delta = Some(delta);
+        self
+    }
+
+    /// Set the suffix appended to the delta (default `"%"`).
+    #[must_use]
+    pub fn with_delta_unit(mut self, unit: &str) -> Self {
+        self.delta_unit = unit.to_string();
+        self
+    }
+
+    /// Set the number of decimal places for the delta (default 1).
+    #[must_use]
+    pub fn with_delta_precision(mut self, precision: usize) -> Self {
+        self.delta_precision = precision;
+        self
+    }
+
+    /// Swap the delta colors so that a decrease is shown as favourable.
+    #[must_use]
+    pub fn with_inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Set the style of the value line.
+    #[must_use]
+    pub fn with_value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// Set the style of the caption line.
+    #[must_use]
+    pub fn with_caption_style(mut self, style: Style) -> Self {
+        self.caption_style = style;
+        self
+    }
+
+    /// Set the styles of favourable and unfavourable deltas.
+    #[must_use]
+    pub fn with_delta_styles(mut self, up: Style, down: Style) -> Self {
+        self.up_style = up;
+        self.down_style = down;
+        self
+    }
+
+    /// Set the box characters for the border.
+    #[must_use]
+    pub fn with_box_chars(mut self, box_chars: &'static BoxChars) -> Self {
+        self.box_chars = box_chars;
+        self
+    }
+
+    /// Set the style of the border.
+    #[must_use]
+    pub fn with_border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Set a fixed width for the card, including the border.
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// The delta line, e.g. `▲ 12.5%`, or `None` when no delta is set.
+    fn delta_text(&self) -> Option<Text> {
+        let delta = self.delta?;
+        let magnitude = format!(
+            "{:.*}{}",
+            self.delta_precision,
+            delta.abs(),
+            self.delta_unit
+        );
+        // A delta that rounds to zero reads as flat rather than as a tiny move.
+        let flat = format!("{:.*}", self.delta_precision, 0.0);
+        let shown = format!("{:.*}", self.delta_precision, delta.abs());
+        let (arrow, style) = if shown == flat {
+            (FLAT, &self.flat_style)
+        } else if (delta > 0.0) != self.inverted {
+            (if delta > 0.0 { UP } else { DOWN }, &self.up_style)
+        } else {
+            (if delta > 0.0 { UP } else { DOWN }, &self.down_style)
+        };
+        Some(Text::styled(&format!("{arrow} {magnitude}"), style.clone()))
+    }
+
+    /// The card body: value, caption and delta lines, centered.
+    fn content(&self) -> Text {
+        let mut text = Text::new("", Style::null());
+        text.append_text(&layered(&self.value, &self.value_style));
+        text.append_str("\n", None);
+        text.append_text(&layered(&self.caption, &self.caption_style));
+        if let Some(delta) = self.delta_text() {
+            text.append_str("\n", None);
+            text.append_text(&delta);
+        }
+        text.justify = Some(JustifyMethod::Center);
+        text
+    }
+
+    /// Widest content line plus border and padding.
+    fn natural_width(&self) -> usize {
+        let content = self
+            .value
+            .cell_len()
+            .max(self.caption.cell_len())
+            .max(self.delta_text().map(|t| t.cell_len()).unwrap_or_default());
+        content + 4
+    }
+
+    /// Build the panel this card renders as.
+    fn panel(&self) -> Panel {
+        let mut panel = Panel::new(self.content())
+            .with_box_chars(self.box_chars)
+            .with_border_style(self.border_style.clone());
+        if let Some(width) = self.width {
+            panel = panel.with_width(width);
+        }
+        panel
+    }
+
+    /// Measure the card: its natural width, or the fixed width when set.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let width = self.width.unwrap_or_else(|| self.natural_width());
+        let width = width.min(options.max_width);
+        Measurement::new(width, width)
+    }
+}
+
+impl Renderable for StatCard {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.panel().gilt_console(console, options)
+    }
+}
+
+impl std::fmt::Display for StatCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// StatRow
+// ---------------------------------------------------------------------------
+
+/// Several [`StatCard`]s laid out side by side.
+///
+/// The cards are laid out as [`Columns`] with `equal` set, so every card in
+/// the row gets the same width: the widest card's natural width decides how
+/// many fit across, and when [`expand`](Self::expand) is on (the default) the
+/// cards stretch to fill the available width. Cards that do not fit wrap onto
+/// further rows, and cards in a row share the height of the tallest one.
+#[derive(Debug, Clone)]
+pub struct StatRow {
+    /// Cards in display order.
+    pub cards: Vec<StatCard>,
+    /// Blank cells between adjacent cards.
+    pub gap: usize,
+    /// Stretch the cards to fill the available width.
+    pub expand: bool,
+    /// Maximum number of cards per row.
+    pub max_columns: Option<usize>,
+}
+
+impl Default for StatRow {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl StatRow {
+    /// Create a row from the given cards.
+    pub fn new(cards: Vec<StatCard>) -> Self {
+        StatRow {
+            cards,
+            gap: 1,
+            expand: true,
+            max_columns: None,
+        }
+    }
+
+    /// Append a card.
+    pub fn add(&mut self, card: StatCard) {
+        self.cards.push(card);
+    }
+
+    /// Builder: append a card.
+    #[must_use]
+    pub fn with_card(mut self, card: StatCard) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    /// Set the number of blank cells between adjacent cards.
+    #[must_use]
+    pub fn with_gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set whether the cards stretch to fill the available width.
+    #[must_use]
+    pub fn with_expand(mut self, expand: bool) -> Self {
+        self.expand = expand;
+        self
+    }
+
+    /// Limit the number of cards per row.
+    #[must_use]
+    pub fn with_max_columns(mut self, columns: usize) -> Self {
+        self.max_columns = Some(columns.max(1));
+        self
+    }
+
+    /// Number of cards.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether the row has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// The cards as equal-width [`Columns`], the layout the row renders with.
+    fn columns(&self) -> Columns {
+        let mut columns = Columns::new()
+            .with_equal(true)
+            .with_expand(self.expand)
+            .with_padding((0, self.gap, 0, 0));
+        if let Some(max_columns) = self.max_columns {
+            columns = columns.with_max_columns(max_columns);
+        }
+        for card in &self.cards {
+            columns.add_panel(card.panel());
+        }
+        columns
+    }
+
+    /// Measure the row: the widest card up to everything on one line.
+    pub fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let widths: Vec<usize> = self
+            .cards
+            .iter()
+            .map(|c| c.measure(console, options).maximum)
+            .collect();
+        let widest = widths.iter().copied().max().unwrap_or(0);
+        let total = widest * widths.len() + self.gap * widths.len().saturating_sub(1);
+        Measurement::new(widest, total.max(widest)).clamp(None, Some(options.max_width))
+    }
+}
+
+impl Renderable for StatRow {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.columns().gilt_console(console, options)
+    }
+}
+
+impl std::fmt::Display for StatRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_console(width: usize) -> Console {
+        Console::builder()
+            .width(width)
+            .force_terminal(true)
+            .no_color(true)
+            .markup(false)
+            .build()
+    }
+
+    #[test]
+    fn test_card_layout() {
+        let card = StatCard::new("42", "Users").with_delta(5.0);
+        assert_eq!(
+            format!("{:12}", card),
+            "╭──────────╮\n\
+             │    42    │\n\
+             │  Users   │\n\
+             │  ▲ 5.0%  │\n\
+             ╰──────────╯"
+        );
+    }
+
+    #[test]
+    fn test_card_without_delta() {
+        let out = format!("{:10}", StatCard::new("7", "Jobs"));
+        assert_eq!(out.lines().count(), 4);
+        assert!(!out.contains('▲') && !out.contains('▼'));
+    }
+
+    #[test]
+    fn test_delta_arrows_and_styles() {
+        let card = StatCard::new("1", "x").with_delta(-2.25);
+        let delta = card.delta_text().unwrap();
+        assert_eq!(delta.plain(), "▼ 2.2%");
+        assert_eq!(delta.spans[0].style, card.down_style);
+
+        let inverted = card.clone().with_inverted(true).delta_text().unwrap();
+        assert_eq!(inverted.plain(), "▼ 2.2%");
+        assert_eq!(inverted.spans[0].style, card.up_style);
+
+        let up = StatCard::new("1", "x").with_delta(3.0).with_inverted(true);
+        assert_eq!(up.delta_text().unwrap().spans[0].style, up.down_style);
+    }
+
+    #[test]
+    fn test_delta_flat_and_units() {
+        let flat = StatCard::new("1", "x").with_delta(0.01);
+        let text = flat.delta_text().unwrap();
+        assert_eq!(text.plain(), "• 0.0%");
+        assert_eq!(text.spans[0].style, flat.flat_style);
+
+        let units = StatCard::new("1", "x")
+            .with_delta(120.0)
+            .with_delta_unit(" ms")
+            .with_delta_precision(0);
+        assert_eq!(units.delta_text().unwrap().plain(), "▲ 120 ms");
+    }
+
+    #[test]
+    fn test_card_measure() {
+        let console = make_console(80);
+        let opts = console.options();
+        let card = StatCard::new("1,204", "Requests").with_delta(12.5);
+        assert_eq!(card.measure(&console, &opts).maximum, 12);
+        assert_eq!(card.with_width(20).measure(&console, &opts).maximum, 20);
+    }
+
+    #[test]
+    fn test_row_side_by_side() {
+        let row = StatRow::new(vec![StatCard::new("1", "A"), StatCard::new("2", "B")]);
+        let out = format!("{:21}", row);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "╭────────╮ ╭────────╮");
+        assert_eq!(lines[1], "│   1    │ │   2    │");
+    }
+
+    #[test]
+    fn test_row_wraps_when_narrow() {
+        let row = StatRow::new(vec![
+            StatCard::new("100", "Alpha"),
+            StatCard::new("200", "Beta"),
+            StatCard::new("300", "Gamma"),
+        ]);
+        // Each card needs 9 cells; only two fit in 20.
+        let out = format!("{:20}", row);
+        assert_eq!(out.lines().count(), 8);
+        assert!(out.lines().nth(4).unwrap().starts_with('╭'));
+    }
+
+    #[test]
+    fn test_row_equal_heights() {
+        let row = StatRow::new(vec![
+            StatCard::new("1", "A").with_delta(1.0),
+            StatCard::new("2", "B"),
+        ]);
+        let out = format!("{:30}", row);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 5);
+        // The shorter card's bottom border sits above blank padding
+        assert!(lines[3].contains('╰'));
+        assert!(lines.iter().all(|l| l.chars().count() == 30));
+    }
+
+    #[test]
+    fn test_row_no_expand_and_max_columns() {
+        let row = StatRow::new(vec![StatCard::new("1", "A"), StatCard::new("2", "B")])
+            .with_expand(false)
+            .with_max_columns(1);
+        let out = format!("{:40}", row);
+        assert_eq!(out.lines().count(), 8);
+        assert_eq!(out.lines().next().unwrap(), "╭───╮");
+    }
+
+    #[test]
+    fn test_row_keeps_card_styles() {
+        let card = StatCard::new("1", "A").with_delta(2.0);
+        let up_style = card.up_style.clone();
+        let row = StatRow::new(vec![card, StatCard::new("2", "B")]);
+        let console = make_console(30);
+        let segments = row.gilt_console(&console, &console.options());
+        let arrow = segments.iter().find(|s| s.text.contains('▲')).unwrap();
+        assert_eq!(arrow.style, Some(up_style));
+    }
+
+    #[test]
+    fn test_empty_row() {
+        let row = StatRow::default();
+        assert!(row.is_empty());
+        assert_eq!(row.to_string(), "");
+    }
+}
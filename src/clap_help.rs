@@ -0,0 +1,703 @@
+//! clap integration -- help and error output in gilt styling.
+//!
+//! [`styles`] builds a clap [`Styles`] preset from a gilt [`Theme`], so
+//! clap's own help and error output uses the theme's `clap.*` styles.
+//! [`ClapHelp`] goes further and renders a command's help through the
+//! [`Console`]: the usage line in a [`Panel`] and each group of arguments,
+//! options and subcommands in a [`Table`]. [`ClapError`] renders a parse
+//! error the same way, and [`exit`] prints any clap error and exits with
+//! clap's exit code.
+//!
+//! Requires the `clap` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use gilt::clap_help::ClapHelp;
+//!
+//! let cmd = Command::new("greet")
+//!     .about("Say hello")
+//!     .arg(Arg::new("name").help("Who to greet"));
+//! let help = format!("{:60}", ClapHelp::new(&cmd));
+//! assert!(help.contains("Usage"));
+//! assert!(help.contains("[name]"));
+//! ```
+
+use std::fmt;
+
+use clap::builder::Styles;
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+
+use crate::align_widget::HorizontalAlign;
+use crate::box_chars::ROUNDED;
+use crate::color::theme::Theme;
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::panel::Panel;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::table::{ColumnOptions, Table};
+use crate::text::{JustifyMethod, Text};
+
+/// Theme style for section headings.
+const HEADER: &str = "clap.header";
+/// Theme style for the usage line.
+const USAGE: &str = "clap.usage";
+/// Theme style for literal flags, names and subcommands.
+const LITERAL: &str = "clap.literal";
+/// Theme style for value placeholders such as `<FILE>`.
+const PLACEHOLDER: &str = "clap.placeholder";
+/// Theme style for the error heading.
+const ERROR: &str = "clap.error";
+/// Theme style for suggested values.
+const VALID: &str = "clap.valid";
+/// Theme style for the offending value.
+const INVALID: &str = "clap.invalid";
+
+/// Build a clap [`Styles`] preset from the `clap.*` styles of `theme`.
+///
+/// Pass the result to `Command::styles` (with clap's `color` feature) so
+/// clap's built-in help and errors match gilt's own output.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::clap_help::styles;
+/// use gilt::theme::Theme;
+///
+/// let styles = styles(&Theme::new(None, true));
+/// assert!(styles.get_literal().get_effects().contains(anstyle::Effects::BOLD));
+/// ```
+pub fn styles(theme: &Theme) -> Styles {
+    let style = |name: &str| -> anstyle::Style {
+        theme
+            .get(name)
+            .map(anstyle::Style::from)
+            .unwrap_or_default()
+    };
+    Styles::plain()
+        .header(style(HEADER))
+        .usage(style(USAGE))
+        .literal(style(LITERAL))
+        .placeholder(style(PLACEHOLDER))
+        .error(style(ERROR))
+        .valid(style(VALID))
+        .invalid(style(INVALID))
+}
+
+/// Print the help for `cmd` on `console`.
+pub fn print_help(console: &mut Console, cmd: &Command) {
+    console.print(&ClapHelp::new(cmd));
+}
+
+/// Print a clap error on `console`.
+///
+/// Help and version requests are printed as clap renders them; other
+/// errors are shown as a [`ClapError`].
+pub fn print_error(console: &mut Console, err: &clap::Error) {
+    console.print(&ClapError::new(err));
+}
+
+/// Print a clap error on `console` and exit with clap's exit code (0 for
+/// help and version requests, 2 for usage errors).
+pub fn exit(console: &mut Console, err: &clap::Error) -> ! {
+    print_error(console, err);
+    std::process::exit(err.exit_code())
+}
+
+// ---------------------------------------------------------------------------
+// ClapHelp
+// ---------------------------------------------------------------------------
+
+/// One row of a help table.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// Flags, argument or subcommand name.
+    name: String,
+    /// Value placeholders, e.g. ` <PATH>`.
+    value: String,
+    /// Help text.
+    help: String,
+    /// Notes such as `[default: 1]`.
+    notes: Vec<String>,
+}
+
+/// The help of a clap [`Command`], rendered with gilt widgets.
+///
+/// The about text comes first, then the usage line in a [`Panel`], then a
+/// [`Table`] per help heading: positional arguments, options (or their
+/// custom `help_heading`s) and subcommands. Flags and names use the
+/// `clap.literal` style, placeholders `clap.placeholder` and table titles
+/// `clap.header`, all looked up in the console's theme.
+#[derive(Debug, Clone)]
+pub struct ClapHelp {
+    /// Display name and version, e.g. `app 1.2.0`.
+    title: String,
+    /// About text.
+    about: Option<String>,
+    /// Usage line without the `Usage:` prefix.
+    usage: String,
+    /// (heading, rows) in display order.
+    sections: Vec<(String, Vec<Entry>)>,
+    /// Text after the help.
+    after_help: Option<String>,
+}
+
+impl ClapHelp {
+    /// Collect the help of `cmd`, including the generated `--help` and
+    /// `--version` flags.
+    pub fn new(cmd: &Command) -> Self {
+        let mut cmd = cmd.clone();
+        cmd.build();
+
+        let name = cmd
+            .get_display_name()
+            .or(cmd.get_bin_name())
+            .unwrap_or(cmd.get_name())
+            .to_string();
+        let title = match cmd.get_version() {
+            Some(version) => format!("{name} {version}"),
+            None => name,
+        };
+        let usage = cmd.render_usage().to_string();
+        let usage = usage
+            .trim()
+            .strip_prefix("Usage:")
+            .unwrap_or(&usage)
+            .trim()
+            .to_string();
+
+        let mut sections: Vec<(String, Vec<Entry>)> = Vec::new();
+        let mut push = |heading: &str, entry: Entry| match sections
+            .iter_mut()
+            .find(|(name, _)| name == heading)
+        {
+            Some((_, entries)) => entries.push(entry),
+            None => sections.push((heading.to_string(), vec![entry])),
+        };
+        for arg in cmd.get_arguments().filter(|arg| !arg.is_hide_set()) {
+            let default_heading = if arg.is_positional() {
+                "Arguments"
+            } else {
+                "Options"
+            };
+            push(
+                arg.get_help_heading().unwrap_or(default_heading),
+                arg_entry(arg),
+            );
+        }
+        for sub in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+            push(
+                "Commands",
+                Entry {
+                    name: sub.get_name().to_string(),
+                    value: String::new(),
+                    help: sub.get_about().map(|s| s.to_string()).unwrap_or_default(),
+                    notes: Vec::new(),
+                },
+            );
+        }
+        // Positional arguments first, subcommands last, like clap.
+        sections.sort_by_key(|(heading, _)| match heading.as_str() {
+            "Arguments" => 0,
+            "Commands" => 2,
+            _ => 1,
+        });
+
+        ClapHelp {
+            title,
+            about: cmd.get_about().map(|s| s.to_string()),
+            usage,
+            sections,
+            after_help: cmd.get_after_help().map(|s| s.to_string()),
+        }
+    }
+
+    /// The usage line as styled text: literals in `clap.literal`,
+    /// `<placeholders>` and `[optional]` parts in `clap.placeholder`.
+    fn usage_text(&self, console: &Console) -> Text {
+        let literal = theme_style(console, LITERAL);
+        let placeholder = theme_style(console, PLACEHOLDER);
+        let mut text = Text::empty();
+        for (i, word) in self.usage.split(' ').enumerate() {
+            if i > 0 {
+                text.append_str(" ", None);
+            }
+            let style = if word.starts_with(['<', '[']) {
+                placeholder.clone()
+            } else {
+                literal.clone()
+            };
+            text.append_str(word, Some(style));
+        }
+        text
+    }
+
+    /// The table for one help heading.
+    fn section_table(&self, console: &Console, heading: &str, entries: &[Entry]) -> Table {
+        let literal = theme_style(console, LITERAL);
+        let placeholder = theme_style(console, PLACEHOLDER);
+        let dim = Style::parse("dim").unwrap_or_else(|_| Style::null());
+
+        let mut table = Table::new(&[])
+            .with_title(heading)
+            .with_title_style(HEADER)
+            .with_title_justify(JustifyMethod::Left)
+            .with_box_chars(Some(&*ROUNDED))
+            .with_border_style("dim")
+            .with_show_header(false)
+            .with_expand(true);
+        table.add_column(
+            "",
+            "",
+            ColumnOptions {
+                no_wrap: true,
+                ..Default::default()
+            },
+        );
+        table.add_column(
+            "",
+            "",
+            ColumnOptions {
+                ratio: Some(1),
+                ..Default::default()
+            },
+        );
+        for entry in entries {
+            let mut name = Text::styled(&entry.name, literal.clone());
+            name.append_str(&entry.value, Some(placeholder.clone()));
+            let mut help = Text::new(&entry.help, Style::null());
+            for note in &entry.notes {
+                if !help.plain().is_empty() {
+                    help.append_str(" ", None);
+                }
+                help.append_str(note, Some(dim.clone()));
+            }
+            table.add_row_text(&[name, help]);
+        }
+        table
+    }
+}
+
+/// The help row for an argument.
+fn arg_entry(arg: &Arg) -> Entry {
+    // Like clap, positionals default to their id and options to the id in
+    // upper case.
+    let value_names: Vec<String> = match arg.get_value_names() {
+        Some(names) => names.iter().map(|name| name.to_string()).collect(),
+        None if arg.is_positional() => vec![arg.get_id().as_str().to_string()],
+        None => vec![arg.get_id().as_str().to_uppercase()],
+    };
+    let multiple = arg
+        .get_num_args()
+        .is_some_and(|range| range.max_values() > 1);
+    let ellipsis = if multiple { "..." } else { "" };
+
+    let (name, value) = if arg.is_positional() {
+        let name = value_names.join(" ");
+        let name = if arg.is_required_set() {
+            format!("<{name}>{ellipsis}")
+        } else {
+            format!("[{name}]{ellipsis}")
+        };
+        (name, String::new())
+    } else {
+        let mut flags = Vec::new();
+        if let Some(short) = arg.get_short() {
+            flags.push(format!("-{short}"));
+        }
+        if let Some(long) = arg.get_long() {
+            flags.push(format!("--{long}"));
+        }
+        let name = if arg.get_short().is_none() {
+            // Keep long-only flags aligned with `-s, --long`.
+            format!("    {}", flags.join(", "))
+        } else {
+            flags.join(", ")
+        };
+        let value = if arg.get_action().takes_values() {
+            let names: Vec<String> = value_names.iter().map(|n| format!("<{n}>")).collect();
+            format!(" {}{ellipsis}", names.join(" "))
+        } else {
+            String::new()
+        };
+        (name, value)
+    };
+
+    let help = arg
+        .get_help()
+        .or(arg.get_long_help())
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    let mut notes = Vec::new();
+    if arg.is_positional() && arg.is_required_set() {
+        notes.push("[required]".to_string());
+    }
+    if !arg.is_hide_default_value_set() && arg.get_action().takes_values() {
+        let defaults: Vec<String> = arg
+            .get_default_values()
+            .iter()
+            .map(|value| value.to_string_lossy().into_owned())
+            .collect();
+        if !defaults.is_empty() {
+            notes.push(format!("[default: {}]", defaults.join(", ")));
+        }
+    }
+    if !arg.is_hide_possible_values_set() {
+        let possible: Vec<String> = arg
+            .get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| value.get_name().to_string())
+            .collect();
+        if !possible.is_empty() && arg.get_action().takes_values() {
+            notes.push(format!("[possible values: {}]", possible.join(", ")));
+        }
+    }
+
+    Entry {
+        name,
+        value,
+        help,
+        notes,
+    }
+}
+
+impl Renderable for ClapHelp {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let bold = Style::parse("bold").unwrap_or_else(|_| Style::null());
+
+        let mut header = Text::styled(&self.title, bold);
+        if let Some(about) = &self.about {
+            header.append_str(" — ", None);
+            header.append_str(about, None);
+        }
+        header.append_str("\n", None);
+        segments.extend(header.gilt_console(console, options));
+
+        let usage = Panel::new(self.usage_text(console))
+            .with_title(Text::styled("Usage", theme_style(console, HEADER)))
+            .with_title_align(HorizontalAlign::Left)
+            .with_border_style(Style::parse("dim").unwrap_or_else(|_| Style::null()));
+        segments.extend(usage.gilt_console(console, options));
+
+        for (heading, entries) in &self.sections {
+            let table = self.section_table(console, heading, entries);
+            segments.extend(table.gilt_console(console, options));
+        }
+
+        if let Some(after) = &self.after_help {
+            let mut text = Text::new(after, Style::null());
+            text.append_str("\n", None);
+            segments.extend(Text::empty().gilt_console(console, options));
+            segments.extend(text.gilt_console(console, options));
+        }
+        segments
+    }
+}
+
+impl fmt::Display for ClapHelp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_plain(self, f)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ClapError
+// ---------------------------------------------------------------------------
+
+/// A clap parse error, rendered with gilt widgets.
+///
+/// The message goes in a [`Panel`] with a `clap.error` border, quoted
+/// values in the `clap.invalid` style; the usage line follows in its own
+/// panel and clap's tips (`tip: a similar argument exists: '--all'`) come
+/// last with suggestions in `clap.valid`. Help and version requests, which
+/// clap reports as errors, are shown as clap rendered them, as plain text.
+#[derive(Debug, Clone)]
+pub struct ClapError {
+    /// Whether this is a help or version request rather than an error.
+    display_only: bool,
+    /// clap's rendering as plain text.
+    rendered: String,
+    /// The message without the `error:` prefix.
+    message: String,
+    /// Usage line without the `Usage:` prefix.
+    usage: Option<String>,
+    /// Tip and hint lines.
+    tips: Vec<String>,
+}
+
+impl ClapError {
+    /// Split the rendering of `err` into its message, usage and tips.
+    pub fn new(err: &clap::Error) -> Self {
+        let display_only = matches!(
+            err.kind(),
+            ErrorKind::DisplayHelp
+                | ErrorKind::DisplayVersion
+                | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+        );
+        let plain = err.render().to_string();
+
+        let mut message = Vec::new();
+        let mut usage = None;
+        let mut tips = Vec::new();
+        for paragraph in plain.split("\n\n") {
+            let paragraph = paragraph.trim_end();
+            let trimmed = paragraph.trim_start();
+            if trimmed.is_empty() {
+                continue;
+            }
+            if let Some(rest) = trimmed.strip_prefix("Usage:") {
+                usage = Some(rest.trim().to_string());
+            } else if message.is_empty() {
+                message.push(
+                    trimmed
+                        .strip_prefix("error:")
+                        .unwrap_or(trimmed)
+                        .trim()
+                        .to_string(),
+                );
+            } else if trimmed.starts_with("tip:") || trimmed.starts_with("For more information") {
+                tips.extend(paragraph.lines().map(|line| line.trim().to_string()));
+            } else {
+                message.push(paragraph.to_string());
+            }
+        }
+
+        ClapError {
+            display_only,
+            rendered: plain.clone(),
+            message: message.join("\n\n"),
+            usage,
+            tips,
+        }
+    }
+
+    /// The error message without clap's `error:` prefix.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// `text` with every `'quoted'` part styled with `style`.
+fn highlight_quoted(text: &str, style: &Style) -> Text {
+    let mut out = Text::empty();
+    for (i, part) in text.split('\'').enumerate() {
+        if i % 2 == 1 {
+            out.append_str(&format!("'{part}'"), Some(style.clone()));
+        } else {
+            out.append_str(part, None);
+        }
+    }
+    out
+}
+
+impl Renderable for ClapError {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        if self.display_only {
+            return Text::new(self.rendered.trim_end(), Style::null())
+                .gilt_console(console, options);
+        }
+
+        let error = theme_style(console, ERROR);
+        let mut segments = Vec::new();
+        let panel = Panel::new(highlight_quoted(
+            &self.message,
+            &theme_style(console, INVALID),
+        ))
+        .with_title(Text::styled("Error", error.clone()))
+        .with_title_align(HorizontalAlign::Left)
+        .with_border_style(error);
+        segments.extend(panel.gilt_console(console, options));
+
+        if let Some(usage) = &self.usage {
+            let help = ClapHelp {
+                title: String::new(),
+                about: None,
+                usage: usage.clone(),
+                sections: Vec::new(),
+                after_help: None,
+            };
+            let panel = Panel::new(help.usage_text(console))
+                .with_title(Text::styled("Usage", theme_style(console, HEADER)))
+                .with_title_align(HorizontalAlign::Left)
+                .with_border_style(Style::parse("dim").unwrap_or_else(|_| Style::null()));
+            segments.extend(panel.gilt_console(console, options));
+        }
+
+        let valid = theme_style(console, VALID);
+        for tip in &self.tips {
+            segments.extend(highlight_quoted(tip, &valid).gilt_console(console, options));
+        }
+        segments
+    }
+}
+
+impl fmt::Display for ClapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_plain(self, f)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// A style from the console's theme, or the null style if it is missing.
+fn theme_style(console: &Console, name: &str) -> Style {
+    console.get_style(name).unwrap_or_else(|_| Style::null())
+}
+
+/// Render without color at the formatter's width (default 80).
+fn render_plain(renderable: &dyn Renderable, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut console = Console::builder()
+        .width(f.width().unwrap_or(80))
+        .force_terminal(true)
+        .no_color(true)
+        .build();
+    console.begin_capture();
+    console.print(renderable);
+    let output = console.end_capture();
+    write!(f, "{}", output.trim_end_matches('\n'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{ArgAction, Command};
+
+    fn command() -> Command {
+        Command::new("app")
+            .version("1.2.0")
+            .about("Does things")
+            .arg(Arg::new("input").required(true).help("Input file"))
+            .arg(
+                Arg::new("verbose")
+                    .short('v')
+                    .long("verbose")
+                    .action(ArgAction::SetTrue)
+                    .help("Print more"),
+            )
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .value_name("FMT")
+                    .value_parser(["json", "text"])
+                    .default_value("text")
+                    .help("Output format"),
+            )
+            .arg(
+                Arg::new("secret")
+                    .long("secret")
+                    .hide(true)
+                    .action(ArgAction::SetTrue),
+            )
+            .subcommand(Command::new("init").about("Create a project"))
+    }
+
+    #[test]
+    fn test_styles_from_theme() {
+        let styles = styles(&Theme::new(None, true));
+        let error = styles.get_error();
+        assert!(error.get_effects().contains(anstyle::Effects::BOLD));
+        assert_eq!(
+            error.get_fg_color(),
+            Some(anstyle::Color::Ansi(anstyle::AnsiColor::Red))
+        );
+        assert_eq!(
+            styles.get_placeholder().get_fg_color(),
+            Some(anstyle::Color::Ansi(anstyle::AnsiColor::Cyan))
+        );
+    }
+
+    #[test]
+    fn test_help_sections_and_entries() {
+        let output = format!("{:100}", ClapHelp::new(&command()));
+        assert!(output.starts_with("app 1.2.0 — Does things\n"));
+        assert!(output.contains("│ app [OPTIONS] <input> [COMMAND]"));
+        let arguments = output.find("Arguments").unwrap();
+        let options = output.find("Options").unwrap();
+        let commands = output.find("Commands").unwrap();
+        assert!(arguments < options && options < commands);
+        assert!(output.contains("│ <input>   │ Input file [required]"));
+        assert!(output.contains("-v, --verbose"));
+        assert!(output.contains("    --format <FMT>"));
+        assert!(output.contains("[default: text] [possible values: json, text]"));
+        assert!(output.contains("-h, --help"));
+        assert!(output.contains("-V, --version"));
+        assert!(output.contains("init"));
+        assert!(output.contains("Create a project"));
+        assert!(!output.contains("--secret"));
+    }
+
+    #[test]
+    fn test_help_custom_heading() {
+        let cmd = Command::new("app").arg(
+            Arg::new("color")
+                .long("color")
+                .help_heading("Display")
+                .action(ArgAction::SetTrue),
+        );
+        let output = format!("{:60}", ClapHelp::new(&cmd));
+        assert!(output.contains("Display"));
+        let display = output.find("Display").unwrap();
+        let color = output.find("--color").unwrap();
+        let help = output.find("--help").unwrap();
+        assert!(display < color && color < help);
+    }
+
+    #[test]
+    fn test_help_uses_theme_styles() {
+        let mut console = Console::builder().width(60).force_terminal(true).build();
+        console.begin_capture();
+        print_help(&mut console, &command());
+        let output = console.end_capture();
+        // `clap.literal` is bold cyan.
+        assert!(output.contains("\x1b[1;36m-v, --verbose"));
+    }
+
+    #[test]
+    fn test_error_message_usage_and_tips() {
+        let err = command()
+            .try_get_matches_from(["app", "in.txt", "--verbos"])
+            .unwrap_err();
+        let error = ClapError::new(&err);
+        assert!(error
+            .message()
+            .starts_with("unexpected argument '--verbos' found"));
+        let output = format!("{:70}", error);
+        assert!(output.starts_with("╭─ Error "));
+        assert!(output.contains("│ unexpected argument '--verbos' found"));
+        assert!(output.contains("╭─ Usage "));
+        assert!(output.contains("│ app <input>"));
+        assert!(output.ends_with(
+            "╯\ntip: to pass '--verbos' as a value, use '-- --verbos'\n\
+             For more information, try '--help'."
+        ));
+    }
+
+    #[test]
+    fn test_error_styles_invalid_value() {
+        let err = command()
+            .try_get_matches_from(["app", "in.txt", "--format", "xml"])
+            .unwrap_err();
+        let mut console = Console::builder().width(80).force_terminal(true).build();
+        console.begin_capture();
+        print_error(&mut console, &err);
+        let output = console.end_capture();
+        // `clap.invalid` is bold yellow.
+        assert!(output.contains("\x1b[1;33m'xml'"));
+    }
+
+    #[test]
+    fn test_help_request_is_shown_as_rendered() {
+        let err = command()
+            .try_get_matches_from(["app", "--version"])
+            .unwrap_err();
+        let output = format!("{}", ClapError::new(&err));
+        assert_eq!(output, "app 1.2.0");
+    }
+}
@@ -38,36 +38,43 @@ pub enum ColorEnvOverride {
 /// 4. `CLICOLOR` = `"0"` → [`ColorEnvOverride::NoColor`]
 /// 5. Otherwise → [`ColorEnvOverride::None`]
 pub fn detect_color_env() -> ColorEnvOverride {
+    detect_color_env_source().0
+}
+
+/// Like [`detect_color_env`], but also name the variable that decided the
+/// result (`None` when no variable applies).
+pub(crate) fn detect_color_env_source() -> (ColorEnvOverride, Option<&'static str>) {
     // 1. NO_COLOR – presence alone is enough
     if env::var_os("NO_COLOR").is_some() {
-        return ColorEnvOverride::NoColor;
+        return (ColorEnvOverride::NoColor, Some("NO_COLOR"));
     }
 
     // 2. FORCE_COLOR
     if let Ok(val) = env::var("FORCE_COLOR") {
-        return match val.as_str() {
+        let color = match val.as_str() {
             "0" => ColorEnvOverride::NoColor,
             "1" | "2" => ColorEnvOverride::ForceColor,
             "3" => ColorEnvOverride::ForceColorTruecolor,
             _ => ColorEnvOverride::ForceColor,
         };
+        return (color, Some("FORCE_COLOR"));
     }
 
     // 3. CLICOLOR_FORCE – any non-"0" value forces color
     if let Ok(val) = env::var("CLICOLOR_FORCE") {
         if val != "0" {
-            return ColorEnvOverride::ForceColor;
+            return (ColorEnvOverride::ForceColor, Some("CLICOLOR_FORCE"));
         }
     }
 
     // 4. CLICOLOR=0 disables color
     if let Ok(val) = env::var("CLICOLOR") {
         if val == "0" {
-            return ColorEnvOverride::NoColor;
+            return (ColorEnvOverride::NoColor, Some("CLICOLOR"));
         }
     }
 
-    ColorEnvOverride::None
+    (ColorEnvOverride::None, None)
 }
 
 /// Detect if the user prefers reduced motion.
@@ -98,10 +105,7 @@ mod tests {
     static ENV_LOCK: Mutex<()> = Mutex::new(());
 
     /// Helper: remove all color env vars, run `f`, then restore.
-    fn with_env<F: FnOnce() -> ColorEnvOverride>(
-        vars: &[(&str, Option<&str>)],
-        f: F,
-    ) -> ColorEnvOverride {
+    fn with_env<T, F: FnOnce() -> T>(vars: &[(&str, Option<&str>)], f: F) -> T {
         let _guard = ENV_LOCK.lock().unwrap();
 
         // Save originals & clear all colour vars first.
@@ -140,6 +144,20 @@ mod tests {
         assert_eq!(r, ColorEnvOverride::NoColor);
     }
 
+    #[test]
+    fn test_source_names_deciding_variable() {
+        let r = with_env(
+            &[("FORCE_COLOR", Some("3")), ("CLICOLOR", Some("0"))],
+            detect_color_env_source,
+        );
+        assert_eq!(
+            r,
+            (ColorEnvOverride::ForceColorTruecolor, Some("FORCE_COLOR"))
+        );
+        let r = with_env(&[], detect_color_env_source);
+        assert_eq!(r, (ColorEnvOverride::None, None));
+    }
+
     #[test]
     fn test_no_color_any_value() {
         let r = with_env(&[("NO_COLOR", Some("1"))], detect_color_env);
@@ -13,7 +13,7 @@ pub mod theme;
 use crate::error::ColorParseError;
 
 use self::color_triplet::ColorTriplet;
-use self::palette::{EIGHT_BIT_PALETTE, STANDARD_PALETTE, WINDOWS_PALETTE};
+use self::palette::{Palette, EIGHT_BIT_PALETTE, STANDARD_PALETTE, WINDOWS_PALETTE};
 use self::terminal_theme::{TerminalTheme, DEFAULT_TERMINAL_THEME};
 use std::fmt;
 use std::fmt::Write as _;
@@ -48,6 +48,30 @@ pub enum ColorType {
     Windows = 4,
 }
 
+/// How colors the terminal cannot show are mapped to ones it can.
+///
+/// Only colors outside the console's color system are affected: a truecolor
+/// color on a 256-color terminal, or a truecolor or 256-color color on a
+/// 16-color terminal.
+#[derive(Debug, Clone, Default)]
+pub enum DownsampleStrategy {
+    /// Rich's downgrade: the 6×6×6 color cube or grayscale ramp on 256-color
+    /// terminals, the nearest standard color on 16-color terminals.
+    #[default]
+    Auto,
+    /// The perceptually nearest of all 256 palette colors, including the 16
+    /// standard ones. On 16-color terminals this is the same as
+    /// [`Nearest16`](Self::Nearest16).
+    Nearest256,
+    /// The perceptually nearest of the 16 standard colors, even on
+    /// 256-color terminals, so output follows the terminal's color scheme.
+    Nearest16,
+    /// The nearest entry of a palette describing the colors the terminal
+    /// actually shows, written as `color(index)`. Only the first 16 entries
+    /// are used on 16-color terminals.
+    Palette(Palette),
+}
+
 /// A terminal color representation.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Color {
@@ -492,6 +516,55 @@ impl Color {
             }
         }
     }
+
+    /// Returns `true` if a terminal using `system` can show this color
+    /// without downgrading it.
+    pub fn fits(&self, system: ColorSystem) -> bool {
+        match system {
+            ColorSystem::TrueColor => true,
+            ColorSystem::EightBit => self.color_type != ColorType::TrueColor,
+            ColorSystem::Standard | ColorSystem::Windows => matches!(
+                self.color_type,
+                ColorType::Default | ColorType::Standard | ColorType::Windows
+            ),
+        }
+    }
+
+    /// Downgrades the color to `system` using `strategy`, leaving colors the
+    /// system can already show unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::color::{Color, ColorSystem, DownsampleStrategy};
+    ///
+    /// let orange = Color::from_rgb(255, 135, 0);
+    /// let nearest = orange.downgrade_with(ColorSystem::EightBit, &DownsampleStrategy::Nearest16);
+    /// assert_eq!(nearest.number, Some(9));
+    /// ```
+    pub fn downgrade_with(&self, system: ColorSystem, strategy: &DownsampleStrategy) -> Color {
+        if self.fits(system) {
+            return self.clone();
+        }
+        let nearest = |palette: &Palette, count: usize| {
+            let triplet = self.get_truecolor(None, true);
+            Color::from_ansi(palette.match_color_within(&triplet, count) as u8)
+        };
+        match (strategy, system) {
+            (DownsampleStrategy::Auto, _) | (_, ColorSystem::Windows) => self.downgrade(system),
+            (DownsampleStrategy::Nearest256, ColorSystem::EightBit) => {
+                nearest(&EIGHT_BIT_PALETTE, 256)
+            }
+            (DownsampleStrategy::Nearest256 | DownsampleStrategy::Nearest16, _) => {
+                nearest(&STANDARD_PALETTE, 16)
+            }
+            (DownsampleStrategy::Palette(palette), _) if palette.is_empty() => {
+                self.downgrade(system)
+            }
+            (DownsampleStrategy::Palette(palette), ColorSystem::Standard) => nearest(palette, 16),
+            (DownsampleStrategy::Palette(palette), _) => nearest(palette, 256),
+        }
+    }
 }
 
 impl fmt::Display for Color {
@@ -546,7 +619,7 @@ pub fn blend_rgb(color1: ColorTriplet, color2: ColorTriplet, cross_fade: f64) ->
 /// - hue: 0.0-1.0
 /// - lightness: 0.0-1.0
 /// - saturation: 0.0-1.0
-fn rgb_to_hls(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+pub(crate) fn rgb_to_hls(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
     let (r, g, b) = rgb;
     let max = r.max(g).max(b);
     let min = r.min(g).min(b);
@@ -574,6 +647,37 @@ fn rgb_to_hls(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
     (h / 6.0, l, s)
 }
 
+/// Converts HLS (each component 0.0-1.0) back to normalized RGB.
+///
+/// Inverse of [`rgb_to_hls`].
+pub(crate) fn hls_to_rgb(hls: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (h, l, s) = hls;
+    if s == 0.0 {
+        return (l, l, l); // achromatic
+    }
+
+    let q = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        l + s - l * s
+    };
+    let p = 2.0 * l - q;
+    let channel = |t: f64| {
+        let t = t.rem_euclid(1.0);
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 0.5 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+
+    (channel(h + 1.0 / 3.0), channel(h), channel(h - 1.0 / 3.0))
+}
+
 /// Gets the ANSI color number for a named color.
 fn get_ansi_color_number(name: &str) -> Option<u8> {
     match name {
@@ -784,6 +888,32 @@ fn get_ansi_color_number(name: &str) -> Option<u8> {
     }
 }
 
+// ============================================================================
+// LRU Cache for Color Parsing
+// ============================================================================
+
+use lru::LruCache;
+use std::sync::Mutex;
+
+/// Global LRU cache for parsed colors with capacity for 512 entries.
+static COLOR_CACHE: Mutex<Option<LruCache<String, Color>>> = Mutex::new(None);
+
+/// Clears the global color cache.
+pub fn clear_color_cache() {
+    if let Ok(mut cache) = COLOR_CACHE.lock() {
+        *cache = None;
+    }
+}
+
+/// Returns the current number of entries in the color cache.
+pub fn color_cache_size() -> usize {
+    if let Ok(cache) = COLOR_CACHE.lock() {
+        cache.as_ref().map(|c| c.len()).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1171,6 +1301,21 @@ mod tests {
         assert_eq!(s, 0.0);
     }
 
+    #[test]
+    fn test_hls_round_trip() {
+        for rgb in [
+            (1.0, 0.0, 0.0),
+            (0.2, 0.6, 0.4),
+            (0.5, 0.5, 0.5),
+            (0.9, 0.1, 0.8),
+        ] {
+            let (r, g, b) = hls_to_rgb(rgb_to_hls(rgb));
+            assert!((r - rgb.0).abs() < 1e-9);
+            assert!((g - rgb.1).abs() < 1e-9);
+            assert!((b - rgb.2).abs() < 1e-9);
+        }
+    }
+
     // Named color tests
     #[test]
     fn test_parse_case_insensitive() {
@@ -1211,30 +1356,58 @@ mod tests {
         let downgraded = color.downgrade(ColorSystem::Standard);
         assert_eq!(downgraded.color_type, ColorType::Default);
     }
-}
 
-// ============================================================================
-// LRU Cache for Color Parsing
-// ============================================================================
+    #[test]
+    fn test_downgrade_with_strategies() {
+        let maroon = Color::from_rgb(128, 0, 0);
+        let auto = maroon.downgrade_with(ColorSystem::EightBit, &DownsampleStrategy::Auto);
+        assert_eq!(auto, maroon.downgrade(ColorSystem::EightBit));
+        assert_eq!(auto.number, Some(88));
 
-use lru::LruCache;
-use std::sync::Mutex;
+        // Palette entry 1 is exactly rgb(128, 0, 0).
+        let nearest256 =
+            maroon.downgrade_with(ColorSystem::EightBit, &DownsampleStrategy::Nearest256);
+        assert_eq!(nearest256, Color::from_ansi(1));
 
-/// Global LRU cache for parsed colors with capacity for 512 entries.
-static COLOR_CACHE: Mutex<Option<LruCache<String, Color>>> = Mutex::new(None);
+        let nearest16 =
+            maroon.downgrade_with(ColorSystem::EightBit, &DownsampleStrategy::Nearest16);
+        assert_eq!(nearest16.color_type, ColorType::Standard);
+        assert_eq!(nearest16.number, Some(1));
+    }
 
-/// Clears the global color cache.
-pub fn clear_color_cache() {
-    if let Ok(mut cache) = COLOR_CACHE.lock() {
-        *cache = None;
+    #[test]
+    fn test_downgrade_with_custom_palette() {
+        let palette = Palette::new(vec![(0, 0, 0), (200, 100, 50)]);
+        let strategy = DownsampleStrategy::Palette(palette);
+        let color = Color::from_rgb(190, 110, 40);
+        assert_eq!(
+            color.downgrade_with(ColorSystem::EightBit, &strategy),
+            Color::from_ansi(1)
+        );
+        // An empty palette falls back to the default downgrade.
+        let empty = DownsampleStrategy::Palette(Palette::new(Vec::new()));
+        assert_eq!(
+            color.downgrade_with(ColorSystem::EightBit, &empty),
+            color.downgrade(ColorSystem::EightBit)
+        );
     }
-}
 
-/// Returns the current number of entries in the color cache.
-pub fn color_cache_size() -> usize {
-    if let Ok(cache) = COLOR_CACHE.lock() {
-        cache.as_ref().map(|c| c.len()).unwrap_or(0)
-    } else {
-        0
+    #[test]
+    fn test_downgrade_with_keeps_colors_that_fit() {
+        let strategy = DownsampleStrategy::Nearest16;
+        let color = Color::from_ansi(200);
+        assert_eq!(
+            color.downgrade_with(ColorSystem::EightBit, &strategy),
+            color
+        );
+        let truecolor = Color::from_rgb(1, 2, 3);
+        assert_eq!(
+            truecolor.downgrade_with(ColorSystem::TrueColor, &strategy),
+            truecolor
+        );
+        assert_ne!(
+            color.downgrade_with(ColorSystem::Standard, &strategy),
+            color
+        );
     }
 }
@@ -32,6 +32,22 @@ impl Palette {
     /// Uses the "redmean" weighted Euclidean distance formula for
     /// perceptually accurate color matching.
     pub fn match_color(&self, color: &ColorTriplet) -> usize {
+        self.match_color_within(color, self.colors.len())
+    }
+
+    /// Returns the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Like [`match_color`](Self::match_color), but only considers the first
+    /// `count` entries.
+    pub(crate) fn match_color_within(&self, color: &ColorTriplet, count: usize) -> usize {
         let red1 = color.red as i32;
         let green1 = color.green as i32;
         let blue1 = color.blue as i32;
@@ -39,7 +55,7 @@ impl Palette {
         let mut min_index = 0;
         let mut min_distance = f64::MAX;
 
-        for (index, &(r, g, b)) in self.colors.iter().enumerate() {
+        for (index, &(r, g, b)) in self.colors.iter().take(count).enumerate() {
             let red2 = r as i32;
             let green2 = g as i32;
             let blue2 = b as i32;
@@ -2,13 +2,16 @@
 //!
 //! Provides [`TerminalTheme`] and several built-in themes (default, SVG export,
 //! Monokai, Dimmed Monokai, Night Owlish) used when resolving named/system
-//! colors to RGB values.
+//! colors to RGB values, and [`detect_background`] to find out whether the
+//! running terminal has a dark or light background.
 
+use crate::color::accessibility::contrast_ratio;
 use crate::color::color_triplet::ColorTriplet;
 use crate::palette::Palette;
 use std::sync::LazyLock;
 
 /// A terminal theme definition consisting of foreground, background, and ANSI colors.
+#[derive(Debug, Clone)]
 pub struct TerminalTheme {
     /// Background color of the terminal.
     pub background_color: ColorTriplet,
@@ -188,6 +191,142 @@ pub static NIGHT_OWLISH: LazyLock<TerminalTheme> = LazyLock::new(|| {
     )
 });
 
+// ---------------------------------------------------------------------------
+// Background detection
+// ---------------------------------------------------------------------------
+
+/// Query the terminal for its background color.
+///
+/// Sends the OSC 11 query to the controlling terminal and waits briefly for
+/// its reply. Terminals that do not answer (or when there is no terminal,
+/// e.g. output is piped) fall back to the `COLORFGBG` environment variable
+/// set by some terminals, which only distinguishes dark from light. Returns
+/// `None` when neither is available.
+///
+/// Use [`is_dark`] to pick dark or light styles from the result.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gilt::terminal_theme::{detect_background, is_dark};
+///
+/// let dark = detect_background().map_or(true, |bg| is_dark(&bg));
+/// ```
+pub fn detect_background() -> Option<ColorTriplet> {
+    query_background().or_else(|| {
+        std::env::var("COLORFGBG")
+            .ok()
+            .and_then(|value| parse_colorfgbg(&value))
+    })
+}
+
+/// Whether `background` is dark, i.e. light text contrasts with it better
+/// than dark text.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::color_triplet::ColorTriplet;
+/// use gilt::terminal_theme::is_dark;
+///
+/// assert!(is_dark(&ColorTriplet::new(40, 42, 54)));
+/// assert!(!is_dark(&ColorTriplet::new(253, 246, 227)));
+/// ```
+pub fn is_dark(background: &ColorTriplet) -> bool {
+    let white = ColorTriplet::new(255, 255, 255);
+    let black = ColorTriplet::new(0, 0, 0);
+    contrast_ratio(&white, background) > contrast_ratio(&black, background)
+}
+
+/// Send the OSC 11 query to `/dev/tty` in raw mode and parse the reply.
+#[cfg(unix)]
+fn query_background() -> Option<ColorTriplet> {
+    use std::fs::OpenOptions;
+    use std::io::{Read, Write};
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let saved = stty(&["-g"])?;
+    // Non-canonical, no echo, and reads give up after 100ms of silence.
+    stty(&["-icanon", "-echo", "min", "0", "time", "1"])?;
+
+    let mut reply = Vec::new();
+    if tty
+        .write_all(b"\x1b]11;?\x07")
+        .and_then(|_| tty.flush())
+        .is_ok()
+    {
+        let mut buf = [0u8; 64];
+        while let Ok(n) = tty.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            reply.extend_from_slice(&buf[..n]);
+            if reply.ends_with(b"\x07") || reply.ends_with(b"\x1b\\") || reply.len() > 256 {
+                break;
+            }
+        }
+    }
+    stty(&[saved.trim()]);
+    parse_osc11(&String::from_utf8_lossy(&reply))
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<ColorTriplet> {
+    None
+}
+
+/// Run `stty` against the controlling terminal, returning its output.
+#[cfg(unix)]
+fn stty(args: &[&str]) -> Option<String> {
+    use std::process::{Command, Stdio};
+
+    let tty = std::fs::File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(tty))
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse an OSC 11 reply such as `ESC ] 11 ; rgb:1e1e/1e1e/2e2e BEL`.
+///
+/// Each channel has one to four hex digits; only the most significant byte
+/// is kept.
+fn parse_osc11(reply: &str) -> Option<ColorTriplet> {
+    let start = reply.find("rgb:")? + 4;
+    let body = reply[start..].trim_end_matches(['\x07', '\x1b', '\\']);
+    let mut channels = body.split('/').map(|channel| {
+        let digits = channel.get(..channel.len().min(4))?;
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        let max = (1u32 << (4 * digits.len() as u32)) - 1;
+        Some((value * 255 / max) as u8)
+    });
+    let red = channels.next()??;
+    let green = channels.next()??;
+    let blue = channels.next()??;
+    Some(ColorTriplet::new(red, green, blue))
+}
+
+/// Parse `COLORFGBG` (`"fg;bg"` or `"fg;default;bg"`), whose last field is
+/// the ANSI index of the background: 7 and 9-15 are light, the rest dark.
+fn parse_colorfgbg(value: &str) -> Option<ColorTriplet> {
+    let background: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    if matches!(background, 7 | 9..=15) {
+        Some(ColorTriplet::new(255, 255, 255))
+    } else {
+        Some(ColorTriplet::new(0, 0, 0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +428,37 @@ mod tests {
         assert_eq!(NIGHT_OWLISH.foreground_color.green, 63);
         assert_eq!(NIGHT_OWLISH.foreground_color.blue, 83);
     }
+
+    #[test]
+    fn test_parse_osc11_four_digit_channels() {
+        let color = parse_osc11("\x1b]11;rgb:ffff/8080/0000\x07").unwrap();
+        assert_eq!((color.red, color.green, color.blue), (255, 128, 0));
+    }
+
+    #[test]
+    fn test_parse_osc11_short_channels_and_st_terminator() {
+        let color = parse_osc11("\x1b]11;rgb:f/80/000\x1b\\").unwrap();
+        assert_eq!((color.red, color.green, color.blue), (255, 128, 0));
+    }
+
+    #[test]
+    fn test_parse_osc11_rejects_garbage() {
+        assert!(parse_osc11("").is_none());
+        assert!(parse_osc11("\x1b]11;rgb:zz/00/00\x07").is_none());
+        assert!(parse_osc11("\x1b]11;rgb:00/00\x07").is_none());
+    }
+
+    #[test]
+    fn test_parse_colorfgbg() {
+        assert!(is_dark(&parse_colorfgbg("15;0").unwrap()));
+        assert!(!is_dark(&parse_colorfgbg("0;15").unwrap()));
+        assert!(!is_dark(&parse_colorfgbg("0;default;7").unwrap()));
+        assert!(parse_colorfgbg("default").is_none());
+    }
+
+    #[test]
+    fn test_is_dark() {
+        assert!(is_dark(&SVG_EXPORT_THEME.background_color));
+        assert!(!is_dark(&DEFAULT_TERMINAL_THEME.background_color));
+    }
 }
@@ -9,7 +9,9 @@ use std::fmt;
 use std::io;
 use std::path::Path;
 
-use crate::default_styles::DEFAULT_STYLES;
+use crate::color::color_triplet::ColorTriplet;
+use crate::color::terminal_theme::is_dark;
+use crate::default_styles::{DEFAULT_GLYPHS, DEFAULT_STYLES};
 use crate::error::StyleError;
 use crate::style::Style;
 
@@ -18,13 +20,16 @@ use crate::style::Style;
 pub struct Theme {
     /// The mapping from style names to Style instances.
     pub styles: HashMap<String, Style>,
+    /// The glyphs that accessible mode prefixes to text styled with a
+    /// given name (e.g. `"error"` to `"✖"`).
+    pub glyphs: HashMap<String, String>,
 }
 
 impl Theme {
     /// Creates a new Theme.
     ///
     /// If `inherit` is true (the default behavior), the theme starts with all
-    /// default styles and then overlays the provided styles on top.
+    /// default styles and glyphs and then overlays the provided styles on top.
     ///
     /// Style values in the `styles` map can be provided as Style instances.
     /// For string-based construction, callers should parse strings into Styles
@@ -40,7 +45,67 @@ impl Theme {
             merged.extend(s);
         }
 
-        Theme { styles: merged }
+        let glyphs = if inherit {
+            DEFAULT_GLYPHS.clone()
+        } else {
+            HashMap::new()
+        };
+
+        Theme {
+            styles: merged,
+            glyphs,
+        }
+    }
+
+    /// Set the glyph shown before text styled with `name` in accessible mode
+    /// (builder pattern).
+    #[must_use]
+    pub fn with_glyph(mut self, name: &str, glyph: &str) -> Self {
+        self.glyphs.insert(name.to_string(), glyph.to_string());
+        self
+    }
+
+    /// Layers this theme on top of `parent` (builder pattern).
+    ///
+    /// Styles and glyphs defined here win; anything missing is taken from
+    /// `parent`. Build the child with `inherit = false` so that only the
+    /// styles it overrides shadow the parent's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use gilt::style::Style;
+    /// use gilt::theme::Theme;
+    ///
+    /// let mut overrides = HashMap::new();
+    /// overrides.insert("rule.line".to_string(), Style::parse("magenta").unwrap());
+    /// let theme = Theme::new(Some(overrides), false).inherit(&Theme::nord());
+    ///
+    /// assert_eq!(theme.get("rule.line"), Some(&Style::parse("magenta").unwrap()));
+    /// assert_eq!(theme.get("table.header"), Theme::nord().get("table.header"));
+    /// ```
+    #[must_use]
+    pub fn inherit(self, parent: &Theme) -> Self {
+        let mut styles = parent.styles.clone();
+        styles.extend(self.styles);
+        let mut glyphs = parent.glyphs.clone();
+        glyphs.extend(self.glyphs);
+        Theme { styles, glyphs }
+    }
+
+    /// The default styles suited to a terminal with the given background:
+    /// the standard defaults on dark backgrounds and
+    /// [`github_light`](Theme::github_light) on light ones.
+    ///
+    /// Pair with [`detect_background`](crate::terminal_theme::detect_background)
+    /// to adapt to the running terminal.
+    pub fn for_background(background: &ColorTriplet) -> Self {
+        if is_dark(background) {
+            Theme::new(None, true)
+        } else {
+            Theme::github_light()
+        }
     }
 
     /// Looks up a style by name.
@@ -48,6 +113,11 @@ impl Theme {
         self.styles.get(name)
     }
 
+    /// Looks up the accessible-mode glyph for a style name.
+    pub fn glyph(&self, name: &str) -> Option<&str> {
+        self.glyphs.get(name).map(String::as_str)
+    }
+
     /// Returns an INI-format config string representing this theme.
     ///
     /// The output is compatible with Python rich's Theme.config property:
@@ -65,6 +135,19 @@ impl Theme {
         for (name, style) in entries {
             result.push_str(&format!("{} = {}\n", name, style));
         }
+
+        let mut glyphs: Vec<(&String, &String)> = self
+            .glyphs
+            .iter()
+            .filter(|(name, glyph)| DEFAULT_GLYPHS.get(*name) != Some(*glyph))
+            .collect();
+        if !glyphs.is_empty() {
+            glyphs.sort();
+            result.push_str("\n[glyphs]\n");
+            for (name, glyph) in glyphs {
+                result.push_str(&format!("{} = {}\n", name, glyph));
+            }
+        }
         result
     }
 
@@ -81,22 +164,28 @@ impl Theme {
     /// info = dim cyan
     /// warning = magenta
     /// danger = bold red
+    ///
+    /// [glyphs]
+    /// danger = ☠
     /// ```
     ///
-    /// Blank lines and `#` comments are ignored. All style definitions
-    /// are parsed via [`Style::parse`].
+    /// Blank lines and `#` or `;` comments are ignored. All style definitions
+    /// are parsed via [`Style::parse`]. The optional `[glyphs]` section sets
+    /// the accessible-mode glyphs (see [`Theme::with_glyph`]).
     ///
     /// The resulting theme does **not** inherit from defaults — it contains
     /// only the styles explicitly listed in the content.
     pub fn from_str(content: &str, inherit: bool) -> Result<Self, ThemeFromStrError> {
         let mut styles = HashMap::new();
+        let mut glyphs = HashMap::new();
         let mut in_styles_section = false;
+        let mut in_glyphs_section = false;
 
         for (line_no, raw_line) in content.lines().enumerate() {
             let line = raw_line.trim();
 
             // Skip blank lines and comments
-            if line.is_empty() || line.starts_with('#') {
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
                 continue;
             }
 
@@ -104,9 +193,22 @@ impl Theme {
             if line.starts_with('[') && line.ends_with(']') {
                 let section = &line[1..line.len() - 1];
                 in_styles_section = section.eq_ignore_ascii_case("styles");
+                in_glyphs_section = section.eq_ignore_ascii_case("glyphs");
                 continue;
             }
 
+            if in_glyphs_section {
+                if let Some((name, glyph)) = line.split_once('=') {
+                    glyphs.insert(name.trim().to_string(), glyph.trim().to_string());
+                    continue;
+                }
+                return Err(ThemeFromStrError::Parse(format!(
+                    "line {}: expected 'name = glyph', got: {}",
+                    line_no + 1,
+                    line
+                )));
+            }
+
             if !in_styles_section {
                 continue;
             }
@@ -138,31 +240,517 @@ impl Theme {
             }
         }
 
-        Ok(Theme::new(Some(styles), inherit))
+        let mut theme = Theme::new(Some(styles), inherit);
+        theme.glyphs.extend(glyphs);
+        Ok(theme)
+    }
+
+    /// Parses TOML theme content into a Theme (requires the `toml` feature).
+    ///
+    /// Expected format, with style names containing dots quoted as TOML
+    /// requires:
+    /// ```text
+    /// [styles]
+    /// info = "dim cyan"
+    /// "repr.number" = "bold blue"
+    ///
+    /// [glyphs]
+    /// danger = "☠"
+    /// ```
+    ///
+    /// Other tables are ignored, as in [`Theme::from_str`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml(content: &str, inherit: bool) -> Result<Self, ThemeFromStrError> {
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e: toml::de::Error| ThemeFromStrError::Parse(e.message().to_string()))?;
+
+        let section = |name: &str| -> Result<Vec<(String, String)>, ThemeFromStrError> {
+            let Some(value) = table.get(name) else {
+                return Ok(Vec::new());
+            };
+            let entries = value
+                .as_table()
+                .ok_or_else(|| ThemeFromStrError::Parse(format!("'{}' must be a table", name)))?;
+            entries
+                .iter()
+                .map(|(key, value)| match value.as_str() {
+                    Some(value) => Ok((key.clone(), value.to_string())),
+                    None => Err(ThemeFromStrError::Parse(format!(
+                        "{}.{}: expected a string, got: {}",
+                        name, key, value
+                    ))),
+                })
+                .collect()
+        };
+
+        let mut styles = HashMap::new();
+        for (name, definition) in section("styles")? {
+            let style = Style::parse(&definition).map_err(|e| ThemeFromStrError::Style {
+                name: name.clone(),
+                source: e,
+            })?;
+            styles.insert(name, style);
+        }
+
+        let mut theme = Theme::new(Some(styles), inherit);
+        theme.glyphs.extend(section("glyphs")?);
+        Ok(theme)
+    }
+
+    /// Returns a TOML config string representing this theme (requires the
+    /// `toml` feature).
+    ///
+    /// The output can be read back with [`Theme::from_toml`]; like
+    /// [`config`](Theme::config), only glyphs that differ from the defaults
+    /// are written.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        let styles: toml::Table = self
+            .styles
+            .iter()
+            .map(|(name, style)| (name.clone(), toml::Value::String(style.to_string())))
+            .collect();
+        let glyphs: toml::Table = self
+            .glyphs
+            .iter()
+            .filter(|(name, glyph)| DEFAULT_GLYPHS.get(*name) != Some(*glyph))
+            .map(|(name, glyph)| (name.clone(), toml::Value::String(glyph.clone())))
+            .collect();
+
+        let mut table = toml::Table::new();
+        table.insert("styles".to_string(), toml::Value::Table(styles));
+        if !glyphs.is_empty() {
+            table.insert("glyphs".to_string(), toml::Value::Table(glyphs));
+        }
+        table.to_string()
+    }
+
+    /// Parses theme content in either format: TOML when the `toml` feature is
+    /// enabled and the content is valid TOML, INI otherwise.
+    fn parse_any(content: &str) -> Result<Self, ThemeFromStrError> {
+        #[cfg(feature = "toml")]
+        if content.parse::<toml::Table>().is_ok() {
+            return Theme::from_toml(content, true);
+        }
+        Theme::from_str(content, true)
     }
 
     /// Reads theme content from a file path.
     ///
-    /// The file should contain INI-style theme content as described in
-    /// [`Theme::from_str`].
+    /// Files ending in `.toml` are read as TOML (see [`Theme::from_toml`],
+    /// which needs the `toml` feature); anything else is read like
+    /// [`Theme::read`].
     pub fn from_file(path: &Path) -> Result<Self, io::Error> {
         let content = std::fs::read_to_string(path)?;
-        Theme::from_str(&content, true)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+        let is_toml = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        let theme = if is_toml {
+            #[cfg(feature = "toml")]
+            {
+                Theme::from_toml(&content, true)
+            }
+            #[cfg(not(feature = "toml"))]
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "TOML themes require the `toml` feature",
+                ));
+            }
+        } else {
+            Theme::parse_any(&content)
+        };
+        theme.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
     }
 
     /// Reads theme content from any reader.
     ///
     /// The reader should provide INI-style theme content as described in
-    /// [`Theme::from_str`].
+    /// [`Theme::from_str`], or TOML as described in [`Theme::from_toml`]
+    /// when the `toml` feature is enabled.
     pub fn read(reader: &mut impl io::Read) -> Result<Self, io::Error> {
         let mut content = String::new();
         reader.read_to_string(&mut content)?;
-        Theme::from_str(&content, true)
+        Theme::parse_any(&content)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
     }
 }
 
+/// Formats the theme as INI content, the same as [`Theme::config`], so
+/// `theme.to_string()` can be read back with [`Theme::from_str`].
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.config())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Built-in themes
+// ---------------------------------------------------------------------------
+
+/// Monokai palette colors.
+pub mod monokai {
+    /// Background color.
+    pub const BG: &str = "#272822";
+    /// Foreground color.
+    pub const FG: &str = "#f8f8f2";
+    /// Red accent.
+    pub const RED: &str = "#f92672";
+    /// Green accent.
+    pub const GREEN: &str = "#a6e22e";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#e6db74";
+    /// Blue accent.
+    pub const BLUE: &str = "#66d9ef";
+    /// Purple accent.
+    pub const PURPLE: &str = "#ae81ff";
+    /// Cyan accent.
+    pub const CYAN: &str = "#a1efe4";
+    /// Orange accent.
+    pub const ORANGE: &str = "#fd971f";
+}
+
+/// Solarized Dark palette colors.
+pub mod solarized_dark {
+    /// Background color (base03).
+    pub const BG: &str = "#002b36";
+    /// Foreground color (base0).
+    pub const FG: &str = "#839496";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#b58900";
+    /// Orange accent.
+    pub const ORANGE: &str = "#cb4b16";
+    /// Red accent.
+    pub const RED: &str = "#dc322f";
+    /// Magenta accent.
+    pub const MAGENTA: &str = "#d33682";
+    /// Violet accent.
+    pub const VIOLET: &str = "#6c71c4";
+    /// Blue accent.
+    pub const BLUE: &str = "#268bd2";
+    /// Cyan accent.
+    pub const CYAN: &str = "#2aa198";
+    /// Green accent.
+    pub const GREEN: &str = "#859900";
+}
+
+/// Solarized Light palette colors.
+pub mod solarized_light {
+    /// Background color (base3).
+    pub const BG: &str = "#fdf6e3";
+    /// Foreground color (base00).
+    pub const FG: &str = "#657b83";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#b58900";
+    /// Orange accent.
+    pub const ORANGE: &str = "#cb4b16";
+    /// Red accent.
+    pub const RED: &str = "#dc322f";
+    /// Magenta accent.
+    pub const MAGENTA: &str = "#d33682";
+    /// Violet accent.
+    pub const VIOLET: &str = "#6c71c4";
+    /// Blue accent.
+    pub const BLUE: &str = "#268bd2";
+    /// Cyan accent.
+    pub const CYAN: &str = "#2aa198";
+    /// Green accent.
+    pub const GREEN: &str = "#859900";
+}
+
+/// Dracula palette colors.
+pub mod dracula {
+    /// Background color.
+    pub const BG: &str = "#282a36";
+    /// Foreground color.
+    pub const FG: &str = "#f8f8f2";
+    /// Red accent.
+    pub const RED: &str = "#ff5555";
+    /// Green accent.
+    pub const GREEN: &str = "#50fa7b";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#f1fa8c";
+    /// Purple accent.
+    pub const PURPLE: &str = "#bd93f9";
+    /// Pink accent.
+    pub const PINK: &str = "#ff79c6";
+    /// Cyan accent.
+    pub const CYAN: &str = "#8be9fd";
+    /// Orange accent.
+    pub const ORANGE: &str = "#ffb86c";
+}
+
+/// Nord palette colors.
+pub mod nord {
+    /// Background color (nord0).
+    pub const BG: &str = "#2e3440";
+    /// Foreground color (nord4).
+    pub const FG: &str = "#d8dee9";
+    /// Frost 1 (nord7).
+    pub const FROST_1: &str = "#8fbcbb";
+    /// Frost 2 (nord8).
+    pub const FROST_2: &str = "#88c0d0";
+    /// Frost 3 (nord9).
+    pub const FROST_3: &str = "#81a1c1";
+    /// Frost 4 (nord10).
+    pub const FROST_4: &str = "#5e81ac";
+    /// Aurora red (nord11).
+    pub const RED: &str = "#bf616a";
+    /// Aurora orange (nord12).
+    pub const ORANGE: &str = "#d08770";
+    /// Aurora yellow (nord13).
+    pub const YELLOW: &str = "#ebcb8b";
+    /// Aurora green (nord14).
+    pub const GREEN: &str = "#a3be8c";
+    /// Aurora purple (nord15).
+    pub const PURPLE: &str = "#b48ead";
+}
+
+/// One Dark palette colors.
+pub mod one_dark {
+    /// Background color.
+    pub const BG: &str = "#282c34";
+    /// Foreground color.
+    pub const FG: &str = "#abb2bf";
+    /// Red accent.
+    pub const RED: &str = "#e06c75";
+    /// Green accent.
+    pub const GREEN: &str = "#98c379";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#e5c07b";
+    /// Blue accent.
+    pub const BLUE: &str = "#61afef";
+    /// Magenta accent.
+    pub const MAGENTA: &str = "#c678dd";
+    /// Cyan accent.
+    pub const CYAN: &str = "#56b6c2";
+}
+
+/// GitHub Dark palette colors.
+pub mod github_dark {
+    /// Background color.
+    pub const BG: &str = "#0d1117";
+    /// Foreground color.
+    pub const FG: &str = "#c9d1d9";
+    /// Blue accent.
+    pub const BLUE: &str = "#58a6ff";
+    /// Green accent.
+    pub const GREEN: &str = "#3fb950";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#d29922";
+    /// Orange accent.
+    pub const ORANGE: &str = "#f0883e";
+    /// Red accent.
+    pub const RED: &str = "#f85149";
+    /// Purple accent.
+    pub const PURPLE: &str = "#bc8cff";
+}
+
+/// GitHub Light palette colors.
+pub mod github_light {
+    /// Background color.
+    pub const BG: &str = "#ffffff";
+    /// Foreground color.
+    pub const FG: &str = "#24292f";
+    /// Blue accent.
+    pub const BLUE: &str = "#0969da";
+    /// Green accent.
+    pub const GREEN: &str = "#1a7f37";
+    /// Yellow accent.
+    pub const YELLOW: &str = "#9a6700";
+    /// Orange accent.
+    pub const ORANGE: &str = "#bc4c00";
+    /// Red accent.
+    pub const RED: &str = "#cf222e";
+    /// Purple accent.
+    pub const PURPLE: &str = "#8250df";
+}
+
+/// Accent colors used to derive a built-in theme from a palette.
+struct PaletteAccents {
+    primary: &'static str,
+    secondary: &'static str,
+    string: &'static str,
+    number: &'static str,
+    warning: &'static str,
+    error: &'static str,
+}
+
+/// Builds a theme (inheriting the defaults) whose semantic styles use the
+/// given palette accents.
+fn palette_theme(accents: PaletteAccents) -> Theme {
+    let PaletteAccents {
+        primary,
+        secondary,
+        string,
+        number,
+        warning,
+        error,
+    } = accents;
+    let definitions = [
+        ("repr.str", string.to_string()),
+        ("repr.number", format!("bold {}", number)),
+        ("repr.bool_true", format!("italic {}", string)),
+        ("repr.bool_false", format!("italic {}", error)),
+        ("repr.none", format!("italic {}", secondary)),
+        ("repr.call", format!("bold {}", secondary)),
+        ("json.key", format!("bold {}", primary)),
+        ("json.str", string.to_string()),
+        ("json.number", format!("bold {}", number)),
+        ("table.header", format!("bold {}", primary)),
+        ("table.title", format!("italic {}", primary)),
+        ("rule.line", primary.to_string()),
+        ("bar.complete", secondary.to_string()),
+        ("bar.finished", string.to_string()),
+        ("bar.pulse", secondary.to_string()),
+        ("progress.percentage", primary.to_string()),
+        ("progress.spinner", secondary.to_string()),
+        ("status.spinner", secondary.to_string()),
+        ("logging.level.info", primary.to_string()),
+        ("logging.level.warning", warning.to_string()),
+        ("logging.level.error", format!("bold {}", error)),
+        ("markdown.h1", format!("bold {}", primary)),
+        ("markdown.h2", format!("bold {}", secondary)),
+        ("markdown.link", format!("underline {}", primary)),
+        ("prompt.choices", format!("bold {}", secondary)),
+        ("traceback.title", format!("bold {}", error)),
+        ("traceback.border", error.to_string()),
+    ];
+    let styles = definitions
+        .iter()
+        .filter_map(|(name, def)| Style::parse(def).ok().map(|s| (name.to_string(), s)))
+        .collect();
+    Theme::new(Some(styles), true)
+}
+
+impl Theme {
+    /// The Monokai theme.
+    pub fn monokai() -> Self {
+        palette_theme(PaletteAccents {
+            primary: monokai::BLUE,
+            secondary: monokai::RED,
+            string: monokai::GREEN,
+            number: monokai::PURPLE,
+            warning: monokai::ORANGE,
+            error: monokai::RED,
+        })
+    }
+
+    /// The Solarized Dark theme.
+    pub fn solarized_dark() -> Self {
+        palette_theme(PaletteAccents {
+            primary: solarized_dark::BLUE,
+            secondary: solarized_dark::MAGENTA,
+            string: solarized_dark::GREEN,
+            number: solarized_dark::CYAN,
+            warning: solarized_dark::YELLOW,
+            error: solarized_dark::RED,
+        })
+    }
+
+    /// The Solarized Light theme.
+    pub fn solarized_light() -> Self {
+        palette_theme(PaletteAccents {
+            primary: solarized_light::BLUE,
+            secondary: solarized_light::VIOLET,
+            string: solarized_light::GREEN,
+            number: solarized_light::CYAN,
+            warning: solarized_light::ORANGE,
+            error: solarized_light::RED,
+        })
+    }
+
+    /// The Dracula theme.
+    pub fn dracula() -> Self {
+        palette_theme(PaletteAccents {
+            primary: dracula::PURPLE,
+            secondary: dracula::PINK,
+            string: dracula::GREEN,
+            number: dracula::CYAN,
+            warning: dracula::ORANGE,
+            error: dracula::RED,
+        })
+    }
+
+    /// The Nord theme.
+    pub fn nord() -> Self {
+        palette_theme(PaletteAccents {
+            primary: nord::FROST_2,
+            secondary: nord::FROST_3,
+            string: nord::GREEN,
+            number: nord::PURPLE,
+            warning: nord::YELLOW,
+            error: nord::RED,
+        })
+    }
+
+    /// The One Dark theme.
+    pub fn one_dark() -> Self {
+        palette_theme(PaletteAccents {
+            primary: one_dark::BLUE,
+            secondary: one_dark::MAGENTA,
+            string: one_dark::GREEN,
+            number: one_dark::CYAN,
+            warning: one_dark::YELLOW,
+            error: one_dark::RED,
+        })
+    }
+
+    /// The GitHub Dark theme.
+    pub fn github_dark() -> Self {
+        palette_theme(PaletteAccents {
+            primary: github_dark::BLUE,
+            secondary: github_dark::PURPLE,
+            string: github_dark::GREEN,
+            number: github_dark::ORANGE,
+            warning: github_dark::YELLOW,
+            error: github_dark::RED,
+        })
+    }
+
+    /// The GitHub Light theme.
+    pub fn github_light() -> Self {
+        palette_theme(PaletteAccents {
+            primary: github_light::BLUE,
+            secondary: github_light::PURPLE,
+            string: github_light::GREEN,
+            number: github_light::ORANGE,
+            warning: github_light::YELLOW,
+            error: github_light::RED,
+        })
+    }
+}
+
+/// Names of all built-in themes, accepted by [`get_builtin_theme`].
+pub fn builtin_themes() -> &'static [&'static str] {
+    &[
+        "monokai",
+        "solarized_dark",
+        "solarized_light",
+        "dracula",
+        "nord",
+        "one_dark",
+        "github_dark",
+        "github_light",
+    ]
+}
+
+/// Looks up a built-in theme by name, returning `None` for unknown names.
+pub fn get_builtin_theme(name: &str) -> Option<Theme> {
+    match name {
+        "monokai" => Some(Theme::monokai()),
+        "solarized_dark" => Some(Theme::solarized_dark()),
+        "solarized_light" => Some(Theme::solarized_light()),
+        "dracula" => Some(Theme::dracula()),
+        "nord" => Some(Theme::nord()),
+        "one_dark" => Some(Theme::one_dark()),
+        "github_dark" => Some(Theme::github_dark()),
+        "github_light" => Some(Theme::github_light()),
+        _ => None,
+    }
+}
+
 /// Error returned when parsing a theme from a string fails.
 #[derive(Debug)]
 pub enum ThemeFromStrError {
@@ -215,6 +803,7 @@ impl std::error::Error for ThemeStackError {}
 /// to restore previous state. The base theme can never be popped.
 pub struct ThemeStack {
     entries: Vec<HashMap<String, Style>>,
+    glyph_entries: Vec<HashMap<String, String>>,
 }
 
 impl ThemeStack {
@@ -222,6 +811,7 @@ impl ThemeStack {
     pub fn new(theme: Theme) -> Self {
         ThemeStack {
             entries: vec![theme.styles],
+            glyph_entries: vec![theme.glyphs],
         }
     }
 
@@ -233,6 +823,15 @@ impl ThemeStack {
             .get(name)
     }
 
+    /// Looks up an accessible-mode glyph by style name in the top-most theme.
+    pub fn get_glyph(&self, name: &str) -> Option<&str> {
+        self.glyph_entries
+            .last()
+            .expect("ThemeStack should never be empty")
+            .get(name)
+            .map(String::as_str)
+    }
+
     /// Pushes a new theme onto the stack.
     ///
     /// If `inherit` is true (the default), the new layer inherits all styles
@@ -241,18 +840,25 @@ impl ThemeStack {
     ///
     /// If `inherit` is false, only the pushed theme's styles are available.
     pub fn push_theme(&mut self, theme: Theme, inherit: bool) {
-        let styles = if inherit {
+        let (styles, glyphs) = if inherit {
             let mut merged = self
                 .entries
                 .last()
                 .expect("ThemeStack should never be empty")
                 .clone();
             merged.extend(theme.styles);
-            merged
+            let mut merged_glyphs = self
+                .glyph_entries
+                .last()
+                .expect("ThemeStack should never be empty")
+                .clone();
+            merged_glyphs.extend(theme.glyphs);
+            (merged, merged_glyphs)
         } else {
-            theme.styles
+            (theme.styles, theme.glyphs)
         };
         self.entries.push(styles);
+        self.glyph_entries.push(glyphs);
     }
 
     /// Pops the top-most theme from the stack.
@@ -263,6 +869,7 @@ impl ThemeStack {
             return Err(ThemeStackError);
         }
         self.entries.pop();
+        self.glyph_entries.pop();
         Ok(())
     }
 }
@@ -463,6 +1070,26 @@ danger = bold red
         assert_eq!(theme.styles.len(), 3);
     }
 
+    #[test]
+    fn test_glyphs() {
+        let theme = Theme::new(None, true).with_glyph("done", "*");
+        assert_eq!(theme.glyph("error"), Some("\u{2716}"));
+        assert_eq!(theme.glyph("done"), Some("*"));
+        assert!(Theme::new(None, false).glyphs.is_empty());
+
+        // Only glyphs that differ from the defaults are exported.
+        assert!(theme.config().contains("[glyphs]\ndone = *\n"));
+        let parsed =
+            Theme::from_str("[styles]\ndone = green\n\n[glyphs]\ndone = *\n", false).unwrap();
+        assert_eq!(parsed.glyph("done"), Some("*"));
+
+        let mut stack = ThemeStack::new(Theme::new(None, true));
+        stack.push_theme(Theme::new(None, false).with_glyph("error", "E"), true);
+        assert_eq!(stack.get_glyph("error"), Some("E"));
+        stack.pop_theme().unwrap();
+        assert_eq!(stack.get_glyph("error"), Some("\u{2716}"));
+    }
+
     #[test]
     fn test_from_str_with_inheritance() {
         let content = "\
@@ -671,6 +1298,83 @@ warning = magenta
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn test_display_round_trips_through_from_str() {
+        let theme = Theme::new(None, true).with_glyph("danger", "☠");
+        let parsed = Theme::from_str(&theme.to_string(), false).unwrap();
+        assert_eq!(parsed.styles, theme.styles);
+        assert_eq!(parsed.glyph("danger"), Some("☠"));
+    }
+
+    #[test]
+    fn test_from_str_semicolon_comments() {
+        let theme =
+            Theme::from_str("; rich theme\n[styles]\n; note\ninfo = cyan\n", false).unwrap();
+        assert_eq!(theme.styles.len(), 1);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let content = r#"
+[styles]
+info = "dim cyan"
+"repr.number" = "bold blue"
+
+[glyphs]
+danger = "☠"
+"#;
+        let theme = Theme::from_toml(content, false).unwrap();
+        assert_eq!(theme.get("info"), Some(&Style::parse("dim cyan").unwrap()));
+        assert_eq!(
+            theme.get("repr.number"),
+            Some(&Style::parse("bold blue").unwrap())
+        );
+        assert_eq!(theme.glyph("danger"), Some("☠"));
+
+        let err = Theme::from_toml("[styles]\ninfo = 3\n", false).unwrap_err();
+        assert!(err.to_string().contains("styles.info"));
+        assert!(matches!(
+            Theme::from_toml("[styles]\ninfo = \"nope nope\"\n", false),
+            Err(ThemeFromStrError::Style { .. })
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_to_toml_round_trips() {
+        let theme = Theme::monokai().with_glyph("danger", "☠");
+        let parsed = Theme::from_toml(&theme.to_toml(), false).unwrap();
+        assert_eq!(parsed.styles, theme.styles);
+        assert_eq!(parsed.glyph("danger"), Some("☠"));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_read_detects_format() {
+        let toml = "[styles]\ninfo = \"dim cyan\"\n";
+        let ini = "[styles]\ninfo = dim cyan\n";
+        for content in [toml, ini] {
+            let theme = Theme::read(&mut std::io::Cursor::new(content)).unwrap();
+            assert_eq!(theme.get("info"), Some(&Style::parse("dim cyan").unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_from_file_toml() {
+        let path = std::env::temp_dir().join("gilt_test_theme.toml");
+        std::fs::write(&path, "[styles]\ninfo = \"dim cyan\"\n").unwrap();
+        let result = Theme::from_file(&path);
+        let _ = std::fs::remove_file(&path);
+        #[cfg(feature = "toml")]
+        assert_eq!(
+            result.unwrap().get("info"),
+            Some(&Style::parse("dim cyan").unwrap())
+        );
+        #[cfg(not(feature = "toml"))]
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+
     #[test]
     fn test_theme_from_str_error_display() {
         let err = ThemeFromStrError::Parse("test message".to_string());
@@ -701,4 +1405,65 @@ progress.elapsed = cyan
         assert!(theme.get("bar.back").is_some());
         assert!(theme.get("progress.elapsed").is_some());
     }
+
+    #[test]
+    fn test_builtin_themes_lookup() {
+        for name in builtin_themes() {
+            let theme = get_builtin_theme(name).unwrap();
+            assert!(theme.get("table.header").is_some());
+            assert!(theme.get("dim").is_some());
+        }
+        assert!(get_builtin_theme("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_builtin_theme_uses_palette() {
+        let theme = Theme::monokai();
+        assert_eq!(
+            theme.get("rule.line").unwrap(),
+            &Style::parse(monokai::BLUE).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_inherit_child_wins_and_parent_fills_gaps() {
+        let mut custom = HashMap::new();
+        custom.insert("rule.line".to_string(), Style::parse("red").unwrap());
+        let parent = Theme::dracula();
+        let theme = Theme::new(Some(custom), false)
+            .with_glyph("error", "!")
+            .inherit(&parent);
+
+        assert_eq!(theme.get("rule.line"), Some(&Style::parse("red").unwrap()));
+        assert_eq!(theme.get("table.header"), parent.get("table.header"));
+        assert_eq!(theme.glyph("error"), Some("!"));
+        assert_eq!(theme.glyph("warning"), parent.glyph("warning"));
+    }
+
+    #[test]
+    fn test_inherit_chains() {
+        let mut base = HashMap::new();
+        base.insert("a".to_string(), Style::parse("red").unwrap());
+        base.insert("b".to_string(), Style::parse("red").unwrap());
+        let mut middle = HashMap::new();
+        middle.insert("b".to_string(), Style::parse("green").unwrap());
+        let theme = Theme::new(Some(middle), false).inherit(&Theme::new(Some(base), false));
+
+        assert_eq!(theme.get("a"), Some(&Style::parse("red").unwrap()));
+        assert_eq!(theme.get("b"), Some(&Style::parse("green").unwrap()));
+    }
+
+    #[test]
+    fn test_for_background() {
+        let dark = Theme::for_background(&ColorTriplet::new(0, 0, 0));
+        let light = Theme::for_background(&ColorTriplet::new(255, 255, 255));
+        assert_eq!(
+            dark.get("table.header"),
+            Theme::new(None, true).get("table.header")
+        );
+        assert_eq!(
+            light.get("table.header"),
+            Theme::github_light().get("table.header")
+        );
+    }
 }
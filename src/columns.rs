@@ -3,14 +3,179 @@
 //! Port of Python's `rich/columns.py`. Uses `Table::grid()` internally
 //! to lay out items in a grid of columns that fits within the available
 //! console width.
+//!
+//! With [`Columns::with_masonry`] (or any item placed through
+//! [`Columns::add_to_column`]) items are instead stacked into balanced
+//! columns: each item is measured at the column width and packed so the
+//! columns end at similar heights, which suits blocks of uneven height such
+//! as cards or panels.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::measure::{Measure, Measurement};
+use crate::panel::Panel;
 use crate::segment::Segment;
-use crate::table::{ColumnOptions, Table};
+use crate::style::Style;
+use crate::table::{CellContent, ColumnOptions, Table};
 use crate::text::{JustifyMethod, Text};
 
+// ---------------------------------------------------------------------------
+// ColumnItem
+// ---------------------------------------------------------------------------
+
+/// One item laid out by [`Columns`].
+///
+/// Strings are parsed as markup. Panels and other renderables are rendered at
+/// the width of the column they land in, so they adapt to the console width.
+#[derive(Debug, Clone)]
+pub struct ColumnItem {
+    content: ItemContent,
+    /// Column the item is pinned to in masonry layout.
+    column: Option<usize>,
+}
+
+#[derive(Clone)]
+enum ItemContent {
+    Markup(String),
+    Panel(Box<Panel>),
+    Renderable(Arc<dyn Renderable + Send + Sync>),
+}
+
+impl ColumnItem {
+    /// Wrap any renderable, such as a [`Table`] or a [`Text`].
+    pub fn new<R: Renderable + Send + Sync + 'static>(renderable: R) -> Self {
+        ColumnItem {
+            content: ItemContent::Renderable(Arc::new(renderable)),
+            column: None,
+        }
+    }
+
+    /// The markup of an item added as a string, or `None` for other items.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.content {
+            ItemContent::Markup(markup) => Some(markup),
+            _ => None,
+        }
+    }
+
+    /// Natural width of the item.
+    fn width(&self, console: &Console, options: &ConsoleOptions) -> usize {
+        match &self.content {
+            ItemContent::Markup(markup) => {
+                let text = console.render_str(markup, None, None, None);
+                Measure::measure(&text, console, options).maximum
+            }
+            ItemContent::Panel(panel) => panel.measure(console, options).maximum,
+            ItemContent::Renderable(renderable) => {
+                let options = options.reset_height();
+                console
+                    .render_lines(&**renderable, Some(&options), None, false, false)
+                    .iter()
+                    .map(|line| {
+                        let text: String = line.iter().map(|s| s.text.as_str()).collect();
+                        crate::cells::cell_len(text.trim_end())
+                    })
+                    .max()
+                    .unwrap_or(0)
+            }
+        }
+    }
+
+    /// A panel or renderable rendered exactly `width` cells wide with its
+    /// styles, or `None` for markup. Panels stretch to the width when
+    /// `expand` is set.
+    fn fit_text(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+        width: usize,
+        expand: bool,
+    ) -> Option<Text> {
+        let options = options.update_width(width);
+        let lines = match &self.content {
+            ItemContent::Markup(_) => return None,
+            ItemContent::Panel(panel) => {
+                let panel = panel.clone().with_expand(expand);
+                console.render_lines(&panel, Some(&options), None, true, false)
+            }
+            ItemContent::Renderable(renderable) => {
+                console.render_lines(&**renderable, Some(&options), None, true, false)
+            }
+        };
+        let mut text = Text::empty();
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                text.append_str("\n", None);
+            }
+            for segment in line.iter().filter(|s| s.control.is_none()) {
+                text.append_str(&segment.text, segment.style.clone());
+            }
+        }
+        Some(text)
+    }
+
+    /// The item as text for a grid cell; renderables become their plain
+    /// rendering at the available width.
+    fn to_text(&self, console: &Console, options: &ConsoleOptions) -> Text {
+        let renderable: &dyn Renderable = match &self.content {
+            ItemContent::Markup(markup) => return console.render_str(markup, None, None, None),
+            ItemContent::Panel(panel) => &**panel,
+            ItemContent::Renderable(renderable) => &**renderable,
+        };
+        let lines = console.render_lines(renderable, Some(options), None, false, false);
+        let plain: Vec<String> = lines
+            .iter()
+            .map(|line| line.iter().map(|seg| seg.text.as_str()).collect())
+            .collect();
+        Text::new(&plain.join("\n"), Style::null())
+    }
+}
+
+impl std::fmt::Debug for ItemContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemContent::Markup(markup) => f.debug_tuple("Markup").field(markup).finish(),
+            ItemContent::Panel(panel) => f.debug_tuple("Panel").field(panel).finish(),
+            ItemContent::Renderable(_) => f.write_str("Renderable(..)"),
+        }
+    }
+}
+
+impl From<&str> for ColumnItem {
+    fn from(markup: &str) -> Self {
+        ColumnItem {
+            content: ItemContent::Markup(markup.to_string()),
+            column: None,
+        }
+    }
+}
+
+impl From<String> for ColumnItem {
+    fn from(markup: String) -> Self {
+        ColumnItem {
+            content: ItemContent::Markup(markup),
+            column: None,
+        }
+    }
+}
+
+impl From<Panel> for ColumnItem {
+    fn from(panel: Panel) -> Self {
+        ColumnItem {
+            content: ItemContent::Panel(Box::new(panel)),
+            column: None,
+        }
+    }
+}
+
+impl PartialEq<&str> for ColumnItem {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == Some(*other)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Columns
 // ---------------------------------------------------------------------------
@@ -18,11 +183,13 @@ use crate::text::{JustifyMethod, Text};
 /// Display renderables in neat columns.
 ///
 /// Items are laid out in a grid that auto-fits the available console width.
-/// Internally a `Table::grid()` is used for rendering.
+/// Internally a `Table::grid()` is used for rendering. In
+/// [masonry](Self::with_masonry) mode items are stacked into columns of
+/// balanced height instead.
 #[derive(Debug, Clone)]
 pub struct Columns {
-    /// The renderable items (stored as strings, converted to Text on demand).
-    pub renderables: Vec<String>,
+    /// The items to lay out, in order.
+    pub renderables: Vec<ColumnItem>,
     /// Fixed column width, or `None` for auto-detect.
     pub width: Option<usize>,
     /// Padding around cells `(top, right, bottom, left)`.
@@ -39,6 +206,12 @@ pub struct Columns {
     pub align: Option<JustifyMethod>,
     /// Optional title displayed above the columns.
     pub title: Option<String>,
+    /// Explicit number of columns, or `None` to fit as many as the width allows.
+    column_count: Option<usize>,
+    /// Upper limit on the number of fitted columns.
+    max_columns: Option<usize>,
+    /// Stack items into height-balanced columns instead of a row grid.
+    masonry: bool,
 }
 
 impl Columns {
@@ -54,12 +227,36 @@ impl Columns {
             right_to_left: false,
             align: None,
             title: None,
+            column_count: None,
+            max_columns: None,
+            masonry: false,
         }
     }
 
     /// Add a renderable item (as a string).
     pub fn add_renderable(&mut self, text: &str) {
-        self.renderables.push(text.to_string());
+        self.renderables.push(ColumnItem::from(text));
+    }
+
+    /// Add a panel, rendered at the width of the column it lands in.
+    ///
+    /// Unlike a pre-rendered string, the panel adapts to the console width
+    /// at render time; in masonry layout with [`expand`](Self::expand) it
+    /// stretches to fill its column.
+    pub fn add_panel(&mut self, panel: Panel) {
+        self.renderables.push(ColumnItem::from(panel));
+    }
+
+    /// Add an item pinned to column `column` (zero-based).
+    ///
+    /// Accepts markup strings, panels, or any renderable wrapped in a
+    /// [`ColumnItem`]. Pinning switches the layout to masonry. Columns beyond
+    /// the rendered column count fall back to the last column.
+    pub fn add_to_column(&mut self, column: usize, item: impl Into<ColumnItem>) {
+        let mut item = item.into();
+        item.column = Some(column);
+        self.renderables.push(item);
+        self.masonry = true;
     }
 
     /// Set the fixed column width.
@@ -118,19 +315,43 @@ impl Columns {
         self
     }
 
+    /// Use exactly `count` columns instead of fitting them to the width.
+    #[must_use]
+    pub fn with_column_count(mut self, count: usize) -> Self {
+        self.column_count = Some(count.max(1));
+        self
+    }
+
+    /// Fit at most `count` columns, fewer when the width does not allow it.
+    #[must_use]
+    pub fn with_max_columns(mut self, count: usize) -> Self {
+        self.max_columns = Some(count.max(1));
+        self
+    }
+
+    /// Set whether to stack items into height-balanced columns.
+    ///
+    /// Items go to the currently shortest column, or with
+    /// [`column_first`](Self::column_first) fill the columns in order,
+    /// top-to-bottom, split so the columns end at similar heights.
+    #[must_use]
+    pub fn with_masonry(mut self, masonry: bool) -> Self {
+        self.masonry = masonry;
+        self
+    }
+
     /// Iterate renderables in the order determined by `column_first`.
     ///
-    /// Yields `(renderable_width, Option<&str>)` tuples. When `column_first`
+    /// Yields `(renderable_width, Option<index>)` tuples. When `column_first`
     /// is true, items fill columns top-to-bottom then left-to-right.
     /// Incomplete final rows are padded with `(0, None)`.
-    fn iter_renderables<'a>(
+    fn iter_renderables(
         &self,
         column_count: usize,
-        renderable_widths: &'a [usize],
-        renderables: &'a [Text],
-    ) -> Vec<(usize, Option<&'a Text>)> {
-        let item_count = renderables.len();
-        let mut result: Vec<(usize, Option<&'a Text>)> = Vec::new();
+        renderable_widths: &[usize],
+    ) -> Vec<(usize, Option<usize>)> {
+        let item_count = renderable_widths.len();
+        let mut result: Vec<(usize, Option<usize>)> = Vec::new();
 
         if self.column_first {
             // Distribute items into columns top-to-bottom
@@ -160,12 +381,12 @@ impl Columns {
                         break;
                     }
                     let idx = index as usize;
-                    result.push((renderable_widths[idx], Some(&renderables[idx])));
+                    result.push((renderable_widths[idx], Some(idx)));
                 }
             }
         } else {
-            for (i, renderable) in renderables.iter().enumerate() {
-                result.push((renderable_widths[i], Some(renderable)));
+            for (i, &width) in renderable_widths.iter().enumerate() {
+                result.push((width, Some(i)));
             }
         }
 
@@ -180,6 +401,268 @@ impl Columns {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Measurement
+// ---------------------------------------------------------------------------
+
+impl Columns {
+    /// Natural width of each item.
+    fn item_widths(&self, console: &Console, options: &ConsoleOptions) -> Vec<usize> {
+        self.renderables
+            .iter()
+            .map(|item| item.width(console, options))
+            .collect()
+    }
+
+    /// Measure the columns: the widest item up to every item on one line.
+    pub fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let widths = match self.width {
+            Some(width) => vec![width; self.renderables.len()],
+            None => self.item_widths(console, options),
+        };
+        let (_, right, _, left) = self.padding;
+        let gap = right.max(left);
+        let widest = widths.iter().copied().max().unwrap_or(0);
+        let total = widths.iter().sum::<usize>() + gap * widths.len().saturating_sub(1);
+        let maximum = if self.expand {
+            options.max_width
+        } else {
+            total
+        };
+        Measurement::new(widest, maximum.max(widest)).clamp(None, Some(options.max_width))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Masonry layout
+// ---------------------------------------------------------------------------
+
+impl Columns {
+    /// Width of each masonry column.
+    ///
+    /// Columns are as wide as the widest item (or the fixed
+    /// [`width`](Self::width)); when expanding, the available width is split
+    /// evenly with leftover cells going to the leftmost columns.
+    fn masonry_widths(&self, natural: usize, max_width: usize, gap: usize) -> Vec<usize> {
+        let pinned = self
+            .renderables
+            .iter()
+            .filter_map(|item| item.column)
+            .map(|c| c + 1)
+            .max();
+        let count = match self.column_count {
+            Some(count) => count,
+            None => {
+                let mut count = self.fit_limit();
+                while count > 1 && count * natural + (count - 1) * gap > max_width {
+                    count -= 1;
+                }
+                count.max(pinned.unwrap_or(1))
+            }
+        }
+        .max(1);
+        self.equal_widths(count, natural, max_width, gap)
+    }
+
+    /// Widths of `count` equal columns: `natural` each, or when expanding an
+    /// even split of the available width with leftover cells going to the
+    /// leftmost columns.
+    fn equal_widths(
+        &self,
+        count: usize,
+        natural: usize,
+        max_width: usize,
+        gap: usize,
+    ) -> Vec<usize> {
+        let available = max_width.saturating_sub((count - 1) * gap);
+        let (base, extra) = (available / count, available % count);
+        (0..count)
+            .map(|i| {
+                let even = base + usize::from(i < extra);
+                if self.expand {
+                    even.max(1)
+                } else {
+                    natural.min(base).max(1)
+                }
+            })
+            .collect()
+    }
+
+    /// Most columns an auto-fitted layout may use.
+    fn fit_limit(&self) -> usize {
+        self.renderables
+            .len()
+            .min(self.max_columns.unwrap_or(usize::MAX))
+    }
+
+    /// Distribute items over `count` columns, returning item indices per column.
+    ///
+    /// Pinned items are placed first. The rest go to the shortest column, or
+    /// with `column_first` are split in order at the smallest height that
+    /// fits every item.
+    fn masonry_assign(&self, heights: &[usize], count: usize, spacing: usize) -> Vec<Vec<usize>> {
+        let mut columns: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut column_heights = vec![0usize; count];
+        let place =
+            |columns: &mut [Vec<usize>], column_heights: &mut [usize], col: usize, idx: usize| {
+                if !columns[col].is_empty() {
+                    column_heights[col] += spacing;
+                }
+                column_heights[col] += heights[idx];
+                columns[col].push(idx);
+            };
+
+        let mut free = Vec::new();
+        for idx in 0..heights.len() {
+            match self.renderables[idx].column {
+                Some(col) => place(&mut columns, &mut column_heights, col.min(count - 1), idx),
+                None => free.push(idx),
+            }
+        }
+
+        if self.column_first {
+            // Sequential fill: each column takes items until the next one would
+            // push it past `limit`. Find the smallest limit that fits them all.
+            let fill = |limit: usize| -> Option<Vec<Vec<usize>>> {
+                let mut columns = columns.clone();
+                let mut column_heights = column_heights.clone();
+                let mut col = 0;
+                for &idx in &free {
+                    while col < count {
+                        let extra = if columns[col].is_empty() { 0 } else { spacing };
+                        if column_heights[col] + extra + heights[idx] <= limit {
+                            break;
+                        }
+                        col += 1;
+                    }
+                    if col == count {
+                        return None;
+                    }
+                    place(&mut columns, &mut column_heights, col, idx);
+                }
+                Some(columns)
+            };
+            let mut low = heights.iter().copied().max().unwrap_or(0);
+            let mut high = heights.iter().sum::<usize>()
+                + spacing * heights.len()
+                + column_heights.iter().copied().max().unwrap_or(0);
+            while low < high {
+                let mid = (low + high) / 2;
+                if fill(mid).is_some() {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            if let Some(filled) = fill(low) {
+                columns = filled;
+            }
+        } else {
+            for idx in free {
+                let col = (0..count)
+                    .min_by_key(|&c| (column_heights[c], c))
+                    .unwrap_or(0);
+                place(&mut columns, &mut column_heights, col, idx);
+            }
+        }
+
+        for column in &mut columns {
+            column.sort_unstable();
+        }
+        columns
+    }
+
+    /// Render items stacked into height-balanced columns.
+    fn render_masonry(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let (top, right, bottom, left) = self.padding;
+        let gap = right.max(left);
+        let spacing = top.max(bottom);
+        let max_width = options.max_width;
+
+        let natural = self.width.unwrap_or_else(|| {
+            self.item_widths(console, options)
+                .into_iter()
+                .max()
+                .unwrap_or(0)
+        });
+        let widths = self.masonry_widths(natural, max_width, gap);
+        let narrowest = widths.iter().copied().min().unwrap_or(1);
+
+        let render = |renderable: &dyn Renderable, width: usize| -> Vec<Vec<Segment>> {
+            let item_options = options.update_width(width);
+            let lines = console.render_lines(renderable, Some(&item_options), None, true, false);
+            Segment::set_shape(&lines, width, None, None, false)
+        };
+        // Panels are stretched to fill their column when the columns expand.
+        let render_item = |idx: usize, width: usize| match &self.renderables[idx].content {
+            ItemContent::Markup(markup) => {
+                let mut text = console.render_str(markup, None, None, None);
+                text.justify = self.align;
+                render(&text, width)
+            }
+            ItemContent::Panel(panel) => render(&panel.clone().with_expand(self.expand), width),
+            ItemContent::Renderable(renderable) => render(&**renderable, width),
+        };
+        let heights: Vec<usize> = (0..self.renderables.len())
+            .map(|idx| render_item(idx, narrowest).len())
+            .collect();
+        let assignment = self.masonry_assign(&heights, widths.len(), spacing);
+
+        let stacks: Vec<Vec<Vec<Segment>>> = assignment
+            .iter()
+            .zip(&widths)
+            .map(|(items, &width)| {
+                let mut lines = Vec::new();
+                for (i, &idx) in items.iter().enumerate() {
+                    if i > 0 {
+                        for _ in 0..spacing {
+                            lines.push(vec![Segment::text(&" ".repeat(width))]);
+                        }
+                    }
+                    lines.extend(render_item(idx, width));
+                }
+                lines
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..widths.len()).collect();
+        if self.right_to_left {
+            order.reverse();
+        }
+        let total_width = widths.iter().sum::<usize>() + gap * (widths.len() - 1);
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let gap_text = " ".repeat(gap);
+        let mut segments = Vec::new();
+
+        if let Some(ref title) = self.title {
+            let title_style = console
+                .get_style("table.title")
+                .unwrap_or_else(|_| Style::null());
+            let mut title_text =
+                console.render_str(title, Some(&title_style.to_string()), None, None);
+            title_text.justify = Some(JustifyMethod::Center);
+            for line in render(&title_text, total_width) {
+                segments.extend(line);
+                segments.push(Segment::line());
+            }
+        }
+
+        for y in 0..height {
+            for (i, &col) in order.iter().enumerate() {
+                if i > 0 && gap > 0 {
+                    segments.push(Segment::text(&gap_text));
+                }
+                match stacks[col].get(y) {
+                    Some(line) => segments.extend(line.iter().cloned()),
+                    None => segments.push(Segment::text(&" ".repeat(widths[col]))),
+                }
+            }
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
 impl Default for Columns {
     fn default() -> Self {
         Self::new()
@@ -188,36 +671,55 @@ impl Default for Columns {
 
 impl Renderable for Columns {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
-        // Convert all string renderables to Text
+        if self.renderables.is_empty() {
+            return Vec::new();
+        }
+        if self.masonry {
+            return self.render_masonry(console, options);
+        }
+
+        // Convert all items to Text; renderables become their plain
+        // rendering at the available width.
         let renderables: Vec<Text> = self
             .renderables
             .iter()
-            .map(|s| console.render_str(s, None, None, None))
+            .map(|item| item.to_text(console, options))
             .collect();
 
-        if renderables.is_empty() {
-            return Vec::new();
-        }
-
         let (_top, right, _bottom, left) = self.padding;
         let width_padding = right.max(left);
         let max_width = options.max_width;
 
         // Measure each renderable's maximum width
-        let mut renderable_widths: Vec<usize> =
-            renderables.iter().map(|r| r.measure().maximum).collect();
+        let mut renderable_widths: Vec<usize> = renderables
+            .iter()
+            .map(|r| Measure::measure(r, console, options).maximum)
+            .collect();
 
-        // If equal, set all widths to the max
+        // If equal, set all widths to the max. Panels and other renderables
+        // count at their natural width, as they are rendered to fit the column.
         if self.equal {
-            let max_w = renderable_widths.iter().copied().max().unwrap_or(0);
+            let max_w = self
+                .renderables
+                .iter()
+                .zip(&renderable_widths)
+                .map(|(item, &width)| match item.content {
+                    ItemContent::Markup(_) => width,
+                    _ => item.width(console, options),
+                })
+                .max()
+                .unwrap_or(0);
             renderable_widths = vec![max_w; renderable_widths.len()];
         }
 
-        let mut column_count = renderables.len();
+        let mut column_count = self.fit_limit();
 
-        if let Some(fixed_w) = self.width {
+        if let Some(count) = self.column_count {
+            column_count = count.min(renderables.len());
+        } else if let Some(fixed_w) = self.width {
             // Fixed width mode: calculate column count from width
-            column_count = max_width / (fixed_w + width_padding);
+            column_count =
+                (max_width / (fixed_w + width_padding)).min(self.max_columns.unwrap_or(usize::MAX));
             if column_count == 0 {
                 column_count = 1;
             }
@@ -226,7 +728,7 @@ impl Renderable for Columns {
             while column_count > 1 {
                 let mut widths: HashMap<usize, usize> = HashMap::new();
                 let mut column_no: usize = 0;
-                let items = self.iter_renderables(column_count, &renderable_widths, &renderables);
+                let items = self.iter_renderables(column_count, &renderable_widths);
                 let mut fits = true;
 
                 for (renderable_width, _) in &items {
@@ -254,9 +756,11 @@ impl Renderable for Columns {
         }
 
         // Get the renderables in the correct order
-        let items = self.iter_renderables(column_count, &renderable_widths, &renderables);
-        let mut final_renderables: Vec<Option<Text>> =
-            items.into_iter().map(|(_, r)| r.cloned()).collect();
+        let items = self.iter_renderables(column_count, &renderable_widths);
+        let mut final_renderables: Vec<Option<Text>> = items
+            .iter()
+            .map(|&(_, index)| index.map(|i| renderables[i].clone()))
+            .collect();
 
         // If equal, constrain each renderable to the equal width by truncating
         if self.equal {
@@ -282,6 +786,39 @@ impl Renderable for Columns {
                 .collect();
         }
 
+        // Column widths known up front: the fixed width, or the equal width
+        // stretched to fill the available width when expanding.
+        let column_widths = match self.width {
+            Some(fixed_w) => Some(vec![fixed_w; column_count]),
+            None if self.equal => {
+                let natural = renderable_widths.first().copied().unwrap_or(0);
+                Some(self.equal_widths(column_count, natural, max_width, width_padding))
+            }
+            None => None,
+        };
+
+        // Panels and other renderables are rendered to fit a known column
+        // width, keeping their styles.
+        let cells: Vec<CellContent> = final_renderables
+            .iter()
+            .zip(&items)
+            .enumerate()
+            .map(|(slot, (text, &(_, index)))| {
+                let fitted = column_widths
+                    .as_ref()
+                    .zip(index)
+                    .and_then(|(widths, index)| {
+                        let width = widths[slot % column_count];
+                        self.renderables[index].fit_text(console, options, width, self.expand)
+                    });
+                match (fitted, text) {
+                    (Some(fitted), _) => CellContent::Styled(fitted),
+                    (None, Some(text)) => CellContent::Plain(text.plain().to_string()),
+                    (None, None) => CellContent::Plain(String::new()),
+                }
+            })
+            .collect();
+
         // Build the table grid
         let mut table = Table::grid(&[]);
         table.padding = self.padding;
@@ -291,13 +828,16 @@ impl Renderable for Columns {
         table.title = self.title.clone();
 
         // Add columns
-        if let Some(fixed_w) = self.width {
-            for _ in 0..column_count {
+        if let Some(mut widths) = column_widths {
+            if self.right_to_left {
+                widths.reverse();
+            }
+            for width in widths {
                 table.add_column(
                     "",
                     "",
                     ColumnOptions {
-                        width: Some(fixed_w),
+                        width: Some(width),
                         ..Default::default()
                     },
                 );
@@ -309,24 +849,15 @@ impl Renderable for Columns {
         }
 
         // Build rows
-        for start in (0..final_renderables.len()).step_by(column_count) {
-            let end = (start + column_count).min(final_renderables.len());
-            let mut row_strings: Vec<String> = Vec::new();
-
-            for item in &final_renderables[start..end] {
-                match item {
-                    Some(text) => row_strings.push(text.plain().to_string()),
-                    None => row_strings.push(String::new()),
-                }
-            }
+        for row in cells.chunks(column_count) {
+            let mut row = row.to_vec();
 
             // Handle right_to_left by reversing the row
             if self.right_to_left {
-                row_strings.reverse();
+                row.reverse();
             }
 
-            let row: Vec<&str> = row_strings.iter().map(|s| s.as_str()).collect();
-            table.add_row(&row);
+            table.add_row_contents(&row, None, false);
         }
 
         // Render the table
@@ -711,6 +1242,191 @@ mod tests {
         assert!(output.contains("file2.txt"));
     }
 
+    // -- Explicit column count ----------------------------------------------
+
+    #[test]
+    fn test_column_count_grid() {
+        let mut cols = Columns::new().with_column_count(2);
+        for item in ["a", "b", "c", "d"] {
+            cols.add_renderable(item);
+        }
+        let output = render_columns(&cols, 80);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["a  b", "c  d"]);
+    }
+
+    // -- Masonry layout -----------------------------------------------------
+
+    #[test]
+    fn test_masonry_balances_heights() {
+        let mut cols = Columns::new().with_masonry(true).with_column_count(2);
+        cols.add_renderable("A1\nA2\nA3");
+        cols.add_renderable("B1");
+        cols.add_renderable("C1");
+        cols.add_renderable("D1\nD2");
+        let output = render_columns(&cols, 40);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        // B and C go under each other beside A; D goes to the (then) shorter
+        // right column.
+        assert_eq!(lines, vec!["A1 B1", "A2 C1", "A3 D1", "   D2"]);
+    }
+
+    #[test]
+    fn test_masonry_column_first() {
+        let mut cols = Columns::new()
+            .with_masonry(true)
+            .with_column_first(true)
+            .with_column_count(2);
+        for item in ["a", "b", "c", "d\nd", "e"] {
+            cols.add_renderable(item);
+        }
+        let output = render_columns(&cols, 40);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        // In order, split at the smallest height holding everything (3 lines).
+        assert_eq!(lines, vec!["a d", "b d", "c e"]);
+    }
+
+    #[test]
+    fn test_masonry_add_to_column() {
+        let mut cols = Columns::new().with_column_count(3);
+        cols.add_to_column(2, "pinned");
+        cols.add_renderable("x");
+        cols.add_renderable("y");
+        assert!(cols.masonry);
+        assert_eq!(cols.renderables[0], "pinned");
+        let output = render_columns(&cols, 40);
+        let first = output.lines().next().unwrap();
+        assert!(first.trim_end().ends_with("pinned"), "got {first:?}");
+        assert!(first.starts_with('x'));
+    }
+
+    #[test]
+    fn test_masonry_pinned_column_adds_columns() {
+        let mut cols = Columns::new();
+        cols.add_renderable("a");
+        cols.add_to_column(3, "z");
+        let output = render_columns(&cols, 40);
+        let line = output.lines().next().unwrap().trim_end();
+        // Four one-cell columns: the pin to column 3 widens the layout.
+        assert_eq!(line, "a     z");
+    }
+
+    #[test]
+    fn test_masonry_expand_fills_width_and_title() {
+        let mut cols = Columns::new()
+            .with_masonry(true)
+            .with_expand(true)
+            .with_column_count(2)
+            .with_title("Cards");
+        cols.add_renderable("left");
+        cols.add_renderable("right");
+        let output = render_columns(&cols, 21);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].trim(), "Cards");
+        assert_eq!(cell_len_of(lines[1]), 21);
+        assert!(lines[1].starts_with("left"));
+        assert_eq!(&lines[1][11..16], "right");
+    }
+
+    #[test]
+    fn test_masonry_right_to_left() {
+        let mut cols = Columns::new()
+            .with_masonry(true)
+            .with_right_to_left(true)
+            .with_column_count(2);
+        cols.add_renderable("1");
+        cols.add_renderable("2");
+        let output = render_columns(&cols, 40);
+        assert_eq!(output.lines().next().unwrap().trim_end(), "2 1");
+    }
+
+    #[test]
+    fn test_panels_follow_column_width() {
+        let card = || Panel::new(Text::new("alpha beta gamma", Style::null())).with_expand(false);
+        let mut cols = Columns::new().with_masonry(true).with_column_count(2);
+        cols.add_panel(card());
+        cols.add_panel(card());
+        assert!(cols.renderables.iter().all(|item| item.as_str().is_none()));
+
+        // Wide enough: cards at their natural width, side by side.
+        let output = render_columns(&cols, 60);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].matches("alpha beta gamma").count(), 2);
+
+        // Narrow console: the cards wrap inside their columns instead of
+        // being clipped.
+        let output = render_columns(&cols, 25);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines.len() > 3, "{output}");
+        assert!(lines.iter().all(|l| cell_len_of(l) <= 25));
+        assert_eq!(lines[0].matches('╭').count(), 2);
+
+        // Expanding stretches the cards to fill the width.
+        let output = render_columns(&cols.with_expand(true), 60);
+        let first = output.lines().next().unwrap();
+        assert_eq!(cell_len_of(first), 60);
+        assert!(first.ends_with('╮'));
+    }
+
+    #[test]
+    fn test_panels_in_grid() {
+        let mut cols = Columns::new();
+        cols.add_panel(Panel::new(Text::new("x", Style::null())).with_expand(false));
+        cols.add_renderable("y");
+        let output = render_columns(&cols, 40);
+        assert!(output.contains("│ x │"), "{output}");
+        assert!(output.contains('y'));
+    }
+
+    #[test]
+    fn test_renderables_follow_column_width() {
+        let mut cols = Columns::new().with_masonry(true).with_column_count(2);
+        cols.renderables
+            .push(ColumnItem::new(Text::new("alpha beta", Style::null())));
+        cols.add_renderable("x");
+        let output = render_columns(&cols, 12);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["alpha x", "beta"]);
+    }
+
+    #[test]
+    fn test_equal_grid_fits_panels_to_columns() {
+        let card = |s: &str| Panel::new(Text::new(s, Style::null()));
+        let mut cols = Columns::new()
+            .with_equal(true)
+            .with_expand(true)
+            .with_max_columns(2);
+        cols.add_panel(card("a"));
+        cols.add_panel(card("bbb"));
+        cols.add_panel(card("c"));
+        let output = render_columns(&cols, 20);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "╭────────╮ ╭───────╮");
+        assert_eq!(lines[3].trim_end(), "╭────────╮");
+    }
+
+    #[test]
+    fn test_add_panel_to_column() {
+        let mut cols = Columns::new().with_column_count(2);
+        cols.add_to_column(
+            1,
+            Panel::new(Text::new("p", Style::null())).with_expand(false),
+        );
+        cols.add_renderable("x");
+        let output = render_columns(&cols, 40);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        // Both columns are as wide as the panel.
+        assert_eq!(lines[0], "x     ╭───╮");
+        assert_eq!(lines[1], "      │ p │");
+    }
+
+    fn cell_len_of(line: &str) -> usize {
+        crate::cells::cell_len(line)
+    }
+
     // -- Rendering integration tests ----------------------------------------
 
     #[test]
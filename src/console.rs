@@ -3,13 +3,22 @@
 //! The Console manages terminal capabilities, drives the rendering pipeline,
 //! and handles output buffering, capture, and export.
 
+use crate::capabilities::Capabilities;
 use crate::cells::cell_len;
-use crate::color::ColorSystem;
-use crate::color_env::{detect_color_env, ColorEnvOverride};
-use crate::control::Control;
+use crate::color::{ColorSystem, DownsampleStrategy};
+use crate::color_env::{detect_color_env_source, ColorEnvOverride};
+use crate::color_triplet::ColorTriplet;
+use crate::control::{visualize_control_codes, Control};
+use crate::emoji_replace::emoji_replace;
 use crate::error::traceback::Traceback;
 use crate::error::ConsoleError;
-use crate::export_format::{CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT};
+use crate::export_format::{
+    CONSOLE_HTML_CODE_CLOSE, CONSOLE_HTML_CODE_OPEN, CONSOLE_HTML_FORMAT, CONSOLE_SVG_FONT_FACES,
+    CONSOLE_SVG_FORMAT,
+};
+use crate::format_context::FormatContext;
+use crate::glyphs::Glyphs;
+use crate::highlighter::Highlighter;
 #[cfg(feature = "json")]
 use crate::json::{Json, JsonOptions};
 use crate::markup;
@@ -17,13 +26,21 @@ use crate::measure::Measurement;
 use crate::pager::Pager;
 use crate::rule::Rule;
 use crate::segment::Segment;
+use crate::session::Session;
+#[cfg(feature = "json")]
+use crate::session::SessionError;
 use crate::status::Status;
 use crate::style::Style;
-use crate::terminal_theme::{TerminalTheme, DEFAULT_TERMINAL_THEME, SVG_EXPORT_THEME};
+use crate::table::Table;
+use crate::terminal_theme::{
+    detect_background, TerminalTheme, DEFAULT_TERMINAL_THEME, SVG_EXPORT_THEME,
+};
 use crate::text::{JustifyMethod, OverflowMethod, Text};
 use crate::theme::{Theme, ThemeStack};
 use std::borrow::Cow;
 use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 // ---------------------------------------------------------------------------
 // ConsoleDimensions
@@ -38,6 +55,87 @@ pub struct ConsoleDimensions {
     pub height: usize,
 }
 
+// ---------------------------------------------------------------------------
+// Overrides
+// ---------------------------------------------------------------------------
+
+/// Where an effective console setting came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingSource {
+    /// Set on the [`ConsoleBuilder`].
+    Builder,
+    /// Changed after construction, e.g. with [`Console::set_width`].
+    Runtime,
+    /// Read from the named environment variable.
+    Env(&'static str),
+    /// Measured from the attached terminal after a resize.
+    Terminal,
+    /// Nothing set it, so the built-in default applies.
+    Default,
+}
+
+impl std::fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingSource::Builder => f.write_str("builder"),
+            SettingSource::Runtime => f.write_str("runtime"),
+            SettingSource::Env(name) => write!(f, "env {}", name),
+            SettingSource::Terminal => f.write_str("terminal"),
+            SettingSource::Default => f.write_str("default"),
+        }
+    }
+}
+
+/// An effective console setting and where it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting<T> {
+    /// The value the console uses.
+    pub value: T,
+    /// Where the value came from.
+    pub source: SettingSource,
+}
+
+/// The effective width, height, color system and terminal decision of a
+/// console, each with its source. Returned by [`Console::overrides`].
+///
+/// The `Display` output lists one setting per line, which answers questions
+/// like "why is my width 80?":
+///
+/// ```text
+/// width: 80 (default)
+/// height: 25 (default)
+/// color system: truecolor (default)
+/// terminal: yes (env TERM)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overrides {
+    /// Width in columns.
+    pub width: Setting<usize>,
+    /// Height in rows.
+    pub height: Setting<usize>,
+    /// Color system, `None` when colors are disabled.
+    pub color_system: Setting<Option<ColorSystem>>,
+    /// Whether output is treated as a terminal.
+    pub is_terminal: Setting<bool>,
+}
+
+impl std::fmt::Display for Overrides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let color = match self.color_system.value {
+            Some(ColorSystem::Standard) => "standard",
+            Some(ColorSystem::EightBit) => "256",
+            Some(ColorSystem::TrueColor) => "truecolor",
+            Some(ColorSystem::Windows) => "windows",
+            None => "none",
+        };
+        let terminal = if self.is_terminal.value { "yes" } else { "no" };
+        writeln!(f, "width: {} ({})", self.width.value, self.width.source)?;
+        writeln!(f, "height: {} ({})", self.height.value, self.height.source)?;
+        writeln!(f, "color system: {} ({})", color, self.color_system.source)?;
+        write!(f, "terminal: {} ({})", terminal, self.is_terminal.source)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ConsoleOptions
 // ---------------------------------------------------------------------------
@@ -71,6 +169,10 @@ pub struct ConsoleOptions {
     pub markup: Option<bool>,
     /// Explicit height constraint for renderables, if set.
     pub height: Option<usize>,
+    /// Locale conventions for numbers, sizes and times of day; read with
+    /// [`format`](Self::format) and replace with
+    /// [`with_format`](Self::with_format).
+    pub(crate) format: FormatContext,
 }
 
 /// Builder for applying selective updates to `ConsoleOptions`.
@@ -140,6 +242,18 @@ impl ConsoleOptions {
         opts
     }
 
+    /// The locale conventions used to format numbers, sizes and times.
+    pub fn format(&self) -> &FormatContext {
+        &self.format
+    }
+
+    /// Replace the locale conventions used to format numbers, sizes and times.
+    #[must_use]
+    pub fn with_format(mut self, format: FormatContext) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Apply a set of optional field updates, returning a new `ConsoleOptions`.
     pub fn with_updates(&self, updates: &ConsoleOptionsUpdates) -> Self {
         let mut opts = self.clone();
@@ -178,6 +292,131 @@ impl ConsoleOptions {
     }
 }
 
+// ---------------------------------------------------------------------------
+// PrintOptions
+// ---------------------------------------------------------------------------
+
+/// Per-call settings for [`Console::print_with_options`], overriding the
+/// console's defaults for a single print.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::{Console, PrintOptions};
+/// use gilt::text::{OverflowMethod, Text};
+/// use gilt::style::Style;
+///
+/// let mut console = Console::builder().width(40).build();
+/// let text = Text::new("a rather long line that will not fit", Style::null());
+/// console.begin_capture();
+/// console.print_with_options(
+///     &text,
+///     &PrintOptions::new()
+///         .with_width(12)
+///         .with_no_wrap(true)
+///         .with_overflow(OverflowMethod::Ellipsis),
+/// );
+/// assert_eq!(console.end_capture(), "a rather lo…\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrintOptions {
+    /// Extra style applied to the output, e.g. `"bold red"`.
+    pub style: Option<String>,
+    /// Justification override.
+    pub justify: Option<JustifyMethod>,
+    /// Overflow strategy override.
+    pub overflow: Option<OverflowMethod>,
+    /// Disable wrapping, if set.
+    pub no_wrap: Option<bool>,
+    /// Render at this width instead of the console width.
+    pub width: Option<usize>,
+    /// Height constraint passed to the renderable.
+    pub height: Option<usize>,
+    /// Crop lines to the render width (default `true`).
+    pub crop: bool,
+    /// Let the terminal wrap long lines: disables wrapping, overflow
+    /// handling and cropping.
+    pub soft_wrap: bool,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions {
+            style: None,
+            justify: None,
+            overflow: None,
+            no_wrap: None,
+            width: None,
+            height: None,
+            crop: true,
+            soft_wrap: false,
+        }
+    }
+}
+
+impl PrintOptions {
+    /// Create options that keep every console default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set an extra style for the output (builder pattern).
+    #[must_use]
+    pub fn with_style(mut self, style: &str) -> Self {
+        self.style = Some(style.to_string());
+        self
+    }
+
+    /// Set the justification (builder pattern).
+    #[must_use]
+    pub fn with_justify(mut self, justify: JustifyMethod) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// Set the overflow strategy (builder pattern).
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: OverflowMethod) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Enable or disable wrapping (builder pattern).
+    #[must_use]
+    pub fn with_no_wrap(mut self, no_wrap: bool) -> Self {
+        self.no_wrap = Some(no_wrap);
+        self
+    }
+
+    /// Set the render width (builder pattern).
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height constraint (builder pattern).
+    #[must_use]
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Enable or disable cropping to the render width (builder pattern).
+    #[must_use]
+    pub fn with_crop(mut self, crop: bool) -> Self {
+        self.crop = crop;
+        self
+    }
+
+    /// Enable or disable soft wrapping (builder pattern).
+    #[must_use]
+    pub fn with_soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Renderable trait
 // ---------------------------------------------------------------------------
@@ -186,10 +425,20 @@ impl ConsoleOptions {
 pub trait Renderable {
     /// Produce segments for rendering on the given console with given options.
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment>;
+
+    /// Produce segments along with the number of leading lines that should
+    /// stay in view while the rest scrolls, such as a table's header.
+    ///
+    /// The built-in pager and [`Live::update_pinned`](crate::live::Live::update_pinned)
+    /// keep these lines at the top of the screen. Defaults to
+    /// [`gilt_console`](Self::gilt_console) with no sticky lines.
+    fn render_sticky(&self, console: &Console, options: &ConsoleOptions) -> (Vec<Segment>, usize) {
+        (self.gilt_console(console, options), 0)
+    }
 }
 
 impl Renderable for Text {
-    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let mut text = self.clone();
         if let Some(justify) = &options.justify {
             text.justify = Some(*justify);
@@ -197,16 +446,25 @@ impl Renderable for Text {
         if let Some(overflow) = &options.overflow {
             text.overflow = Some(*overflow);
         }
-        if options.no_wrap || options.overflow == Some(OverflowMethod::Ignore) {
+        if console.visualize_control {
+            if let Cow::Owned(visualized) = visualize_control_codes(text.plain()) {
+                text.set_plain(&visualized);
+            }
+        }
+        let tab_size = text.tab_size.unwrap_or(console.tab_size);
+        if options.overflow == Some(OverflowMethod::Ignore) {
+            text.expand_tabs(Some(tab_size));
             text.render()
         } else {
-            let tab_size = text.tab_size.unwrap_or(8);
-            let lines = text.wrap(
+            // With `no_wrap`, each line is kept whole and then truncated
+            // according to the overflow method.
+            let lines = text.wrap_with_ellipsis(
                 options.max_width,
                 text.justify,
                 text.overflow,
                 tab_size,
-                text.no_wrap.unwrap_or(false),
+                options.no_wrap || text.no_wrap.unwrap_or(false),
+                &console.glyphs.ellipsis,
             );
             let mut segments = Vec::new();
             for line in lines.iter() {
@@ -236,23 +494,48 @@ impl Renderable for String {
 // ConsoleBuilder
 // ---------------------------------------------------------------------------
 
+/// The console theme: `theme` (or the defaults) on its own, or layered over
+/// the defaults for a detected `background`.
+fn build_theme(theme: Option<Theme>, background: Option<&ColorTriplet>) -> Theme {
+    match (theme, background) {
+        (Some(theme), Some(background)) => theme.inherit(&Theme::for_background(background)),
+        (Some(theme), None) => theme,
+        (None, Some(background)) => Theme::for_background(background),
+        (None, None) => Theme::new(None, true),
+    }
+}
+
 /// Builder for constructing a `Console` with custom options.
 pub struct ConsoleBuilder {
     color_system: Option<String>,
     color_system_override: Option<ColorSystem>,
+    downsample: DownsampleStrategy,
+    capabilities: Option<Capabilities>,
     width: Option<usize>,
     height: Option<usize>,
     force_terminal: Option<bool>,
     record: bool,
     theme: Option<Theme>,
+    adaptive_theme: bool,
     markup: bool,
+    emoji: bool,
+    emoji_variant: Option<String>,
     highlight: bool,
+    highlighter: Option<Arc<dyn Highlighter + Send + Sync>>,
     no_color: bool,
     no_color_explicit: bool,
     tab_size: usize,
+    visualize_control: bool,
     quiet: bool,
     soft_wrap: bool,
     safe_box: bool,
+    glyphs: Glyphs,
+    format: FormatContext,
+    accessible: Option<bool>,
+    output: Output,
+    log_time: bool,
+    log_path: bool,
+    log_time_format: Option<String>,
 }
 
 impl Default for ConsoleBuilder {
@@ -260,19 +543,33 @@ impl Default for ConsoleBuilder {
         ConsoleBuilder {
             color_system: None,
             color_system_override: None,
+            downsample: DownsampleStrategy::Auto,
+            capabilities: None,
             width: None,
             height: None,
             force_terminal: None,
             record: false,
             theme: None,
+            adaptive_theme: false,
             markup: true,
+            emoji: true,
+            emoji_variant: None,
             highlight: true,
+            highlighter: None,
             no_color: false,
             no_color_explicit: false,
             tab_size: 8,
+            visualize_control: false,
             quiet: false,
             soft_wrap: false,
             safe_box: true,
+            glyphs: Glyphs::default(),
+            format: FormatContext::default(),
+            accessible: None,
+            output: Output::Stdout,
+            log_time: true,
+            log_path: true,
+            log_time_format: None,
         }
     }
 }
@@ -301,6 +598,28 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Write output to standard error instead of standard output.
+    ///
+    /// Useful for tools that reserve stdout for machine-readable output.
+    pub fn stderr(mut self, stderr: bool) -> Self {
+        self.output = if stderr {
+            Output::Stderr
+        } else {
+            Output::Stdout
+        };
+        self
+    }
+
+    /// Write output to `writer`, e.g. a file or an in-memory buffer.
+    ///
+    /// A writer is not treated as a terminal unless
+    /// [`force_terminal`](Self::force_terminal) says so; width, height and
+    /// color system come from the builder or the environment as usual.
+    pub fn writer(mut self, writer: Box<dyn std::io::Write + Send>) -> Self {
+        self.output = Output::Writer(Mutex::new(writer));
+        self
+    }
+
     /// Force or prevent terminal detection regardless of the actual environment.
     pub fn force_terminal(mut self, f: bool) -> Self {
         self.force_terminal = Some(f);
@@ -319,18 +638,67 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Pick the default styles from the terminal's background color.
+    ///
+    /// When enabled, [`build`](Self::build) queries the terminal with
+    /// [`detect_background`] and starts from
+    /// [`Theme::for_background`]; a theme set with [`theme`](Self::theme) is
+    /// layered on top with [`Theme::inherit`]. If the background cannot be
+    /// detected the usual defaults are used.
+    pub fn adaptive_theme(mut self, adaptive: bool) -> Self {
+        self.adaptive_theme = adaptive;
+        self
+    }
+
     /// Enable or disable markup parsing in print methods.
     pub fn markup(mut self, m: bool) -> Self {
         self.markup = m;
         self
     }
 
+    /// Enable or disable replacing `:shortcode:` emoji (e.g. `:heart:`) in
+    /// printed strings. Enabled by default.
+    pub fn emoji(mut self, e: bool) -> Self {
+        self.emoji = e;
+        self
+    }
+
+    /// Set the default emoji presentation, `"text"` (VS15) or `"emoji"`
+    /// (VS16), appended to replaced shortcodes that do not pick one with
+    /// `:name-text:` or `:name-emoji:`.
+    pub fn emoji_variant(mut self, variant: &str) -> Self {
+        self.emoji_variant = Some(variant.to_string());
+        self
+    }
+
     /// Enable or disable automatic syntax highlighting.
     pub fn highlight(mut self, h: bool) -> Self {
         self.highlight = h;
         self
     }
 
+    /// Set the highlighter applied to strings printed with
+    /// [`Console::print_text`] or printed as `&str`, e.g.
+    /// [`ReprHighlighter`](crate::highlighter::ReprHighlighter) to colorize
+    /// numbers, strings, booleans, URLs and paths. It runs after markup, so
+    /// explicit markup styles win, and is skipped when
+    /// [`highlight`](Self::highlight) is off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::Console;
+    /// use gilt::highlighter::ReprHighlighter;
+    ///
+    /// let console = Console::builder().highlighter(ReprHighlighter::new()).build();
+    /// let text = console.render_str("retries=3 ok=true", None, None, None);
+    /// assert!(!text.spans().is_empty());
+    /// ```
+    pub fn highlighter<H: Highlighter + Send + Sync + 'static>(mut self, highlighter: H) -> Self {
+        self.highlighter = Some(Arc::new(highlighter));
+        self
+    }
+
     /// Enable or disable all color output.
     pub fn no_color(mut self, nc: bool) -> Self {
         self.no_color = nc;
@@ -345,12 +713,51 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Set how colors the color system cannot show are mapped to ones it
+    /// can, e.g. [`DownsampleStrategy::Nearest16`] to keep truecolor themes
+    /// within the terminal's own 16-color scheme.
+    pub fn downsample(mut self, strategy: DownsampleStrategy) -> Self {
+        self.downsample = strategy;
+        self
+    }
+
+    /// Use `capabilities` instead of detecting them from the environment,
+    /// e.g. to describe a remote terminal or to test capability branches.
+    pub fn capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
     /// Set the tab size in spaces for text rendering.
+    ///
+    /// Tabs expand to the next multiple of this width from the start of the
+    /// line. A [`Text::tab_size`] set on the text itself takes precedence.
     pub fn tab_size(mut self, ts: usize) -> Self {
         self.tab_size = ts;
         self
     }
 
+    /// Show control characters as Unicode Control Pictures (`␍`, `␛`, ...)
+    /// instead of stripping them or passing them to the terminal.
+    ///
+    /// Use this when printing untrusted input such as log files, where a
+    /// stray escape sequence could otherwise restyle or clear the screen.
+    /// Newlines and tabs are unaffected. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::Console;
+    ///
+    /// let console = Console::builder().visualize_control(true).build();
+    /// let text = console.render_str("50%\rdone", None, None, None);
+    /// assert_eq!(text.plain(), "50%␍done");
+    /// ```
+    pub fn visualize_control(mut self, visualize: bool) -> Self {
+        self.visualize_control = visualize;
+        self
+    }
+
     /// Enable or disable quiet mode, which suppresses all output.
     pub fn quiet(mut self, q: bool) -> Self {
         self.quiet = q;
@@ -369,6 +776,51 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Set the glyphs used to mark truncated or elided content.
+    ///
+    /// Pass [`Glyphs::ascii`] for fonts or terminals that lack symbols such as `…`.
+    pub fn glyphs(mut self, glyphs: Glyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Set the locale conventions used to format numbers, sizes and times.
+    pub fn format_context(mut self, format: FormatContext) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Enable or disable accessible mode (see [`Console::is_accessible`]).
+    ///
+    /// Defaults to on when colors are disabled by the `NO_COLOR` environment
+    /// variable rather than by the builder.
+    pub fn accessible(mut self, accessible: bool) -> Self {
+        self.accessible = Some(accessible);
+        self
+    }
+
+    /// Show or hide the timestamp column of [`Console::log`].
+    pub fn log_time(mut self, show: bool) -> Self {
+        self.log_time = show;
+        self
+    }
+
+    /// Show or hide the `file:line` column of [`Console::log`].
+    pub fn log_path(mut self, show: bool) -> Self {
+        self.log_path = show;
+        self
+    }
+
+    /// Set the timestamp format of [`Console::log`] (UTC).
+    ///
+    /// Supports `%Y`, `%m`, `%d`, `%H`, `%I`, `%M`, `%S`, `%p`, `%X` (the
+    /// time of day in the console's [`FormatContext`]) and `%%`. The default
+    /// is `[%X]`.
+    pub fn log_time_format(mut self, format: &str) -> Self {
+        self.log_time_format = Some(format.to_string());
+        self
+    }
+
     /// Build the `Console` instance with the configured options.
     ///
     /// # Examples
@@ -397,6 +849,9 @@ impl ConsoleBuilder {
             Some("standard" | "256" | "truecolor" | "windows")
         );
 
+        // Accessible mode follows NO_COLOR unless the caller chose colors.
+        let mut colors_disabled_by_env = false;
+        let mut color_system_source = SettingSource::Builder;
         let color_system = if let Some(cs) = self.color_system_override {
             // (1) Explicit ColorSystem override always wins.
             Some(cs)
@@ -414,8 +869,17 @@ impl ConsoleBuilder {
             None
         } else {
             // (4) Consult environment variables.
-            match detect_color_env() {
-                ColorEnvOverride::NoColor => None,
+            let (color_env, variable) = detect_color_env_source();
+            color_system_source = match variable {
+                Some(name) => SettingSource::Env(name),
+                None if self.no_color => SettingSource::Builder,
+                None => SettingSource::Default,
+            };
+            match color_env {
+                ColorEnvOverride::NoColor => {
+                    colors_disabled_by_env = std::env::var_os("NO_COLOR").is_some();
+                    None
+                }
                 ColorEnvOverride::ForceColor => Some(ColorSystem::EightBit),
                 ColorEnvOverride::ForceColorTruecolor => Some(ColorSystem::TrueColor),
                 ColorEnvOverride::None => {
@@ -429,31 +893,58 @@ impl ConsoleBuilder {
             }
         };
 
-        let theme = self.theme.unwrap_or_else(|| Theme::new(None, true));
+        let background = if self.adaptive_theme {
+            detect_background()
+        } else {
+            None
+        };
+        let theme = build_theme(self.theme, background.as_ref());
         let theme_stack = ThemeStack::new(theme);
 
         Console {
             color_system,
+            color_system_source,
+            downsample: self.downsample,
+            capabilities: self.capabilities.unwrap_or_else(Capabilities::detect),
             width_override: self.width,
+            width_source: SettingSource::Builder,
             height_override: self.height,
+            height_source: SettingSource::Builder,
             force_terminal: self.force_terminal,
+            terminal_source: SettingSource::Builder,
+            settings_generation: 0,
             tab_size: self.tab_size,
+            visualize_control: self.visualize_control,
             record: self.record,
             markup_enabled: self.markup,
+            emoji_enabled: self.emoji,
+            emoji_variant: self.emoji_variant,
             highlight_enabled: self.highlight,
+            highlighter: self.highlighter,
             soft_wrap: self.soft_wrap,
             no_color: self.no_color,
             quiet: self.quiet,
             safe_box: self.safe_box,
+            glyphs: self.glyphs,
+            format: self.format,
+            accessible: self.accessible.unwrap_or(colors_disabled_by_env),
             legacy_windows: false,
             base_style: None,
             theme_stack,
             buffer: Vec::new(),
             buffer_index: 0,
             record_buffer: Vec::new(),
+            record_times: Vec::new(),
+            record_html: Vec::new(),
+            regions: Vec::new(),
+            region_stack: Vec::new(),
             is_alt_screen: false,
             capture_buffer: None,
             live_id: None,
+            output: self.output,
+            log_time: self.log_time,
+            log_path: self.log_path,
+            log_time_format: self.log_time_format.unwrap_or_else(|| "[%X]".to_string()),
         }
     }
 }
@@ -469,20 +960,34 @@ impl ConsoleBuilder {
 pub struct Console {
     // Configuration
     color_system: Option<ColorSystem>,
+    color_system_source: SettingSource,
+    downsample: DownsampleStrategy,
+    capabilities: Capabilities,
     width_override: Option<usize>,
+    width_source: SettingSource,
     height_override: Option<usize>,
+    height_source: SettingSource,
     force_terminal: Option<bool>,
-    #[allow(dead_code)] // Reserved for future tab expansion support
+    terminal_source: SettingSource,
+    /// Bumped whenever a setting that affects layout changes at runtime.
+    settings_generation: u64,
     tab_size: usize,
+    visualize_control: bool,
     record: bool,
     markup_enabled: bool,
+    emoji_enabled: bool,
+    emoji_variant: Option<String>,
     highlight_enabled: bool,
+    highlighter: Option<Arc<dyn Highlighter + Send + Sync>>,
     #[allow(dead_code)] // Reserved for future soft-wrap rendering
     soft_wrap: bool,
     no_color: bool,
     quiet: bool,
     #[allow(dead_code)] // Reserved for future safe box-drawing fallback
     safe_box: bool,
+    glyphs: Glyphs,
+    format: FormatContext,
+    accessible: bool,
     legacy_windows: bool,
     base_style: Option<Style>,
 
@@ -493,11 +998,67 @@ pub struct Console {
     buffer: Vec<Segment>,
     buffer_index: usize,
     record_buffer: Vec<Segment>,
+    /// When each recorded write happened, and where it starts in `record_buffer`.
+    record_times: Vec<(Instant, usize)>,
+    /// Semantic HTML recorded for ranges of `record_buffer` (see `print_table`).
+    record_html: Vec<(std::ops::Range<usize>, String)>,
+    regions: Vec<(String, Vec<Segment>)>,
+    region_stack: Vec<usize>,
 
     // State
     is_alt_screen: bool,
     capture_buffer: Option<Vec<Segment>>,
     live_id: Option<usize>,
+    output: Output,
+    log_time: bool,
+    log_path: bool,
+    log_time_format: String,
+}
+
+/// Where a console writes its output.
+///
+/// A custom writer sits behind a mutex so the console stays `Sync` for
+/// writers that are only `Send`.
+enum Output {
+    Stdout,
+    Stderr,
+    Writer(Mutex<Box<dyn std::io::Write + Send>>),
+}
+
+impl Output {
+    fn write(&mut self, output: &str) {
+        use std::io::Write;
+        let _ = match self {
+            Output::Stdout => {
+                let mut stdout = std::io::stdout().lock();
+                stdout
+                    .write_all(output.as_bytes())
+                    .and_then(|_| stdout.flush())
+            }
+            Output::Stderr => {
+                let mut stderr = std::io::stderr().lock();
+                stderr
+                    .write_all(output.as_bytes())
+                    .and_then(|_| stderr.flush())
+            }
+            Output::Writer(writer) => {
+                let writer = writer.get_mut().unwrap_or_else(|e| e.into_inner());
+                writer
+                    .write_all(output.as_bytes())
+                    .and_then(|_| writer.flush())
+            }
+        };
+    }
+
+    /// Whether the stream is attached to a terminal device.
+    fn is_tty(&self) -> bool {
+        use std::io::IsTerminal;
+        match self {
+            Output::Stdout => std::io::stdout().is_terminal(),
+            Output::Stderr => std::io::stderr().is_terminal(),
+            Output::Writer(_) => false,
+        }
+    }
 }
 
 impl Console {
@@ -533,6 +1094,29 @@ impl Console {
         ConsoleBuilder::default()
     }
 
+    /// Wrap this console in a [`SharedConsole`] that threads can clone and
+    /// print through without interleaving each other's lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::Console;
+    ///
+    /// let console = Console::builder().width(40).build().shared();
+    /// let handles: Vec<_> = (0..4)
+    ///     .map(|n| {
+    ///         let console = console.clone();
+    ///         std::thread::spawn(move || console.print_text(&format!("worker {n} done")))
+    ///     })
+    ///     .collect();
+    /// for handle in handles {
+    ///     handle.join().unwrap();
+    /// }
+    /// ```
+    pub fn shared(self) -> SharedConsole {
+        SharedConsole::new(self)
+    }
+
     // -- Properties ---------------------------------------------------------
 
     /// The current terminal width in columns.
@@ -544,6 +1128,12 @@ impl Console {
         w
     }
 
+    /// The tab size used for text that does not set its own
+    /// [`Text::tab_size`].
+    pub fn tab_size(&self) -> usize {
+        self.tab_size
+    }
+
     /// The current terminal height in rows.
     pub fn height(&self) -> usize {
         if let Some(h) = self.height_override {
@@ -578,6 +1168,7 @@ impl Console {
             highlight: Some(self.highlight_enabled),
             markup: Some(self.markup_enabled),
             height: None,
+            format: self.format.clone(),
         }
     }
 
@@ -601,15 +1192,90 @@ impl Console {
         "utf-8"
     }
 
+    /// How colors the color system cannot show are mapped to ones it can.
+    pub fn downsample(&self) -> &DownsampleStrategy {
+        &self.downsample
+    }
+
+    /// Replace the strategy for mapping colors the color system cannot show.
+    pub fn set_downsample(&mut self, strategy: DownsampleStrategy) {
+        self.downsample = strategy;
+    }
+
+    /// The glyphs used to mark truncated or elided content.
+    pub fn glyphs(&self) -> &Glyphs {
+        &self.glyphs
+    }
+
+    /// Replace the glyphs used to mark truncated or elided content.
+    pub fn set_glyphs(&mut self, glyphs: Glyphs) {
+        self.glyphs = glyphs;
+    }
+
+    /// The locale conventions used to format numbers, sizes and times.
+    pub fn format_context(&self) -> &FormatContext {
+        &self.format
+    }
+
+    /// Replace the locale conventions used to format numbers, sizes and times.
+    pub fn set_format_context(&mut self, format: FormatContext) {
+        self.format = format;
+    }
+
+    /// Whether accessible mode is on.
+    ///
+    /// In accessible mode, meanings that are usually carried by color are
+    /// also shown as text: markup tags with a theme glyph (e.g. `[error]`)
+    /// are prefixed with it, and when colors are disabled, text attributes
+    /// such as bold are still emitted and links are underlined.
+    pub fn is_accessible(&self) -> bool {
+        self.accessible
+    }
+
+    /// Turn accessible mode on or off.
+    pub fn set_accessible(&mut self, accessible: bool) {
+        self.accessible = accessible;
+    }
+
+    /// The glyph for style `name` from the current theme, if accessible
+    /// mode is on.
+    pub fn semantic_glyph(&self, name: &str) -> Option<&str> {
+        if self.accessible {
+            self.theme_stack.get_glyph(name)
+        } else {
+            None
+        }
+    }
+
+    /// The glyph for style `name` from the current theme, whether or not
+    /// accessible mode is on.
+    pub fn theme_glyph(&self, name: &str) -> Option<&str> {
+        self.theme_stack.get_glyph(name)
+    }
+
     /// Whether the console is connected to a terminal.
     pub fn is_terminal(&self) -> bool {
         if let Some(forced) = self.force_terminal {
             return forced;
         }
+        if self.is_writer() {
+            return false;
+        }
         // Check environment variables as a heuristic
         std::env::var("TERM").is_ok()
     }
 
+    /// Whether output goes to standard error.
+    pub fn is_stderr(&self) -> bool {
+        matches!(self.output, Output::Stderr)
+    }
+
+    /// Whether output goes to a custom writer set with
+    /// [`ConsoleBuilder::writer`].
+    fn is_writer(&self) -> bool {
+        matches!(self.output, Output::Writer(_))
+    }
+
     /// Whether this is a "dumb" terminal with no styling support.
     pub fn is_dumb_terminal(&self) -> bool {
         match std::env::var("TERM") {
@@ -618,43 +1284,217 @@ impl Console {
         }
     }
 
-    // -- Terminal detection -------------------------------------------------
-
-    /// Detect the terminal size from environment variables, falling back to 80x25.
-    pub fn detect_terminal_size() -> (usize, usize) {
-        let width = std::env::var("COLUMNS")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(80);
-        let height = std::env::var("LINES")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(25);
-        (width, height)
-    }
-
-    // -- Theme / Style ------------------------------------------------------
-
-    /// Look up a style by name from the theme stack, or parse it as a style definition.
-    pub fn get_style(&self, name: &str) -> Result<Style, ConsoleError> {
-        // First try the theme stack
-        if let Some(style) = self.theme_stack.get(name) {
-            return Ok(style.clone());
-        }
-        // Then try parsing as a style definition
-        Style::parse(name).map_err(|e| {
-            ConsoleError::RenderError(format!("Failed to get style '{}': {}", name, e))
-        })
-    }
-
-    /// Push a new theme onto the theme stack.
-    pub fn push_theme(&mut self, theme: Theme) {
-        self.theme_stack.push_theme(theme, true);
+    /// What the terminal is known to support, detected from the environment
+    /// when the console was built unless given with
+    /// [`ConsoleBuilder::capabilities`].
+    ///
+    /// The color system is chosen separately; pass
+    /// `capabilities().color_system` to
+    /// [`ConsoleBuilder::color_system_override`] to follow the terminal.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
     }
 
-    /// Pop the top theme from the theme stack.
-    pub fn pop_theme(&mut self) {
-        let _ = self.theme_stack.pop_theme();
+    /// Whether styled output can carry OSC 8 hyperlinks: the console is a
+    /// terminal with colors enabled and is not a "dumb" terminal.
+    ///
+    /// Links in styles are dropped on consoles without styling, so renderables
+    /// that would otherwise lose a URL can spell it out instead.
+    pub fn supports_hyperlinks(&self) -> bool {
+        !self.no_color
+            && self.color_system.is_some()
+            && self.is_terminal()
+            && !self.is_dumb_terminal()
+    }
+
+    /// Report the effective width, height, color system and terminal
+    /// decision, and where each came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::{Console, SettingSource};
+    ///
+    /// let mut console = Console::builder().width(100).build();
+    /// assert_eq!(console.overrides().width.source, SettingSource::Builder);
+    ///
+    /// console.set_width(Some(60));
+    /// let overrides = console.overrides();
+    /// assert_eq!(overrides.width.value, 60);
+    /// assert_eq!(overrides.width.source, SettingSource::Runtime);
+    /// println!("{}", overrides);
+    /// ```
+    pub fn overrides(&self) -> Overrides {
+        let size_setting = |value: Option<usize>, source, var, default| match value {
+            Some(value) => Setting { value, source },
+            None => match std::env::var(var).ok().and_then(|v| v.parse().ok()) {
+                Some(value) => Setting {
+                    value,
+                    source: SettingSource::Env(var),
+                },
+                None => Setting {
+                    value: default,
+                    source: SettingSource::Default,
+                },
+            },
+        };
+        let is_terminal = match self.force_terminal {
+            Some(value) => Setting {
+                value,
+                source: self.terminal_source,
+            },
+            None if self.is_writer() => Setting {
+                value: false,
+                source: SettingSource::Builder,
+            },
+            None if std::env::var("TERM").is_ok() => Setting {
+                value: true,
+                source: SettingSource::Env("TERM"),
+            },
+            None => Setting {
+                value: false,
+                source: SettingSource::Default,
+            },
+        };
+        Overrides {
+            width: size_setting(self.width_override, self.width_source, "COLUMNS", 80),
+            height: size_setting(self.height_override, self.height_source, "LINES", 25),
+            color_system: Setting {
+                value: self.color_system,
+                source: self.color_system_source,
+            },
+            is_terminal,
+        }
+    }
+
+    /// Override the width in columns, or pass `None` to detect it again.
+    ///
+    /// A [`Live`](crate::live::Live) display using this console redraws
+    /// every line on its next refresh.
+    pub fn set_width(&mut self, width: Option<usize>) {
+        self.width_override = width;
+        self.width_source = SettingSource::Runtime;
+        self.settings_generation += 1;
+    }
+
+    /// Override the height in rows, or pass `None` to detect it again.
+    pub fn set_height(&mut self, height: Option<usize>) {
+        self.height_override = height;
+        self.height_source = SettingSource::Runtime;
+        self.settings_generation += 1;
+    }
+
+    /// Replace the color system; `None` disables colors.
+    pub fn set_color_system(&mut self, color_system: Option<ColorSystem>) {
+        self.color_system = color_system;
+        self.color_system_source = SettingSource::Runtime;
+        self.settings_generation += 1;
+    }
+
+    /// Force or prevent terminal behaviour, or pass `None` to detect it again.
+    pub fn set_force_terminal(&mut self, force: Option<bool>) {
+        self.force_terminal = force;
+        self.terminal_source = SettingSource::Runtime;
+        self.settings_generation += 1;
+    }
+
+    /// Adopt the attached terminal's current size, returning whether the
+    /// width or height changed.
+    ///
+    /// Only dimensions that follow the terminal are updated: those not fixed
+    /// on the builder or with [`set_width`](Console::set_width) and
+    /// [`set_height`](Console::set_height). Nothing changes when the output
+    /// is not a terminal. A running [`Live`](crate::live::Live) display calls
+    /// this after every resize and redraws at the new size.
+    #[cfg(feature = "resize")]
+    pub fn sync_terminal_size(&mut self) -> bool {
+        if !self.is_terminal() {
+            return false;
+        }
+        let Some((width, height)) = crate::resize::terminal_size() else {
+            return false;
+        };
+        let follows = |value: Option<usize>, source: SettingSource| {
+            value.is_none() || source == SettingSource::Terminal
+        };
+        let mut changed = false;
+        if follows(self.width_override, self.width_source) && self.width_override != Some(width) {
+            self.width_override = Some(width);
+            self.width_source = SettingSource::Terminal;
+            changed = true;
+        }
+        if follows(self.height_override, self.height_source) && self.height_override != Some(height)
+        {
+            self.height_override = Some(height);
+            self.height_source = SettingSource::Terminal;
+            changed = true;
+        }
+        if changed {
+            self.settings_generation += 1;
+        }
+        changed
+    }
+
+    /// A counter bumped by the runtime setters, so a live display can tell
+    /// that its last frame was laid out with different settings.
+    pub(crate) fn settings_generation(&self) -> u64 {
+        self.settings_generation
+    }
+
+    // -- Terminal detection -------------------------------------------------
+
+    /// Detect the terminal size from environment variables, falling back to 80x25.
+    pub fn detect_terminal_size() -> (usize, usize) {
+        let width = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(80);
+        let height = std::env::var("LINES")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(25);
+        (width, height)
+    }
+
+    // -- Theme / Style ------------------------------------------------------
+
+    /// Look up a style by name from the theme stack, or parse it as a style definition.
+    ///
+    /// A name starting with `$`, such as `"$accent"`, is a theme token: it is
+    /// only looked up in the theme stack and never parsed, so a missing theme
+    /// entry is an error rather than a silently different style.
+    pub fn get_style(&self, name: &str) -> Result<Style, ConsoleError> {
+        if let Some(token) = name.strip_prefix('$') {
+            return self.theme_stack.get(token).cloned().ok_or_else(|| {
+                ConsoleError::RenderError(format!("Unknown theme style '{}'", token))
+            });
+        }
+        // First try the theme stack
+        if let Some(style) = self.theme_stack.get(name) {
+            return Ok(style.clone());
+        }
+        // Then try parsing as a style definition
+        Style::parse(name).map_err(|e| {
+            ConsoleError::RenderError(format!("Failed to get style '{}': {}", name, e))
+        })
+    }
+
+    /// The style a widget draws with: `name`, looked up with
+    /// [`get_style`](Console::get_style) at render time, when it is set and
+    /// resolves, and `style` otherwise.
+    pub(crate) fn widget_style(&self, style: &Style, name: Option<&str>) -> Style {
+        name.and_then(|name| self.get_style(name).ok())
+            .unwrap_or_else(|| style.clone())
+    }
+
+    /// Push a new theme onto the theme stack.
+    pub fn push_theme(&mut self, theme: Theme) {
+        self.theme_stack.push_theme(theme, true);
+    }
+
+    /// Pop the top theme from the theme stack.
+    pub fn pop_theme(&mut self) {
+        let _ = self.theme_stack.pop_theme();
     }
 
     // -- Core rendering -----------------------------------------------------
@@ -696,21 +1536,50 @@ impl Console {
         let default_opts = self.options();
         let opts = options.unwrap_or(&default_opts);
         let segments = renderable.gilt_console(self, opts);
+        self.split_rendered(&segments, opts, style, pad, new_lines)
+    }
 
-        // Apply base style if present
-        let segments = if let Some(base) = &self.base_style {
-            Segment::apply_style(&segments, Some(base.clone()), None)
-        } else {
-            segments
-        };
+    /// Render a Renderable into lines of Segments like
+    /// [`render_lines`](Self::render_lines), along with the number of leading
+    /// lines to keep in view (see [`Renderable::render_sticky`]).
+    pub fn render_lines_sticky(
+        &self,
+        renderable: &dyn Renderable,
+        options: Option<&ConsoleOptions>,
+        style: Option<&Style>,
+        pad: bool,
+        new_lines: bool,
+    ) -> (Vec<Vec<Segment>>, usize) {
+        let default_opts = self.options();
+        let opts = options.unwrap_or(&default_opts);
+        let (segments, sticky) = renderable.render_sticky(self, opts);
+        let lines = self.split_rendered(&segments, opts, style, pad, new_lines);
+        (lines, sticky)
+    }
 
-        Segment::split_and_crop_lines(&segments, opts.max_width, style, pad, new_lines)
+    /// Apply the base style, if any, and split rendered segments into lines.
+    fn split_rendered(
+        &self,
+        segments: &[Segment],
+        opts: &ConsoleOptions,
+        style: Option<&Style>,
+        pad: bool,
+        new_lines: bool,
+    ) -> Vec<Vec<Segment>> {
+        // Apply base style if present
+        if let Some(base) = &self.base_style {
+            let segments = Segment::apply_style(segments, Some(base.clone()), None);
+            return Segment::split_and_crop_lines(&segments, opts.max_width, style, pad, new_lines);
+        }
+        Segment::split_and_crop_lines(segments, opts.max_width, style, pad, new_lines)
     }
 
     /// Parse a string (optionally with markup) into a `Text` object.
     ///
     /// If markup is enabled on this console, rich markup tags (e.g. `[bold]`)
-    /// are parsed and applied as spans.
+    /// are parsed and applied as spans. If emoji are enabled, `:shortcode:`
+    /// emoji are replaced in the text outside tags. The console's
+    /// [highlighter](ConsoleBuilder::highlighter), if any, is then applied.
     ///
     /// # Examples
     ///
@@ -733,11 +1602,28 @@ impl Console {
             None => Style::null(),
         };
 
+        let text = if self.visualize_control {
+            visualize_control_codes(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        let text = text.as_ref();
+        let emoji = self.emoji_enabled.then_some(self.emoji_variant.as_deref());
         let mut gilt_text = if self.markup_enabled {
-            markup::render(text, base_style.clone()).unwrap_or_else(|_| Text::new(text, base_style))
+            markup::render_markup(text, base_style.clone(), emoji, |name| {
+                self.semantic_glyph(name)
+            })
+            .unwrap_or_else(|_| Text::new(text, base_style))
+        } else if let Some(variant) = emoji {
+            Text::new(&emoji_replace(text, variant), base_style)
         } else {
             Text::new(text, base_style)
         };
+        if self.highlight_enabled {
+            if let Some(highlighter) = &self.highlighter {
+                highlighter.highlight(&mut gilt_text);
+            }
+        }
 
         if let Some(j) = justify {
             gilt_text.justify = Some(j);
@@ -775,6 +1661,9 @@ impl Console {
     }
 
     /// Print a Renderable with full styling options.
+    ///
+    /// See [`print_with_options`](Self::print_with_options) for a form that
+    /// also sets the width and height.
     #[allow(clippy::too_many_arguments)]
     pub fn print_styled(
         &mut self,
@@ -786,18 +1675,59 @@ impl Console {
         crop: bool,
         soft_wrap: bool,
     ) {
+        let options = PrintOptions {
+            style: style.map(str::to_string),
+            justify,
+            overflow,
+            no_wrap: no_wrap.then_some(true),
+            crop,
+            soft_wrap,
+            ..PrintOptions::default()
+        };
+        self.print_with_options(renderable, &options);
+    }
+
+    /// Print a Renderable with per-call wrapping, overflow, width and
+    /// justification settings instead of the console defaults.
+    pub fn print_with_options(&mut self, renderable: &dyn Renderable, options: &PrintOptions) {
         let mut opts = self.options();
-        if let Some(j) = justify {
+        if let Some(width) = options.width {
+            opts = opts.update_width(width.min(opts.max_width));
+        }
+        if let Some(height) = options.height {
+            opts = opts.update_height(height);
+        }
+        if let Some(j) = options.justify {
             opts.justify = Some(j);
         }
-        if let Some(o) = overflow {
+        if let Some(o) = options.overflow {
             opts.overflow = Some(o);
         }
-        if no_wrap {
+        if let Some(no_wrap) = options.no_wrap {
+            opts.no_wrap = no_wrap;
+        }
+        if options.soft_wrap {
             opts.no_wrap = true;
+            opts.overflow = Some(OverflowMethod::Ignore);
         }
 
-        let mut segments = renderable.gilt_console(self, &opts);
+        let crop = options.crop && !options.soft_wrap;
+        let segments = self.render_for_print(renderable, &opts, options.style.as_deref(), crop);
+        self.write_segments(&segments);
+    }
+
+    /// Run the `print` rendering pipeline without writing the result.
+    ///
+    /// Applies the extra and base styles, strips color in no-color mode,
+    /// optionally crops to the render width and guarantees a trailing newline.
+    fn render_for_print(
+        &self,
+        renderable: &dyn Renderable,
+        opts: &ConsoleOptions,
+        style: Option<&str>,
+        crop: bool,
+    ) -> Vec<Segment> {
+        let mut segments = renderable.gilt_console(self, opts);
 
         // Apply additional style
         if let Some(style_str) = style {
@@ -817,7 +1747,7 @@ impl Console {
         }
 
         // Crop to width if requested
-        if crop && !soft_wrap {
+        if crop {
             let width = opts.max_width;
             let lines = Segment::split_and_crop_lines(&segments, width, None, false, true);
             segments = lines.into_iter().flatten().collect();
@@ -830,7 +1760,7 @@ impl Console {
             }
         }
 
-        self.write_segments(&segments);
+        segments
     }
 
     /// Print a plain text string to the console.
@@ -856,10 +1786,13 @@ impl Console {
 
     // -- Convenience methods ------------------------------------------------
 
-    /// Print a log line with a timestamp prefix.
+    /// Log a renderable with a timestamp and the caller's `file:line`.
     ///
-    /// The current time is formatted as `[HH:MM:SS]` and styled with the
-    /// `"log.time"` theme style, followed by a space and the rendered text.
+    /// The time column uses the `log.time` style and the format set with
+    /// [`ConsoleBuilder::log_time_format`]; the path column, right-aligned
+    /// on the first line, uses `log.path` with the file name in bold. The
+    /// message wraps between the two. Either column can be turned off with
+    /// [`ConsoleBuilder::log_time`] and [`ConsoleBuilder::log_path`].
     ///
     /// # Examples
     ///
@@ -872,44 +1805,89 @@ impl Console {
     /// let output = console.end_capture();
     /// assert!(output.contains("Processing started"));
     /// assert!(output.contains('['));  // timestamp bracket
+    /// assert!(output.contains(".rs:"));  // caller location
     /// ```
-    pub fn log(&mut self, text: &str) {
-        let now = {
-            // Get current local time using libc/localtime
+    #[track_caller]
+    pub fn log<R: Renderable + ?Sized>(&mut self, renderable: &R) {
+        self.log_at(renderable, std::panic::Location::caller());
+    }
+
+    /// [`log`](Self::log) with an explicit caller location, for wrappers
+    /// that capture it themselves.
+    pub(crate) fn log_at<R: Renderable + ?Sized>(
+        &mut self,
+        renderable: &R,
+        location: &std::panic::Location<'_>,
+    ) {
+        let style = |name: &str| self.get_style(name).unwrap_or_else(|_| Style::null());
+
+        let time_text = if self.log_time {
             let secs = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_secs();
-            // Format manually to avoid pulling in chrono
-            let secs_i64 = secs as i64;
-            // Simple UTC-based formatting (matches Python's default local-time log,
-            // but always UTC -- acceptable for a library without chrono).
-            let secs_of_day = ((secs_i64 % 86400) + 86400) % 86400;
-            let h = secs_of_day / 3600;
-            let m = (secs_of_day % 3600) / 60;
-            let s = secs_of_day % 60;
-            format!("[{:02}:{:02}:{:02}]", h, m, s)
+            let stamp = format_log_time(&self.log_time_format, secs, &self.format);
+            Some(Text::styled(&stamp, style("log.time")))
+        } else {
+            None
         };
 
-        let time_style = self
-            .get_style("log.time")
-            .unwrap_or_else(|_| Style::parse("dim").unwrap_or_else(|_| Style::null()));
-
-        let time_text = Text::styled(&now, time_style);
-        let body = self.render_str(text, None, None, None);
+        let path_text = if self.log_path {
+            let file = location.file();
+            let (dir, name) = match file.rfind(['/', '\\']) {
+                Some(i) => file.split_at(i + 1),
+                None => ("", file),
+            };
+            let path_style = style("log.path");
+            let mut text = Text::empty();
+            text.append_str(dir, Some(path_style.clone()));
+            text.append_str(
+                name,
+                Some(path_style.clone() + Style::parse("bold").unwrap_or_else(|_| Style::null())),
+            );
+            text.append_str(&format!(":{}", location.line()), Some(path_style));
+            Some(text)
+        } else {
+            None
+        };
 
-        // Combine: time + space + body
-        let mut segments = time_text.gilt_console(self, &self.options());
-        // Remove trailing newline from time segments
-        segments.retain(|s| s.text != "\n");
-        segments.push(Segment::text(" "));
-        segments.extend(body.gilt_console(self, &self.options()));
+        let time_width = time_text.as_ref().map_or(0, |t| t.cell_len() + 1);
+        let path_width = path_text.as_ref().map_or(0, |t| t.cell_len() + 1);
+        let options = self.options();
+        let body_width = options
+            .max_width
+            .saturating_sub(time_width + path_width)
+            .max(10);
+        let body_options = options.update_width(body_width);
+        let body = Segment::split_and_crop_lines(
+            &renderable.gilt_console(self, &body_options),
+            body_width,
+            None,
+            false,
+            false,
+        );
 
-        // Ensure trailing newline
-        if let Some(last) = segments.last() {
-            if !last.text.ends_with('\n') {
-                segments.push(Segment::line());
+        let mut segments = Vec::new();
+        for (index, line) in body.iter().enumerate() {
+            if let Some(ref time) = time_text {
+                if index == 0 {
+                    segments.extend(time.render().into_iter().filter(|s| s.text != "\n"));
+                    segments.push(Segment::text(" "));
+                } else {
+                    segments.push(Segment::text(&" ".repeat(time_width)));
+                }
+            }
+            segments.extend(line.iter().cloned());
+            if let (0, Some(path)) = (index, &path_text) {
+                let used = time_width + Segment::get_line_length(line);
+                let gap = options
+                    .max_width
+                    .saturating_sub(used + path.cell_len())
+                    .max(1);
+                segments.push(Segment::text(&" ".repeat(gap)));
+                segments.extend(path.render().into_iter().filter(|s| s.text != "\n"));
             }
+            segments.push(Segment::line());
         }
 
         self.write_segments(&segments);
@@ -1007,6 +1985,21 @@ impl Console {
         }
     }
 
+    /// Print a table, recording its semantic HTML alongside the output.
+    ///
+    /// The table renders exactly as with [`print`](Self::print). When
+    /// recording, [`export_html_with`](Self::export_html_with) can then
+    /// export it as a `<table>` element (see [`Table::to_html`]) instead of
+    /// box-drawing text.
+    pub fn print_table(&mut self, table: &Table) {
+        let start = self.record_buffer.len();
+        self.print(table);
+        let end = self.record_buffer.len();
+        if self.record && end > start {
+            self.record_html.push((start..end, table.to_html()));
+        }
+    }
+
     /// Inspect a value, printing its type, debug representation, and optional docs.
     ///
     /// Renders the value inside a styled panel using the [`Inspect`](crate::inspect::Inspect) widget.
@@ -1015,6 +2008,16 @@ impl Console {
         self.print(&widget);
     }
 
+    /// Inspect a value that implements [`InspectInfo`](crate::inspect::InspectInfo),
+    /// listing its fields, metadata and methods below the debug representation.
+    pub fn inspect_info<T>(&mut self, value: &T)
+    where
+        T: std::fmt::Debug + crate::inspect::InspectInfo + 'static,
+    {
+        let widget = crate::inspect::Inspect::from_info(value).with_methods(true);
+        self.print(&widget);
+    }
+
     /// Print an error with its causal chain, rendered inside a panel.
     pub fn print_error(&mut self, error: &dyn std::error::Error) {
         let tb = Traceback::from_error(error);
@@ -1119,6 +2122,62 @@ impl Console {
         std::fs::write(path, svg)
     }
 
+    // -- Sessions -----------------------------------------------------------
+
+    /// The recorded output as a timed [`Session`], one frame per write.
+    ///
+    /// Requires `record` mode to be enabled when the Console was created.
+    /// The first frame has no delay; later frames carry the time elapsed
+    /// since the write before them.
+    pub fn session(&self) -> Session {
+        let mut session = Session::new(self.width(), self.height());
+        let mut previous: Option<Instant> = None;
+        for (i, &(time, start)) in self.record_times.iter().enumerate() {
+            let end = self
+                .record_times
+                .get(i + 1)
+                .map_or(self.record_buffer.len(), |&(_, next)| next);
+            let delay = previous.map_or(Duration::ZERO, |p| time.duration_since(p));
+            session.push(delay, self.record_buffer[start..end].to_vec());
+            previous = Some(time);
+        }
+        session
+    }
+
+    /// Save the recorded output with its timing to a session file.
+    ///
+    /// Requires `record` mode to be enabled when the Console was created.
+    /// The file can be played back later with [`replay`](Self::replay); see
+    /// [`session`](crate::session) for the format.
+    #[cfg(feature = "json")]
+    pub fn save_session(&self, path: &str) -> Result<(), SessionError> {
+        self.session().save(path)
+    }
+
+    /// Play back a session file saved with [`save_session`](Self::save_session).
+    ///
+    /// `speed` scales the recorded delays: `2.0` plays twice as fast, and
+    /// `f64::INFINITY` (or any non-positive value) writes every frame
+    /// immediately, which is what golden-file tests want.
+    #[cfg(feature = "json")]
+    pub fn replay(&mut self, path: &str, speed: f64) -> Result<(), SessionError> {
+        let session = Session::load(path)?;
+        self.replay_session(&session, speed);
+        Ok(())
+    }
+
+    /// Write the frames of a [`Session`] to this console, pausing between
+    /// them as recorded (scaled by `speed`, as for [`replay`](Self::replay)).
+    pub fn replay_session(&mut self, session: &Session, speed: f64) {
+        let paced = speed > 0.0 && speed.is_finite();
+        for frame in &session.frames {
+            if paced && !frame.delay.is_zero() {
+                std::thread::sleep(frame.delay.div_f64(speed));
+            }
+            self.write_segments(&frame.segments);
+        }
+    }
+
     // -- Segment output -----------------------------------------------------
 
     pub(crate) fn write_segments(&mut self, segments: &[Segment]) {
@@ -1126,10 +2185,16 @@ impl Console {
             return;
         }
 
-        if self.record {
+        if self.record && !segments.is_empty() {
+            self.record_times
+                .push((Instant::now(), self.record_buffer.len()));
             self.record_buffer.extend(segments.iter().cloned());
         }
 
+        for &index in &self.region_stack {
+            self.regions[index].1.extend(segments.iter().cloned());
+        }
+
         if let Some(ref mut capture) = self.capture_buffer {
             capture.extend(segments.iter().cloned());
             return;
@@ -1140,11 +2205,9 @@ impl Console {
             return;
         }
 
-        // Default path: render to ANSI and write to stdout immediately.
+        // Default path: render to ANSI and write to the output immediately.
         let output = self.render_buffer(segments);
-        use std::io::Write;
-        let _ = std::io::stdout().write_all(output.as_bytes());
-        let _ = std::io::stdout().flush();
+        self.output.write(&output);
     }
 
     // -- Buffering ----------------------------------------------------------
@@ -1207,7 +2270,19 @@ impl Console {
                 // Control segments are rendered directly (ANSI escape codes)
                 output.push_str(&segment.text);
             } else if let Some(ref style) = segment.style {
-                output.push_str(&style.render(&segment.text, color_system));
+                if color_system.is_none() && self.accessible {
+                    // Keep attributes such as bold and underline, which
+                    // monochrome terminals can still show.
+                    let style = style.monochrome();
+                    output.push_str(&style.render(&segment.text, Some(ColorSystem::Standard)));
+                } else if let Some(system) =
+                    color_system.filter(|_| !matches!(self.downsample, DownsampleStrategy::Auto))
+                {
+                    let style = style.downgrade_with(system, &self.downsample);
+                    output.push_str(&style.render(&segment.text, color_system));
+                } else {
+                    output.push_str(&style.render(&segment.text, color_system));
+                }
             } else {
                 output.push_str(&segment.text);
             }
@@ -1247,6 +2322,117 @@ impl Console {
         self.render_buffer(&segments)
     }
 
+    // -- Regions ------------------------------------------------------------
+
+    /// Begin recording a named region.
+    ///
+    /// Everything written until the matching [`end_region`](Console::end_region)
+    /// is recorded into the region, independently of `record` mode, so it can
+    /// later be exported on its own with
+    /// [`export_region_text`](Console::export_region_text) or
+    /// [`export_region_html`](Console::export_region_html). Regions may be
+    /// nested; output is recorded into every open region. Beginning a region
+    /// that already exists discards its previous contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::Console;
+    ///
+    /// let mut console = Console::builder().width(80).no_color(true).markup(false).build();
+    /// console.begin_capture();
+    /// console.print_text("log line");
+    /// console.begin_region("summary");
+    /// console.print_text("3 passed, 0 failed");
+    /// console.end_region();
+    /// let _ = console.end_capture();
+    ///
+    /// let summary = console.export_region_text("summary", false).unwrap();
+    /// assert_eq!(summary, "3 passed, 0 failed\n");
+    /// ```
+    pub fn begin_region(&mut self, name: &str) {
+        let index = match self.regions.iter().position(|(n, _)| n == name) {
+            Some(index) => {
+                self.regions[index].1.clear();
+                index
+            }
+            None => {
+                self.regions.push((name.to_string(), Vec::new()));
+                self.regions.len() - 1
+            }
+        };
+        self.region_stack.retain(|&i| i != index);
+        self.region_stack.push(index);
+    }
+
+    /// Stop recording the most recently begun region.
+    ///
+    /// Returns the region's name, or `None` if no region was open.
+    pub fn end_region(&mut self) -> Option<String> {
+        let index = self.region_stack.pop()?;
+        Some(self.regions[index].0.clone())
+    }
+
+    /// Names of all recorded regions, in the order they were first begun.
+    pub fn region_names(&self) -> Vec<&str> {
+        self.regions.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Replace the contents of a named region with a freshly rendered renderable.
+    ///
+    /// Nothing is written to the terminal; only the recorded region changes.
+    /// The region is created if it does not exist yet. [`Live`](crate::live::Live)
+    /// uses this to keep a region in sync with its latest frame.
+    pub fn replace_region(&mut self, name: &str, renderable: &dyn Renderable) {
+        let opts = self.options();
+        let segments = self.render_for_print(renderable, &opts, None, true);
+        match self.regions.iter_mut().find(|(n, _)| n == name) {
+            Some((_, recorded)) => *recorded = segments,
+            None => self.regions.push((name.to_string(), segments)),
+        }
+    }
+
+    /// Discard a named region. Returns `false` if it did not exist.
+    pub fn remove_region(&mut self, name: &str) -> bool {
+        let Some(index) = self.regions.iter().position(|(n, _)| n == name) else {
+            return false;
+        };
+        self.regions.remove(index);
+        self.region_stack.retain(|&i| i != index);
+        for i in &mut self.region_stack {
+            if *i > index {
+                *i -= 1;
+            }
+        }
+        true
+    }
+
+    /// Export a named region as plain or styled text.
+    ///
+    /// Returns `None` if no region with that name has been recorded.
+    pub fn export_region_text(&self, name: &str, styles: bool) -> Option<String> {
+        let (_, segments) = self.regions.iter().find(|(n, _)| n == name)?;
+        Some(self.segments_to_text(segments, styles))
+    }
+
+    /// Export a named region as an HTML document.
+    ///
+    /// Returns `None` if no region with that name has been recorded.
+    pub fn export_region_html(
+        &self,
+        name: &str,
+        theme: Option<&TerminalTheme>,
+        inline_styles: bool,
+    ) -> Option<String> {
+        let (_, segments) = self.regions.iter().find(|(n, _)| n == name)?;
+        let options = HtmlOptions {
+            theme: theme.cloned(),
+            inline_styles,
+            ..Default::default()
+        };
+        Some(segments_to_html(segments, &[], &options).0)
+    }
+
     // -- Control ------------------------------------------------------------
 
     /// Send a terminal control sequence.
@@ -1364,6 +2550,38 @@ impl Console {
         let _ = pager.show(&text);
     }
 
+    /// Show a renderable in a pager when the output is a terminal, and print
+    /// it otherwise.
+    ///
+    /// With the `input` feature the built-in
+    /// [`InteractivePager`](crate::pager::InteractivePager) is used; without
+    /// it the rendered output is piped through the default external [`Pager`].
+    /// Content that fits on one screen is printed directly, as is everything
+    /// while output is being captured or if the pager cannot start.
+    pub fn print_paged(&mut self, renderable: &dyn Renderable) {
+        let fits = self
+            .render_lines(renderable, None, None, false, false)
+            .len()
+            < self.height();
+        if fits || self.capture_buffer.is_some() || !self.output.is_tty() {
+            self.print(renderable);
+            return;
+        }
+
+        #[cfg(feature = "input")]
+        let paged = crate::pager::InteractivePager::new(self, renderable)
+            .run(self)
+            .is_ok();
+        #[cfg(not(feature = "input"))]
+        let paged = {
+            let output = self.render_buffer(&self.render(renderable, None));
+            Pager::new().show(&output).is_ok()
+        };
+        if !paged {
+            self.print(renderable);
+        }
+    }
+
     // -- Screen helpers -----------------------------------------------------
 
     /// Enter alternate screen mode, optionally hiding the cursor.
@@ -1430,14 +2648,20 @@ impl Console {
         let buffer = self.record_buffer.clone();
         if clear {
             self.record_buffer.clear();
+            self.record_times.clear();
+            self.record_html.clear();
         }
+        self.segments_to_text(&buffer, styles)
+    }
 
+    /// Convert segments to plain text, or to ANSI text when `styles` is true.
+    fn segments_to_text(&self, buffer: &[Segment], styles: bool) -> String {
         if styles {
-            self.render_buffer(&buffer)
+            self.render_buffer(buffer)
         } else {
             // Strip control segments and just concatenate text
             let mut output = String::new();
-            for segment in &buffer {
+            for segment in buffer {
                 if !segment.is_control() {
                     output.push_str(&segment.text);
                 }
@@ -1475,52 +2699,62 @@ impl Console {
         clear: bool,
         inline_styles: bool,
     ) -> String {
-        let theme = theme.unwrap_or(&DEFAULT_TERMINAL_THEME);
-        let buffer = self.record_buffer.clone();
+        let options = HtmlOptions {
+            theme: theme.cloned(),
+            inline_styles,
+            ..Default::default()
+        };
+        self.export_html_with(&options, clear)
+    }
+
+    /// Export recorded output as an HTML document using [`HtmlOptions`].
+    ///
+    /// With class-based styles each distinct style becomes one CSS class,
+    /// either embedded in the page or, with
+    /// [`stylesheet_href`](HtmlOptions::stylesheet_href), written separately
+    /// via [`export_html_stylesheet`](Self::export_html_stylesheet). With
+    /// [`semantic_tables`](HtmlOptions::semantic_tables), tables printed with
+    /// [`print_table`](Self::print_table) are exported as `<table>` elements.
+    /// Requires `record` mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::{Console, HtmlOptions};
+    /// use gilt::table::Table;
+    ///
+    /// let mut console = Console::builder().width(40).record(true).build();
+    /// console.print_text("[bold]Report[/bold]");
+    /// let mut table = Table::new(&["Item", "Qty"]);
+    /// table.add_row(&["Apples", "3"]);
+    /// console.print_table(&table);
+    ///
+    /// let options = HtmlOptions::default()
+    ///     .with_stylesheet_href("report.css")
+    ///     .with_semantic_tables(true);
+    /// let html = console.export_html_with(&options, false);
+    /// let css = console.export_html_stylesheet(&options);
+    /// assert!(html.contains("<link rel=\"stylesheet\" href=\"report.css\">"));
+    /// assert!(html.contains("<span class=\"r1\">Report</span>"));
+    /// assert!(html.contains("<td>Apples</td>"));
+    /// assert!(css.starts_with(".r1 { font-weight: bold }"));
+    /// ```
+    pub fn export_html_with(&mut self, options: &HtmlOptions, clear: bool) -> String {
+        let html = segments_to_html(&self.record_buffer, &self.record_html, options).0;
         if clear {
             self.record_buffer.clear();
+            self.record_times.clear();
+            self.record_html.clear();
         }
+        html
+    }
 
-        let mut code = String::new();
-        let mut stylesheet = String::new();
-        let mut style_cache: Vec<(Style, String)> = Vec::new();
-
-        for segment in &buffer {
-            if segment.is_control() {
-                continue;
-            }
-            let escaped = html_escape(&segment.text);
-
-            if let Some(ref style) = segment.style {
-                if style.is_null() {
-                    code.push_str(&escaped);
-                    continue;
-                }
-
-                let css = style.get_html_style(Some(theme));
-                if css.is_empty() {
-                    code.push_str(&escaped);
-                } else if inline_styles {
-                    write!(code, "<span style=\"{}\">{}</span>", css, escaped).unwrap();
-                } else {
-                    // Use class-based styles
-                    let class_name =
-                        find_or_insert_class(&mut style_cache, &mut stylesheet, style, &css);
-                    write!(code, "<span class=\"{}\">{}</span>", class_name, escaped).unwrap();
-                }
-            } else {
-                code.push_str(&escaped);
-            }
-        }
-
-        let fg = theme.foreground_color.hex();
-        let bg = theme.background_color.hex();
-
-        CONSOLE_HTML_FORMAT
-            .replace("{stylesheet}", &stylesheet)
-            .replace("{foreground}", &fg)
-            .replace("{background}", &bg)
-            .replace("{code}", &code)
+    /// The CSS classes used by [`export_html_with`](Self::export_html_with)
+    /// for the current recording, for saving as an external stylesheet.
+    ///
+    /// Empty when [`inline_styles`](HtmlOptions::inline_styles) is set.
+    pub fn export_html_stylesheet(&self, options: &HtmlOptions) -> String {
+        segments_to_html(&self.record_buffer, &self.record_html, options).1
     }
 
     /// Export recorded output as an SVG document.
@@ -1555,108 +2789,163 @@ impl Console {
         unique_id: Option<&str>,
         font_aspect_ratio: f64,
     ) -> String {
-        let theme = theme.unwrap_or(&SVG_EXPORT_THEME);
-        let unique_id = unique_id.unwrap_or("gilt");
+        let mut options = SvgOptions::new(title).with_font_aspect_ratio(font_aspect_ratio);
+        options.theme = theme.cloned();
+        if let Some(id) = unique_id {
+            options = options.with_unique_id(id);
+        }
+        self.export_svg_with(&options, clear)
+    }
+
+    /// Export recorded output as an SVG document using [`SvgOptions`].
+    ///
+    /// Like [`export_svg`](Self::export_svg), with control over fonts, the
+    /// window chrome and background transparency. Requires `record` mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::console::{Console, SvgOptions};
+    ///
+    /// let mut console = Console::builder().width(40).record(true).build();
+    /// console.print_text("plain frame");
+    /// let options = SvgOptions::new("")
+    ///     .with_chrome(false)
+    ///     .with_background_opacity(0.0)
+    ///     .with_font("JetBrains Mono");
+    /// let svg = console.export_svg_with(&options, true);
+    /// assert!(svg.contains("JetBrains Mono"));
+    /// assert!(!svg.contains("<circle"));
+    /// ```
+    pub fn export_svg_with(&mut self, options: &SvgOptions, clear: bool) -> String {
         let buffer = self.record_buffer.clone();
         if clear {
             self.record_buffer.clear();
+            self.record_times.clear();
+            self.record_html.clear();
         }
 
-        // Split into lines
-        let text_lines: Vec<Vec<&Segment>> = {
-            let mut lines: Vec<Vec<&Segment>> = Vec::new();
-            let mut current: Vec<&Segment> = Vec::new();
-            for seg in &buffer {
-                if seg.is_control() {
-                    continue;
-                }
-                if seg.text.contains('\n') {
-                    // Push text before newline, start a new line
-                    let parts: Vec<&str> = seg.text.split('\n').collect();
-                    for (i, part) in parts.iter().enumerate() {
-                        if !part.is_empty() {
-                            // Create a temporary reference - we need owned segments for this
-                            // Just use the original segment for non-split content
-                            current.push(seg);
-                        }
-                        if i + 1 < parts.len() {
-                            lines.push(std::mem::take(&mut current));
-                        }
-                    }
-                } else {
-                    current.push(seg);
-                }
-            }
-            if !current.is_empty() {
-                lines.push(current);
-            }
-            lines
-        };
-
-        let char_height = 20.0_f64;
-        let line_height = char_height * 1.22;
-        let char_width = char_height * font_aspect_ratio;
-        let margin_top = 1.0;
-        let margin_right = 1.0;
-        let margin_bottom = 1.0;
-        let margin_left = 1.0;
-        let padding_top = 40.0;
-        let padding_right = 8.0;
-        let padding_bottom = 8.0;
-        let padding_left = 8.0;
-
-        let console_width = self.width() as f64;
-        let line_count = text_lines.len().max(1) as f64;
-
-        let terminal_width = (console_width * char_width + padding_left + padding_right).ceil();
-        let terminal_height = (line_count * line_height + padding_top + padding_bottom).ceil();
-        let svg_width = (terminal_width + margin_left + margin_right).ceil();
-        let svg_height = (terminal_height + margin_top + margin_bottom).ceil();
-
-        let terminal_x = margin_left;
-        let terminal_y = margin_top;
-
-        // Build the chrome (window decorations)
-        let chrome = build_svg_chrome(terminal_width, terminal_height, theme, title, unique_id);
-
-        // Build the text matrix
-        let (matrix, backgrounds, styles, lines_defs) = build_svg_text(
-            &buffer,
+        let theme = options.theme.as_ref().unwrap_or(&SVG_EXPORT_THEME);
+        let layout = SvgLayout::new(options);
+        let lines = split_svg_lines(&buffer);
+        let mut style_cache: Vec<(String, String)> = Vec::new();
+        let mut styles = String::new();
+        let (matrix, backgrounds) = build_svg_text(
+            &lines,
             theme,
-            unique_id,
-            char_width,
-            line_height,
-            padding_top,
-            padding_left,
+            &options.unique_id,
+            &layout,
+            &mut style_cache,
+            &mut styles,
         );
+        render_svg_document(
+            options,
+            &layout,
+            self.width(),
+            lines.len(),
+            &backgrounds,
+            &matrix,
+            &styles,
+        )
+    }
+
+    /// Export a recorded [`Session`] as an animated SVG.
+    ///
+    /// Each screen of [`Session::screens`] becomes a frame shown for as long
+    /// as the recording showed it, and the animation loops after holding the
+    /// last frame for [`SvgOptions::loop_delay`]. This turns a recorded
+    /// [`Live`](crate::live::Live) display into an image for a README.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use gilt::console::{Console, SvgOptions};
+    /// use gilt::segment::Segment;
+    /// use gilt::session::Session;
+    ///
+    /// let mut session = Session::new(20, 4);
+    /// session.push(Duration::ZERO, vec![Segment::text("1")]);
+    /// session.push(Duration::from_millis(500), vec![Segment::text("\r2")]);
+    ///
+    /// let console = Console::builder().width(20).build();
+    /// let svg = console.export_svg_animation(&session, &SvgOptions::new("count"));
+    /// assert_eq!(svg.matches("<animate ").count(), 2);
+    /// ```
+    pub fn export_svg_animation(&self, session: &Session, options: &SvgOptions) -> String {
+        let theme = options.theme.as_ref().unwrap_or(&SVG_EXPORT_THEME);
+        let layout = SvgLayout::new(options);
+        let screens: Vec<(Duration, Vec<SvgLine>)> = session
+            .screens()
+            .into_iter()
+            .map(|screen| {
+                let lines = screen
+                    .lines
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .map(|seg| (seg.text.to_string(), seg.style.clone()))
+                            .collect()
+                    })
+                    .collect();
+                (screen.delay, lines)
+            })
+            .collect();
 
-        // Pre-format numeric values into a shared buffer to avoid per-replace allocations.
-        let mut buf = String::with_capacity(16);
-        macro_rules! fmt_buf {
-            ($fmt:literal, $val:expr) => {{
-                buf.clear();
-                write!(buf, $fmt, $val).unwrap();
-                &buf
-            }};
-        }
-
-        // Apply replacements that use the shared buffer one at a time,
-        // cloning the formatted value so `buf` can be reused.
-        let mut svg = CONSOLE_SVG_FORMAT.replace("{unique_id}", unique_id);
-        svg = svg.replace("{char_height}", fmt_buf!("{:.1}", char_height));
-        svg = svg.replace("{line_height}", fmt_buf!("{:.1}", line_height));
-        svg = svg.replace("{width}", fmt_buf!("{:.0}", svg_width));
-        svg = svg.replace("{height}", fmt_buf!("{:.0}", svg_height));
-        svg = svg.replace("{terminal_width}", fmt_buf!("{:.0}", terminal_width));
-        svg = svg.replace("{terminal_height}", fmt_buf!("{:.0}", terminal_height));
-        svg = svg.replace("{terminal_x}", fmt_buf!("{:.0}", terminal_x));
-        svg = svg.replace("{terminal_y}", fmt_buf!("{:.0}", terminal_y));
-        svg = svg.replace("{chrome}", &chrome);
-        svg = svg.replace("{matrix}", &matrix);
-        svg = svg.replace("{backgrounds}", &backgrounds);
-        svg = svg.replace("{styles}", &styles);
-        svg = svg.replace("{lines}", &lines_defs);
-        svg
+        // Frame i is visible from starts[i] until starts[i + 1] (or the end).
+        let mut starts: Vec<f64> = Vec::with_capacity(screens.len());
+        let mut elapsed = 0.0;
+        for (i, (delay, _)) in screens.iter().enumerate() {
+            if i > 0 {
+                elapsed += delay.as_secs_f64();
+            }
+            starts.push(elapsed);
+        }
+        let total = elapsed + options.loop_delay.as_secs_f64();
+        let animate = screens.len() > 1 && total > 0.0;
+
+        let mut style_cache: Vec<(String, String)> = Vec::new();
+        let mut styles = String::new();
+        let mut frames = String::new();
+        for (i, (_, lines)) in screens.iter().enumerate() {
+            let (matrix, backgrounds) = build_svg_text(
+                lines,
+                theme,
+                &options.unique_id,
+                &layout,
+                &mut style_cache,
+                &mut styles,
+            );
+            if animate {
+                let end = starts.get(i + 1).copied().unwrap_or(total);
+                writeln!(
+                    frames,
+                    "<g visibility=\"hidden\">\n    <animate attributeName=\"visibility\" \
+                     values=\"hidden;visible;hidden\" keyTimes=\"0;{:.4};{:.4}\" \
+                     dur=\"{:.3}s\" calcMode=\"discrete\" repeatCount=\"indefinite\"/>",
+                    starts[i] / total,
+                    end / total,
+                    total,
+                )
+                .unwrap();
+            } else {
+                frames.push_str("<g>\n");
+            }
+            frames.push_str(&backgrounds);
+            frames.push_str(&matrix);
+            frames.push_str("    </g>\n");
+        }
+
+        let line_count = screens.iter().map(|(_, l)| l.len()).max().unwrap_or(0);
+        render_svg_document(
+            options,
+            &layout,
+            session.width,
+            line_count,
+            "",
+            &frames,
+            &styles,
+        )
     }
 }
 
@@ -1666,12 +2955,396 @@ impl Default for Console {
     }
 }
 
+// ---------------------------------------------------------------------------
+// HtmlOptions
+// ---------------------------------------------------------------------------
+
+/// Options for [`Console::export_html_with`].
+///
+/// The defaults match [`Console::export_html`] with class-based styles: an
+/// embedded stylesheet and tables exported as text.
+#[derive(Debug, Clone)]
+pub struct HtmlOptions {
+    /// Colors used to resolve ANSI colors. `None` uses the default theme.
+    pub theme: Option<TerminalTheme>,
+    /// Put each span's CSS in a `style` attribute instead of a class.
+    pub inline_styles: bool,
+    /// Prefix of generated class names (`r1`, `r2`, ... by default).
+    pub class_prefix: String,
+    /// Link to this stylesheet instead of embedding the classes; write its
+    /// contents with [`Console::export_html_stylesheet`].
+    pub stylesheet_href: Option<String>,
+    /// Export tables printed with [`Console::print_table`] as `<table>`
+    /// elements rather than box-drawing text.
+    pub semantic_tables: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        HtmlOptions {
+            theme: None,
+            inline_styles: false,
+            class_prefix: "r".to_string(),
+            stylesheet_href: None,
+            semantic_tables: false,
+        }
+    }
+}
+
+impl HtmlOptions {
+    /// Builder: set the color theme.
+    #[must_use]
+    pub fn with_theme(mut self, theme: TerminalTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Builder: use inline `style` attributes instead of classes.
+    #[must_use]
+    pub fn with_inline_styles(mut self, inline: bool) -> Self {
+        self.inline_styles = inline;
+        self
+    }
+
+    /// Builder: set the prefix of generated class names.
+    #[must_use]
+    pub fn with_class_prefix(mut self, prefix: &str) -> Self {
+        self.class_prefix = prefix.to_string();
+        self
+    }
+
+    /// Builder: link to an external stylesheet instead of embedding one.
+    #[must_use]
+    pub fn with_stylesheet_href(mut self, href: &str) -> Self {
+        self.stylesheet_href = Some(href.to_string());
+        self
+    }
+
+    /// Builder: export recorded tables as `<table>` elements.
+    #[must_use]
+    pub fn with_semantic_tables(mut self, semantic: bool) -> Self {
+        self.semantic_tables = semantic;
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SvgOptions
+// ---------------------------------------------------------------------------
+
+/// Options for [`Console::export_svg_with`] and
+/// [`Console::export_svg_animation`].
+///
+/// The defaults match [`Console::export_svg`]: Fira Code, a macOS-style
+/// window with a title bar, and an opaque background.
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+    /// Title shown in the window chrome. Empty for none.
+    pub title: String,
+    /// Colors used for the background, foreground and ANSI palette.
+    /// `None` uses [`SVG_EXPORT_THEME`].
+    pub theme: Option<TerminalTheme>,
+    /// Prefix for CSS classes and element ids, so several exports can be
+    /// embedded in one page.
+    pub unique_id: String,
+    /// Width of a character cell relative to its height.
+    pub font_aspect_ratio: f64,
+    /// Font family for the terminal text. `None` uses Fira Code.
+    pub font_family: Option<String>,
+    /// URLs (or `data:` URIs) embedded as the `@font-face` sources of
+    /// [`font_family`](Self::font_family). Empty to rely on installed fonts.
+    pub font_urls: Vec<String>,
+    /// Whether to draw the window frame, title and control buttons.
+    pub chrome: bool,
+    /// Opacity of the terminal background, from `0.0` (transparent) to `1.0`.
+    pub background_opacity: f64,
+    /// How long an animation holds its last frame before looping.
+    pub loop_delay: Duration,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            title: "gilt".to_string(),
+            theme: None,
+            unique_id: "gilt".to_string(),
+            font_aspect_ratio: 0.61,
+            font_family: None,
+            font_urls: Vec::new(),
+            chrome: true,
+            background_opacity: 1.0,
+            loop_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Create default options with the given window title.
+    pub fn new(title: &str) -> Self {
+        SvgOptions {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder: set the color theme.
+    #[must_use]
+    pub fn with_theme(mut self, theme: TerminalTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Builder: set the prefix for CSS classes and element ids.
+    #[must_use]
+    pub fn with_unique_id(mut self, unique_id: &str) -> Self {
+        self.unique_id = unique_id.to_string();
+        self
+    }
+
+    /// Builder: set the width of a character cell relative to its height.
+    #[must_use]
+    pub fn with_font_aspect_ratio(mut self, ratio: f64) -> Self {
+        self.font_aspect_ratio = ratio;
+        self
+    }
+
+    /// Builder: render text in `family` instead of Fira Code.
+    #[must_use]
+    pub fn with_font(mut self, family: &str) -> Self {
+        self.font_family = Some(family.to_string());
+        self
+    }
+
+    /// Builder: add a source for the custom font, tried in the order added.
+    #[must_use]
+    pub fn with_font_url(mut self, url: &str) -> Self {
+        self.font_urls.push(url.to_string());
+        self
+    }
+
+    /// Builder: show or hide the window chrome.
+    #[must_use]
+    pub fn with_chrome(mut self, chrome: bool) -> Self {
+        self.chrome = chrome;
+        self
+    }
+
+    /// Builder: set the background opacity, clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn with_background_opacity(mut self, opacity: f64) -> Self {
+        self.background_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder: set how long an animation holds its last frame.
+    #[must_use]
+    pub fn with_loop_delay(mut self, delay: Duration) -> Self {
+        self.loop_delay = delay;
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SharedConsole
+// ---------------------------------------------------------------------------
+
+/// A cheap-to-clone handle to a [`Console`] shared between threads.
+///
+/// Every call locks the console for its whole duration, so each printed
+/// renderable is rendered and written in one piece: output from different
+/// threads never interleaves within a line. Libraries can hold a
+/// `SharedConsole` instead of reaching for the global [`with_console`](crate::with_console).
+///
+/// Created with [`Console::shared`]. A thread that panics while holding the
+/// lock does not poison the handle for other threads.
+#[derive(Clone)]
+pub struct SharedConsole {
+    inner: std::sync::Arc<std::sync::Mutex<Console>>,
+}
+
+impl SharedConsole {
+    /// Wrap `console` in a shared handle.
+    pub fn new(console: Console) -> Self {
+        SharedConsole {
+            inner: std::sync::Arc::new(std::sync::Mutex::new(console)),
+        }
+    }
+
+    /// Lock the console for a sequence of calls that must not be
+    /// interleaved with other threads' output.
+    pub fn lock(&self) -> std::sync::MutexGuard<'_, Console> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Run `f` with exclusive access to the console.
+    pub fn with<R>(&self, f: impl FnOnce(&mut Console) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Print a renderable.
+    pub fn print(&self, renderable: &dyn Renderable) {
+        self.lock().print(renderable);
+    }
+
+    /// Print a string, processing markup.
+    pub fn print_text(&self, text: &str) {
+        self.lock().print_text(text);
+    }
+
+    /// Log a renderable with a timestamp and the caller's location.
+    #[track_caller]
+    pub fn log<R: Renderable + ?Sized>(&self, renderable: &R) {
+        let location = std::panic::Location::caller();
+        self.lock().log_at(renderable, location);
+    }
+}
+
+impl From<Console> for SharedConsole {
+    fn from(console: Console) -> Self {
+        SharedConsole::new(console)
+    }
+}
+
+impl std::fmt::Debug for SharedConsole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedConsole").finish_non_exhaustive()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helper functions
 // ---------------------------------------------------------------------------
 
+/// Format a UTC timestamp for [`Console::log`] with a small `strftime`
+/// subset: `%Y %m %d %H %I %M %S %p %X %%`. Unknown directives are kept.
+pub(crate) fn format_log_time(format: &str, secs: u64, context: &FormatContext) -> String {
+    let days = (secs / 86_400) as i64;
+    let secs_of_day = secs % 86_400;
+    let (hour, minute, second) = (
+        (secs_of_day / 3600) as u32,
+        ((secs_of_day % 3600) / 60) as u32,
+        (secs_of_day % 60) as u32,
+    );
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let mut out = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&year.to_string()),
+            Some('m') => out.push_str(&format!("{month:02}")),
+            Some('d') => out.push_str(&format!("{day:02}")),
+            Some('H') => out.push_str(&format!("{hour:02}")),
+            Some('I') => out.push_str(&format!("{:02}", (hour + 11) % 12 + 1)),
+            Some('M') => out.push_str(&format!("{minute:02}")),
+            Some('S') => out.push_str(&format!("{second:02}")),
+            Some('p') => out.push_str(if hour < 12 { "AM" } else { "PM" }),
+            Some('X') => out.push_str(&context.format_time_of_day(hour, minute, second)),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// Render segments as a complete HTML document, returning the document
+/// and the stylesheet of its CSS classes.
+///
+/// `html_blocks` are ranges of `buffer` with semantic HTML to use in their
+/// place when [`HtmlOptions::semantic_tables`] is set.
+fn segments_to_html(
+    buffer: &[Segment],
+    html_blocks: &[(std::ops::Range<usize>, String)],
+    options: &HtmlOptions,
+) -> (String, String) {
+    let theme = options.theme.as_ref().unwrap_or(&DEFAULT_TERMINAL_THEME);
+    let mut code = String::new();
+    let mut stylesheet = String::new();
+    let mut style_cache: Vec<(String, String)> = Vec::new();
+    let mut blocks = html_blocks
+        .iter()
+        .filter(|_| options.semantic_tables)
+        .peekable();
+
+    let mut index = 0;
+    while index < buffer.len() {
+        if let Some((range, html)) = blocks.next_if(|(range, _)| range.start <= index) {
+            if range.start == index && range.end > index {
+                code.push_str(CONSOLE_HTML_CODE_CLOSE);
+                code.push('\n');
+                code.push_str(html);
+                code.push('\n');
+                code.push_str(CONSOLE_HTML_CODE_OPEN);
+                index = range.end;
+            }
+            continue;
+        }
+        let segment = &buffer[index];
+        index += 1;
+        if segment.is_control() {
+            continue;
+        }
+        let escaped = html_escape(&segment.text);
+
+        let css = match segment.style {
+            Some(ref style) if !style.is_null() => style.get_html_style(Some(theme)),
+            _ => String::new(),
+        };
+        if css.is_empty() {
+            code.push_str(&escaped);
+        } else if options.inline_styles {
+            write!(code, "<span style=\"{}\">{}</span>", css, escaped).unwrap();
+        } else {
+            let class_name = find_or_insert_class(
+                &mut style_cache,
+                &mut stylesheet,
+                &options.class_prefix,
+                &css,
+            );
+            write!(code, "<span class=\"{}\">{}</span>", class_name, escaped).unwrap();
+        }
+    }
+
+    let fg = theme.foreground_color.hex();
+    let bg = theme.background_color.hex();
+    let (link, embedded) = match &options.stylesheet_href {
+        Some(href) => (
+            format!("<link rel=\"stylesheet\" href=\"{}\">\n", html_escape(href)),
+            "",
+        ),
+        None => (String::new(), stylesheet.as_str()),
+    };
+
+    let html = CONSOLE_HTML_FORMAT
+        .replace("{link}", &link)
+        .replace("{stylesheet}", embedded)
+        .replace("{foreground}", &fg)
+        .replace("{background}", &bg)
+        .replace("{code}", &code);
+    (html, stylesheet)
+}
+
 /// Escape HTML special characters.
-fn html_escape(s: &str) -> Cow<'_, str> {
+pub(crate) fn html_escape(s: &str) -> Cow<'_, str> {
     if !s.contains(['&', '<', '>', '"']) {
         return Cow::Borrowed(s);
     }
@@ -1688,42 +3361,173 @@ fn html_escape(s: &str) -> Cow<'_, str> {
     Cow::Owned(out)
 }
 
-/// Find an existing CSS class for a style, or create a new one.
+/// Find the CSS class for a set of CSS declarations, or create a new one.
+///
+/// Styles that render to the same CSS share a class.
 fn find_or_insert_class(
-    cache: &mut Vec<(Style, String)>,
+    cache: &mut Vec<(String, String)>,
     stylesheet: &mut String,
-    style: &Style,
+    prefix: &str,
     css: &str,
 ) -> String {
-    for (cached_style, class_name) in cache.iter() {
-        if cached_style == style {
-            return class_name.clone();
-        }
+    if let Some((_, class_name)) = cache.iter().find(|(cached, _)| cached == css) {
+        return class_name.clone();
     }
     let mut class_name = String::new();
-    write!(class_name, "r{}", cache.len() + 1).unwrap();
+    write!(class_name, "{}{}", prefix, cache.len() + 1).unwrap();
     writeln!(stylesheet, ".{} {{ {} }}", class_name, css).unwrap();
-    cache.push((style.clone(), class_name.clone()));
+    cache.push((css.to_string(), class_name.clone()));
     class_name
 }
 
+/// Character cell and padding metrics shared by every part of an SVG export.
+struct SvgLayout {
+    char_height: f64,
+    line_height: f64,
+    char_width: f64,
+    padding_top: f64,
+    padding_right: f64,
+    padding_bottom: f64,
+    padding_left: f64,
+}
+
+impl SvgLayout {
+    fn new(options: &SvgOptions) -> Self {
+        let char_height = 20.0_f64;
+        SvgLayout {
+            char_height,
+            line_height: char_height * 1.22,
+            char_width: char_height * options.font_aspect_ratio,
+            // The title bar needs room above the text.
+            padding_top: if options.chrome { 40.0 } else { 8.0 },
+            padding_right: 8.0,
+            padding_bottom: 8.0,
+            padding_left: 8.0,
+        }
+    }
+}
+
+/// Fill the SVG template for a terminal `columns` wide and `line_count` high.
+fn render_svg_document(
+    options: &SvgOptions,
+    layout: &SvgLayout,
+    columns: usize,
+    line_count: usize,
+    backgrounds: &str,
+    matrix: &str,
+    styles: &str,
+) -> String {
+    let theme = options.theme.as_ref().unwrap_or(&SVG_EXPORT_THEME);
+    let unique_id = options.unique_id.as_str();
+    let margin_top = 1.0;
+    let margin_right = 1.0;
+    let margin_bottom = 1.0;
+    let margin_left = 1.0;
+
+    let line_count = line_count.max(1) as f64;
+    let terminal_width =
+        (columns as f64 * layout.char_width + layout.padding_left + layout.padding_right).ceil();
+    let terminal_height =
+        (line_count * layout.line_height + layout.padding_top + layout.padding_bottom).ceil();
+    let svg_width = (terminal_width + margin_left + margin_right).ceil();
+    let svg_height = (terminal_height + margin_top + margin_bottom).ceil();
+
+    let terminal_x = margin_left;
+    let terminal_y = margin_top;
+
+    // Build the chrome (window decorations)
+    let chrome = build_svg_chrome(terminal_width, terminal_height, theme, options);
+
+    let font_faces = match &options.font_family {
+        None => CONSOLE_SVG_FONT_FACES.to_string(),
+        Some(family) => {
+            let mut faces = String::new();
+            if !options.font_urls.is_empty() {
+                let sources: Vec<String> = options
+                    .font_urls
+                    .iter()
+                    .map(|url| format!("url(\"{}\")", svg_escape(url)))
+                    .collect();
+                write!(
+                    faces,
+                    "    @font-face {{\n        font-family: \"{}\";\n        src: {};\n    }}",
+                    svg_escape(family),
+                    sources.join(", ")
+                )
+                .unwrap();
+            }
+            faces
+        }
+    };
+    let font_family = match &options.font_family {
+        None => "Fira Code, monospace".to_string(),
+        Some(family) => format!("\"{}\", monospace", svg_escape(family)),
+    };
+
+    // Pre-format numeric values into a shared buffer to avoid per-replace allocations.
+    let mut buf = String::with_capacity(16);
+    macro_rules! fmt_buf {
+        ($fmt:literal, $val:expr) => {{
+            buf.clear();
+            write!(buf, $fmt, $val).unwrap();
+            &buf
+        }};
+    }
+
+    // Apply replacements that use the shared buffer one at a time,
+    // cloning the formatted value so `buf` can be reused.
+    let mut svg = CONSOLE_SVG_FORMAT.replace("{font_faces}", &font_faces);
+    svg = svg.replace("{font_family}", &font_family);
+    svg = svg.replace("{unique_id}", unique_id);
+    svg = svg.replace("{char_height}", fmt_buf!("{:.1}", layout.char_height));
+    svg = svg.replace("{line_height}", fmt_buf!("{:.1}", layout.line_height));
+    svg = svg.replace("{width}", fmt_buf!("{:.0}", svg_width));
+    svg = svg.replace("{height}", fmt_buf!("{:.0}", svg_height));
+    svg = svg.replace("{terminal_width}", fmt_buf!("{:.0}", terminal_width));
+    svg = svg.replace("{terminal_height}", fmt_buf!("{:.0}", terminal_height));
+    svg = svg.replace("{terminal_x}", fmt_buf!("{:.0}", terminal_x));
+    svg = svg.replace("{terminal_y}", fmt_buf!("{:.0}", terminal_y));
+    svg = svg.replace("{chrome}", &chrome);
+    svg = svg.replace("{matrix}", matrix);
+    svg = svg.replace("{backgrounds}", backgrounds);
+    svg = svg.replace("{styles}", styles);
+    svg = svg.replace("{lines}", "");
+    svg
+}
+
 /// Build the SVG chrome (window title bar and decorations).
+///
+/// Without chrome only a flat background rectangle is drawn.
 fn build_svg_chrome(
     width: f64,
     height: f64,
     theme: &TerminalTheme,
-    title: &str,
-    unique_id: &str,
+    options: &SvgOptions,
 ) -> String {
     let bg = theme.background_color.hex();
     let mut chrome = String::new();
+    let opacity = if options.background_opacity < 1.0 {
+        format!(" fill-opacity=\"{}\"", options.background_opacity)
+    } else {
+        String::new()
+    };
+
+    if !options.chrome {
+        writeln!(
+            chrome,
+            "<rect fill=\"{}\"{} x=\"0\" y=\"0\" width=\"{}\" height=\"{}\"/>",
+            bg, opacity, width, height,
+        )
+        .unwrap();
+        return chrome;
+    }
 
     // Background rectangle with rounded corners
     writeln!(
         chrome,
-        "<rect fill=\"{}\" stroke=\"rgba(255,255,255,0.35)\" stroke-width=\"1\" \
+        "<rect fill=\"{}\"{} stroke=\"rgba(255,255,255,0.35)\" stroke-width=\"1\" \
          x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" rx=\"8\"/>",
-        bg, width, height,
+        bg, opacity, width, height,
     )
     .unwrap();
 
@@ -1740,15 +3544,15 @@ fn build_svg_chrome(
     }
 
     // Title text
-    if !title.is_empty() {
+    if !options.title.is_empty() {
         writeln!(
             chrome,
             "    <text class=\"{}-title\" fill=\"{}\" x=\"{}\" y=\"23\" \
              text-anchor=\"middle\">{}</text>",
-            unique_id,
+            options.unique_id,
             theme.foreground_color.hex(),
             width / 2.0,
-            svg_escape(title),
+            svg_escape(&options.title),
         )
         .unwrap();
     }
@@ -1756,28 +3560,13 @@ fn build_svg_chrome(
     chrome
 }
 
-/// Build the SVG text content from segments.
-fn build_svg_text(
-    buffer: &[Segment],
-    theme: &TerminalTheme,
-    unique_id: &str,
-    char_width: f64,
-    line_height: f64,
-    padding_top: f64,
-    padding_left: f64,
-) -> (String, String, String, String) {
-    let mut matrix = String::new();
-    let mut backgrounds = String::new();
-    let mut styles = String::new();
-    let lines_defs = String::new();
-
-    let mut style_cache: Vec<(String, String)> = Vec::new();
-    let mut y = padding_top + line_height;
-    let mut x: f64;
-    let mut line_segments: Vec<Vec<(String, Option<Style>)>> = Vec::new();
+/// One line of an SVG export: runs of text and their styles.
+type SvgLine = Vec<(String, Option<Style>)>;
 
-    // Split buffer into lines
-    let mut current_line: Vec<(String, Option<Style>)> = Vec::new();
+/// Split recorded segments into lines of styled text, dropping control codes.
+fn split_svg_lines(buffer: &[Segment]) -> Vec<SvgLine> {
+    let mut line_segments: Vec<SvgLine> = Vec::new();
+    let mut current_line: SvgLine = Vec::new();
     for seg in buffer {
         if seg.is_control() {
             continue;
@@ -1795,9 +3584,30 @@ fn build_svg_text(
     if !current_line.is_empty() {
         line_segments.push(current_line);
     }
+    line_segments
+}
+
+/// Build the SVG text and background elements for lines of styled text.
+///
+/// Style classes are added to `styles` through `style_cache`, so several
+/// calls (one per animation frame) share one stylesheet.
+fn build_svg_text(
+    line_segments: &[SvgLine],
+    theme: &TerminalTheme,
+    unique_id: &str,
+    layout: &SvgLayout,
+    style_cache: &mut Vec<(String, String)>,
+    styles: &mut String,
+) -> (String, String) {
+    let mut matrix = String::new();
+    let mut backgrounds = String::new();
+    let (char_width, line_height) = (layout.char_width, layout.line_height);
+
+    let mut y = layout.padding_top + line_height;
+    let mut x: f64;
 
-    for line in &line_segments {
-        x = padding_left;
+    for line in line_segments {
+        x = layout.padding_left;
         for (text, style) in line {
             let escaped = svg_escape(text);
             let text_width = cell_len(text) as f64 * char_width;
@@ -1822,8 +3632,7 @@ fn build_svg_text(
                 // Foreground text with style class
                 let css = style.get_html_style(Some(theme));
                 if !css.is_empty() {
-                    let class_name =
-                        find_or_insert_svg_class(&mut style_cache, &mut styles, unique_id, &css);
+                    let class_name = find_or_insert_svg_class(style_cache, styles, unique_id, &css);
                     writeln!(
                         matrix,
                         "    <text class=\"{}\" x=\"{:.1}\" y=\"{:.1}\" \
@@ -1863,7 +3672,7 @@ fn build_svg_text(
         y += line_height;
     }
 
-    (matrix, backgrounds, styles, lines_defs)
+    (matrix, backgrounds)
 }
 
 /// Find or create an SVG style class.
@@ -1942,8 +3751,6 @@ fn svg_escape(s: &str) -> Cow<'_, str> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::segment::ControlCode;
-    use crate::segment::ControlType;
 
     // -- ConsoleDimensions --------------------------------------------------
 
@@ -2153,6 +3960,71 @@ mod tests {
         assert_eq!(console.color_system(), Some(ColorSystem::TrueColor));
     }
 
+    // -- Overrides ----------------------------------------------------------
+
+    #[test]
+    fn test_overrides_report_builder_sources() {
+        let console = Console::builder()
+            .width(100)
+            .height(30)
+            .color_system("standard")
+            .force_terminal(false)
+            .build();
+        let overrides = console.overrides();
+        assert_eq!(
+            overrides.width,
+            Setting {
+                value: 100,
+                source: SettingSource::Builder
+            }
+        );
+        assert_eq!(overrides.height.value, 30);
+        assert_eq!(overrides.color_system.value, Some(ColorSystem::Standard));
+        assert_eq!(overrides.color_system.source, SettingSource::Builder);
+        assert!(!overrides.is_terminal.value);
+        assert_eq!(overrides.is_terminal.source, SettingSource::Builder);
+        assert_eq!(
+            overrides.to_string(),
+            "width: 100 (builder)\nheight: 30 (builder)\n\
+             color system: standard (builder)\nterminal: no (builder)"
+        );
+    }
+
+    #[test]
+    fn test_runtime_setters_change_settings_and_sources() {
+        let mut console = Console::builder().width(100).build();
+        let generation = console.settings_generation();
+        console.set_width(Some(60));
+        console.set_height(Some(10));
+        console.set_color_system(None);
+        console.set_force_terminal(Some(true));
+        assert_eq!(console.width(), 60);
+        assert_eq!(console.height(), 10);
+        assert!(console.color_system().is_none());
+        assert!(console.is_terminal());
+        let overrides = console.overrides();
+        assert_eq!(overrides.width.source, SettingSource::Runtime);
+        assert_eq!(overrides.color_system.source, SettingSource::Runtime);
+        assert_eq!(overrides.is_terminal.source, SettingSource::Runtime);
+        assert_eq!(console.settings_generation(), generation + 4);
+    }
+
+    #[cfg(feature = "resize")]
+    #[test]
+    fn test_sync_terminal_size_keeps_fixed_dimensions() {
+        let mut console = Console::builder()
+            .width(40)
+            .height(12)
+            .force_terminal(true)
+            .build();
+        let generation = console.settings_generation();
+        assert!(!console.sync_terminal_size());
+        assert_eq!(console.width(), 40);
+        assert_eq!(console.height(), 12);
+        assert_eq!(console.settings_generation(), generation);
+        assert_eq!(SettingSource::Terminal.to_string(), "terminal");
+    }
+
     // -- Theme / style lookup -----------------------------------------------
 
     #[test]
@@ -2179,6 +4051,77 @@ mod tests {
         assert!(style.is_err());
     }
 
+    #[test]
+    fn test_get_style_theme_token() {
+        let mut console = Console::new();
+        assert!(console.get_style("$accent").is_err());
+        assert!(console.get_style("$repr.number").is_ok());
+        // A token is never parsed as a style definition.
+        assert!(console.get_style("italic red").is_ok());
+        assert!(console.get_style("$italic red").is_err());
+
+        let mut styles = std::collections::HashMap::new();
+        styles.insert("accent".to_string(), Style::parse("magenta").unwrap());
+        console.push_theme(Theme::new(Some(styles), true));
+        assert_eq!(
+            console.get_style("$accent").unwrap(),
+            Style::parse("magenta").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_build_theme_layers_custom_theme_over_detected_background() {
+        let mut styles = std::collections::HashMap::new();
+        styles.insert("rule.line".to_string(), Style::parse("red").unwrap());
+        let custom = Theme::new(Some(styles), false);
+        let light = ColorTriplet::new(255, 255, 255);
+
+        let theme = build_theme(Some(custom.clone()), Some(&light));
+        assert_eq!(theme.get("rule.line"), Some(&Style::parse("red").unwrap()));
+        assert_eq!(
+            theme.get("table.header"),
+            Theme::github_light().get("table.header")
+        );
+
+        let theme = build_theme(None, Some(&light));
+        assert_eq!(
+            theme.get("rule.line"),
+            Theme::github_light().get("rule.line")
+        );
+        assert!(build_theme(Some(custom), None)
+            .get("table.header")
+            .is_none());
+    }
+
+    #[test]
+    fn test_highlighter_colorizes_print_text() {
+        use crate::highlighter::ReprHighlighter;
+
+        let mut console = Console::builder()
+            .width(40)
+            .force_terminal(true)
+            .color_system("truecolor")
+            .highlighter(ReprHighlighter::new())
+            .build();
+        console.begin_capture();
+        console.print_text("count 42");
+        let output = console.end_capture();
+        assert!(
+            output.contains("\x1b["),
+            "expected styled output: {:?}",
+            output
+        );
+
+        let console = Console::builder()
+            .highlighter(ReprHighlighter::new())
+            .highlight(false)
+            .build();
+        assert!(console
+            .render_str("count 42", None, None, None)
+            .spans()
+            .is_empty());
+    }
+
     #[test]
     fn test_push_pop_theme() {
         let mut console = Console::new();
@@ -2226,6 +4169,29 @@ mod tests {
         assert!(!text.spans().is_empty());
     }
 
+    #[test]
+    fn test_render_str_replaces_emoji() {
+        let console = Console::new();
+        let text = console.render_str("[bold]Done[/bold] :heart: :nope:", None, None, None);
+        assert_eq!(text.plain(), "Done \u{2764} :nope:");
+
+        let console = Console::builder().markup(false).build();
+        let text = console.render_str("[x] :heart:", None, None, None);
+        assert_eq!(text.plain(), "[x] \u{2764}");
+
+        let console = Console::builder().emoji(false).build();
+        let text = console.render_str("Done :heart:", None, None, None);
+        assert_eq!(text.plain(), "Done :heart:");
+    }
+
+    #[test]
+    fn test_render_str_emoji_variant() {
+        let console = Console::builder().emoji_variant("emoji").build();
+        let text = console.render_str(":heart: :heart-text:", None, None, None);
+        assert_eq!(text.plain(), "\u{2764}\u{FE0F} \u{2764}\u{FE0E}");
+        assert_eq!(text.cell_len(), 4);
+    }
+
     #[test]
     fn test_render_str_with_style() {
         let console = Console::new();
@@ -2417,6 +4383,70 @@ mod tests {
         assert!(!html.contains("<script>"));
     }
 
+    #[test]
+    fn test_export_html_classes_are_deduplicated_by_css() {
+        let mut console = Console::builder().width(80).record(true).build();
+        // "bold" and "bold not italic" render to the same CSS.
+        console.print(&Text::styled("a", Style::parse("bold").unwrap()));
+        console.print(&Text::styled("b", Style::parse("bold not italic").unwrap()));
+        console.print(&Text::styled("c", Style::parse("italic").unwrap()));
+        let options = HtmlOptions::default().with_class_prefix("g");
+        let html = console.export_html_with(&options, false);
+        assert!(html.contains("<span class=\"g1\">a</span>"));
+        assert!(html.contains("<span class=\"g1\">b</span>"));
+        assert!(html.contains("<span class=\"g2\">c</span>"));
+        assert_eq!(
+            console.export_html_stylesheet(&options),
+            ".g1 { font-weight: bold }\n.g2 { font-style: italic }\n"
+        );
+        assert!(html.contains(".g1 { font-weight: bold }"));
+    }
+
+    #[test]
+    fn test_export_html_external_stylesheet() {
+        let mut console = Console::builder().width(80).record(true).build();
+        console.print(&Text::styled("x", Style::parse("bold").unwrap()));
+        let options = HtmlOptions::default().with_stylesheet_href("a&b.css");
+        let html = console.export_html_with(&options, false);
+        assert!(html.contains("<link rel=\"stylesheet\" href=\"a&amp;b.css\">\n<style>"));
+        assert!(!html.contains("font-weight: bold"));
+
+        let inline = HtmlOptions::default().with_inline_styles(true);
+        assert_eq!(console.export_html_stylesheet(&inline), "");
+    }
+
+    #[test]
+    fn test_export_html_semantic_tables() {
+        let mut console = Console::builder()
+            .width(40)
+            .record(true)
+            .no_color(true)
+            .build();
+        console.print_text("before");
+        let mut table = Table::new(&["Item"]);
+        table.add_row(&["Apples"]);
+        console.print_table(&table);
+        console.print_text("after");
+
+        let text_html = console.export_html_with(&HtmlOptions::default(), false);
+        assert!(text_html.contains("Apples"));
+        assert!(!text_html.contains("<table>"));
+
+        let options = HtmlOptions::default().with_semantic_tables(true);
+        let html = console.export_html_with(&options, true);
+        let table_at = html.find("<table>").unwrap();
+        assert!(html[..table_at].contains("before\n</code></pre>\n"));
+        assert!(html[table_at..].contains("</table>\n<pre"));
+        assert!(html.contains("<td>Apples</td>"));
+        assert!(!html.contains("┏"));
+        assert!(html.contains("after"));
+
+        // Clearing the recording drops the recorded tables too.
+        console.print_text("later");
+        let html = console.export_html_with(&options, false);
+        assert!(!html.contains("<table>"));
+    }
+
     // -- render_buffer ------------------------------------------------------
 
     #[test]
@@ -2437,6 +4467,30 @@ mod tests {
         assert!(output.contains("Bold"));
     }
 
+    #[test]
+    fn test_render_buffer_downsample_strategy() {
+        let segments = vec![Segment::styled("x", Style::parse("#800000").unwrap())];
+        let console = Console::builder().color_system("256").build();
+        assert_eq!(console.render_buffer(&segments), "\x1b[38;5;88mx\x1b[0m");
+        let console = Console::builder()
+            .color_system("256")
+            .downsample(DownsampleStrategy::Nearest16)
+            .build();
+        assert_eq!(console.render_buffer(&segments), "\x1b[31mx\x1b[0m");
+    }
+
+    #[test]
+    fn test_builder_capabilities_override() {
+        let caps = Capabilities {
+            hyperlinks: true,
+            sixel: true,
+            ..Capabilities::default()
+        };
+        let console = Console::builder().capabilities(caps.clone()).build();
+        assert_eq!(console.capabilities(), &caps);
+        assert_eq!(Console::new().capabilities(), &Capabilities::detect());
+    }
+
     #[test]
     fn test_render_buffer_no_color() {
         let console = Console::builder().no_color(true).color_system("").build();
@@ -2467,6 +4521,27 @@ mod tests {
         assert!(output.contains("click"));
     }
 
+    #[test]
+    fn test_supports_hyperlinks() {
+        let terminal = Console::builder()
+            .force_terminal(true)
+            .color_system("truecolor")
+            .build();
+        assert!(terminal.supports_hyperlinks() || terminal.is_dumb_terminal());
+
+        let piped = Console::builder()
+            .force_terminal(false)
+            .color_system("truecolor")
+            .build();
+        assert!(!piped.supports_hyperlinks());
+
+        let plain = Console::builder()
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        assert!(!plain.supports_hyperlinks());
+    }
+
     #[test]
     fn test_render_buffer_link_only() {
         let console = Console::builder().color_system("truecolor").build();
@@ -2598,107 +4673,362 @@ mod tests {
     // -- Renderable trait for Text ------------------------------------------
 
     #[test]
-    fn test_renderable_text() {
-        let console = Console::builder().width(80).build();
-        let text = Text::new("Renderable text", Style::null());
-        let opts = console.options();
-        let segments = text.gilt_console(&console, &opts);
-        assert!(!segments.is_empty());
-        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
-        assert!(combined.contains("Renderable text"));
+    fn test_renderable_text() {
+        let console = Console::builder().width(80).build();
+        let text = Text::new("Renderable text", Style::null());
+        let opts = console.options();
+        let segments = text.gilt_console(&console, &opts);
+        assert!(!segments.is_empty());
+        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert!(combined.contains("Renderable text"));
+    }
+
+    // -- Renderable trait for str -------------------------------------------
+
+    #[test]
+    fn test_renderable_str() {
+        let console = Console::builder().width(80).markup(false).build();
+        let opts = console.options();
+        let text = "Hello from str";
+        let segments = text.gilt_console(&console, &opts);
+        assert!(!segments.is_empty());
+        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert!(combined.contains("Hello from str"));
+    }
+
+    #[test]
+    fn test_renderable_string() {
+        let console = Console::builder().width(80).markup(false).build();
+        let opts = console.options();
+        let text = String::from("Hello from String");
+        let segments = text.gilt_console(&console, &opts);
+        assert!(!segments.is_empty());
+        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert!(combined.contains("Hello from String"));
+    }
+
+    // -- Quiet mode ---------------------------------------------------------
+
+    #[test]
+    fn test_quiet_mode() {
+        let mut console = Console::builder()
+            .width(80)
+            .record(true)
+            .quiet(true)
+            .markup(false)
+            .build();
+
+        let text = Text::new("Should not appear", Style::null());
+        console.print(&text);
+        let exported = console.export_text(false, false);
+        // Quiet mode should suppress all output including recording
+        assert!(exported.is_empty());
+    }
+
+    // -- Soft wrap mode -----------------------------------------------------
+
+    #[test]
+    fn test_soft_wrap_builder() {
+        let console = Console::builder().soft_wrap(true).build();
+        assert!(console.soft_wrap);
+    }
+
+    // -- No-color mode stripping --------------------------------------------
+
+    #[test]
+    fn test_no_color_mode_strips_color() {
+        let mut console = Console::builder()
+            .width(80)
+            .no_color(true)
+            .color_system("")
+            .record(true)
+            .markup(false)
+            .build();
+
+        let text = Text::styled("Colored text", Style::parse("red").unwrap());
+        console.print(&text);
+
+        // In no-color mode, the rendered output should be plain
+        let exported = console.export_text(false, true);
+        assert!(exported.contains("Colored text"));
+        // Should NOT contain ANSI color codes since color_system is None
+        assert!(!exported.contains("\x1b["));
+    }
+
+    // -- Record buffer accumulation -----------------------------------------
+
+    #[test]
+    fn test_record_buffer_accumulation() {
+        let mut console = Console::builder()
+            .width(80)
+            .record(true)
+            .no_color(true)
+            .markup(false)
+            .build();
+
+        let text1 = Text::new("First", Style::null());
+        let text2 = Text::new("Second", Style::null());
+        console.print(&text1);
+        console.print(&text2);
+
+        let exported = console.export_text(false, false);
+        assert!(exported.contains("First"));
+        assert!(exported.contains("Second"));
+    }
+
+    // -- Sessions -----------------------------------------------------------
+
+    #[test]
+    fn test_session_has_one_frame_per_write() {
+        let mut console = Console::builder()
+            .width(30)
+            .height(5)
+            .record(true)
+            .markup(false)
+            .build();
+        console.begin_capture();
+        console.print_text("one");
+        console.print_text("two");
+        let _ = console.end_capture();
+
+        let session = console.session();
+        assert_eq!((session.width, session.height), (30, 5));
+        assert_eq!(session.len(), 2);
+        assert_eq!(session.frames[0].delay, Duration::ZERO);
+        assert_eq!(session.plain_text(), "one\ntwo\n");
+
+        console.export_text(true, false);
+        assert!(console.session().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_save_session_and_replay() {
+        let path = std::env::temp_dir().join(format!("gilt_replay_{}.jsonl", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let mut recorder = Console::builder()
+            .width(40)
+            .record(true)
+            .force_terminal(true)
+            .color_system("truecolor")
+            .build();
+        recorder.begin_capture();
+        recorder.print_text("[bold red]alert[/bold red] raised");
+        let original = recorder.end_capture();
+        recorder.save_session(path).unwrap();
+
+        let mut player = Console::builder()
+            .width(40)
+            .force_terminal(true)
+            .color_system("truecolor")
+            .build();
+        player.begin_capture();
+        player.replay(path, f64::INFINITY).unwrap();
+        let replayed = player.end_capture();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(replayed, original);
+        assert!(player.replay(path, 1.0).is_err());
+    }
+
+    // -- Regions ------------------------------------------------------------
+
+    fn region_console() -> Console {
+        Console::builder()
+            .width(40)
+            .no_color(true)
+            .markup(false)
+            .build()
+    }
+
+    #[test]
+    fn test_region_records_only_its_output() {
+        let mut console = region_console();
+        console.begin_capture();
+        console.print_text("outside");
+        console.begin_region("report");
+        console.print_text("inside");
+        assert_eq!(console.end_region().as_deref(), Some("report"));
+        console.print_text("outside again");
+        let _ = console.end_capture();
+        let exported = console.export_region_text("report", false).unwrap();
+        assert_eq!(exported, "inside\n");
+    }
+
+    #[test]
+    fn test_region_independent_of_record_mode() {
+        let mut console = region_console();
+        console.begin_capture();
+        console.print_text("before");
+        console.begin_region("r");
+        console.print_text("during");
+        console.end_region();
+        console.print_text("after");
+        let captured = console.end_capture();
+        assert!(captured.contains("before") && captured.contains("after"));
+        assert_eq!(console.export_region_text("r", false).unwrap(), "during\n");
+        assert!(console.export_text(false, false).is_empty());
+    }
+
+    #[test]
+    fn test_nested_regions() {
+        let mut console = region_console();
+        console.begin_capture();
+        console.begin_region("outer");
+        console.print_text("a");
+        console.begin_region("inner");
+        console.print_text("b");
+        assert_eq!(console.end_region().as_deref(), Some("inner"));
+        console.print_text("c");
+        assert_eq!(console.end_region().as_deref(), Some("outer"));
+        assert_eq!(console.end_region(), None);
+        let _ = console.end_capture();
+        assert_eq!(
+            console.export_region_text("outer", false).unwrap(),
+            "a\nb\nc\n"
+        );
+        assert_eq!(console.export_region_text("inner", false).unwrap(), "b\n");
+        assert_eq!(console.region_names(), vec!["outer", "inner"]);
     }
 
-    // -- Renderable trait for str -------------------------------------------
-
     #[test]
-    fn test_renderable_str() {
-        let console = Console::builder().width(80).markup(false).build();
-        let opts = console.options();
-        let text = "Hello from str";
-        let segments = text.gilt_console(&console, &opts);
-        assert!(!segments.is_empty());
-        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
-        assert!(combined.contains("Hello from str"));
+    fn test_replace_and_remove_region() {
+        let mut console = region_console();
+        console.replace_region("live", &Text::new("frame 1", Style::null()));
+        console.replace_region("live", &Text::new("frame 2", Style::null()));
+        assert_eq!(
+            console.export_region_text("live", false).unwrap(),
+            "frame 2\n"
+        );
+        assert!(console.remove_region("live"));
+        assert!(!console.remove_region("live"));
+        assert!(console.export_region_text("live", false).is_none());
     }
 
     #[test]
-    fn test_renderable_string() {
-        let console = Console::builder().width(80).markup(false).build();
-        let opts = console.options();
-        let text = String::from("Hello from String");
-        let segments = text.gilt_console(&console, &opts);
-        assert!(!segments.is_empty());
-        let combined: String = segments.iter().map(|s| s.text.as_str()).collect();
-        assert!(combined.contains("Hello from String"));
+    fn test_export_region_html() {
+        let mut console = Console::builder()
+            .width(40)
+            .markup(false)
+            .quiet(true)
+            .build();
+        console.replace_region("r", &Text::styled("<red>", Style::parse("red").unwrap()));
+        let html = console.export_region_html("r", None, true).unwrap();
+        assert!(html.contains("<!DOCTYPE html>"));
+        assert!(html.contains("&lt;red&gt;"));
+        assert!(console.export_region_html("missing", None, true).is_none());
     }
 
-    // -- Quiet mode ---------------------------------------------------------
+    // -- Glyphs -------------------------------------------------------------
 
     #[test]
-    fn test_quiet_mode() {
+    fn test_default_glyphs_are_unicode() {
+        let console = Console::builder().build();
+        assert_eq!(console.glyphs(), &Glyphs::unicode());
+    }
+
+    #[test]
+    fn test_ascii_glyphs_used_for_text_ellipsis() {
         let mut console = Console::builder()
-            .width(80)
-            .record(true)
-            .quiet(true)
+            .width(10)
+            .no_color(true)
             .markup(false)
+            .glyphs(Glyphs::ascii())
             .build();
+        let mut text = Text::new("a rather long line", Style::null());
+        text.overflow = Some(OverflowMethod::Ellipsis);
+        text.no_wrap = Some(true);
+        console.begin_capture();
+        console.print(&text);
+        assert_eq!(console.end_capture(), "a rathe...\n");
 
-        let text = Text::new("Should not appear", Style::null());
+        console.set_glyphs(Glyphs::unicode());
+        console.begin_capture();
         console.print(&text);
-        let exported = console.export_text(false, false);
-        // Quiet mode should suppress all output including recording
-        assert!(exported.is_empty());
+        assert_eq!(console.end_capture(), "a rather \u{2026}\n");
     }
 
-    // -- Soft wrap mode -----------------------------------------------------
-
     #[test]
-    fn test_soft_wrap_builder() {
-        let console = Console::builder().soft_wrap(true).build();
-        assert!(console.soft_wrap);
+    fn test_ascii_glyphs_used_for_rule_title() {
+        let mut console = Console::builder()
+            .width(12)
+            .no_color(true)
+            .glyphs(Glyphs::ascii())
+            .build();
+        console.begin_capture();
+        console.rule(Some("A very long title"));
+        let output = console.end_capture();
+        assert!(output.contains("..."));
+        assert!(!output.contains('\u{2026}'));
     }
 
-    // -- No-color mode stripping --------------------------------------------
+    // -- Format context -----------------------------------------------------
 
     #[test]
-    fn test_no_color_mode_strips_color() {
+    fn test_format_context_reaches_options() {
         let mut console = Console::builder()
-            .width(80)
-            .no_color(true)
-            .color_system("")
-            .record(true)
-            .markup(false)
+            .format_context(FormatContext::for_locale("de"))
             .build();
+        assert_eq!(console.options().format().decimal_separator, ',');
+        console.set_format_context(FormatContext::default());
+        assert_eq!(console.format_context(), &FormatContext::default());
+        assert_eq!(console.options().format().decimal_separator, '.');
+    }
 
-        let text = Text::styled("Colored text", Style::parse("red").unwrap());
-        console.print(&text);
+    #[test]
+    fn test_options_with_format() {
+        let options = Console::new()
+            .options()
+            .with_format(FormatContext::for_locale("de"));
+        assert_eq!(options.format().decimal_separator, ',');
+        assert_eq!(options.update_width(10).format().decimal_separator, ',');
+    }
 
-        // In no-color mode, the rendered output should be plain
-        let exported = console.export_text(false, true);
-        assert!(exported.contains("Colored text"));
-        // Should NOT contain ANSI color codes since color_system is None
-        assert!(!exported.contains("\x1b["));
+    #[test]
+    fn test_accessible_mode_adds_glyphs() {
+        let console = Console::builder().accessible(true).build();
+        let text = console.render_str("[error]failed[/error]", None, None, None);
+        assert_eq!(text.plain(), "\u{2716} failed");
+        assert_eq!(console.semantic_glyph("success"), Some("\u{2714}"));
+
+        let console = Console::builder().accessible(false).build();
+        let text = console.render_str("[error]failed[/error]", None, None, None);
+        assert_eq!(text.plain(), "failed");
+        assert_eq!(console.semantic_glyph("success"), None);
     }
 
-    // -- Record buffer accumulation -----------------------------------------
+    #[test]
+    fn test_accessible_monochrome_keeps_attributes() {
+        let segments = vec![
+            Segment::styled("bold", Style::parse("bold red").unwrap()),
+            Segment::styled("link", Style::parse("link https://example.com").unwrap()),
+        ];
+        let console = Console::builder().no_color(true).accessible(true).build();
+        let output = console.render_buffer(&segments);
+        assert!(output.contains("\x1b[1mbold"));
+        assert!(output.contains("\x1b[4m"));
+        assert!(!output.contains("31"));
+
+        let console = Console::builder().no_color(true).accessible(false).build();
+        assert_eq!(console.render_buffer(&segments), "boldlink");
+    }
 
     #[test]
-    fn test_record_buffer_accumulation() {
+    fn test_log_uses_twelve_hour_clock() {
         let mut console = Console::builder()
             .width(80)
-            .record(true)
             .no_color(true)
             .markup(false)
+            .format_context(FormatContext::for_locale("en-US"))
             .build();
-
-        let text1 = Text::new("First", Style::null());
-        let text2 = Text::new("Second", Style::null());
-        console.print(&text1);
-        console.print(&text2);
-
-        let exported = console.export_text(false, false);
-        assert!(exported.contains("First"));
-        assert!(exported.contains("Second"));
+        console.begin_capture();
+        console.log("tick");
+        let output = console.end_capture();
+        assert!(
+            output.contains(" AM]") || output.contains(" PM]"),
+            "{output}"
+        );
     }
 
     // -- options() default --------------------------------------------------
@@ -2795,6 +5125,86 @@ mod tests {
         assert!(svg.contains("</svg>"));
     }
 
+    fn svg_console() -> Console {
+        let mut console = Console::builder()
+            .width(20)
+            .record(true)
+            .no_color(true)
+            .markup(false)
+            .build();
+        console.print_text("frame");
+        console
+    }
+
+    #[test]
+    fn test_export_svg_with_defaults_matches_export_svg() {
+        let mut console = svg_console();
+        let expected = console.export_svg("gilt", None, false, None, 0.61);
+        assert_eq!(
+            console.export_svg_with(&SvgOptions::default(), false),
+            expected
+        );
+        assert!(expected.contains("FiraCode-Regular"));
+        assert!(!expected.contains("fill-opacity"));
+    }
+
+    #[test]
+    fn test_export_svg_without_chrome() {
+        let mut console = svg_console();
+        let framed = console.export_svg_with(&SvgOptions::new("Title"), false);
+        let plain = console.export_svg_with(&SvgOptions::new("Title").with_chrome(false), false);
+        assert!(framed.contains("<circle") && framed.contains(">Title</text>"));
+        assert!(!plain.contains("<circle"));
+        assert!(!plain.contains(">Title</text>"));
+        assert!(!plain.contains("rx=\"8\""));
+        // Without the title bar the image is shorter.
+        assert!(plain.contains("viewBox=\"0 0 262 43\""));
+        assert!(framed.contains("viewBox=\"0 0 262 75\""));
+    }
+
+    #[test]
+    fn test_export_svg_custom_font_and_transparency() {
+        let mut console = svg_console();
+        let options = SvgOptions::default()
+            .with_font("Iosevka")
+            .with_font_url("https://example.com/iosevka.woff2")
+            .with_background_opacity(-1.0);
+        let svg = console.export_svg_with(&options, false);
+        assert!(svg.contains("font-family: \"Iosevka\", monospace;"));
+        assert!(svg.contains("src: url(\"https://example.com/iosevka.woff2\");"));
+        assert!(!svg.contains("FiraCode"));
+        assert!(svg.contains("fill-opacity=\"0\""));
+    }
+
+    #[test]
+    fn test_export_svg_animation_frames() {
+        let mut session = Session::new(10, 3);
+        session.push(Duration::ZERO, vec![Segment::text("one")]);
+        session.push(Duration::from_secs(1), vec![Segment::text("\rtwo")]);
+        session.push(Duration::from_secs(1), vec![Segment::text("\rsix")]);
+        let console = Console::builder().width(10).build();
+        let options = SvgOptions::new("").with_loop_delay(Duration::from_secs(2));
+        let svg = console.export_svg_animation(&session, &options);
+
+        assert_eq!(svg.matches("<animate ").count(), 3);
+        assert!(svg.contains("keyTimes=\"0;0.0000;0.2500\" dur=\"4.000s\""));
+        assert!(svg.contains("keyTimes=\"0;0.2500;0.5000\""));
+        assert!(svg.contains("keyTimes=\"0;0.5000;1.0000\""));
+        for word in ["one", "two", "six"] {
+            assert!(svg.contains(&format!(">{}</text>", word)));
+        }
+    }
+
+    #[test]
+    fn test_export_svg_animation_single_frame_is_static() {
+        let mut session = Session::new(10, 3);
+        session.push(Duration::ZERO, vec![Segment::text("still")]);
+        let console = Console::builder().width(10).build();
+        let svg = console.export_svg_animation(&session, &SvgOptions::default());
+        assert!(!svg.contains("<animate"));
+        assert!(svg.contains(">still</text>"));
+    }
+
     // -- encoding -----------------------------------------------------------
 
     #[test]
@@ -3093,6 +5503,63 @@ mod tests {
         assert!(captured.ends_with('\n'));
     }
 
+    #[test]
+    fn test_log_path_column_is_right_aligned() {
+        let mut console = Console::builder()
+            .width(60)
+            .no_color(true)
+            .markup(false)
+            .log_time(false)
+            .build();
+        console.begin_capture();
+        let line = line!() + 1;
+        console.log("message");
+        let captured = console.end_capture();
+        let expected_path = format!("console.rs:{line}");
+        assert!(captured.starts_with("message "), "{captured}");
+        assert!(
+            captured.ends_with(&format!("{expected_path}\n")),
+            "{captured}"
+        );
+        assert_eq!(cell_len(captured.trim_end_matches('\n')), 60);
+    }
+
+    #[test]
+    fn test_log_wraps_renderable_between_columns() {
+        let mut console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .markup(false)
+            .log_time_format("%H:%M")
+            .log_path(false)
+            .build();
+        let text = Text::new(
+            "alpha beta gamma delta epsilon zeta eta theta",
+            Style::null(),
+        );
+        console.begin_capture();
+        console.log(&text);
+        let captured = console.end_capture();
+        let lines: Vec<&str> = captured.lines().collect();
+        assert_eq!(lines.len(), 2, "{captured}");
+        assert_eq!(lines[0].as_bytes()[2], b':');
+        assert!(lines[1].starts_with("      "));
+        assert!(lines.iter().all(|line| cell_len(line) <= 40));
+    }
+
+    #[test]
+    fn test_format_log_time() {
+        let context = FormatContext::default();
+        // 2024-02-29 13:05:09 UTC
+        let secs = 1_709_211_909;
+        assert_eq!(
+            format_log_time("%Y-%m-%d %H:%M:%S", secs, &context),
+            "2024-02-29 13:05:09"
+        );
+        assert_eq!(format_log_time("%I%p %% %q", secs, &context), "01PM % %q");
+        assert_eq!(format_log_time("[%X]", secs, &context), "[13:05:09]");
+    }
+
     #[test]
     fn test_print_error_basic() {
         #[derive(Debug)]
@@ -3136,6 +5603,177 @@ mod tests {
         console.pager(Some("cat"));
     }
 
+    // -- PrintOptions -------------------------------------------------------
+
+    fn plain_console(width: usize) -> Console {
+        Console::builder()
+            .width(width)
+            .no_color(true)
+            .markup(false)
+            .build()
+    }
+
+    #[test]
+    fn test_print_with_options_width_and_justify() {
+        let mut console = plain_console(20);
+        let text = Text::new("one two three", Style::null());
+        console.begin_capture();
+        console.print_with_options(
+            &text,
+            &PrintOptions::new()
+                .with_width(8)
+                .with_justify(JustifyMethod::Right),
+        );
+        assert_eq!(console.end_capture(), " one two\n   three\n");
+    }
+
+    #[test]
+    fn test_print_with_options_soft_wrap_skips_cropping() {
+        let mut console = plain_console(10);
+        let text = Text::new("a line longer than ten", Style::null());
+        console.begin_capture();
+        console.print_with_options(&text, &PrintOptions::new().with_soft_wrap(true));
+        assert_eq!(console.end_capture(), "a line longer than ten\n");
+
+        console.begin_capture();
+        console.print_with_options(&text, &PrintOptions::new().with_no_wrap(true));
+        assert_eq!(console.end_capture(), "a line lon\n");
+    }
+
+    #[test]
+    fn test_print_with_options_leaves_console_defaults() {
+        let mut console = plain_console(10);
+        let text = Text::new("a line longer than ten", Style::null());
+        console.begin_capture();
+        console.print_with_options(&text, &PrintOptions::new().with_no_wrap(true));
+        console.print(&text);
+        assert_eq!(
+            console.end_capture(),
+            "a line lon\na line \nlonger \nthan ten\n"
+        );
+    }
+
+    // -- Output targets -----------------------------------------------------
+
+    /// A writer whose contents stay readable after the console takes it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_writer_receives_output() {
+        let buffer = SharedBuffer::default();
+        let mut console = Console::builder()
+            .width(40)
+            .color_system("standard")
+            .writer(Box::new(buffer.clone()))
+            .build();
+        console.print_text("[bold]hello[/bold]");
+        let written = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(written, "\x1b[1mhello\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_writer_is_not_a_terminal_unless_forced() {
+        let console = Console::builder().writer(Box::new(std::io::sink())).build();
+        assert!(!console.is_terminal());
+        assert_eq!(
+            console.overrides().is_terminal,
+            Setting {
+                value: false,
+                source: SettingSource::Builder
+            }
+        );
+        let console = Console::builder()
+            .writer(Box::new(std::io::sink()))
+            .force_terminal(true)
+            .build();
+        assert!(console.is_terminal());
+    }
+
+    #[test]
+    fn test_console_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Console>();
+    }
+
+    #[test]
+    fn test_stderr_target() {
+        assert!(Console::builder().stderr(true).build().is_stderr());
+        assert!(!Console::new().is_stderr());
+    }
+
+    // -- SharedConsole ------------------------------------------------------
+
+    #[test]
+    fn test_shared_console_lines_do_not_interleave() {
+        let shared = Console::builder()
+            .width(80)
+            .no_color(true)
+            .markup(false)
+            .build()
+            .shared();
+        shared.lock().begin_capture();
+        let threads: Vec<_> = (0..8)
+            .map(|n| {
+                let shared = shared.clone();
+                std::thread::spawn(move || {
+                    for i in 0..50 {
+                        let line = format!("thread {n} line {i} {}", "x".repeat(40));
+                        shared.print(&Text::new(&line, Style::null()));
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        let output = shared.with(|console| console.end_capture());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 400);
+        for line in lines {
+            assert!(line.starts_with("thread "));
+            assert!(line.ends_with(&"x".repeat(40)));
+        }
+    }
+
+    #[test]
+    fn test_shared_console_survives_poisoning() {
+        let shared = SharedConsole::from(Console::builder().width(40).build());
+        let poisoner = shared.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock();
+            panic!("poison the lock");
+        })
+        .join();
+        assert_eq!(shared.with(|console| console.width()), 40);
+    }
+
+    #[test]
+    fn test_print_paged_while_capturing_prints() {
+        let mut console = Console::builder()
+            .width(40)
+            .height(3)
+            .no_color(true)
+            .markup(false)
+            .build();
+        let text = Text::new("one\ntwo\nthree\nfour", Style::null());
+        console.begin_capture();
+        console.print_paged(&text);
+        let output = console.end_capture();
+        assert_eq!(output, "one\ntwo\nthree\nfour\n");
+    }
+
     // -- Screen enter/exit --------------------------------------------------
 
     #[test]
@@ -3389,6 +6027,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_visualize_control_in_strings_and_text() {
+        let mut console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .visualize_control(true)
+            .build();
+        console.begin_capture();
+        console.print_text("[bold]x[/bold]\x1b[2J\r");
+        console.print(&Text::styled("\x1b[31mred", Style::parse("bold").unwrap()));
+        let output = console.end_capture();
+        assert_eq!(output, "x␛[2J␍\n␛[31mred\n");
+
+        // Off by default: carriage returns are stripped.
+        let console = Console::builder().build();
+        assert_eq!(console.render_str("a\rb", None, None, None).plain(), "ab");
+    }
+
+    #[test]
+    fn test_console_tab_size_expands_to_tab_stops() {
+        let mut console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .tab_size(4)
+            .build();
+        assert_eq!(console.tab_size(), 4);
+        console.begin_capture();
+        console.print(&Text::new("a\tbc\tdef\tg", Style::null()));
+        let mut own = Text::new("a\tb", Style::null());
+        own.tab_size = Some(2);
+        console.print(&own);
+        let output = console.end_capture();
+        assert_eq!(output, "a   bc  def g\na b\n");
+    }
+
+    #[test]
+    fn test_measure_expands_tabs() {
+        let text = Text::new("ab\tc", Style::null());
+        assert_eq!(text.measure().maximum, 9);
+    }
+
     // -- Helper function for tests ------------------------------------------
 
     fn make_default_options() -> ConsoleOptions {
@@ -3409,6 +6088,7 @@ mod tests {
             highlight: None,
             markup: None,
             height: None,
+            format: FormatContext::default(),
         }
     }
 }
@@ -0,0 +1,550 @@
+//! DataFrame adapter -- print columnar query results as a [`Table`].
+//!
+//! Data libraries store results column by column with a type per column.
+//! The [`Columnar`] trait describes such a source -- column names, column
+//! types and cell values with nulls -- and [`Table::from_columnar`] turns it
+//! into a table the way data tools print frames:
+//!
+//! - numeric columns are right-justified, booleans centered, the rest left;
+//! - nulls are shown as a dim italic `null` (configurable);
+//! - long frames are cut to their first and last rows with a `…` row in
+//!   between, and the caption gives the full shape (`1000 rows × 3 columns`);
+//! - with [`FrameOptions::show_types`], each header carries its type.
+//!
+//! Arrow record batches implement [`Columnar`] out of the box, so
+//! [`Table::from_arrow`] prints a [`RecordBatch`] directly. For other frame
+//! types, implement [`Columnar`] yourself.
+//!
+//! Requires the `dataframe` feature, which pulls in `arrow-array` 54. Build
+//! batches with the same major version of arrow, or use the [`RecordBatch`]
+//! re-exported here.
+//!
+//! # Examples
+//!
+//! ```
+//! use gilt::dataframe::{ColumnKind, Columnar};
+//! use gilt::table::Table;
+//!
+//! struct Prices {
+//!     names: Vec<&'static str>,
+//!     prices: Vec<Option<f64>>,
+//! }
+//!
+//! impl Columnar for Prices {
+//!     fn column_count(&self) -> usize {
+//!         2
+//!     }
+//!     fn row_count(&self) -> usize {
+//!         self.names.len()
+//!     }
+//!     fn column_name(&self, column: usize) -> String {
+//!         ["item", "price"][column].to_string()
+//!     }
+//!     fn column_kind(&self, column: usize) -> ColumnKind {
+//!         [ColumnKind::Text, ColumnKind::Float][column]
+//!     }
+//!     fn value(&self, row: usize, column: usize) -> Option<String> {
+//!         match column {
+//!             0 => Some(self.names[row].to_string()),
+//!             _ => self.prices[row].map(|p| format!("{p:.2}")),
+//!         }
+//!     }
+//! }
+//!
+//! let frame = Prices { names: vec!["tea", "cake"], prices: vec![Some(3.5), None] };
+//! let table = Table::from_columnar(&frame);
+//! assert_eq!(table.row_count(), 2);
+//! assert_eq!(table.caption.as_deref(), Some("2 rows × 2 columns"));
+//! ```
+
+use arrow_array::Array;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::DataType;
+
+use crate::markup::escape;
+use crate::style::Style;
+use crate::table::{ColumnOptions, Table};
+use crate::text::{JustifyMethod, Text};
+
+pub use arrow_array::RecordBatch;
+
+// ---------------------------------------------------------------------------
+// Columnar
+// ---------------------------------------------------------------------------
+
+/// The broad type of a column, which decides how it is aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// Signed or unsigned integers.
+    Integer,
+    /// Floating point and decimal numbers.
+    Float,
+    /// Booleans.
+    Boolean,
+    /// Strings.
+    Text,
+    /// Dates, times, timestamps and durations.
+    Temporal,
+    /// Anything else: lists, structs, binary data.
+    Other,
+}
+
+impl ColumnKind {
+    /// How cells of this kind are justified: numbers right, booleans
+    /// centered, everything else left.
+    pub fn justify(self) -> JustifyMethod {
+        match self {
+            ColumnKind::Integer | ColumnKind::Float => JustifyMethod::Right,
+            ColumnKind::Boolean => JustifyMethod::Center,
+            _ => JustifyMethod::Left,
+        }
+    }
+
+    /// Short type name shown in headers when no
+    /// [`Columnar::type_name`] is given.
+    pub fn name(self) -> &'static str {
+        match self {
+            ColumnKind::Integer => "int",
+            ColumnKind::Float => "float",
+            ColumnKind::Boolean => "bool",
+            ColumnKind::Text => "str",
+            ColumnKind::Temporal => "datetime",
+            ColumnKind::Other => "object",
+        }
+    }
+}
+
+/// A column-oriented data source, such as a DataFrame or record batch.
+pub trait Columnar {
+    /// Number of columns.
+    fn column_count(&self) -> usize;
+
+    /// Number of rows.
+    fn row_count(&self) -> usize;
+
+    /// Name of `column`.
+    fn column_name(&self, column: usize) -> String;
+
+    /// Broad type of `column`.
+    fn column_kind(&self, column: usize) -> ColumnKind;
+
+    /// The value at `row` in `column` as text, or `None` for null.
+    fn value(&self, row: usize, column: usize) -> Option<String>;
+
+    /// Exact type name of `column` for headers, e.g. `i64` or
+    /// `timestamp[ms]`. Defaults to the [`ColumnKind`] name.
+    fn type_name(&self, column: usize) -> Option<String> {
+        let _ = column;
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Arrow
+// ---------------------------------------------------------------------------
+
+impl ColumnKind {
+    /// The broad kind of an Arrow data type.
+    pub fn from_arrow(data_type: &DataType) -> ColumnKind {
+        if data_type.is_integer() {
+            ColumnKind::Integer
+        } else if data_type.is_floating()
+            || matches!(
+                data_type,
+                DataType::Decimal128(..) | DataType::Decimal256(..)
+            )
+        {
+            ColumnKind::Float
+        } else if data_type.is_temporal() || matches!(data_type, DataType::Duration(_)) {
+            ColumnKind::Temporal
+        } else {
+            match data_type {
+                DataType::Boolean => ColumnKind::Boolean,
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => ColumnKind::Text,
+                _ => ColumnKind::Other,
+            }
+        }
+    }
+}
+
+/// Reads each array with its data type, formatting values the way Arrow
+/// displays them; headers show the Arrow type name.
+impl Columnar for RecordBatch {
+    fn column_count(&self) -> usize {
+        self.num_columns()
+    }
+
+    fn row_count(&self) -> usize {
+        self.num_rows()
+    }
+
+    fn column_name(&self, column: usize) -> String {
+        self.schema().field(column).name().clone()
+    }
+
+    fn column_kind(&self, column: usize) -> ColumnKind {
+        ColumnKind::from_arrow(self.column(column).data_type())
+    }
+
+    fn value(&self, row: usize, column: usize) -> Option<String> {
+        let array = self.column(column);
+        if array.is_null(row) {
+            return None;
+        }
+        let options = FormatOptions::default();
+        ArrayFormatter::try_new(array.as_ref(), &options)
+            .ok()
+            .map(|formatter| formatter.value(row).to_string())
+    }
+
+    fn type_name(&self, column: usize) -> Option<String> {
+        Some(self.column(column).data_type().to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// FrameOptions
+// ---------------------------------------------------------------------------
+
+/// Options for [`Table::from_columnar_with`].
+#[derive(Debug, Clone)]
+pub struct FrameOptions {
+    /// Maximum number of rows shown; longer frames show their first and
+    /// last rows around a `…` row. `None` shows every row.
+    pub max_rows: Option<usize>,
+    /// Text shown for null values.
+    pub null: String,
+    /// Style of null values.
+    pub null_style: Style,
+    /// Whether to show each column's type under its name.
+    pub show_types: bool,
+    /// Whether to add a `rows × columns` caption.
+    pub show_shape: bool,
+}
+
+impl Default for FrameOptions {
+    fn default() -> Self {
+        FrameOptions {
+            max_rows: Some(20),
+            null: "null".to_string(),
+            null_style: Style::parse("dim italic").unwrap_or_else(|_| Style::null()),
+            show_types: false,
+            show_shape: true,
+        }
+    }
+}
+
+impl FrameOptions {
+    /// Set the maximum number of rows shown (`None` for all).
+    #[must_use]
+    pub fn with_max_rows(mut self, max_rows: Option<usize>) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Set the text shown for null values.
+    #[must_use]
+    pub fn with_null(mut self, null: &str) -> Self {
+        self.null = null.to_string();
+        self
+    }
+
+    /// Set the style of null values.
+    #[must_use]
+    pub fn with_null_style(mut self, style: Style) -> Self {
+        self.null_style = style;
+        self
+    }
+
+    /// Show each column's type under its name.
+    #[must_use]
+    pub fn with_show_types(mut self, show: bool) -> Self {
+        self.show_types = show;
+        self
+    }
+
+    /// Add or remove the `rows × columns` caption.
+    #[must_use]
+    pub fn with_show_shape(mut self, show: bool) -> Self {
+        self.show_shape = show;
+        self
+    }
+}
+
+/// The rows to show for `total` rows with at most `max` of them: all rows,
+/// or the first and last halves (the head gets the odd one) with `None`
+/// marking the gap.
+fn visible_rows(total: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if total > max => {
+            let head = max.div_ceil(2);
+            let tail = max - head;
+            (0..head)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((total - tail..total).map(Some))
+                .collect()
+        }
+        _ => (0..total).map(Some).collect(),
+    }
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("{n} {word}")
+    } else {
+        format!("{n} {word}s")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Table constructors
+// ---------------------------------------------------------------------------
+
+impl Table {
+    /// Build a table from a [`Columnar`] source with default
+    /// [`FrameOptions`]: at most 20 rows, nulls as `null` and a shape
+    /// caption.
+    pub fn from_columnar(source: &dyn Columnar) -> Table {
+        Table::from_columnar_with(source, &FrameOptions::default())
+    }
+
+    /// Build a table from an Arrow [`RecordBatch`] with default
+    /// [`FrameOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_array::{ArrayRef, Int64Array, StringArray};
+    /// use gilt::dataframe::RecordBatch;
+    /// use gilt::table::Table;
+    ///
+    /// let ids: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+    /// let names: ArrayRef = Arc::new(StringArray::from(vec![Some("tea"), None]));
+    /// let batch = RecordBatch::try_from_iter([("id", ids), ("name", names)]).unwrap();
+    ///
+    /// let table = Table::from_arrow(&batch);
+    /// assert_eq!(table.row_count(), 2);
+    /// assert_eq!(table.caption.as_deref(), Some("2 rows × 2 columns"));
+    /// ```
+    pub fn from_arrow(batch: &RecordBatch) -> Table {
+        Table::from_columnar(batch)
+    }
+
+    /// Build a table from an Arrow [`RecordBatch`] with `options`.
+    pub fn from_arrow_with(batch: &RecordBatch, options: &FrameOptions) -> Table {
+        Table::from_columnar_with(batch, options)
+    }
+
+    /// Build a table from a [`Columnar`] source with `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gilt::dataframe::{ColumnKind, Columnar};
+    /// use gilt::dataframe::FrameOptions;
+    /// use gilt::table::Table;
+    /// # struct Ids(usize);
+    /// # impl Columnar for Ids {
+    /// #     fn column_count(&self) -> usize { 1 }
+    /// #     fn row_count(&self) -> usize { self.0 }
+    /// #     fn column_name(&self, _: usize) -> String { "id".into() }
+    /// #     fn column_kind(&self, _: usize) -> ColumnKind { ColumnKind::Integer }
+    /// #     fn value(&self, row: usize, _: usize) -> Option<String> { Some(row.to_string()) }
+    /// # }
+    ///
+    /// let options = FrameOptions::default().with_max_rows(Some(4));
+    /// let table = Table::from_columnar_with(&Ids(1000), &options);
+    /// // Two head rows, the `…` row and two tail rows.
+    /// assert_eq!(table.row_count(), 5);
+    /// assert_eq!(table.caption.as_deref(), Some("1000 rows × 1 column"));
+    /// ```
+    pub fn from_columnar_with(source: &dyn Columnar, options: &FrameOptions) -> Table {
+        let columns = source.column_count();
+        let rows = source.row_count();
+
+        let mut table = Table::new(&[]);
+        for column in 0..columns {
+            let kind = source.column_kind(column);
+            let mut header = escape(&source.column_name(column));
+            if options.show_types {
+                let type_name = source
+                    .type_name(column)
+                    .unwrap_or_else(|| kind.name().to_string());
+                header.push_str(&format!("\n[dim]{}[/dim]", escape(&type_name)));
+            }
+            table.add_column(
+                &header,
+                "",
+                ColumnOptions {
+                    justify: Some(kind.justify()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let dim = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        for row in visible_rows(rows, options.max_rows) {
+            let cells: Vec<Text> = (0..columns)
+                .map(|column| match row {
+                    None => Text::styled("…", dim.clone()),
+                    Some(row) => match source.value(row, column) {
+                        Some(value) => Text::new(&value, Style::null()),
+                        None => Text::styled(&options.null, options.null_style.clone()),
+                    },
+                })
+                .collect();
+            table.add_row_text(&cells);
+        }
+
+        if options.show_shape {
+            table = table.with_caption(&format!(
+                "{} × {}",
+                plural(rows, "row"),
+                plural(columns, "column")
+            ));
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::render_plain;
+
+    struct Frame {
+        ids: Vec<i64>,
+        names: Vec<Option<&'static str>>,
+        active: Vec<bool>,
+    }
+
+    impl Columnar for Frame {
+        fn column_count(&self) -> usize {
+            3
+        }
+
+        fn row_count(&self) -> usize {
+            self.ids.len()
+        }
+
+        fn column_name(&self, column: usize) -> String {
+            ["id", "name", "active"][column].to_string()
+        }
+
+        fn column_kind(&self, column: usize) -> ColumnKind {
+            [ColumnKind::Integer, ColumnKind::Text, ColumnKind::Boolean][column]
+        }
+
+        fn value(&self, row: usize, column: usize) -> Option<String> {
+            match column {
+                0 => Some(self.ids[row].to_string()),
+                1 => self.names[row].map(str::to_string),
+                _ => Some(self.active[row].to_string()),
+            }
+        }
+
+        fn type_name(&self, column: usize) -> Option<String> {
+            (column == 0).then(|| "i64".to_string())
+        }
+    }
+
+    fn frame(rows: usize) -> Frame {
+        Frame {
+            ids: (1..=rows as i64).collect(),
+            names: (0..rows)
+                .map(|i| if i % 2 == 0 { Some("[b]x[/b]") } else { None })
+                .collect(),
+            active: (0..rows).map(|i| i % 3 == 0).collect(),
+        }
+    }
+
+    #[test]
+    fn test_justify_by_kind() {
+        let table = Table::from_columnar(&frame(2));
+        assert_eq!(table.columns[0].justify, JustifyMethod::Right);
+        assert_eq!(table.columns[1].justify, JustifyMethod::Left);
+        assert_eq!(table.columns[2].justify, JustifyMethod::Center);
+    }
+
+    #[test]
+    fn test_nulls_and_plain_values() {
+        let table = Table::from_columnar(&frame(2));
+        let output = render_plain(&table, 60);
+        assert!(output.contains("│     1│ [b]x[/b]   │   true   │"));
+        assert!(output.contains("│     2│ null       │   false  │"));
+        assert!(output.contains("2 rows × 3 columns"));
+
+        let options = FrameOptions::default()
+            .with_null("∅")
+            .with_show_shape(false);
+        let output = render_plain(&Table::from_columnar_with(&frame(2), &options), 60);
+        assert!(output.contains("│ ∅ "));
+        assert!(!output.contains("rows"));
+    }
+
+    #[test]
+    fn test_row_limit_keeps_head_and_tail() {
+        assert_eq!(visible_rows(3, Some(5)), [Some(0), Some(1), Some(2)]);
+        assert_eq!(visible_rows(10, Some(3)), [Some(0), Some(1), None, Some(9)]);
+        assert_eq!(visible_rows(10, None).len(), 10);
+
+        let options = FrameOptions::default().with_max_rows(Some(4));
+        let output = render_plain(&Table::from_columnar_with(&frame(100), &options), 60);
+        let ids: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix('│'))
+            .filter_map(|line| line.split('│').next())
+            .map(str::trim)
+            .collect();
+        assert_eq!(ids, ["1", "2", "…", "99", "100"]);
+        assert!(output.contains("100 rows × 3 columns"));
+    }
+
+    #[test]
+    fn test_from_arrow() {
+        use std::sync::Arc;
+
+        use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray};
+
+        let columns: [(&str, ArrayRef); 4] = [
+            ("id", Arc::new(Int32Array::from(vec![1, 22]))),
+            ("price", Arc::new(Float64Array::from(vec![Some(2.5), None]))),
+            ("name", Arc::new(StringArray::from(vec!["[b]x[/b]", "y"]))),
+            ("ok", Arc::new(BooleanArray::from(vec![true, false]))),
+        ];
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let options = FrameOptions::default().with_show_types(true);
+        let table = Table::from_arrow_with(&batch, &options);
+
+        let justify: Vec<JustifyMethod> = table.columns.iter().map(|c| c.justify).collect();
+        assert_eq!(
+            justify,
+            [
+                JustifyMethod::Right,
+                JustifyMethod::Right,
+                JustifyMethod::Left,
+                JustifyMethod::Center
+            ]
+        );
+        assert_eq!(table.columns[0].header, "id\n[dim]Int32[/dim]");
+        let output = render_plain(&table, 60);
+        assert!(output.contains("[b]x[/b]"));
+        assert!(output.contains("2.5"));
+        assert!(output.contains("null"));
+        assert!(output.contains("2 rows × 4 columns"));
+    }
+
+    #[test]
+    fn test_type_headers() {
+        let options = FrameOptions::default().with_show_types(true);
+        let table = Table::from_columnar_with(&frame(1), &options);
+        assert_eq!(table.columns[0].header, "id\n[dim]i64[/dim]");
+        assert_eq!(table.columns[1].header, "name\n[dim]str[/dim]");
+        let output = render_plain(&table, 60);
+        assert!(output.contains("i64"));
+        assert!(output.contains("bool"));
+    }
+}
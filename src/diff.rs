@@ -2,6 +2,8 @@
 //!
 //! Provides a [`Diff`] widget that computes and renders line-level diffs
 //! between two texts, supporting both unified and side-by-side display styles.
+//! Changes are grouped into hunks under `@@` headers, and within a changed
+//! line only the words (or characters) that differ are highlighted.
 //!
 //! The diff algorithm uses a simple LCS (Longest Common Subsequence) approach
 //! with O(n*m) complexity, suitable for typical text sizes.
@@ -112,6 +114,159 @@ pub enum DiffStyle {
     SideBySide,
 }
 
+// ---------------------------------------------------------------------------
+// InlineDiff
+// ---------------------------------------------------------------------------
+
+/// Granularity of the highlighting inside a changed line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InlineDiff {
+    /// Color whole lines only.
+    None,
+    /// Highlight the words that changed.
+    #[default]
+    Word,
+    /// Highlight the characters that changed.
+    Char,
+}
+
+/// A piece of a changed line and whether it differs from the paired line.
+type Piece = (String, bool);
+
+/// Split `line` into the tokens compared by an inline diff: characters, or
+/// runs of word characters, runs of whitespace and single punctuation marks.
+fn tokenize(line: &str, granularity: InlineDiff) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, next)| next);
+        let same_run = match (granularity, next) {
+            (InlineDiff::Char, _) | (_, None) => false,
+            (_, Some(n)) => {
+                let word = |c: char| c.is_alphanumeric() || c == '_';
+                (word(c) && word(n)) || (c.is_whitespace() && n.is_whitespace())
+            }
+        };
+        if !same_run {
+            let end = i + c.len_utf8();
+            tokens.push(&line[start..end]);
+            start = end;
+        }
+    }
+    tokens
+}
+
+/// Diff two changed lines token by token, returning the pieces of each with
+/// the changed ones flagged. Returns `None` when the lines share no visible
+/// token, since highlighting everything tells the reader nothing.
+fn inline_pieces(
+    old: &str,
+    new: &str,
+    granularity: InlineDiff,
+) -> Option<(Vec<Piece>, Vec<Piece>)> {
+    if granularity == InlineDiff::None {
+        return None;
+    }
+    let ops = compute_diff(&tokenize(old, granularity), &tokenize(new, granularity));
+    let shared = ops
+        .iter()
+        .any(|op| matches!(op, DiffOp::Equal(token) if !token.trim().is_empty()));
+    if !shared {
+        return None;
+    }
+
+    fn push(pieces: &mut Vec<Piece>, token: &str, changed: bool) {
+        match pieces.last_mut() {
+            Some((text, flag)) if *flag == changed => text.push_str(token),
+            _ => pieces.push((token.to_string(), changed)),
+        }
+    }
+    let (mut old_pieces, mut new_pieces) = (Vec::new(), Vec::new());
+    for op in &ops {
+        match op {
+            DiffOp::Equal(token) => {
+                push(&mut old_pieces, token, false);
+                push(&mut new_pieces, token, false);
+            }
+            DiffOp::Delete(token) => push(&mut old_pieces, token, true),
+            DiffOp::Insert(token) => push(&mut new_pieces, token, true),
+        }
+    }
+    Some((old_pieces, new_pieces))
+}
+
+/// A row of a hunk: deleted and inserted lines are paired up so each change
+/// sits beside (or right after) the line it replaced.
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    /// An unchanged line.
+    Equal(String),
+    /// A deleted line, the line inserted in its place, or both.
+    Change(Option<Vec<Piece>>, Option<Vec<Piece>>),
+}
+
+/// Pair each run of deletions with the insertions that follow it.
+fn hunk_rows(ops: &[DiffOp], granularity: InlineDiff) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < ops.len() {
+        if let DiffOp::Equal(line) = &ops[i] {
+            rows.push(Row::Equal(line.clone()));
+            i += 1;
+            continue;
+        }
+        let mut deleted = Vec::new();
+        while let Some(DiffOp::Delete(line)) = ops.get(i) {
+            deleted.push(line.as_str());
+            i += 1;
+        }
+        let mut inserted = Vec::new();
+        while let Some(DiffOp::Insert(line)) = ops.get(i) {
+            inserted.push(line.as_str());
+            i += 1;
+        }
+        for n in 0..deleted.len().max(inserted.len()) {
+            let whole = |line: &str| vec![(line.to_string(), false)];
+            let row = match (deleted.get(n), inserted.get(n)) {
+                (Some(old), Some(new)) => match inline_pieces(old, new, granularity) {
+                    Some((old, new)) => Row::Change(Some(old), Some(new)),
+                    None => Row::Change(Some(whole(old)), Some(whole(new))),
+                },
+                (old, new) => Row::Change(old.map(|l| whole(l)), new.map(|l| whole(l))),
+            };
+            rows.push(row);
+        }
+    }
+    rows
+}
+
+/// Push `pieces` as segments no wider than `width`, drawing changed pieces
+/// with `highlight` on top of `base`. Returns the width used.
+fn push_pieces(
+    segments: &mut Vec<Segment>,
+    pieces: &[Piece],
+    width: usize,
+    base: &Style,
+    highlight: &Style,
+) -> usize {
+    let mut used = 0;
+    for (text, changed) in pieces {
+        let text = truncate_to_width(text, width - used);
+        if text.is_empty() {
+            continue;
+        }
+        used += cell_len(&text);
+        let style = if *changed {
+            base.clone() + highlight.clone()
+        } else {
+            base.clone()
+        };
+        segments.push(Segment::styled(&text, style));
+    }
+    used
+}
+
 // ---------------------------------------------------------------------------
 // Hunk
 // ---------------------------------------------------------------------------
@@ -249,6 +404,8 @@ pub struct Diff {
     style: DiffStyle,
     /// Number of unchanged context lines around each change.
     context_lines: usize,
+    /// Highlighting inside changed lines.
+    inline: InlineDiff,
 }
 
 impl Diff {
@@ -261,6 +418,7 @@ impl Diff {
             new_label: "new".to_string(),
             style: DiffStyle::Unified,
             context_lines: 3,
+            inline: InlineDiff::default(),
         }
     }
 
@@ -286,6 +444,13 @@ impl Diff {
         self
     }
 
+    /// Set the highlighting inside changed lines.
+    #[must_use]
+    pub fn with_inline_diff(mut self, inline: InlineDiff) -> Self {
+        self.inline = inline;
+        self
+    }
+
     /// Create a side-by-side diff with default settings.
     pub fn side_by_side(old_text: &str, new_text: &str) -> Self {
         Diff::new(old_text, new_text).with_style(DiffStyle::SideBySide)
@@ -322,8 +487,6 @@ impl Diff {
         let ops = self.ops();
         let hunks = build_hunks(&ops, self.context_lines);
 
-        let delete_style = Style::parse("red").unwrap_or_else(|_| Style::null());
-        let insert_style = Style::parse("green").unwrap_or_else(|_| Style::null());
         let header_del_style = Style::parse("bold red").unwrap_or_else(|_| Style::null());
         let header_ins_style = Style::parse("bold green").unwrap_or_else(|_| Style::null());
         let hunk_style = Style::parse("cyan").unwrap_or_else(|_| Style::null());
@@ -363,10 +526,16 @@ impl Diff {
             ));
             segments.push(Segment::line());
 
-            // Hunk body
-            for op in &hunk.ops {
-                match op {
-                    DiffOp::Equal(line) => {
+            // Hunk body: each block of changes lists its old lines, then
+            // its new ones.
+            let rows = hunk_rows(&hunk.ops, self.inline);
+            let mut inserted: Vec<&[Piece]> = Vec::new();
+            for row in &rows {
+                match row {
+                    Row::Equal(line) => {
+                        for pieces in inserted.drain(..) {
+                            self.push_marked(&mut segments, "+", pieces, max_width, true);
+                        }
                         let display = format!(" {}", line);
                         segments.push(Segment::styled(
                             &truncate_to_width(&display, max_width),
@@ -374,48 +543,56 @@ impl Diff {
                         ));
                         segments.push(Segment::line());
                     }
-                    DiffOp::Delete(line) => {
-                        let display = format!("-{}", line);
-                        segments.push(Segment::styled(
-                            &truncate_to_width(&display, max_width),
-                            delete_style.clone(),
-                        ));
-                        segments.push(Segment::line());
-                    }
-                    DiffOp::Insert(line) => {
-                        let display = format!("+{}", line);
-                        segments.push(Segment::styled(
-                            &truncate_to_width(&display, max_width),
-                            insert_style.clone(),
-                        ));
-                        segments.push(Segment::line());
+                    Row::Change(old, new) => {
+                        if let Some(pieces) = old {
+                            self.push_marked(&mut segments, "-", pieces, max_width, false);
+                        }
+                        if let Some(pieces) = new {
+                            inserted.push(pieces);
+                        }
                     }
                 }
             }
+            for pieces in inserted {
+                self.push_marked(&mut segments, "+", pieces, max_width, true);
+            }
         }
 
         segments
     }
 
+    /// Push a unified diff line: `marker` followed by `pieces`, in the
+    /// insert or delete style with changed pieces highlighted.
+    fn push_marked(
+        &self,
+        segments: &mut Vec<Segment>,
+        marker: &str,
+        pieces: &[Piece],
+        max_width: usize,
+        insert: bool,
+    ) {
+        let (base, highlight) = change_styles(insert);
+        if max_width > 0 {
+            segments.push(Segment::styled(marker, base.clone()));
+            push_pieces(segments, pieces, max_width - 1, &base, &highlight);
+        }
+        segments.push(Segment::line());
+    }
+
     // -- Side-by-side rendering ---------------------------------------------
 
     /// Render the diff in side-by-side format, returning segments.
     fn render_side_by_side(&self, max_width: usize) -> Vec<Segment> {
         let ops = self.ops();
+        let hunks = build_hunks(&ops, self.context_lines);
 
-        let delete_style = Style::parse("red").unwrap_or_else(|_| Style::null());
-        let insert_style = Style::parse("green").unwrap_or_else(|_| Style::null());
         let context_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
         let border_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
         let header_style = Style::parse("bold").unwrap_or_else(|_| Style::null());
+        let hunk_style = Style::parse("cyan").unwrap_or_else(|_| Style::null());
 
         let mut segments = Vec::new();
 
-        // Calculate column widths:
-        // Layout: "| " + left_num + " | " + left_text + " | " + right_num + " | " + right_text + " |"
-        // Minimal: 2 borders + separators overhead
-        // We use a simpler layout with line numbers.
-
         // Count old/new lines for number width
         let old_lines = Self::split_lines(&self.old_text);
         let new_lines = Self::split_lines(&self.new_text);
@@ -458,81 +635,74 @@ impl Diff {
         segments.push(Segment::line());
 
         // Separator
-        let sep_total = max_width;
-        let sep_line: String = "\u{2500}".repeat(sep_total);
-        segments.push(Segment::styled(
-            &truncate_to_width(&sep_line, max_width),
-            border_style.clone(),
-        ));
+        let sep_line: String = "\u{2500}".repeat(max_width);
+        segments.push(Segment::styled(&sep_line, border_style.clone()));
         segments.push(Segment::line());
 
-        // Render rows
-        let mut old_idx = 0usize;
-        let mut new_idx = 0usize;
-
-        for op in &ops {
-            match op {
-                DiffOp::Equal(line) => {
-                    old_idx += 1;
-                    new_idx += 1;
-                    let left_num = format!("{:>width$}", old_idx, width = old_num_width);
-                    let right_num = format!("{:>width$}", new_idx, width = new_num_width);
-                    let left_text = pad_or_truncate(line, left_width);
-                    let right_text = pad_or_truncate(line, right_width);
-
-                    segments.push(Segment::styled(
-                        &format!("{} | ", left_num),
-                        border_style.clone(),
-                    ));
-                    segments.push(Segment::styled(&left_text, context_style.clone()));
-                    segments.push(Segment::styled(" | ", border_style.clone()));
-                    segments.push(Segment::styled(
-                        &format!("{} | ", right_num),
-                        border_style.clone(),
-                    ));
-                    segments.push(Segment::styled(&right_text, context_style.clone()));
-                    segments.push(Segment::line());
-                }
-                DiffOp::Delete(line) => {
-                    old_idx += 1;
-                    let left_num = format!("{:>width$}", old_idx, width = old_num_width);
-                    let right_num = " ".repeat(new_num_width);
-                    let left_text = pad_or_truncate(line, left_width);
-                    let right_text = " ".repeat(right_width);
-
-                    segments.push(Segment::styled(
-                        &format!("{} | ", left_num),
-                        border_style.clone(),
-                    ));
-                    segments.push(Segment::styled(&left_text, delete_style.clone()));
-                    segments.push(Segment::styled(" | ", border_style.clone()));
-                    segments.push(Segment::styled(
-                        &format!("{} | ", right_num),
-                        border_style.clone(),
-                    ));
-                    segments.push(Segment::styled(&right_text, context_style.clone()));
-                    segments.push(Segment::line());
-                }
-                DiffOp::Insert(line) => {
-                    new_idx += 1;
-                    let left_num = " ".repeat(old_num_width);
-                    let right_num = format!("{:>width$}", new_idx, width = new_num_width);
-                    let left_text = " ".repeat(left_width);
-                    let right_text = pad_or_truncate(line, right_width);
+        for hunk in &hunks {
+            let hunk_header = format!(
+                "@@ -{},{} +{},{} @@",
+                hunk.old_start, hunk.old_count, hunk.new_start, hunk.new_count
+            );
+            segments.push(Segment::styled(
+                &truncate_to_width(&hunk_header, max_width),
+                hunk_style.clone(),
+            ));
+            segments.push(Segment::line());
 
+            let mut old_idx = hunk.old_start;
+            let mut new_idx = hunk.new_start;
+            for row in hunk_rows(&hunk.ops, self.inline) {
+                let (old, new) = match row {
+                    Row::Equal(line) => {
+                        let pieces = vec![(line, false)];
+                        (Some((pieces.clone(), None)), Some((pieces, None)))
+                    }
+                    Row::Change(old, new) => {
+                        (old.map(|p| (p, Some(false))), new.map(|p| (p, Some(true))))
+                    }
+                };
+                let sides = [
+                    (old, &mut old_idx, old_num_width, left_width),
+                    (new, &mut new_idx, new_num_width, right_width),
+                ];
+                for (side, (content, idx, num_width, width)) in sides.into_iter().enumerate() {
+                    let num = match content {
+                        Some(_) => {
+                            *idx += 1;
+                            format!("{:>width$}", *idx - 1, width = num_width)
+                        }
+                        None => " ".repeat(num_width),
+                    };
                     segments.push(Segment::styled(
-                        &format!("{} | ", left_num),
-                        border_style.clone(),
-                    ));
-                    segments.push(Segment::styled(&left_text, context_style.clone()));
-                    segments.push(Segment::styled(" | ", border_style.clone()));
-                    segments.push(Segment::styled(
-                        &format!("{} | ", right_num),
+                        &format!("{} | ", num),
                         border_style.clone(),
                     ));
-                    segments.push(Segment::styled(&right_text, insert_style.clone()));
-                    segments.push(Segment::line());
+                    let used = match content {
+                        Some((pieces, None)) => push_pieces(
+                            &mut segments,
+                            &pieces,
+                            width,
+                            &context_style,
+                            &Style::null(),
+                        ),
+                        Some((pieces, Some(insert))) => {
+                            let (base, highlight) = change_styles(insert);
+                            push_pieces(&mut segments, &pieces, width, &base, &highlight)
+                        }
+                        None => 0,
+                    };
+                    if used < width {
+                        segments.push(Segment::styled(
+                            &" ".repeat(width - used),
+                            context_style.clone(),
+                        ));
+                    }
+                    if side == 0 {
+                        segments.push(Segment::styled(" | ", border_style.clone()));
+                    }
                 }
+                segments.push(Segment::line());
             }
         }
 
@@ -557,21 +727,33 @@ fn truncate_to_width(s: &str, max_width: usize) -> String {
     if len <= max_width {
         s.to_string()
     } else {
-        // Simple byte-level truncation with respect to char boundaries
+        // Truncate on grapheme cluster boundaries
         let mut width = 0;
         let mut end = 0;
-        for (i, ch) in s.char_indices() {
-            let cw = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+        for (i, cluster, cw) in crate::cells::cell_clusters(s) {
             if width + cw > max_width {
                 break;
             }
             width += cw;
-            end = i + ch.len_utf8();
+            end = i + cluster.len();
         }
         s[..end].to_string()
     }
 }
 
+/// The line style and changed-span highlight for deletions or insertions.
+fn change_styles(insert: bool) -> (Style, Style) {
+    let (base, highlight) = if insert {
+        ("green", "on #005f00")
+    } else {
+        ("red", "on #5f0000")
+    };
+    (
+        Style::parse(base).unwrap_or_else(|_| Style::null()),
+        Style::parse(highlight).unwrap_or_else(|_| Style::null()),
+    )
+}
+
 /// Pad or truncate a string to exactly `width` cells.
 fn pad_or_truncate(s: &str, width: usize) -> String {
     let len = cell_len(s);
@@ -641,6 +823,302 @@ impl std::fmt::Display for Diff {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Structural JSON diff
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "json")]
+impl Diff {
+    /// Create a structural diff of two JSON values.
+    ///
+    /// Objects are compared key by key and arrays index by index, so the
+    /// output lists added, removed and changed values under the path that
+    /// leads to them, instead of a line diff of the serialized text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use gilt::diff::{Diff, JsonChange};
+    /// use serde_json::json;
+    ///
+    /// let old = json!({"server": {"port": 80}, "debug": true});
+    /// let new = json!({"server": {"port": 8080, "tls": true}});
+    /// let diff = Diff::json(&old, &new);
+    /// assert_eq!(diff.changes().len(), 3);
+    /// assert!(matches!(&diff.changes()[1], JsonChange::Changed { path, .. } if path == "server.port"));
+    /// ```
+    pub fn json(old: &serde_json::Value, new: &serde_json::Value) -> JsonDiff {
+        JsonDiff::new(old, new)
+    }
+}
+
+/// A single difference between two JSON values.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonChange {
+    /// A key or array element present only in the new value.
+    Added {
+        /// Path to the value, e.g. `server.ports[2]`.
+        path: String,
+        /// The added value.
+        value: serde_json::Value,
+    },
+    /// A key or array element present only in the old value.
+    Removed {
+        /// Path to the value.
+        path: String,
+        /// The removed value.
+        value: serde_json::Value,
+    },
+    /// A value that differs between the two.
+    Changed {
+        /// Path to the value.
+        path: String,
+        /// The old value.
+        old: serde_json::Value,
+        /// The new value.
+        new: serde_json::Value,
+    },
+}
+
+/// One line of a rendered [`JsonDiff`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone)]
+enum JsonLine {
+    /// An unchanged container opening or closing around changes.
+    Context { depth: usize, text: String },
+    /// An entry from the change list, labelled with its key or index.
+    Change {
+        depth: usize,
+        label: String,
+        change: usize,
+    },
+}
+
+/// A structural diff of two JSON values, created by [`Diff::json`].
+///
+/// Only the branches that contain changes are shown, indented like the
+/// JSON they come from: `+` marks added entries, `-` removed ones and `~`
+/// changed values, shown as `old → new`.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone)]
+pub struct JsonDiff {
+    changes: Vec<JsonChange>,
+    lines: Vec<JsonLine>,
+}
+
+#[cfg(feature = "json")]
+impl JsonDiff {
+    fn new(old: &serde_json::Value, new: &serde_json::Value) -> Self {
+        let mut diff = JsonDiff {
+            changes: Vec::new(),
+            lines: Vec::new(),
+        };
+        diff.compare(old, new, "", 0, String::new());
+        diff
+    }
+
+    /// The differences, in the order they are shown.
+    pub fn changes(&self) -> &[JsonChange] {
+        &self.changes
+    }
+
+    /// Whether the two values are equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Record the differences between `old` and `new`, found at `path` and
+    /// shown as `label` at `depth`.
+    fn compare(
+        &mut self,
+        old: &serde_json::Value,
+        new: &serde_json::Value,
+        path: &str,
+        depth: usize,
+        label: String,
+    ) {
+        use serde_json::Value;
+
+        if old == new {
+            return;
+        }
+        let (open, close) = match (old, new) {
+            (Value::Object(_), Value::Object(_)) => ("{", "}"),
+            (Value::Array(_), Value::Array(_)) => ("[", "]"),
+            _ => {
+                self.push_change(
+                    depth,
+                    label,
+                    JsonChange::Changed {
+                        path: path.to_string(),
+                        old: old.clone(),
+                        new: new.clone(),
+                    },
+                );
+                return;
+            }
+        };
+
+        self.lines.push(JsonLine::Context {
+            depth,
+            text: format!("{}{}", label, open),
+        });
+        match (old, new) {
+            (Value::Object(old), Value::Object(new)) => {
+                let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+                keys.sort();
+                keys.dedup();
+                for key in keys {
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    let child_label = format!("{}: ", serde_json::Value::from(key.as_str()));
+                    self.compare_entry(
+                        old.get(key),
+                        new.get(key),
+                        child_path,
+                        depth + 1,
+                        child_label,
+                    );
+                }
+            }
+            (Value::Array(old), Value::Array(new)) => {
+                for i in 0..old.len().max(new.len()) {
+                    let child_path = format!("{}[{}]", path, i);
+                    self.compare_entry(
+                        old.get(i),
+                        new.get(i),
+                        child_path,
+                        depth + 1,
+                        format!("[{}]: ", i),
+                    );
+                }
+            }
+            _ => unreachable!("containers matched above"),
+        }
+        self.lines.push(JsonLine::Context {
+            depth,
+            text: close.to_string(),
+        });
+    }
+
+    /// Compare an entry that may be missing on either side.
+    fn compare_entry(
+        &mut self,
+        old: Option<&serde_json::Value>,
+        new: Option<&serde_json::Value>,
+        path: String,
+        depth: usize,
+        label: String,
+    ) {
+        match (old, new) {
+            (Some(old), Some(new)) => self.compare(old, new, &path, depth, label),
+            (Some(value), None) => self.push_change(
+                depth,
+                label,
+                JsonChange::Removed {
+                    path,
+                    value: value.clone(),
+                },
+            ),
+            (None, Some(value)) => self.push_change(
+                depth,
+                label,
+                JsonChange::Added {
+                    path,
+                    value: value.clone(),
+                },
+            ),
+            (None, None) => {}
+        }
+    }
+
+    fn push_change(&mut self, depth: usize, label: String, change: JsonChange) {
+        self.lines.push(JsonLine::Change {
+            depth,
+            label,
+            change: self.changes.len(),
+        });
+        self.changes.push(change);
+    }
+}
+
+#[cfg(feature = "json")]
+impl Renderable for JsonDiff {
+    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let context_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        let (delete_style, _) = change_styles(false);
+        let (insert_style, _) = change_styles(true);
+        let changed_style = Style::parse("yellow").unwrap_or_else(|_| Style::null());
+        let compact = |value: &serde_json::Value| value.to_string();
+
+        let mut segments = Vec::new();
+        for line in &self.lines {
+            let mut pieces: Vec<(String, Style)> = Vec::new();
+            match line {
+                JsonLine::Context { depth, text } => {
+                    pieces.push((
+                        format!("  {}{}", "  ".repeat(*depth), text),
+                        context_style.clone(),
+                    ));
+                }
+                JsonLine::Change {
+                    depth,
+                    label,
+                    change,
+                } => {
+                    let indent = "  ".repeat(*depth);
+                    match &self.changes[*change] {
+                        JsonChange::Added { value, .. } => pieces.push((
+                            format!("+ {}{}{}", indent, label, compact(value)),
+                            insert_style.clone(),
+                        )),
+                        JsonChange::Removed { value, .. } => pieces.push((
+                            format!("- {}{}{}", indent, label, compact(value)),
+                            delete_style.clone(),
+                        )),
+                        JsonChange::Changed { old, new, .. } => {
+                            pieces.push((format!("~ {}{}", indent, label), changed_style.clone()));
+                            pieces.push((compact(old), delete_style.clone()));
+                            pieces.push((" → ".to_string(), context_style.clone()));
+                            pieces.push((compact(new), insert_style.clone()));
+                        }
+                    }
+                }
+            }
+            let mut used = 0;
+            for (text, style) in pieces {
+                let text =
+                    truncate_to_width(&text, options.max_width - used.min(options.max_width));
+                used += cell_len(&text);
+                if !text.is_empty() {
+                    segments.push(Segment::styled(&text, style));
+                }
+            }
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
+#[cfg(feature = "json")]
+impl std::fmt::Display for JsonDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1047,4 +1525,156 @@ mod tests {
         // No changes, no output
         assert!(segments.is_empty());
     }
+
+    // -- Inline highlighting and hunks ---------------------------------------
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("let x  = f(1);", InlineDiff::Word),
+            vec!["let", " ", "x", "  ", "=", " ", "f", "(", "1", ")", ";"]
+        );
+        assert_eq!(tokenize("ab c", InlineDiff::Char), vec!["a", "b", " ", "c"]);
+    }
+
+    #[test]
+    fn test_inline_pieces_flag_changed_words() {
+        let (old, new) = inline_pieces("let x = 1;", "let y = 1;", InlineDiff::Word).unwrap();
+        assert_eq!(
+            old,
+            vec![
+                ("let ".to_string(), false),
+                ("x".to_string(), true),
+                (" = 1;".to_string(), false)
+            ]
+        );
+        assert_eq!(new[1], ("y".to_string(), true));
+
+        let (old, _) = inline_pieces("colour", "color", InlineDiff::Char).unwrap();
+        assert_eq!(old[1], ("u".to_string(), true));
+
+        // Nothing in common: no inline highlight.
+        assert_eq!(inline_pieces("abc", "xyz", InlineDiff::Word), None);
+        assert_eq!(inline_pieces("a b", "a c", InlineDiff::None), None);
+    }
+
+    #[test]
+    fn test_hunk_rows_pair_deletions_with_insertions() {
+        let ops = compute_diff(&["keep", "old one", "gone"], &["keep", "new one"]);
+        let rows = hunk_rows(&ops, InlineDiff::Word);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], Row::Equal("keep".to_string()));
+        match &rows[1] {
+            Row::Change(Some(old), Some(new)) => {
+                assert_eq!(old[0], ("old".to_string(), true));
+                assert_eq!(new[0], ("new".to_string(), true));
+            }
+            row => panic!("unexpected row {:?}", row),
+        }
+        assert_eq!(
+            rows[2],
+            Row::Change(Some(vec![("gone".to_string(), false)]), None)
+        );
+    }
+
+    #[test]
+    fn test_unified_highlights_changed_span() {
+        let diff = Diff::new("let x = 1;\n", "let y = 1;\n");
+        let console = make_console();
+        let segments = diff.gilt_console(&console, &console.options());
+        let highlighted: Vec<&str> = segments
+            .iter()
+            .filter(|seg| seg.style.as_ref().and_then(|s| s.bgcolor()).is_some())
+            .map(|seg| seg.text.as_str())
+            .collect();
+        assert_eq!(highlighted, vec!["x", "y"]);
+
+        let plain = Diff::new("let x = 1;\n", "let y = 1;\n").with_inline_diff(InlineDiff::None);
+        let segments = plain.gilt_console(&console, &console.options());
+        assert!(segments
+            .iter()
+            .all(|seg| seg.style.as_ref().and_then(|s| s.bgcolor()).is_none()));
+        assert!(format!("{}", plain).contains("-let x = 1;\n+let y = 1;"));
+    }
+
+    #[test]
+    fn test_unified_lists_deletions_before_insertions() {
+        let output = format!("{}", Diff::new("a\nb\nc\n", "a\nB\nC\n"));
+        assert!(output.contains("-b\n-c\n+B\n+C"));
+    }
+
+    #[test]
+    fn test_side_by_side_hunks() {
+        let old: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+        let mut new = old.clone();
+        new[2] = "line three".to_string();
+        new[16] = "line seventeen".to_string();
+        let diff = Diff::side_by_side(&old.join("\n"), &new.join("\n")).with_context(1);
+        let output = format!("{}", diff);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[2], "@@ -2,3 +2,3 @@");
+        assert!(lines[4].starts_with(" 3 | line 3 "));
+        assert!(lines[4].contains("|  3 | line three"));
+        assert_eq!(lines[6], "@@ -16,3 +16,3 @@");
+        assert_eq!(lines.len(), 10);
+        assert!(!output.contains("line 10"));
+    }
+
+    // -- Structural JSON diff --------------------------------------------------
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_diff_changes() {
+        use serde_json::json;
+
+        let old = json!({"name": "api", "ports": [80, 443], "env": {"debug": true}});
+        let new = json!({"name": "api", "ports": [8080, 443, 9000], "env": {}});
+        let diff = Diff::json(&old, &new);
+        assert_eq!(
+            diff.changes(),
+            &[
+                JsonChange::Removed {
+                    path: "env.debug".to_string(),
+                    value: json!(true)
+                },
+                JsonChange::Changed {
+                    path: "ports[0]".to_string(),
+                    old: json!(80),
+                    new: json!(8080)
+                },
+                JsonChange::Added {
+                    path: "ports[2]".to_string(),
+                    value: json!(9000)
+                },
+            ]
+        );
+        assert!(Diff::json(&old, &old).is_empty());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_diff_rendering() {
+        use serde_json::json;
+
+        let old = json!({"server": {"port": 80, "host": "a"}, "debug": false});
+        let new = json!({"server": {"port": 8080, "host": "a", "tls": {"on": true}}});
+        let output = format!("{}", Diff::json(&old, &new));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "  {",
+                "-   \"debug\": false",
+                "    \"server\": {",
+                "~     \"port\": 80 → 8080",
+                "+     \"tls\": {\"on\":true}",
+                "    }",
+                "  }",
+            ]
+        );
+
+        // Scalars at the root are a single change.
+        let output = format!("{}", Diff::json(&json!(1), &json!("one")));
+        assert_eq!(output, "~ 1 → \"one\"");
+    }
 }
@@ -53,7 +53,7 @@ impl eyre::EyreHandler for GiltEyreHandler {
 
         let text = Text::from_markup(&markup).unwrap_or_else(|_| Text::new(&markup, Style::null()));
         let mut panel = Panel::new(text);
-        panel.title = Some(Text::new("Error Report", Style::null()));
+        panel.title = Some(Text::new("Error Report", Style::null()).into());
 
         console.print(&panel);
         let output = console.end_capture();
@@ -3,7 +3,27 @@
 //! This module provides a [`RichHandler`] that implements [`log::Log`],
 //! producing styled, formatted log output through gilt's [`Console`].
 //!
-//! Port of Python's `rich/logging.py`.
+//! Port of Python's `rich/logging.py`, with a few additions: per-target
+//! level filters, level styles and icons taken from the console's theme,
+//! rendering of structured key-value fields, and suppression of repeated
+//! messages.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gilt::logging_handler::{install_handler, RichHandler};
+//! use log::LevelFilter;
+//!
+//! let handler = RichHandler::new()
+//!     .with_level(LevelFilter::Info)
+//!     .with_target_level("hyper", LevelFilter::Warn)
+//!     .with_target_level("my_app::db", LevelFilter::Trace)
+//!     .with_theme_styles(true)
+//!     .with_suppress_duplicates(true);
+//! install_handler(handler).unwrap();
+//!
+//! log::info!(user = "ada", attempts = 3; "signed in");
+//! ```
 
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -44,6 +64,59 @@ pub struct RichHandler {
     gilt_tracebacks: bool,
     keywords: Vec<String>,
     level_styles: HashMap<log::Level, Style>,
+    /// Styles set with [`with_level_style`](Self::with_level_style), which
+    /// take precedence over theme styles.
+    style_overrides: HashMap<log::Level, Style>,
+    level_icons: HashMap<log::Level, String>,
+    theme_styles: bool,
+    show_fields: bool,
+    level: log::LevelFilter,
+    /// `(target prefix, filter)` pairs; the longest matching prefix wins.
+    target_levels: Vec<(String, log::LevelFilter)>,
+    suppress_duplicates: bool,
+    /// The last message printed and how many times it has since repeated.
+    last: Mutex<Option<(LastRecord, usize)>>,
+}
+
+/// What identifies a repeated message for duplicate suppression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LastRecord {
+    level: log::Level,
+    target: String,
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+/// Collects a record's key-value fields as display strings.
+struct FieldCollector(Vec<(String, String)>);
+
+impl<'kvs> log::kv::VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        self.0.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+}
+
+/// The record's key-value fields, in the order they were given.
+fn record_fields(record: &log::Record) -> Vec<(String, String)> {
+    let mut collector = FieldCollector(Vec::new());
+    let _ = record.key_values().visit(&mut collector);
+    collector.0
+}
+
+/// The theme style name for a level, following Python's level names.
+fn level_style_name(level: log::Level) -> &'static str {
+    match level {
+        log::Level::Error => "logging.level.error",
+        log::Level::Warn => "logging.level.warning",
+        log::Level::Info => "logging.level.info",
+        log::Level::Debug => "logging.level.debug",
+        log::Level::Trace => "logging.level.notset",
+    }
 }
 
 impl RichHandler {
@@ -60,6 +133,14 @@ impl RichHandler {
             gilt_tracebacks: false,
             keywords: DEFAULT_KEYWORDS.iter().map(|s| s.to_string()).collect(),
             level_styles: Self::default_level_styles(),
+            style_overrides: HashMap::new(),
+            level_icons: HashMap::new(),
+            theme_styles: false,
+            show_fields: true,
+            level: log::LevelFilter::Trace,
+            target_levels: Vec::new(),
+            suppress_duplicates: false,
+            last: Mutex::new(None),
         }
     }
 
@@ -105,6 +186,93 @@ impl RichHandler {
         self
     }
 
+    /// Set the level filter for targets without a more specific filter.
+    #[must_use]
+    pub fn with_level(mut self, level: log::LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the level filter for `target` and the modules below it.
+    ///
+    /// A filter for `"my_app::db"` applies to records whose target is
+    /// `my_app::db` or starts with `my_app::db::`; when several filters
+    /// match, the longest target wins.
+    #[must_use]
+    pub fn with_target_level(mut self, target: &str, level: log::LevelFilter) -> Self {
+        self.target_levels.retain(|(t, _)| t != target);
+        self.target_levels.push((target.to_string(), level));
+        self
+    }
+
+    /// Set the style of one level's column, overriding both the default
+    /// and the theme.
+    #[must_use]
+    pub fn with_level_style(mut self, level: log::Level, style: Style) -> Self {
+        self.style_overrides.insert(level, style);
+        self
+    }
+
+    /// Set an icon shown before one level's name.
+    #[must_use]
+    pub fn with_level_icon(mut self, level: log::Level, icon: &str) -> Self {
+        self.level_icons.insert(level, icon.to_string());
+        self
+    }
+
+    /// Take level styles and icons from the console's theme.
+    ///
+    /// Levels are styled with the theme's `logging.level.error`,
+    /// `logging.level.warning`, `logging.level.info`, `logging.level.debug`
+    /// and (for trace) `logging.level.notset` styles, and a level without
+    /// an icon of its own uses the theme glyph of the same name.
+    #[must_use]
+    pub fn with_theme_styles(mut self, theme_styles: bool) -> Self {
+        self.theme_styles = theme_styles;
+        self
+    }
+
+    /// Set whether a record's key-value fields are shown after the message.
+    #[must_use]
+    pub fn with_show_fields(mut self, show: bool) -> Self {
+        self.show_fields = show;
+        self
+    }
+
+    /// Set whether consecutive identical messages are collapsed.
+    ///
+    /// A message repeating the previous one (same level, target, text and
+    /// fields) is not printed; when a different message arrives, or on
+    /// [`flush`](log::Log::flush), a single line reports how many times it
+    /// repeated.
+    #[must_use]
+    pub fn with_suppress_duplicates(mut self, suppress: bool) -> Self {
+        self.suppress_duplicates = suppress;
+        self
+    }
+
+    /// The level filter that applies to `target`.
+    pub fn level_for(&self, target: &str) -> log::LevelFilter {
+        self.target_levels
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.level, |(_, level)| *level)
+    }
+
+    /// The most verbose level any filter lets through, for
+    /// [`log::set_max_level`].
+    pub fn max_level(&self) -> log::LevelFilter {
+        self.target_levels
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, std::cmp::max)
+    }
+
     /// Return the default level style map.
     fn default_level_styles() -> HashMap<log::Level, Style> {
         let mut m = HashMap::new();
@@ -151,6 +319,9 @@ impl RichHandler {
     }
 
     /// Build the level column text, left-padded to 8 chars.
+    ///
+    /// When any level has an icon, every level gets an icon slot of the
+    /// same width so messages stay aligned.
     fn render_level(&self, level: log::Level) -> Text {
         let name = match level {
             log::Level::Error => "ERROR",
@@ -159,13 +330,59 @@ impl RichHandler {
             log::Level::Debug => "DEBUG",
             log::Level::Trace => "TRACE",
         };
-        let padded = format!("{:<8}", name);
-        let style = self
-            .level_styles
+        let icons: Vec<(log::Level, String)> = log::Level::iter()
+            .filter_map(|l| self.level_icon(l).map(|icon| (l, icon)))
+            .collect();
+        let icon_width = icons
+            .iter()
+            .map(|(_, icon)| crate::cells::cell_len(icon))
+            .max()
+            .unwrap_or(0);
+        let mut padded = String::new();
+        if icon_width > 0 {
+            let icon = icons
+                .iter()
+                .find(|(l, _)| *l == level)
+                .map_or("", |(_, icon)| icon.as_str());
+            padded.push_str(icon);
+            padded.push_str(&" ".repeat(icon_width - crate::cells::cell_len(icon) + 1));
+        }
+        padded.push_str(&format!("{:<8}", name));
+        Text::styled(&padded, self.level_style(level))
+    }
+
+    /// The style for a level: an explicit override, then the theme (when
+    /// enabled), then the defaults.
+    fn level_style(&self, level: log::Level) -> Style {
+        if let Some(style) = self.style_overrides.get(&level) {
+            return style.clone();
+        }
+        if self.theme_styles {
+            if let Ok(console) = self.console.lock() {
+                if let Ok(style) = console.get_style(level_style_name(level)) {
+                    return style;
+                }
+            }
+        }
+        self.level_styles
             .get(&level)
             .cloned()
-            .unwrap_or_else(Style::null);
-        Text::styled(&padded, style)
+            .unwrap_or_else(Style::null)
+    }
+
+    /// The icon for a level: an explicit icon, then the theme glyph (when
+    /// enabled).
+    fn level_icon(&self, level: log::Level) -> Option<String> {
+        if let Some(icon) = self.level_icons.get(&level) {
+            return Some(icon.clone());
+        }
+        if !self.theme_styles {
+            return None;
+        }
+        let console = self.console.lock().ok()?;
+        console
+            .theme_glyph(level_style_name(level))
+            .map(str::to_string)
     }
 
     /// Build the message column, optionally parsing markup and highlighting keywords.
@@ -188,6 +405,21 @@ impl RichHandler {
         text
     }
 
+    /// Build the ` key=value` suffix for a record's fields.
+    fn render_fields(&self, fields: &[(String, String)]) -> Text {
+        let mut text = Text::empty();
+        if let Ok(console) = self.console.lock() {
+            let style = |name: &str| console.get_style(name).unwrap_or_else(|_| Style::null());
+            for (key, value) in fields {
+                text.append_str(" ", None);
+                text.append_str(key, Some(style("repr.attrib_name")));
+                text.append_str("=", Some(style("repr.attrib_equal")));
+                text.append_str(value, Some(style("repr.attrib_value")));
+            }
+        }
+        text
+    }
+
     /// Build the path column (`module::path:line`).
     fn render_path(record: &log::Record) -> Text {
         let dim_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
@@ -203,6 +435,49 @@ impl RichHandler {
 
     /// Compose all columns into a single line and print it.
     fn emit(&self, record: &log::Record) {
+        let fields = record_fields(record);
+        if self.suppress_duplicates {
+            let current = LastRecord {
+                level: record.level(),
+                target: record.target().to_string(),
+                message: record.args().to_string(),
+                fields: fields.clone(),
+            };
+            let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+            match last.as_mut() {
+                Some((previous, count)) if *previous == current => {
+                    *count += 1;
+                    return;
+                }
+                _ => {
+                    if let Some((previous, count)) = last.replace((current, 0)) {
+                        self.emit_repeats(previous.level, count);
+                    }
+                }
+            }
+        }
+
+        let mut message_text = self.render_message(record);
+        if self.show_fields && !fields.is_empty() {
+            message_text.append_text(&self.render_fields(&fields));
+        }
+        let path_text = self.show_path.then(|| Self::render_path(record));
+        self.print_line(record.level(), &message_text, path_text.as_ref());
+    }
+
+    /// Print the "repeated" note for a suppressed run of `count` duplicates.
+    fn emit_repeats(&self, level: log::Level, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let plural = if count == 1 { "" } else { "s" };
+        let note = format!("(previous message repeated {} more time{})", count, plural);
+        let style = Style::parse("dim italic").unwrap_or_else(|_| Style::null());
+        self.print_line(level, &Text::styled(&note, style), None);
+    }
+
+    /// Lay out the time, level, message and path columns and print them.
+    fn print_line(&self, level: log::Level, message: &Text, path: Option<&Text>) {
         let mut parts = Text::new("", Style::null());
 
         if self.show_time {
@@ -212,18 +487,16 @@ impl RichHandler {
         }
 
         if self.show_level {
-            let level_text = self.render_level(record.level());
+            let level_text = self.render_level(level);
             parts.append_text(&level_text);
             parts.append_str(" ", None);
         }
 
-        let message_text = self.render_message(record);
-        parts.append_text(&message_text);
+        parts.append_text(message);
 
-        if self.show_path {
-            let path_text = Self::render_path(record);
+        if let Some(path_text) = path {
             parts.append_str(" ", None);
-            parts.append_text(&path_text);
+            parts.append_text(path_text);
         }
 
         if let Ok(mut console) = self.console.lock() {
@@ -239,8 +512,8 @@ impl Default for RichHandler {
 }
 
 impl log::Log for RichHandler {
-    fn enabled(&self, _metadata: &log::Metadata) -> bool {
-        true
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
     }
 
     fn log(&self, record: &log::Record) {
@@ -250,7 +523,15 @@ impl log::Log for RichHandler {
     }
 
     fn flush(&self) {
-        // Console output is not buffered in a way that needs explicit flushing.
+        // Console output is not buffered; only a pending repeat count needs
+        // reporting.
+        let pending = self.last.lock().ok().and_then(|mut last| {
+            last.as_mut()
+                .map(|(r, count)| (r.level, std::mem::take(count)))
+        });
+        if let Some((level, count)) = pending {
+            self.emit_repeats(level, count);
+        }
     }
 }
 
@@ -263,9 +544,18 @@ impl log::Log for RichHandler {
 /// Sets the max log level to [`log::LevelFilter::Trace`] so all messages
 /// are forwarded to the handler.
 pub fn install() -> Result<(), log::SetLoggerError> {
-    let handler = RichHandler::new();
+    install_handler(RichHandler::new())
+}
+
+/// Install `handler` as the global logger.
+///
+/// Sets the max log level to the handler's
+/// [`max_level`](RichHandler::max_level), so records every filter would
+/// reject are skipped before they are formatted.
+pub fn install_handler(handler: RichHandler) -> Result<(), log::SetLoggerError> {
+    let max_level = handler.max_level();
     log::set_boxed_logger(Box::new(handler))?;
-    log::set_max_level(log::LevelFilter::Trace);
+    log::set_max_level(max_level);
     Ok(())
 }
 
@@ -846,4 +1136,150 @@ mod tests {
         assert!(handler.keywords.contains(&"PUT".to_string()));
         assert!(handler.keywords.contains(&"DELETE".to_string()));
     }
+
+    // -- Filtering, styles, fields and duplicates ------------------------------
+
+    /// A handler printing only level and message to a recording console.
+    fn recording_handler() -> RichHandler {
+        let console = Console::builder()
+            .width(80)
+            .no_color(true)
+            .record(true)
+            .markup(false)
+            .build();
+        RichHandler::new()
+            .with_console(console)
+            .with_show_time(false)
+            .with_show_path(false)
+    }
+
+    fn exported(handler: &RichHandler) -> String {
+        handler.console.lock().unwrap().export_text(true, false)
+    }
+
+    #[test]
+    fn test_target_level_filters() {
+        let handler = RichHandler::new()
+            .with_level(log::LevelFilter::Info)
+            .with_target_level("hyper", log::LevelFilter::Warn)
+            .with_target_level("app::db", log::LevelFilter::Trace);
+        assert_eq!(handler.level_for("app"), log::LevelFilter::Info);
+        assert_eq!(handler.level_for("hyper"), log::LevelFilter::Warn);
+        assert_eq!(handler.level_for("hyper::client"), log::LevelFilter::Warn);
+        assert_eq!(handler.level_for("hyperlocal"), log::LevelFilter::Info);
+        assert_eq!(handler.level_for("app::db::pool"), log::LevelFilter::Trace);
+        assert_eq!(handler.max_level(), log::LevelFilter::Trace);
+
+        let metadata = |level, target| {
+            log::MetadataBuilder::new()
+                .level(level)
+                .target(target)
+                .build()
+        };
+        assert!(!log::Log::enabled(
+            &handler,
+            &metadata(log::Level::Info, "hyper::proto")
+        ));
+        assert!(log::Log::enabled(
+            &handler,
+            &metadata(log::Level::Warn, "hyper")
+        ));
+        assert!(log::Log::enabled(
+            &handler,
+            &metadata(log::Level::Trace, "app::db")
+        ));
+        assert!(!log::Log::enabled(
+            &handler,
+            &metadata(log::Level::Debug, "app")
+        ));
+    }
+
+    #[test]
+    fn test_target_level_replaces_existing() {
+        let handler = RichHandler::new()
+            .with_level(log::LevelFilter::Off)
+            .with_target_level("a", log::LevelFilter::Trace)
+            .with_target_level("a", log::LevelFilter::Error);
+        assert_eq!(handler.target_levels.len(), 1);
+        assert_eq!(handler.max_level(), log::LevelFilter::Error);
+    }
+
+    #[test]
+    fn test_level_style_override_and_icons_align() {
+        let style = Style::parse("magenta").unwrap();
+        let handler = RichHandler::new()
+            .with_level_style(log::Level::Info, style.clone())
+            .with_level_icon(log::Level::Error, "✖");
+        let info = handler.render_level(log::Level::Info);
+        assert_eq!(info.plain(), "  INFO    ");
+        assert_eq!(info.spans()[0].style, style);
+        assert_eq!(
+            handler.render_level(log::Level::Error).plain(),
+            "✖ ERROR   "
+        );
+    }
+
+    #[test]
+    fn test_theme_styles_and_glyphs() {
+        let mut theme = crate::theme::Theme::new(None, true);
+        theme.styles.insert(
+            "logging.level.info".to_string(),
+            Style::parse("bold cyan").unwrap(),
+        );
+        let console = Console::builder().theme(theme).build();
+        let handler = RichHandler::new()
+            .with_console(console)
+            .with_theme_styles(true);
+        let info = handler.render_level(log::Level::Info);
+        assert_eq!(info.spans()[0].style.color().unwrap().name, "cyan");
+        // The default theme has glyphs for warnings and errors only.
+        assert_eq!(handler.render_level(log::Level::Warn).plain(), "! WARN    ");
+        assert_eq!(
+            handler.render_level(log::Level::Error).plain(),
+            "✖ ERROR   "
+        );
+        assert_eq!(info.plain(), "  INFO    ");
+    }
+
+    #[test]
+    fn test_key_value_fields() {
+        let handler = recording_handler();
+        let fields: &[(&str, &str)] = &[("user", "ada"), ("id", "7")];
+        let record = log::Record::builder()
+            .args(format_args!("signed in"))
+            .level(log::Level::Info)
+            .key_values(&fields)
+            .build();
+        handler.emit(&record);
+        assert!(exported(&handler).contains("signed in user=ada id=7"));
+
+        let handler = recording_handler().with_show_fields(false);
+        handler.emit(&record);
+        assert!(!exported(&handler).contains("user="));
+    }
+
+    #[test]
+    fn test_suppress_duplicates() {
+        let handler = recording_handler().with_suppress_duplicates(true);
+        for message in ["retrying", "retrying", "retrying", "done", "done"] {
+            handler.emit(
+                &log::Record::builder()
+                    .args(format_args!("{}", message))
+                    .level(log::Level::Warn)
+                    .build(),
+            );
+        }
+        log::Log::flush(&handler);
+        let output = exported(&handler);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            [
+                "WARN     retrying",
+                "WARN     (previous message repeated 2 more times)",
+                "WARN     done",
+                "WARN     (previous message repeated 1 more time)",
+            ]
+        );
+    }
 }
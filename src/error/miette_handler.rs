@@ -1,7 +1,10 @@
 //! Integration with the `miette` diagnostic reporting crate.
 //!
 //! When the `miette` feature is enabled, gilt provides a [`GiltMietteHandler`]
-//! that renders diagnostics using gilt's styled terminal output.
+//! that renders diagnostics using gilt's styled terminal output: source
+//! snippets with a line-number gutter (syntax highlighted when the `syntax`
+//! feature is on), labels underlined in their own color, related diagnostics
+//! as a tree, and a help / docs footer.
 //!
 //! # Setup
 //! ```ignore
@@ -9,20 +12,59 @@
 //! ```
 
 use crate::console::Console;
+use crate::live::render_to_text;
 use crate::panel::Panel;
-use crate::style::Style;
-use crate::text::Text;
-use miette::{Diagnostic, ReportHandler};
+use crate::style::{Style, UnderlineStyle};
+use crate::text::{OverflowMethod, Text};
+use crate::tree::Tree;
+use crate::utils::cells::cell_len;
+use miette::{Diagnostic, LabeledSpan, ReportHandler, Severity, SourceCode};
 use std::fmt;
 
+/// Colors given to secondary labels, in order. The primary label uses the
+/// severity color.
+const LABEL_COLORS: [&str; 5] = ["magenta", "yellow", "green", "cyan", "blue"];
+
+/// Columns a tab expands to in source snippets.
+const TAB_WIDTH: usize = 4;
+
+/// How a label's span is marked beneath the source line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LabelMarker {
+    /// A straight line (`───`) and a single underline on the code.
+    #[default]
+    Underline,
+    /// A squiggle (`~~~`) and a curly underline on the code.
+    Squiggly,
+}
+
+impl LabelMarker {
+    /// Character repeated under the labeled span.
+    pub fn symbol(&self) -> char {
+        match self {
+            LabelMarker::Underline => '─',
+            LabelMarker::Squiggly => '~',
+        }
+    }
+
+    /// Underline style applied to the labeled code itself.
+    pub fn underline_style(&self) -> UnderlineStyle {
+        match self {
+            LabelMarker::Underline => UnderlineStyle::Single,
+            LabelMarker::Squiggly => UnderlineStyle::Curly,
+        }
+    }
+}
+
 /// A miette [`ReportHandler`] that renders diagnostics using gilt's [`Console`].
 ///
-/// The handler formats errors inside a styled [`Panel`] with:
-/// - The main error message in bold red
-/// - The diagnostic code (if present)
-/// - Help text (if present)
-/// - A URL link (if present)
+/// The handler formats errors inside a [`Panel`] bordered in the severity
+/// color, with:
+/// - The severity and message, with the diagnostic code as the panel title
+/// - Source snippets for the labels, each label underlined in its own color
+/// - Related diagnostics as a [`Tree`]
 /// - The full error source chain
+/// - Help text and a docs link as a footer
 pub struct GiltMietteHandler {
     /// Whether to show the diagnostic code.
     pub show_code: bool,
@@ -30,6 +72,14 @@ pub struct GiltMietteHandler {
     pub show_url: bool,
     /// Whether to show help text.
     pub show_help: bool,
+    /// Lines of source shown before and after the labeled lines.
+    pub context_lines: usize,
+    /// How labeled spans are marked.
+    pub marker: LabelMarker,
+    /// Whether to syntax highlight source snippets (needs the `syntax` feature).
+    pub highlight_source: bool,
+    /// Syntax theme for source snippets.
+    pub theme: Option<String>,
 }
 
 impl GiltMietteHandler {
@@ -39,63 +89,418 @@ impl GiltMietteHandler {
             show_code: true,
             show_url: true,
             show_help: true,
+            context_lines: 1,
+            marker: LabelMarker::default(),
+            highlight_source: true,
+            theme: None,
         }
     }
-}
 
-impl Default for GiltMietteHandler {
-    fn default() -> Self {
-        Self::new()
+    /// Set how many lines of source to show around labeled lines.
+    #[must_use]
+    pub fn with_context_lines(mut self, lines: usize) -> Self {
+        self.context_lines = lines;
+        self
     }
-}
 
-impl ReportHandler for GiltMietteHandler {
-    fn debug(&self, error: &dyn Diagnostic, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let width = f.width().unwrap_or(80);
-        let mut console = Console::builder()
-            .width(width)
-            .force_terminal(true)
-            .no_color(false)
-            .build();
-        console.begin_capture();
+    /// Set how labeled spans are marked.
+    #[must_use]
+    pub fn with_marker(mut self, marker: LabelMarker) -> Self {
+        self.marker = marker;
+        self
+    }
 
-        // Build markup string for the panel content.
-        let title = format!("{error}");
-        let mut markup = format!("[bold red]Error:[/bold red] {title}");
+    /// Enable or disable syntax highlighting of source snippets.
+    #[must_use]
+    pub fn with_highlight_source(mut self, highlight: bool) -> Self {
+        self.highlight_source = highlight;
+        self
+    }
 
-        if self.show_code {
-            if let Some(code) = error.code() {
-                markup.push_str(&format!("\n[dim]Code:[/dim] {code}"));
-            }
-        }
+    /// Set the syntax theme used for source snippets.
+    #[must_use]
+    pub fn with_theme(mut self, theme: &str) -> Self {
+        self.theme = Some(theme.to_string());
+        self
+    }
 
-        if self.show_help {
-            if let Some(help) = error.help() {
-                markup.push_str(&format!("\n[bold cyan]Help:[/bold cyan] {help}"));
+    /// Render a diagnostic to a [`Text`] at `width` columns, as it appears
+    /// inside the panel: message, snippets, related diagnostics, causes and
+    /// footer.
+    pub fn render(&self, diagnostic: &dyn Diagnostic, width: usize) -> Text {
+        let mut lines = self.body(diagnostic, None, width);
+
+        if let Some(related) = diagnostic.related() {
+            let mut tree =
+                Tree::new(Text::styled("Related", dim_style())).with_guide_style(dim_style());
+            let mut any = false;
+            for rel in related {
+                self.add_related(&mut tree, rel, diagnostic.source_code(), width, 1);
+                any = true;
             }
-        }
-
-        if self.show_url {
-            if let Some(url) = error.url() {
-                markup.push_str(&format!("\n[blue underline]{url}[/blue underline]"));
+            if any {
+                lines.push(Text::empty());
+                lines.push(render_to_text(&tree, width.max(1)));
             }
         }
 
         // Walk the error source chain.
-        let mut source = std::error::Error::source(error);
+        let mut source = std::error::Error::source(diagnostic);
         if source.is_some() {
-            markup.push_str("\n\n[bold]Caused by:[/bold]");
+            lines.push(Text::empty());
+            lines.push(Text::styled("Caused by:", bold_style()));
         }
         let mut i = 0;
         while let Some(err) = source {
-            markup.push_str(&format!("\n  {i}. {err}"));
+            lines.push(Text::new(&format!("  {i}. {err}"), Style::null()));
             source = err.source();
             i += 1;
         }
 
-        let text = Text::from_markup(&markup).unwrap_or_else(|_| Text::new(&markup, Style::null()));
-        let mut panel = Panel::new(text);
-        panel.title = Some(Text::new("Diagnostic", Style::null()));
+        let footer = self.footer(diagnostic);
+        if !footer.is_empty() {
+            lines.push(Text::empty());
+            lines.extend(footer);
+        }
+
+        Text::new("\n", Style::null()).join(&lines)
+    }
+
+    /// Message and source snippets of one diagnostic.
+    fn body(
+        &self,
+        diagnostic: &dyn Diagnostic,
+        parent_source: Option<&dyn SourceCode>,
+        width: usize,
+    ) -> Vec<Text> {
+        let severity = diagnostic.severity().unwrap_or(Severity::Error);
+        let mut header = Text::styled(
+            &format!("{}:", severity_name(severity)),
+            severity_style(severity, true),
+        );
+        header.append_str(&format!(" {diagnostic}"), None);
+        let mut lines = vec![header];
+        let snippet = self.snippet(diagnostic, parent_source, width);
+        if !snippet.is_empty() {
+            lines.push(Text::empty());
+            lines.extend(snippet);
+        }
+        lines
+    }
+
+    /// Add `diagnostic` and, recursively, its own related diagnostics to `tree`.
+    fn add_related(
+        &self,
+        tree: &mut Tree,
+        diagnostic: &dyn Diagnostic,
+        parent_source: Option<&dyn SourceCode>,
+        width: usize,
+        depth: usize,
+    ) {
+        let inner = width.saturating_sub(4 * depth).max(1);
+        let mut lines = self.body(diagnostic, parent_source, inner);
+        if self.show_help {
+            if let Some(help) = diagnostic.help() {
+                lines.push(help_line(&help.to_string()));
+            }
+        }
+        let node = tree.add(Text::new("\n", Style::null()).join(&lines));
+        let source = diagnostic.source_code().or(parent_source);
+        if let Some(related) = diagnostic.related() {
+            for rel in related {
+                self.add_related(node, rel, source, width, depth + 1);
+            }
+        }
+    }
+
+    /// Help text and docs link.
+    fn footer(&self, diagnostic: &dyn Diagnostic) -> Vec<Text> {
+        let mut lines = Vec::new();
+        if self.show_help {
+            if let Some(help) = diagnostic.help() {
+                lines.push(help_line(&help.to_string()));
+            }
+        }
+        if self.show_url {
+            if let Some(url) = diagnostic.url() {
+                let url = url.to_string();
+                let mut line = Text::styled("docs:", bold_style() + blue_style());
+                line.append_str(" ", None);
+                let link = Style::parse("blue underline").unwrap_or_else(|_| Style::null())
+                    + Style::with_link(&url);
+                line.append_str(&url, Some(link));
+                lines.push(line);
+            }
+        }
+        lines
+    }
+
+    /// The labeled source lines of a diagnostic, with a gutter, underline
+    /// markers and label text. Empty when there is no source or no labels.
+    fn snippet(
+        &self,
+        diagnostic: &dyn Diagnostic,
+        parent_source: Option<&dyn SourceCode>,
+        width: usize,
+    ) -> Vec<Text> {
+        let Some(source) = diagnostic.source_code().or(parent_source) else {
+            return Vec::new();
+        };
+        let labels: Vec<LabeledSpan> = match diagnostic.labels() {
+            Some(labels) => labels.collect(),
+            None => return Vec::new(),
+        };
+        if labels.is_empty() {
+            return Vec::new();
+        }
+        let severity = diagnostic.severity().unwrap_or(Severity::Error);
+
+        let start = labels.iter().map(LabeledSpan::offset).min().unwrap_or(0);
+        let end = labels
+            .iter()
+            .map(|label| label.offset() + label.len())
+            .max()
+            .unwrap_or(start);
+        // Read one extra line of context on each side so the snippet starts
+        // and ends on line boundaries, then trim it back below.
+        let context = self.context_lines + 1;
+        let Ok(contents) = source.read_span(&(start, end - start).into(), context, context) else {
+            return Vec::new();
+        };
+        let Ok(data) = std::str::from_utf8(contents.data()) else {
+            return Vec::new();
+        };
+        let base = contents.span().offset();
+
+        // Source lines as (absolute byte offset, code without line ending).
+        let mut source_lines = Vec::new();
+        let mut offset = base;
+        for raw in data.split_inclusive('\n') {
+            source_lines.push((offset, raw.trim_end_matches(['\n', '\r'])));
+            offset += raw.len();
+        }
+        if source_lines.is_empty() {
+            source_lines.push((base, ""));
+        }
+        let last_labeled = end.saturating_sub(1).max(start);
+        let first = source_lines
+            .iter()
+            .rposition(|(line_start, _)| *line_start <= start)
+            .unwrap_or(0);
+        let last = source_lines
+            .iter()
+            .rposition(|(line_start, _)| *line_start <= last_labeled)
+            .unwrap_or(first);
+        let skipped = first.saturating_sub(self.context_lines);
+        source_lines.truncate(last + self.context_lines + 1);
+        source_lines.drain(..skipped);
+
+        let primary = labels.iter().position(LabeledSpan::primary).unwrap_or(0);
+        let styles: Vec<Style> = labels
+            .iter()
+            .enumerate()
+            .map(|(i, _)| {
+                if i == primary {
+                    severity_style(severity, false)
+                } else {
+                    let secondary = if i < primary { i } else { i - 1 };
+                    Style::parse(LABEL_COLORS[secondary % LABEL_COLORS.len()])
+                        .unwrap_or_else(|_| Style::null())
+                }
+            })
+            .collect();
+
+        let first_line = contents.line() + skipped + 1;
+        let number_width = (first_line + source_lines.len() - 1).to_string().len();
+        let gutter = |number: Option<usize>| {
+            let number = number.map(|n| n.to_string()).unwrap_or_default();
+            Text::styled(&format!("{number:>number_width$} │ "), dim_style())
+        };
+
+        let mut out = Vec::new();
+
+        // Header with the location of the primary label.
+        let anchor = labels[primary].offset();
+        let (line_index, (line_start, code)) = source_lines
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (line_start, _))| *line_start <= anchor)
+            .map(|(i, line)| (i, *line))
+            .unwrap_or((0, source_lines[0]));
+        let column = code
+            .get(..anchor.saturating_sub(line_start).min(code.len()))
+            .map_or(0, |prefix| prefix.chars().count())
+            + 1;
+        let location = match contents.name() {
+            Some(name) => format!("{name}:{}:{column}", first_line + line_index),
+            None => format!("{}:{column}", first_line + line_index),
+        };
+        out.push(Text::styled(
+            &format!("{:number_width$} ╭─[{location}]", ""),
+            dim_style(),
+        ));
+
+        let expanded: Vec<String> = source_lines
+            .iter()
+            .map(|(_, code)| code.replace('\t', &" ".repeat(TAB_WIDTH)))
+            .collect();
+        let language = contents
+            .language()
+            .map(str::to_string)
+            .or_else(|| contents.name().map(lexer_for_name));
+        let mut code_lines = self.highlight(&expanded, language.as_deref());
+
+        for (index, &(line_start, code)) in source_lines.iter().enumerate() {
+            let line_end = line_start + code.len();
+            let next_start = source_lines
+                .get(index + 1)
+                .map_or(usize::MAX, |(next, _)| *next);
+            let mut code_text = std::mem::replace(&mut code_lines[index], Text::empty());
+
+            // (first column, last column, style, label text) for this line.
+            let mut markers = Vec::new();
+            let mut ending = Vec::new();
+            for (label, style) in labels.iter().zip(&styles) {
+                let label_start = label.offset();
+                let label_end = label_start + label.len();
+                let touches = if label.is_empty() {
+                    label_start >= line_start && label_start < next_start
+                } else {
+                    label_start < next_start.min(line_end + 1) && label_end > line_start
+                };
+                if !touches {
+                    continue;
+                }
+                let lo = label_start.clamp(line_start, line_end) - line_start;
+                let hi = label_end.clamp(line_start, line_end) - line_start;
+                let (lo_chars, lo_cells) = columns(code, lo);
+                let (hi_chars, hi_cells) = columns(code, hi);
+                let hi_cells = hi_cells.max(lo_cells + 1);
+                code_text.stylize(
+                    self.underline(style),
+                    lo_chars,
+                    Some(hi_chars.max(lo_chars + 1)),
+                );
+                markers.push((lo_cells, hi_cells, style.clone()));
+                let last = if label.is_empty() {
+                    label_start
+                } else {
+                    label_end - 1
+                };
+                if last < next_start {
+                    if let Some(text) = label.label() {
+                        ending.push((lo_cells, style.clone(), text.to_string()));
+                    }
+                }
+            }
+
+            let mut row = gutter(Some(first_line + index));
+            row.append_text(&code_text);
+            out.push(row);
+
+            if markers.is_empty() {
+                continue;
+            }
+            let mut marker_row = gutter(None);
+            let mut column = 0;
+            let mut cells: Vec<(char, Option<Style>)> = Vec::new();
+            for (lo, hi, style) in &markers {
+                if cells.len() < *hi {
+                    cells.resize(*hi, (' ', None));
+                }
+                for cell in &mut cells[*lo..*hi] {
+                    *cell = (self.marker.symbol(), Some(style.clone()));
+                }
+            }
+            for (symbol, style) in cells {
+                marker_row.append_str(&symbol.to_string(), style);
+                column += 1;
+            }
+            ending.sort_by_key(|(col, _, _)| *col);
+            if let [(_, style, text)] = ending.as_slice() {
+                marker_row.append_str(" ", None);
+                marker_row.append_str(text, Some(style.clone()));
+                ending.clear();
+            }
+            out.push(marker_row);
+            for (col, style, text) in ending {
+                let mut label_row = gutter(None);
+                label_row.append_str(&" ".repeat(col.min(column)), None);
+                label_row.append_str(&format!("╰─ {text}"), Some(style));
+                out.push(label_row);
+            }
+        }
+        out.push(Text::styled(
+            &format!("{:number_width$} ╰─", ""),
+            dim_style(),
+        ));
+
+        for line in &mut out {
+            line.truncate(width.max(1), Some(OverflowMethod::Crop), false);
+        }
+        out
+    }
+
+    /// Style applied to a labeled span of code.
+    fn underline(&self, style: &Style) -> Style {
+        let mut underline = Style::null();
+        underline.set_underline(Some(true));
+        underline.set_underline_style(Some(self.marker.underline_style()));
+        underline.set_underline_color(style.color().cloned());
+        underline
+    }
+
+    /// One [`Text`] per source line, syntax highlighted when possible.
+    #[cfg_attr(not(feature = "syntax"), allow(unused_variables))]
+    fn highlight(&self, lines: &[String], language: Option<&str>) -> Vec<Text> {
+        #[cfg(feature = "syntax")]
+        if self.highlight_source {
+            if let Some(language) = language {
+                let mut syntax = crate::syntax::Syntax::new(&lines.join("\n"), language);
+                if let Some(theme) = &self.theme {
+                    syntax = syntax.with_theme(theme);
+                }
+                let highlighted = syntax.highlight().split("\n", false, true).lines;
+                if highlighted.len() >= lines.len() {
+                    return highlighted.into_iter().take(lines.len()).collect();
+                }
+            }
+        }
+        lines
+            .iter()
+            .map(|line| Text::new(line, Style::null()))
+            .collect()
+    }
+}
+
+impl Default for GiltMietteHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReportHandler for GiltMietteHandler {
+    fn debug(&self, error: &dyn Diagnostic, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = f.width().unwrap_or(80);
+        let mut console = Console::builder()
+            .width(width)
+            .force_terminal(true)
+            .no_color(false)
+            .build();
+        console.begin_capture();
+
+        let severity = error.severity().unwrap_or(Severity::Error);
+        let mut panel = Panel::new(self.render(error, width.saturating_sub(4)))
+            .with_border_style(severity_style(severity, false));
+        let code = if self.show_code { error.code() } else { None };
+        panel.title = Some(
+            match code {
+                Some(code) => Text::styled(&code.to_string(), severity_style(severity, true)),
+                None => Text::new("Diagnostic", Style::null()),
+            }
+            .into(),
+        );
 
         console.print(&panel);
         let output = console.end_capture();
@@ -111,6 +516,76 @@ pub fn install() {
     miette::set_hook(Box::new(|_| Box::new(GiltMietteHandler::new()))).ok();
 }
 
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "Error",
+        Severity::Warning => "Warning",
+        Severity::Advice => "Advice",
+    }
+}
+
+fn severity_style(severity: Severity, bold: bool) -> Style {
+    let color = match severity {
+        Severity::Error => "red",
+        Severity::Warning => "yellow",
+        Severity::Advice => "cyan",
+    };
+    let definition = if bold {
+        format!("bold {color}")
+    } else {
+        color.to_string()
+    };
+    Style::parse(&definition).unwrap_or_else(|_| Style::null())
+}
+
+fn dim_style() -> Style {
+    Style::parse("dim").unwrap_or_else(|_| Style::null())
+}
+
+fn bold_style() -> Style {
+    Style::parse("bold").unwrap_or_else(|_| Style::null())
+}
+
+fn blue_style() -> Style {
+    Style::parse("blue").unwrap_or_else(|_| Style::null())
+}
+
+fn help_line(help: &str) -> Text {
+    let mut line = Text::styled(
+        "help:",
+        Style::parse("bold cyan").unwrap_or_else(|_| Style::null()),
+    );
+    line.append_str(&format!(" {help}"), None);
+    line
+}
+
+/// Character index and cell column of byte offset `byte` in `code` once tabs
+/// are expanded.
+fn columns(code: &str, byte: usize) -> (usize, usize) {
+    let mut byte = byte.min(code.len());
+    while !code.is_char_boundary(byte) {
+        byte -= 1;
+    }
+    let prefix = code[..byte].replace('\t', &" ".repeat(TAB_WIDTH));
+    (prefix.chars().count(), cell_len(&prefix))
+}
+
+/// Syntax name for a source file name, from its extension.
+fn lexer_for_name(name: &str) -> String {
+    #[cfg(feature = "syntax")]
+    {
+        crate::syntax::guess_lexer(name)
+    }
+    #[cfg(not(feature = "syntax"))]
+    {
+        name.rsplit('.').next().unwrap_or("txt").to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +767,129 @@ mod tests {
         );
     }
 
+    #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+    #[error("type mismatch")]
+    #[diagnostic(
+        code(gilt::check::mismatch),
+        help("convert the value with `parse`"),
+        url("https://example.com/E0308")
+    )]
+    struct Mismatch {
+        #[source_code]
+        src: miette::NamedSource<String>,
+        #[label(primary, "found &str")]
+        found: miette::SourceSpan,
+        #[label("expected due to this")]
+        expected: miette::SourceSpan,
+        #[related]
+        related: Vec<Mismatch>,
+    }
+
+    fn mismatch() -> Mismatch {
+        Mismatch {
+            src: miette::NamedSource::new(
+                "main.rs",
+                "fn main() {\n    let x: i32 = \"hello\";\n}\n".to_string(),
+            ),
+            found: (29, 7).into(),
+            expected: (23, 3).into(),
+            related: Vec::new(),
+        }
+    }
+
+    fn plain(handler: &GiltMietteHandler, diagnostic: &dyn Diagnostic) -> String {
+        crate::testing::normalize(handler.render(diagnostic, 60).plain())
+    }
+
+    #[test]
+    fn test_snippet_gutter_markers_and_labels() {
+        let handler = GiltMietteHandler::new().with_highlight_source(false);
+        let output = plain(&handler, &mismatch());
+        assert!(output.starts_with("Error: type mismatch\n\n  ╭─[main.rs:2:18]\n"));
+        assert!(output.contains("1 │ fn main() {\n"));
+        assert!(output.contains("2 │     let x: i32 = \"hello\";\n"));
+        assert!(output.contains("  │            ───   ───────\n"));
+        assert!(output.contains("  │            ╰─ expected due to this\n"));
+        assert!(output.contains("  │                  ╰─ found &str\n"));
+        assert!(output.contains("3 │ }\n  ╰─"));
+        assert!(output
+            .ends_with("help: convert the value with `parse`\ndocs: https://example.com/E0308"));
+    }
+
+    #[test]
+    fn test_single_label_inline_and_squiggly_marker() {
+        #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+        #[error("unexpected token")]
+        struct ParseError {
+            #[source_code]
+            src: String,
+            #[label("here")]
+            span: miette::SourceSpan,
+        }
+
+        let err = ParseError {
+            src: "let x = ;".to_string(),
+            span: (8, 1).into(),
+        };
+        let handler = GiltMietteHandler::new()
+            .with_marker(LabelMarker::Squiggly)
+            .with_context_lines(0);
+        let output = plain(&handler, &err);
+        assert_eq!(
+            output,
+            "Error: unexpected token\n\n  ╭─[1:9]\n1 │ let x = ;\n  │         ~ here\n  ╰─"
+        );
+    }
+
+    #[test]
+    fn test_label_styles_underline_code() {
+        let handler = GiltMietteHandler::new()
+            .with_highlight_source(false)
+            .with_marker(LabelMarker::Squiggly);
+        let text = handler.render(&mismatch(), 60);
+        let plain = text.plain().to_string();
+        let start = plain[..plain.find("\"hello\"").unwrap()].chars().count();
+        let span = text
+            .spans()
+            .iter()
+            .find(|span| span.start == start && span.style.underline_style().is_some())
+            .expect("labeled code is underlined");
+        assert_eq!(span.end, start + 7);
+        assert_eq!(span.style.underline_style(), Some(UnderlineStyle::Curly));
+        assert_eq!(
+            span.style.underline_color(),
+            Style::parse("red").unwrap().color()
+        );
+    }
+
+    #[test]
+    fn test_related_diagnostics_render_as_tree() {
+        let mut err = mismatch();
+        let mut nested = mismatch();
+        nested.related.push(mismatch());
+        err.related.push(nested);
+        let output = plain(&GiltMietteHandler::new(), &err);
+        assert!(output.contains("\nRelated\n└── Error: type mismatch\n"));
+        assert!(output.contains("    └── Error: type mismatch\n"));
+        assert_eq!(output.matches("╭─[main.rs:2:18]").count(), 3);
+    }
+
+    #[test]
+    fn test_panel_title_and_severity_border() {
+        #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+        #[error("careful")]
+        #[diagnostic(code(gilt::warn), severity(Warning))]
+        struct Careful;
+
+        let handler = GiltMietteHandler::new();
+        let output = format!("{:60}", DisplayViaDebugHandler(&handler, &Careful));
+        let plain = crate::testing::strip_ansi(&output);
+        assert!(plain.starts_with("╭─"));
+        assert!(plain.contains(" gilt::warn "));
+        assert!(plain.contains("Warning: careful"));
+        assert!(output.contains("\x1b[33m╭"));
+    }
+
     /// Helper to format a diagnostic through the GiltMietteHandler's debug method.
     struct DisplayViaDebugHandler<'a, E: miette::Diagnostic>(&'a GiltMietteHandler, &'a E);
 
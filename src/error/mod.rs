@@ -10,6 +10,9 @@ pub mod eyre_handler;
 pub mod logging_handler;
 #[cfg(feature = "miette")]
 pub mod miette_handler;
+pub mod result;
+#[cfg(feature = "tracing")]
+pub mod span_dashboard;
 pub mod traceback;
 #[cfg(feature = "tracing")]
 pub mod tracing_layer;
@@ -148,6 +151,23 @@ pub enum MarkupError {
     },
 }
 
+/// Errors that can occur when parsing a box-drawing specification.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BoxCharsError {
+    /// The specification has neither 4 nor 8 lines.
+    #[error("box specification must have 4 or 8 lines, got {0}")]
+    LineCount(usize),
+
+    /// A line of the specification is not exactly 4 characters long.
+    #[error("box specification line {line} must have 4 characters, got {chars}")]
+    LineLength {
+        /// 1-based line number.
+        line: usize,
+        /// Number of characters found on the line.
+        chars: usize,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,15 +434,15 @@ mod tests {
     #[test]
     fn test_all_errors_implement_debug() {
         // Verify Debug implementation works for all error types
-        format!(
+        let _ = format!(
             "{:?}",
             ColorParseError::InvalidHexFormat("test".to_string())
         );
-        format!("{:?}", StyleError::InvalidSyntax("test".to_string()));
-        format!("{:?}", ConsoleError::Generic("test".to_string()));
-        format!("{:?}", SegmentError::InvalidSegment("test".to_string()));
-        format!("{:?}", CellError::InvalidWidth("test".to_string()));
-        format!("{:?}", PaletteError::InvalidIndex(0));
+        let _ = format!("{:?}", StyleError::InvalidSyntax("test".to_string()));
+        let _ = format!("{:?}", ConsoleError::Generic("test".to_string()));
+        let _ = format!("{:?}", SegmentError::InvalidSegment("test".to_string()));
+        let _ = format!("{:?}", CellError::InvalidWidth("test".to_string()));
+        let _ = format!("{:?}", PaletteError::InvalidIndex(0));
     }
 
     #[test]
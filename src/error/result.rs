@@ -0,0 +1,227 @@
+//! Styled reporting for `Result` errors.
+//!
+//! [`ReportExt`] adds `.report()` and `.unwrap_or_report()` to any
+//! `Result<T, E>` whose error implements [`std::error::Error`]. The error and
+//! its `source()` chain are printed to stderr as a tree inside a red panel,
+//! giving eyre/miette-style output for plain std errors without either
+//! dependency.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gilt::result::ReportExt;
+//!
+//! let contents = std::fs::read_to_string("config.toml").unwrap_or_report();
+//! ```
+
+use std::error::Error;
+
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::panel::Panel;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{Text, TextPart};
+use crate::tree::Tree;
+
+// ---------------------------------------------------------------------------
+// ErrorReport
+// ---------------------------------------------------------------------------
+
+/// A renderable error report: the error message with its chain of causes
+/// drawn as a tree inside a panel.
+pub struct ErrorReport<'a> {
+    error: &'a dyn Error,
+    /// Panel title.
+    pub title: String,
+}
+
+impl<'a> ErrorReport<'a> {
+    /// Create a report for `error`.
+    pub fn new(error: &'a dyn Error) -> Self {
+        ErrorReport {
+            error,
+            title: "Error".to_string(),
+        }
+    }
+
+    /// Set the panel title.
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// The error's message followed by the message of each `source()`,
+    /// outermost first.
+    pub fn chain(&self) -> Vec<String> {
+        let mut messages = vec![self.error.to_string()];
+        let mut source = self.error.source();
+        while let Some(cause) = source {
+            messages.push(cause.to_string());
+            source = cause.source();
+        }
+        messages
+    }
+
+    /// Build the tree with each cause nested under the error it caused.
+    fn tree(&self) -> Tree {
+        let mut chain = self.chain().into_iter();
+        let message = chain.next().unwrap_or_default();
+        let mut tree = Tree::new(Text::styled(
+            &message,
+            Style::parse("bold").unwrap_or_else(|_| Style::null()),
+        ))
+        .with_guide_style(Style::parse("red").unwrap_or_else(|_| Style::null()));
+        let mut node = &mut tree;
+        for cause in chain {
+            node = node.add(Text::new(&cause, Style::null()));
+        }
+        tree
+    }
+}
+
+impl std::fmt::Display for ErrorReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let chain = self.chain();
+        write!(f, "{}", chain[0])?;
+        if chain.len() > 1 {
+            write!(f, "\n\nCaused by:")?;
+            for (i, cause) in chain[1..].iter().enumerate() {
+                write!(f, "\n  {}: {}", i, cause)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Renderable for ErrorReport<'_> {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let tree_options = options.update_width(options.max_width.saturating_sub(4));
+        let parts: Vec<TextPart> = self
+            .tree()
+            .gilt_console(console, &tree_options)
+            .into_iter()
+            .filter(|seg| !seg.is_control())
+            .map(|seg| match seg.style {
+                Some(style) => TextPart::Styled(seg.text.to_string(), style),
+                None => TextPart::Raw(seg.text.to_string()),
+            })
+            .collect();
+        let mut content = Text::assemble(&parts, Style::null());
+        content.remove_suffix("\n");
+
+        Panel::new(content)
+            .with_title(Text::styled(
+                &self.title,
+                Style::parse("bold red").unwrap_or_else(|_| Style::null()),
+            ))
+            .with_border_style(Style::parse("red").unwrap_or_else(|_| Style::null()))
+            .gilt_console(console, options)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ReportExt
+// ---------------------------------------------------------------------------
+
+/// Report the error of a `Result` with gilt's styled output.
+pub trait ReportExt<T>: Sized {
+    /// Print the error report to stderr if this is an `Err`, and return the
+    /// result unchanged.
+    fn report(self) -> Self;
+
+    /// Print the error report to `console` if this is an `Err`, and return
+    /// the result unchanged.
+    fn report_to(self, console: &mut Console) -> Self;
+
+    /// Return the `Ok` value, or print the error report to stderr and exit
+    /// the process with status 1.
+    fn unwrap_or_report(self) -> T;
+}
+
+impl<T, E: Error> ReportExt<T> for Result<T, E> {
+    fn report(self) -> Self {
+        let mut console = Console::builder().stderr(true).build();
+        self.report_to(&mut console)
+    }
+
+    fn report_to(self, console: &mut Console) -> Self {
+        if let Err(ref error) = self {
+            console.print(&ErrorReport::new(error));
+        }
+        self
+    }
+
+    fn unwrap_or_report(self) -> T {
+        match self.report() {
+            Ok(value) => value,
+            Err(_) => std::process::exit(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("failed to load config")]
+    struct ConfigError(#[source] std::io::Error);
+
+    fn config_error() -> ConfigError {
+        ConfigError(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "permission denied",
+        ))
+    }
+
+    fn capture_console() -> Console {
+        Console::builder()
+            .width(50)
+            .force_terminal(true)
+            .no_color(true)
+            .build()
+    }
+
+    #[test]
+    fn test_chain_walks_sources() {
+        let error = config_error();
+        let report = ErrorReport::new(&error);
+        assert_eq!(
+            report.chain(),
+            vec!["failed to load config", "permission denied"]
+        );
+        assert_eq!(
+            report.to_string(),
+            "failed to load config\n\nCaused by:\n  0: permission denied"
+        );
+    }
+
+    #[test]
+    fn test_report_renders_tree_in_panel() {
+        let error = config_error();
+        let mut console = capture_console();
+        console.begin_capture();
+        console.print(&ErrorReport::new(&error));
+        let output = console.end_capture();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].contains(" Error "));
+        assert!(lines[1].contains("failed to load config"));
+        assert!(lines[2].contains("└── permission denied"));
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn test_report_to_prints_only_errors() {
+        let mut console = capture_console();
+        console.begin_capture();
+        let ok: Result<u8, ConfigError> = Ok(3);
+        assert_eq!(ok.report_to(&mut console).unwrap(), 3);
+        assert_eq!(console.end_capture(), "");
+
+        console.begin_capture();
+        let err: Result<u8, ConfigError> = Err(config_error());
+        assert!(err.report_to(&mut console).is_err());
+        assert!(console.end_capture().contains("permission denied"));
+    }
+}
@@ -0,0 +1,503 @@
+//! A live table of the [`tracing`](https://docs.rs/tracing) spans that are
+//! currently open.
+//!
+//! [`GiltSpanDashboard`] is a tracing layer that keeps track of every open
+//! span: when it was created, how long it has spent entered (busy) and how
+//! long it has waited between entries (idle). Its [`table`](GiltSpanDashboard::table)
+//! lists them, and [`into_live`](GiltSpanDashboard::into_live) refreshes that
+//! table in a [`Live`] display -- a small `tokio-console` for quick
+//! diagnostics of what an application is doing right now.
+//!
+//! Enable with the `tracing` Cargo feature flag.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use gilt::span_dashboard::GiltSpanDashboard;
+//! use tracing_subscriber::prelude::*;
+//!
+//! let dashboard = GiltSpanDashboard::new();
+//! tracing_subscriber::registry().with(dashboard.clone()).init();
+//!
+//! let mut live = dashboard.into_live();
+//! live.start();
+//! // ... run the application; open spans are listed as they come and go ...
+//! live.stop();
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::live::{render_to_text, Live};
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::table::{ColumnOptions, Table};
+use crate::text::{JustifyMethod, Text};
+
+/// Default number of spans listed in the table.
+const DEFAULT_MAX_ROWS: usize = 20;
+
+// ---------------------------------------------------------------------------
+// SpanStatus
+// ---------------------------------------------------------------------------
+
+/// A snapshot of one open span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanStatus {
+    /// The span's name.
+    pub name: String,
+    /// The span's target (usually its module path).
+    pub target: String,
+    /// Number of open ancestor spans.
+    pub depth: usize,
+    /// The span's fields as `(name, value)` pairs.
+    pub fields: Vec<(String, String)>,
+    /// Time since the span was created.
+    pub elapsed: Duration,
+    /// Total time the span has been entered.
+    pub busy: Duration,
+    /// Whether the span is entered right now.
+    pub active: bool,
+}
+
+impl SpanStatus {
+    /// Time the span has been open but not entered.
+    pub fn idle(&self) -> Duration {
+        self.elapsed.saturating_sub(self.busy)
+    }
+}
+
+/// Live bookkeeping for one open span.
+struct SpanEntry {
+    name: &'static str,
+    target: &'static str,
+    parent: Option<u64>,
+    depth: usize,
+    fields: Vec<(String, String)>,
+    created: Instant,
+    busy: Duration,
+    /// Number of threads currently inside the span and when the first
+    /// of them entered.
+    entered: usize,
+    entered_at: Option<Instant>,
+}
+
+impl SpanEntry {
+    fn status(&self, now: Instant) -> SpanStatus {
+        let current = self.entered_at.map_or(Duration::ZERO, |at| now - at);
+        SpanStatus {
+            name: self.name.to_string(),
+            target: self.target.to_string(),
+            depth: self.depth,
+            fields: self.fields.clone(),
+            elapsed: now - self.created,
+            busy: self.busy + current,
+            active: self.entered > 0,
+        }
+    }
+}
+
+/// Collects span fields as display strings.
+struct FieldCollector(Vec<(String, String)>);
+
+impl FieldCollector {
+    fn set(&mut self, field: &Field, value: String) {
+        match self.0.iter_mut().find(|(name, _)| name == field.name()) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((field.name().to_string(), value)),
+        }
+    }
+}
+
+impl Visit for FieldCollector {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.set(field, format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.set(field, value.to_string());
+    }
+}
+
+/// Format a duration for the table: `850µs`, `12.3ms`, `4.20s`, `3m05s`.
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.1}ms", micros as f64 / 1000.0)
+    } else if duration.as_secs() < 60 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        format!(
+            "{}m{:02}s",
+            duration.as_secs() / 60,
+            duration.as_secs() % 60
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GiltSpanDashboard
+// ---------------------------------------------------------------------------
+
+/// A [`tracing_subscriber::Layer`] that tracks open spans and shows them as
+/// a table.
+///
+/// The table has one row per open span, oldest first, with the span name
+/// (indented under its parent), its fields, and how long it has been open,
+/// busy and idle. Spans entered right now are marked with `●`.
+///
+/// `GiltSpanDashboard` is cheap to clone; clones share the same span data,
+/// so one clone is installed as a layer while another drives the display.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::span_dashboard::GiltSpanDashboard;
+/// use tracing_subscriber::prelude::*;
+///
+/// let dashboard = GiltSpanDashboard::new();
+/// let subscriber = tracing_subscriber::registry().with(dashboard.clone());
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _request = tracing::info_span!("request", id = 7).entered();
+///     let spans = dashboard.spans();
+///     assert_eq!(spans[0].name, "request");
+///     assert!(spans[0].active);
+/// });
+/// assert!(dashboard.spans().is_empty());
+/// ```
+#[derive(Clone)]
+pub struct GiltSpanDashboard {
+    spans: Arc<Mutex<BTreeMap<u64, SpanEntry>>>,
+    max_rows: usize,
+    refresh_per_second: f64,
+}
+
+impl GiltSpanDashboard {
+    /// Create a dashboard with no spans.
+    pub fn new() -> Self {
+        Self {
+            spans: Arc::new(Mutex::new(BTreeMap::new())),
+            max_rows: DEFAULT_MAX_ROWS,
+            refresh_per_second: 4.0,
+        }
+    }
+
+    /// Set the maximum number of spans listed (builder pattern). Spans
+    /// beyond it are counted in the table caption.
+    #[must_use]
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows.max(1);
+        self
+    }
+
+    /// Set how often [`into_live`](Self::into_live) redraws the table
+    /// (builder pattern).
+    #[must_use]
+    pub fn with_refresh_per_second(mut self, rate: f64) -> Self {
+        self.refresh_per_second = rate;
+        self
+    }
+
+    /// Snapshot the open spans in tree order: each span is followed by its
+    /// children, and siblings are ordered oldest first.
+    pub fn spans(&self) -> Vec<SpanStatus> {
+        let now = Instant::now();
+        let spans = self.spans.lock().unwrap();
+        let mut order: Vec<(u64, &SpanEntry)> = spans.iter().map(|(id, e)| (*id, e)).collect();
+        order.sort_by_key(|(_, entry)| entry.created);
+
+        // Spans whose parent is closed (or untracked) are shown as roots.
+        let is_root = |entry: &SpanEntry| entry.parent.is_none_or(|p| !spans.contains_key(&p));
+        let mut stack: Vec<u64> = order
+            .iter()
+            .rev()
+            .filter(|(_, entry)| is_root(entry))
+            .map(|(id, _)| *id)
+            .collect();
+        let mut statuses = Vec::with_capacity(order.len());
+        while let Some(id) = stack.pop() {
+            statuses.push(spans[&id].status(now));
+            stack.extend(
+                order
+                    .iter()
+                    .rev()
+                    .filter(|(_, entry)| entry.parent == Some(id))
+                    .map(|(child, _)| *child),
+            );
+        }
+        statuses
+    }
+
+    /// Build the table of open spans.
+    pub fn table(&self) -> Table {
+        let spans = self.spans();
+        let mut table = Table::new(&[]).with_title("Active spans");
+        let right = || ColumnOptions {
+            justify: Some(JustifyMethod::Right),
+            no_wrap: true,
+            ..Default::default()
+        };
+        table.add_column(
+            "Span",
+            "",
+            ColumnOptions {
+                no_wrap: true,
+                ..Default::default()
+            },
+        );
+        table.add_column("Fields", "", ColumnOptions::default());
+        table.add_column("Duration", "", right());
+        table.add_column("Busy", "", right());
+        table.add_column("Idle", "", right());
+
+        let active_style = Style::parse("bold green").unwrap_or_else(|_| Style::null());
+        let idle_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        let field_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        for status in spans.iter().take(self.max_rows) {
+            let mut name = Text::new(&"  ".repeat(status.depth), Style::null());
+            if status.active {
+                name.append_str("● ", Some(active_style.clone()));
+            } else {
+                name.append_str("○ ", Some(idle_style.clone()));
+            }
+            name.append_str(&status.name, None);
+
+            let fields = status
+                .fields
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join(" ");
+            table.add_row_text(&[
+                name,
+                Text::styled(&fields, field_style.clone()),
+                Text::new(&format_duration(status.elapsed), Style::null()),
+                Text::new(&format_duration(status.busy), Style::null()),
+                Text::new(&format_duration(status.idle()), Style::null()),
+            ]);
+        }
+
+        let hidden = spans.len().saturating_sub(self.max_rows);
+        if hidden > 0 {
+            table = table.with_caption(&format!("… and {} more", hidden));
+        } else if spans.is_empty() {
+            table = table.with_caption("no open spans");
+        }
+        table
+    }
+
+    /// Render the table at `width` columns into a single [`Text`], for a
+    /// [`Live`] display.
+    pub fn to_text(&self, width: usize) -> Text {
+        render_to_text(&self.table(), width)
+    }
+
+    /// Create a [`Live`] display that redraws the table periodically.
+    pub fn into_live(self) -> Live {
+        let live = Live::new(Text::empty()).with_refresh_per_second(self.refresh_per_second);
+        let width = live.console().width();
+        live.with_get_renderable(move || self.to_text(width))
+    }
+}
+
+impl Default for GiltSpanDashboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for GiltSpanDashboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GiltSpanDashboard")
+            .field("open_spans", &self.spans.lock().unwrap().len())
+            .field("max_rows", &self.max_rows)
+            .finish()
+    }
+}
+
+impl Renderable for GiltSpanDashboard {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.table().gilt_console(console, options)
+    }
+}
+
+impl<S> Layer<S> for GiltSpanDashboard
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = FieldCollector(Vec::new());
+        attrs.record(&mut fields);
+        let span = ctx.span(id);
+        let depth = span.as_ref().map_or(0, |span| span.scope().skip(1).count());
+        let parent = span
+            .and_then(|span| span.parent())
+            .map(|p| p.id().into_u64());
+        let metadata = attrs.metadata();
+        self.spans.lock().unwrap().insert(
+            id.into_u64(),
+            SpanEntry {
+                name: metadata.name(),
+                target: metadata.target(),
+                parent,
+                depth,
+                fields: fields.0,
+                created: Instant::now(),
+                busy: Duration::ZERO,
+                entered: 0,
+                entered_at: None,
+            },
+        );
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(entry) = spans.get_mut(&id.into_u64()) {
+            let mut fields = FieldCollector(std::mem::take(&mut entry.fields));
+            values.record(&mut fields);
+            entry.fields = fields.0;
+        }
+    }
+
+    fn on_enter(&self, id: &Id, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(entry) = spans.get_mut(&id.into_u64()) {
+            if entry.entered == 0 {
+                entry.entered_at = Some(Instant::now());
+            }
+            entry.entered += 1;
+        }
+    }
+
+    fn on_exit(&self, id: &Id, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(entry) = spans.get_mut(&id.into_u64()) {
+            entry.entered = entry.entered.saturating_sub(1);
+            if entry.entered == 0 {
+                if let Some(at) = entry.entered_at.take() {
+                    entry.busy += at.elapsed();
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        self.spans.lock().unwrap().remove(&id.into_u64());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::prelude::*;
+
+    fn with_dashboard(dashboard: &GiltSpanDashboard, f: impl FnOnce()) {
+        let subscriber = tracing_subscriber::registry().with(dashboard.clone());
+        tracing::subscriber::with_default(subscriber, f);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(12)), "12µs");
+        assert_eq!(format_duration(Duration::from_micros(2_500)), "2.5ms");
+        assert_eq!(format_duration(Duration::from_millis(4_200)), "4.20s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m05s");
+    }
+
+    #[test]
+    fn test_tracks_open_spans_and_nesting() {
+        let dashboard = GiltSpanDashboard::new();
+        with_dashboard(&dashboard, || {
+            let outer = tracing::info_span!("server", port = 8080u64);
+            let _outer = outer.enter();
+            let inner = tracing::debug_span!("request", path = "/users");
+            inner.record("path", "/items");
+            let spans = dashboard.spans();
+            assert_eq!(spans.len(), 2);
+            assert_eq!((spans[0].name.as_str(), spans[0].depth), ("server", 0));
+            assert_eq!((spans[1].name.as_str(), spans[1].depth), ("request", 1));
+            assert!(spans[0].active);
+            assert!(!spans[1].active);
+            assert_eq!(
+                spans[1].fields,
+                [("path".to_string(), "/items".to_string())]
+            );
+            drop(inner);
+            assert_eq!(dashboard.spans().len(), 1);
+        });
+        assert!(dashboard.spans().is_empty());
+    }
+
+    #[test]
+    fn test_spans_are_listed_in_tree_order() {
+        let dashboard = GiltSpanDashboard::new();
+        with_dashboard(&dashboard, || {
+            let a = tracing::info_span!("a");
+            let b = tracing::info_span!("b");
+            let a_child = a.in_scope(|| tracing::info_span!("a.child"));
+            let names: Vec<String> = dashboard.spans().into_iter().map(|s| s.name).collect();
+            assert_eq!(names, ["a", "a.child", "b"]);
+            drop((a, b, a_child));
+        });
+    }
+
+    #[test]
+    fn test_busy_and_idle_time() {
+        let dashboard = GiltSpanDashboard::new();
+        with_dashboard(&dashboard, || {
+            let span = tracing::info_span!("work");
+            {
+                let _guard = span.enter();
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            std::thread::sleep(Duration::from_millis(20));
+            let status = &dashboard.spans()[0];
+            assert!(!status.active);
+            assert!(status.busy >= Duration::from_millis(20));
+            assert!(status.idle() >= Duration::from_millis(20));
+            assert!(status.elapsed >= status.busy + Duration::from_millis(20));
+        });
+    }
+
+    #[test]
+    fn test_table_rows_and_caption() {
+        let dashboard = GiltSpanDashboard::new().with_max_rows(1);
+        let empty = crate::testing::render_plain(&dashboard, 60);
+        assert!(empty.contains("no open spans"));
+        with_dashboard(&dashboard, || {
+            let _a = tracing::info_span!("alpha", n = 1u64).entered();
+            let _b = tracing::info_span!("beta").entered();
+            let out = crate::testing::render_plain(&dashboard, 60);
+            assert!(out.contains("Active spans"));
+            assert!(out.contains("● alpha"));
+            assert!(out.contains("n=1"));
+            assert!(!out.contains("beta"));
+            assert!(out.contains("… and 1 more"));
+        });
+    }
+
+    #[test]
+    fn test_to_text_width() {
+        let dashboard = GiltSpanDashboard::new();
+        let text = dashboard.to_text(40);
+        assert!(text
+            .plain()
+            .lines()
+            .all(|line| crate::cells::cell_len(line) <= 40));
+    }
+}
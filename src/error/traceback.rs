@@ -106,6 +106,9 @@ pub struct Traceback {
     pub word_wrap: bool,
     /// Maximum number of frames to display.
     pub max_frames: usize,
+    /// Frames whose function name or file path starts with one of these
+    /// prefixes are hidden.
+    suppress: Vec<String>,
 }
 
 impl Traceback {
@@ -121,6 +124,7 @@ impl Traceback {
             theme: "base16-ocean.dark".to_string(),
             word_wrap: true,
             max_frames: 100,
+            suppress: Vec::new(),
         }
     }
 
@@ -203,6 +207,13 @@ impl Traceback {
         self
     }
 
+    /// Set the stack frames, ordered from outermost to innermost.
+    #[must_use]
+    pub fn with_frames(mut self, frames: Vec<Frame>) -> Self {
+        self.frames = frames;
+        self
+    }
+
     /// Set whether to show locals (reserved for future use).
     #[must_use]
     pub fn with_show_locals(mut self, show: bool) -> Self {
@@ -245,6 +256,29 @@ impl Traceback {
         self
     }
 
+    /// Hide frames whose function name or file path starts with any of
+    /// `prefixes`, e.g. `&["std::", "/rustc/"]`.
+    #[must_use]
+    pub fn with_suppress(mut self, prefixes: &[&str]) -> Self {
+        self.suppress = prefixes.iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    /// Whether `frame` is hidden by the suppress list.
+    pub fn is_suppressed(&self, frame: &Frame) -> bool {
+        self.suppress.iter().any(|prefix| {
+            frame.name.starts_with(prefix.as_str()) || frame.filename.starts_with(prefix.as_str())
+        })
+    }
+
+    /// The frames that survive the suppress list, in display order.
+    fn visible_frames(&self) -> Vec<&Frame> {
+        self.frames
+            .iter()
+            .filter(|frame| !self.is_suppressed(frame))
+            .collect()
+    }
+
     // -- Internal rendering -------------------------------------------------
 
     /// Build the inner content `Text` that goes inside the Panel.
@@ -256,7 +290,8 @@ impl Traceback {
         let mut parts: Vec<TextPart> = Vec::new();
 
         // Determine how many frames to show
-        let frame_count = self.frames.len();
+        let frames = self.visible_frames();
+        let frame_count = frames.len();
         let show_count = frame_count.min(self.max_frames);
         let truncated = frame_count > self.max_frames;
 
@@ -284,7 +319,7 @@ impl Traceback {
                 ));
             }
 
-            let frame = &self.frames[frame_idx];
+            let frame = frames[frame_idx];
 
             // File location line
             let location = match frame.lineno {
@@ -332,7 +367,7 @@ impl std::fmt::Display for Traceback {
         if !self.title.is_empty() {
             writeln!(f, "{}", self.title)?;
         }
-        for frame in &self.frames {
+        for frame in self.visible_frames() {
             writeln!(f, "{}", frame)?;
         }
         if !self.message.is_empty() {
@@ -355,17 +390,18 @@ impl Renderable for Traceback {
         let mut content_parts: Vec<TextPart> = Vec::new();
 
         // Determine how many frames to show
-        let frame_count = self.frames.len();
+        let frames = self.visible_frames();
+        let frame_count = frames.len();
         let show_count = frame_count.min(self.max_frames);
         let truncated = frame_count > self.max_frames;
 
         let frames_to_show: Vec<&Frame> = if truncated {
             let half = self.max_frames / 2;
-            let mut combined: Vec<&Frame> = self.frames.iter().take(half).collect();
-            combined.extend(self.frames.iter().skip(frame_count - half));
+            let mut combined: Vec<&Frame> = frames[..half].to_vec();
+            combined.extend_from_slice(&frames[frame_count - half..]);
             combined
         } else {
-            self.frames.iter().collect()
+            frames
         };
 
         let actual_show = frames_to_show.len();
@@ -403,61 +439,63 @@ impl Renderable for Traceback {
             content_parts.push(TextPart::Raw("\n".to_string()));
 
             // Source context: try to read the file and show context lines
+            let context = frame
+                .lineno
+                .and_then(|lineno| source_context(&frame.filename, lineno, self.extra_lines));
             #[allow(unused_mut)]
-            let mut showed_syntax = false;
+            let mut showed_context = false;
 
             #[cfg(feature = "syntax")]
-            if let Some(lineno) = frame.lineno {
-                if lineno > 0 {
-                    let path = std::path::Path::new(&frame.filename);
-                    if (path.is_absolute() || frame.filename.starts_with("./")) && path.exists() {
-                        if let Ok(file_contents) = std::fs::read_to_string(path) {
-                            let total_lines = file_contents.lines().count();
-                            if lineno <= total_lines {
-                                let start = lineno.saturating_sub(self.extra_lines).max(1);
-                                let end = (lineno + self.extra_lines).min(total_lines);
-
-                                let context: String = file_contents
-                                    .lines()
-                                    .enumerate()
-                                    .filter(|(i, _)| {
-                                        let n = i + 1;
-                                        n >= start && n <= end
-                                    })
-                                    .map(|(_, line)| line)
-                                    .collect::<Vec<_>>()
-                                    .join("\n");
-
-                                // Determine language from file extension
-                                let ext =
-                                    path.extension().and_then(|e| e.to_str()).unwrap_or("txt");
-
-                                let syntax = Syntax::new(&context, ext)
-                                    .with_theme(&self.theme)
-                                    .with_line_numbers(true)
-                                    .with_start_line(start)
-                                    .with_highlight_lines(vec![lineno])
-                                    .with_word_wrap(self.word_wrap);
-
-                                let syntax_segments = syntax.gilt_console(
-                                    console,
-                                    &options.update_width(panel_width.saturating_sub(4)),
-                                );
-                                if !syntax_segments.is_empty() {
-                                    // Collect syntax output as a styled text block
-                                    for seg in &syntax_segments {
-                                        content_parts.push(TextPart::Raw(seg.text.to_string()));
-                                    }
-                                    showed_syntax = true;
-                                }
-                            }
-                        }
+            if let (Some((start, lines)), Some(lineno)) = (&context, frame.lineno) {
+                let ext = std::path::Path::new(&frame.filename)
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or("txt");
+
+                let syntax = Syntax::new(&lines.join("\n"), ext)
+                    .with_theme(&self.theme)
+                    .with_line_numbers(true)
+                    .with_start_line(*start)
+                    .with_highlight_lines(vec![lineno])
+                    .with_word_wrap(self.word_wrap);
+
+                let syntax_segments = syntax.gilt_console(
+                    console,
+                    &options.update_width(panel_width.saturating_sub(4)),
+                );
+                for seg in syntax_segments.iter().filter(|seg| !seg.is_control()) {
+                    content_parts.push(match &seg.style {
+                        Some(style) => TextPart::Styled(seg.text.to_string(), style.clone()),
+                        None => TextPart::Raw(seg.text.to_string()),
+                    });
+                }
+                showed_context = !syntax_segments.is_empty();
+            }
+
+            // Without highlighting, number the context lines and mark the
+            // failing one.
+            if !showed_context {
+                if let (Some((start, lines)), Some(lineno)) = (&context, frame.lineno) {
+                    let number_width = (start + lines.len()).to_string().len();
+                    for (offset, line) in lines.iter().enumerate() {
+                        let n = start + offset;
+                        let (marker, style) = if n == lineno {
+                            (">", "bold red")
+                        } else {
+                            (" ", "dim")
+                        };
+                        content_parts.push(TextPart::Styled(
+                            format!("{} {:>width$} ", marker, n, width = number_width),
+                            Style::parse(style).unwrap_or_else(|_| Style::null()),
+                        ));
+                        content_parts.push(TextPart::Raw(format!("{}\n", line)));
                     }
+                    showed_context = true;
                 }
             }
 
-            // Fallback: show the single source line if we didn't render syntax
-            if !showed_syntax {
+            // Fallback: show the single source line if the file is unreadable
+            if !showed_context {
                 if let Some(ref source) = frame.source_line {
                     let trimmed = source.trim();
                     if !trimmed.is_empty() {
@@ -511,6 +549,117 @@ impl Renderable for Traceback {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Panic hook
+// ---------------------------------------------------------------------------
+
+/// Frame prefixes hidden by [`install`]: the standard library, the panic
+/// machinery, process start-up and the hook itself.
+pub const INTERNAL_FRAMES: &[&str] = &[
+    "/rustc/",
+    "std::",
+    "core::",
+    "alloc::",
+    "rust_begin_unwind",
+    "__rust",
+    "__libc_start",
+    "_start",
+    "gilt::error::traceback::",
+];
+
+/// Install a panic hook that prints panics as a styled [`Traceback`] on
+/// stderr.
+///
+/// The backtrace is always captured, whatever `RUST_BACKTRACE` says. Frames
+/// from the standard library and the panic machinery are hidden, and source
+/// snippets are shown for frames whose files can be read. Rust does not
+/// expose local variables at runtime, so there are no locals to display.
+///
+/// # Examples
+///
+/// ```no_run
+/// gilt::traceback::install();
+/// panic!("something went wrong");
+/// ```
+pub fn install() {
+    install_with(Traceback::new().with_suppress(INTERNAL_FRAMES));
+}
+
+/// Install a panic hook that renders panics using the settings of
+/// `settings` (width, extra lines, theme, word wrap, frame limit and
+/// suppress list).
+///
+/// # Examples
+///
+/// ```no_run
+/// use gilt::traceback::{install_with, Traceback, INTERNAL_FRAMES};
+///
+/// let mut suppress = INTERNAL_FRAMES.to_vec();
+/// suppress.push("tokio::");
+/// install_with(
+///     Traceback::new()
+///         .with_extra_lines(1)
+///         .with_max_frames(20)
+///         .with_suppress(&suppress),
+/// );
+/// ```
+pub fn install_with(settings: Traceback) {
+    std::panic::set_hook(Box::new(move |info| {
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+        let thread = std::thread::current();
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+        let traceback = panic_traceback(
+            &settings,
+            thread.name().unwrap_or("<unnamed>"),
+            &panic_message(info.payload()),
+            location.as_deref(),
+            &backtrace,
+        );
+        let mut console = Console::builder().stderr(true).build();
+        console.print(&traceback);
+    }));
+}
+
+/// Extract the message from a panic payload.
+fn panic_message(payload: &dyn std::any::Any) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Build the traceback shown by the panic hook, outermost frame first.
+fn panic_traceback(
+    settings: &Traceback,
+    thread: &str,
+    message: &str,
+    location: Option<&str>,
+    backtrace: &str,
+) -> Traceback {
+    let header = match location {
+        Some(location) => format!("thread '{}' panicked at {}:", thread, location),
+        None => format!("thread '{}' panicked:", thread),
+    };
+    let mut frames = parse_backtrace(backtrace);
+    // Frames without a location (process start-up, stripped libraries) say
+    // nothing useful once any frame has debug info.
+    if frames.iter().any(|frame| !frame.filename.is_empty()) {
+        frames.retain(|frame| !frame.filename.is_empty());
+    }
+    frames.reverse();
+    Traceback {
+        title: "Panic".to_string(),
+        message: format!("{}\n{}", header, message),
+        frames,
+        ..settings.clone()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Backtrace parsing
 // ---------------------------------------------------------------------------
@@ -574,6 +723,36 @@ fn parse_backtrace(bt: &str) -> Vec<Frame> {
     frames
 }
 
+/// Read the lines around `lineno` from a local source file.
+///
+/// Returns the first line number of the context together with the lines, or
+/// `None` when the file is not a local path, cannot be read, or is shorter
+/// than `lineno`.
+fn source_context(
+    filename: &str,
+    lineno: usize,
+    extra_lines: usize,
+) -> Option<(usize, Vec<String>)> {
+    let path = std::path::Path::new(filename);
+    if lineno == 0 || !(path.is_absolute() || filename.starts_with("./")) {
+        return None;
+    }
+    let contents = std::fs::read_to_string(path).ok()?;
+    let total_lines = contents.lines().count();
+    if lineno > total_lines {
+        return None;
+    }
+    let start = lineno.saturating_sub(extra_lines).max(1);
+    let end = (lineno + extra_lines).min(total_lines);
+    let lines = contents
+        .lines()
+        .skip(start - 1)
+        .take(end + 1 - start)
+        .map(str::to_string)
+        .collect();
+    Some((start, lines))
+}
+
 /// Extract a short type name from an error reference.
 ///
 /// Since Rust does not have built-in runtime type names for trait objects, we
@@ -1154,4 +1333,86 @@ mod tests {
         assert!(display.contains("src/math.rs"));
         assert!(display.contains("15"));
     }
+
+    // -- Frame suppression and the panic hook ---------------------------------
+
+    #[test]
+    fn test_suppress_hides_frames_by_name_and_path() {
+        let tb = Traceback::from_backtrace(SAMPLE_BACKTRACE).with_suppress(INTERNAL_FRAMES);
+        let names: Vec<&str> = tb
+            .visible_frames()
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["myapp::myfunction", "myapp::main"]);
+        assert!(tb.is_suppressed(&Frame::new("/rustc/abc/lib.rs", Some(1), "<F as Fn>::call")));
+
+        let display = format!("{}", tb);
+        assert!(!display.contains("force_capture"));
+        assert!(display.contains("myapp::main"));
+    }
+
+    #[test]
+    fn test_source_context_reads_surrounding_lines() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/error/traceback.rs");
+        let (start, lines) = source_context(path, 2, 3).unwrap();
+        assert_eq!(start, 1);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("//! Traceback formatting module"));
+
+        assert!(source_context("src/error/traceback.rs", 2, 3).is_none());
+        assert!(source_context(path, 1_000_000, 3).is_none());
+    }
+
+    #[test]
+    fn test_renderable_shows_source_snippet() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/error/traceback.rs");
+        let mut tb = Traceback::new().with_extra_lines(1);
+        tb.frames.push(Frame::new(path, Some(2), "myapp::main"));
+        let console = Console::builder()
+            .width(120)
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        let output: String = tb
+            .gilt_console(&console, &console.options())
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert!(output.contains("Traceback formatting module"));
+        assert!(output.contains("Provides the `Traceback` struct"));
+    }
+
+    #[test]
+    fn test_panic_message_from_payload() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&String::from("bang")), "bang");
+        assert_eq!(panic_message(&42), "Box<dyn Any>");
+    }
+
+    #[test]
+    fn test_panic_traceback_orders_outermost_first() {
+        let settings = Traceback::new()
+            .with_extra_lines(1)
+            .with_suppress(INTERNAL_FRAMES);
+        let tb = panic_traceback(
+            &settings,
+            "main",
+            "oops",
+            Some("src/main.rs:42:9"),
+            SAMPLE_BACKTRACE,
+        );
+        assert_eq!(tb.title, "Panic");
+        assert_eq!(
+            tb.message,
+            "thread 'main' panicked at src/main.rs:42:9:\noops"
+        );
+        assert_eq!(tb.extra_lines, 1);
+        let names: Vec<&str> = tb
+            .visible_frames()
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["myapp::main", "myapp::myfunction"]);
+    }
 }
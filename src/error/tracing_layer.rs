@@ -18,11 +18,26 @@
 //!
 //! tracing::info!(user = "alice", "request handled");
 //! ```
+//!
+//! In [`SpanMode::Verbose`] spans are drawn as a tree, with each span's
+//! fields on the line that opens it and its elapsed time on the line that
+//! closes it:
+//!
+//! ```text
+//! 12:00:01          ┌ request method=GET path=/users
+//! 12:00:01 INFO     │ ┌ db.query table=users
+//! 12:00:01 DEBUG    │ │ rows fetched count=42
+//! 12:00:01          │ └ db.query 2.1ms
+//! 12:00:01 INFO     │ responded status=200
+//! 12:00:01          └ request 3.4ms
+//! ```
 
 use std::fmt;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
 use tracing::{Event, Level, Subscriber};
 use tracing_subscriber::layer::{Context, Layer};
 use tracing_subscriber::registry::LookupSpan;
@@ -90,6 +105,42 @@ impl Visit for FieldVisitor {
     }
 }
 
+// ---------------------------------------------------------------------------
+// SpanMode
+// ---------------------------------------------------------------------------
+
+/// How a [`GiltLayer`] shows the spans around events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpanMode {
+    /// One line per event, prefixed with the names of its enclosing spans
+    /// (`server:request`).
+    #[default]
+    Compact,
+    /// Spans drawn as an indented tree: a line with the span's fields when
+    /// it is first entered, events indented beneath it, and a line with the
+    /// elapsed time when it closes.
+    Verbose,
+}
+
+/// Per-span data kept in the registry's span extensions.
+struct SpanTiming {
+    fields: Vec<(String, String)>,
+    /// When the span was first entered, if it has been.
+    opened: Option<Instant>,
+}
+
+/// Format a duration compactly: `850µs`, `12.3ms`, `1.25s`.
+fn format_duration(elapsed: Duration) -> String {
+    let micros = elapsed.as_micros();
+    if micros < 1000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.1}ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // GiltLayer
 // ---------------------------------------------------------------------------
@@ -107,6 +158,10 @@ impl Visit for FieldVisitor {
 /// - **Target** (module path, dim style) — toggle with [`with_show_target`](Self::with_show_target)
 /// - **Span path** (parent spans, italic style) — toggle with [`with_show_span_path`](Self::with_show_span_path)
 ///
+/// In [`SpanMode::Verbose`] (see [`verbose`](Self::verbose)) the span path is
+/// replaced by a tree of span open and close lines, and events are indented
+/// under the spans they occur in.
+///
 /// ## Level color mapping
 ///
 /// | Level | Style |
@@ -122,10 +177,13 @@ pub struct GiltLayer {
     show_target: bool,
     show_level: bool,
     show_span_path: bool,
+    mode: SpanMode,
 }
 
 impl GiltLayer {
     /// Create a new `GiltLayer` with all columns enabled and a default console.
+    ///
+    /// Spans are shown in [`SpanMode::Compact`].
     pub fn new() -> Self {
         Self {
             console: Mutex::new(Console::new()),
@@ -133,9 +191,33 @@ impl GiltLayer {
             show_target: true,
             show_level: true,
             show_span_path: true,
+            mode: SpanMode::Compact,
         }
     }
 
+    /// Create a layer that shows spans in [`SpanMode::Compact`].
+    pub fn compact() -> Self {
+        Self::new()
+    }
+
+    /// Create a layer that draws spans as a timed tree
+    /// ([`SpanMode::Verbose`]).
+    pub fn verbose() -> Self {
+        Self::new().with_mode(SpanMode::Verbose)
+    }
+
+    /// Set how spans are shown.
+    #[must_use]
+    pub fn with_mode(mut self, mode: SpanMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// How spans are shown.
+    pub fn mode(&self) -> SpanMode {
+        self.mode
+    }
+
     /// Replace the console used for output.
     #[must_use]
     pub fn with_console(mut self, console: Console) -> Self {
@@ -220,15 +302,67 @@ impl GiltLayer {
         Text::styled(target, dim_style)
     }
 
-    /// Format structured fields as `key=value` pairs in dim italic style.
+    /// Format structured fields as dim `key=value` chips separated by spaces.
+    ///
+    /// Keys are dim italic and values dim, so the fields read as secondary
+    /// to the message.
     fn render_fields(fields: &[(String, String)]) -> Text {
-        if fields.is_empty() {
-            return Text::new("", Style::null());
+        let key_style = Style::parse("dim italic").unwrap_or_else(|_| Style::null());
+        let value_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        let mut text = Text::new("", Style::null());
+        for (i, (key, value)) in fields.iter().enumerate() {
+            if i > 0 {
+                text.append_str(" ", None);
+            }
+            text.append_str(key, Some(key_style.clone()));
+            text.append_str("=", Some(value_style.clone()));
+            text.append_str(value, Some(value_style.clone()));
+        }
+        text
+    }
+
+    /// Build the time and level columns that start every line. Lines
+    /// without a level (span open and close lines) get a blank level column.
+    fn render_columns(&self, level: Option<&Level>) -> Text {
+        let mut parts = Text::new("", Style::null());
+        if self.show_time {
+            let time_text = Self::render_time();
+            parts.append_text(&time_text);
+            parts.append_str(" ", None);
+        }
+        if self.show_level {
+            match level {
+                Some(level) => parts.append_text(&Self::render_level(level)),
+                None => parts.append_str(&" ".repeat(8), None),
+            };
+            parts.append_str(" ", None);
+        }
+        parts
+    }
+
+    /// Tree guides for a line nested `depth` spans deep.
+    fn render_guides(depth: usize) -> Text {
+        let guide_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        Text::styled(&"│ ".repeat(depth), guide_style)
+    }
+
+    /// Print a verbose-mode span line: `┌ name fields` when opening, or
+    /// `└ name elapsed` when closing.
+    fn emit_span_line(&self, depth: usize, marker: &str, name: &str, suffix: &Text) {
+        let mut parts = self.render_columns(None);
+        parts.append_text(&Self::render_guides(depth));
+        let guide_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        parts.append_str(marker, Some(guide_style));
+        parts.append_str(" ", None);
+        let name_style = Style::parse("bold cyan").unwrap_or_else(|_| Style::null());
+        parts.append_str(name, Some(name_style));
+        if !suffix.is_empty() {
+            parts.append_str(" ", None);
+            parts.append_text(suffix);
+        }
+        if let Ok(mut console) = self.console.lock() {
+            console.print(&parts);
         }
-        let parts: Vec<String> = fields.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
-        let joined = parts.join(" ");
-        let style = Style::parse("dim italic").unwrap_or_else(|_| Style::null());
-        Text::styled(&joined, style)
     }
 
     /// Compose all columns into a single line and print via the console.
@@ -242,34 +376,29 @@ impl GiltLayer {
         event.record(&mut visitor);
 
         let metadata = event.metadata();
-        let mut parts = Text::new("", Style::null());
+        let mut parts = self.render_columns(Some(metadata.level()));
 
-        // Time column
-        if self.show_time {
-            let time_text = Self::render_time();
-            parts.append_text(&time_text);
-            parts.append_str(" ", None);
-        }
-
-        // Level column
-        if self.show_level {
-            let level_text = Self::render_level(metadata.level());
-            parts.append_text(&level_text);
-            parts.append_str(" ", None);
-        }
-
-        // Span path (if enabled and spans exist)
-        if self.show_span_path {
-            if let Some(scope) = ctx.event_scope(event) {
-                let span_names: Vec<&str> = scope.from_root().map(|s| s.name()).collect();
-                if !span_names.is_empty() {
-                    let path = span_names.join(":");
-                    let span_style = Style::parse("italic cyan").unwrap_or_else(|_| Style::null());
-                    let span_text = Text::styled(&path, span_style);
-                    parts.append_text(&span_text);
-                    parts.append_str(" ", None);
+        match self.mode {
+            // Indent under the enclosing spans.
+            SpanMode::Verbose => {
+                let depth = ctx.event_scope(event).map_or(0, |scope| scope.count());
+                parts.append_text(&Self::render_guides(depth));
+            }
+            // Span path (if enabled and spans exist)
+            SpanMode::Compact if self.show_span_path => {
+                if let Some(scope) = ctx.event_scope(event) {
+                    let span_names: Vec<&str> = scope.from_root().map(|s| s.name()).collect();
+                    if !span_names.is_empty() {
+                        let path = span_names.join(":");
+                        let span_style =
+                            Style::parse("italic cyan").unwrap_or_else(|_| Style::null());
+                        let span_text = Text::styled(&path, span_style);
+                        parts.append_text(&span_text);
+                        parts.append_str(" ", None);
+                    }
                 }
             }
+            SpanMode::Compact => {}
         }
 
         // Message
@@ -310,6 +439,71 @@ impl<S> Layer<S> for GiltLayer
 where
     S: Subscriber + for<'a> LookupSpan<'a>,
 {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if self.mode != SpanMode::Verbose {
+            return;
+        }
+        let mut visitor = FieldVisitor::new();
+        attrs.record(&mut visitor);
+        let mut fields = visitor.fields;
+        if let Some(message) = visitor.message {
+            fields.insert(0, ("message".to_string(), message));
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanTiming {
+                fields,
+                opened: None,
+            });
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut visitor = FieldVisitor::new();
+        values.record(&mut visitor);
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            for (key, value) in visitor.fields {
+                match timing.fields.iter_mut().find(|(k, _)| *k == key) {
+                    Some(field) => field.1 = value,
+                    None => timing.fields.push((key, value)),
+                }
+            }
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let fields = {
+            let mut extensions = span.extensions_mut();
+            match extensions.get_mut::<SpanTiming>() {
+                // Spans may be entered many times (e.g. by async tasks); only
+                // the first entry opens it in the tree.
+                Some(timing) if timing.opened.is_none() => {
+                    timing.opened = Some(Instant::now());
+                    timing.fields.clone()
+                }
+                _ => return,
+            }
+        };
+        let depth = span.scope().skip(1).count();
+        self.emit_span_line(depth, "┌", span.name(), &Self::render_fields(&fields));
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else { return };
+        let opened = span
+            .extensions()
+            .get::<SpanTiming>()
+            .and_then(|timing| timing.opened);
+        if let Some(opened) = opened {
+            let depth = span.scope().skip(1).count();
+            let elapsed_style = Style::parse("dim").unwrap_or_else(|_| Style::null());
+            let elapsed = Text::styled(&format_duration(opened.elapsed()), elapsed_style);
+            self.emit_span_line(depth, "└", span.name(), &elapsed);
+        }
+    }
+
     fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
         self.emit(event, &ctx);
     }
@@ -733,6 +927,103 @@ mod tests {
         let _: fn() -> Result<(), Box<dyn std::error::Error + Send + Sync>> = install;
     }
 
+    // -- Span modes ----------------------------------------------------------
+
+    /// Run `f` under a registry with `layer` on a recording console and
+    /// return the exported plain text.
+    fn capture(layer: GiltLayer, f: impl FnOnce()) -> String {
+        let console = Console::builder()
+            .width(120)
+            .no_color(true)
+            .record(true)
+            .markup(false)
+            .build();
+        let layer = layer
+            .with_console(console)
+            .with_show_time(false)
+            .with_show_target(false);
+        let dispatch = tracing::Dispatch::new(tracing_subscriber::registry().with(layer));
+        tracing::dispatcher::with_default(&dispatch, f);
+        let layer = dispatch.downcast_ref::<GiltLayer>().unwrap();
+        let mut console = layer.console.lock().unwrap();
+        console.export_text(true, false)
+    }
+
+    #[test]
+    fn test_mode_constructors() {
+        assert_eq!(GiltLayer::new().mode(), SpanMode::Compact);
+        assert_eq!(GiltLayer::compact().mode(), SpanMode::Compact);
+        assert_eq!(GiltLayer::verbose().mode(), SpanMode::Verbose);
+        assert_eq!(
+            GiltLayer::new().with_mode(SpanMode::Verbose).mode(),
+            SpanMode::Verbose
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(850)), "850µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.3ms");
+        assert_eq!(format_duration(Duration::from_millis(1250)), "1.25s");
+    }
+
+    #[test]
+    fn test_render_fields_chips_are_styled_separately() {
+        let fields = vec![("user".to_string(), "alice".to_string())];
+        let text = GiltLayer::render_fields(&fields);
+        assert_eq!(text.spans().len(), 3);
+        assert_eq!(text.spans()[0].style.italic(), Some(true));
+        assert_eq!(text.spans()[2].style.dim(), Some(true));
+    }
+
+    #[test]
+    fn test_compact_mode_output() {
+        let output = capture(GiltLayer::compact(), || {
+            let _guard = tracing::info_span!("server", port = 8080u64).entered();
+            let _inner = tracing::info_span!("request").entered();
+            tracing::info!(user = "alice", "handled");
+        });
+        assert_eq!(
+            output.trim_end(),
+            "INFO     server:request handled user=alice"
+        );
+    }
+
+    #[test]
+    fn test_verbose_mode_draws_span_tree() {
+        let output = capture(GiltLayer::verbose(), || {
+            let outer = tracing::info_span!("server", port = 8080u64);
+            let _guard = outer.enter();
+            tracing::info!("listening");
+            {
+                let inner = tracing::info_span!("request", id = 1u64);
+                inner.record("id", 2u64);
+                let _guard = inner.enter();
+                tracing::warn!(status = 404u64, "not found");
+            }
+        });
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines.len(), 6, "{}", output);
+        assert_eq!(lines[0], "         ┌ server port=8080");
+        assert_eq!(lines[1], "INFO     │ listening");
+        assert_eq!(lines[2], "         │ ┌ request id=2");
+        assert_eq!(lines[3], "WARN     │ │ not found status=404");
+        assert!(lines[4].starts_with("         │ └ request "));
+        assert!(lines[5].starts_with("         └ server "));
+    }
+
+    #[test]
+    fn test_verbose_span_reentry_opens_once() {
+        let output = capture(GiltLayer::verbose(), || {
+            let span = tracing::info_span!("task");
+            for _ in 0..3 {
+                let _guard = span.enter();
+            }
+        });
+        assert_eq!(output.matches("┌ task").count(), 1);
+        assert_eq!(output.matches("└ task").count(), 1);
+    }
+
     // -- Helper: dummy callsite for creating fields in tests -----------------
 
     struct TestCallsite;
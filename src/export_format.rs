@@ -1,12 +1,12 @@
 //! Export format templates for Console HTML and SVG output.
 
 /// HTML template for console export.
-/// Placeholders: {stylesheet}, {foreground}, {background}, {code}
+/// Placeholders: {link}, {stylesheet}, {foreground}, {background}, {code}
 pub const CONSOLE_HTML_FORMAT: &str = r##"<!DOCTYPE html>
 <html>
 <head>
 <meta charset="UTF-8">
-<style>
+{link}<style>
 {stylesheet}
 body {
     color: {foreground};
@@ -20,13 +20,16 @@ body {
 </html>
 "##;
 
-/// SVG template for console export.
-/// See Python rich's _export_format.py for full variable list.
-pub const CONSOLE_SVG_FORMAT: &str = r##"<svg class="gilt-terminal" viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">
-    <!-- Generated with gilt https://github.com/gilt-rs -->
-    <style>
+/// Opening tags of the code block in [`CONSOLE_HTML_FORMAT`], used to resume
+/// it after semantic markup such as a `<table>` has been inserted.
+pub const CONSOLE_HTML_CODE_OPEN: &str = r#"<pre style="font-family:Menlo,'DejaVu Sans Mono',consolas,'Courier New',monospace"><code style="font-family:inherit">"#;
 
-    @font-face {
+/// Closing tags of the code block in [`CONSOLE_HTML_FORMAT`].
+pub const CONSOLE_HTML_CODE_CLOSE: &str = "</code></pre>";
+
+/// Default `@font-face` rules for SVG export: Fira Code from a CDN, or the
+/// locally installed copy.
+pub const CONSOLE_SVG_FONT_FACES: &str = r##"    @font-face {
         font-family: "Fira Code";
         src: local("FiraCode-Regular"),
                 url("https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff2/FiraCode-Regular.woff2") format("woff2"),
@@ -41,10 +44,18 @@ pub const CONSOLE_SVG_FORMAT: &str = r##"<svg class="gilt-terminal" viewBox="0 0
                 url("https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff/FiraCode-Bold.woff") format("woff");
         font-style: bold;
         font-weight: 700;
-    }
+    }"##;
+
+/// SVG template for console export.
+/// See Python rich's _export_format.py for full variable list.
+pub const CONSOLE_SVG_FORMAT: &str = r##"<svg class="gilt-terminal" viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">
+    <!-- Generated with gilt https://github.com/gilt-rs -->
+    <style>
+
+{font_faces}
 
     .{unique_id}-matrix {
-        font-family: Fira Code, monospace;
+        font-family: {font_family};
         font-size: {char_height}px;
         line-height: {line_height}px;
         font-variant-east-asian: full-width;
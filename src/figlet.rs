@@ -1,24 +1,38 @@
-//! Large ASCII art text using Unicode block characters.
+//! Large ASCII art text using Unicode block characters or FIGlet fonts.
 //!
-//! Renders banner text using a built-in 5-wide x 7-tall pixel font where each
-//! set pixel is drawn as a full block character (`\u{2588}`).
+//! By default banners are drawn with a built-in 5-wide x 7-tall pixel font
+//! where each set pixel is a full block character (`\u{2588}`). Standard
+//! FIGlet `.flf` fonts can be used instead: a few are embedded (see
+//! [`FigletFont::BUILTIN`]) and any other font can be loaded from disk with
+//! [`FigletFont::from_file`]. FIGlet fonts are laid out with the kerning and
+//! smushing rules declared in their header.
 //!
 //! # Example
 //!
 //! ```rust
-//! use gilt::figlet::Figlet;
+//! use gilt::figlet::{Figlet, FigletFont};
 //!
 //! let banner = Figlet::new("HI");
 //! let output = format!("{}", banner);
 //! assert!(!output.is_empty());
+//!
+//! let standard = Figlet::new("Hi").with_font(FigletFont::standard());
+//! assert_eq!(format!("{}", standard).lines().count(), 6);
 //! ```
 
+use std::collections::HashMap;
 use std::fmt;
+use std::path::Path;
+
+use thiserror::Error;
 
+use crate::color::Color;
 use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::gradient::color_scale;
 use crate::measure::Measurement;
 use crate::segment::Segment;
 use crate::style::Style;
+use crate::text::JustifyMethod;
 
 // ---------------------------------------------------------------------------
 // Built-in 5x7 block font
@@ -283,23 +297,428 @@ fn rendered_width(text: &str) -> usize {
     width
 }
 
+// ---------------------------------------------------------------------------
+// FIGlet (.flf) fonts
+// ---------------------------------------------------------------------------
+
+/// Horizontal smushing rule 1: equal characters merge into one.
+const SMUSH_EQUAL: u32 = 1;
+/// Horizontal smushing rule 2: an underscore is replaced by a border character.
+const SMUSH_LOWLINE: u32 = 2;
+/// Horizontal smushing rule 3: the higher class in `| /\ [] {} () <>` wins.
+const SMUSH_HIERARCHY: u32 = 4;
+/// Horizontal smushing rule 4: opposing brackets become a vertical bar.
+const SMUSH_PAIR: u32 = 8;
+/// Horizontal smushing rule 5: `/\` becomes `|`, `\/` becomes `Y`, `><` becomes `X`.
+const SMUSH_BIG_X: u32 = 16;
+/// Horizontal smushing rule 6: two hardblanks merge into one.
+const SMUSH_HARDBLANK: u32 = 32;
+/// Layout bit: move characters together until they touch (kerning).
+const LAYOUT_KERN: u32 = 64;
+/// Layout bit: overlap characters by one column using the smushing rules.
+const LAYOUT_SMUSH: u32 = 128;
+
+/// Tallest glyph height accepted in a font header.
+const MAX_FONT_HEIGHT: i64 = 256;
+
+/// Code points of the Deutsch glyphs that follow printable ASCII in a `.flf` file.
+const DEUTSCH: [u32; 7] = [196, 214, 220, 228, 246, 252, 223];
+
+const STANDARD_FLF: &str = include_str!("figlet/fonts/standard.flf");
+const SMALL_FLF: &str = include_str!("figlet/fonts/small.flf");
+const MINI_FLF: &str = include_str!("figlet/fonts/mini.flf");
+
+/// Errors that can occur while loading a FIGlet font.
+#[derive(Error, Debug)]
+pub enum FigletError {
+    /// The first line is not a valid `flf2a` header.
+    #[error("invalid FIGlet font header: {0}")]
+    InvalidHeader(String),
+
+    /// The font ended before all printable ASCII glyphs were defined.
+    #[error("FIGlet font ends before the glyph for {0:?}")]
+    Truncated(char),
+
+    /// An I/O error occurred while reading a font file.
+    #[error("FIGlet font I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A parsed FIGlet `.flf` font.
+///
+/// Glyphs for printable ASCII are required; the Deutsch glyphs and any
+/// code-tagged glyphs that follow are loaded when present. Only the
+/// horizontal layout of the header is honoured, left to right.
+///
+/// # Example
+///
+/// ```rust
+/// use gilt::figlet::FigletFont;
+///
+/// let font = FigletFont::builtin("small").unwrap();
+/// assert_eq!(font.height(), 5);
+/// assert!(font.has_glyph('A'));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FigletFont {
+    /// Number of rows in every glyph.
+    height: usize,
+    /// Rows from the top of a glyph to its baseline.
+    baseline: usize,
+    /// Sub-character that renders as a space but is never smushed.
+    hardblank: char,
+    /// Horizontal layout bits (`SMUSH_*` rules plus `LAYOUT_*` mode).
+    layout: u32,
+    /// Glyph rows, padded to a common width.
+    glyphs: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl FigletFont {
+    /// Names accepted by [`FigletFont::builtin`].
+    pub const BUILTIN: &'static [&'static str] = &["standard", "small", "mini"];
+
+    /// Parse a font from the contents of a `.flf` file.
+    ///
+    /// Fonts taller than 256 rows are rejected as invalid.
+    pub fn parse(source: &str) -> Result<Self, FigletError> {
+        let mut lines = source.lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| FigletError::InvalidHeader("empty font".to_string()))?;
+        let invalid = || FigletError::InvalidHeader(header.to_string());
+
+        let mut rest = header.strip_prefix("flf2a").ok_or_else(invalid)?.chars();
+        let hardblank = rest.next().ok_or_else(invalid)?;
+        let fields = rest
+            .as_str()
+            .split_whitespace()
+            .map(str::parse::<i64>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        if fields.len() < 5 || !(1..=MAX_FONT_HEIGHT).contains(&fields[0]) || fields[4] < 0 {
+            return Err(invalid());
+        }
+
+        let height = fields[0] as usize;
+        let baseline = fields[1].clamp(1, fields[0]) as usize;
+        let layout = match fields.get(6) {
+            Some(&full) => full as u32 & 0xff,
+            None => match fields[3] {
+                0 => LAYOUT_KERN,
+                old if old > 0 => LAYOUT_SMUSH | (old as u32 & 63),
+                _ => 0,
+            },
+        };
+
+        let mut lines = lines.skip(fields[4] as usize);
+        // Reject a font too short for even one glyph before allocating rows.
+        if lines.clone().take(height).count() < height {
+            return Err(FigletError::Truncated(' '));
+        }
+        let mut glyphs = HashMap::new();
+
+        for code in (32..=126).chain(DEUTSCH) {
+            let ch = char::from_u32(code).unwrap_or(' ');
+            match read_glyph(&mut lines, height) {
+                Some(rows) => {
+                    glyphs.insert(ch, rows);
+                }
+                None if code > 126 => break,
+                None => return Err(FigletError::Truncated(ch)),
+            }
+        }
+
+        // Code-tagged glyphs: a line starting with the code point, then rows.
+        while let Some(tag) = lines.next() {
+            let Some(code) = tag.split_whitespace().next().and_then(parse_code) else {
+                break;
+            };
+            let Some(rows) = read_glyph(&mut lines, height) else {
+                break;
+            };
+            if let Some(ch) = u32::try_from(code).ok().and_then(char::from_u32) {
+                glyphs.insert(ch, rows);
+            }
+        }
+
+        Ok(Self {
+            height,
+            baseline,
+            hardblank,
+            layout,
+            glyphs,
+        })
+    }
+
+    /// Load a font from an uncompressed `.flf` file on disk.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, FigletError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Look up one of the embedded fonts by name (case-insensitive).
+    pub fn builtin(name: &str) -> Option<Self> {
+        let source = match name.to_ascii_lowercase().as_str() {
+            "standard" => STANDARD_FLF,
+            "small" => SMALL_FLF,
+            "mini" => MINI_FLF,
+            _ => return None,
+        };
+        Some(Self::parse(source).expect("embedded FIGlet fonts are valid"))
+    }
+
+    /// The embedded "standard" font, six rows tall.
+    pub fn standard() -> Self {
+        Self::parse(STANDARD_FLF).expect("embedded FIGlet fonts are valid")
+    }
+
+    /// The embedded "small" font, five rows tall.
+    pub fn small() -> Self {
+        Self::parse(SMALL_FLF).expect("embedded FIGlet fonts are valid")
+    }
+
+    /// The embedded "mini" font, four rows tall.
+    pub fn mini() -> Self {
+        Self::parse(MINI_FLF).expect("embedded FIGlet fonts are valid")
+    }
+
+    /// Number of rows in every glyph.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Rows from the top of a glyph to its baseline.
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    /// Whether the font defines a glyph for `ch`.
+    pub fn has_glyph(&self, ch: char) -> bool {
+        self.glyphs.contains_key(&ch)
+    }
+
+    /// Width of a single glyph, or 0 if the font does not define it.
+    fn glyph_width(&self, ch: char) -> usize {
+        self.glyphs
+            .get(&ch)
+            .and_then(|rows| rows.first())
+            .map_or(0, Vec::len)
+    }
+
+    /// Render one line of text, returning `height` rows of equal width.
+    ///
+    /// Characters without a glyph are skipped.
+    fn render(&self, text: &[char]) -> Vec<String> {
+        let mut rows: Vec<Vec<char>> = vec![Vec::new(); self.height];
+        let mut prev_width = 0;
+        for ch in text {
+            let Some(glyph) = self.glyphs.get(ch) else {
+                continue;
+            };
+            let width = glyph.first().map_or(0, Vec::len);
+            let overlap = self.overlap(&rows, glyph, prev_width, width);
+            for (row, glyph_row) in rows.iter_mut().zip(glyph) {
+                let start = row.len() as isize - overlap as isize;
+                for (k, &right) in glyph_row.iter().take(overlap).enumerate() {
+                    let idx = start + k as isize;
+                    if idx >= 0 {
+                        let left = row[idx as usize];
+                        row[idx as usize] =
+                            self.smush(left, right, prev_width, width).unwrap_or(right);
+                    }
+                }
+                row.extend_from_slice(&glyph_row[overlap.min(glyph_row.len())..]);
+            }
+            prev_width = width;
+        }
+        rows.into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|c| if c == self.hardblank { ' ' } else { c })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Rendered width of one line of text.
+    fn text_width(&self, text: &[char]) -> usize {
+        self.render(text)
+            .first()
+            .map_or(0, |row| row.chars().count())
+    }
+
+    /// Number of columns the next glyph can slide into the rows rendered so far.
+    fn overlap(
+        &self,
+        rows: &[Vec<char>],
+        glyph: &[Vec<char>],
+        prev_width: usize,
+        width: usize,
+    ) -> usize {
+        if self.layout & (LAYOUT_SMUSH | LAYOUT_KERN) == 0 {
+            return 0;
+        }
+        let mut amount = width;
+        for (row, glyph_row) in rows.iter().zip(glyph) {
+            // Last visible column of the line so far (0 if there is none).
+            let line_end = row.iter().rposition(|&c| c != ' ').unwrap_or(0);
+            let left = row.get(line_end).copied();
+            // First visible column of the glyph.
+            let glyph_start = glyph_row
+                .iter()
+                .position(|&c| c != ' ')
+                .unwrap_or(glyph_row.len());
+            let right = glyph_row.get(glyph_start).copied();
+
+            let mut row_amount = (glyph_start + row.len()) as isize - 1 - line_end as isize;
+            match (left, right) {
+                (None, _) | (Some(' '), _) => row_amount += 1,
+                (Some(l), Some(r)) if self.smush(l, r, prev_width, width).is_some() => {
+                    row_amount += 1
+                }
+                _ => {}
+            }
+            amount = amount.min(row_amount.max(0) as usize);
+        }
+        amount
+    }
+
+    /// Merge two overlapping sub-characters, or `None` if they may not touch.
+    fn smush(&self, left: char, right: char, prev_width: usize, width: usize) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        if prev_width < 2 || width < 2 || self.layout & LAYOUT_SMUSH == 0 {
+            return None;
+        }
+
+        let rules = self.layout & 63;
+        if rules == 0 {
+            // Universal smushing: the right character wins over visible ones.
+            return Some(if right == self.hardblank { left } else { right });
+        }
+        if left == self.hardblank || right == self.hardblank {
+            return (rules & SMUSH_HARDBLANK != 0 && left == right).then_some(left);
+        }
+        if rules & SMUSH_EQUAL != 0 && left == right {
+            return Some(left);
+        }
+        if rules & SMUSH_LOWLINE != 0 {
+            const BORDERS: &str = "|/\\[]{}()<>";
+            if left == '_' && BORDERS.contains(right) {
+                return Some(right);
+            }
+            if right == '_' && BORDERS.contains(left) {
+                return Some(left);
+            }
+        }
+        if rules & SMUSH_HIERARCHY != 0 {
+            if let (Some(l), Some(r)) = (hierarchy_class(left), hierarchy_class(right)) {
+                if l != r {
+                    return Some(if l > r { left } else { right });
+                }
+            }
+        }
+        if rules & SMUSH_PAIR != 0 {
+            if let ('[', ']') | (']', '[') | ('{', '}') | ('}', '{') | ('(', ')') | (')', '(') =
+                (left, right)
+            {
+                return Some('|');
+            }
+        }
+        if rules & SMUSH_BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Read one glyph of `height` rows, stripping the endmarks from each row.
+fn read_glyph<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    height: usize,
+) -> Option<Vec<Vec<char>>> {
+    let mut rows = Vec::with_capacity(height);
+    for _ in 0..height {
+        let line = lines.next()?.trim_end();
+        let row = match line.chars().last() {
+            Some(endmark) => line.trim_end_matches(endmark),
+            None => line,
+        };
+        rows.push(row.chars().collect::<Vec<char>>());
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, ' ');
+    }
+    Some(rows)
+}
+
+/// Parse a code tag: decimal, `0x` hexadecimal or leading-zero octal.
+fn parse_code(tag: &str) -> Option<i64> {
+    let (negative, digits) = match tag.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, tag),
+    };
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// Rank used by the hierarchy smushing rule; higher classes win.
+fn hierarchy_class(ch: char) -> Option<u8> {
+    match ch {
+        '|' => Some(1),
+        '/' | '\\' => Some(2),
+        '[' | ']' => Some(3),
+        '{' | '}' => Some(4),
+        '(' | ')' => Some(5),
+        '<' | '>' => Some(6),
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Figlet struct
 // ---------------------------------------------------------------------------
 
-/// Large ASCII art banner text rendered using Unicode full-block characters.
+/// Large ASCII art banner text.
 ///
-/// Each character is drawn on a 5-wide by 7-tall pixel grid. Set pixels are
-/// rendered as `\u{2588}` (full block) and unset pixels as spaces. Characters
-/// are separated by a 1-column gap.
+/// By default each character is drawn on a 5-wide by 7-tall pixel grid. Set
+/// pixels are rendered as `\u{2588}` (full block) and unset pixels as spaces.
+/// Characters are separated by a 1-column gap. With [`Figlet::with_font`] the
+/// text is instead laid out in a FIGlet font, and newlines start a new banner.
 ///
 /// # Example
 ///
 /// ```rust
-/// use gilt::figlet::Figlet;
+/// use gilt::color::Color;
+/// use gilt::figlet::{Figlet, FigletFont};
+/// use gilt::text::JustifyMethod;
 ///
 /// let banner = Figlet::new("OK");
 /// println!("{}", banner);
+///
+/// let fancy = Figlet::new("gilt")
+///     .with_font(FigletFont::small())
+///     .with_justify(JustifyMethod::Center)
+///     .with_gradient(vec![Color::from_rgb(255, 215, 0), Color::from_rgb(255, 69, 0)]);
+/// println!("{}", fancy);
 /// ```
 #[derive(Debug, Clone)]
 pub struct Figlet {
@@ -310,6 +729,12 @@ pub struct Figlet {
     /// Optional maximum width; if the rendered text exceeds this, it wraps
     /// to the next "line" of banner rows.
     width: Option<usize>,
+    /// FIGlet font to render with, or `None` for the built-in block font.
+    font: Option<FigletFont>,
+    /// Horizontal placement of the banner within the available width.
+    justify: Option<JustifyMethod>,
+    /// Color stops blended left to right across each banner.
+    gradient: Vec<Color>,
 }
 
 impl Figlet {
@@ -319,6 +744,9 @@ impl Figlet {
             text: text.to_string(),
             style: Style::null(),
             width: None,
+            font: None,
+            justify: None,
+            gradient: Vec::new(),
         }
     }
 
@@ -337,6 +765,33 @@ impl Figlet {
         self
     }
 
+    /// Render with a FIGlet font instead of the built-in block font.
+    #[must_use]
+    pub fn with_font(mut self, font: FigletFont) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the justification of the banner within the available width.
+    ///
+    /// `Center` and `Right` shift every row by the same amount so glyphs stay
+    /// aligned; `Left`, `Full` and `Default` leave the banner at the left edge.
+    #[must_use]
+    pub fn with_justify(mut self, justify: JustifyMethod) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// Fill the banner with a horizontal gradient through `colors`.
+    ///
+    /// The gradient spans the width of each banner and is layered over the
+    /// style set with [`Figlet::with_style`].
+    #[must_use]
+    pub fn with_gradient(mut self, colors: Vec<Color>) -> Self {
+        self.gradient = colors;
+        self
+    }
+
     /// Render the text into lines of block characters.
     ///
     /// Returns a `Vec<String>` where each element is one row of the output.
@@ -346,6 +801,15 @@ impl Figlet {
             return Vec::new();
         }
 
+        if let Some(font) = &self.font {
+            return self
+                .text
+                .split('\n')
+                .flat_map(|line| self.split_font_chunks(font, line))
+                .flat_map(|chunk| font.render(&chunk))
+                .collect();
+        }
+
         // Split text into "visual lines" that respect the width constraint
         let chunks = self.split_into_chunks();
 
@@ -436,15 +900,101 @@ impl Figlet {
         chunks
     }
 
+    /// Split one line of text into chunks whose rendered width in `font`
+    /// fits within the width constraint.
+    fn split_font_chunks(&self, font: &FigletFont, line: &str) -> Vec<Vec<char>> {
+        let chars: Vec<char> = line.chars().collect();
+        let Some(max_width) = self.width else {
+            return vec![chars];
+        };
+
+        let mut chunks: Vec<Vec<char>> = Vec::new();
+        let mut current: Vec<char> = Vec::new();
+        for ch in chars {
+            current.push(ch);
+            if current.len() > 1 && font.text_width(&current) > max_width {
+                current.pop();
+                chunks.push(std::mem::replace(&mut current, vec![ch]));
+            }
+        }
+        chunks.push(current);
+        chunks
+    }
+
+    /// Number of columns to shift a row of `row_width` right within `width`.
+    fn justify_offset(justify: Option<JustifyMethod>, row_width: usize, width: usize) -> usize {
+        let free = width.saturating_sub(row_width);
+        match justify {
+            Some(JustifyMethod::Center) => free / 2,
+            Some(JustifyMethod::Right) => free,
+            _ => 0,
+        }
+    }
+
+    /// Build the segments for one rendered row, applying style and gradient.
+    fn row_segments(&self, row: &str) -> Vec<Segment> {
+        let styled = |text: &str, style: &Style| {
+            if style.is_null() {
+                Segment::text(text)
+            } else {
+                Segment::styled(text, style.clone())
+            }
+        };
+
+        if self.gradient.is_empty() {
+            return vec![styled(row, &self.style)];
+        }
+
+        let chars: Vec<char> = row.chars().collect();
+        let last = chars.len().saturating_sub(1).max(1) as f64;
+        let mut segments = Vec::new();
+        let mut blank = String::new();
+        for (i, &ch) in chars.iter().enumerate() {
+            if ch == ' ' {
+                blank.push(ch);
+                continue;
+            }
+            if !blank.is_empty() {
+                segments.push(styled(&std::mem::take(&mut blank), &self.style));
+            }
+            let color = color_scale(&self.gradient, i as f64 / last);
+            let style = self.style.clone() + Style::from_color(Some(color), None);
+            segments.push(Segment::styled(&ch.to_string(), style));
+        }
+        if !blank.is_empty() {
+            segments.push(styled(&blank, &self.style));
+        }
+        segments
+    }
+
     /// Return the measurement (width) of this figlet text.
     pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
-        let natural = rendered_width(&self.text);
+        let (natural, min) = match &self.font {
+            Some(font) => {
+                let natural = self
+                    .text
+                    .split('\n')
+                    .map(|line| font.text_width(&line.chars().collect::<Vec<_>>()))
+                    .max()
+                    .unwrap_or(0);
+                let widest = self
+                    .text
+                    .chars()
+                    .map(|ch| font.glyph_width(ch))
+                    .max()
+                    .unwrap_or(0);
+                (natural, widest)
+            }
+            // Minimum is one character width
+            None => (
+                rendered_width(&self.text),
+                if self.text.is_empty() { 0 } else { CHAR_WIDTH },
+            ),
+        };
         let max = match self.width {
             Some(w) => w.min(options.max_width),
             None => natural.min(options.max_width),
         };
-        // Minimum is one character width
-        let min = if self.text.is_empty() { 0 } else { CHAR_WIDTH };
         Measurement::new(min.min(max), max)
     }
 }
@@ -454,15 +1004,19 @@ impl Figlet {
 // ---------------------------------------------------------------------------
 
 impl Renderable for Figlet {
-    fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
+    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let lines = self.render_lines();
+        let justify = self.justify.or(options.justify);
+        let width = self
+            .width
+            .map_or(options.max_width, |w| w.min(options.max_width));
         let mut segments = Vec::new();
         for line in &lines {
-            if self.style.is_null() {
-                segments.push(Segment::text(line));
-            } else {
-                segments.push(Segment::styled(line, self.style.clone()));
+            let offset = Self::justify_offset(justify, line.chars().count(), width);
+            if offset > 0 {
+                segments.push(Segment::text(&" ".repeat(offset)));
             }
+            segments.extend(self.row_segments(line));
             segments.push(Segment::line());
         }
         segments
@@ -480,6 +1034,10 @@ impl fmt::Display for Figlet {
             if i > 0 {
                 writeln!(f)?;
             }
+            if let Some(width) = self.width {
+                let offset = Self::justify_offset(self.justify, line.chars().count(), width);
+                write!(f, "{}", " ".repeat(offset))?;
+            }
             write!(f, "{}", line)?;
         }
         Ok(())
@@ -736,4 +1294,259 @@ mod tests {
         assert_eq!(rendered_width("A B"), 15); // 5+1+3+1+5
         assert_eq!(rendered_width(" "), 3);
     }
+
+    // -- FIGlet fonts -------------------------------------------------------
+
+    /// A tiny two-row font: `flf2a$ 2 2 4 <old> 0 [dir full]` + 95 glyphs.
+    fn tiny_font(layout: &str, glyphs: &[(char, [&str; 2])]) -> String {
+        let mut src = format!("flf2a$ 2 2 4 {layout}\n");
+        for code in 32u8..=126 {
+            let rows = glyphs
+                .iter()
+                .find(|(ch, _)| *ch == code as char)
+                .map_or(["$", "$"], |(_, rows)| *rows);
+            src.push_str(&format!("{}@\n{}@@\n", rows[0], rows[1]));
+        }
+        src
+    }
+
+    #[test]
+    fn test_builtin_fonts_parse() {
+        for name in FigletFont::BUILTIN {
+            let font = FigletFont::builtin(name).unwrap();
+            for code in 32u8..=126 {
+                assert!(font.has_glyph(code as char), "{name} missing {code}");
+            }
+        }
+        assert_eq!(FigletFont::standard().height(), 6);
+        assert_eq!(FigletFont::small().height(), 5);
+        assert_eq!(FigletFont::mini().height(), 4);
+        assert!(FigletFont::builtin("STANDARD").is_some());
+        assert!(FigletFont::builtin("nope").is_none());
+    }
+
+    #[test]
+    fn test_parse_header_errors() {
+        assert!(matches!(
+            FigletFont::parse(""),
+            Err(FigletError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            FigletFont::parse("tlf2a$ 2 2 4 0 0\n"),
+            Err(FigletError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            FigletFont::parse("flf2a$ x 2 4 0 0\n"),
+            Err(FigletError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            FigletFont::parse("flf2a$ 2 2 4 0 0\n$@\n$@@\n"),
+            Err(FigletError::Truncated('!'))
+        ));
+        assert!(matches!(
+            FigletFont::parse("flf2a$ 100000000000 1 1 0 0\n"),
+            Err(FigletError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            FigletFont::parse("flf2a$ 200 1 1 0 0\n$@\n$@@\n"),
+            Err(FigletError::Truncated(' '))
+        ));
+    }
+
+    #[test]
+    fn test_parse_comments_deutsch_and_code_tags() {
+        let mut src = tiny_font("0 2", &[]);
+        src = src.replacen("\n", "\ncomment one\ncomment two\n", 1);
+        for _ in DEUTSCH {
+            src.push_str("Dd@\nDd@@\n");
+        }
+        src.push_str("0x263A  SMILE\n:)@\n  @@\n");
+        let font = FigletFont::parse(&src).unwrap();
+        assert!(font.has_glyph('\u{c4}'));
+        assert!(font.has_glyph('\u{263a}'));
+        assert_eq!(font.render(&['\u{263a}']), vec![":)", "  "]);
+    }
+
+    #[test]
+    fn test_parse_code_formats() {
+        assert_eq!(parse_code("65"), Some(65));
+        assert_eq!(parse_code("0x41"), Some(65));
+        assert_eq!(parse_code("0101"), Some(65));
+        assert_eq!(parse_code("-2"), Some(-2));
+        assert_eq!(parse_code("abc"), None);
+    }
+
+    #[test]
+    fn test_full_width_layout() {
+        let src = tiny_font("-1 0", &[('A', ["/\\ ", "|| "]), ('B', [" B", " B"])]);
+        let font = FigletFont::parse(&src).unwrap();
+        assert_eq!(font.render(&['A', 'B']), vec!["/\\  B", "||  B"]);
+    }
+
+    #[test]
+    fn test_kerning_layout() {
+        let src = tiny_font("0 0", &[('A', ["/\\ ", "|| "]), ('B', [" B", " B"])]);
+        let font = FigletFont::parse(&src).unwrap();
+        assert_eq!(font.render(&['A', 'B']), vec!["/\\B", "||B"]);
+    }
+
+    #[test]
+    fn test_smushing_rules() {
+        let font = FigletFont::parse(&tiny_font("0 0 0 191", &[])).unwrap();
+        // Rule 1: equal characters
+        assert_eq!(font.smush('|', '|', 2, 2), Some('|'));
+        // Rule 2: underscore
+        assert_eq!(font.smush('_', '/', 2, 2), Some('/'));
+        assert_eq!(font.smush(')', '_', 2, 2), Some(')'));
+        // Rule 3: hierarchy
+        assert_eq!(font.smush('|', '/', 2, 2), Some('/'));
+        assert_eq!(font.smush('<', '[', 2, 2), Some('<'));
+        // Rule 4: opposite pairs
+        assert_eq!(font.smush('[', ']', 2, 2), Some('|'));
+        assert_eq!(font.smush(')', '(', 2, 2), Some('|'));
+        // Rule 5: big X
+        assert_eq!(font.smush('/', '\\', 2, 2), Some('|'));
+        assert_eq!(font.smush('\\', '/', 2, 2), Some('Y'));
+        assert_eq!(font.smush('>', '<', 2, 2), Some('X'));
+        // Rule 6: hardblanks
+        assert_eq!(font.smush('$', '$', 2, 2), Some('$'));
+        assert_eq!(font.smush('$', 'x', 2, 2), None);
+        // Unrelated characters and narrow glyphs never smush
+        assert_eq!(font.smush('a', 'b', 2, 2), None);
+        assert_eq!(font.smush('|', '|', 1, 2), None);
+    }
+
+    #[test]
+    fn test_universal_smushing() {
+        let font = FigletFont::parse(&tiny_font("0 0 0 128", &[])).unwrap();
+        assert_eq!(font.smush('a', 'b', 2, 2), Some('b'));
+        assert_eq!(font.smush('a', '$', 2, 2), Some('a'));
+    }
+
+    #[test]
+    fn test_smushing_overlaps_one_column() {
+        let src = tiny_font("15 0", &[('A', ["|_", "| "]), ('B', ["_|", " |"])]);
+        let font = FigletFont::parse(&src).unwrap();
+        assert_eq!(font.render(&['A', 'B']), vec!["|_|", "| |"]);
+    }
+
+    #[test]
+    fn test_old_layout_derivation() {
+        assert_eq!(
+            FigletFont::parse(&tiny_font("-1 0", &[])).unwrap().layout,
+            0
+        );
+        assert_eq!(
+            FigletFont::parse(&tiny_font("0 0", &[])).unwrap().layout,
+            LAYOUT_KERN
+        );
+        assert_eq!(
+            FigletFont::parse(&tiny_font("15 0", &[])).unwrap().layout,
+            LAYOUT_SMUSH | 15
+        );
+    }
+
+    #[test]
+    fn test_hardblanks_render_as_spaces() {
+        let font = FigletFont::standard();
+        let rows = font.render(&['a', ' ', 'b']);
+        assert!(rows.iter().all(|row| !row.contains('$')));
+    }
+
+    #[test]
+    fn test_standard_font_rendering() {
+        let f = Figlet::new("Hi").with_font(FigletFont::standard());
+        let lines = f.render_lines();
+        assert_eq!(
+            lines,
+            vec![
+                " _   _ _ ",
+                "| | | (_)",
+                "| |_| | |",
+                "|  _  | |",
+                "|_| |_|_|",
+                "         ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_font_newlines_start_new_banner() {
+        let f = Figlet::new("A\nB").with_font(FigletFont::mini());
+        assert_eq!(f.render_lines().len(), 8);
+    }
+
+    #[test]
+    fn test_font_width_constraint() {
+        let font = FigletFont::standard();
+        let f = Figlet::new("ABCDEF").with_font(font).with_width(20);
+        let lines = f.render_lines();
+        assert!(lines.len() > 6);
+        assert_eq!(lines.len() % 6, 0);
+        assert!(lines.iter().all(|line| line.chars().count() <= 20));
+    }
+
+    #[test]
+    fn test_font_measure() {
+        let f = Figlet::new("Hi").with_font(FigletFont::standard());
+        let console = make_console(80);
+        let m = f.measure(&console, &console.options());
+        assert_eq!(m.maximum, 9);
+        assert_eq!(m.minimum, 7);
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = std::env::temp_dir().join(format!("gilt-figlet-{}.flf", std::process::id()));
+        std::fs::write(&path, STANDARD_FLF).unwrap();
+        let font = FigletFont::from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(font.height(), 6);
+        assert!(matches!(
+            FigletFont::from_file("/nonexistent/font.flf"),
+            Err(FigletError::Io(_))
+        ));
+    }
+
+    // -- Justify and gradient -----------------------------------------------
+
+    #[test]
+    fn test_justify_center() {
+        let f = Figlet::new("A").with_justify(JustifyMethod::Center);
+        let console = make_console(25);
+        let segments = f.gilt_console(&console, &console.options());
+        // (25 - 5) / 2 = 10 columns of padding before each row
+        assert_eq!(segments[0].text, " ".repeat(10));
+    }
+
+    #[test]
+    fn test_justify_right_display() {
+        let f = Figlet::new("A")
+            .with_width(12)
+            .with_justify(JustifyMethod::Right);
+        let output = format!("{}", f);
+        assert!(output.lines().all(|line| line.starts_with("       ")));
+    }
+
+    #[test]
+    fn test_justify_left_unchanged() {
+        let f = Figlet::new("A").with_justify(JustifyMethod::Left);
+        let console = make_console(25);
+        let segments = f.gilt_console(&console, &console.options());
+        assert_eq!(segments.len(), CHAR_HEIGHT * 2);
+    }
+
+    #[test]
+    fn test_gradient_fill() {
+        let start = Color::from_rgb(255, 0, 0);
+        let end = Color::from_rgb(0, 0, 255);
+        let f = Figlet::new("I").with_gradient(vec![start.clone(), end.clone()]);
+        let console = make_console(80);
+        let segments = f.gilt_console(&console, &console.options());
+        // First row of "I" is solid: five blocks from red to blue.
+        let first_row: Vec<&Segment> = segments.iter().take(5).collect();
+        assert_eq!(first_row[0].style.as_ref().unwrap().color(), Some(&start));
+        assert_eq!(first_row[4].style.as_ref().unwrap().color(), Some(&end));
+        assert!(first_row.iter().all(|seg| seg.text == "\u{2588}"));
+    }
 }
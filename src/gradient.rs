@@ -2,7 +2,9 @@
 //!
 //! This module provides the [`Gradient`] widget that creates smoothly
 //! interpolated color gradients across text, supporting multi-stop
-//! gradients, rainbow presets, and per-character color blending.
+//! gradients with explicit stop positions, rainbow presets, blending in
+//! sRGB, HSL or OKLab space, vertical gradients across lines, and
+//! background fills.
 //!
 //! # Example
 //!
@@ -15,9 +17,18 @@
 //!
 //! // Rainbow gradient
 //! let g = Gradient::rainbow("All the colors!");
+//!
+//! // Stops at explicit positions, blended in OKLab, painted as background
+//! use gilt::gradient::Interpolation;
+//! let g = Gradient::from_stops(
+//!     "Sunset",
+//!     &[(0.0, Color::from_rgb(255, 94, 77)), (0.8, Color::from_rgb(72, 52, 212))],
+//! )
+//! .with_interpolation(Interpolation::Oklab)
+//! .with_background(true);
 //! ```
 
-use crate::color::Color;
+use crate::color::{hls_to_rgb, rgb_to_hls, Color};
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::segment::Segment;
 use crate::style::Style;
@@ -43,14 +54,206 @@ fn interpolate_color(c1: &Color, c2: &Color, t: f64) -> Color {
     Color::from_rgb(r, g, b)
 }
 
+/// Color space in which gradient stops are blended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Blend each sRGB channel linearly (the classic terminal gradient).
+    #[default]
+    Rgb,
+    /// Blend hue, saturation and lightness, taking the shorter way around
+    /// the hue circle. Keeps intermediate colors saturated.
+    Hsl,
+    /// Blend in the perceptual OKLab space, which avoids the muddy midpoints
+    /// of sRGB blends.
+    Oklab,
+}
+
+impl Interpolation {
+    /// Blends `c1` and `c2` at parameter `t` (0.0 = c1, 1.0 = c2) in this space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use gilt::color::Color;
+    /// use gilt::gradient::Interpolation;
+    ///
+    /// let red = Color::from_rgb(255, 0, 0);
+    /// let blue = Color::from_rgb(0, 0, 255);
+    /// assert_eq!(Interpolation::Hsl.mix(&red, &blue, 0.5), Color::from_rgb(255, 0, 255));
+    /// ```
+    pub fn mix(self, c1: &Color, c2: &Color, t: f64) -> Color {
+        match self {
+            Interpolation::Rgb => interpolate_color(c1, c2, t),
+            Interpolation::Hsl => interpolate_hsl(c1, c2, t),
+            Interpolation::Oklab => interpolate_oklab(c1, c2, t),
+        }
+    }
+}
+
+/// Interpolates between two colors in HSL space along the shorter hue arc.
+///
+/// An achromatic endpoint borrows the other endpoint's hue so that blends
+/// towards grey do not sweep through unrelated hues.
+fn interpolate_hsl(c1: &Color, c2: &Color, t: f64) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (h1, l1, s1) = rgb_to_hls(c1.get_truecolor(None, true).normalized());
+    let (h2, l2, s2) = rgb_to_hls(c2.get_truecolor(None, true).normalized());
+    let (h1, h2) = match (s1 == 0.0, s2 == 0.0) {
+        (true, false) => (h2, h2),
+        (false, true) => (h1, h1),
+        _ => (h1, h2),
+    };
+
+    let mut dh = h2 - h1;
+    if dh > 0.5 {
+        dh -= 1.0;
+    } else if dh < -0.5 {
+        dh += 1.0;
+    }
+    let (r, g, b) = hls_to_rgb((
+        (h1 + dh * t).rem_euclid(1.0),
+        l1 + (l2 - l1) * t,
+        s1 + (s2 - s1) * t,
+    ));
+    Color::from_rgb(to_byte(r), to_byte(g), to_byte(b))
+}
+
+/// Interpolates between two colors in OKLab space.
+fn interpolate_oklab(c1: &Color, c2: &Color, t: f64) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let a = srgb_to_oklab(c1.get_truecolor(None, true).normalized());
+    let b = srgb_to_oklab(c2.get_truecolor(None, true).normalized());
+    let (r, g, b) = oklab_to_srgb((
+        a.0 + (b.0 - a.0) * t,
+        a.1 + (b.1 - a.1) * t,
+        a.2 + (b.2 - a.2) * t,
+    ));
+    Color::from_rgb(to_byte(r), to_byte(g), to_byte(b))
+}
+
+/// Converts a normalized sRGB triple to OKLab `(L, a, b)`.
+fn srgb_to_oklab((r, g, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let linear = |c: f64| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+    (
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    )
+}
+
+/// Converts OKLab `(L, a, b)` back to a normalized sRGB triple.
+fn oklab_to_srgb((l, a, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+    let gamma = |c: f64| {
+        if c <= 0.003_130_8 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    (
+        gamma(4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_),
+        gamma(-1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_),
+        gamma(-0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701_0 * s_),
+    )
+}
+
+/// Converts a normalized channel to a byte, clamping out-of-gamut values.
+fn to_byte(c: f64) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Returns the color at position `t` (0.0 ..= 1.0, clamped) along evenly
+/// spaced color stops.
+///
+/// With no stops this is the default color; with one it is that color.
+///
+/// # Example
+///
+/// ```rust
+/// use gilt::color::Color;
+/// use gilt::gradient::color_scale;
+///
+/// let stops = [Color::from_rgb(0, 0, 0), Color::from_rgb(200, 100, 0)];
+/// assert_eq!(color_scale(&stops, 0.5), Color::from_rgb(100, 50, 0));
+/// ```
+pub fn color_scale(colors: &[Color], t: f64) -> Color {
+    sample(colors, &[], t, Interpolation::Rgb)
+}
+
+/// Returns the color at `t` along `colors`, placed at `positions` when one
+/// position per color is given and evenly spaced otherwise.
+fn sample(colors: &[Color], positions: &[f64], t: f64, interpolation: Interpolation) -> Color {
+    match colors {
+        [] => Color::default_color(),
+        [only] => only.clone(),
+        _ => {
+            let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+            if positions.len() != colors.len() {
+                let segments = colors.len() - 1;
+                let scaled = t * segments as f64;
+                let seg = (scaled.floor() as usize).min(segments - 1);
+                let local_t = scaled - seg as f64;
+                return interpolation.mix(&colors[seg], &colors[seg + 1], local_t);
+            }
+
+            if t <= positions[0] {
+                return colors[0].clone();
+            }
+            for seg in 0..colors.len() - 1 {
+                let (start, end) = (positions[seg], positions[seg + 1]);
+                if t <= end {
+                    let local_t = if end > start {
+                        (t - start) / (end - start)
+                    } else {
+                        1.0
+                    };
+                    return interpolation.mix(&colors[seg], &colors[seg + 1], local_t);
+                }
+            }
+            colors[colors.len() - 1].clone()
+        }
+    }
+}
+
+/// Axis along which a [`Gradient`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientDirection {
+    /// Left to right across each line; every line gets the full gradient.
+    #[default]
+    Horizontal,
+    /// Top to bottom across lines; each line is a single color.
+    Vertical,
+}
+
 // ---------------------------------------------------------------------------
 // Gradient
 // ---------------------------------------------------------------------------
 
 /// A text widget that renders with a smooth color gradient across characters.
 ///
-/// The gradient distributes the given color stops evenly across the text
-/// length and interpolates between adjacent stops for each character.
+/// By default the gradient distributes the given color stops evenly across
+/// each line and interpolates between adjacent stops in sRGB for each
+/// character. Stops can be pinned to positions with
+/// [`Gradient::from_stops`], blended in another color space with
+/// [`Gradient::with_interpolation`], run top to bottom with
+/// [`Gradient::with_direction`], and painted behind the text with
+/// [`Gradient::with_background`].
 #[derive(Debug, Clone)]
 pub struct Gradient {
     /// The plain text to render.
@@ -62,6 +265,14 @@ pub struct Gradient {
     pub style: Style,
     /// Optional text justification.
     pub justify: Option<JustifyMethod>,
+    /// Positions (0.0 ..= 1.0) of each color stop; empty for even spacing.
+    positions: Vec<f64>,
+    /// Color space used to blend between stops.
+    interpolation: Interpolation,
+    /// Whether the gradient runs across each line or down the lines.
+    direction: GradientDirection,
+    /// Paint the gradient as the background color instead of the foreground.
+    background: bool,
 }
 
 impl Gradient {
@@ -77,9 +288,50 @@ impl Gradient {
             colors,
             style: Style::null(),
             justify: None,
+            positions: Vec::new(),
+            interpolation: Interpolation::default(),
+            direction: GradientDirection::default(),
+            background: false,
         }
     }
 
+    /// Creates a gradient from `(position, color)` stops.
+    ///
+    /// Positions are clamped to `0.0..=1.0` and sorted; before the first stop
+    /// the first color is used and after the last stop the last color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use gilt::color::Color;
+    /// use gilt::gradient::Gradient;
+    ///
+    /// // Mostly red, with a quick fade to blue at the end
+    /// let g = Gradient::from_stops(
+    ///     "Warning!",
+    ///     &[(0.0, Color::from_rgb(255, 0, 0)), (0.7, Color::from_rgb(255, 0, 0)), (1.0, Color::from_rgb(0, 0, 255))],
+    /// );
+    /// assert_eq!(g.positions(), &[0.0, 0.7, 1.0]);
+    /// ```
+    pub fn from_stops(text: &str, stops: &[(f64, Color)]) -> Self {
+        let mut stops: Vec<(f64, Color)> = stops
+            .iter()
+            .map(|(pos, color)| {
+                let pos = if pos.is_nan() {
+                    0.0
+                } else {
+                    pos.clamp(0.0, 1.0)
+                };
+                (pos, color.clone())
+            })
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut gradient = Self::new(text, stops.iter().map(|(_, c)| c.clone()).collect());
+        gradient.positions = stops.into_iter().map(|(pos, _)| pos).collect();
+        gradient
+    }
+
     /// Creates a simple two-color gradient.
     pub fn two_color(text: &str, start: Color, end: Color) -> Self {
         Self::new(text, vec![start, end])
@@ -117,39 +369,78 @@ impl Gradient {
         self
     }
 
+    /// Sets the color space used to blend between stops.
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets whether the gradient runs horizontally or vertically.
+    #[must_use]
+    pub fn with_direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Paints the gradient as the background instead of the foreground.
+    #[must_use]
+    pub fn with_background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    // -- accessors ----------------------------------------------------------
+
+    /// Positions (0.0 ..= 1.0) of each color stop; empty for even spacing.
+    pub fn positions(&self) -> &[f64] {
+        &self.positions
+    }
+
     // -- internal helpers ---------------------------------------------------
 
     /// Computes the interpolated color for position `index` out of `total`
-    /// characters, distributing `self.colors` evenly.
+    /// characters (or lines), distributing `self.colors` over the stops.
     fn color_at(&self, index: usize, total: usize) -> Color {
-        if self.colors.is_empty() {
-            return Color::default_color();
-        }
-        if self.colors.len() == 1 || total <= 1 {
-            return self.colors[0].clone();
-        }
-
-        let t = index as f64 / (total - 1) as f64; // 0.0 .. 1.0
-        let segments = self.colors.len() - 1;
-        let scaled = t * segments as f64;
-        let seg = (scaled.floor() as usize).min(segments - 1);
-        let local_t = scaled - seg as f64;
+        let t = if total <= 1 {
+            0.0
+        } else {
+            index as f64 / (total - 1) as f64
+        };
+        sample(&self.colors, &self.positions, t, self.interpolation)
+    }
 
-        interpolate_color(&self.colors[seg], &self.colors[seg + 1], local_t)
+    /// Returns the style for a character painted in `color`.
+    fn paint(&self, color: Color, style: &Style) -> Style {
+        if self.background {
+            Style::from_color(None, Some(color)) + style.clone()
+        } else {
+            Style::from_color(Some(color), None) + style.clone()
+        }
     }
 
-    /// Renders a single line of text into gradient-colored segments.
-    fn render_line(&self, line: &str, style: &Style) -> Vec<Segment> {
+    /// Renders line `line_idx` of `line_count` into gradient-colored segments.
+    fn render_line(
+        &self,
+        line: &str,
+        line_idx: usize,
+        line_count: usize,
+        style: &Style,
+    ) -> Vec<Segment> {
         let chars: Vec<char> = line.chars().collect();
         let total = chars.len();
         if total == 0 {
             return Vec::new();
         }
 
+        if self.direction == GradientDirection::Vertical {
+            let style = self.paint(self.color_at(line_idx, line_count), style);
+            return vec![Segment::styled(line, style)];
+        }
+
         let mut segments = Vec::with_capacity(total);
         for (i, ch) in chars.iter().enumerate() {
-            let fg = self.color_at(i, total);
-            let char_style = Style::from_color(Some(fg), None) + style.clone();
+            let char_style = self.paint(self.color_at(i, total), style);
             segments.push(Segment::styled(&ch.to_string(), char_style));
         }
         segments
@@ -168,7 +459,7 @@ impl Renderable for Gradient {
         let mut all_segments = Vec::new();
 
         for (line_idx, line) in lines.iter().enumerate() {
-            let mut line_segs = self.render_line(line, &self.style);
+            let mut line_segs = self.render_line(line, line_idx, lines.len(), &self.style);
 
             // Apply justification if requested
             if let Some(just) = justify {
@@ -488,4 +779,157 @@ mod tests {
             ColorTriplet::new(0, 0, 255)
         );
     }
+
+    #[test]
+    fn test_from_stops_positions() {
+        let red = Color::from_rgb(255, 0, 0);
+        let blue = Color::from_rgb(0, 0, 255);
+        let g = Gradient::from_stops("ABCDE", &[(1.0, blue.clone()), (0.5, red.clone())]);
+        // Stops are sorted by position
+        assert_eq!(g.positions, vec![0.5, 1.0]);
+        assert_eq!(g.colors, vec![red.clone(), blue.clone()]);
+
+        let console = Console::builder().width(80).force_terminal(true).build();
+        let segments = g.gilt_console(&console, &console.options());
+        let fg = |i: usize| segments[i].style.as_ref().unwrap().color().unwrap().clone();
+        // Before the first stop the first color holds
+        assert_eq!(fg(0), red);
+        assert_eq!(fg(2), red);
+        // t = 0.75 is halfway between the stops
+        assert_eq!(fg(3), Color::from_rgb(128, 0, 128));
+        assert_eq!(fg(4), blue);
+    }
+
+    #[test]
+    fn test_sample_with_coincident_stops() {
+        let colors = [
+            Color::from_rgb(255, 0, 0),
+            Color::from_rgb(0, 255, 0),
+            Color::from_rgb(0, 0, 255),
+        ];
+        // A hard edge at 0.5: green takes over immediately after it
+        let positions = [0.0, 0.5, 0.5];
+        assert_eq!(
+            sample(&colors, &[0.0, 0.5, 1.0], 0.25, Interpolation::Rgb),
+            Color::from_rgb(128, 128, 0)
+        );
+        assert_eq!(
+            sample(&colors, &positions, 0.5, Interpolation::Rgb),
+            Color::from_rgb(0, 255, 0)
+        );
+        assert_eq!(
+            sample(&colors, &positions, 0.6, Interpolation::Rgb),
+            Color::from_rgb(0, 0, 255)
+        );
+    }
+
+    #[test]
+    fn test_hsl_interpolation_keeps_saturation() {
+        let red = Color::from_rgb(255, 0, 0);
+        let green = Color::from_rgb(0, 255, 0);
+        // sRGB midpoint is a dull olive; HSL goes through pure yellow
+        assert_eq!(
+            Interpolation::Rgb.mix(&red, &green, 0.5),
+            Color::from_rgb(128, 128, 0)
+        );
+        assert_eq!(
+            Interpolation::Hsl.mix(&red, &green, 0.5),
+            Color::from_rgb(255, 255, 0)
+        );
+    }
+
+    #[test]
+    fn test_hsl_interpolation_short_hue_arc() {
+        // Red (0) to magenta (5/6) goes backwards through rose, not via green
+        let mid = Interpolation::Hsl.mix(
+            &Color::from_rgb(255, 0, 0),
+            &Color::from_rgb(255, 0, 255),
+            0.5,
+        );
+        assert_eq!(mid, Color::from_rgb(255, 0, 127));
+    }
+
+    #[test]
+    fn test_hsl_interpolation_towards_grey() {
+        let mid = Interpolation::Hsl.mix(
+            &Color::from_rgb(255, 0, 0),
+            &Color::from_rgb(128, 128, 128),
+            0.5,
+        );
+        let t = mid.get_truecolor(None, true);
+        // Stays on the red hue while desaturating
+        assert!(t.red > t.green);
+        assert_eq!(t.green, t.blue);
+    }
+
+    #[test]
+    fn test_oklab_interpolation() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        assert_eq!(Interpolation::Oklab.mix(&black, &white, 0.0), black);
+        assert_eq!(Interpolation::Oklab.mix(&black, &white, 1.0), white);
+        // OKLab lightness 0.5 is perceptual mid-grey, darker than sRGB's 128
+        let mid = Interpolation::Oklab
+            .mix(&black, &white, 0.5)
+            .get_truecolor(None, true);
+        assert_eq!(mid.red, mid.green);
+        assert_eq!(mid.green, mid.blue);
+        assert_eq!(mid.red, 99);
+    }
+
+    #[test]
+    fn test_oklab_round_trip() {
+        for (r, g, b) in [(1.0, 0.0, 0.0), (0.2, 0.5, 0.9), (0.0, 0.0, 0.0)] {
+            let (r2, g2, b2) = oklab_to_srgb(srgb_to_oklab((r, g, b)));
+            assert!((r - r2).abs() < 1e-6);
+            assert!((g - g2).abs() < 1e-6);
+            assert!((b - b2).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vertical_gradient() {
+        let g = Gradient::two_color(
+            "AB\nCD\nEF",
+            Color::from_rgb(255, 0, 0),
+            Color::from_rgb(0, 0, 255),
+        )
+        .with_direction(GradientDirection::Vertical);
+        let console = Console::builder().width(80).force_terminal(true).build();
+        let segments = g.gilt_console(&console, &console.options());
+        // One segment per line: AB, \n, CD, \n, EF, trailing \n
+        assert_eq!(segments.len(), 6);
+        assert_eq!(segments[0].text, "AB");
+        let fg = |i: usize| segments[i].style.as_ref().unwrap().color().unwrap().clone();
+        assert_eq!(fg(0), Color::from_rgb(255, 0, 0));
+        assert_eq!(fg(2), Color::from_rgb(128, 0, 128));
+        assert_eq!(fg(4), Color::from_rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn test_background_gradient() {
+        let g = Gradient::two_color("AB", Color::from_rgb(255, 0, 0), Color::from_rgb(0, 0, 255))
+            .with_background(true);
+        let console = Console::builder().width(80).force_terminal(true).build();
+        let segments = g.gilt_console(&console, &console.options());
+        let style = segments[0].style.as_ref().unwrap();
+        assert!(style.color().is_none());
+        assert_eq!(style.bgcolor(), Some(&Color::from_rgb(255, 0, 0)));
+        assert_eq!(
+            segments[1].style.as_ref().unwrap().bgcolor(),
+            Some(&Color::from_rgb(0, 0, 255))
+        );
+    }
+
+    #[test]
+    fn test_new_builder_methods() {
+        let g = Gradient::rainbow("x")
+            .with_interpolation(Interpolation::Oklab)
+            .with_direction(GradientDirection::Vertical)
+            .with_background(true);
+        assert_eq!(g.interpolation, Interpolation::Oklab);
+        assert_eq!(g.direction, GradientDirection::Vertical);
+        assert!(g.background);
+        assert!(g.positions.is_empty());
+    }
 }
@@ -8,15 +8,26 @@
 //! - **Fit (`fit`)**: constrains the width to the widest item in the group
 //!   (measurement returns the combined measurement of all items).
 //!
+//! Items may be separated by a [`Rule`], and a group can produce its items
+//! lazily from a closure that is re-run on every render, which suits
+//! [`Live`](crate::live::Live) displays whose contents change over time.
+//!
 //! Rust port of Python's `rich.console.Group`.
 
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::Arc;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::measure::Measurement;
+use crate::rule::Rule;
 use crate::segment::Segment;
+use crate::style::Style;
 use crate::text::Text;
 
+/// Closure producing a lazy group's items.
+type Generator = Arc<dyn Fn() -> Vec<Text> + Send + Sync>;
+
 // ---------------------------------------------------------------------------
 // Group
 // ---------------------------------------------------------------------------
@@ -32,6 +43,7 @@ use crate::text::Text;
 ///
 /// ```
 /// use gilt::group::Group;
+/// use gilt::rule::Rule;
 /// use gilt::text::Text;
 /// use gilt::style::Style;
 ///
@@ -39,12 +51,17 @@ use crate::text::Text;
 ///     Text::new("Hello", Style::null()),
 ///     Text::new("World", Style::null()),
 /// ];
-/// let group = Group::new(items);
+/// let group = Group::fit(items).with_separator(Rule::new().with_characters("-"));
+/// assert_eq!(format!("{}", group), "Hello\n-----\nWorld");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Group {
     /// The renderable items in this group.
     items: Vec<Text>,
+    /// Produces the items at render time instead of `items`.
+    generator: Option<Generator>,
+    /// Rule rendered between consecutive items.
+    separator: Option<Rule>,
     /// When `true`, constrain width to the widest item.
     /// When `false`, fill the available width.
     fit: bool,
@@ -56,7 +73,12 @@ impl Group {
     /// By default, `fit` is `false` -- the group fills the available width.
     /// Use [`Group::fit`] to create a group that constrains to content width.
     pub fn new(items: Vec<Text>) -> Self {
-        Group { items, fit: false }
+        Group {
+            items,
+            generator: None,
+            separator: None,
+            fit: false,
+        }
     }
 
     /// Create a new `Group` that constrains its width to the widest item.
@@ -64,7 +86,54 @@ impl Group {
     /// This is equivalent to `Group::new(items)` with `fit` set to `true`,
     /// matching Python rich's `Group(*renderables, fit=True)`.
     pub fn fit(items: Vec<Text>) -> Self {
-        Group { items, fit: true }
+        Group::new(items).with_fit(true)
+    }
+
+    /// Create a `Group` whose items are generated on demand.
+    ///
+    /// The closure runs each time the group is measured or rendered, so the
+    /// group always shows the current state of whatever it reads -- the
+    /// Rust counterpart of rich's `@group()` generator decorator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use gilt::group::Group;
+    /// use gilt::text::Text;
+    ///
+    /// let done = Arc::new(AtomicUsize::new(1));
+    /// let counter = Arc::clone(&done);
+    /// let group = Group::lazy(move || {
+    ///     (0..counter.load(Ordering::Relaxed)).map(|i| Text::from(format!("step {}", i + 1)))
+    /// });
+    /// assert_eq!(format!("{}", group), "step 1");
+    /// done.store(2, Ordering::Relaxed);
+    /// assert_eq!(format!("{}", group), "step 1\nstep 2");
+    /// ```
+    pub fn lazy<F, I>(generate: F) -> Self
+    where
+        F: Fn() -> I + Send + Sync + 'static,
+        I: IntoIterator<Item = Text>,
+    {
+        let mut group = Group::new(Vec::new());
+        group.generator = Some(Arc::new(move || generate().into_iter().collect()));
+        group
+    }
+
+    /// Set whether the group constrains its width to the widest item.
+    #[must_use]
+    pub fn with_fit(mut self, fit: bool) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Render `rule` between consecutive items.
+    #[must_use]
+    pub fn with_separator(mut self, rule: Rule) -> Self {
+        self.separator = Some(rule);
+        self
     }
 
     /// Return `true` if this group constrains width to content.
@@ -72,19 +141,39 @@ impl Group {
         self.fit
     }
 
+    /// Return `true` if this group generates its items on demand.
+    pub fn is_lazy(&self) -> bool {
+        self.generator.is_some()
+    }
+
+    /// Return the separator rule, if any.
+    pub fn separator(&self) -> Option<&Rule> {
+        self.separator.as_ref()
+    }
+
     /// Return a reference to the items in this group.
+    ///
+    /// Lazy groups have no stored items; see [`Group::lazy`].
     pub fn items(&self) -> &[Text] {
         &self.items
     }
 
     /// Return the number of items in this group.
     pub fn len(&self) -> usize {
-        self.items.len()
+        self.children().len()
     }
 
     /// Return `true` if this group has no items.
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.children().is_empty()
+    }
+
+    /// The items to render: generated for lazy groups, stored otherwise.
+    fn children(&self) -> Cow<'_, [Text]> {
+        match &self.generator {
+            Some(generate) => Cow::Owned(generate()),
+            None => Cow::Borrowed(&self.items),
+        }
     }
 
     /// Measure the group.
@@ -92,48 +181,115 @@ impl Group {
     /// When `fit` is `true`, the measurement is the combined measurement of all
     /// items (maximum of each item's min and max). When `fit` is `false`, both
     /// minimum and maximum are set to `options.max_width` (fill available space).
+    /// Separator rules stretch to the group's width and never widen it.
     pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
         if self.fit {
-            self.measure_renderables(options)
+            measure_renderables(&self.children(), options)
         } else {
             Measurement::new(options.max_width, options.max_width)
         }
     }
 
-    /// Compute the combined measurement of all items.
+    /// Flatten the group into a single multi-line [`Text`].
     ///
-    /// The minimum width is the maximum of all individual minimums, and the
-    /// maximum width is the maximum of all individual maximums, clamped to
-    /// `options.max_width`.
-    fn measure_renderables(&self, options: &ConsoleOptions) -> Measurement {
-        if self.items.is_empty() {
-            return Measurement::new(1, 1);
+    /// Separator rules are drawn at the width of the widest item. This is how
+    /// a group becomes the content of a [`Panel`](crate::panel::Panel), which
+    /// then sizes itself to the widest line under `Panel::fit`.
+    pub fn to_text(&self) -> Text {
+        let items = self.children();
+        let width = items.iter().map(|t| t.measure().maximum).max().unwrap_or(0);
+        let separator = self.separator.as_ref().map(|rule| rule_text(rule, width));
+
+        let mut text = Text::new("", Style::null());
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                text.append_str("\n", None);
+                if let Some(separator) = &separator {
+                    text.append_text(separator);
+                    text.append_str("\n", None);
+                }
+            }
+            text.append_text(&crate::kv::layered(item, &Style::null()));
         }
-        let mut min_width = 0usize;
-        let mut max_width = 0usize;
-        for item in &self.items {
-            let m = item.measure();
-            min_width = min_width.max(m.minimum);
-            max_width = max_width.max(m.maximum);
+        text
+    }
+}
+
+/// Compute the combined measurement of `items`.
+///
+/// The minimum width is the maximum of all individual minimums, and the
+/// maximum width is the maximum of all individual maximums, clamped to
+/// `options.max_width`.
+fn measure_renderables(items: &[Text], options: &ConsoleOptions) -> Measurement {
+    if items.is_empty() {
+        return Measurement::new(1, 1);
+    }
+    let mut min_width = 0usize;
+    let mut max_width = 0usize;
+    for item in items {
+        let m = item.measure();
+        min_width = min_width.max(m.minimum);
+        max_width = max_width.max(m.maximum);
+    }
+    Measurement::new(
+        min_width.min(options.max_width),
+        max_width.min(options.max_width),
+    )
+}
+
+/// Render `rule` as a single line of styled text `width` cells wide.
+fn rule_text(rule: &Rule, width: usize) -> Text {
+    let console = Console::builder().width(width.max(1)).build();
+    let options = console.options();
+    let mut text = Text::new("", Style::null());
+    for segment in rule.gilt_console(&console, &options) {
+        if segment.text != "\n" {
+            text.append_str(&segment.text, segment.style);
         }
-        Measurement::new(
-            min_width.min(options.max_width),
-            max_width.min(options.max_width),
-        )
+    }
+    text
+}
+
+impl fmt::Debug for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("items", &self.items)
+            .field("lazy", &self.is_lazy())
+            .field("separator", &self.separator)
+            .field("fit", &self.fit)
+            .finish()
+    }
+}
+
+impl FromIterator<Text> for Group {
+    fn from_iter<I: IntoIterator<Item = Text>>(iter: I) -> Self {
+        Group::new(iter.into_iter().collect())
+    }
+}
+
+impl From<Group> for Text {
+    fn from(group: Group) -> Self {
+        group.to_text()
     }
 }
 
 impl Renderable for Group {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let items = self.children();
         let render_options = if self.fit {
-            let measurement = self.measure_renderables(options);
+            let measurement = measure_renderables(&items, options);
             options.update_width(measurement.maximum.min(options.max_width))
         } else {
             options.clone()
         };
 
         let mut segments = Vec::new();
-        for item in &self.items {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                if let Some(rule) = &self.separator {
+                    segments.extend(rule.gilt_console(console, &render_options));
+                }
+            }
             segments.extend(item.gilt_console(console, &render_options));
         }
         segments
@@ -413,15 +569,105 @@ mod tests {
 
         let has_bold = segments.iter().any(|s| {
             s.text.contains("Bold item")
-                && s.style.as_ref().map_or(false, |st| st.bold() == Some(true))
+                && s.style.as_ref().is_some_and(|st| st.bold() == Some(true))
         });
         let has_italic = segments.iter().any(|s| {
             s.text.contains("Italic item")
-                && s.style
-                    .as_ref()
-                    .map_or(false, |st| st.italic() == Some(true))
+                && s.style.as_ref().is_some_and(|st| st.italic() == Some(true))
         });
         assert!(has_bold, "Expected bold segment in output");
         assert!(has_italic, "Expected italic segment in output");
     }
+
+    // -- Separators ----------------------------------------------------------
+
+    #[test]
+    fn test_separator_between_items() {
+        let items = vec![
+            Text::new("one", Style::null()),
+            Text::new("two", Style::null()),
+            Text::new("three", Style::null()),
+        ];
+        let group = Group::new(items).with_separator(Rule::new().with_characters("="));
+        assert!(group.separator().is_some());
+        assert_eq!(format!("{:6}", group), "one\n======\ntwo\n======\nthree");
+    }
+
+    #[test]
+    fn test_fit_separator_spans_content_width() {
+        let items = vec![
+            Text::new("ab", Style::null()),
+            Text::new("abcd", Style::null()),
+        ];
+        let group = Group::fit(items).with_separator(Rule::new().with_characters("-"));
+        assert_eq!(format!("{:40}", group), "ab\n----\nabcd");
+    }
+
+    // -- Lazy groups ---------------------------------------------------------
+
+    #[test]
+    fn test_lazy_regenerates_each_render() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let group = Group::lazy(move || {
+            let n = counter.fetch_add(1, Ordering::Relaxed);
+            vec![Text::new(&format!("render {}", n), Style::null())]
+        });
+        assert!(group.is_lazy());
+        assert!(group.items().is_empty());
+        assert_eq!(format!("{}", group), "render 0");
+        assert_eq!(format!("{}", group), "render 1");
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_lazy_fit_measure() {
+        let console = make_console(80);
+        let opts = console.options();
+        let group = Group::lazy(|| ["a", "abcdef"].map(Text::from)).with_fit(true);
+        assert_eq!(group.len(), 2);
+        let m = group.measure(&console, &opts);
+        assert_eq!(m.maximum, 6);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let group: Group = ["x", "y"].into_iter().map(Text::from).collect();
+        assert_eq!(group.len(), 2);
+        assert!(!group.is_fit());
+    }
+
+    // -- Nesting in a fit panel ----------------------------------------------
+
+    #[test]
+    fn test_to_text_with_separator() {
+        let group = Group::fit(vec![Text::from("ab"), Text::from("abcd")])
+            .with_separator(Rule::new().with_characters("-"));
+        let text = group.to_text();
+        assert_eq!(text.plain(), "ab\n----\nabcd");
+        assert_eq!(text.measure().maximum, 4);
+    }
+
+    #[test]
+    fn test_to_text_keeps_item_styles() {
+        let bold = Style::parse("bold").unwrap();
+        let group = Group::new(vec![Text::new("x", bold.clone()), Text::from("y")]);
+        let text: Text = group.into();
+        assert_eq!(text.plain(), "x\ny");
+        assert!(text.spans.iter().any(|s| s.style == bold && s.start == 0));
+    }
+
+    #[test]
+    fn test_group_in_fit_panel() {
+        use crate::panel::Panel;
+
+        let group = Group::fit(vec![Text::from("a"), Text::from("bbbb"), Text::from("cc")]);
+        let panel = Panel::fit(group.into());
+        let output = format!("{:40}", panel);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "╭──────╮");
+        assert_eq!(lines[2], "│ bbbb │");
+    }
 }
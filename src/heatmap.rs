@@ -0,0 +1,408 @@
+//! Heatmap -- a matrix of values drawn as colored cells.
+//!
+//! A [`Heatmap`] maps each value of a 2-D matrix onto a color scale (see
+//! [`color_scale`](crate::gradient::color_scale)) and paints it as the
+//! background of a cell, with optional row and column labels, the values
+//! themselves, and a legend showing the scale. Handy for CI timing matrices
+//! and correlation tables.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::heatmap::Heatmap;
+//!
+//! let heatmap = Heatmap::new(vec![vec![1.0, 0.2], vec![0.2, 1.0]])
+//!     .with_row_labels(&["cpu", "mem"])
+//!     .with_column_labels(&["cpu", "mem"])
+//!     .with_values(true);
+//! let output = format!("{}", heatmap);
+//! assert!(output.contains("cpu"));
+//! assert!(output.contains("0.2"));
+//! ```
+
+use std::fmt;
+
+use crate::accessibility::contrast_ratio;
+use crate::cells::{cell_len, set_cell_size};
+use crate::color::Color;
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::gradient::color_scale;
+use crate::measure::Measurement;
+use crate::segment::Segment;
+use crate::style::Style;
+
+/// Cells drawn for the legend's color scale.
+const LEGEND_CELLS: usize = 12;
+
+/// A 2-D matrix of values drawn as colored background cells.
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    /// The matrix, one `Vec` per row. Rows may differ in length; missing and
+    /// non-finite values are drawn as empty cells.
+    pub values: Vec<Vec<f64>>,
+    /// Labels shown left of each row.
+    pub row_labels: Vec<String>,
+    /// Labels shown above each column.
+    pub column_labels: Vec<String>,
+    /// Color stops from the lowest value to the highest.
+    pub colors: Vec<Color>,
+    /// Values mapped to the ends of the scale, or `None` to fit the data.
+    pub range: Option<(f64, f64)>,
+    /// Width of each cell in characters.
+    pub cell_width: usize,
+    /// Whether to print each value inside its cell.
+    pub show_values: bool,
+    /// Decimal places for printed values.
+    pub precision: usize,
+    /// Whether to show the color scale below the matrix.
+    pub show_legend: bool,
+    /// Style of the row and column labels.
+    pub label_style: Style,
+}
+
+impl Heatmap {
+    /// Create a heatmap of `values` on a blue-to-yellow-to-red scale.
+    pub fn new(values: Vec<Vec<f64>>) -> Self {
+        Heatmap {
+            values,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            colors: vec![
+                Color::from_rgb(49, 54, 149),
+                Color::from_rgb(255, 255, 191),
+                Color::from_rgb(165, 0, 38),
+            ],
+            range: None,
+            cell_width: 4,
+            show_values: false,
+            precision: 1,
+            show_legend: true,
+            label_style: Style::null(),
+        }
+    }
+
+    /// Set the row labels.
+    #[must_use]
+    pub fn with_row_labels(mut self, labels: &[&str]) -> Self {
+        self.row_labels = labels.iter().map(|l| l.to_string()).collect();
+        self
+    }
+
+    /// Set the column labels.
+    #[must_use]
+    pub fn with_column_labels(mut self, labels: &[&str]) -> Self {
+        self.column_labels = labels.iter().map(|l| l.to_string()).collect();
+        self
+    }
+
+    /// Set the color stops, from the lowest value to the highest.
+    #[must_use]
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Map `min` and `max` to the ends of the scale instead of fitting the
+    /// data.
+    #[must_use]
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Set the width of each cell in characters.
+    #[must_use]
+    pub fn with_cell_width(mut self, width: usize) -> Self {
+        self.cell_width = width.max(1);
+        self
+    }
+
+    /// Show or hide the values inside their cells.
+    #[must_use]
+    pub fn with_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Set the decimal places for printed values.
+    #[must_use]
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Show or hide the legend.
+    #[must_use]
+    pub fn with_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Set the label style.
+    #[must_use]
+    pub fn with_label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// The values mapped to the ends of the color scale.
+    pub fn bounds(&self) -> (f64, f64) {
+        if let Some(range) = self.range {
+            return range;
+        }
+        self.values
+            .iter()
+            .flatten()
+            .filter(|v| v.is_finite())
+            .fold(None, |bounds, &v| match bounds {
+                None => Some((v, v)),
+                Some((lo, hi)) => Some((f64::min(lo, v), f64::max(hi, v))),
+            })
+            .unwrap_or((0.0, 1.0))
+    }
+
+    /// The background color for `value`, or `None` if it is not finite.
+    pub fn color_for(&self, value: f64) -> Option<Color> {
+        if !value.is_finite() {
+            return None;
+        }
+        let (min, max) = self.bounds();
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.5
+        };
+        Some(color_scale(&self.colors, t))
+    }
+
+    /// Number of columns in the widest row.
+    fn column_count(&self) -> usize {
+        self.values
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(self.column_labels.len())
+    }
+
+    /// Style of a cell painted with `background`, with text in whichever of
+    /// black or white contrasts more.
+    fn cell_style(&self, background: Color) -> Style {
+        let bg = background.get_truecolor(None, false);
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        let foreground = if contrast_ratio(&black.get_truecolor(None, true), &bg)
+            >= contrast_ratio(&white.get_truecolor(None, true), &bg)
+        {
+            black
+        } else {
+            white
+        };
+        Style::from_color(Some(foreground), Some(background))
+    }
+
+    /// Build the heatmap lines.
+    fn render_lines(&self) -> Vec<Vec<Segment>> {
+        let width = self.cell_width;
+        let columns = self.column_count();
+        let gutter = self
+            .row_labels
+            .iter()
+            .map(|label| cell_len(label))
+            .max()
+            .map_or(0, |w| w + 1);
+        let mut lines = Vec::new();
+
+        if !self.column_labels.is_empty() {
+            let mut header = vec![Segment::text(&" ".repeat(gutter))];
+            for col in 0..columns {
+                let label = self.column_labels.get(col).map_or("", String::as_str);
+                header.push(Segment::styled(
+                    &centered(label, width),
+                    self.label_style.clone(),
+                ));
+            }
+            lines.push(header);
+        }
+
+        for (row, values) in self.values.iter().enumerate() {
+            let mut line = Vec::with_capacity(columns + 1);
+            if gutter > 0 {
+                let label = self.row_labels.get(row).map_or("", String::as_str);
+                line.push(Segment::styled(
+                    &set_cell_size(label, gutter),
+                    self.label_style.clone(),
+                ));
+            }
+            for col in 0..columns {
+                let value = values.get(col).copied().unwrap_or(f64::NAN);
+                line.push(match self.color_for(value) {
+                    Some(color) => {
+                        let text = if self.show_values {
+                            centered(&format!("{:.*}", self.precision, value), width)
+                        } else {
+                            " ".repeat(width)
+                        };
+                        Segment::styled(&text, self.cell_style(color))
+                    }
+                    None => Segment::text(&" ".repeat(width)),
+                });
+            }
+            lines.push(line);
+        }
+
+        if self.show_legend && !self.values.is_empty() {
+            let (min, max) = self.bounds();
+            let mut legend = vec![
+                Segment::text(&" ".repeat(gutter)),
+                Segment::styled(
+                    &format!("{:.*} ", self.precision, min),
+                    self.label_style.clone(),
+                ),
+            ];
+            for i in 0..LEGEND_CELLS {
+                let t = i as f64 / (LEGEND_CELLS - 1) as f64;
+                legend.push(Segment::styled(
+                    " ",
+                    Style::from_color(None, Some(color_scale(&self.colors, t))),
+                ));
+            }
+            legend.push(Segment::styled(
+                &format!(" {:.*}", self.precision, max),
+                self.label_style.clone(),
+            ));
+            lines.push(legend);
+        }
+        lines
+    }
+
+    /// Return the measurement for this heatmap.
+    pub fn measure(&self, _console: &Console, _options: &ConsoleOptions) -> Measurement {
+        let width = self
+            .render_lines()
+            .iter()
+            .map(|line| Segment::get_line_length(line))
+            .max()
+            .unwrap_or(0);
+        Measurement::new(width, width)
+    }
+}
+
+/// `text` centered in `width` cells, cropped if it does not fit.
+fn centered(text: &str, width: usize) -> String {
+    let pad = width.saturating_sub(cell_len(text));
+    let left = " ".repeat(pad / 2);
+    set_cell_size(&format!("{}{}", left, text), width).into_owned()
+}
+
+// ---------------------------------------------------------------------------
+// Display
+// ---------------------------------------------------------------------------
+
+impl fmt::Display for Heatmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .render_lines()
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Renderable
+// ---------------------------------------------------------------------------
+
+impl Renderable for Heatmap {
+    fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for line in self.render_lines() {
+            segments.extend(line);
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn black_to_white() -> Vec<Color> {
+        vec![Color::from_rgb(0, 0, 0), Color::from_rgb(255, 255, 255)]
+    }
+
+    #[test]
+    fn test_bounds_and_colors() {
+        let heatmap =
+            Heatmap::new(vec![vec![0.0, 5.0], vec![10.0, f64::NAN]]).with_colors(black_to_white());
+        assert_eq!(heatmap.bounds(), (0.0, 10.0));
+        assert_eq!(heatmap.color_for(0.0), Some(Color::from_rgb(0, 0, 0)));
+        assert_eq!(heatmap.color_for(5.0), Some(Color::from_rgb(128, 128, 128)));
+        assert_eq!(heatmap.color_for(f64::NAN), None);
+        // Values outside a fixed range clamp to its ends.
+        let fixed = heatmap.with_range(0.0, 2.0);
+        assert_eq!(fixed.color_for(5.0), Some(Color::from_rgb(255, 255, 255)));
+        assert_eq!(Heatmap::new(Vec::new()).bounds(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_layout_with_labels() {
+        let output = Heatmap::new(vec![vec![1.0, 2.0], vec![3.0]])
+            .with_row_labels(&["linux", "mac"])
+            .with_column_labels(&["build", "t"])
+            .with_values(true)
+            .with_legend(false)
+            .to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec!["      buil t  ", "linux 1.0 2.0 ", "mac   3.0     "]
+        );
+    }
+
+    #[test]
+    fn test_cell_styles() {
+        let heatmap = Heatmap::new(vec![vec![0.0, 1.0]])
+            .with_colors(black_to_white())
+            .with_legend(false);
+        let lines = heatmap.render_lines();
+        let dark = lines[0][0].style.as_ref().unwrap();
+        assert_eq!(dark.bgcolor(), Some(&Color::from_rgb(0, 0, 0)));
+        assert_eq!(dark.color(), Some(&Color::from_rgb(255, 255, 255)));
+        let light = lines[0][1].style.as_ref().unwrap();
+        assert_eq!(light.color(), Some(&Color::from_rgb(0, 0, 0)));
+        assert_eq!(lines[0][0].text, "    ");
+    }
+
+    #[test]
+    fn test_legend() {
+        let heatmap = Heatmap::new(vec![vec![-1.0, 1.0]]).with_colors(black_to_white());
+        let lines = heatmap.render_lines();
+        let legend = lines.last().unwrap();
+        assert_eq!(legend[1].text, "-1.0 ");
+        assert_eq!(legend.last().unwrap().text, " 1.0");
+        assert_eq!(legend.len(), LEGEND_CELLS + 3);
+        assert_eq!(
+            legend[2].style.as_ref().unwrap().bgcolor(),
+            Some(&Color::from_rgb(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let console = Console::builder().width(80).build();
+        let heatmap = Heatmap::new(vec![vec![1.0, 2.0, 3.0]])
+            .with_row_labels(&["r"])
+            .with_legend(false);
+        assert_eq!(
+            heatmap.measure(&console, &console.options()),
+            Measurement::new(14, 14)
+        );
+    }
+}
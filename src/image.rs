@@ -0,0 +1,766 @@
+//! Image -- raster images drawn in the terminal.
+//!
+//! An [`Image`] holds RGBA pixels and renders them with Unicode half-block
+//! characters (`▀`), giving each cell a truecolor top and bottom pixel. This
+//! works in any terminal with color support. Terminals that speak a graphics
+//! protocol can instead show the image at full resolution via
+//! [sixel](ImageProtocol::Sixel) or the
+//! [kitty graphics protocol](ImageProtocol::Kitty).
+//!
+//! Images are built from raw pixel data or loaded from PPM files; decode
+//! other formats with the crate of your choice and pass the pixels in.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::image::Image;
+//!
+//! // A 2x2 image: red and green on top, blue and white below.
+//! let pixels = vec![
+//!     255, 0, 0, 255, 0, 255, 0, 255,
+//!     0, 0, 255, 255, 255, 255, 255, 255,
+//! ];
+//! let image = Image::from_rgba(2, 2, pixels).unwrap();
+//! assert_eq!(image.cell_size(80), (2, 1));
+//! ```
+
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::color::Color;
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::control::{base64_encode, Control};
+use crate::measure::Measurement;
+use crate::segment::Segment;
+use crate::style::Style;
+
+/// Pixels with at least this alpha are drawn; the rest are transparent.
+const ALPHA_THRESHOLD: u8 = 128;
+
+/// Largest base64 payload sent in one kitty graphics escape.
+const KITTY_CHUNK: usize = 4096;
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Errors that can occur while building or loading an image.
+#[derive(Error, Debug)]
+pub enum ImageError {
+    /// The pixel buffer does not match the image dimensions.
+    #[error("expected {expected} bytes of pixel data, got {actual}")]
+    SizeMismatch {
+        /// Bytes needed for the given width and height.
+        expected: usize,
+        /// Bytes supplied.
+        actual: usize,
+    },
+
+    /// The image dimensions overflow the size of a pixel buffer.
+    #[error("image dimensions {width}x{height} are too large")]
+    TooLarge {
+        /// Width in pixels.
+        width: usize,
+        /// Height in pixels.
+        height: usize,
+    },
+
+    /// The data is not a valid PPM image.
+    #[error("invalid PPM image: {0}")]
+    InvalidPpm(String),
+
+    /// An I/O error occurred while reading an image file.
+    #[error("image I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+// ---------------------------------------------------------------------------
+// ImageProtocol
+// ---------------------------------------------------------------------------
+
+/// How an [`Image`] is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageProtocol {
+    /// Unicode half-blocks with truecolor, two pixels per cell.
+    #[default]
+    HalfBlock,
+    /// The kitty graphics protocol when the terminal supports it, else sixel
+    /// when supported, else half-blocks. Graphics are only used when the
+    /// console writes to a terminal.
+    Auto,
+    /// DEC sixel graphics.
+    Sixel,
+    /// The kitty graphics protocol.
+    Kitty,
+}
+
+// ---------------------------------------------------------------------------
+// Image
+// ---------------------------------------------------------------------------
+
+/// A raster image that renders to the terminal.
+///
+/// The image is scaled to fit the available width, keeping its aspect ratio
+/// on the assumption that a terminal cell is twice as tall as it is wide.
+#[derive(Debug, Clone)]
+pub struct Image {
+    /// Width in pixels.
+    width: usize,
+    /// Height in pixels.
+    height: usize,
+    /// Pixels in row-major order.
+    pixels: Vec<[u8; 4]>,
+    /// Target width in terminal columns, or `None` for one column per pixel.
+    pub columns: Option<usize>,
+    /// How the image is drawn.
+    pub protocol: ImageProtocol,
+    /// Assumed size of a terminal cell in pixels, used to size sixel output.
+    pub cell_pixels: (usize, usize),
+}
+
+impl Image {
+    /// Create an image from RGBA bytes, four per pixel in row-major order.
+    pub fn from_rgba(width: usize, height: usize, data: Vec<u8>) -> Result<Self, ImageError> {
+        let expected = buffer_len(width, height, 4)?;
+        if data.len() != expected {
+            return Err(ImageError::SizeMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+        let pixels = data
+            .chunks_exact(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect();
+        Ok(Self::from_pixels(width, height, pixels))
+    }
+
+    /// Create an opaque image from RGB bytes, three per pixel in row-major
+    /// order.
+    pub fn from_rgb(width: usize, height: usize, data: &[u8]) -> Result<Self, ImageError> {
+        let expected = buffer_len(width, height, 3)?;
+        if data.len() != expected {
+            return Err(ImageError::SizeMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+        let pixels = data
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2], 255])
+            .collect();
+        Ok(Self::from_pixels(width, height, pixels))
+    }
+
+    /// Parse a binary (`P6`) or ASCII (`P3`) PPM image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::image::Image;
+    ///
+    /// let image = Image::from_ppm(b"P3\n# red dot\n1 1\n255\n255 0 0\n").unwrap();
+    /// assert_eq!((image.width(), image.height()), (1, 1));
+    /// ```
+    pub fn from_ppm(data: &[u8]) -> Result<Self, ImageError> {
+        let mut reader = PpmReader { data, pos: 0 };
+        let magic = reader.token()?;
+        let binary = match magic {
+            b"P6" => true,
+            b"P3" => false,
+            _ => return Err(ImageError::InvalidPpm("missing P3 or P6 header".into())),
+        };
+        let width = reader.number()?;
+        let height = reader.number()?;
+        let max = reader.number()?;
+        if max == 0 || max > 65535 {
+            return Err(ImageError::InvalidPpm(format!("bad maximum value {max}")));
+        }
+        let scale = |value: usize| (value.min(max) * 255 / max) as u8;
+
+        // Check the header against the data before allocating for it.
+        let count = buffer_len(width, height, 3)?;
+        let truncated = || ImageError::InvalidPpm("truncated pixel data".into());
+        if binary {
+            // Exactly one whitespace byte separates the header from the data.
+            let start = reader.pos + 1;
+            let size = if max < 256 { 1 } else { 2 };
+            let body = count
+                .checked_mul(size)
+                .and_then(|len| data.get(start..start.checked_add(len)?))
+                .ok_or_else(truncated)?;
+            let mut rgb = Vec::with_capacity(count);
+            if size == 1 {
+                rgb.extend(body.iter().map(|&b| scale(b as usize)));
+            } else {
+                rgb.extend(
+                    body.chunks_exact(2)
+                        .map(|b| scale(u16::from_be_bytes([b[0], b[1]]) as usize)),
+                );
+            }
+            Self::from_rgb(width, height, &rgb)
+        } else {
+            // Every ASCII sample takes at least one byte.
+            if count > data.len().saturating_sub(reader.pos) {
+                return Err(truncated());
+            }
+            let mut rgb = Vec::with_capacity(count);
+            for _ in 0..count {
+                rgb.push(scale(reader.number()?));
+            }
+            Self::from_rgb(width, height, &rgb)
+        }
+    }
+
+    /// Load a PPM image from a file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ImageError> {
+        Self::from_ppm(&std::fs::read(path)?)
+    }
+
+    fn from_pixels(width: usize, height: usize, pixels: Vec<[u8; 4]>) -> Self {
+        Image {
+            width,
+            height,
+            pixels,
+            columns: None,
+            protocol: ImageProtocol::default(),
+            cell_pixels: (10, 20),
+        }
+    }
+
+    /// Set the target width in terminal columns.
+    #[must_use]
+    pub fn with_width(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set how the image is drawn.
+    #[must_use]
+    pub fn with_protocol(mut self, protocol: ImageProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Set the assumed size of a terminal cell in pixels (default 10x20).
+    #[must_use]
+    pub fn with_cell_pixels(mut self, width: usize, height: usize) -> Self {
+        self.cell_pixels = (width.max(1), height.max(1));
+        self
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The RGBA value of the pixel at (`x`, `y`), or `None` outside the
+    /// image.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The size in terminal (columns, rows) the image occupies when at most
+    /// `max_width` columns are available.
+    pub fn cell_size(&self, max_width: usize) -> (usize, usize) {
+        if self.width == 0 || self.height == 0 {
+            return (0, 0);
+        }
+        let columns = self.columns.unwrap_or(self.width).min(max_width).max(1);
+        (columns, self.scaled_height(columns).div_ceil(2))
+    }
+
+    /// Height in pixels when scaled to `width` pixels wide.
+    fn scaled_height(&self, width: usize) -> usize {
+        ((self.height * width + self.width / 2) / self.width).max(1)
+    }
+
+    /// Resample to `width` x `height` by averaging the source pixels each
+    /// target pixel covers (nearest pixel when enlarging).
+    fn resample(&self, width: usize, height: usize) -> Vec<[u8; 4]> {
+        let mut out = Vec::with_capacity(width * height);
+        for ty in 0..height {
+            let y0 = ty * self.height / height;
+            let y1 = ((ty + 1) * self.height / height).max(y0 + 1);
+            for tx in 0..width {
+                let x0 = tx * self.width / width;
+                let x1 = ((tx + 1) * self.width / width).max(x0 + 1);
+                let mut sum = [0usize; 4];
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let [r, g, b, a] = self.pixels[y * self.width + x];
+                        let a = a as usize;
+                        sum[0] += r as usize * a;
+                        sum[1] += g as usize * a;
+                        sum[2] += b as usize * a;
+                        sum[3] += a;
+                    }
+                }
+                let count = (y1 - y0) * (x1 - x0);
+                let alpha = sum[3];
+                let channel = |total: usize| total.checked_div(alpha).unwrap_or(0) as u8;
+                let pixel = [
+                    channel(sum[0]),
+                    channel(sum[1]),
+                    channel(sum[2]),
+                    (alpha / count) as u8,
+                ];
+                out.push(pixel);
+            }
+        }
+        out
+    }
+
+    /// The protocol to draw with on `console`.
+    fn resolve_protocol(&self, console: &Console) -> ImageProtocol {
+        match self.protocol {
+            ImageProtocol::Auto if console.is_terminal() => {
+                let capabilities = console.capabilities();
+                if capabilities.kitty_graphics {
+                    ImageProtocol::Kitty
+                } else if capabilities.sixel {
+                    ImageProtocol::Sixel
+                } else {
+                    ImageProtocol::HalfBlock
+                }
+            }
+            ImageProtocol::Auto => ImageProtocol::HalfBlock,
+            protocol => protocol,
+        }
+    }
+
+    /// Half-block rendering: each cell shows a top and a bottom pixel.
+    fn render_half_blocks(&self, columns: usize, rows: usize) -> Vec<Segment> {
+        let pixels = self.resample(columns, rows * 2);
+        let visible = |p: [u8; 4]| p[3] >= ALPHA_THRESHOLD;
+        let color = |p: [u8; 4]| Color::from_rgb(p[0], p[1], p[2]);
+
+        let mut segments = Vec::with_capacity(rows * (columns + 1));
+        for row in 0..rows {
+            for x in 0..columns {
+                let top = pixels[row * 2 * columns + x];
+                let bottom = pixels[(row * 2 + 1) * columns + x];
+                let segment = match (visible(top), visible(bottom)) {
+                    (true, true) => Segment::styled(
+                        "▀",
+                        Style::from_color(Some(color(top)), Some(color(bottom))),
+                    ),
+                    (true, false) => {
+                        Segment::styled("▀", Style::from_color(Some(color(top)), None))
+                    }
+                    (false, true) => {
+                        Segment::styled("▄", Style::from_color(Some(color(bottom)), None))
+                    }
+                    (false, false) => Segment::text(" "),
+                };
+                segments.push(segment);
+            }
+            segments.push(Segment::line());
+        }
+        segments
+    }
+
+    /// Graphics protocol rendering: reserve the image's cells with blank
+    /// lines, then draw over them from the end of the last line and return
+    /// the cursor there, so surrounding layout is unaffected.
+    fn render_graphics(&self, payload: String, columns: usize, rows: usize) -> Vec<Segment> {
+        let blank = " ".repeat(columns);
+        let mut segments = Vec::with_capacity(rows * 2 + 1);
+        for row in 0..rows {
+            segments.push(Segment::text(&blank));
+            if row + 1 == rows {
+                let origin = Control::cursor_move(-(columns as i32), -(rows as i32 - 1));
+                let escape = format!("\x1b7{}{}\x1b8", origin.segment.text, payload);
+                segments.push(Segment::new(&escape, None, Some(Vec::new())));
+            }
+            segments.push(Segment::line());
+        }
+        segments
+    }
+
+    /// Kitty graphics escapes that scale the image to `columns` x `rows`.
+    fn kitty_payload(&self, columns: usize, rows: usize) -> String {
+        let data: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        let encoded = base64_encode(&data);
+        let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK).collect();
+        let mut payload = String::with_capacity(encoded.len() + chunks.len() * 16 + 64);
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = u8::from(i + 1 < chunks.len());
+            let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+            if i == 0 {
+                payload.push_str(&format!(
+                    "\x1b_Ga=T,f=32,s={},v={},c={},r={},C=1,q=2,m={};{}\x1b\\",
+                    self.width, self.height, columns, rows, more, chunk
+                ));
+            } else {
+                payload.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+            }
+        }
+        payload
+    }
+
+    /// A sixel image `columns` cells wide, quantized to a 6x6x6 color cube.
+    fn sixel_payload(&self, columns: usize, rows: usize) -> String {
+        let (cell_width, cell_height) = self.cell_pixels;
+        let width = columns * cell_width;
+        let height = (self.height * width / self.width).clamp(1, rows * cell_height);
+        let pixels = self.resample(width, height);
+
+        let level = |v: u8| (v as usize * 5 + 127) / 255;
+        let indices: Vec<Option<usize>> = pixels
+            .iter()
+            .map(|p| {
+                (p[3] >= ALPHA_THRESHOLD).then(|| level(p[0]) * 36 + level(p[1]) * 6 + level(p[2]))
+            })
+            .collect();
+
+        let mut used = [false; 216];
+        for index in indices.iter().flatten() {
+            used[*index] = true;
+        }
+
+        // P2=1 leaves unset pixels transparent.
+        let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", width, height);
+        for (index, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+            let percent = |l: usize| l * 100 / 5;
+            out.push_str(&format!(
+                "#{};2;{};{};{}",
+                index,
+                percent(index / 36),
+                percent(index / 6 % 6),
+                percent(index % 6)
+            ));
+        }
+
+        for band in 0..height.div_ceil(6) {
+            let y0 = band * 6;
+            let mut colors: Vec<usize> = (y0..(y0 + 6).min(height))
+                .flat_map(|y| {
+                    indices[y * width..(y + 1) * width]
+                        .iter()
+                        .flatten()
+                        .copied()
+                })
+                .collect();
+            colors.sort_unstable();
+            colors.dedup();
+            for (n, &color) in colors.iter().enumerate() {
+                if n > 0 {
+                    out.push('$');
+                }
+                out.push_str(&format!("#{}", color));
+                let mut line: Vec<u8> = (0..width)
+                    .map(|x| {
+                        let mut bits = 0u8;
+                        for k in 0..6 {
+                            let y = y0 + k;
+                            if y < height && indices[y * width + x] == Some(color) {
+                                bits |= 1 << k;
+                            }
+                        }
+                        63 + bits
+                    })
+                    .collect();
+                while line.last() == Some(&63) {
+                    line.pop();
+                }
+                push_sixel_runs(&mut out, &line);
+            }
+            out.push('-');
+        }
+        out.push_str("\x1b\\");
+        out
+    }
+}
+
+/// Append sixel characters, run-length encoding repeats of four or more.
+fn push_sixel_runs(out: &mut String, line: &[u8]) {
+    let mut i = 0;
+    while i < line.len() {
+        let c = line[i];
+        let run = line[i..].iter().take_while(|&&b| b == c).count();
+        if run >= 4 {
+            out.push_str(&format!("!{}{}", run, c as char));
+        } else {
+            out.extend(std::iter::repeat_n(c as char, run));
+        }
+        i += run;
+    }
+}
+
+/// Bytes needed for a `width` x `height` image with `channels` bytes per
+/// pixel, or an error if that overflows.
+fn buffer_len(width: usize, height: usize, channels: usize) -> Result<usize, ImageError> {
+    width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(channels))
+        .ok_or(ImageError::TooLarge { width, height })
+}
+
+/// Whitespace- and comment-aware tokenizer for PPM headers.
+struct PpmReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PpmReader<'a> {
+    fn token(&mut self) -> Result<&'a [u8], ImageError> {
+        loop {
+            match self.data.get(self.pos) {
+                Some(b'#') => {
+                    while !matches!(self.data.get(self.pos), Some(b'\n') | None) {
+                        self.pos += 1;
+                    }
+                }
+                Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+                Some(_) => break,
+                None => return Err(ImageError::InvalidPpm("unexpected end of data".into())),
+            }
+        }
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|b| !b.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+        Ok(&self.data[start..self.pos])
+    }
+
+    fn number(&mut self) -> Result<usize, ImageError> {
+        let token = self.token()?;
+        std::str::from_utf8(token)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| ImageError::InvalidPpm(format!("expected a number, got {:?}", token)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Renderable
+// ---------------------------------------------------------------------------
+
+impl Renderable for Image {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let (columns, rows) = self.cell_size(options.max_width);
+        if columns == 0 {
+            return Vec::new();
+        }
+        match self.resolve_protocol(console) {
+            ImageProtocol::Kitty => {
+                self.render_graphics(self.kitty_payload(columns, rows), columns, rows)
+            }
+            ImageProtocol::Sixel => {
+                self.render_graphics(self.sixel_payload(columns, rows), columns, rows)
+            }
+            _ => self.render_half_blocks(columns, rows),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Measure
+// ---------------------------------------------------------------------------
+
+impl Image {
+    /// Return the measurement for this image.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let (columns, _) = self.cell_size(options.max_width);
+        Measurement::new(columns.min(1), columns)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::capabilities::Capabilities;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+
+    fn image(width: usize, height: usize, pixels: &[[u8; 4]]) -> Image {
+        Image::from_rgba(width, height, pixels.concat()).unwrap()
+    }
+
+    fn render(image: &Image, console: &Console) -> Vec<Segment> {
+        image.gilt_console(console, &console.options())
+    }
+
+    fn line_count(segments: &[Segment]) -> usize {
+        segments.iter().filter(|s| s.text == "\n").count()
+    }
+
+    fn console() -> Console {
+        Console::builder().width(20).build()
+    }
+
+    #[test]
+    fn test_size_mismatch() {
+        let err = Image::from_rgba(2, 2, vec![0; 15]).unwrap_err();
+        assert!(matches!(
+            err,
+            ImageError::SizeMismatch {
+                expected: 16,
+                actual: 15
+            }
+        ));
+        assert!(Image::from_rgb(1, 1, &[0; 4]).is_err());
+    }
+
+    #[test]
+    fn test_from_ppm_binary_and_ascii() {
+        let mut p6 = b"P6 # comment\n2 1\n255\n".to_vec();
+        p6.extend([255, 0, 0, 0, 0, 255]);
+        let image = Image::from_ppm(&p6).unwrap();
+        assert_eq!(image.pixel(0, 0), Some(RED));
+        assert_eq!(image.pixel(1, 0), Some(BLUE));
+        assert_eq!(image.pixel(2, 0), None);
+        assert_eq!(image.pixel(0, 1), None);
+
+        let p3 = Image::from_ppm(b"P3\n1 1\n15\n15 0 0\n").unwrap();
+        assert_eq!(p3.pixel(0, 0), Some(RED));
+
+        assert!(matches!(
+            Image::from_ppm(b"P5\n1 1\n255\n"),
+            Err(ImageError::InvalidPpm(_))
+        ));
+        assert!(Image::from_ppm(b"P6\n2 2\n255\n\x00").is_err());
+    }
+
+    #[test]
+    fn test_from_ppm_rejects_oversized_headers() {
+        assert!(matches!(
+            Image::from_ppm(b"P3\n100000 100000\n255\n1 2 3\n"),
+            Err(ImageError::InvalidPpm(_))
+        ));
+        assert!(matches!(
+            Image::from_ppm(b"P6\n4000000000 4000000000\n255\n\x00"),
+            Err(ImageError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            Image::from_rgb(usize::MAX, 2, &[]),
+            Err(ImageError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_half_block_colors() {
+        let segments = render(&image(1, 2, &[RED, BLUE]), &console());
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "▀");
+        let style = segments[0].style.as_ref().unwrap();
+        assert_eq!(style.color(), Some(&Color::from_rgb(255, 0, 0)));
+        assert_eq!(style.bgcolor(), Some(&Color::from_rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn test_half_block_transparency() {
+        let segments = render(
+            &image(3, 2, &[RED, CLEAR, CLEAR, CLEAR, BLUE, CLEAR]),
+            &console(),
+        );
+        let text: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(text, vec!["▀", "▄", " ", "\n"]);
+        let lower = segments[1].style.as_ref().unwrap();
+        assert_eq!(lower.color(), Some(&Color::from_rgb(0, 0, 255)));
+        assert_eq!(lower.bgcolor(), None);
+    }
+
+    #[test]
+    fn test_scaling_to_width() {
+        let image = image(4, 4, &[RED; 16]);
+        assert_eq!(image.cell_size(80), (4, 2));
+        assert_eq!(image.cell_size(2), (2, 1));
+        assert_eq!(image.clone().with_width(8).cell_size(80), (8, 4));
+
+        let segments = render(&image.with_width(2), &console());
+        assert_eq!(line_count(&segments), 1);
+        assert_eq!(Segment::get_line_length(&segments[..2]), 2);
+    }
+
+    #[test]
+    fn test_kitty_output_reserves_cells() {
+        let image = image(1, 2, &[RED, BLUE]).with_protocol(ImageProtocol::Kitty);
+        let segments = render(&image, &console());
+        assert_eq!(segments[0].text, " ");
+        assert!(segments[1].is_control());
+        let escape = segments[1].text.as_str();
+        assert!(escape.starts_with("\x1b7\x1b[1D\x1b_Ga=T,f=32,s=1,v=2,c=1,r=1,C=1,q=2,m=0;"));
+        assert!(escape.ends_with("\x1b\\\x1b8"));
+    }
+
+    #[test]
+    fn test_kitty_payload_is_chunked() {
+        let image = image(64, 64, &[RED; 64 * 64]);
+        let payload = image.kitty_payload(8, 4);
+        assert_eq!(payload.matches("\x1b_G").count(), 6);
+        assert!(payload.contains(",m=1;"));
+        assert!(payload.contains("\x1b_Gm=0;"));
+    }
+
+    #[test]
+    fn test_sixel_output() {
+        let image = image(2, 4, &[RED; 8])
+            .with_protocol(ImageProtocol::Sixel)
+            .with_cell_pixels(2, 4);
+        let segments = render(&image, &console());
+        assert_eq!(line_count(&segments), 2);
+        let escape = segments.iter().find(|s| s.is_control()).unwrap();
+        // Two cells wide at 2px per cell, aspect kept: 4x8 pixels.
+        assert_eq!(
+            escape.text.as_str(),
+            "\x1b7\x1b[2D\x1b[1A\x1bP0;1;0q\"1;1;4;8#180;2;100;0;0#180!4~-#180!4B-\x1b\\\x1b8"
+        );
+    }
+
+    #[test]
+    fn test_auto_protocol() {
+        let image = image(1, 2, &[RED, BLUE]).with_protocol(ImageProtocol::Auto);
+        assert_eq!(image.resolve_protocol(&console()), ImageProtocol::HalfBlock);
+
+        let kitty = Console::builder()
+            .force_terminal(true)
+            .capabilities(Capabilities {
+                kitty_graphics: true,
+                sixel: true,
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(image.resolve_protocol(&kitty), ImageProtocol::Kitty);
+
+        let sixel = Console::builder()
+            .force_terminal(true)
+            .capabilities(Capabilities {
+                sixel: true,
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(image.resolve_protocol(&sixel), ImageProtocol::Sixel);
+    }
+
+    #[test]
+    fn test_measure() {
+        let console = console();
+        let image = image(4, 2, &[RED; 8]);
+        assert_eq!(
+            image.measure(&console, &console.options()),
+            Measurement::new(1, 4)
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! Keyboard input -- raw-mode key reading for interactive widgets.
+//!
+//! Selection prompts, pagers and live dashboards need single key presses
+//! rather than whole lines. This module switches the terminal into raw mode
+//! with [`RawMode`] and decodes the bytes it sends into [`Key`]s with
+//! [`KeyReader`]. It has no dependencies: raw mode is set through `stty` on
+//! Unix, and decoding works on any [`Read`] source, so it can be tested with
+//! an in-memory buffer.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gilt::input::{Key, KeyReader, RawMode};
+//!
+//! let _raw = RawMode::enable().unwrap();
+//! let mut keys = KeyReader::stdin();
+//! loop {
+//!     match keys.read_key().unwrap() {
+//!         Key::Up => println!("up"),
+//!         Key::Down => println!("down"),
+//!         Key::Enter | Key::Escape => break,
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use std::io::{self, Read};
+
+use thiserror::Error;
+
+/// Errors that can occur while reading keys.
+#[derive(Error, Debug)]
+pub enum InputError {
+    /// An I/O error occurred while reading input or configuring the terminal.
+    #[error("input I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The terminal could not be switched into raw mode.
+    #[error("cannot enable raw mode: {0}")]
+    RawMode(String),
+
+    /// The input ended before a key was read.
+    #[error("end of input")]
+    Eof,
+}
+
+// ---------------------------------------------------------------------------
+// Key
+// ---------------------------------------------------------------------------
+
+/// A decoded key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Key {
+    /// A printable character.
+    Char(char),
+    /// A control chord such as Ctrl+C, holding the lowercase letter.
+    Ctrl(char),
+    /// Enter or Return.
+    Enter,
+    /// Escape on its own.
+    Escape,
+    /// Backspace.
+    Backspace,
+    /// Tab.
+    Tab,
+    /// Shift+Tab.
+    BackTab,
+    /// Up arrow.
+    Up,
+    /// Down arrow.
+    Down,
+    /// Left arrow.
+    Left,
+    /// Right arrow.
+    Right,
+    /// Home.
+    Home,
+    /// End.
+    End,
+    /// Page Up.
+    PageUp,
+    /// Page Down.
+    PageDown,
+    /// Insert.
+    Insert,
+    /// Delete (forward delete).
+    Delete,
+    /// A function key, F1 to F12.
+    F(u8),
+    /// An escape sequence that is not recognised, as received.
+    Unknown(Vec<u8>),
+}
+
+/// Decode the first key in `bytes`.
+///
+/// Returns the key and the number of bytes it used, or `None` if `bytes` is
+/// empty or ends in the middle of a key (a partial escape sequence or UTF-8
+/// character), in which case more input is needed. An escape byte with
+/// nothing after it is a lone [`Key::Escape`], since terminals send a whole
+/// escape sequence at once.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::input::{decode_key, Key};
+///
+/// assert_eq!(decode_key(b"\x1b[A"), Some((Key::Up, 3)));
+/// assert_eq!(decode_key(b"q"), Some((Key::Char('q'), 1)));
+/// assert_eq!(decode_key(b"\x1b["), None);
+/// ```
+pub fn decode_key(bytes: &[u8]) -> Option<(Key, usize)> {
+    let (&first, rest) = bytes.split_first()?;
+    let key = match first {
+        0x1b => return decode_escape(rest),
+        b'\r' | b'\n' => Key::Enter,
+        b'\t' => Key::Tab,
+        0x7f | 0x08 => Key::Backspace,
+        0x01..=0x1a => Key::Ctrl((b'a' + first - 1) as char),
+        0x00..=0x1f => Key::Unknown(vec![first]),
+        0x20..=0x7e => Key::Char(first as char),
+        _ => return decode_utf8(bytes),
+    };
+    Some((key, 1))
+}
+
+/// Decode the escape sequence following an ESC byte.
+fn decode_escape(rest: &[u8]) -> Option<(Key, usize)> {
+    match rest.first() {
+        None => Some((Key::Escape, 1)),
+        Some(b'[') => decode_csi(&rest[1..]).map(|(key, len)| (key, len + 2)),
+        Some(b'O') => {
+            let &code = rest.get(1)?;
+            let key = match code {
+                b'A' => Key::Up,
+                b'B' => Key::Down,
+                b'C' => Key::Right,
+                b'D' => Key::Left,
+                b'H' => Key::Home,
+                b'F' => Key::End,
+                b'P'..=b'S' => Key::F(code - b'P' + 1),
+                _ => Key::Unknown(vec![0x1b, b'O', code]),
+            };
+            Some((key, 3))
+        }
+        // ESC followed by an ordinary key: report the escape on its own.
+        Some(_) => Some((Key::Escape, 1)),
+    }
+}
+
+/// Decode a CSI sequence (after `ESC [`): parameters then a final byte.
+fn decode_csi(body: &[u8]) -> Option<(Key, usize)> {
+    let end = body.iter().position(|b| (0x40..=0x7e).contains(b))?;
+    let params = std::str::from_utf8(&body[..end]).unwrap_or("");
+    let first_param: u32 = params
+        .split(';')
+        .next()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(0);
+    let key = match body[end] {
+        b'A' => Key::Up,
+        b'B' => Key::Down,
+        b'C' => Key::Right,
+        b'D' => Key::Left,
+        b'H' => Key::Home,
+        b'F' => Key::End,
+        b'Z' => Key::BackTab,
+        b'~' => match first_param {
+            1 | 7 => Key::Home,
+            2 => Key::Insert,
+            3 => Key::Delete,
+            4 | 8 => Key::End,
+            5 => Key::PageUp,
+            6 => Key::PageDown,
+            11..=15 => Key::F((first_param - 10) as u8),
+            17..=21 => Key::F((first_param - 11) as u8),
+            23 | 24 => Key::F((first_param - 12) as u8),
+            _ => Key::Unknown(csi_bytes(body, end)),
+        },
+        _ => Key::Unknown(csi_bytes(body, end)),
+    };
+    Some((key, end + 1))
+}
+
+/// The full bytes of a CSI sequence whose body ends at `end`.
+fn csi_bytes(body: &[u8], end: usize) -> Vec<u8> {
+    let mut bytes = vec![0x1b, b'['];
+    bytes.extend_from_slice(&body[..=end]);
+    bytes
+}
+
+/// Decode a multi-byte UTF-8 character.
+fn decode_utf8(bytes: &[u8]) -> Option<(Key, usize)> {
+    let len = match bytes[0] {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        other => return Some((Key::Unknown(vec![other]), 1)),
+    };
+    if bytes.len() < len {
+        return None;
+    }
+    match std::str::from_utf8(&bytes[..len]) {
+        Ok(s) => s.chars().next().map(|c| (Key::Char(c), len)),
+        Err(_) => Some((Key::Unknown(vec![bytes[0]]), 1)),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// KeyReader
+// ---------------------------------------------------------------------------
+
+/// Reads [`Key`]s from a byte source.
+///
+/// The terminal must be in raw mode (see [`RawMode`]) for keys to arrive
+/// one at a time rather than a line at a time.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::input::{Key, KeyReader};
+///
+/// let mut keys = KeyReader::new(&b"j\x1b[B\r"[..]);
+/// assert_eq!(keys.read_key().unwrap(), Key::Char('j'));
+/// assert_eq!(keys.read_key().unwrap(), Key::Down);
+/// assert_eq!(keys.read_key().unwrap(), Key::Enter);
+/// assert!(keys.read_key().is_err());
+/// ```
+pub struct KeyReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+}
+
+impl KeyReader<io::Stdin> {
+    /// Create a reader for standard input.
+    pub fn stdin() -> Self {
+        KeyReader::new(io::stdin())
+    }
+}
+
+impl<R: Read> KeyReader<R> {
+    /// Create a reader over `reader`.
+    pub fn new(reader: R) -> Self {
+        KeyReader {
+            reader,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Block until a key is available and return it.
+    ///
+    /// Returns [`InputError::Eof`] when the input ends. A partial sequence
+    /// left at the end of input is returned as [`Key::Unknown`].
+    pub fn read_key(&mut self) -> Result<Key, InputError> {
+        loop {
+            if let Some((key, used)) = decode_key(&self.buffer) {
+                self.buffer.drain(..used);
+                return Ok(key);
+            }
+            let mut chunk = [0u8; 64];
+            let read = self.reader.read(&mut chunk)?;
+            if read == 0 {
+                if self.buffer.is_empty() {
+                    return Err(InputError::Eof);
+                }
+                return Ok(Key::Unknown(std::mem::take(&mut self.buffer)));
+            }
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+impl<R: Read> Iterator for KeyReader<R> {
+    type Item = Key;
+
+    /// Yield keys until the input ends or fails.
+    fn next(&mut self) -> Option<Key> {
+        self.read_key().ok()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RawMode
+// ---------------------------------------------------------------------------
+
+/// A guard that keeps the terminal in raw mode while it is alive.
+///
+/// In raw mode key presses are delivered immediately, without echo or line
+/// editing, and Ctrl+C arrives as [`Key::Ctrl('c')`](Key::Ctrl) instead of
+/// interrupting the process. Output processing is left on, so widgets can
+/// keep writing `\n` to end a line. The previous settings are restored on
+/// drop.
+#[derive(Debug)]
+pub struct RawMode {
+    #[cfg_attr(not(unix), allow(dead_code))]
+    saved: String,
+}
+
+impl RawMode {
+    /// Switch the controlling terminal into raw mode.
+    ///
+    /// Fails with [`InputError::RawMode`] when there is no terminal (e.g.
+    /// input is piped) or on platforms without `stty`.
+    pub fn enable() -> Result<RawMode, InputError> {
+        #[cfg(unix)]
+        {
+            let saved = stty(&["-g"])?;
+            stty(&[
+                "-icanon", "-echo", "-isig", "-ixon", "min", "1", "time", "0",
+            ])?;
+            Ok(RawMode {
+                saved: saved.trim().to_string(),
+            })
+        }
+        #[cfg(not(unix))]
+        {
+            Err(InputError::RawMode(
+                "raw mode is only supported on Unix".to_string(),
+            ))
+        }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        let _ = stty(&[self.saved.as_str()]);
+    }
+}
+
+/// Run `stty` against the controlling terminal and return its output.
+#[cfg(unix)]
+fn stty(args: &[&str]) -> Result<String, InputError> {
+    use std::process::{Command, Stdio};
+
+    let tty = std::fs::File::open("/dev/tty")
+        .map_err(|e| InputError::RawMode(format!("no terminal: {e}")))?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(tty))
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| InputError::RawMode(format!("cannot run stty: {e}")))?;
+    if !output.status.success() {
+        return Err(InputError::RawMode(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Enable raw mode, read a single key from standard input, and restore the
+/// terminal.
+pub fn read_key() -> Result<Key, InputError> {
+    let _raw = RawMode::enable()?;
+    KeyReader::stdin().read_key()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(bytes: &[u8]) -> Vec<Key> {
+        KeyReader::new(bytes).collect()
+    }
+
+    #[test]
+    fn test_decode_arrows() {
+        assert_eq!(
+            keys(b"\x1b[A\x1b[B\x1b[C\x1b[D"),
+            vec![Key::Up, Key::Down, Key::Right, Key::Left]
+        );
+        // Application cursor mode.
+        assert_eq!(keys(b"\x1bOA\x1bOB"), vec![Key::Up, Key::Down]);
+    }
+
+    #[test]
+    fn test_decode_enter_escape_and_controls() {
+        assert_eq!(
+            keys(b"\r\n\x1b\t\x7f\x03"),
+            vec![
+                Key::Enter,
+                Key::Enter,
+                Key::Escape,
+                Key::Tab,
+                Key::Backspace,
+                Key::Ctrl('c'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_tilde_sequences() {
+        assert_eq!(
+            keys(b"\x1b[3~\x1b[5~\x1b[6~\x1b[1~\x1b[4~\x1b[15~\x1b[24~"),
+            vec![
+                Key::Delete,
+                Key::PageUp,
+                Key::PageDown,
+                Key::Home,
+                Key::End,
+                Key::F(5),
+                Key::F(12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_modified_arrow_and_backtab() {
+        // Ctrl+Up reports parameters; the final byte still decides the key.
+        assert_eq!(keys(b"\x1b[1;5A\x1b[Z"), vec![Key::Up, Key::BackTab]);
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        assert_eq!(keys("é→".as_bytes()), vec![Key::Char('é'), Key::Char('→')]);
+    }
+
+    #[test]
+    fn test_partial_sequences_wait_for_more() {
+        assert_eq!(decode_key(b"\x1b[1;"), None);
+        assert_eq!(decode_key(&"é".as_bytes()[..1]), None);
+        assert_eq!(decode_key(b""), None);
+    }
+
+    #[test]
+    fn test_sequence_split_across_reads() {
+        struct Chunks(Vec<&'static [u8]>);
+        impl Read for Chunks {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                let chunk = self.0.remove(0);
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Ok(chunk.len())
+            }
+        }
+        let mut reader = KeyReader::new(Chunks(vec![b"\x1b[", b"B"]));
+        assert_eq!(reader.read_key().unwrap(), Key::Down);
+    }
+
+    #[test]
+    fn test_unknown_sequence_is_preserved() {
+        assert_eq!(keys(b"\x1b[99q"), vec![Key::Unknown(b"\x1b[99q".to_vec())]);
+        assert_eq!(keys(b"\x1b["), vec![Key::Unknown(b"\x1b[".to_vec())]);
+    }
+
+    #[test]
+    fn test_eof() {
+        let mut reader = KeyReader::new(&b""[..]);
+        assert!(matches!(reader.read_key(), Err(InputError::Eof)));
+    }
+}
@@ -12,7 +12,21 @@
 //! let json = Json::new(r#"{"name": "world", "count": 42}"#, JsonOptions::default()).unwrap();
 //! assert!(json.text.plain().contains("name"));
 //! ```
+//!
+//! In-memory data can be displayed without serializing it to a string first:
+//!
+//! ```rust
+//! use gilt::json::{Json, JsonOptions};
+//!
+//! let scores = vec![("alice", 3), ("bob", 5)];
+//! let json = Json::from_serialize(&scores, JsonOptions::compact()).unwrap();
+//! assert_eq!(json.text.plain(), r#"[["alice",3],["bob",5]]"#);
+//! ```
+
+use std::io;
 
+use serde::Serialize;
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 use serde_json::Value;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
@@ -30,6 +44,9 @@ pub enum JsonError {
     /// The input string is not valid JSON.
     #[error("invalid JSON: {0}")]
     InvalidJson(#[from] serde_json::Error),
+    /// The value passed to [`Json::from_serialize`] could not be serialized.
+    #[error("failed to serialize value: {0}")]
+    Serialize(serde_json::Error),
 }
 
 // ---------------------------------------------------------------------------
@@ -57,6 +74,15 @@ pub struct JsonOptions {
     /// order depends on the `serde_json` feature flags in use.  When
     /// `sort_keys` is `true`, keys are always guaranteed to be sorted.
     pub sort_keys: bool,
+    /// Deepest array or object shown in full; deeper ones are folded to
+    /// `[…]` or `{…}`. `None` means no limit.
+    pub max_depth: Option<usize>,
+    /// Maximum number of items shown per array or object, followed by a
+    /// `... +N more` indicator. `None` means all.
+    pub max_length: Option<usize>,
+    /// Maximum number of characters shown per string value, followed by
+    /// `…`. `None` means full strings.
+    pub max_string: Option<usize>,
 }
 
 impl Default for JsonOptions {
@@ -65,6 +91,9 @@ impl Default for JsonOptions {
             indent: Some(2),
             highlight: true,
             sort_keys: false,
+            max_depth: None,
+            max_length: None,
+            max_string: None,
         }
     }
 }
@@ -98,6 +127,27 @@ impl JsonOptions {
         self.sort_keys = sort_keys;
         self
     }
+
+    /// Builder: fold arrays and objects nested deeper than `max_depth`.
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Builder: show at most `max_length` items per array or object.
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Builder: truncate string values longer than `max_string` characters.
+    #[must_use]
+    pub fn with_max_string(mut self, max_string: usize) -> Self {
+        self.max_string = Some(max_string);
+        self
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -106,8 +156,9 @@ impl JsonOptions {
 
 /// A renderable which pretty-prints JSON with syntax highlighting.
 ///
-/// Construct via [`Json::new`] (from a JSON string) or [`Json::from_value`]
-/// (from a pre-parsed [`serde_json::Value`]).
+/// Construct via [`Json::new`] (from a JSON string), [`Json::from_value`]
+/// (from a pre-parsed [`serde_json::Value`]) or [`Json::from_serialize`]
+/// (from any [`Serialize`] type).
 #[derive(Debug)]
 pub struct Json {
     /// The highlighted text representation of the JSON data.
@@ -145,6 +196,20 @@ impl Json {
 
         Json { text }
     }
+
+    /// Create a `Json` from any value implementing [`Serialize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsonError::Serialize`] if the value cannot be represented as
+    /// JSON, e.g. a map with non-string keys.
+    pub fn from_serialize<T: Serialize + ?Sized>(
+        value: &T,
+        options: JsonOptions,
+    ) -> Result<Self, JsonError> {
+        let value = serde_json::to_value(value).map_err(JsonError::Serialize)?;
+        Ok(Self::from_value(&value, options))
+    }
 }
 
 impl Renderable for Json {
@@ -174,26 +239,96 @@ fn sort_value(value: &Value) -> Value {
     }
 }
 
-/// Format a `Value` as a JSON string respecting indent and sort_keys options.
+/// Write `value` through `formatter`, applying the `max_depth`, `max_length`
+/// and `max_string` limits. Elided data is written as bare markers (`[…]`,
+/// `{…}`, `... +N more`) through the formatter, so they follow its layout.
+/// `depth` is the nesting level of `value`, starting at 1 for the top-level
+/// container.
+fn write_value<W: io::Write, F: Formatter>(
+    writer: &mut W,
+    formatter: &mut F,
+    value: &Value,
+    depth: usize,
+    options: &JsonOptions,
+) -> io::Result<()> {
+    let folded = options.max_depth.is_some_and(|max| depth > max);
+    match value {
+        Value::String(s) => match options.max_string {
+            Some(max) if s.chars().count() > max => {
+                let truncated = format!("{}…", s.chars().take(max).collect::<String>());
+                serde_json::to_writer(writer, &truncated)?;
+            }
+            _ => serde_json::to_writer(writer, s)?,
+        },
+        Value::Array(arr) if folded && !arr.is_empty() => {
+            formatter.write_raw_fragment(writer, "[…]")?;
+        }
+        Value::Object(map) if folded && !map.is_empty() => {
+            formatter.write_raw_fragment(writer, "{…}")?;
+        }
+        Value::Array(arr) => {
+            let shown = options.max_length.unwrap_or(arr.len()).min(arr.len());
+            formatter.begin_array(writer)?;
+            for (i, item) in arr[..shown].iter().enumerate() {
+                formatter.begin_array_value(writer, i == 0)?;
+                write_value(writer, formatter, item, depth + 1, options)?;
+                formatter.end_array_value(writer)?;
+            }
+            if shown < arr.len() {
+                formatter.begin_array_value(writer, shown == 0)?;
+                formatter
+                    .write_raw_fragment(writer, &format!("... +{} more", arr.len() - shown))?;
+                formatter.end_array_value(writer)?;
+            }
+            formatter.end_array(writer)?;
+        }
+        Value::Object(map) => {
+            let shown = options.max_length.unwrap_or(map.len()).min(map.len());
+            formatter.begin_object(writer)?;
+            for (i, (key, item)) in map.iter().take(shown).enumerate() {
+                formatter.begin_object_key(writer, i == 0)?;
+                serde_json::to_writer(&mut *writer, key)?;
+                formatter.end_object_key(writer)?;
+                formatter.begin_object_value(writer)?;
+                write_value(writer, formatter, item, depth + 1, options)?;
+                formatter.end_object_value(writer)?;
+            }
+            if shown < map.len() {
+                // The marker takes the place of a key and value.
+                formatter.begin_object_key(writer, shown == 0)?;
+                formatter
+                    .write_raw_fragment(writer, &format!("... +{} more", map.len() - shown))?;
+                formatter.end_object_value(writer)?;
+            }
+            formatter.end_object(writer)?;
+        }
+        other => serde_json::to_writer(writer, other)?,
+    }
+    Ok(())
+}
+
+/// Format a `Value` as a JSON string respecting the indent, sort_keys and
+/// truncation options.
 fn format_value(value: &Value, options: &JsonOptions) -> String {
+    let sorted;
     let value = if options.sort_keys {
-        sort_value(value)
+        sorted = sort_value(value);
+        &sorted
     } else {
-        value.clone()
+        value
     };
 
-    match options.indent {
-        None => serde_json::to_string(&value).unwrap_or_default(),
+    let mut buf = Vec::new();
+    let result = match options.indent {
+        None => write_value(&mut buf, &mut CompactFormatter, value, 1, options),
         Some(indent) => {
-            let mut buf = Vec::new();
             let indent_str: Vec<u8> = vec![b' '; indent];
-            let formatter = serde_json::ser::PrettyFormatter::with_indent(&indent_str);
-            let mut ser = serde_json::Serializer::with_formatter(&mut buf, formatter);
-            serde::Serialize::serialize(&value, &mut ser)
-                .expect("serialization of Value should not fail");
-            String::from_utf8(buf).unwrap_or_default()
+            let mut formatter = PrettyFormatter::with_indent(&indent_str);
+            write_value(&mut buf, &mut formatter, value, 1, options)
         }
-    }
+    };
+    result.expect("writing JSON to a Vec should not fail");
+    String::from_utf8(buf).unwrap_or_default()
 }
 
 // ---------------------------------------------------------------------------
@@ -624,4 +759,82 @@ mod tests {
         assert!(s.contains("name"));
         assert!(s.contains("world"));
     }
+
+    // -- Serialize input ------------------------------------------------
+
+    #[test]
+    fn test_from_serialize() {
+        #[derive(serde::Serialize)]
+        struct User {
+            name: &'static str,
+            tags: Vec<&'static str>,
+        }
+        let user = User {
+            name: "Alice",
+            tags: vec!["admin"],
+        };
+        let json = Json::from_serialize(&user, JsonOptions::compact()).unwrap();
+        assert_eq!(json.text.plain(), r#"{"name":"Alice","tags":["admin"]}"#);
+    }
+
+    #[test]
+    fn test_from_serialize_error() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(vec![1], 2);
+        let err = Json::from_serialize(&map, JsonOptions::default()).unwrap_err();
+        assert!(matches!(err, JsonError::Serialize(_)));
+        assert!(err.to_string().starts_with("failed to serialize value"));
+    }
+
+    // -- Truncation -----------------------------------------------------
+
+    #[test]
+    fn test_max_depth_folds_nested_containers() {
+        let input = r#"{"a": {"b": {"c": 1}, "e": []}, "d": [[1], 2]}"#;
+        let json = Json::new(input, JsonOptions::compact().with_max_depth(2)).unwrap();
+        assert_eq!(json.text.plain(), r#"{"a":{"b":{…},"e":[]},"d":[[…],2]}"#);
+        let json = Json::new(input, JsonOptions::compact().with_max_depth(1)).unwrap();
+        assert_eq!(json.text.plain(), r#"{"a":{…},"d":[…]}"#);
+    }
+
+    #[test]
+    fn test_max_length_truncates_arrays_and_objects() {
+        let input = r#"{"a": [1, 2, 3, 4], "b": 2, "c": 3}"#;
+        let json = Json::new(input, JsonOptions::compact().with_max_length(2)).unwrap();
+        assert_eq!(
+            json.text.plain(),
+            r#"{"a":[1,2,... +2 more],"b":2,... +1 more}"#
+        );
+
+        let json = Json::new(input, JsonOptions::default().with_max_length(1)).unwrap();
+        assert_eq!(
+            json.text.plain(),
+            "{\n  \"a\": [\n    1,\n    ... +3 more\n  ],\n  ... +2 more\n}"
+        );
+    }
+
+    #[test]
+    fn test_max_string_truncates_strings() {
+        let input = r#"{"greeting": "hello world", "short": "hi"}"#;
+        let json = Json::new(input, JsonOptions::compact().with_max_string(5)).unwrap();
+        assert_eq!(json.text.plain(), r#"{"greeting":"hello…","short":"hi"}"#);
+    }
+
+    #[test]
+    fn test_truncation_leaves_private_use_characters_alone() {
+        let input = "[\"\u{E000}x\", {\"\u{E001}\": 1}, 3]";
+        let json = Json::new(input, JsonOptions::compact().with_max_length(2)).unwrap();
+        assert_eq!(
+            json.text.plain(),
+            "[\"\u{E000}x\",{\"\u{E001}\":1},... +1 more]"
+        );
+    }
+
+    #[test]
+    fn test_truncation_matches_untruncated_layout() {
+        let input = r#"{"a": [1, {"b": null}], "c": "d"}"#;
+        let plain = Json::new(input, JsonOptions::default()).unwrap();
+        let limited = Json::new(input, JsonOptions::default().with_max_length(10)).unwrap();
+        assert_eq!(plain.text.plain(), limited.text.plain());
+    }
 }
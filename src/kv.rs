@@ -0,0 +1,521 @@
+//! Key/value (definition list) widget.
+//!
+//! [`KeyValue`] renders pairs as two aligned columns: every key is padded to
+//! the width of the widest key so the values line up. Long values wrap with a
+//! hanging indent under the value column (or are truncated), multi-line
+//! values keep their line breaks, and pairs can be grouped under headers.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::kv::KeyValue;
+//!
+//! let mut kv = KeyValue::new();
+//! kv.add("Host", "web-01");
+//! kv.add("Uptime", "12 days");
+//! assert_eq!(kv.to_text().plain(), "Host:   web-01\nUptime: 12 days");
+//! ```
+
+use crate::cells::cell_len;
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::measure::Measurement;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{JustifyMethod, OverflowMethod, Text};
+
+// ---------------------------------------------------------------------------
+// Entries
+// ---------------------------------------------------------------------------
+
+/// One row of a [`KeyValue`] list.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// The key, or the title of a group header.
+    key: Text,
+    /// The value; `None` marks a header introducing the pairs that follow it.
+    value: Option<Text>,
+}
+
+// ---------------------------------------------------------------------------
+// KeyValue
+// ---------------------------------------------------------------------------
+
+/// Aligned key/value pairs with optional group headers.
+///
+/// Keys and values accept anything convertible into [`Text`], so a plain
+/// `&str` is taken literally (no markup parsing) while a styled `Text` keeps
+/// its spans. Key styles layer under any style carried by the key itself.
+#[derive(Debug, Clone)]
+pub struct KeyValue {
+    /// Rows in insertion order.
+    entries: Vec<Entry>,
+    /// Text placed between each key and its value.
+    pub separator: String,
+    /// Style applied to every key.
+    pub key_style: Style,
+    /// Style applied to the separator.
+    pub separator_style: Style,
+    /// Style applied to every value.
+    pub value_style: Style,
+    /// Style applied to group headers.
+    pub group_style: Style,
+    /// Alignment of keys within the key column.
+    pub key_justify: JustifyMethod,
+    /// Cap on the key column width; longer keys are truncated with `…`.
+    pub max_key_width: Option<usize>,
+    /// Wrap long values under the value column; when `false` they are
+    /// truncated with `…` instead.
+    pub wrap_values: bool,
+    /// Indentation of pairs that belong to a group.
+    pub group_indent: usize,
+}
+
+impl Default for KeyValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyValue {
+    /// Create an empty list with a `": "` separator and bold keys.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            separator: ": ".to_string(),
+            key_style: Style::parse("bold").unwrap_or_else(|_| Style::null()),
+            separator_style: Style::null(),
+            value_style: Style::null(),
+            group_style: Style::parse("bold underline").unwrap_or_else(|_| Style::null()),
+            key_justify: JustifyMethod::Left,
+            max_key_width: None,
+            wrap_values: true,
+            group_indent: 2,
+        }
+    }
+
+    /// Add a key/value pair.
+    pub fn add(&mut self, key: impl Into<Text>, value: impl Into<Text>) {
+        self.entries.push(Entry {
+            key: key.into(),
+            value: Some(value.into()),
+        });
+    }
+
+    /// Start a group: a header line, after which pairs are indented.
+    pub fn add_group(&mut self, title: impl Into<Text>) {
+        self.entries.push(Entry {
+            key: title.into(),
+            value: None,
+        });
+    }
+
+    /// Add a key/value pair (builder pattern).
+    #[must_use]
+    pub fn with_pair(mut self, key: impl Into<Text>, value: impl Into<Text>) -> Self {
+        self.add(key, value);
+        self
+    }
+
+    /// Start a group (builder pattern).
+    #[must_use]
+    pub fn with_group(mut self, title: impl Into<Text>) -> Self {
+        self.add_group(title);
+        self
+    }
+
+    /// Set the separator between keys and values.
+    #[must_use]
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Set the style applied to every key.
+    #[must_use]
+    pub fn with_key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the style applied to the separator.
+    #[must_use]
+    pub fn with_separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+
+    /// Set the style applied to every value.
+    #[must_use]
+    pub fn with_value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// Set the style applied to group headers.
+    #[must_use]
+    pub fn with_group_style(mut self, style: Style) -> Self {
+        self.group_style = style;
+        self
+    }
+
+    /// Set the alignment of keys within the key column.
+    #[must_use]
+    pub fn with_key_justify(mut self, justify: JustifyMethod) -> Self {
+        self.key_justify = justify;
+        self
+    }
+
+    /// Cap the width of the key column.
+    #[must_use]
+    pub fn with_max_key_width(mut self, width: usize) -> Self {
+        self.max_key_width = Some(width);
+        self
+    }
+
+    /// Set whether long values wrap (`true`) or are truncated (`false`).
+    #[must_use]
+    pub fn with_wrap_values(mut self, wrap: bool) -> Self {
+        self.wrap_values = wrap;
+        self
+    }
+
+    /// Number of key/value pairs, not counting group headers.
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|e| e.value.is_some()).count()
+    }
+
+    /// Whether the list has no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // -- layout -------------------------------------------------------------
+
+    /// Width of the key column, before the separator.
+    fn key_width(&self) -> usize {
+        let widest = self
+            .entries
+            .iter()
+            .filter(|e| e.value.is_some())
+            .map(|e| e.key.cell_len())
+            .max()
+            .unwrap_or(0);
+        self.max_key_width.map_or(widest, |cap| widest.min(cap))
+    }
+
+    /// Indentation of pairs: nonzero once any group has been declared.
+    fn pair_indent(&self) -> usize {
+        if self.entries.iter().any(|e| e.value.is_none()) {
+            self.group_indent
+        } else {
+            0
+        }
+    }
+
+    /// Width of everything to the left of the value column.
+    fn value_offset(&self) -> usize {
+        self.pair_indent() + self.key_width() + cell_len(&self.separator)
+    }
+
+    /// Lay the list out as lines of text.
+    ///
+    /// With `width` set, values wrap (or are truncated) to fit; otherwise
+    /// each value line is kept whole.
+    fn lines(&self, width: Option<usize>) -> Vec<Text> {
+        let key_width = self.key_width();
+        let offset = self.value_offset();
+        let value_width = width.map(|w| w.saturating_sub(offset).max(1));
+        let indent = " ".repeat(self.pair_indent());
+        let hanging = " ".repeat(offset);
+
+        let mut lines = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            match &entry.value {
+                None => {
+                    if i > 0 {
+                        lines.push(Text::empty());
+                    }
+                    lines.push(layered(&entry.key, &self.group_style));
+                }
+                Some(value) => {
+                    let mut line = Text::new(&indent, Style::null());
+                    line.append_text(&self.key_cell(&entry.key, key_width));
+
+                    let value = layered(value, &self.value_style);
+                    let value_lines: Vec<Text> = match value_width {
+                        Some(w) => value
+                            .wrap(
+                                w,
+                                None,
+                                Some(if self.wrap_values {
+                                    OverflowMethod::Fold
+                                } else {
+                                    OverflowMethod::Ellipsis
+                                }),
+                                8,
+                                !self.wrap_values,
+                            )
+                            .iter()
+                            .cloned()
+                            .collect(),
+                        None => value.split("\n", false, true).iter().cloned().collect(),
+                    };
+
+                    for (n, value_line) in value_lines.iter().enumerate() {
+                        if n > 0 {
+                            lines.push(std::mem::replace(
+                                &mut line,
+                                Text::new(&hanging, Style::null()),
+                            ));
+                        }
+                        let mut value_line = value_line.clone();
+                        value_line.rstrip();
+                        line.append_text(&value_line);
+                    }
+                    lines.push(line);
+                }
+            }
+        }
+        for line in &mut lines {
+            line.end = String::new();
+        }
+        lines
+    }
+
+    /// A key truncated to `width` cells and joined to the separator, padded
+    /// so that every key cell has the same width.
+    ///
+    /// Left-aligned keys keep the separator next to the key and pad after
+    /// it; right-aligned and centered keys pad before it.
+    fn key_cell(&self, key: &Text, width: usize) -> Text {
+        let mut cell = layered(key, &self.key_style);
+        if cell.cell_len() > width {
+            cell.truncate(width, Some(OverflowMethod::Ellipsis), false);
+        }
+        let pad = width.saturating_sub(cell.cell_len());
+        let separator = Text::styled(&self.separator, self.separator_style.clone());
+        let mut padded = Text::empty();
+        match self.key_justify {
+            JustifyMethod::Right | JustifyMethod::Center => {
+                let left = if self.key_justify == JustifyMethod::Right {
+                    pad
+                } else {
+                    pad / 2
+                };
+                padded.append_str(&" ".repeat(left), None);
+                padded.append_text(&cell);
+                padded.append_str(&" ".repeat(pad - left), None);
+                padded.append_text(&separator);
+            }
+            _ => {
+                padded.append_text(&cell);
+                padded.append_text(&separator);
+                padded.append_str(&" ".repeat(pad), None);
+            }
+        }
+        padded
+    }
+
+    /// The list as a single [`Text`], one line per row, without wrapping.
+    ///
+    /// Useful as content for widgets that take `Text`, such as
+    /// [`Panel`](crate::panel::Panel).
+    pub fn to_text(&self) -> Text {
+        let mut text = Text::empty();
+        text.end = String::new();
+        for (i, line) in self.lines(None).iter().enumerate() {
+            if i > 0 {
+                text.append_str("\n", None);
+            }
+            text.append_text(line);
+        }
+        text
+    }
+
+    /// Return the measurement for this list.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let natural = self
+            .lines(None)
+            .iter()
+            .map(Text::cell_len)
+            .max()
+            .unwrap_or(0);
+        let max = natural.min(options.max_width);
+        let min = (self.value_offset() + 1).min(max);
+        Measurement::new(min, max)
+    }
+}
+
+/// `text` with `style` layered beneath its own base style and spans.
+pub(crate) fn layered(text: &Text, style: &Style) -> Text {
+    let mut out = Text::new(text.plain(), Style::null());
+    let base = style.clone() + text.style().clone();
+    if !base.is_null() {
+        out.stylize(base, 0, None);
+    }
+    for span in &text.spans {
+        out.stylize(span.style.clone(), span.start, Some(span.end));
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Renderable
+// ---------------------------------------------------------------------------
+
+impl Renderable for KeyValue {
+    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for line in self.lines(Some(options.max_width)) {
+            segments.extend(line.render());
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Display
+// ---------------------------------------------------------------------------
+
+impl std::fmt::Display for KeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_plain(kv: &KeyValue, width: usize) -> Vec<String> {
+        let console = Console::builder()
+            .width(width)
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        let segments = kv.gilt_console(&console, &console.options());
+        let joined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        joined.lines().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_keys_are_aligned() {
+        let kv = KeyValue::new()
+            .with_pair("a", "1")
+            .with_pair("long key", "2");
+        assert_eq!(render_plain(&kv, 80), vec!["a:        1", "long key: 2"]);
+    }
+
+    #[test]
+    fn test_custom_separator_and_right_justify() {
+        let kv = KeyValue::new()
+            .with_separator(" = ")
+            .with_key_justify(JustifyMethod::Right)
+            .with_pair("a", "1")
+            .with_pair("abc", "2");
+        assert_eq!(render_plain(&kv, 80), vec!["  a = 1", "abc = 2"]);
+    }
+
+    #[test]
+    fn test_value_wraps_with_hanging_indent() {
+        let kv = KeyValue::new().with_pair("k", "one two three four");
+        assert_eq!(
+            render_plain(&kv, 12),
+            vec!["k: one two", "   three", "   four"]
+        );
+    }
+
+    #[test]
+    fn test_value_truncated_without_wrap() {
+        let kv = KeyValue::new()
+            .with_wrap_values(false)
+            .with_pair("k", "one two three four");
+        assert_eq!(render_plain(&kv, 12), vec!["k: one two \u{2026}"]);
+    }
+
+    #[test]
+    fn test_multiline_value() {
+        let kv = KeyValue::new().with_pair("path", "a\nb");
+        assert_eq!(kv.to_text().plain(), "path: a\n      b");
+    }
+
+    #[test]
+    fn test_max_key_width_truncates() {
+        let kv = KeyValue::new()
+            .with_max_key_width(4)
+            .with_pair("abcdefgh", "1");
+        assert_eq!(kv.to_text().plain(), "abc\u{2026}: 1");
+    }
+
+    #[test]
+    fn test_groups() {
+        let kv = KeyValue::new()
+            .with_group("Server")
+            .with_pair("host", "web-01")
+            .with_group("Client")
+            .with_pair("agent", "curl");
+        assert_eq!(
+            kv.to_text().plain(),
+            "Server\n  host:  web-01\n\nClient\n  agent: curl"
+        );
+        assert_eq!(kv.len(), 2);
+    }
+
+    #[test]
+    fn test_key_style_layers_under_key_text() {
+        let kv = KeyValue::new()
+            .with_pair(Text::styled("k", Style::parse("red").unwrap()), "v")
+            .with_value_style(Style::parse("italic").unwrap());
+        let segments = kv.to_text().render();
+        let key = segments.iter().find(|s| s.text == "k").unwrap();
+        let style = key.style.as_ref().unwrap();
+        assert_eq!(style.bold(), Some(true));
+        assert!(style.color().is_some());
+        let value = segments.iter().find(|s| s.text == "v").unwrap();
+        assert_eq!(value.style.as_ref().unwrap().italic(), Some(true));
+    }
+
+    #[test]
+    fn test_values_are_not_markup() {
+        let kv = KeyValue::new().with_pair("k", "[bold]x[/bold]");
+        assert_eq!(kv.to_text().plain(), "k: [bold]x[/bold]");
+    }
+
+    #[test]
+    fn test_measure() {
+        let kv = KeyValue::new()
+            .with_pair("key", "value")
+            .with_pair("k", "a longer value");
+        let console = Console::builder().width(80).build();
+        let m = kv.measure(&console, &console.options());
+        assert_eq!(m.maximum, 19);
+        assert_eq!(m.minimum, 6);
+    }
+
+    #[test]
+    fn test_empty() {
+        let kv = KeyValue::new();
+        assert!(kv.is_empty());
+        assert_eq!(kv.to_text().plain(), "");
+        assert!(render_plain(&kv, 80).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let kv = KeyValue::new().with_pair("a", "1");
+        assert_eq!(format!("{}", kv), "a: 1");
+    }
+}
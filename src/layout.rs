@@ -12,6 +12,7 @@ use crate::region::Region;
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::Text;
+use crate::tree::Tree;
 
 // ---------------------------------------------------------------------------
 // Splitter trait + implementations
@@ -37,7 +38,7 @@ impl Splitter for RowSplitter {
     }
 
     fn divide(&self, children: &[Layout], region: Region) -> Vec<(usize, Region)> {
-        let widths = ratio_resolve(region.width, children);
+        let widths = fit_sizes(ratio_resolve(region.width, children), region.width);
         let mut offset: usize = 0;
         let mut result = Vec::with_capacity(children.len());
         for (i, &child_width) in widths.iter().enumerate() {
@@ -67,7 +68,7 @@ impl Splitter for ColumnSplitter {
     }
 
     fn divide(&self, children: &[Layout], region: Region) -> Vec<(usize, Region)> {
-        let heights = ratio_resolve(region.height, children);
+        let heights = fit_sizes(ratio_resolve(region.height, children), region.height);
         let mut offset: usize = 0;
         let mut result = Vec::with_capacity(children.len());
         for (i, &child_height) in heights.iter().enumerate() {
@@ -86,6 +87,26 @@ impl Splitter for ColumnSplitter {
     }
 }
 
+/// Shrink resolved sizes that overflow `total`.
+///
+/// [`ratio_resolve`] honours every `minimum_size` (and fixed `size`) even when
+/// together they exceed the space available. Rather than letting regions run
+/// past the edge, the overflow is taken from the last children first, so the
+/// leading layouts keep their sizes and trailing ones shrink (possibly to
+/// zero).
+fn fit_sizes(mut sizes: Vec<usize>, total: usize) -> Vec<usize> {
+    let mut overflow = sizes.iter().sum::<usize>().saturating_sub(total);
+    for size in sizes.iter_mut().rev() {
+        if overflow == 0 {
+            break;
+        }
+        let cut = overflow.min(*size);
+        *size -= cut;
+        overflow -= cut;
+    }
+    sizes
+}
+
 // ---------------------------------------------------------------------------
 // SplitterType enum
 // ---------------------------------------------------------------------------
@@ -115,6 +136,14 @@ impl SplitterType {
             SplitterType::Column => "column",
         }
     }
+
+    /// Icon and theme style used for this splitter in [`Layout::tree`].
+    fn tree_icon(&self) -> (&'static str, &'static str) {
+        match self {
+            SplitterType::Row => ("⬌", "layout.tree.row"),
+            SplitterType::Column => ("⬍", "layout.tree.column"),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -241,6 +270,89 @@ impl Layout {
         None
     }
 
+    /// Show or hide the layout called `name`.
+    ///
+    /// Hidden layouts take no space; their siblings share the region as if
+    /// they were absent. Returns `false` if no layout has that name.
+    pub fn set_visible(&mut self, name: &str, visible: bool) -> bool {
+        match self.get_mut(name) {
+            Some(layout) => {
+                layout.visible = visible;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Names of this layout and all its descendants, depth first.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.name.as_deref().into_iter().collect();
+        for child in &self.children {
+            names.extend(child.names());
+        }
+        names
+    }
+
+    /// A [`Tree`] showing the split structure, for debugging layouts.
+    ///
+    /// Split layouts are prefixed with `⬌` (row) or `⬍` (column) in the
+    /// `layout.tree.row` / `layout.tree.column` theme styles; hidden layouts
+    /// are dimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::layout::Layout;
+    ///
+    /// let named = |name: &str| Layout::new(None, Some(name.into()), None, None, None, None);
+    /// let mut root = named("root");
+    /// root.split_row(vec![named("left"), named("right")]);
+    /// root["right"].size = Some(20);
+    /// let tree = format!("{}", root.tree());
+    /// assert!(tree.contains("⬌ Layout(name='root')"));
+    /// assert!(tree.contains("Layout(name='right', size=20)"));
+    /// ```
+    pub fn tree(&self) -> Tree {
+        let mut label = String::new();
+        let mut style_name = None;
+        if !self.children.is_empty() {
+            let (icon, icon_style) = self.splitter.tree_icon();
+            label.push_str(icon);
+            label.push(' ');
+            style_name = Some(icon_style);
+        }
+        label.push_str(&self.describe());
+        if !self.visible {
+            style_name = Some("dim");
+        }
+
+        let mut node = Tree::new(Text::new(&label, Style::null()));
+        node.style_name = style_name.map(str::to_string);
+        node.children = self.children.iter().map(Layout::tree).collect();
+        node
+    }
+
+    /// Short description such as `Layout(name='body', ratio=2)`.
+    fn describe(&self) -> String {
+        let mut fields = Vec::new();
+        if let Some(name) = &self.name {
+            fields.push(format!("name='{}'", name));
+        }
+        if let Some(size) = self.size {
+            fields.push(format!("size={}", size));
+        }
+        if self.minimum_size != 1 {
+            fields.push(format!("minimum_size={}", self.minimum_size));
+        }
+        if self.ratio != 1 {
+            fields.push(format!("ratio={}", self.ratio));
+        }
+        if !self.visible {
+            fields.push("visible=false".to_string());
+        }
+        format!("Layout({})", fields.join(", "))
+    }
+
     /// Get visible children only.
     pub fn visible_children(&self) -> Vec<&Layout> {
         self.children.iter().filter(|c| c.visible).collect()
@@ -281,7 +393,7 @@ impl Layout {
         }
 
         // Sort by region (y, x) for deterministic order
-        layout_regions.sort_by(|a, b| (a.1.y, a.1.x).cmp(&(b.1.y, b.1.x)));
+        layout_regions.sort_by_key(|a| (a.1.y, a.1.x));
 
         layout_regions
     }
@@ -352,7 +464,7 @@ impl Renderable for Layout {
         // Collect all (region, lines) sorted by position
         let mut entries: Vec<(Region, &Vec<Vec<Segment>>)> =
             render_map.values().map(|(r, lines)| (*r, lines)).collect();
-        entries.sort_by(|a, b| (a.0.y, a.0.x).cmp(&(b.0.y, b.0.x)));
+        entries.sort_by_key(|a| (a.0.y, a.0.x));
 
         let mut layout_lines: Vec<Vec<Segment>> = vec![Vec::new(); height];
 
@@ -394,6 +506,37 @@ fn placeholder_text(layout: &Layout, width: usize, height: usize) -> Text {
     Text::new(&title, Style::null())
 }
 
+// ---------------------------------------------------------------------------
+// Named lookup
+// ---------------------------------------------------------------------------
+
+impl std::ops::Index<&str> for Layout {
+    type Output = Layout;
+
+    /// Look up a layout by name, e.g. `layout["sidebar"]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no layout has that name; use [`Layout::get`] to test first.
+    fn index(&self, name: &str) -> &Layout {
+        self.get(name)
+            .unwrap_or_else(|| panic!("no layout named '{}'", name))
+    }
+}
+
+impl std::ops::IndexMut<&str> for Layout {
+    /// Look up a layout by name for modification, e.g.
+    /// `layout["sidebar"].visible = false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no layout has that name; use [`Layout::get_mut`] to test first.
+    fn index_mut(&mut self, name: &str) -> &mut Layout {
+        self.get_mut(name)
+            .unwrap_or_else(|| panic!("no layout named '{}'", name))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Display
 // ---------------------------------------------------------------------------
@@ -1235,4 +1378,125 @@ mod tests {
         let s = format!("{}", layout);
         assert!(!s.is_empty());
     }
+
+    // -- Visibility, minimum size, lookup and tree ---------------------------
+
+    fn named(name: &str) -> Layout {
+        Layout::new(None, Some(name.to_string()), None, None, None, None)
+    }
+
+    #[test]
+    fn test_set_visible() {
+        let mut root = named("root");
+        root.split_row(vec![named("left"), named("right")]);
+
+        assert!(root.set_visible("left", false));
+        assert!(!root.set_visible("missing", false));
+        let map = root.make_region_map(40, 10);
+        let right = map
+            .iter()
+            .find(|(l, _)| l.name.as_deref() == Some("right"))
+            .unwrap();
+        assert_eq!(right.1, Region::new(0, 0, 40, 10));
+
+        assert!(root.set_visible("left", true));
+        assert_eq!(root.visible_children().len(), 2);
+    }
+
+    #[test]
+    fn test_index_by_name() {
+        let mut root = named("root");
+        root.split_column(vec![named("header"), named("body")]);
+        root["header"].size = Some(3);
+        root["body"].visible = false;
+        assert_eq!(root["header"].size, Some(3));
+        assert!(!root["body"].visible);
+        assert_eq!(root.names(), vec!["root", "header", "body"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no layout named 'nope'")]
+    fn test_index_missing_name_panics() {
+        let root = named("root");
+        let _ = &root["nope"];
+    }
+
+    #[test]
+    fn test_minimum_size_enforced() {
+        let mut root = Layout::default_layout();
+        let mut side = named("side");
+        side.minimum_size = 15;
+        root.split_row(vec![named("main"), side]);
+
+        // An even split would give each 10; the sidebar keeps its minimum
+        let map = root.make_region_map(20, 5);
+        let width_of = |name: &str| {
+            map.iter()
+                .find(|(l, _)| l.name.as_deref() == Some(name))
+                .map(|(_, r)| r.width)
+                .unwrap()
+        };
+        assert_eq!(width_of("side"), 15);
+        assert_eq!(width_of("main"), 5);
+    }
+
+    #[test]
+    fn test_minimum_size_overflow_falls_back() {
+        let mut root = Layout::default_layout();
+        let mut a = named("a");
+        a.minimum_size = 8;
+        let mut b = named("b");
+        b.minimum_size = 8;
+        root.split_column(vec![a, b]);
+
+        // Minimums total 16 but only 10 rows exist: the last child shrinks
+        let map = root.make_region_map(10, 10);
+        let heights: Vec<usize> = ["a", "b"]
+            .iter()
+            .map(|n| {
+                map.iter()
+                    .find(|(l, _)| l.name.as_deref() == Some(*n))
+                    .unwrap()
+                    .1
+                    .height
+            })
+            .collect();
+        assert_eq!(heights, vec![8, 2]);
+
+        let console = Console::builder().width(10).height(10).build();
+        let segments = root.gilt_console(&console, &console.options());
+        let lines = segments.iter().filter(|s| s.text == "\n").count();
+        assert_eq!(lines, 10);
+    }
+
+    #[test]
+    fn test_fit_sizes() {
+        assert_eq!(fit_sizes(vec![3, 4], 10), vec![3, 4]);
+        assert_eq!(fit_sizes(vec![6, 6, 6], 10), vec![6, 4, 0]);
+        assert_eq!(fit_sizes(vec![12, 1], 10), vec![10, 0]);
+    }
+
+    #[test]
+    fn test_tree() {
+        let mut root = named("root");
+        let mut body = named("body");
+        body.ratio = 2;
+        body.split_row(vec![named("main"), named("side")]);
+        root.split_column(vec![named("header"), body]);
+        root.set_visible("side", false);
+
+        let tree = root.tree();
+        assert_eq!(tree.label.plain(), "⬍ Layout(name='root')");
+        assert_eq!(tree.children[0].label.plain(), "Layout(name='header')");
+        assert_eq!(
+            tree.children[1].label.plain(),
+            "⬌ Layout(name='body', ratio=2)"
+        );
+        let side = &tree.children[1].children[1];
+        assert_eq!(side.label.plain(), "Layout(name='side', visible=false)");
+        assert_eq!(side.style_name.as_deref(), Some("dim"));
+
+        let output = format!("{}", tree);
+        assert_eq!(output.lines().count(), 5);
+    }
 }
@@ -450,14 +450,18 @@
 //!
 //! ## Figlet
 //!
-//! [`Figlet`](figlet::Figlet) renders large ASCII art text using a built-in 5x7 block font.
+//! [`Figlet`](figlet::Figlet) renders large ASCII art text using a built-in 5x7 block font,
+//! or any FIGlet `.flf` font via [`FigletFont`](figlet::FigletFont).
 //!
 //! ```rust
-//! use gilt::figlet::Figlet;
+//! use gilt::figlet::{Figlet, FigletFont};
 //!
 //! let banner = Figlet::new("HI");
 //! let output = format!("{}", banner);
 //! assert!(!output.is_empty());
+//!
+//! let small = Figlet::new("hi").with_font(FigletFont::small());
+//! assert!(!format!("{}", small).is_empty());
 //! ```
 //!
 //! ## Inspect
@@ -500,7 +504,7 @@
 //!
 //! # Derive Macros
 //!
-//! With the `derive` feature enabled, gilt provides seven proc-macro derives that
+//! With the `derive` feature enabled, gilt provides eight proc-macro derives that
 //! automatically generate widget conversions from struct definitions:
 //!
 //! | Derive | Generates | Method |
@@ -511,6 +515,7 @@
 //! | `Columns` | Columns from a struct | `value.to_columns()` |
 //! | `Rule` | Rule from a struct | `value.to_rule()` |
 //! | `Inspect` | Inspect panel from a struct | `value.to_inspect()` |
+//! | `InspectInfo` | `InspectInfo` impl from doc comments | `gilt::inspect_info(&value)` |
 //! | `Renderable` | `Renderable` trait impl | `console.print(&value)` |
 //!
 //! ```rust,ignore
@@ -551,12 +556,20 @@
 //! | `interactive` | Yes | `rpassword` | Password prompts and selection menus |
 //! | `logging` | Yes | `log` | Logging handler |
 //! | `tracing` | No | `tracing`, `tracing-subscriber` | [`GiltLayer`](tracing_layer::GiltLayer) subscriber |
-//! | `derive` | No | `gilt-derive` | 7 proc-macro derives |
+//! | `derive` | No | `gilt-derive` | 8 proc-macro derives |
 //! | `miette` | No | `miette` | [`GiltMietteHandler`](miette_handler::GiltMietteHandler) |
 //! | `eyre` | No | `eyre` | [`GiltEyreHandler`](eyre_handler::GiltEyreHandler) |
 //! | `anstyle` | No | `anstyle` | Bidirectional `From` conversions |
+//! | `clap` | No | `clap`, `anstyle` | Styled help and errors via [`clap_help`](clap_help) |
 //! | `csv` | No | `csv` | CSV file reading (built-in parser always available) |
+//! | `toml` | No | `toml` | TOML theme files via [`Theme::from_toml`](theme::Theme::from_toml) |
 //! | `readline` | No | `rustyline` | Readline-based prompt completions |
+//! | `notify` | No | `notify` | [`TailView`](tail::TailView) file follower |
+//! | `bidi` | No | `unicode-bidi` | Right-to-left reordering and alignment when wrapping [`Text`](text::Text) |
+//! | `dataframe` | No | `arrow-array`, `arrow-cast`, `arrow-schema` | Arrow record batches and other columnar sources as tables via [`dataframe`](dataframe) |
+//! | `input` | No | -- | Raw-mode key reading via [`KeyReader`](input::KeyReader), arrow-key prompts and the built-in pager |
+//! | `rayon` | No | `rayon` | `.par_progress()` for parallel iterators via [`ParallelProgressExt`](progress::ParallelProgressExt) |
+//! | `resize` | No | `terminal_size`, `signal-hook` (Unix) | Redraw [`Live`](live::Live) and progress displays when the terminal is resized, via [`resize`](resize) |
 //!
 //! For a minimal build with no heavy dependencies:
 //!
@@ -597,6 +610,10 @@
 //!     .init();
 //! ```
 //!
+//! [`GiltSpanDashboard`](span_dashboard::GiltSpanDashboard) is a second
+//! layer that keeps a live table of the spans currently open, with their
+//! busy and idle time.
+//!
 //! ## anstyle -- Type Conversions
 //!
 //! With the `anstyle` feature, gilt [`Color`](color::Color) and [`Style`](style::Style)
@@ -604,6 +621,20 @@
 //! [anstyle](https://docs.rs/anstyle) counterparts, enabling interop with clap,
 //! owo-colors, and the anstyle ecosystem.
 //!
+//! ## clap -- Help and Errors
+//!
+//! With the `clap` feature, [`clap_help::styles`](clap_help::styles) turns a
+//! gilt theme into a clap `Styles` preset, and
+//! [`ClapHelp`](clap_help::ClapHelp) and [`ClapError`](clap_help::ClapError)
+//! render help and parse errors with panels and tables, so a CLI's help looks
+//! like the rest of its output. *(Requires the `clap` feature.)*
+//!
+//! ```rust,ignore
+//! let matches = cmd
+//!     .try_get_matches()
+//!     .unwrap_or_else(|err| gilt::clap_help::exit(&mut Console::new(), &err));
+//! ```
+//!
 //! # Advanced
 //!
 //! ## Theme System
@@ -694,7 +725,12 @@
 //! Files can be written directly with
 //! [`save_text`](console::Console::save_text),
 //! [`save_html`](console::Console::save_html), and
-//! [`save_svg`](console::Console::save_svg).
+//! [`save_svg`](console::Console::save_svg). A recording console also keeps
+//! the timing of each write:
+//! [`save_session`](console::Console::save_session) stores it as a
+//! [`session`] file that [`replay`](console::Console::replay) plays back,
+//! or that [`export_svg_animation`](console::Console::export_svg_animation)
+//! turns into an animated SVG.
 //!
 //! # Module Index
 //!
@@ -712,23 +748,32 @@
 //! | [`progress`] | Multi-task progress bars with live display |
 //! | [`live`] | Live-updating terminal display |
 //! | [`status`] | Spinner with status message |
+//! | [`subprocess`] | Run a command and stream its output into a live panel |
 //! | [`gradient`] | True-color gradient text |
+//! | [`barchart`] | Labeled horizontal bar charts |
+//! | [`heatmap`] | Matrices of values as colored cells |
 //! | [`sparkline`] | Inline Unicode sparkline charts |
 //! | [`canvas`] | Braille dot-matrix graphics |
+//! | [`plot`] | Line and scatter charts with axes and legends |
+//! | [`image`] | Raster images via half-blocks, sixel or kitty graphics |
 //! | [`diff`] | Colored unified and side-by-side diffs |
 //! | [`figlet`] | Large ASCII art text |
+//! | [`kv`] | Aligned key/value definition lists |
+//! | [`cards`] | Dashboard KPI cards laid out side by side |
 //! | [`csv_table`] | CSV-to-Table conversion |
 //! | [`styled_str`] | Stylize trait for `"text".bold().red()` chaining |
 //! | [`mod@inspect`] | Debug any value with rich formatting |
 //! | [`markup`] | Markup tag parser |
 //! | [`color`] | Color types and parsing |
 //! | [`segment`] | Low-level rendering segments |
+//! | [`session`] | Timed recordings of console output, saved and replayed |
 //! | [`theme`] | Named style collections |
 //! | [`accessibility`] | WCAG 2.1 contrast checking |
 //! | [`highlighter`] | Regex-based and repr syntax highlighters |
 //! | [`emoji`] | Emoji shortcode replacement |
 //! | [`box_chars`] | 19 box-drawing character sets |
 //! | [`prelude`] | Convenience re-exports |
+//! | [`testing`] | Plain-text render assertions and snapshots for tests |
 
 // Module hierarchy - organized by functionality
 pub mod color;
@@ -745,16 +790,25 @@ pub mod accordion;
 #[cfg(feature = "anstyle")]
 pub mod anstyle_adapter;
 pub mod badge;
+pub mod barchart;
 pub mod breadcrumbs;
 pub mod canvas;
+pub mod cards;
+#[cfg(feature = "clap")]
+pub mod clap_help;
 pub mod columns;
 pub mod console;
 pub mod csv_table;
+#[cfg(feature = "dataframe")]
+pub mod dataframe;
 pub mod diff;
 pub mod export_format;
 pub mod figlet;
 pub mod gradient;
 pub mod group;
+pub mod heatmap;
+pub mod image;
+pub mod kv;
 pub mod layout;
 #[cfg(feature = "markdown")]
 pub mod markdown;
@@ -762,19 +816,23 @@ pub mod markup;
 pub mod measure;
 pub mod pager;
 pub mod panel;
+pub mod plot;
 pub mod prelude;
 pub mod progress_bar;
 pub mod prompt;
 pub mod region;
 pub mod rule;
 pub mod segment;
+pub mod session;
 pub mod sparkline;
 pub mod style;
 // styled and styled_str are now in utils/
 pub use utils::styled;
 pub use utils::styled_str;
+pub mod subprocess;
 #[cfg(feature = "syntax")]
 pub mod syntax;
+pub mod testing;
 pub mod tree;
 pub mod wrap;
 
@@ -783,8 +841,14 @@ pub mod wrap;
 pub mod r#async;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "input")]
+pub mod input;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "resize")]
+pub mod resize;
+#[cfg(feature = "notify")]
+pub mod tail;
 
 // Backward compatible re-exports
 // Backward compatible re-exports for moved modules
@@ -795,17 +859,20 @@ pub use error::eyre_handler;
 pub use error::logging_handler;
 #[cfg(feature = "miette")]
 pub use error::miette_handler;
+pub use error::result;
+#[cfg(feature = "tracing")]
+pub use error::span_dashboard;
 pub use error::traceback;
 #[cfg(feature = "tracing")]
 pub use error::tracing_layer;
-pub use live::{live_render, screen};
+pub use live::{dashboard, live_group, live_render, screen};
 pub use status::{spinner, spinners, toast};
 
 // Re-export commonly used utils for backward compatibility
 pub use utils::{
-    align_widget, ansi, bar, box_chars, cells, constrain, containers, control, default_styles,
-    diagnose, emoji, emoji_codes, emoji_replace, filesize, highlighter, inspect, padding, pretty,
-    protocol, ratio, scope,
+    align_widget, ansi, bar, box_chars, capabilities, cells, clock, constrain, containers, control,
+    default_styles, diagnose, emoji, emoji_codes, emoji_replace, filesize, format_context, glyphs,
+    highlighter, inspect, padding, pretty, protocol, ratio, scope, shadow,
 };
 
 // Backward compatible re-exports for widgets
@@ -820,6 +887,8 @@ pub use gilt_derive::Columns as DeriveColumns;
 #[cfg(feature = "derive")]
 pub use gilt_derive::Inspect as DeriveInspect;
 #[cfg(feature = "derive")]
+pub use gilt_derive::InspectInfo as DeriveInspectInfo;
+#[cfg(feature = "derive")]
 pub use gilt_derive::Panel;
 #[cfg(feature = "derive")]
 pub use gilt_derive::Renderable;
@@ -867,6 +936,15 @@ pub fn print_json(json: &str) {
     with_console(|c| c.print_json(json));
 }
 
+/// Pretty-print a `Debug` value to the default console.
+///
+/// Containers stay on one line while they fit the console width. This is
+/// the Rust equivalent of Python rich's `pretty.pprint()`; use
+/// [`Pretty::new`](pretty::Pretty::new) to set depth and length limits.
+pub fn pprint<T: std::fmt::Debug + ?Sized>(value: &T) {
+    with_console(|c| c.print(&pretty::Pretty::new(value)));
+}
+
 /// Inspect a value in the default console.
 ///
 /// Displays the type name, Debug representation, and optional docs
@@ -874,3 +952,14 @@ pub fn print_json(json: &str) {
 pub fn inspect<T: std::fmt::Debug + 'static>(value: &T) {
     with_console(|c| c.inspect(value));
 }
+
+/// Inspect a value in the default console, including the fields, metadata
+/// and methods it reports through [`InspectInfo`](inspect::InspectInfo).
+///
+/// This is the Rust equivalent of Python rich's `inspect(obj, methods=True)`.
+pub fn inspect_info<T>(value: &T)
+where
+    T: std::fmt::Debug + inspect::InspectInfo + 'static,
+{
+    with_console(|c| c.inspect_info(value));
+}
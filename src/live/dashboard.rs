@@ -0,0 +1,363 @@
+//! Dashboard -- a full-screen [`Layout`] that redraws only the panes that
+//! changed.
+//!
+//! [`Live`](crate::live::Live) repaints its whole renderable on every
+//! refresh. For `top`-like tools built from a [`Layout`] that is wasteful and
+//! flickers, so [`Dashboard`] renders the layout on the alternate screen,
+//! remembers each pane's last frame, and on refresh moves the cursor only to
+//! the panes whose position or content differs.
+
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::console::Console;
+use crate::control::Control;
+use crate::layout::Layout;
+use crate::region::Region;
+use crate::segment::Segment;
+
+/// The rendered lines of one pane and where they were drawn.
+type PaneFrame = (Region, Vec<Vec<Segment>>);
+
+/// A full-screen [`Layout`] refreshed pane by pane.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::Console;
+/// use gilt::layout::Layout;
+/// use gilt::live::dashboard::Dashboard;
+///
+/// let mut root = Layout::default_layout();
+/// root.split_column(vec![
+///     Layout::new(Some("cpu 12%".into()), Some("cpu".into()), None, None, None, None),
+///     Layout::new(Some("mem 40%".into()), Some("mem".into()), None, None, None, None),
+/// ]);
+///
+/// let console = Console::builder().width(20).height(4).no_color(true).build();
+/// let mut dashboard = Dashboard::new(root).with_console(console).with_screen(false);
+/// dashboard.console_mut().begin_capture();
+/// assert_eq!(dashboard.refresh(), 2); // first frame draws every pane
+/// dashboard.update("cpu", "cpu 15%");
+/// assert_eq!(dashboard.refresh(), 1); // only the cpu pane changed
+/// assert_eq!(dashboard.refresh(), 0);
+/// let output = dashboard.console_mut().end_capture();
+/// assert!(output.contains("cpu 15%"));
+/// ```
+pub struct Dashboard {
+    layout: Layout,
+    console: Console,
+    screen: bool,
+    /// Number of refreshes per second used by [`run`](Dashboard::run).
+    pub refresh_per_second: f64,
+    started: bool,
+    previous: HashMap<String, PaneFrame>,
+}
+
+impl Dashboard {
+    /// Create a dashboard for `layout`.
+    ///
+    /// # Defaults
+    /// - `screen`: `true` (draw on the alternate screen)
+    /// - `refresh_per_second`: `4.0`
+    pub fn new(layout: Layout) -> Self {
+        Dashboard {
+            layout,
+            console: Console::new(),
+            screen: true,
+            refresh_per_second: 4.0,
+            started: false,
+            previous: HashMap::new(),
+        }
+    }
+
+    // -- Builder methods ----------------------------------------------------
+
+    /// Set the console to draw on (builder pattern).
+    #[must_use]
+    pub fn with_console(mut self, console: Console) -> Self {
+        self.console = console;
+        self
+    }
+
+    /// Enable or disable the alternate screen (builder pattern).
+    #[must_use]
+    pub fn with_screen(mut self, screen: bool) -> Self {
+        self.screen = screen;
+        self
+    }
+
+    /// Set the refresh rate used by [`run`](Dashboard::run) (builder pattern).
+    ///
+    /// # Panics
+    /// Panics if `rate` is not greater than zero.
+    #[must_use]
+    pub fn with_refresh_per_second(mut self, rate: f64) -> Self {
+        assert!(rate > 0.0, "refresh_per_second must be > 0");
+        self.refresh_per_second = rate;
+        self
+    }
+
+    // -- Accessors ----------------------------------------------------------
+
+    /// The layout being displayed.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Mutable access to the layout; changes appear on the next refresh.
+    pub fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+
+    /// Replace the content of the pane called `name`. Returns `false` if no
+    /// pane has that name.
+    pub fn update(&mut self, name: &str, content: &str) -> bool {
+        match self.layout.get_mut(name) {
+            Some(pane) => {
+                pane.update(content.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The console the dashboard draws on.
+    pub fn console(&self) -> &Console {
+        &self.console
+    }
+
+    /// Mutable access to the console.
+    pub fn console_mut(&mut self) -> &mut Console {
+        &mut self.console
+    }
+
+    /// Whether the dashboard is currently running.
+    pub fn is_started(&self) -> bool {
+        self.started
+    }
+
+    // -- Lifecycle ----------------------------------------------------------
+
+    /// Take over the terminal and draw the first frame.
+    ///
+    /// Switches to the alternate screen (unless disabled), hides the cursor
+    /// and clears the screen. Does nothing if already started.
+    pub fn start(&mut self) {
+        if self.started {
+            return;
+        }
+        self.started = true;
+        if self.screen {
+            self.console.set_alt_screen(true);
+        }
+        self.console.show_cursor(false);
+        self.console.clear();
+        self.invalidate();
+        self.refresh();
+    }
+
+    /// Restore the cursor and leave the alternate screen. Does nothing if not
+    /// started.
+    pub fn stop(&mut self) {
+        if !self.started {
+            return;
+        }
+        self.started = false;
+        self.console.show_cursor(true);
+        if self.screen {
+            self.console.set_alt_screen(false);
+        }
+    }
+
+    /// Forget the previous frame so the next refresh redraws every pane,
+    /// e.g. after something else has written to the screen.
+    pub fn invalidate(&mut self) {
+        self.previous.clear();
+    }
+
+    // -- Rendering ----------------------------------------------------------
+
+    /// Render the layout and redraw the panes that changed since the last
+    /// refresh. Returns the number of panes redrawn.
+    ///
+    /// A pane is redrawn when its content or its region differs from the
+    /// previous frame, so resizing the terminal or a neighbouring pane
+    /// redraws everything it affects.
+    pub fn refresh(&mut self) -> usize {
+        let options = self.console.options();
+        let options = options.update_dimensions(options.max_width, options.size.height);
+        let frame = self.layout.render(&self.console, &options);
+
+        let mut panes: Vec<(&String, &PaneFrame)> = frame.iter().collect();
+        panes.sort_by_key(|(_, (region, _))| (region.y, region.x));
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut redrawn = 0;
+        for (name, pane) in panes {
+            if self.previous.get(name) == Some(pane) {
+                continue;
+            }
+            redrawn += 1;
+            let (region, lines) = pane;
+            for (row, line) in lines.iter().enumerate() {
+                segments.push(Control::move_to(region.x, region.y + row as i32).segment);
+                segments.extend(line.iter().cloned());
+            }
+        }
+
+        if !segments.is_empty() {
+            self.console.begin_synchronized();
+            self.console.write_segments(&segments);
+            self.console.end_synchronized();
+        }
+        self.previous = frame;
+        redrawn
+    }
+
+    /// Drive the dashboard until `tick` returns `false`.
+    ///
+    /// Starts the display, then repeatedly calls `tick` with the layout to
+    /// update, refreshes, and sleeps for the rest of the frame at
+    /// `refresh_per_second`. The display is stopped when the loop ends.
+    pub fn run<F>(&mut self, mut tick: F)
+    where
+        F: FnMut(&mut Layout) -> bool,
+    {
+        let interval = Duration::from_secs_f64(1.0 / self.refresh_per_second);
+        self.start();
+        loop {
+            let frame_start = Instant::now();
+            if !tick(&mut self.layout) {
+                break;
+            }
+            self.refresh();
+            if let Some(remaining) = interval.checked_sub(frame_start.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.stop();
+    }
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pane(name: &str, content: &str) -> Layout {
+        Layout::new(
+            Some(content.to_string()),
+            Some(name.to_string()),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn dashboard() -> Dashboard {
+        let mut root = Layout::default_layout();
+        root.split_row(vec![pane("left", "L1"), pane("right", "R1")]);
+        let console = Console::builder()
+            .width(20)
+            .height(3)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        Dashboard::new(root).with_console(console)
+    }
+
+    #[test]
+    fn test_only_changed_pane_is_written() {
+        let mut dash = dashboard().with_screen(false);
+        dash.console_mut().begin_capture();
+        dash.refresh();
+        dash.console_mut().end_capture();
+
+        dash.update("right", "R2");
+        dash.console_mut().begin_capture();
+        assert_eq!(dash.refresh(), 1);
+        let output = dash.console_mut().end_capture();
+        assert!(output.contains("R2"));
+        assert!(!output.contains("L1"));
+        // The right pane starts at column 10 (1-indexed: 11).
+        assert!(output.contains("\x1b[1;11H"), "{output:?}");
+    }
+
+    #[test]
+    fn test_layout_change_redraws_affected_panes() {
+        let mut dash = dashboard().with_screen(false);
+        dash.console_mut().begin_capture();
+        dash.refresh();
+        dash.layout_mut().get_mut("left").unwrap().size = Some(5);
+        assert_eq!(dash.refresh(), 2);
+        dash.console_mut().end_capture();
+    }
+
+    #[test]
+    fn test_invalidate_forces_full_redraw() {
+        let mut dash = dashboard().with_screen(false);
+        dash.console_mut().begin_capture();
+        dash.refresh();
+        dash.invalidate();
+        assert_eq!(dash.refresh(), 2);
+        dash.console_mut().end_capture();
+    }
+
+    #[test]
+    fn test_update_unknown_pane() {
+        let mut dash = dashboard();
+        assert!(!dash.update("missing", "x"));
+        assert!(dash.update("left", "x"));
+        assert_eq!(
+            dash.layout().get("left").unwrap().renderable.as_deref(),
+            Some("x")
+        );
+    }
+
+    #[test]
+    fn test_start_and_stop_use_alt_screen() {
+        let mut dash = dashboard();
+        dash.console_mut().begin_capture();
+        dash.start();
+        assert!(dash.is_started());
+        dash.stop();
+        let output = dash.console_mut().end_capture();
+        assert!(!dash.is_started());
+        assert!(output.contains("\x1b[?1049h"));
+        assert!(output.contains("\x1b[?1049l"));
+        assert!(output.contains("\x1b[?25l") && output.contains("\x1b[?25h"));
+    }
+
+    #[test]
+    fn test_run_stops_when_tick_returns_false() {
+        let mut dash = dashboard()
+            .with_screen(false)
+            .with_refresh_per_second(1000.0);
+        dash.console_mut().begin_capture();
+        let mut ticks = 0;
+        dash.run(|layout| {
+            ticks += 1;
+            layout
+                .get_mut("left")
+                .unwrap()
+                .update(format!("tick {ticks}"));
+            ticks < 3
+        });
+        let output = dash.console_mut().end_capture();
+        assert!(!dash.is_started());
+        assert!(output.contains("tick 2"));
+        assert!(!output.contains("tick 3"));
+    }
+}
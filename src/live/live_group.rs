@@ -0,0 +1,300 @@
+//! Live groups -- several independently updated widgets in one live display.
+//!
+//! A terminal has a single cursor, so two [`Live`] displays running at once
+//! overwrite each other. A [`LiveGroup`] instead stacks named slots
+//! vertically and drives them all through one `Live`; each widget updates
+//! only its own slot.
+
+use std::sync::{Arc, Mutex};
+
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::live::{render_to_text, Live};
+use crate::segment::Segment;
+use crate::text::{OverflowMethod, Text};
+
+/// One named region of a [`LiveGroup`].
+#[derive(Clone)]
+struct Slot {
+    name: String,
+    /// The slot's widget, or `None` while it is empty.
+    content: Option<Arc<dyn Renderable + Send + Sync>>,
+    /// Fixed number of lines, or `None` to size to the content.
+    height: Option<usize>,
+}
+
+impl Slot {
+    /// Render the slot's lines, cropped or padded to its fixed height.
+    fn render_lines(&self, console: &Console, options: &ConsoleOptions) -> Vec<Vec<Segment>> {
+        let mut lines = match &self.content {
+            Some(content) => console.render_lines(&**content, Some(options), None, false, false),
+            None => Vec::new(),
+        };
+        // Empty text renders as a single blank line; treat it as no content.
+        if lines.len() == 1 && lines[0].iter().all(|segment| segment.text.is_empty()) {
+            lines.clear();
+        }
+        if let Some(height) = self.height {
+            lines.resize_with(height, Vec::new);
+        }
+        lines
+    }
+}
+
+impl std::fmt::Debug for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Slot")
+            .field("name", &self.name)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LiveGroup
+// ---------------------------------------------------------------------------
+
+/// A stack of named, independently updated slots rendered as one live display.
+///
+/// Slots are drawn top to bottom in the order they were added and may hold
+/// any renderable: text, a table, a progress bar, ... A slot with a fixed
+/// height is cropped or padded to exactly that many lines, so widgets below
+/// it never move. Text is not wrapped; overlong lines end in an ellipsis.
+///
+/// `LiveGroup` is cheap to clone; clones share the same slots, so one clone
+/// can drive the [`Live`] while others (or [`LiveSlot`] handles) are updated
+/// from worker threads.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::live_group::LiveGroup;
+/// use gilt::style::Style;
+/// use gilt::text::Text;
+///
+/// let group = LiveGroup::new()
+///     .with_slot("status", Some(1))
+///     .with_slot("log", Some(2));
+/// let status = group.slot("status");
+///
+/// status.update(Text::new("Connecting...", Style::null()));
+/// group.update("log", Text::new("one\ntwo\nthree", Style::null()));
+/// assert_eq!(group.to_text(40).plain(), "Connecting...\none\ntwo");
+///
+/// status.update(Text::new("Connected", Style::null()));
+/// assert_eq!(group.to_text(40).plain(), "Connected\none\ntwo");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LiveGroup {
+    slots: Arc<Mutex<Vec<Slot>>>,
+}
+
+impl LiveGroup {
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an empty slot (builder pattern). See [`add_slot`](Self::add_slot).
+    #[must_use]
+    pub fn with_slot(self, name: &str, height: Option<usize>) -> Self {
+        self.add_slot(name, height);
+        self
+    }
+
+    /// Append an empty slot below the existing ones.
+    ///
+    /// With `Some(height)` the slot always occupies exactly `height` lines;
+    /// with `None` it grows and shrinks with its content. Adding a name that
+    /// already exists only changes that slot's height.
+    pub fn add_slot(&self, name: &str, height: Option<usize>) {
+        let mut slots = self.slots.lock().unwrap();
+        match slots.iter_mut().find(|slot| slot.name == name) {
+            Some(slot) => slot.height = height,
+            None => slots.push(Slot {
+                name: name.to_string(),
+                content: None,
+                height,
+            }),
+        }
+    }
+
+    /// Remove a slot. Returns `false` if no slot has that name.
+    pub fn remove_slot(&self, name: &str) -> bool {
+        let mut slots = self.slots.lock().unwrap();
+        let before = slots.len();
+        slots.retain(|slot| slot.name != name);
+        slots.len() != before
+    }
+
+    /// Replace the contents of a slot with any renderable. Returns `false` if
+    /// no slot has that name.
+    ///
+    /// The renderable is drawn afresh, at the display's width, on every
+    /// refresh.
+    pub fn update<R: Renderable + Send + Sync + 'static>(&self, name: &str, content: R) -> bool {
+        let mut slots = self.slots.lock().unwrap();
+        match slots.iter_mut().find(|slot| slot.name == name) {
+            Some(slot) => {
+                slot.content = Some(Arc::new(content));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A handle that updates a single slot, for handing to one widget.
+    pub fn slot(&self, name: &str) -> LiveSlot {
+        LiveSlot {
+            group: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    /// The slot names, top to bottom.
+    pub fn names(&self) -> Vec<String> {
+        let slots = self.slots.lock().unwrap();
+        slots.iter().map(|slot| slot.name.clone()).collect()
+    }
+
+    /// Render the stacked slots at `width` columns into a single [`Text`],
+    /// for a [`Live`] display.
+    pub fn to_text(&self, width: usize) -> Text {
+        render_to_text(self, width)
+    }
+
+    /// Create a [`Live`] display that renders this group on every refresh.
+    pub fn into_live(self) -> Live {
+        let live = Live::new(Text::empty());
+        let width = live.console().width();
+        live.with_get_renderable(move || self.to_text(width))
+    }
+}
+
+impl Renderable for LiveGroup {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut options = options.clone();
+        options.no_wrap = true;
+        options.overflow = Some(OverflowMethod::Ellipsis);
+        let slots = self.slots.lock().unwrap();
+        let mut segments = Vec::new();
+        for slot in slots.iter() {
+            for line in slot.render_lines(console, &options) {
+                segments.extend(line);
+                segments.push(Segment::line());
+            }
+        }
+        segments
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LiveSlot
+// ---------------------------------------------------------------------------
+
+/// A handle to one slot of a [`LiveGroup`].
+#[derive(Debug, Clone)]
+pub struct LiveSlot {
+    group: LiveGroup,
+    name: String,
+}
+
+impl LiveSlot {
+    /// The slot's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Replace the slot's contents with any renderable. Returns `false` if
+    /// the slot has been removed from the group.
+    pub fn update<R: Renderable + Send + Sync + 'static>(&self, content: R) -> bool {
+        self.group.update(&self.name, content)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    fn text(s: &str) -> Text {
+        Text::new(s, Style::null())
+    }
+
+    #[test]
+    fn test_fixed_height_pads_and_crops() {
+        let group = LiveGroup::new()
+            .with_slot("a", Some(2))
+            .with_slot("b", None);
+        group.update("b", text("below"));
+        assert_eq!(group.to_text(40).plain(), "\n\nbelow");
+
+        group.update("a", text("1\n2\n3"));
+        assert_eq!(group.to_text(40).plain(), "1\n2\nbelow");
+    }
+
+    #[test]
+    fn test_auto_height_follows_content() {
+        let group = LiveGroup::new().with_slot("a", None).with_slot("b", None);
+        group.update("a", text("x\ny"));
+        group.update("b", text("z"));
+        assert_eq!(group.to_text(40).plain(), "x\ny\nz");
+        group.update("a", Text::empty());
+        assert_eq!(group.to_text(40).plain(), "z");
+    }
+
+    #[test]
+    fn test_unknown_and_removed_slots() {
+        let group = LiveGroup::new().with_slot("a", None);
+        let handle = group.slot("a");
+        assert!(!group.update("missing", text("x")));
+        assert!(handle.update(text("x")));
+        assert!(group.remove_slot("a"));
+        assert!(!handle.update(text("y")));
+        assert!(group.names().is_empty());
+    }
+
+    #[test]
+    fn test_add_existing_slot_changes_height() {
+        let group = LiveGroup::new().with_slot("a", None);
+        group.update("a", text("one"));
+        group.add_slot("a", Some(3));
+        assert_eq!(group.names(), vec!["a"]);
+        assert_eq!(group.to_text(40).plain(), "one\n\n");
+    }
+
+    #[test]
+    fn test_styles_survive_stacking() {
+        let group = LiveGroup::new().with_slot("a", None).with_slot("b", None);
+        group.update("a", text("plain"));
+        group.update("b", Text::new("bold", Style::parse("bold").unwrap()));
+        let stacked = group.to_text(40);
+        let span = stacked.spans().last().unwrap();
+        assert_eq!((span.start, span.end), (6, 10));
+        assert!(span.style.bold().unwrap_or(false));
+    }
+
+    #[test]
+    fn test_slots_hold_any_renderable() {
+        let group = LiveGroup::new()
+            .with_slot("rule", Some(1))
+            .with_slot("b", None);
+        group.update("rule", crate::rule::Rule::new());
+        group.update("b", text("below"));
+        assert_eq!(group.to_text(5).plain(), "━━━━━\nbelow");
+    }
+
+    #[test]
+    fn test_render_does_not_wrap() {
+        let group = LiveGroup::new().with_slot("a", Some(1));
+        group.update("a", text("a line far too long"));
+        let console = Console::builder().width(10).no_color(true).build();
+        let segments = group.gilt_console(&console, &console.options());
+        let out: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(out.lines().count(), 1);
+        assert!(out.contains('\u{2026}'));
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Port of Python's `rich/live_render.py`. Used by `Live` to display content
 //! that can be refreshed in-place by emitting cursor movement control codes.
+//! [`LiveRender::diff_segments`] keeps the previous frame and rewrites only
+//! the lines that changed.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::segment::{ControlCode, ControlType, Segment};
@@ -15,7 +17,8 @@ use crate::text::{JustifyMethod, OverflowMethod, Text};
 pub enum VerticalOverflowMethod {
     /// Crop excess lines (discard lines beyond the height).
     Crop,
-    /// Show an ellipsis ("...") line in place of the last visible line.
+    /// Show the console's continuation glyph (`"..."` by default) in place of
+    /// the last visible line.
     Ellipsis,
     /// Show all lines regardless of the height constraint.
     Visible,
@@ -30,10 +33,20 @@ pub struct LiveRender {
     pub style: Style,
     /// How to handle vertical overflow.
     pub vertical_overflow: VerticalOverflowMethod,
+    /// Leading lines (such as a table header) kept at the top when the
+    /// content overflows; the newest lines are shown beneath them instead
+    /// of the first ones. Ignored with [`VerticalOverflowMethod::Visible`].
+    pub sticky_lines: usize,
     /// The (width, height) of the last render, or `None` if never rendered.
     /// Uses `Cell` for interior mutability so the `Renderable` trait method
     /// (which takes `&self`) can cache the computed shape without unsafe code.
     shape: Cell<Option<(usize, usize)>>,
+    /// The lines of the last render, compared against by
+    /// [`diff_segments`](Self::diff_segments).
+    lines: RefCell<Vec<Vec<Segment>>>,
+    /// Set by [`invalidate`](Self::invalidate): the next diff rewrites
+    /// every line instead of skipping unchanged ones.
+    stale: Cell<bool>,
 }
 
 impl LiveRender {
@@ -45,7 +58,10 @@ impl LiveRender {
             renderable,
             style: Style::null(),
             vertical_overflow: VerticalOverflowMethod::Ellipsis,
+            sticky_lines: 0,
             shape: Cell::new(None),
+            lines: RefCell::new(Vec::new()),
+            stale: Cell::new(false),
         }
     }
 
@@ -78,6 +94,23 @@ impl LiveRender {
         self.renderable = renderable;
     }
 
+    /// Forget the shape of the last render.
+    ///
+    /// Used after the previous render has been erased and other output was
+    /// written in its place, so the next [`position_cursor`](Self::position_cursor)
+    /// does not move over lines that no longer belong to the live display.
+    pub(crate) fn reset_shape(&self) {
+        self.shape.set(None);
+        self.lines.borrow_mut().clear();
+    }
+
+    /// Make the next [`diff_segments`](Self::diff_segments) rewrite every
+    /// line, e.g. because the console width changed and lines that render
+    /// the same may no longer be laid out the same on screen.
+    pub fn invalidate(&self) {
+        self.stale.set(true);
+    }
+
     /// Return control segments that move the cursor back to the start of the
     /// last render output so that it can be overwritten.
     ///
@@ -123,10 +156,91 @@ impl LiveRender {
 
         vec![Segment::new("", None, Some(codes))]
     }
-}
 
-impl Renderable for LiveRender {
-    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+    /// Render the next frame and return the segments that turn the previous
+    /// frame into it, rewriting only the lines that changed.
+    ///
+    /// Expects the cursor where the previous render left it, at the end of
+    /// its last line, and leaves it at the end of the new frame's last line.
+    /// Unchanged lines are skipped with cursor moves, lines the previous
+    /// frame did not have are appended with newlines, and lines it had
+    /// beyond the new frame's height are erased. Without a previous frame
+    /// the whole frame is written, as by
+    /// [`gilt_console`](Renderable::gilt_console).
+    pub fn diff_segments(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let previous = std::mem::take(&mut *self.lines.borrow_mut());
+        let had_frame = self.last_render_height() > 0;
+        let stale = self.stale.replace(false);
+        let lines = self.render_frame(console, options);
+        if !had_frame {
+            return join_lines(lines);
+        }
+        if !stale && lines == previous {
+            return Vec::new();
+        }
+
+        let mut segments = Vec::new();
+        let mut codes = vec![ControlCode::Simple(ControlType::CarriageReturn)];
+        if previous.len() > 1 {
+            codes.push(ControlCode::WithParam(
+                ControlType::CursorUp,
+                previous.len() as i32 - 1,
+            ));
+        }
+        // Rows that exist on screen; moving below them needs a newline.
+        let mut existing = previous.len();
+        let mut row = 0;
+        for index in 0..lines.len().max(previous.len()) {
+            let new_line = lines.get(index);
+            if !stale && new_line.is_some() && new_line == previous.get(index) {
+                continue;
+            }
+            if index > row {
+                // Move down over rows already on screen, then add new ones.
+                let within = index.min(existing - 1);
+                if within > row {
+                    codes.push(ControlCode::WithParam(
+                        ControlType::CursorDown,
+                        (within - row) as i32,
+                    ));
+                }
+                if index > within {
+                    flush_codes(&mut segments, &mut codes);
+                    for _ in within..index {
+                        segments.push(Segment::line());
+                    }
+                    existing = index + 1;
+                }
+            }
+            row = index;
+            codes.push(ControlCode::Simple(ControlType::CarriageReturn));
+            codes.push(ControlCode::WithParam(ControlType::EraseInLine, 2));
+            flush_codes(&mut segments, &mut codes);
+            if let Some(line) = new_line {
+                segments.extend(line.iter().cloned());
+            }
+        }
+
+        // Finish on the new frame's last line.
+        let last = lines.len().saturating_sub(1);
+        if row > last {
+            codes.push(ControlCode::WithParam(
+                ControlType::CursorUp,
+                (row - last) as i32,
+            ));
+        } else if row < last {
+            codes.push(ControlCode::WithParam(
+                ControlType::CursorDown,
+                (last - row) as i32,
+            ));
+        }
+        flush_codes(&mut segments, &mut codes);
+        segments
+    }
+
+    /// Render the content into lines, applying the vertical overflow method,
+    /// and remember the resulting shape and lines.
+    fn render_frame(&self, console: &Console, options: &ConsoleOptions) -> Vec<Vec<Segment>> {
         // Render the inner content into lines.
         let style_ref = if self.style.is_null() {
             None
@@ -140,7 +254,30 @@ impl Renderable for LiveRender {
         let (_, height) = Segment::get_shape(&lines);
         let max_height = options.height.unwrap_or(options.size.height);
 
-        if height > max_height {
+        if height > max_height
+            && self.sticky_lines > 0
+            && self.sticky_lines < max_height
+            && self.vertical_overflow != VerticalOverflowMethod::Visible
+        {
+            // Keep the sticky lines and scroll the rest to its newest lines,
+            // marking the gap with the continuation glyph for `Ellipsis`.
+            let mut tail = lines.split_off(self.sticky_lines);
+            while tail
+                .last()
+                .is_some_and(|line| line.iter().all(|s| s.text.trim().is_empty()))
+            {
+                tail.pop();
+            }
+            let mut room = max_height - self.sticky_lines;
+            if tail.len() > room
+                && self.vertical_overflow == VerticalOverflowMethod::Ellipsis
+                && room > 1
+            {
+                lines.push(self.continuation_line(console, options));
+                room -= 1;
+            }
+            lines.extend(tail.split_off(tail.len().saturating_sub(room)));
+        } else if height > max_height {
             match self.vertical_overflow {
                 VerticalOverflowMethod::Crop => {
                     lines.truncate(max_height);
@@ -148,13 +285,7 @@ impl Renderable for LiveRender {
                 VerticalOverflowMethod::Ellipsis => {
                     let ellipsis_lines = if max_height > 0 { max_height - 1 } else { 0 };
                     lines.truncate(ellipsis_lines);
-                    // Build an ellipsis text line.
-                    let mut overflow_text = Text::new("...", Style::null());
-                    overflow_text.overflow = Some(OverflowMethod::Crop);
-                    overflow_text.justify = Some(JustifyMethod::Center);
-                    overflow_text.end = String::new();
-                    let ellipsis_segments = console.render(&overflow_text, Some(options));
-                    lines.push(ellipsis_segments);
+                    lines.push(self.continuation_line(console, options));
                 }
                 VerticalOverflowMethod::Visible => {
                     // Keep all lines; do not truncate.
@@ -173,19 +304,44 @@ impl Renderable for LiveRender {
         }
         let final_shape = Segment::get_shape(&lines);
         self.shape.set(Some(final_shape));
+        *self.lines.borrow_mut() = lines.clone();
+        lines
+    }
 
-        // Flatten lines into a single segment list, inserting newlines between
-        // lines (but not after the last line).
-        let mut segments = Vec::new();
-        let line_count = lines.len();
-        for (i, line) in lines.into_iter().enumerate() {
-            segments.extend(line);
-            if i + 1 < line_count {
-                segments.push(Segment::line());
-            }
+    /// The console's continuation glyph, centred, marking omitted lines.
+    fn continuation_line(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let mut overflow_text = Text::new(&console.glyphs().continuation, Style::null());
+        overflow_text.overflow = Some(OverflowMethod::Crop);
+        overflow_text.justify = Some(JustifyMethod::Center);
+        overflow_text.end = String::new();
+        console.render(&overflow_text, Some(options))
+    }
+}
+
+/// Move pending control codes into `segments` as a single control segment.
+fn flush_codes(segments: &mut Vec<Segment>, codes: &mut Vec<ControlCode>) {
+    if !codes.is_empty() {
+        segments.push(Segment::new("", None, Some(std::mem::take(codes))));
+    }
+}
+
+/// Flatten lines into a single segment list, inserting newlines between
+/// lines (but not after the last line).
+fn join_lines(lines: Vec<Vec<Segment>>) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let line_count = lines.len();
+    for (i, line) in lines.into_iter().enumerate() {
+        segments.extend(line);
+        if i + 1 < line_count {
+            segments.push(Segment::line());
         }
+    }
+    segments
+}
 
-        segments
+impl Renderable for LiveRender {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        join_lines(self.render_frame(console, options))
     }
 }
 
@@ -331,6 +487,40 @@ mod tests {
         assert_eq!(lr.last_render_height(), 5);
     }
 
+    // -- Vertical overflow: sticky lines ------------------------------------
+
+    #[test]
+    fn test_sticky_lines_keep_header_and_newest_lines() {
+        let console = Console::builder().width(80).height(4).build();
+        let mut lr = LiveRender::new(Text::new("H\nL1\nL2\nL3\nL4\nL5", Style::null()))
+            .with_vertical_overflow(VerticalOverflowMethod::Crop);
+        lr.sticky_lines = 1;
+        let opts = console.options();
+        let combined: String = lr
+            .gilt_console(&console, &opts)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert!(combined.starts_with('H'));
+        assert!(!combined.contains("L2"));
+        assert!(combined.contains("L3"));
+        assert!(combined.contains("L5"));
+        assert_eq!(lr.last_render_height(), 4);
+
+        lr.vertical_overflow = VerticalOverflowMethod::Ellipsis;
+        let combined: String = lr
+            .gilt_console(&console, &opts)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert!(combined.starts_with('H'));
+        assert!(combined.contains("..."));
+        assert!(!combined.contains("L3"));
+        assert!(combined.contains("L4"));
+        assert!(combined.contains("L5"));
+        assert_eq!(lr.last_render_height(), 4);
+    }
+
     // -- position_cursor ----------------------------------------------------
 
     #[test]
@@ -456,6 +646,102 @@ mod tests {
         assert_eq!(ctrl[2], ControlCode::WithParam(ControlType::EraseInLine, 2));
     }
 
+    // -- diff_segments ------------------------------------------------------
+
+    fn diff(lr: &mut LiveRender, console: &Console, content: &str) -> Vec<Segment> {
+        lr.set_renderable(Text::new(content, Style::null()));
+        lr.diff_segments(console, &console.options())
+    }
+
+    fn written(segments: &[Segment]) -> String {
+        segments.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    fn codes(segments: &[Segment]) -> Vec<ControlCode> {
+        segments
+            .iter()
+            .filter_map(|s| s.control.clone())
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_first_frame_writes_everything() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        let segments = diff(&mut lr, &console, "a\nb");
+        assert_eq!(written(&segments), "a\nb");
+        assert!(codes(&segments).is_empty());
+    }
+
+    #[test]
+    fn test_diff_unchanged_frame_writes_nothing() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        diff(&mut lr, &console, "a\nb");
+        assert!(diff(&mut lr, &console, "a\nb").is_empty());
+    }
+
+    #[test]
+    fn test_diff_rewrites_only_changed_line() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        diff(&mut lr, &console, "one\ntwo\nthree");
+        let segments = diff(&mut lr, &console, "one\n2\nthree");
+        assert_eq!(written(&segments), "2");
+        assert_eq!(
+            codes(&segments),
+            vec![
+                ControlCode::Simple(ControlType::CarriageReturn),
+                ControlCode::WithParam(ControlType::CursorUp, 2),
+                ControlCode::WithParam(ControlType::CursorDown, 1),
+                ControlCode::Simple(ControlType::CarriageReturn),
+                ControlCode::WithParam(ControlType::EraseInLine, 2),
+                ControlCode::WithParam(ControlType::CursorDown, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_appends_new_lines() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        diff(&mut lr, &console, "a");
+        let segments = diff(&mut lr, &console, "a\nb\nc");
+        assert_eq!(written(&segments), "\nb\nc");
+        assert_eq!(lr.last_render_height(), 3);
+    }
+
+    #[test]
+    fn test_diff_erases_removed_lines() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        diff(&mut lr, &console, "a\nb\nc");
+        let segments = diff(&mut lr, &console, "a");
+        assert_eq!(written(&segments), "");
+        let codes = codes(&segments);
+        assert_eq!(
+            codes
+                .iter()
+                .filter(|c| **c == ControlCode::WithParam(ControlType::EraseInLine, 2))
+                .count(),
+            2
+        );
+        assert_eq!(
+            codes.last(),
+            Some(&ControlCode::WithParam(ControlType::CursorUp, 2))
+        );
+    }
+
+    #[test]
+    fn test_diff_after_reset_writes_everything() {
+        let console = Console::builder().width(80).build();
+        let mut lr = LiveRender::new(Text::empty());
+        diff(&mut lr, &console, "a\nb");
+        lr.reset_shape();
+        assert_eq!(written(&diff(&mut lr, &console, "a\nb")), "a\nb");
+    }
+
     // -- Vertical overflow enum variants ------------------------------------
 
     #[test]
@@ -3,10 +3,20 @@
 //! Port of Python's `rich/live.py`. Provides a `Live` struct that can display
 //! content that updates in-place using cursor movement control codes and an
 //! optional background refresh thread.
+//!
+//! With the `async` feature, the refresh loop can instead be driven by a
+//! Tokio task via [`Live::start_async`] or [`Live::run_async`].
+//!
+//! With the `resize` feature, a terminal resize wakes the refresh thread and
+//! the next frame is drawn at the new size.
 
+pub mod dashboard;
+pub mod live_group;
 pub mod live_render;
 pub mod screen;
 
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -14,7 +24,7 @@ use std::time::Duration;
 use crate::console::{Console, Renderable};
 use crate::control::Control;
 use crate::segment::Segment;
-use crate::text::Text;
+use crate::text::{OverflowMethod, Text};
 
 use self::live_render::{LiveRender, VerticalOverflowMethod};
 use self::screen::Screen;
@@ -30,12 +40,47 @@ struct SharedState {
     renderable: Text,
     get_renderable: Option<Box<dyn Fn() -> Text + Send>>,
     screen: bool,
+    region: Option<String>,
+    /// The console's settings generation when the last frame was drawn.
+    settings_generation: u64,
+    /// The resize count when the console last took on the terminal's size,
+    /// or `None` before the first frame.
+    #[cfg(feature = "resize")]
+    resize_generation: Option<u64>,
 }
 
 // ---------------------------------------------------------------------------
 // Helper
 // ---------------------------------------------------------------------------
 
+/// Render `renderable` at `width` columns into a [`Text`] that keeps its
+/// styles, so widgets other than `Text` can be shown by a [`Live`] display.
+///
+/// Lines are cropped rather than wrapped, so the result keeps its shape
+/// when the live display renders it at the same width.
+pub(crate) fn render_to_text(renderable: &dyn Renderable, width: usize) -> Text {
+    render_to_text_sticky(renderable, width).0
+}
+
+/// Like [`render_to_text`], also returning the number of leading lines the
+/// renderable asks to keep in view (see [`Renderable::render_sticky`]).
+fn render_to_text_sticky(renderable: &dyn Renderable, width: usize) -> (Text, usize) {
+    let console = Console::builder().width(width).build();
+    let (lines, sticky) = console.render_lines_sticky(renderable, None, None, false, false);
+    let mut text = Text::empty();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            text.append_str("\n", None);
+        }
+        for segment in line.iter().filter(|segment| segment.control.is_none()) {
+            text.append_str(&segment.text, segment.style.clone());
+        }
+    }
+    text.no_wrap = Some(true);
+    text.overflow = Some(OverflowMethod::Crop);
+    (text, sticky)
+}
+
 /// Emit control-bearing segments to a console.
 fn emit_control_segments(console: &mut Console, segments: &[Segment]) {
     for seg in segments {
@@ -78,6 +123,8 @@ pub struct Live {
     vertical_overflow: VerticalOverflowMethod,
     started: bool,
     refresh_thread: Option<thread::JoinHandle<()>>,
+    #[cfg(feature = "async")]
+    refresh_task: Option<tokio::task::JoinHandle<()>>,
     stop_flag: Arc<(Mutex<bool>, Condvar)>,
 }
 
@@ -100,6 +147,10 @@ impl Live {
             renderable,
             get_renderable: None,
             screen: false,
+            region: None,
+            settings_generation: 0,
+            #[cfg(feature = "resize")]
+            resize_generation: None,
         }));
 
         Live {
@@ -110,6 +161,8 @@ impl Live {
             vertical_overflow: VerticalOverflowMethod::Ellipsis,
             started: false,
             refresh_thread: None,
+            #[cfg(feature = "async")]
+            refresh_task: None,
             stop_flag: Arc::new((Mutex::new(false), Condvar::new())),
         }
     }
@@ -121,6 +174,7 @@ impl Live {
     pub fn with_console(self, console: Console) -> Self {
         {
             let mut s = self.state.lock().unwrap();
+            s.settings_generation = console.settings_generation();
             s.console = console;
         }
         self
@@ -163,6 +217,21 @@ impl Live {
         self
     }
 
+    /// Keep a named console region in sync with the latest frame (builder pattern).
+    ///
+    /// On every refresh the region's recorded contents are replaced with the
+    /// current renderable, so [`Console::export_region_text`] and
+    /// [`Console::export_region_html`] report the final state rather than
+    /// every intermediate frame.
+    #[must_use]
+    pub fn with_region(self, name: &str) -> Self {
+        {
+            let mut s = self.state.lock().unwrap();
+            s.region = Some(name.to_string());
+        }
+        self
+    }
+
     /// Set the vertical overflow method (builder pattern).
     #[must_use]
     pub fn with_vertical_overflow(mut self, overflow: VerticalOverflowMethod) -> Self {
@@ -219,17 +288,23 @@ impl Live {
         }
     }
 
+    /// Get a cloneable handle for updating this display from other threads
+    /// or tasks.
+    pub fn handle(&self) -> LiveHandle {
+        LiveHandle {
+            state: Arc::clone(&self.state),
+            vertical_overflow: self.vertical_overflow,
+        }
+    }
+
     // -- Lifecycle ----------------------------------------------------------
 
-    /// Start the live display.
-    ///
-    /// Hides the cursor, optionally enables the alternate screen, and spawns
-    /// the background refresh thread if `auto_refresh` is enabled.
-    ///
-    /// Calling `start` on an already-started display is a no-op.
-    pub fn start(&mut self) {
+    /// Mark the display as started and prepare the terminal (hide the
+    /// cursor, enter the alternate screen). Returns `false` if the display
+    /// was already running.
+    fn begin(&mut self) -> bool {
         if self.started {
-            return;
+            return false;
         }
         self.started = true;
 
@@ -239,12 +314,27 @@ impl Live {
             *stopped = false;
         }
 
-        {
-            let mut s = self.state.lock().unwrap();
-            s.console.show_cursor(false);
-            if s.screen {
-                s.console.set_alt_screen(true);
-            }
+        // A resize wakes the refresh thread early, as if its interval ended.
+        #[cfg(feature = "resize")]
+        crate::resize::subscribe(&self.stop_flag);
+
+        let mut s = self.state.lock().unwrap();
+        s.console.show_cursor(false);
+        if s.screen {
+            s.console.set_alt_screen(true);
+        }
+        true
+    }
+
+    /// Start the live display.
+    ///
+    /// Hides the cursor, optionally enables the alternate screen, and spawns
+    /// the background refresh thread if `auto_refresh` is enabled.
+    ///
+    /// Calling `start` on an already-started display is a no-op.
+    pub fn start(&mut self) {
+        if !self.begin() {
+            return;
         }
 
         if self.auto_refresh {
@@ -267,6 +357,83 @@ impl Live {
         }
     }
 
+    /// Start the live display with refreshes driven by a Tokio task instead
+    /// of a background thread.
+    ///
+    /// Behaves like [`start`](Live::start), but the auto-refresh loop is a
+    /// task spawned on the current Tokio runtime, so it never blocks a
+    /// runtime worker waiting on a thread join.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime while `auto_refresh` is
+    /// enabled.
+    #[cfg(feature = "async")]
+    pub fn start_async(&mut self) {
+        if !self.begin() {
+            return;
+        }
+
+        if self.auto_refresh {
+            let flag = Arc::clone(&self.stop_flag);
+            let state = Arc::clone(&self.state);
+            let vertical_overflow = self.vertical_overflow;
+            let period = Duration::from_secs_f64(1.0 / self.refresh_per_second);
+
+            self.refresh_task = Some(tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(period);
+                // The first tick completes immediately; skip it so the first
+                // refresh happens one period after start, like the thread.
+                ticker.tick().await;
+                loop {
+                    ticker.tick().await;
+                    if *flag.0.lock().unwrap() {
+                        break;
+                    }
+                    Self::do_refresh(&state, vertical_overflow);
+                }
+            }));
+        }
+    }
+
+    /// Run a future while the display is live, refreshing from a Tokio task.
+    ///
+    /// The display is started with [`start_async`](Live::start_async), the
+    /// closure receives a [`LiveHandle`] for pushing updates, and the display
+    /// is refreshed one final time and stopped once the future completes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use gilt::live::Live;
+    /// use gilt::text::Text;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut live = Live::new(Text::from("Working..."));
+    ///     let answer = live
+    ///         .run_async(|handle| async move {
+    ///             tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    ///             handle.update(Text::from("Done!"));
+    ///             42
+    ///         })
+    ///         .await;
+    ///     assert_eq!(answer, 42);
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn run_async<F, Fut>(&mut self, f: F) -> Fut::Output
+    where
+        F: FnOnce(LiveHandle) -> Fut,
+        Fut: Future,
+    {
+        self.start_async();
+        let output = f(self.handle()).await;
+        self.refresh();
+        self.stop();
+        output
+    }
+
     /// Stop the live display.
     ///
     /// Signals the refresh thread to exit and joins it, optionally erases the
@@ -292,6 +459,13 @@ impl Live {
             let _ = handle.join();
         }
 
+        // Cancel the async refresh task; it re-checks the stop flag before
+        // every refresh, so no frame is drawn after this point.
+        #[cfg(feature = "async")]
+        if let Some(task) = self.refresh_task.take() {
+            task.abort();
+        }
+
         let mut s = self.state.lock().unwrap();
 
         // In transient mode, erase the last render.
@@ -336,11 +510,30 @@ impl Live {
         s.live_render.set_renderable(renderable.clone());
         s.live_render.vertical_overflow = vertical_overflow;
 
+        // The terminal was resized since the last frame, so the console
+        // takes on its new size before laying the frame out.
+        #[cfg(feature = "resize")]
+        {
+            let resizes = crate::resize::generation();
+            if s.resize_generation != Some(resizes) {
+                s.resize_generation = Some(resizes);
+                s.console.sync_terminal_size();
+            }
+        }
+
+        // The console's width or colors changed since the last frame, so
+        // every line is redrawn rather than only those that render differently.
+        let generation = s.console.settings_generation();
+        if generation != s.settings_generation {
+            s.live_render.invalidate();
+            s.settings_generation = generation;
+        }
+
         if s.screen {
             // Screen mode: render through Screen which fills the whole alt-screen.
             let opts = s.console.options();
             let _render_segments = s.live_render.gilt_console(&s.console, &opts);
-            let screen = Screen::new(renderable);
+            let screen = Screen::new(renderable.clone());
             s.console.print(&screen);
         } else {
             // Normal mode: render through LiveRender and write segments directly.
@@ -350,14 +543,14 @@ impl Live {
             // output (N+1 lines), leaking 1 line per refresh frame.
             let opts = s.console.options();
 
-            // First render to compute shape (shape is stored in live_render)
-            let render_segments = s.live_render.gilt_console(&s.console, &opts);
-
-            // Now position cursor using the computed shape
-            let position_segments = s.live_render.position_cursor();
-            emit_control_segments(&mut s.console, &position_segments);
+            // Render the new frame and rewrite only the lines that differ
+            // from the previous one (this records its shape in live_render).
+            let segments = s.live_render.diff_segments(&s.console, &opts);
+            s.console.write_segments(&segments);
+        }
 
-            s.console.write_segments(&render_segments);
+        if let Some(name) = s.region.clone() {
+            s.console.replace_region(&name, &renderable);
         }
     }
 
@@ -368,6 +561,7 @@ impl Live {
         {
             let mut s = self.state.lock().unwrap();
             s.live_render.set_renderable(renderable.clone());
+            s.live_render.sticky_lines = 0;
             s.renderable = renderable;
         }
         if refresh {
@@ -375,6 +569,27 @@ impl Live {
         }
     }
 
+    /// Update the content from any renderable, keeping its sticky lines
+    /// pinned at the top when it is taller than the screen.
+    ///
+    /// For a [`Table`](crate::table::Table) the title and header stay in
+    /// view while the newest rows are shown beneath them, so a table that
+    /// keeps growing reads like a scrolling log under a fixed header.
+    ///
+    /// If `refresh` is `true`, the display is repainted immediately.
+    pub fn update_pinned(&mut self, renderable: &dyn Renderable, refresh: bool) {
+        {
+            let mut s = self.state.lock().unwrap();
+            let (text, sticky_lines) = render_to_text_sticky(renderable, s.console.width());
+            s.live_render.set_renderable(text.clone());
+            s.live_render.sticky_lines = sticky_lines;
+            s.renderable = text;
+        }
+        if refresh {
+            self.refresh();
+        }
+    }
+
     /// Alias for [`update_renderable`](Live::update_renderable).
     pub fn update(&mut self, renderable: Text, refresh: bool) {
         self.update_renderable(renderable, refresh);
@@ -385,6 +600,44 @@ impl Live {
         let s = self.state.lock().unwrap();
         s.renderable.clone()
     }
+
+    // -- Printing above the display -----------------------------------------
+
+    /// Print a renderable above the live display.
+    ///
+    /// While the display is running the last frame is erased, the renderable
+    /// is printed in its place as permanent output, and the live content is
+    /// redrawn beneath it. When the display is not running this is a plain
+    /// [`Console::print`].
+    pub fn print(&self, renderable: &dyn Renderable) {
+        self.write_above(|console| console.print(renderable));
+    }
+
+    /// Log a timestamped message above the live display.
+    ///
+    /// See [`print`](Live::print) and [`Console::log`].
+    #[track_caller]
+    pub fn log(&self, message: &str) {
+        let location = std::panic::Location::caller();
+        self.write_above(|console| console.log_at(message, location));
+    }
+
+    /// Erase the current frame, run `write`, then redraw the display below
+    /// whatever was written.
+    fn write_above(&self, write: impl FnOnce(&mut Console)) {
+        {
+            let mut s = self.state.lock().unwrap();
+            if !self.started || s.screen {
+                write(&mut s.console);
+                return;
+            }
+            let erase = s.live_render.position_cursor();
+            emit_control_segments(&mut s.console, &erase);
+            write(&mut s.console);
+            s.live_render.reset_shape();
+        }
+        self.refresh();
+    }
 }
 
 impl Drop for Live {
@@ -393,6 +646,47 @@ impl Drop for Live {
     }
 }
 
+// ---------------------------------------------------------------------------
+// LiveHandle
+// ---------------------------------------------------------------------------
+
+/// A cloneable, `Send` handle to a [`Live`] display's content.
+///
+/// Obtained from [`Live::handle`]. Updates made through the handle are
+/// picked up by the next refresh, whether it is driven by the refresh
+/// thread, a Tokio task, or an explicit [`refresh`](LiveHandle::refresh).
+#[derive(Clone)]
+pub struct LiveHandle {
+    state: Arc<Mutex<SharedState>>,
+    vertical_overflow: VerticalOverflowMethod,
+}
+
+impl LiveHandle {
+    /// Replace the displayed content without repainting immediately.
+    pub fn update(&self, renderable: Text) {
+        let mut s = self.state.lock().unwrap();
+        s.live_render.set_renderable(renderable.clone());
+        s.live_render.sticky_lines = 0;
+        s.renderable = renderable;
+    }
+
+    /// Repaint the display with the current content.
+    pub fn refresh(&self) {
+        Live::do_refresh(&self.state, self.vertical_overflow);
+    }
+
+    /// Get a clone of the current renderable.
+    pub fn renderable(&self) -> Text {
+        self.state.lock().unwrap().renderable.clone()
+    }
+}
+
+impl std::fmt::Debug for LiveHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveHandle").finish_non_exhaustive()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Smart references for accessing Console and LiveRender through the Mutex
 // ---------------------------------------------------------------------------
@@ -482,6 +776,115 @@ mod tests {
 
     // -- Builder methods ----------------------------------------------------
 
+    #[test]
+    fn test_print_above_running_display() {
+        let console = Console::builder()
+            .width(40)
+            .height(25)
+            .markup(false)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        let mut live = Live::new(Text::new("bar 50%\nbar 20%", Style::null()))
+            .with_console(console)
+            .with_auto_refresh(false);
+        live.console_mut().begin_capture();
+        live.start();
+        live.refresh();
+        live.print(&Text::new("step done", Style::null()));
+        let output = live.console_mut().end_capture();
+        live.stop();
+
+        let message = output.find("step done").unwrap();
+        // The old two-line frame is erased before the message is written.
+        assert!(output[..message].contains("\x1b[1A"));
+        let first_frame = output.find("bar 50%").unwrap();
+        let last_frame = output.rfind("bar 50%").unwrap();
+        assert!(first_frame < message && message < last_frame);
+        // The redraw after the message must not move the cursor back up
+        // over the printed line.
+        assert!(!output[message..].contains("\x1b[1A"));
+        assert!(live.live_render().last_render_height() > 0);
+    }
+
+    #[test]
+    fn test_refresh_rewrites_only_changed_lines() {
+        let console = Console::builder()
+            .width(40)
+            .height(25)
+            .markup(false)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        let mut live = Live::new(Text::new("header\nbar 50%", Style::null()))
+            .with_console(console)
+            .with_auto_refresh(false);
+        live.console_mut().begin_capture();
+        live.start();
+        live.refresh();
+        live.console_mut().end_capture();
+        live.console_mut().begin_capture();
+        live.update(Text::new("header\nbar 60%", Style::null()), true);
+        let output = live.console_mut().end_capture();
+        live.stop();
+
+        assert!(output.contains("bar 60%"));
+        assert!(!output.contains("header"));
+    }
+
+    #[test]
+    fn test_refresh_redraws_all_lines_after_console_setting_change() {
+        let console = Console::builder()
+            .width(40)
+            .height(25)
+            .markup(false)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        let mut live = Live::new(Text::new("header\nbar 50%", Style::null()))
+            .with_console(console)
+            .with_auto_refresh(false);
+        live.console_mut().begin_capture();
+        live.start();
+        live.refresh();
+        live.console_mut().end_capture();
+        live.console_mut().set_width(Some(30));
+        live.console_mut().begin_capture();
+        live.refresh();
+        let output = live.console_mut().end_capture();
+        live.stop();
+
+        assert!(output.contains("header"));
+        assert!(output.contains("bar 50%"));
+    }
+
+    #[test]
+    fn test_print_when_not_started_is_plain_print() {
+        let live = Live::new(Text::new("frame", Style::null())).with_console(
+            Console::builder()
+                .width(40)
+                .markup(false)
+                .no_color(true)
+                .build(),
+        );
+        live.console_mut().begin_capture();
+        live.print(&Text::new("hello", Style::null()));
+        let output = live.console_mut().end_capture();
+        assert_eq!(output, "hello\n");
+    }
+
+    #[test]
+    fn test_with_region_replaces_region_on_refresh() {
+        let mut live = Live::new(Text::new("step 1", Style::null()))
+            .with_console(test_console())
+            .with_auto_refresh(false)
+            .with_region("status");
+        live.refresh();
+        live.update(Text::new("step 2", Style::null()), true);
+        let exported = live.console().export_region_text("status", false).unwrap();
+        assert_eq!(exported, "step 2\n");
+    }
+
     #[test]
     fn test_with_auto_refresh() {
         let live = Live::new(Text::empty()).with_auto_refresh(false);
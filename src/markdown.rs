@@ -20,8 +20,8 @@ use crate::text::{JustifyMethod, Text};
 
 /// Renders Markdown-formatted text to styled terminal output.
 ///
-/// Supports headings, paragraphs, lists, code blocks, emphasis, links,
-/// block quotes, horizontal rules, and tables.
+/// Supports headings, paragraphs, lists, task lists, code blocks, emphasis,
+/// links, nested block quotes, horizontal rules, and tables.
 #[derive(Debug, Clone)]
 pub struct Markdown {
     /// Raw markdown source text.
@@ -32,7 +32,8 @@ pub struct Markdown {
     pub inline_code_lexer: Option<String>,
     /// Theme for inline code (reserved for future use).
     pub inline_code_theme: Option<String>,
-    /// Whether to display hyperlink URLs after link text.
+    /// Whether to show link targets: as OSC 8 hyperlinks on terminals that
+    /// support them, otherwise as the URL after the link text.
     pub hyperlinks: bool,
     /// Text justification method.
     pub justify: Option<JustifyMethod>,
@@ -58,7 +59,7 @@ impl Markdown {
         self
     }
 
-    /// Set whether hyperlink URLs are shown (builder pattern).
+    /// Set whether link targets are shown (builder pattern).
     #[must_use]
     pub fn with_hyperlinks(mut self, hyperlinks: bool) -> Self {
         self.hyperlinks = hyperlinks;
@@ -94,8 +95,8 @@ struct ListContext {
 struct TableContext {
     alignments: Vec<Alignment>,
     header_cells: Vec<String>,
-    current_row: Vec<String>,
-    rows: Vec<Vec<String>>,
+    current_row: Vec<Text>,
+    rows: Vec<Vec<Text>>,
     in_head: bool,
 }
 
@@ -130,8 +131,13 @@ impl Renderable for Markdown {
         // List stack for nested lists
         let mut list_stack: Vec<ListContext> = Vec::new();
 
-        // Block quote nesting depth
+        // Block quote nesting depth, and where each open quote's output
+        // starts so its lines can be prefixed with a bar when it closes
         let mut blockquote_depth: usize = 0;
+        let mut quote_starts: Vec<usize> = Vec::new();
+
+        // Checkbox state of the current task list item
+        let mut task_marker: Option<bool> = None;
 
         // Link URL tracking
         let mut link_url: Option<String> = None;
@@ -142,7 +148,6 @@ impl Renderable for Markdown {
         // Table context
         let mut table_ctx: Option<TableContext> = None;
         let mut in_table_cell = false;
-        let mut cell_text = String::new();
 
         // Track if we need a newline before the next block element
         let mut needs_newline = false;
@@ -151,11 +156,16 @@ impl Renderable for Markdown {
         let mut md_options = Options::empty();
         md_options.insert(Options::ENABLE_TABLES);
         md_options.insert(Options::ENABLE_STRIKETHROUGH);
+        md_options.insert(Options::ENABLE_TASKLISTS);
 
         let parser = Parser::new_ext(&self.markup, md_options);
         let events: Vec<Event> = parser.collect();
 
         for event in events {
+            // Blocks inside quotes lose two columns per level to the bars
+            let block_width = width.saturating_sub(blockquote_depth * 2);
+            let block_opts = options.update_width(block_width);
+
             match event {
                 // -- Headings -----------------------------------------------
                 Event::Start(Tag::Heading { .. }) => {
@@ -186,9 +196,7 @@ impl Renderable for Markdown {
                     text_buffer.end = String::new();
 
                     // Render heading text
-                    let heading_opts =
-                        options.update_width(width.saturating_sub(blockquote_depth * 4));
-                    let heading_segs = text_buffer.gilt_console(console, &heading_opts);
+                    let heading_segs = text_buffer.gilt_console(console, &block_opts);
                     segments.extend(heading_segs);
                     segments.push(Segment::line());
 
@@ -198,7 +206,7 @@ impl Renderable for Markdown {
                             .get_style("markdown.hr")
                             .unwrap_or_else(|_| Style::null());
                         let rule = Rule::new().with_style(rule_style).with_end("");
-                        let rule_segs = rule.gilt_console(console, options);
+                        let rule_segs = rule.gilt_console(console, &block_opts);
                         segments.extend(rule_segs);
                         segments.push(Segment::line());
                     }
@@ -216,9 +224,8 @@ impl Renderable for Markdown {
                 }
                 Event::End(TagEnd::Paragraph) => {
                     if in_table_cell {
-                        // Inside a table cell, append text to cell_text
-                        cell_text.push_str(text_buffer.plain());
-                        text_buffer = Text::new("", Style::null());
+                        // Inside a table cell the text stays in the buffer
+                        // until the cell ends
                         continue;
                     }
 
@@ -226,38 +233,8 @@ impl Renderable for Markdown {
                         segments.push(Segment::line());
                     }
 
-                    // Apply blockquote indentation
-                    let effective_width = width.saturating_sub(blockquote_depth * 4);
-                    let para_opts = options.update_width(effective_width);
-
-                    if blockquote_depth > 0 {
-                        let bq_style = console
-                            .get_style("markdown.block_quote")
-                            .unwrap_or_else(|_| Style::null());
-                        let indent: String =
-                            std::iter::repeat_n(' ', blockquote_depth.saturating_sub(1) * 4)
-                                .collect();
-                        let bq_prefix = format!("{}\u{2502} ", indent);
-
-                        // Let Text wrap normally then split into lines
-                        let text_segs = text_buffer.gilt_console(console, &para_opts);
-                        let rendered_text: String =
-                            text_segs.iter().map(|s| s.text.as_str()).collect();
-
-                        for line in rendered_text.lines() {
-                            segments.push(Segment::styled(&bq_prefix, bq_style.clone()));
-                            segments.push(Segment::text(line));
-                            segments.push(Segment::line());
-                        }
-                        // If the text was empty, still emit one quote line
-                        if rendered_text.trim().is_empty() {
-                            segments.push(Segment::styled(&bq_prefix, bq_style.clone()));
-                            segments.push(Segment::line());
-                        }
-                    } else {
-                        let text_segs = text_buffer.gilt_console(console, &para_opts);
-                        segments.extend(text_segs);
-                    }
+                    let text_segs = text_buffer.gilt_console(console, &block_opts);
+                    segments.extend(text_segs);
 
                     needs_newline = true;
                     text_buffer = Text::new("", Style::null());
@@ -306,38 +283,22 @@ impl Renderable for Markdown {
                     text_buffer.append_str(&text, Some(combined));
                 }
 
-                // -- Links --------------------------------------------------
-                Event::Start(Tag::Link { dest_url, .. }) => {
-                    let link_style = console
+                // -- Links and images ---------------------------------------
+                Event::Start(Tag::Link { dest_url, .. })
+                | Event::Start(Tag::Image { dest_url, .. }) => {
+                    let mut link_style = console
                         .get_style("markdown.link")
                         .unwrap_or_else(|_| Style::parse("bright_blue").unwrap());
-                    style_stack.push(link_style);
-                    link_url = Some(dest_url.to_string());
-                }
-                Event::End(TagEnd::Link) => {
-                    let _ = style_stack.pop();
-                    if self.hyperlinks {
-                        if let Some(ref url) = link_url {
-                            let url_style = console
-                                .get_style("markdown.link_url")
-                                .unwrap_or_else(|_| Style::parse("underline blue").unwrap());
-                            text_buffer.append_str(" (", None);
-                            text_buffer.append_str(url, Some(url_style));
-                            text_buffer.append_str(")", None);
-                        }
+                    if self.hyperlinks && console.supports_hyperlinks() {
+                        // Clickable text; no need to spell out the URL
+                        link_style = link_style + Style::with_link(&dest_url);
+                        link_url = None;
+                    } else {
+                        link_url = Some(dest_url.to_string());
                     }
-                    link_url = None;
-                }
-
-                // -- Images (treat like links with alt text) ----------------
-                Event::Start(Tag::Image { dest_url, .. }) => {
-                    let link_style = console
-                        .get_style("markdown.link")
-                        .unwrap_or_else(|_| Style::parse("bright_blue").unwrap());
                     style_stack.push(link_style);
-                    link_url = Some(dest_url.to_string());
                 }
-                Event::End(TagEnd::Image) => {
+                Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
                     let _ = style_stack.pop();
                     if self.hyperlinks {
                         if let Some(ref url) = link_url {
@@ -375,7 +336,7 @@ impl Renderable for Markdown {
                             .with_box_chars(&HEAVY)
                             .with_style(code_style)
                             .with_expand(true);
-                        let panel_segs = panel.gilt_console(console, options);
+                        let panel_segs = panel.gilt_console(console, &block_opts);
                         segments.extend(panel_segs);
 
                         needs_newline = true;
@@ -406,6 +367,10 @@ impl Renderable for Markdown {
 
                 Event::Start(Tag::Item) => {
                     text_buffer = Text::new("", Style::null());
+                    task_marker = None;
+                }
+                Event::TaskListMarker(checked) => {
+                    task_marker = Some(checked);
                 }
                 Event::End(TagEnd::Item) => {
                     if needs_newline && list_stack.len() <= 1 {
@@ -415,7 +380,21 @@ impl Renderable for Markdown {
                     let indent_level = list_stack.len().saturating_sub(1);
                     let indent: String = std::iter::repeat_n(' ', indent_level * 4).collect();
 
-                    if let Some(ctx) = list_stack.last_mut() {
+                    if let Some(checked) = task_marker.take() {
+                        let (glyph, style_name) = if checked {
+                            ('\u{2611}', "markdown.item.checked")
+                        } else {
+                            ('\u{2610}', "markdown.item.unchecked")
+                        };
+                        let box_style = console
+                            .get_style(style_name)
+                            .unwrap_or_else(|_| Style::null());
+                        let prefix = format!("{}{} ", indent, glyph);
+                        segments.push(Segment::styled(&prefix, box_style));
+                        if let Some(ctx) = list_stack.last_mut() {
+                            ctx.item_number += 1;
+                        }
+                    } else if let Some(ctx) = list_stack.last_mut() {
                         if ctx.ordered {
                             let num_style = console
                                 .get_style("markdown.item.number")
@@ -434,7 +413,7 @@ impl Renderable for Markdown {
 
                     // Render item text
                     let item_width =
-                        width.saturating_sub((list_stack.len().saturating_sub(1)) * 4 + 3);
+                        block_width.saturating_sub((list_stack.len().saturating_sub(1)) * 4 + 3);
                     let item_opts = options.update_width(item_width);
                     let item_segs = text_buffer.gilt_console(console, &item_opts);
                     segments.extend(item_segs);
@@ -445,10 +424,31 @@ impl Renderable for Markdown {
 
                 // -- Block quotes -------------------------------------------
                 Event::Start(Tag::BlockQuote(_kind)) => {
+                    if needs_newline {
+                        segments.push(Segment::line());
+                        needs_newline = false;
+                    }
                     blockquote_depth += 1;
+                    quote_starts.push(segments.len());
                 }
                 Event::End(TagEnd::BlockQuote(_kind)) => {
                     blockquote_depth = blockquote_depth.saturating_sub(1);
+                    let start = quote_starts.pop().unwrap_or(segments.len());
+                    let quoted = segments.split_off(start);
+                    let bq_style = console
+                        .get_style("markdown.block_quote")
+                        .unwrap_or_else(|_| Style::null());
+                    for (line, _) in Segment::split_lines_terminator(&quoted) {
+                        let bar = if line.is_empty() {
+                            "\u{2502}"
+                        } else {
+                            "\u{2502} "
+                        };
+                        segments.push(Segment::styled(bar, bq_style.clone()));
+                        segments.extend(line);
+                        segments.push(Segment::line());
+                    }
+                    needs_newline = true;
                 }
 
                 // -- Tables -------------------------------------------------
@@ -463,7 +463,7 @@ impl Renderable for Markdown {
                             segments.push(Segment::line());
                         }
 
-                        let table_segs = render_table(console, options, &ctx);
+                        let table_segs = render_table(console, &block_opts, &ctx);
                         segments.extend(table_segs);
                         needs_newline = true;
                     }
@@ -479,8 +479,11 @@ impl Renderable for Markdown {
                         // pulldown-cmark may not emit TableRow for the header,
                         // so save any accumulated cells as header_cells here.
                         if !ctx.current_row.is_empty() {
-                            ctx.header_cells = ctx.current_row.clone();
-                            ctx.current_row.clear();
+                            ctx.header_cells = ctx
+                                .current_row
+                                .drain(..)
+                                .map(|t| t.plain().to_string())
+                                .collect();
                         }
                         ctx.in_head = false;
                     }
@@ -493,9 +496,9 @@ impl Renderable for Markdown {
                 }
                 Event::End(TagEnd::TableRow) => {
                     if let Some(ref mut ctx) = table_ctx {
-                        let row = ctx.current_row.clone();
+                        let row = std::mem::take(&mut ctx.current_row);
                         if ctx.in_head {
-                            ctx.header_cells = row;
+                            ctx.header_cells = row.iter().map(|t| t.plain().to_string()).collect();
                         } else {
                             ctx.rows.push(row);
                         }
@@ -504,21 +507,14 @@ impl Renderable for Markdown {
 
                 Event::Start(Tag::TableCell) => {
                     in_table_cell = true;
-                    cell_text.clear();
                     text_buffer = Text::new("", Style::null());
                 }
                 Event::End(TagEnd::TableCell) => {
-                    // Flush any remaining text_buffer into cell_text
-                    let remaining = text_buffer.plain().to_string();
-                    if !remaining.is_empty() {
-                        cell_text.push_str(&remaining);
-                    }
+                    let cell = std::mem::replace(&mut text_buffer, Text::new("", Style::null()));
                     if let Some(ref mut ctx) = table_ctx {
-                        ctx.current_row.push(cell_text.clone());
+                        ctx.current_row.push(cell);
                     }
                     in_table_cell = false;
-                    cell_text.clear();
-                    text_buffer = Text::new("", Style::null());
                 }
 
                 // -- Horizontal rule ----------------------------------------
@@ -530,7 +526,7 @@ impl Renderable for Markdown {
                         .get_style("markdown.hr")
                         .unwrap_or_else(|_| Style::parse("dim").unwrap());
                     let rule = Rule::new().with_style(hr_style).with_end("");
-                    let rule_segs = rule.gilt_console(console, options);
+                    let rule_segs = rule.gilt_console(console, &block_opts);
                     segments.extend(rule_segs);
                     segments.push(Segment::line());
                     needs_newline = true;
@@ -544,12 +540,6 @@ impl Renderable for Markdown {
                         continue;
                     }
 
-                    // If inside a table cell, accumulate text
-                    if in_table_cell {
-                        cell_text.push_str(&text);
-                        continue;
-                    }
-
                     // Apply current style stack
                     let current_style = style_stack.current().clone();
                     if current_style.is_null() {
@@ -565,8 +555,6 @@ impl Renderable for Markdown {
                         if let Some(ref mut code_text) = code_block_text {
                             code_text.push('\n');
                         }
-                    } else if in_table_cell {
-                        cell_text.push(' ');
                     } else {
                         text_buffer.append_str(" ", None);
                     }
@@ -577,7 +565,7 @@ impl Renderable for Markdown {
                             code_text.push('\n');
                         }
                     } else if in_table_cell {
-                        cell_text.push(' ');
+                        text_buffer.append_str(" ", None);
                     } else {
                         text_buffer.append_str("\n", None);
                     }
@@ -633,8 +621,7 @@ fn render_table(console: &Console, options: &ConsoleOptions, ctx: &TableContext)
 
     // Add data rows
     for row in &ctx.rows {
-        let cells: Vec<&str> = row.iter().map(|s| s.as_str()).collect();
-        table.add_row(&cells);
+        table.add_row_text(row);
     }
 
     table.gilt_console(console, options)
@@ -910,6 +897,43 @@ mod tests {
         assert!(!output.contains("https://www.rust-lang.org"));
     }
 
+    #[test]
+    fn test_link_uses_osc8_on_capable_terminals() {
+        let console = Console::builder()
+            .width(80)
+            .force_terminal(true)
+            .color_system("truecolor")
+            .build();
+        let md = Markdown::new("[Rust](https://www.rust-lang.org)");
+        let segments = render_segments(&console, &md);
+        let link = segments
+            .iter()
+            .find(|s| s.text == "Rust")
+            .expect("link text rendered");
+        assert_eq!(
+            link.style.as_ref().and_then(|s| s.link()),
+            Some("https://www.rust-lang.org")
+        );
+        let plain: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert!(!plain.contains("(https://"));
+
+        let output = console.render_buffer(&segments);
+        assert!(output.contains("\x1b]8;;https://www.rust-lang.org\x1b\\"));
+    }
+
+    #[test]
+    fn test_link_falls_back_to_url_without_hyperlink_support() {
+        let console = make_console(80);
+        assert!(!console.supports_hyperlinks());
+        let md = Markdown::new("[Rust](https://www.rust-lang.org)");
+        let segments = render_segments(&console, &md);
+        assert!(segments
+            .iter()
+            .all(|s| s.style.as_ref().and_then(|s| s.link()).is_none()));
+        let output = render_markdown(&console, &md);
+        assert!(output.contains("Rust (https://www.rust-lang.org)"));
+    }
+
     // -- Unordered lists (bullets) ------------------------------------------
 
     #[test]
@@ -964,6 +988,43 @@ mod tests {
         assert!(output.contains('\u{2502}'));
     }
 
+    #[test]
+    fn test_nested_block_quote_draws_a_bar_per_level() {
+        let console = make_console(40);
+        let md = Markdown::new("> outer\n>\n> > inner\n>\n> - item");
+        let output = render_markdown(&console, &md);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "\u{2502} outer");
+        assert_eq!(lines[1], "\u{2502}");
+        assert_eq!(lines[2], "\u{2502} \u{2502} inner");
+        assert!(lines[4].starts_with("\u{2502} \u{2022} item"));
+    }
+
+    #[test]
+    fn test_block_quote_wraps_inside_the_bar() {
+        let console = make_console(20);
+        let md = Markdown::new("> one two three four five six");
+        let output = render_markdown(&console, &md);
+        for line in output.lines() {
+            assert!(line.starts_with("\u{2502} "), "unquoted line {:?}", line);
+            assert!(crate::cells::cell_len(line) <= 20);
+        }
+    }
+
+    // -- Task lists ---------------------------------------------------------
+
+    #[test]
+    fn test_task_list() {
+        let console = make_console(40);
+        let md = Markdown::new("- [x] done\n- [ ] todo\n- plain");
+        let output = render_markdown(&console, &md);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec!["\u{2611} done", "\u{2610} todo", "\u{2022} plain"]
+        );
+    }
+
     // -- Horizontal rules ---------------------------------------------------
 
     #[test]
@@ -1027,6 +1088,22 @@ mod tests {
         assert!(output.contains("Right"));
     }
 
+    #[test]
+    fn test_table_cells_keep_inline_styles() {
+        let console = Console::builder()
+            .width(40)
+            .force_terminal(true)
+            .color_system("truecolor")
+            .build();
+        let md = Markdown::new("| Name |\n|------|\n| **bold** |");
+        let segments = render_segments(&console, &md);
+        let bold = segments
+            .iter()
+            .find(|s| s.text.contains("bold"))
+            .expect("cell rendered");
+        assert!(bold.style.as_ref().and_then(|s| s.bold()).unwrap_or(false));
+    }
+
     // -- Renderable trait integration ---------------------------------------
 
     #[test]
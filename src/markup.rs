@@ -7,6 +7,7 @@ use std::fmt;
 use regex::Regex;
 use std::sync::LazyLock;
 
+use crate::emoji_replace::emoji_replace;
 use crate::error::MarkupError;
 use crate::style::Style;
 use crate::text::{Span, Text};
@@ -185,9 +186,83 @@ fn parse_tag_inner(inner: &str) -> Tag {
 ///
 /// Returns `MarkupError` if a closing tag does not match any open tag.
 pub fn render(markup: &str, style: Style) -> Result<Text, MarkupError> {
+    render_with_glyphs(markup, style, |_| None)
+}
+
+/// Render Rich markup into a styled `Text` object, prefixing the text of
+/// each tag for which `glyph` returns a value with that glyph and a space.
+///
+/// Used by accessible mode so that meanings conveyed by a theme style, such
+/// as `[error]`, are also visible without color.
+///
+/// # Errors
+///
+/// Returns `MarkupError` if a closing tag does not match any open tag.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::markup::render_with_glyphs;
+/// use gilt::style::Style;
+///
+/// let glyph = |tag: &str| (tag == "error").then_some("\u{2716}");
+/// let text = render_with_glyphs("[error]failed[/error]", Style::null(), glyph).unwrap();
+/// assert_eq!(text.plain(), "\u{2716} failed");
+/// ```
+pub fn render_with_glyphs<'a>(
+    markup: &str,
+    style: Style,
+    glyph: impl Fn(&str) -> Option<&'a str>,
+) -> Result<Text, MarkupError> {
+    render_markup(markup, style, None, glyph)
+}
+
+/// Render Rich markup into a styled `Text` object, replacing `:shortcode:`
+/// emoji in the text between tags.
+///
+/// `variant` (`"text"` or `"emoji"`) appends that variation selector to
+/// every emoji that does not pick one itself with `:name-text:` or
+/// `:name-emoji:`. Shortcodes inside tags, such as link URLs, are left alone.
+///
+/// # Errors
+///
+/// Returns `MarkupError` if a closing tag does not match any open tag.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::markup::render_with_emoji;
+/// use gilt::style::Style;
+///
+/// let text = render_with_emoji("[bold]Done[/bold] :heart:", Style::null(), None).unwrap();
+/// assert_eq!(text.plain(), "Done \u{2764}");
+/// ```
+pub fn render_with_emoji(
+    markup: &str,
+    style: Style,
+    variant: Option<&str>,
+) -> Result<Text, MarkupError> {
+    render_markup(markup, style, Some(variant), |_| None)
+}
+
+/// Shared implementation of the `render*` functions. `emoji` is `None` to
+/// leave shortcodes alone, or the default variant to replace them with.
+pub(crate) fn render_markup<'a>(
+    markup: &str,
+    style: Style,
+    emoji: Option<Option<&str>>,
+    glyph: impl Fn(&str) -> Option<&'a str>,
+) -> Result<Text, MarkupError> {
+    let replace_emoji = |plain: &str| -> String {
+        match emoji {
+            Some(variant) => emoji_replace(plain, variant).into_owned(),
+            None => plain.to_string(),
+        }
+    };
+
     // Fast path: no markup at all.
     if !markup.contains('[') {
-        return Ok(Text::new(markup, style));
+        return Ok(Text::new(&replace_emoji(markup), style));
     }
 
     let mut text = Text::new("", style);
@@ -199,7 +274,7 @@ pub fn render(markup: &str, style: Style) -> Result<Text, MarkupError> {
         if let Some(plain) = plain_text {
             // Replace escaped opening brackets with literal `[`.
             let unescaped = plain.replace("\\[", "[");
-            text.append_str(&unescaped, None);
+            text.append_str(&replace_emoji(&unescaped), None);
         } else if let Some(tag) = tag {
             if tag.name.starts_with('/') {
                 // Closing tag.
@@ -255,6 +330,9 @@ pub fn render(markup: &str, style: Style) -> Result<Text, MarkupError> {
                     parameters: tag.parameters.clone(),
                 };
                 let current_len = text.len();
+                if let Some(glyph) = glyph(&open_tag.name) {
+                    text.append_str(&format!("{} ", glyph), None);
+                }
                 style_stack.push((current_len, open_tag));
             }
         }
@@ -592,6 +670,16 @@ mod tests {
         assert_eq!(result.spans().len(), 1);
     }
 
+    #[test]
+    fn test_render_with_glyphs_prefixes_tag_text() {
+        let glyph = |tag: &str| (tag == "warning").then_some("!");
+        let result =
+            render_with_glyphs("disk [warning]low[/warning] ok", Style::null(), glyph).unwrap();
+        assert_eq!(result.plain(), "disk ! low ok");
+        // The glyph is part of the tag's span.
+        assert_eq!((result.spans()[0].start, result.spans()[0].end), (5, 10));
+    }
+
     #[test]
     fn test_parse_markup_escaped_tag() {
         let elements = parse_markup(r"\[bold]");
@@ -636,4 +724,19 @@ mod tests {
         assert_eq!(result.spans().len(), 1);
         assert_eq!(result.spans()[0].style.link(), Some("https://example.com"));
     }
+
+    #[test]
+    fn test_render_with_emoji_only_replaces_text() {
+        let text = render_with_emoji(
+            "[link=https://x.test/:heart:]:heart:[/link] :smiley-text:",
+            Style::null(),
+            Some("emoji"),
+        )
+        .unwrap();
+        assert_eq!(text.plain(), "\u{2764}\u{FE0F} \u{1F603}\u{FE0E}");
+        assert!(text.spans()[0].style.to_string().contains(":heart:"));
+
+        let plain = render("Done :heart:", Style::null()).unwrap();
+        assert_eq!(plain.plain(), "Done :heart:");
+    }
 }
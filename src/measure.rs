@@ -1,10 +1,19 @@
 //! Measurement module for tracking minimum and maximum rendering widths.
 //!
-//! Rust port of Python's `rich/measure.py`.
+//! Rust port of Python's `rich/measure.py`. The [`Measure`] trait is the
+//! counterpart of rich's `__rich_measure__` protocol: containers that size
+//! themselves to their content (`Panel::fit`, [`Align`], derive-generated
+//! renderables) ask their children how wide they need to be instead of
+//! guessing from character counts.
+//!
+//! [`Align`]: crate::align_widget::Align
 
 use std::fmt;
 use std::ops::Add;
 
+use crate::console::{Console, ConsoleOptions};
+use crate::text::Text;
+
 /// Stores the minimum and maximum widths (in cells) required to render an object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Measurement {
@@ -81,6 +90,124 @@ impl Add for Measurement {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Measure protocol
+// ---------------------------------------------------------------------------
+
+/// A renderable that can report the width it needs.
+///
+/// `minimum` is the narrowest width the content can be rendered at without
+/// loss (e.g. the longest word of a paragraph); `maximum` is the width at
+/// which it stops benefiting from more space (e.g. the longest line).
+/// Implementations should not exceed `options.max_width`.
+///
+/// Every built-in widget with a size of its own implements this trait, as
+/// do renderables generated by `#[derive(Renderable)]`.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::Console;
+/// use gilt::measure::Measure;
+/// use gilt::panel::Panel;
+/// use gilt::text::Text;
+///
+/// let console = Console::builder().width(80).build();
+/// let options = console.options();
+/// let panel = Panel::fit(Text::from("short\na longer line"));
+/// let m = Measure::measure(&panel, &console, &options);
+/// assert_eq!((m.minimum, m.maximum), (17, 17));
+/// ```
+pub trait Measure {
+    /// Measure the minimum and maximum widths needed to render `self`.
+    fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement;
+}
+
+impl<T: Measure + ?Sized> Measure for &T {
+    fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        (**self).measure(console, options)
+    }
+}
+
+impl<T: Measure + ?Sized> Measure for Box<T> {
+    fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        (**self).measure(console, options)
+    }
+}
+
+impl Measure for Text {
+    fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        Text::measure(self).clamp(None, Some(options.max_width))
+    }
+}
+
+impl Measure for str {
+    fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        Measure::measure(&Text::from(self), console, options)
+    }
+}
+
+/// Combined measurement of several renderables stacked vertically.
+///
+/// The result is wide enough for the widest of them: the largest minimum and
+/// the largest maximum, clamped to `options.max_width`. An empty slice
+/// measures `(1, 1)`.
+pub fn measure_renderables(
+    console: &Console,
+    options: &ConsoleOptions,
+    renderables: &[&dyn Measure],
+) -> Measurement {
+    if renderables.is_empty() {
+        return Measurement::new(1, 1);
+    }
+    renderables
+        .iter()
+        .map(|r| r.measure(console, options))
+        .fold(Measurement::new(0, 0), |acc, m| acc + m)
+        .clamp(None, Some(options.max_width))
+}
+
+/// Implement [`Measure`] by delegating to an inherent
+/// `measure(&self, &Console, &ConsoleOptions)` method.
+macro_rules! delegate_measure {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Measure for $ty {
+                fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+                    <$ty>::measure(self, console, options)
+                }
+            }
+        )*
+    };
+}
+
+delegate_measure!(
+    crate::bar::Bar,
+    crate::barchart::BarChart,
+    crate::canvas::Canvas,
+    crate::cards::StatCard,
+    crate::cards::StatRow,
+    crate::columns::Columns,
+    crate::constrain::Constrain,
+    crate::csv_table::CsvTable,
+    crate::diff::Diff,
+    crate::figlet::Figlet,
+    crate::group::Group,
+    crate::heatmap::Heatmap,
+    crate::image::Image,
+    crate::kv::KeyValue,
+    crate::padding::Padding,
+    crate::panel::Panel,
+    crate::plot::Plot,
+    crate::progress_bar::ProgressBar,
+    crate::rule::Rule,
+    crate::sparkline::Sparkline,
+    crate::table::Table,
+    crate::tree::Tree,
+    crate::utils::group::Group,
+    crate::utils::inspect::Inspect<'_>,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +285,90 @@ mod tests {
         let result = a + b;
         assert_eq!(result, Measurement::new(20, 50));
     }
+
+    // -- Measure protocol ---------------------------------------------------
+
+    fn console(width: usize) -> Console {
+        Console::builder().width(width).build()
+    }
+
+    #[test]
+    fn test_measure_text_clamped_to_max_width() {
+        let console = console(10);
+        let options = console.options();
+        let text = Text::from("tiny words in a rather long line");
+        let m = Measure::measure(&text, &console, &options);
+        assert_eq!(m, Measurement::new(6, 10));
+    }
+
+    #[test]
+    fn test_measure_through_reference_and_box() {
+        let console = console(80);
+        let options = console.options();
+        let text = Text::from("hello world");
+        let boxed: Box<dyn Measure> = Box::new(text.clone());
+        assert_eq!(
+            Measure::measure(&&text, &console, &options),
+            boxed.measure(&console, &options)
+        );
+    }
+
+    #[test]
+    fn test_measure_renderables_takes_widest() {
+        let console = console(80);
+        let options = console.options();
+        let a = Text::from("abc");
+        let b = Text::from("longer line");
+        let m = measure_renderables(&console, &options, &[&a, &b]);
+        assert_eq!(m, Measurement::new(6, 11));
+        assert_eq!(
+            measure_renderables(&console, &options, &[]),
+            Measurement::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn test_measure_fit_panel_includes_title() {
+        let console = console(80);
+        let options = console.options();
+        let panel = crate::panel::Panel::fit(Text::from("Hi")).with_title("A much longer title");
+        let m = Measure::measure(&panel, &console, &options);
+        // " A much longer title " (21) + 2 fill chars + 2 borders
+        assert_eq!(m, Measurement::new(25, 25));
+    }
+
+    #[test]
+    fn test_measure_matches_rendered_width() {
+        let console = console(80);
+        let options = console.options();
+        let panel = crate::panel::Panel::fit(Text::from("one\ntwo three"));
+        let m = Measure::measure(&panel, &console, &options);
+        let lines = console.render_lines(&panel, Some(&options), None, false, false);
+        let rendered = lines
+            .iter()
+            .map(|l| crate::segment::Segment::get_line_length(l))
+            .max()
+            .unwrap();
+        assert_eq!(m.maximum, rendered);
+    }
+
+    #[test]
+    fn test_measure_tree_uses_longest_word() {
+        let console = console(80);
+        let options = console.options();
+        let mut tree = crate::tree::Tree::new(Text::from("root"));
+        tree.add(Text::from("a child label"));
+        let m = Measure::measure(&tree, &console, &options);
+        assert_eq!(m, Measurement::new(9, 17));
+    }
+
+    #[test]
+    fn test_measure_align_uses_content_width() {
+        let console = console(40);
+        let options = console.options();
+        let panel = crate::panel::Panel::fit(Text::from("Hello"));
+        let align = crate::align_widget::Align::center(panel);
+        let m = Measure::measure(&align, &console, &options);
+        assert_eq!(m, Measurement::new(9, 40));
+    }
 }
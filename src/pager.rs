@@ -3,12 +3,36 @@
 //! This module provides the [`Pager`] struct, which pipes content through
 //! an external pager program (e.g., `less -r`). It mirrors the functionality
 //! of Python rich's `Pager` class.
+//!
+//! [`TablePager`] is an interactive viewer for a [`Table`] that lets the user
+//! re-sort rows by entering a column number.
+//!
+//! With the `input` feature, [`InteractivePager`] is a built-in pager with
+//! scrolling and search that runs on the alternate screen without an
+//! external program; [`Console::print_paged`] uses it when stdout is a
+//! terminal.
 
-use std::io::Write;
+use std::io::{self, BufRead, Write};
 use std::process::{Command, Stdio};
 
 use thiserror::Error;
 
+use crate::console::Console;
+#[cfg(feature = "input")]
+use crate::console::Renderable;
+#[cfg(feature = "input")]
+use crate::control::Control;
+#[cfg(feature = "input")]
+use crate::input::{Key, KeyReader, RawMode};
+use crate::markup::escape;
+#[cfg(feature = "input")]
+use crate::segment::Segment;
+#[cfg(feature = "input")]
+use crate::style::Style;
+use crate::table::Table;
+#[cfg(feature = "input")]
+use crate::text::Text;
+
 /// Errors that can occur during pager operations.
 #[derive(Error, Debug)]
 pub enum PagerError {
@@ -126,6 +150,462 @@ impl Pager {
     }
 }
 
+// ---------------------------------------------------------------------------
+// TablePager
+// ---------------------------------------------------------------------------
+
+/// Result of handling one command in a [`TablePager`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TablePagerAction {
+    /// The table was re-sorted and should be redrawn.
+    Redraw,
+    /// The user asked to leave the pager.
+    Quit,
+    /// The command was not understood; the message explains why.
+    Invalid(String),
+}
+
+/// An interactive viewer for a [`Table`] that re-sorts rows on request.
+///
+/// The table is drawn, followed by a command line. Entering a column number
+/// (1-based) sorts by that column, string/numeric aware; entering the same
+/// number again flips the direction. The sorted column shows a `▲`/`▼`
+/// indicator in its header. Enter `q` (or end the input) to leave.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::Console;
+/// use gilt::pager::TablePager;
+/// use gilt::table::Table;
+///
+/// let mut table = Table::new(&["Name", "Size"]);
+/// table.add_row(&["b.txt", "20"]);
+/// table.add_row(&["a.txt", "100"]);
+///
+/// let mut console = Console::builder().width(40).build();
+/// let mut pager = TablePager::new(table);
+/// console.begin_capture();
+/// pager.run_with_input(&mut console, &mut "2\nq\n".as_bytes()).unwrap();
+/// console.end_capture();
+/// assert_eq!(pager.table().columns[0].cells[0], "b.txt");
+/// ```
+pub struct TablePager {
+    table: Table,
+}
+
+impl TablePager {
+    /// Creates a pager for the given table.
+    pub fn new(table: Table) -> Self {
+        TablePager { table }
+    }
+
+    /// Returns the table in its current sort order.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Consumes the pager, returning the (possibly re-sorted) table.
+    pub fn into_table(self) -> Table {
+        self.table
+    }
+
+    /// Formats the command line shown below the table.
+    pub fn format_command_prompt(&self) -> String {
+        format!(
+            "Sort by column [1-{}], q to quit: ",
+            self.table.columns.len()
+        )
+    }
+
+    /// Applies a single command line to the pager.
+    pub fn handle_input(&mut self, input: &str) -> TablePagerAction {
+        let trimmed = input.trim();
+        if trimmed.eq_ignore_ascii_case("q") || trimmed.eq_ignore_ascii_case("quit") {
+            return TablePagerAction::Quit;
+        }
+        let columns = self.table.columns.len();
+        match trimmed.parse::<usize>() {
+            Ok(n) if n >= 1 && n <= columns => {
+                self.table.toggle_sort(n - 1);
+                TablePagerAction::Redraw
+            }
+            Ok(_) => TablePagerAction::Invalid(format!(
+                "Please enter a column number between 1 and {}",
+                columns
+            )),
+            Err(_) => TablePagerAction::Invalid(format!("'{}' is not a column number", trimmed)),
+        }
+    }
+
+    /// Runs the pager interactively, reading commands from standard input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PagerError::Io`] if reading from standard input fails.
+    pub fn run(&mut self, console: &mut Console) -> Result<(), PagerError> {
+        let stdin = io::stdin();
+        let mut handle = stdin.lock();
+        self.run_with_input(console, &mut handle)
+    }
+
+    /// Testable version of [`run`](Self::run) that reads commands from the
+    /// provided input source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PagerError::Io`] if reading from `input` fails.
+    pub fn run_with_input<R: BufRead>(
+        &mut self,
+        console: &mut Console,
+        input: &mut R,
+    ) -> Result<(), PagerError> {
+        let mut redraw = true;
+        loop {
+            if redraw {
+                console.clear();
+                console.print(&self.table);
+            }
+            console.print_text(&format!(
+                "[dim]{}[/dim]",
+                escape(&self.format_command_prompt())
+            ));
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+
+            match self.handle_input(&line) {
+                TablePagerAction::Redraw => redraw = true,
+                TablePagerAction::Quit => return Ok(()),
+                TablePagerAction::Invalid(message) => {
+                    console.print_text(&format!("[prompt.invalid]{}[/]", escape(&message)));
+                    redraw = false;
+                }
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// InteractivePager
+// ---------------------------------------------------------------------------
+
+/// Result of handling one key in an [`InteractivePager`].
+#[cfg(feature = "input")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagerAction {
+    /// The view changed or may have changed and should be redrawn.
+    Redraw,
+    /// The user asked to leave the pager.
+    Quit,
+}
+
+/// A built-in pager that scrolls rendered content on the alternate screen.
+///
+/// The content is rendered once at the console width. One screen of lines is
+/// shown above a status line. Leading lines the renderable marks as sticky
+/// (see [`Renderable::render_sticky`]), such as a table's header, stay pinned
+/// at the top while the rest scrolls beneath them:
+///
+/// | Key | Action |
+/// |-----|--------|
+/// | `Space`, `f`, `PageDown` | Next page |
+/// | `b`, `PageUp` | Previous page |
+/// | `Down`, `j`, `Enter` | Next line |
+/// | `Up`, `k` | Previous line |
+/// | `g`, `Home` / `G`, `End` | First / last page |
+/// | `/` | Search (case-insensitive); matches are highlighted |
+/// | `n` / `N` | Next / previous line with a match |
+/// | `q`, `Esc`, `Ctrl+C` | Quit |
+///
+/// Requires the `input` feature.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::Console;
+/// use gilt::input::Key;
+/// use gilt::pager::InteractivePager;
+/// use gilt::text::Text;
+/// use gilt::style::Style;
+///
+/// let content: Vec<String> = (1..=50).map(|n| format!("line {}", n)).collect();
+/// let text = Text::new(&content.join("\n"), Style::null());
+///
+/// let mut console = Console::builder().width(40).height(11).build();
+/// let mut pager = InteractivePager::new(&console, &text);
+/// let keys = "/line 42\n".chars().map(|c| match c {
+///     '\n' => Key::Enter,
+///     c => Key::Char(c),
+/// });
+/// console.begin_capture();
+/// pager.run_with_keys(&mut console, keys);
+/// console.end_capture();
+/// assert_eq!(pager.top(), 40);
+/// ```
+#[cfg(feature = "input")]
+pub struct InteractivePager {
+    lines: Vec<Text>,
+    height: usize,
+    sticky: usize,
+    top: usize,
+    search: Option<String>,
+    typing: Option<String>,
+    message: Option<String>,
+    match_style: Style,
+    status_style: Style,
+}
+
+#[cfg(feature = "input")]
+impl InteractivePager {
+    /// Renders `renderable` at the console's width into a pager that fills
+    /// the console's height.
+    pub fn new(console: &Console, renderable: &dyn Renderable) -> Self {
+        let height = console.height().saturating_sub(1).max(1);
+        let (lines, sticky) = console.render_lines_sticky(renderable, None, None, false, false);
+        let lines: Vec<Text> = lines
+            .into_iter()
+            .map(|line| {
+                let mut text = Text::empty();
+                for segment in line.iter().filter(|s| s.control.is_none()) {
+                    text.append_str(&segment.text, segment.style.clone());
+                }
+                text
+            })
+            .collect();
+        // Pin the sticky lines only if a line of content still fits below.
+        let sticky = Some(sticky)
+            .filter(|&sticky| sticky < height)
+            .unwrap_or(0)
+            .min(lines.len());
+        InteractivePager {
+            sticky,
+            lines,
+            height,
+            top: sticky,
+            search: None,
+            typing: None,
+            message: None,
+            match_style: Style::parse("black on yellow").unwrap_or_else(|_| Style::null()),
+            status_style: Style::parse("reverse").unwrap_or_else(|_| Style::null()),
+        }
+    }
+
+    /// Sets the style of search matches (builder pattern).
+    #[must_use]
+    pub fn with_match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Returns the index of the first line on screen below the sticky lines.
+    pub fn top(&self) -> usize {
+        self.top
+    }
+
+    /// Returns the number of lines pinned at the top of the screen.
+    pub fn sticky_lines(&self) -> usize {
+        self.sticky
+    }
+
+    /// Returns the number of content lines.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the active search query, if any.
+    pub fn search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    /// Number of scrolling lines on screen.
+    fn page(&self) -> usize {
+        self.height - self.sticky
+    }
+
+    fn max_top(&self) -> usize {
+        self.lines
+            .len()
+            .saturating_sub(self.page())
+            .max(self.sticky)
+    }
+
+    fn scroll_to(&mut self, top: usize) {
+        self.top = top.clamp(self.sticky, self.max_top());
+    }
+
+    fn line_matches(&self, index: usize, query: &str) -> bool {
+        !find_matches(self.lines[index].plain(), query).is_empty()
+    }
+
+    /// Moves to the next (or previous) line containing the search query.
+    fn jump(&mut self, from: usize, forward: bool) {
+        let Some(query) = self.search.clone() else {
+            return;
+        };
+        let found = if forward {
+            (from..self.lines.len()).find(|&i| self.line_matches(i, &query))
+        } else {
+            (0..from.min(self.lines.len()))
+                .rev()
+                .find(|&i| self.line_matches(i, &query))
+        };
+        match found {
+            Some(index) => self.scroll_to(index),
+            None => self.message = Some("Pattern not found".to_string()),
+        }
+    }
+
+    /// Applies a single key press to the pager.
+    pub fn handle_key(&mut self, key: &Key) -> PagerAction {
+        self.message = None;
+        if let Some(mut query) = self.typing.take() {
+            match key {
+                Key::Enter if !query.is_empty() => {
+                    self.search = Some(query);
+                    self.jump(self.top, true);
+                }
+                Key::Char(c) => {
+                    query.push(*c);
+                    self.typing = Some(query);
+                }
+                Key::Backspace if !query.is_empty() => {
+                    query.pop();
+                    self.typing = Some(query);
+                }
+                _ => {}
+            }
+            return PagerAction::Redraw;
+        }
+
+        let page = self.page();
+        match key {
+            Key::Char('q') | Key::Escape | Key::Ctrl('c') => return PagerAction::Quit,
+            Key::Char(' ') | Key::Char('f') | Key::PageDown => self.scroll_to(self.top + page),
+            Key::Char('b') | Key::PageUp => self.scroll_to(self.top.saturating_sub(page)),
+            Key::Down | Key::Enter | Key::Char('j') => self.scroll_to(self.top + 1),
+            Key::Up | Key::Char('k') => self.scroll_to(self.top.saturating_sub(1)),
+            Key::Home | Key::Char('g') => self.scroll_to(0),
+            Key::End | Key::Char('G') => self.scroll_to(self.max_top()),
+            Key::Char('/') => self.typing = Some(String::new()),
+            Key::Char('n') => self.jump(self.top + 1, true),
+            Key::Char('N') => self.jump(self.top, false),
+            _ => {}
+        }
+        PagerAction::Redraw
+    }
+
+    /// Returns the lines currently on screen, with search matches
+    /// highlighted, followed by the status line.
+    pub fn view(&self) -> Vec<Text> {
+        let end = (self.top + self.page()).min(self.lines.len());
+        let mut view: Vec<Text> = self.lines[..self.sticky]
+            .iter()
+            .chain(&self.lines[self.top..end])
+            .map(|line| {
+                let mut line = line.clone();
+                if let Some(ref query) = self.search {
+                    for (start, end) in find_matches(line.plain(), query) {
+                        line.stylize(self.match_style.clone(), start, Some(end));
+                    }
+                }
+                line
+            })
+            .collect();
+
+        let status = if let Some(ref query) = self.typing {
+            Text::new(&format!("/{}", query), Style::null())
+        } else {
+            let position = if self.lines.is_empty() {
+                "(empty)".to_string()
+            } else {
+                format!("lines {}-{} of {}", self.top + 1, end, self.lines.len())
+            };
+            let hint = self
+                .message
+                .clone()
+                .unwrap_or_else(|| "q to quit, / to search".to_string());
+            Text::styled(
+                &format!(" {} ({}) ", position, hint),
+                self.status_style.clone(),
+            )
+        };
+        view.push(status);
+        view
+    }
+
+    /// Draws the current view over the whole alternate screen.
+    fn draw(&self, console: &mut Console) {
+        let mut segments = vec![Control::begin_sync().segment, Control::clear().segment];
+        let view = self.view();
+        let last = view.len() - 1;
+        // Leave blank rows so the status line sits at the bottom.
+        for (index, mut line) in view.into_iter().enumerate() {
+            if index == last {
+                for _ in index..self.height {
+                    segments.push(Segment::line());
+                }
+                line.end = String::new();
+            } else {
+                line.end = "\n".to_string();
+            }
+            segments.extend(line.render());
+        }
+        segments.push(Control::end_sync().segment);
+        console.write_segments(&segments);
+    }
+
+    /// Runs the pager on the alternate screen, reading keys from the
+    /// terminal in raw mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PagerError::Io`] if the terminal cannot be put in raw mode.
+    pub fn run(&mut self, console: &mut Console) -> Result<(), PagerError> {
+        let _raw = RawMode::enable().map_err(|e| PagerError::Io(io::Error::other(e)))?;
+        self.run_with_keys(console, KeyReader::stdin());
+        Ok(())
+    }
+
+    /// Testable version of [`run`](Self::run) that reads keys from the
+    /// provided source. Returns when the user quits or the keys run out.
+    pub fn run_with_keys(&mut self, console: &mut Console, keys: impl IntoIterator<Item = Key>) {
+        console.enter_screen(true);
+        self.draw(console);
+        for key in keys {
+            if self.handle_key(&key) == PagerAction::Quit {
+                break;
+            }
+            self.draw(console);
+        }
+        console.exit_screen(true);
+    }
+}
+
+/// Character ranges of case-insensitive occurrences of `query` in `plain`.
+#[cfg(feature = "input")]
+fn find_matches(plain: &str, query: &str) -> Vec<(usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let haystack: Vec<char> = plain.chars().map(fold).collect();
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return Vec::new();
+    }
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        if haystack[start..start + needle.len()] == needle[..] {
+            matches.push((start, start + needle.len()));
+            start += needle.len();
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +764,203 @@ mod tests {
         pager.command = "more".to_string();
         assert_eq!(pager.command, "more");
     }
+
+    // -- TablePager --------------------------------------------------------
+
+    fn sample_table() -> Table {
+        let mut table = Table::new(&["Name", "Size"]);
+        table.add_row(&["beta", "20"]);
+        table.add_row(&["alpha", "100"]);
+        table.add_row(&["gamma", "3"]);
+        table
+    }
+
+    fn names(pager: &TablePager) -> Vec<String> {
+        pager.table().columns[0]
+            .cells
+            .iter()
+            .map(|c| match c {
+                crate::table::CellContent::Plain(s) => s.clone(),
+                crate::table::CellContent::Styled(t) => t.plain().to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_table_pager_sort_by_number() {
+        let mut pager = TablePager::new(sample_table());
+        assert_eq!(pager.handle_input("2\n"), TablePagerAction::Redraw);
+        assert_eq!(names(&pager), vec!["gamma", "beta", "alpha"]);
+    }
+
+    #[test]
+    fn test_table_pager_toggle_direction() {
+        let mut pager = TablePager::new(sample_table());
+        pager.handle_input("1");
+        pager.handle_input("1");
+        assert_eq!(names(&pager), vec!["gamma", "beta", "alpha"]);
+        assert_eq!(
+            pager.table().sorted_by,
+            Some((0, crate::table::SortOrder::Descending))
+        );
+    }
+
+    #[test]
+    fn test_table_pager_invalid_input() {
+        let mut pager = TablePager::new(sample_table());
+        assert!(matches!(
+            pager.handle_input("9"),
+            TablePagerAction::Invalid(_)
+        ));
+        assert!(matches!(
+            pager.handle_input("x"),
+            TablePagerAction::Invalid(_)
+        ));
+        assert_eq!(pager.handle_input("q"), TablePagerAction::Quit);
+    }
+
+    #[test]
+    fn test_table_pager_run_with_input() {
+        let mut console = Console::builder().width(40).build();
+        let mut pager = TablePager::new(sample_table());
+        console.begin_capture();
+        pager
+            .run_with_input(&mut console, &mut "1\nbogus\nq\n".as_bytes())
+            .unwrap();
+        let output = console.end_capture();
+        assert!(output.contains("Name ▲"));
+        assert!(output.contains("not a column number"));
+        assert_eq!(names(&pager), vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_table_pager_eof_exits() {
+        let mut console = Console::builder().width(40).build();
+        let mut pager = TablePager::new(sample_table());
+        console.begin_capture();
+        assert!(pager
+            .run_with_input(&mut console, &mut "".as_bytes())
+            .is_ok());
+        console.end_capture();
+    }
+
+    // -- InteractivePager --------------------------------------------------
+
+    #[cfg(feature = "input")]
+    fn numbered_pager(lines: usize, height: usize) -> (Console, InteractivePager) {
+        let content: Vec<String> = (1..=lines).map(|n| format!("line {}", n)).collect();
+        let text = crate::text::Text::new(&content.join("\n"), Style::null());
+        let console = Console::builder().width(30).height(height).build();
+        let pager = InteractivePager::new(&console, &text);
+        (console, pager)
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_scrolling_is_clamped() {
+        let (_, mut pager) = numbered_pager(20, 6);
+        assert_eq!(pager.line_count(), 20);
+        pager.handle_key(&Key::Char(' '));
+        assert_eq!(pager.top(), 5);
+        pager.handle_key(&Key::Down);
+        assert_eq!(pager.top(), 6);
+        pager.handle_key(&Key::End);
+        assert_eq!(pager.top(), 15);
+        pager.handle_key(&Key::PageDown);
+        assert_eq!(pager.top(), 15);
+        pager.handle_key(&Key::Char('g'));
+        pager.handle_key(&Key::Up);
+        assert_eq!(pager.top(), 0);
+        assert_eq!(pager.handle_key(&Key::Char('q')), PagerAction::Quit);
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_search_and_highlight() {
+        let (_, mut pager) = numbered_pager(30, 6);
+        for key in [Key::Char('/'), Key::Char('2'), Key::Enter] {
+            pager.handle_key(&key);
+        }
+        assert_eq!(pager.search(), Some("2"));
+        assert_eq!(pager.top(), 1);
+        pager.handle_key(&Key::Char('n'));
+        assert_eq!(pager.top(), 11);
+        let view = pager.view();
+        assert_eq!(view[0].plain(), "line 12");
+        assert!(view[0]
+            .spans()
+            .iter()
+            .any(|span| span.start == 6 && span.end == 7));
+
+        // `N` goes back to the previous line containing "2".
+        pager.handle_key(&Key::Char('N'));
+        assert_eq!(pager.top(), 1);
+        pager.handle_key(&Key::Char('N'));
+        assert_eq!(pager.top(), 1);
+        assert!(pager
+            .view()
+            .last()
+            .unwrap()
+            .plain()
+            .contains("Pattern not found"));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_escape_cancels_search_input() {
+        let (_, mut pager) = numbered_pager(10, 6);
+        pager.handle_key(&Key::Char('/'));
+        pager.handle_key(&Key::Char('x'));
+        assert_eq!(pager.view().last().unwrap().plain(), "/x");
+        assert_eq!(pager.handle_key(&Key::Escape), PagerAction::Redraw);
+        assert_eq!(pager.search(), None);
+        assert_eq!(pager.handle_key(&Key::Escape), PagerAction::Quit);
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_pins_table_header() {
+        let mut table = Table::new(&["Row"]);
+        for n in 1..=30 {
+            table.add_row(&[&format!("row {}", n)]);
+        }
+        let console = Console::builder().width(30).height(8).build();
+        let mut pager = InteractivePager::new(&console, &table);
+        // Top edge, header and header separator.
+        assert_eq!(pager.sticky_lines(), 3);
+        assert_eq!(pager.top(), 3);
+        pager.handle_key(&Key::PageDown);
+        let view = pager.view();
+        assert!(view[1].plain().contains("Row"));
+        assert!(view[3].plain().contains("row 5"));
+        assert!(view[7].plain().contains("lines 8-11 of 34"));
+        pager.handle_key(&Key::Home);
+        assert_eq!(pager.top(), 3);
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_pins_shadowed_table_header() {
+        let mut table = Table::new(&["Row"]).with_shadow(true);
+        for n in 1..=30 {
+            table.add_row(&[&format!("row {}", n)]);
+        }
+        let console = Console::builder().width(30).height(8).build();
+        let pager = InteractivePager::new(&console, &table);
+        assert_eq!(pager.sticky_lines(), 3);
+        assert!(pager.view()[1].plain().contains("Row"));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_interactive_pager_run_uses_alt_screen() {
+        let (mut console, mut pager) = numbered_pager(20, 6);
+        console.begin_capture();
+        pager.run_with_keys(&mut console, [Key::Char(' '), Key::Char('q')]);
+        let output = console.end_capture();
+        assert!(output.contains("\x1b[?1049h"));
+        assert!(output.contains("line 10"));
+        assert!(output.contains("lines 6-10 of 20"));
+        assert!(output.ends_with("\x1b[?1049l"));
+    }
 }
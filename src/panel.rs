@@ -2,16 +2,141 @@
 //!
 //! Port of Python's `rich/panel.py`.
 
+use std::sync::Arc;
+
 use crate::align_widget::HorizontalAlign;
-use crate::box_chars::{BoxChars, ROUNDED};
+use crate::box_chars::{BoxChars, RowLevel, ROUNDED};
+use crate::cells::cell_len;
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::highlighter::Highlighter;
+use crate::live::live_render::VerticalOverflowMethod;
 use crate::measure::Measurement;
 use crate::padding::PaddingDimensions;
 use crate::segment::Segment;
+use crate::shadow::{render_with_shadow, shadow_style};
 use crate::style::Style;
 use crate::text::Text;
 
+// ---------------------------------------------------------------------------
+// PanelRenderable
+// ---------------------------------------------------------------------------
+
+/// Something a panel draws: its title or subtitle, or a
+/// [section](Panel::sections) of its interior.
+///
+/// Converting from [`Text`] (or a string) keeps the text as is, so titles are
+/// flattened onto one line and truncated as before. Any other renderable,
+/// such as a [`Spinner`](crate::spinner::Spinner) or a
+/// [`Table`](crate::table::Table), is wrapped with [`PanelRenderable::new`]
+/// and rendered afresh each time the panel is; as a title or subtitle only
+/// its first line is drawn.
+#[derive(Clone)]
+pub struct PanelRenderable(Item);
+
+#[derive(Clone)]
+enum Item {
+    Text(Box<Text>),
+    Renderable(Arc<dyn Renderable + Send + Sync>),
+}
+
+impl PanelRenderable {
+    /// Wrap a renderable for use in a panel.
+    pub fn new<R: Renderable + Send + Sync + 'static>(renderable: R) -> Self {
+        PanelRenderable(Item::Renderable(Arc::new(renderable)))
+    }
+
+    /// The text, if this was created from [`Text`] or a string.
+    pub fn as_text(&self) -> Option<&Text> {
+        match &self.0 {
+            Item::Text(text) => Some(&**text),
+            Item::Renderable(_) => None,
+        }
+    }
+
+    fn renderable(&self) -> &dyn Renderable {
+        match &self.0 {
+            Item::Text(text) => &**text,
+            Item::Renderable(renderable) => &**renderable,
+        }
+    }
+
+    /// Render into lines exactly `width` cells wide.
+    fn render_lines(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+        width: usize,
+        style: &Style,
+    ) -> Vec<Vec<Segment>> {
+        let options = options.update_width(width).reset_height();
+        console.render_lines(self.renderable(), Some(&options), Some(style), true, false)
+    }
+
+    /// Width of the widest rendered line, ignoring trailing whitespace.
+    fn content_width(&self, console: &Console, options: &ConsoleOptions) -> usize {
+        let options = options.reset_height();
+        console
+            .render_lines(self.renderable(), Some(&options), None, false, false)
+            .iter()
+            .map(|line| {
+                let text: String = line.iter().map(|s| s.text.as_str()).collect();
+                cell_len(text.trim_end())
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The text to draw in a border: text as is, or the first line of any
+    /// other renderable.
+    fn to_text(&self, console: &Console, options: &ConsoleOptions) -> Text {
+        let renderable = match &self.0 {
+            Item::Text(text) => return (**text).clone(),
+            Item::Renderable(renderable) => renderable,
+        };
+        let mut options = options.clone();
+        options.no_wrap = true;
+        let mut text = Text::empty();
+        for segment in renderable.gilt_console(console, &options) {
+            if segment.control.is_some() {
+                continue;
+            }
+            let (line, rest) = match segment.text.split_once('\n') {
+                Some((line, _)) => (line, true),
+                None => (segment.text.as_str(), false),
+            };
+            text.append_str(line, segment.style.clone());
+            if rest {
+                break;
+            }
+        }
+        text
+    }
+}
+
+impl std::fmt::Debug for PanelRenderable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PanelRenderable(..)")
+    }
+}
+
+impl From<Text> for PanelRenderable {
+    fn from(text: Text) -> Self {
+        PanelRenderable(Item::Text(Box::new(text)))
+    }
+}
+
+impl From<&str> for PanelRenderable {
+    fn from(text: &str) -> Self {
+        PanelRenderable::from(Text::from(text))
+    }
+}
+
+impl From<String> for PanelRenderable {
+    fn from(text: String) -> Self {
+        PanelRenderable::from(Text::from(text))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Panel
 // ---------------------------------------------------------------------------
@@ -42,32 +167,46 @@ use crate::text::Text;
 /// ```
 #[derive(Debug, Clone)]
 pub struct Panel {
-    /// The inner content.
-    pub content: Text,
+    /// The inner content: one entry per section, with a horizontal rule
+    /// drawn between consecutive sections.
+    pub content: Vec<PanelRenderable>,
     /// Box-drawing character set (reference to one of the 19 static constants).
     pub box_chars: &'static BoxChars,
     /// Optional title rendered in the top border.
-    pub title: Option<Text>,
+    pub title: Option<PanelRenderable>,
     /// Alignment of the title within the top border.
     pub title_align: HorizontalAlign,
     /// Optional subtitle rendered in the bottom border.
-    pub subtitle: Option<Text>,
+    pub subtitle: Option<PanelRenderable>,
     /// Alignment of the subtitle within the bottom border.
     pub subtitle_align: HorizontalAlign,
     /// If true, expand to fill available width.
     pub expand: bool,
     /// Style applied to the content area.
     pub style: Style,
+    /// Theme style name for the content area, looked up when the panel is
+    /// rendered; takes precedence over [`style`](Self::style) when it
+    /// resolves.
+    pub style_name: Option<String>,
     /// Style applied to the border characters.
     pub border_style: Style,
+    /// Theme style name for the border, such as `"panel.border"`, looked up
+    /// when the panel is rendered; takes precedence over
+    /// [`border_style`](Self::border_style) when it resolves.
+    pub border_style_name: Option<String>,
     /// Optional fixed width for the panel.
     pub width: Option<usize>,
     /// Optional fixed height for the content area.
     pub height: Option<usize>,
+    /// How content taller than [`height`](Self::height) is handled
+    /// (default [`VerticalOverflowMethod::Crop`]).
+    vertical_overflow: VerticalOverflowMethod,
     /// Inner padding (default `Pair(0, 1)` = 1 space each side horizontally).
     pub padding: PaddingDimensions,
     /// If true, apply `ReprHighlighter` to the content before rendering.
     pub highlight: bool,
+    /// If true, cast a one-cell drop shadow in the `"shadow"` theme style.
+    pub shadow: bool,
 }
 
 impl Panel {
@@ -91,7 +230,7 @@ impl Panel {
     /// ```
     pub fn new(content: Text) -> Self {
         Panel {
-            content,
+            content: vec![content.into()],
             box_chars: &ROUNDED,
             title: None,
             title_align: HorizontalAlign::Center,
@@ -99,14 +238,47 @@ impl Panel {
             subtitle_align: HorizontalAlign::Center,
             expand: true,
             style: Style::null(),
+            style_name: None,
             border_style: Style::null(),
+            border_style_name: None,
             width: None,
             height: None,
+            vertical_overflow: VerticalOverflowMethod::Crop,
             padding: PaddingDimensions::Pair(0, 1),
             highlight: false,
+            shadow: false,
         }
     }
 
+    /// Create an expanding `Panel` whose interior is divided into sections,
+    /// one per renderable, separated by horizontal rules that join the
+    /// border with tee characters (`├───┤`).
+    ///
+    /// Renderables of different types can be mixed by converting them to
+    /// [`PanelRenderable`] first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::panel::{Panel, PanelRenderable};
+    /// use gilt::table::Table;
+    /// use gilt::text::Text;
+    ///
+    /// let mut table = Table::new(&["Key", "Value"]);
+    /// table.add_row(&["retries", "3"]);
+    /// let panel = Panel::sections(vec![
+    ///     PanelRenderable::new(Text::from("Settings")),
+    ///     PanelRenderable::new(table),
+    /// ]);
+    /// let output = format!("{panel:30}");
+    /// assert!(output.lines().any(|line| line.starts_with('├')));
+    /// ```
+    pub fn sections<R: Into<PanelRenderable>>(sections: impl IntoIterator<Item = R>) -> Self {
+        let mut panel = Panel::new(Text::empty());
+        panel.content = sections.into_iter().map(Into::into).collect();
+        panel
+    }
+
     /// Create a non-expanding (fit-to-content) `Panel`.
     pub fn fit(content: Text) -> Self {
         let mut panel = Panel::new(content);
@@ -126,7 +298,30 @@ impl Panel {
     /// Set the title text.
     #[must_use]
     pub fn with_title(mut self, title: impl Into<Text>) -> Self {
-        self.title = Some(title.into());
+        self.title = Some(PanelRenderable::from(title.into()));
+        self
+    }
+
+    /// Use any renderable as the title, e.g. a spinner or a composed
+    /// [`Text`]. Its first line is drawn each time the panel is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::panel::Panel;
+    /// use gilt::spinner::Spinner;
+    /// use gilt::text::Text;
+    ///
+    /// let spinner = Spinner::new("dots").unwrap().with_text(Text::from("Loading"));
+    /// let panel = Panel::new(Text::from("body")).with_title_renderable(spinner);
+    /// assert!(format!("{panel:30}").contains("Loading"));
+    /// ```
+    #[must_use]
+    pub fn with_title_renderable<R: Renderable + Send + Sync + 'static>(
+        mut self,
+        title: R,
+    ) -> Self {
+        self.title = Some(PanelRenderable::new(title));
         self
     }
 
@@ -140,7 +335,19 @@ impl Panel {
     /// Set the subtitle text.
     #[must_use]
     pub fn with_subtitle(mut self, subtitle: impl Into<Text>) -> Self {
-        self.subtitle = Some(subtitle.into());
+        self.subtitle = Some(PanelRenderable::from(subtitle.into()));
+        self
+    }
+
+    /// Use any renderable as the subtitle; like
+    /// [`with_title_renderable`](Self::with_title_renderable), its first
+    /// line is drawn in the bottom border.
+    #[must_use]
+    pub fn with_subtitle_renderable<R: Renderable + Send + Sync + 'static>(
+        mut self,
+        subtitle: R,
+    ) -> Self {
+        self.subtitle = Some(PanelRenderable::new(subtitle));
         self
     }
 
@@ -165,6 +372,14 @@ impl Panel {
         self
     }
 
+    /// Set the content style by theme name, resolved through the console
+    /// theme when the panel is rendered.
+    #[must_use]
+    pub fn with_style_name(mut self, name: &str) -> Self {
+        self.style_name = Some(name.to_string());
+        self
+    }
+
     /// Set the border style.
     #[must_use]
     pub fn with_border_style(mut self, style: Style) -> Self {
@@ -172,6 +387,15 @@ impl Panel {
         self
     }
 
+    /// Set the border style by theme name, such as `"panel.border"`,
+    /// resolved when the panel is rendered, so switching themes restyles
+    /// existing panels.
+    #[must_use]
+    pub fn with_border_style_name(mut self, name: &str) -> Self {
+        self.border_style_name = Some(name.to_string());
+        self
+    }
+
     /// Set a fixed width.
     #[must_use]
     pub fn with_width(mut self, width: usize) -> Self {
@@ -180,16 +404,41 @@ impl Panel {
     }
 
     /// Set a fixed height for the content area.
+    ///
+    /// Shorter content is padded with blank lines; taller content is handled
+    /// according to [`with_vertical_overflow`](Self::with_vertical_overflow).
     #[must_use]
     pub fn with_height(mut self, height: usize) -> Self {
         self.height = Some(height);
         self
     }
 
+    /// Set how content taller than the fixed height is handled: cropped,
+    /// cropped with the console's continuation glyph on the last line, or
+    /// shown in full.
+    #[must_use]
+    pub fn with_vertical_overflow(mut self, overflow: VerticalOverflowMethod) -> Self {
+        self.vertical_overflow = overflow;
+        self
+    }
+
     /// Set the inner padding.
+    ///
+    /// Accepts a [`PaddingDimensions`], a single value for all sides, a
+    /// `(vertical, horizontal)` pair, or `(top, right, bottom, left)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::panel::Panel;
+    /// use gilt::text::Text;
+    ///
+    /// let panel = Panel::new(Text::from("body")).with_padding((1, 2, 0, 2));
+    /// assert_eq!(panel.padding.unpack(), (1, 2, 0, 2));
+    /// ```
     #[must_use]
-    pub fn with_padding(mut self, padding: PaddingDimensions) -> Self {
-        self.padding = padding;
+    pub fn with_padding(mut self, padding: impl Into<PaddingDimensions>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -200,18 +449,78 @@ impl Panel {
         self
     }
 
+    /// Enable or disable the drop shadow.
+    #[must_use]
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     /// Measure the minimum and maximum width requirements.
-    pub fn measure(&self, _console: &Console, _options: &ConsoleOptions) -> Measurement {
-        let (_, right, _, left) = self.padding.unpack();
-        let padding = left + right;
-        let content_width = self.content.cell_len();
-        let w = if let Some(fixed) = self.width {
-            fixed
-        } else {
-            content_width + padding + 2
+    pub fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let w = match self.width {
+            Some(fixed) => fixed,
+            None => {
+                let (title, subtitle) = self.border_texts(console, options);
+                self.fit_child_width(console, options, title.as_ref(), subtitle.as_ref()) + 2
+            }
         };
+        let w = if self.shadow { w + 1 } else { w };
+        let w = w.min(options.max_width);
         Measurement::new(w, w)
     }
+
+    /// The title and subtitle as the text to draw in the borders.
+    fn border_texts(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+    ) -> (Option<Text>, Option<Text>) {
+        let text = |item: &PanelRenderable| item.to_text(console, options);
+        (
+            self.title.as_ref().map(text),
+            self.subtitle.as_ref().map(text),
+        )
+    }
+
+    /// Interior width (excluding the two border columns) a fit-mode panel
+    /// needs: the widest content line plus padding, and room for the title
+    /// and subtitle between the corner fill characters.
+    fn fit_child_width(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+        title: Option<&Text>,
+        subtitle: Option<&Text>,
+    ) -> usize {
+        let (_, right, _, left) = self.padding.unpack();
+        let options = options.update_width(options.max_width.saturating_sub(2 + left + right));
+        let content_width = self
+            .content
+            .iter()
+            .map(|section| match section.as_text() {
+                Some(text) => text.measure().maximum,
+                None => section.content_width(console, &options),
+            })
+            .max()
+            .unwrap_or(0);
+        let mut width = content_width + left + right;
+        for text in title.into_iter().chain(subtitle) {
+            width = width.max(padded_title_len(text) + 2);
+        }
+        width
+    }
+}
+
+/// Cell length of a title or subtitle once newlines are flattened, tabs
+/// expanded and a space added on each side.
+fn padded_title_len(title: &Text) -> usize {
+    let mut text = title.clone();
+    let plain = text.plain().replace('\n', " ");
+    text.set_plain(&plain);
+    text.expand_tabs(None);
+    text.pad(1, ' ');
+    text.cell_len()
 }
 
 // ---------------------------------------------------------------------------
@@ -287,10 +596,25 @@ fn align_title_segments(
 // ---------------------------------------------------------------------------
 
 impl Renderable for Panel {
-    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        if self.shadow {
+            let style = shadow_style(console);
+            return render_with_shadow(options, &style, |opts| self.render_panel(console, opts));
+        }
+        self.render_panel(console, options)
+    }
+}
+
+impl Panel {
+    /// Render the bordered box without any shadow decoration.
+    fn render_panel(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let bx = self.box_chars;
+        let style = console.widget_style(&self.style, self.style_name.as_deref());
+        let border_style =
+            console.widget_style(&self.border_style, self.border_style_name.as_deref());
         let (pad_top, pad_right, pad_bottom, pad_left) = self.padding.unpack();
         let horizontal_padding = pad_left + pad_right;
+        let (title, subtitle) = self.border_texts(console, options);
 
         // Determine the panel width
         let max_width = if let Some(w) = self.width {
@@ -300,40 +624,18 @@ impl Renderable for Panel {
         };
 
         // Calculate child_width (interior width, excluding the two border columns)
-        let mut child_width = if self.expand {
-            max_width.saturating_sub(2)
+        let child_width = if self.expand {
+            let mut w = max_width.saturating_sub(2);
+            for text in title.iter().chain(subtitle.iter()) {
+                w = w.max(padded_title_len(text) + 2);
+            }
+            w
         } else {
-            // Fit mode: measure the content
-            let content_width = self.content.cell_len();
-            content_width + horizontal_padding
+            self.fit_child_width(console, options, title.as_ref(), subtitle.as_ref())
         };
 
-        // If there's a title, ensure child_width is wide enough.
-        // child_width must be >= padded_title_len + 2 so the title fits
-        // between the two anchor fill chars (top_left + fill ... fill + top_right).
-        if let Some(ref title) = self.title {
-            let mut title_text = title.clone();
-            let plain = title_text.plain().replace('\n', " ");
-            title_text.set_plain(&plain);
-            title_text.expand_tabs(None);
-            title_text.pad(1, ' ');
-            let title_cell_len = title_text.cell_len();
-            child_width = child_width.max(title_cell_len + 2);
-        }
-
-        // If there's a subtitle, ensure child_width is wide enough.
-        if let Some(ref subtitle) = self.subtitle {
-            let mut sub_text = subtitle.clone();
-            let plain = sub_text.plain().replace('\n', " ");
-            sub_text.set_plain(&plain);
-            sub_text.expand_tabs(None);
-            sub_text.pad(1, ' ');
-            let sub_cell_len = sub_text.cell_len();
-            child_width = child_width.max(sub_cell_len + 2);
-        }
-
         // Clamp child_width to max_width - 2
-        child_width = child_width.min(max_width.saturating_sub(2));
+        let child_width = child_width.min(max_width.saturating_sub(2));
 
         // The total panel width
         let width = child_width + 2;
@@ -344,47 +646,46 @@ impl Renderable for Panel {
         // wrap (which includes separators in line text) combines with the
         // between-lines Segment::line().
         let inner_width = child_width.saturating_sub(horizontal_padding).max(1);
-        let mut content_copy = self.content.clone();
-        content_copy.end = String::new();
-        let tab_size = content_copy.tab_size.unwrap_or(8);
-
-        // Apply ReprHighlighter if highlight is enabled
-        if self.highlight {
-            crate::highlighter::ReprHighlighter.highlight(&mut content_copy);
-        }
-        let wrapped = content_copy.wrap(
-            inner_width,
-            content_copy.justify,
-            content_copy.overflow,
-            tab_size,
-            content_copy.no_wrap.unwrap_or(false),
-        );
-        let mut lines: Vec<Vec<Segment>> = Vec::new();
-        for mut line in wrapped.lines {
-            line.end = String::new();
-            // Strip trailing newline that Text::split("\n", true, true) embeds
-            // in each line's plain text during wrap().
-            line.remove_suffix("\n");
-            let line_segments = line.render();
-            // Apply content style if set
-            let styled = if !self.style.is_null() {
-                Segment::apply_style(&line_segments, Some(self.style.clone()), None)
-            } else {
-                line_segments
+        // Each row is a content line, or `None` for a rule between sections.
+        let mut lines: Vec<Option<Vec<Segment>>> = Vec::new();
+        for (index, section) in self.content.iter().enumerate() {
+            if index > 0 {
+                lines.push(None);
+            }
+            let section_lines = match section.as_text() {
+                Some(text) => self.text_lines(text, console, inner_width, &style),
+                None => section.render_lines(console, options, inner_width, &style),
             };
-            let adjusted = Segment::adjust_line_length(&styled, inner_width, &self.style, true);
-            lines.push(adjusted);
+            lines.extend(section_lines.into_iter().map(Some));
         }
 
         // Apply fixed height if specified
         if let Some(h) = self.height {
-            lines = Segment::set_shape(&lines, inner_width, Some(h), Some(&self.style), false);
+            let overflows = lines.len() > h;
+            match self.vertical_overflow {
+                VerticalOverflowMethod::Visible if overflows => {}
+                VerticalOverflowMethod::Ellipsis if overflows && h > 0 => {
+                    lines.truncate(h - 1);
+                    let glyph = &console.glyphs().continuation;
+                    let left = inner_width.saturating_sub(cell_len(glyph)) / 2;
+                    let line = vec![
+                        Segment::styled(&" ".repeat(left), style.clone()),
+                        Segment::styled(glyph, style.clone()),
+                    ];
+                    let line = Segment::adjust_line_length(&line, inner_width, &style, true);
+                    lines.push(Some(line));
+                }
+                _ => {
+                    let blank = vec![Segment::styled(&" ".repeat(inner_width), style.clone())];
+                    lines.resize(h, Some(blank));
+                }
+            }
         }
 
         let mut segments = Vec::new();
 
         // ── Top border ────────────────────────────────────────────────
-        match self.title.as_ref() {
+        match title.as_ref() {
             Some(title) if width > 4 => {
                 let available = width.saturating_sub(4); // minus border_char + fill_char on each side
 
@@ -392,28 +693,23 @@ impl Renderable for Panel {
                 let mut left_anchor = String::new();
                 left_anchor.push(bx.top_left);
                 left_anchor.push(bx.top);
-                segments.push(Segment::styled(&left_anchor, self.border_style.clone()));
+                segments.push(Segment::styled(&left_anchor, border_style.clone()));
 
                 // Aligned title within fill chars
-                let title_segs = align_title_segments(
-                    title,
-                    available,
-                    self.title_align,
-                    bx.top,
-                    &self.border_style,
-                );
+                let title_segs =
+                    align_title_segments(title, available, self.title_align, bx.top, &border_style);
                 segments.extend(title_segs);
 
                 // top_fill_char + top_right
                 let mut right_anchor = String::new();
                 right_anchor.push(bx.top);
                 right_anchor.push(bx.top_right);
-                segments.push(Segment::styled(&right_anchor, self.border_style.clone()));
+                segments.push(Segment::styled(&right_anchor, border_style.clone()));
             }
             _ => {
                 // No title or too narrow: full border line
                 let top = bx.get_top(&[child_width]);
-                segments.push(Segment::styled(&top, self.border_style.clone()));
+                segments.push(Segment::styled(&top, border_style.clone()));
             }
         }
         segments.push(Segment::line());
@@ -424,23 +720,31 @@ impl Renderable for Panel {
 
         for _ in 0..pad_top {
             let mid_l = String::from(bx.mid_left);
-            segments.push(Segment::styled(&mid_l, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_l, border_style.clone()));
             let blank = " ".repeat(child_width);
-            segments.push(Segment::styled(&blank, self.style.clone()));
+            segments.push(Segment::styled(&blank, style.clone()));
             let mid_r = String::from(bx.mid_right);
-            segments.push(Segment::styled(&mid_r, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_r, border_style.clone()));
             segments.push(Segment::line());
         }
 
         // ── Content rows ──────────────────────────────────────────────
         for line in &lines {
+            let Some(line) = line else {
+                // Rule between sections, joined to the border with tees
+                let rule = bx.get_row(&[child_width], RowLevel::Row, true);
+                segments.push(Segment::styled(&rule, border_style.clone()));
+                segments.push(Segment::line());
+                continue;
+            };
+
             // Left border
             let mid_l = String::from(bx.mid_left);
-            segments.push(Segment::styled(&mid_l, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_l, border_style.clone()));
 
             // Left padding
             if pad_left > 0 {
-                segments.push(Segment::styled(&left_pad_str, self.style.clone()));
+                segments.push(Segment::styled(&left_pad_str, style.clone()));
             }
 
             // Content segments
@@ -448,59 +752,100 @@ impl Renderable for Panel {
 
             // Right padding
             if pad_right > 0 {
-                segments.push(Segment::styled(&right_pad_str, self.style.clone()));
+                segments.push(Segment::styled(&right_pad_str, style.clone()));
             }
 
             // Right border
             let mid_r = String::from(bx.mid_right);
-            segments.push(Segment::styled(&mid_r, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_r, border_style.clone()));
             segments.push(Segment::line());
         }
 
         // ── Bottom padding rows ───────────────────────────────────────
         for _ in 0..pad_bottom {
             let mid_l = String::from(bx.mid_left);
-            segments.push(Segment::styled(&mid_l, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_l, border_style.clone()));
             let blank = " ".repeat(child_width);
-            segments.push(Segment::styled(&blank, self.style.clone()));
+            segments.push(Segment::styled(&blank, style.clone()));
             let mid_r = String::from(bx.mid_right);
-            segments.push(Segment::styled(&mid_r, self.border_style.clone()));
+            segments.push(Segment::styled(&mid_r, border_style.clone()));
             segments.push(Segment::line());
         }
 
         // ── Bottom border ─────────────────────────────────────────────
-        match self.subtitle.as_ref() {
+        match subtitle.as_ref() {
             Some(subtitle) if width > 4 => {
                 let available = width.saturating_sub(4);
 
                 let mut left_anchor = String::new();
                 left_anchor.push(bx.bottom_left);
                 left_anchor.push(bx.bottom_char);
-                segments.push(Segment::styled(&left_anchor, self.border_style.clone()));
+                segments.push(Segment::styled(&left_anchor, border_style.clone()));
 
                 let sub_segs = align_title_segments(
                     subtitle,
                     available,
                     self.subtitle_align,
                     bx.bottom_char,
-                    &self.border_style,
+                    &border_style,
                 );
                 segments.extend(sub_segs);
 
                 let mut right_anchor = String::new();
                 right_anchor.push(bx.bottom_char);
                 right_anchor.push(bx.bottom_right);
-                segments.push(Segment::styled(&right_anchor, self.border_style.clone()));
+                segments.push(Segment::styled(&right_anchor, border_style.clone()));
             }
             _ => {
                 let bottom = bx.get_bottom(&[child_width]);
-                segments.push(Segment::styled(&bottom, self.border_style.clone()));
+                segments.push(Segment::styled(&bottom, border_style.clone()));
             }
         }
         segments.push(Segment::line());
 
         segments
     }
+
+    /// Wrap a text section into lines exactly `width` cells wide.
+    fn text_lines(
+        &self,
+        text: &Text,
+        console: &Console,
+        width: usize,
+        style: &Style,
+    ) -> Vec<Vec<Segment>> {
+        let mut content_copy = text.clone();
+        content_copy.end = String::new();
+        let tab_size = content_copy.tab_size.unwrap_or(console.tab_size());
+
+        // Apply ReprHighlighter if highlight is enabled
+        if self.highlight {
+            crate::highlighter::ReprHighlighter.highlight(&mut content_copy);
+        }
+        let wrapped = content_copy.wrap(
+            width,
+            content_copy.justify,
+            content_copy.overflow,
+            tab_size,
+            content_copy.no_wrap.unwrap_or(false),
+        );
+        let mut lines = Vec::new();
+        for mut line in wrapped.lines {
+            line.end = String::new();
+            // Strip trailing newline that Text::split("\n", true, true) embeds
+            // in each line's plain text during wrap().
+            line.remove_suffix("\n");
+            let line_segments = line.render();
+            // Apply content style if set
+            let styled = if !style.is_null() {
+                Segment::apply_style(&line_segments, Some(style.clone()), None)
+            } else {
+                line_segments
+            };
+            lines.push(Segment::adjust_line_length(&styled, width, style, true));
+        }
+        lines
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -529,7 +874,6 @@ impl std::fmt::Display for Panel {
 mod tests {
     use super::*;
     use crate::box_chars::{ASCII, DOUBLE, HEAVY, SQUARE};
-    use crate::cells::cell_len;
 
     fn make_console(width: usize) -> Console {
         Console::builder()
@@ -1015,6 +1359,136 @@ mod tests {
         assert_eq!(lines.len(), 7);
     }
 
+    #[test]
+    fn test_fixed_height_vertical_overflow() {
+        let console = make_console(12);
+        let content = Text::new("1\n2\n3\n4\n5", Style::null());
+        let panel = Panel::new(content.clone()).with_height(3);
+        let lines: Vec<String> = content_lines(&render_panel(&console, &panel))
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[3].contains('3'));
+
+        let panel = Panel::new(content.clone())
+            .with_height(3)
+            .with_vertical_overflow(VerticalOverflowMethod::Ellipsis);
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[2].contains('2'));
+        assert_eq!(lines[3], "│   ...    │");
+
+        let panel = Panel::new(content)
+            .with_height(3)
+            .with_vertical_overflow(VerticalOverflowMethod::Visible);
+        let output = render_panel(&console, &panel);
+        assert_eq!(content_lines(&output).len(), 7);
+    }
+
+    #[test]
+    fn test_sections_separated_by_rules() {
+        let console = make_console(12);
+        let panel = Panel::sections(vec![
+            Text::new("one", Style::null()),
+            Text::new("two\nlines", Style::null()),
+            Text::new("three", Style::null()),
+        ]);
+        let output = render_panel(&console, &panel);
+        assert_eq!(
+            content_lines(&output),
+            vec![
+                "╭──────────╮",
+                "│ one      │",
+                "├──────────┤",
+                "│ two      │",
+                "│ lines    │",
+                "├──────────┤",
+                "│ three    │",
+                "╰──────────╯",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_content_sections() {
+        let console = make_console(12);
+        let mut panel = Panel::new(Text::new("one", Style::null()));
+        assert_eq!(panel.content.len(), 1);
+        assert_eq!(panel.content[0].as_text().unwrap().plain(), "one");
+
+        panel.content.push("two".into());
+        let output = render_panel(&console, &panel);
+        assert_eq!(content_lines(&output)[2], "├──────────┤");
+        assert_eq!(content_lines(&output)[3], "│ two      │");
+    }
+
+    #[test]
+    fn test_sections_use_box_tees_and_fit() {
+        let console = make_console(40);
+        let panel = Panel::sections(vec![
+            PanelRenderable::new(Text::new("ab", Style::null())),
+            PanelRenderable::new(Panel::fit(Text::new("x", Style::null()))),
+        ])
+        .with_box_chars(&DOUBLE)
+        .with_expand(false);
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines[0], "╔═══════╗");
+        assert_eq!(lines[2], "╠═══════╣");
+        assert_eq!(lines[3], "║ ╭───╮ ║");
+        assert_eq!(panel.measure(&console, &console.options()).maximum, 9);
+    }
+
+    #[test]
+    fn test_padding_tuple() {
+        let console = make_console(12);
+        let panel = Panel::new(Text::new("X", Style::null())).with_padding((1, 0, 0, 3));
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2], "│   X      │");
+    }
+
+    #[test]
+    fn test_title_renderable() {
+        let console = make_console(24);
+        let mut title = Text::new("Build", Style::parse("bold").unwrap());
+        title.append_str(" 3/5\nignored", None);
+        let panel = Panel::new(Text::new("body", Style::null()))
+            .with_title("unused")
+            .with_title_renderable(title);
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines[0], "╭───── Build 3/5 ──────╮");
+        assert!(!output.contains("ignored"));
+        assert!(!output.contains("unused"));
+
+        // Fit panels make room for the rendered title.
+        let panel = Panel::fit(Text::new("x", Style::null()))
+            .with_title_renderable(Text::new("Long title", Style::null()));
+        let width = panel.measure(&console, &console.options()).maximum;
+        assert_eq!(width, 16);
+    }
+
+    #[test]
+    fn test_subtitle_renderable() {
+        let console = make_console(20);
+        let panel = Panel::new(Text::new("body", Style::null()))
+            .with_subtitle_renderable(Panel::fit(Text::new("v1", Style::null())));
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines[2], "╰───── ╭────╮ ─────╯");
+        assert!(panel.subtitle.as_ref().unwrap().as_text().is_none());
+        assert!(Panel::new(Text::empty())
+            .with_subtitle("v1")
+            .subtitle
+            .unwrap()
+            .as_text()
+            .is_some());
+    }
+
     #[test]
     fn test_panel_consistency_all_lines_same_width() {
         let console = make_console(40);
@@ -1088,6 +1562,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_border_style_theme_key_resolves_at_render() {
+        use crate::theme::Theme;
+        use std::collections::HashMap;
+
+        let theme = |color: &str| {
+            let mut styles = HashMap::new();
+            styles.insert(
+                "panel.border.error".to_string(),
+                Style::parse(color).unwrap(),
+            );
+            Theme::new(Some(styles), true)
+        };
+        let mut console = Console::builder().width(20).theme(theme("red")).build();
+        let panel =
+            Panel::new(Text::new("X", Style::null())).with_border_style_name("panel.border.error");
+        let border = |console: &Console| {
+            panel
+                .gilt_console(console, &console.options())
+                .into_iter()
+                .find(|s| s.text.contains('╭'))
+                .and_then(|s| s.style)
+        };
+        assert_eq!(border(&console), Some(Style::parse("red").unwrap()));
+
+        console.push_theme(theme("blue"));
+        assert_eq!(border(&console), Some(Style::parse("blue").unwrap()));
+    }
+
     // -- Highlight feature ---------------------------------------------------
 
     #[test]
@@ -1184,6 +1687,49 @@ mod tests {
         assert!(output.contains("Title"));
     }
 
+    #[test]
+    fn test_panel_composed_emoji_wraps_aligned() {
+        use crate::cells::cell_len;
+
+        let console = make_console(12);
+        // Family (ZWJ sequence), flag and skin-tone emoji, each two cells.
+        let body = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F1EF}\u{1F1F5}\u{1F44D}\u{1F3FD}x\u{1F44D}\u{1F3FD}\u{1F1EF}\u{1F1F5}";
+        let panel = Panel::new(Text::new(body, Style::null()));
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines.len(), 4, "{output}");
+        for line in &lines {
+            assert_eq!(cell_len(line), 12, "{line:?}");
+        }
+        assert!(lines[1].contains("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"));
+    }
+
+    // -- Shadow -------------------------------------------------------------
+
+    #[test]
+    fn test_panel_shadow() {
+        let console = make_console(20);
+        let panel = Panel::new(Text::new("Hello", Style::null())).with_shadow(true);
+        let output = render_panel(&console, &panel);
+        let lines = content_lines(&output);
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("╮ "));
+        assert!(lines[1].ends_with("│█"));
+        assert!(lines[2].ends_with("╯█"));
+        assert_eq!(lines[3], format!(" {}", "▀".repeat(19)));
+    }
+
+    #[test]
+    fn test_panel_shadow_measure() {
+        let console = make_console(40);
+        let panel = Panel::fit(Text::new("Hi", Style::null()));
+        let plain = panel.measure(&console, &console.options());
+        let shadowed = panel
+            .with_shadow(true)
+            .measure(&console, &console.options());
+        assert_eq!(shadowed.maximum, plain.maximum + 1);
+    }
+
     // -- Extreme width boundary tests ---------------------------------------
 
     #[test]
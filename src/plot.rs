@@ -0,0 +1,768 @@
+//! Plot -- line and scatter charts drawn with Braille dots.
+//!
+//! A [`Plot`] takes one or more [`Series`] of `(x, y)` points, scales them to
+//! fit, and draws the data on a Braille [`Canvas`](crate::canvas::Canvas)
+//! (2x4 dots per cell) framed by axes whose tick labels sit in ordinary
+//! character cells. Each series has its own style, and a legend names them.
+//!
+//! # Example
+//!
+//! ```
+//! use gilt::plot::{Plot, Series};
+//!
+//! let squares: Vec<(f64, f64)> = (0..=10).map(|x| (x as f64, (x * x) as f64)).collect();
+//! let plot = Plot::new()
+//!     .with_series(Series::new("x²", squares))
+//!     .with_width(40)
+//!     .with_height(8);
+//! let output = format!("{}", plot);
+//! assert!(output.contains("100"));
+//! ```
+
+use std::fmt;
+
+use crate::canvas::{braille, Canvas};
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::measure::Measurement;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::utils::cells::cell_len;
+
+/// Colors given to series that have no style of their own, in order.
+const SERIES_COLORS: [&str; 6] = ["cyan", "magenta", "green", "yellow", "blue", "red"];
+
+/// Columns between x-axis tick labels.
+const X_TICK_SPACING: usize = 12;
+
+/// Rows between y-axis tick labels.
+const Y_TICK_SPACING: usize = 4;
+
+// ---------------------------------------------------------------------------
+// Series
+// ---------------------------------------------------------------------------
+
+/// How the points of a [`Series`] are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesKind {
+    /// Consecutive points joined by straight lines.
+    #[default]
+    Line,
+    /// A single dot per point.
+    Scatter,
+}
+
+/// A named set of `(x, y)` points in a [`Plot`].
+#[derive(Debug, Clone)]
+pub struct Series {
+    /// Name shown in the legend.
+    pub name: String,
+    /// Data points, drawn in order.
+    pub points: Vec<(f64, f64)>,
+    /// Style of the series' dots; a null style picks the next default color.
+    pub style: Style,
+    /// Line or scatter.
+    pub kind: SeriesKind,
+}
+
+impl Series {
+    /// Create a line series from `(x, y)` points.
+    pub fn new(name: &str, points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        Series {
+            name: name.to_string(),
+            points: points.into_iter().collect(),
+            style: Style::null(),
+            kind: SeriesKind::default(),
+        }
+    }
+
+    /// Set the style (builder pattern).
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set how the points are drawn (builder pattern).
+    #[must_use]
+    pub fn with_kind(mut self, kind: SeriesKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Points with finite coordinates.
+    fn finite_points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points
+            .iter()
+            .copied()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plot
+// ---------------------------------------------------------------------------
+
+/// A chart of one or more data series with axes and a legend.
+#[derive(Debug, Clone)]
+pub struct Plot {
+    /// The data series, drawn in order (later series on top).
+    pub series: Vec<Series>,
+    /// Total width in cells, or `None` to fill the available width.
+    pub width: Option<usize>,
+    /// Height of the data area in rows.
+    pub height: usize,
+    /// Title shown centered above the chart.
+    pub title: Option<String>,
+    /// Label shown centered below the x axis.
+    pub x_label: Option<String>,
+    /// Label shown above the y axis.
+    pub y_label: Option<String>,
+    /// Fixed x range, or `None` to fit the data.
+    pub x_range: Option<(f64, f64)>,
+    /// Fixed y range, or `None` to fit the data.
+    pub y_range: Option<(f64, f64)>,
+    /// Style of the axis lines.
+    pub axis_style: Style,
+    /// Style of tick labels, axis labels and legend names.
+    pub label_style: Style,
+    /// Whether to show the legend below the chart.
+    pub show_legend: bool,
+}
+
+impl Default for Plot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plot {
+    /// Create an empty plot with a 10-row data area.
+    pub fn new() -> Self {
+        Plot {
+            series: Vec::new(),
+            width: None,
+            height: 10,
+            title: None,
+            x_label: None,
+            y_label: None,
+            x_range: None,
+            y_range: None,
+            axis_style: Style::parse("dim").unwrap_or_else(|_| Style::null()),
+            label_style: Style::null(),
+            show_legend: true,
+        }
+    }
+
+    /// Add a series.
+    pub fn add_series(&mut self, series: Series) {
+        self.series.push(series);
+    }
+
+    /// Add a series (builder pattern).
+    #[must_use]
+    pub fn with_series(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set the total width in cells.
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height of the data area in rows.
+    #[must_use]
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// Set the title.
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set the x-axis label.
+    #[must_use]
+    pub fn with_x_label(mut self, label: &str) -> Self {
+        self.x_label = Some(label.to_string());
+        self
+    }
+
+    /// Set the y-axis label.
+    #[must_use]
+    pub fn with_y_label(mut self, label: &str) -> Self {
+        self.y_label = Some(label.to_string());
+        self
+    }
+
+    /// Fix the x range instead of fitting it to the data.
+    #[must_use]
+    pub fn with_x_range(mut self, min: f64, max: f64) -> Self {
+        self.x_range = Some((min, max));
+        self
+    }
+
+    /// Fix the y range instead of fitting it to the data.
+    #[must_use]
+    pub fn with_y_range(mut self, min: f64, max: f64) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
+    /// Set the axis line style.
+    #[must_use]
+    pub fn with_axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
+
+    /// Set the label style.
+    #[must_use]
+    pub fn with_label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Show or hide the legend.
+    #[must_use]
+    pub fn with_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// The x and y ranges the chart covers.
+    ///
+    /// Ranges not fixed with [`with_x_range`](Self::with_x_range) or
+    /// [`with_y_range`](Self::with_y_range) span the data; an empty or flat
+    /// range is widened so points still land inside it.
+    pub fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let points = || self.series.iter().flat_map(Series::finite_points);
+        let fit = |fixed: Option<(f64, f64)>, values: &mut dyn Iterator<Item = f64>| {
+            let (min, max) = fixed.unwrap_or_else(|| {
+                values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(v), hi.max(v))
+                })
+            });
+            if !min.is_finite() || !max.is_finite() {
+                (0.0, 1.0)
+            } else if min >= max {
+                (min - 1.0, max + 1.0)
+            } else {
+                (min, max)
+            }
+        };
+        (
+            fit(self.x_range, &mut points().map(|(x, _)| x)),
+            fit(self.y_range, &mut points().map(|(_, y)| y)),
+        )
+    }
+
+    /// Style of the series at `index`, falling back to the default colors.
+    fn series_style(&self, index: usize) -> Style {
+        let style = &self.series[index].style;
+        if style.is_null() {
+            Style::parse(SERIES_COLORS[index % SERIES_COLORS.len()])
+                .unwrap_or_else(|_| Style::null())
+        } else {
+            style.clone()
+        }
+    }
+
+    /// Draw each series on its own canvas of `columns` x `rows` cells.
+    fn draw_series(&self, columns: usize, rows: usize, bounds: Bounds) -> Vec<Canvas> {
+        let (x_min, x_max, y_min, y_max) = bounds;
+        let pixel_width = (columns * 2).saturating_sub(1) as f64;
+        let pixel_height = (rows * 4).saturating_sub(1) as f64;
+        let to_pixel = |(x, y): (f64, f64)| {
+            let px = (x - x_min) / (x_max - x_min) * pixel_width;
+            let py = (y_max - y) / (y_max - y_min) * pixel_height;
+            (px, py)
+        };
+        let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        let size = (pixel_width, pixel_height);
+
+        self.series
+            .iter()
+            .map(|series| {
+                let mut canvas = Canvas::new(columns, rows);
+                let mut previous: Option<(f64, f64)> = None;
+                for point in series.finite_points() {
+                    let point = to_pixel(point);
+                    match (series.kind, previous) {
+                        (SeriesKind::Line, Some(start)) => {
+                            if let Some((from, to)) = clip_segment(start, point, size) {
+                                let ((x0, y0), (x1, y1)) = (round(from), round(to));
+                                canvas.line(x0, y0, x1, y1);
+                            }
+                        }
+                        _ => {
+                            if outcode(point, size) == INSIDE {
+                                let (x, y) = round(point);
+                                canvas.set(x as usize, y as usize);
+                            }
+                        }
+                    }
+                    previous = Some(point);
+                }
+                canvas
+            })
+            .collect()
+    }
+
+    /// Tick labels down the y axis, one per row (empty between ticks).
+    fn y_tick_labels(&self, rows: usize, y_min: f64, y_max: f64) -> Vec<String> {
+        let decimals = decimals_for(y_max - y_min);
+        let ticks = tick_positions(rows, Y_TICK_SPACING);
+        (0..rows)
+            .map(|row| {
+                if ticks.contains(&row) {
+                    let fraction = if rows > 1 {
+                        row as f64 / (rows - 1) as f64
+                    } else {
+                        0.0
+                    };
+                    format_tick(y_max - fraction * (y_max - y_min), decimals)
+                } else {
+                    String::new()
+                }
+            })
+            .collect()
+    }
+
+    /// Build the lines of the chart at `width` cells.
+    fn render_lines(&self, width: usize) -> Vec<Vec<Segment>> {
+        let ((x_min, x_max), (y_min, y_max)) = self.bounds();
+        let rows = self.height;
+        let y_labels = self.y_tick_labels(rows, y_min, y_max);
+        let gutter = y_labels.iter().map(|l| cell_len(l)).max().unwrap_or(0) + 1;
+        let columns = width.saturating_sub(gutter + 1).max(1);
+        let canvases = self.draw_series(columns, rows, (x_min, x_max, y_min, y_max));
+        let styles: Vec<Style> = (0..self.series.len())
+            .map(|i| self.series_style(i))
+            .collect();
+
+        let mut lines = Vec::new();
+        let full_width = gutter + 1 + columns;
+        if let Some(title) = &self.title {
+            let title_style =
+                self.label_style.clone() + Style::parse("bold").unwrap_or_else(|_| Style::null());
+            lines.push(centered(title, full_width, title_style));
+        }
+        if let Some(label) = &self.y_label {
+            lines.push(vec![Segment::styled(label, self.label_style.clone())]);
+        }
+
+        // Data rows: tick label, axis, then the merged canvases.
+        for (row, label) in y_labels.iter().enumerate() {
+            let mut line = vec![
+                Segment::styled(
+                    &format!("{:>width$} ", label, width = gutter - 1),
+                    self.label_style.clone(),
+                ),
+                Segment::styled(
+                    if label.is_empty() { "│" } else { "┤" },
+                    self.axis_style.clone(),
+                ),
+            ];
+            for col in 0..columns {
+                let mut bits = 0;
+                let mut style = None;
+                for (canvas, series_style) in canvases.iter().zip(&styles) {
+                    let cell = canvas.cell(col, row);
+                    if cell != 0 {
+                        bits |= cell;
+                        style = Some(series_style.clone());
+                    }
+                }
+                line.push(match style {
+                    Some(style) => Segment::styled(&braille(bits).to_string(), style),
+                    None => Segment::text(" "),
+                });
+            }
+            lines.push(line);
+        }
+
+        // The x axis with tick marks, then the tick labels beneath it.
+        let ticks = tick_positions(columns, X_TICK_SPACING);
+        let axis: String = (0..columns)
+            .map(|col| if ticks.contains(&col) { '┬' } else { '─' })
+            .collect();
+        lines.push(vec![
+            Segment::text(&" ".repeat(gutter)),
+            Segment::styled(&format!("└{}", axis), self.axis_style.clone()),
+        ]);
+
+        let decimals = decimals_for(x_max - x_min);
+        let mut tick_line = String::new();
+        for &col in &ticks {
+            let fraction = if columns > 1 {
+                col as f64 / (columns - 1) as f64
+            } else {
+                0.0
+            };
+            let label = format_tick(x_min + fraction * (x_max - x_min), decimals);
+            let center = gutter + 1 + col;
+            let start = center
+                .saturating_sub(cell_len(&label) / 2)
+                .min(full_width.saturating_sub(cell_len(&label)));
+            let current = cell_len(&tick_line);
+            // Skip labels that would run into the previous one.
+            if tick_line.is_empty() || start > current {
+                tick_line.push_str(&" ".repeat(start - current));
+                tick_line.push_str(&label);
+            }
+        }
+        lines.push(vec![Segment::styled(
+            tick_line.trim_end(),
+            self.label_style.clone(),
+        )]);
+
+        if let Some(label) = &self.x_label {
+            lines.push(centered(label, full_width, self.label_style.clone()));
+        }
+
+        let named: Vec<usize> = (0..self.series.len())
+            .filter(|&i| !self.series[i].name.is_empty())
+            .collect();
+        if self.show_legend && !named.is_empty() {
+            let mut legend = vec![Segment::text(&" ".repeat(gutter + 1))];
+            for (n, &i) in named.iter().enumerate() {
+                if n > 0 {
+                    legend.push(Segment::text("  "));
+                }
+                legend.push(Segment::styled("━━", styles[i].clone()));
+                legend.push(Segment::styled(
+                    &format!(" {}", self.series[i].name),
+                    self.label_style.clone(),
+                ));
+            }
+            lines.push(legend);
+        }
+        lines
+    }
+
+    /// Return the measurement for this plot.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let width = self
+            .width
+            .unwrap_or(options.max_width)
+            .min(options.max_width);
+        Measurement::new(width.min(10), width)
+    }
+}
+
+/// Data ranges as (x_min, x_max, y_min, y_max).
+type Bounds = (f64, f64, f64, f64);
+
+// Cohen–Sutherland region codes for a point relative to the plot area.
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const TOP: u8 = 4;
+const BOTTOM: u8 = 8;
+
+/// Region code of `(x, y)` against the area `0..=width` x `0..=height`.
+fn outcode((x, y): (f64, f64), (width, height): (f64, f64)) -> u8 {
+    let mut code = INSIDE;
+    if x < 0.0 {
+        code |= LEFT;
+    } else if x > width {
+        code |= RIGHT;
+    }
+    if y < 0.0 {
+        code |= TOP;
+    } else if y > height {
+        code |= BOTTOM;
+    }
+    code
+}
+
+/// Clip the segment from `start` to `end` to the area `0..=width` x
+/// `0..=height` (Cohen–Sutherland), or `None` when it lies outside.
+fn clip_segment(
+    mut start: (f64, f64),
+    mut end: (f64, f64),
+    size: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let (width, height) = size;
+    let mut start_code = outcode(start, size);
+    let mut end_code = outcode(end, size);
+    loop {
+        if start_code | end_code == INSIDE {
+            return Some((start, end));
+        }
+        if start_code & end_code != INSIDE {
+            return None;
+        }
+        // Move the endpoint that lies outside onto the edge it crosses.
+        let code = if start_code != INSIDE {
+            start_code
+        } else {
+            end_code
+        };
+        let ((x0, y0), (x1, y1)) = (start, end);
+        let point = if code & TOP != 0 {
+            (x0 + (x1 - x0) * (0.0 - y0) / (y1 - y0), 0.0)
+        } else if code & BOTTOM != 0 {
+            (x0 + (x1 - x0) * (height - y0) / (y1 - y0), height)
+        } else if code & RIGHT != 0 {
+            (width, y0 + (y1 - y0) * (width - x0) / (x1 - x0))
+        } else {
+            (0.0, y0 + (y1 - y0) * (0.0 - x0) / (x1 - x0))
+        };
+        if code == start_code {
+            start = point;
+            start_code = outcode(start, size);
+        } else {
+            end = point;
+            end_code = outcode(end, size);
+        }
+    }
+}
+
+/// Evenly spaced positions in `0..len`, about `spacing` apart, always
+/// including both ends.
+fn tick_positions(len: usize, spacing: usize) -> Vec<usize> {
+    if len < 2 {
+        return vec![0];
+    }
+    let count = ((len - 1) / spacing).max(1);
+    (0..=count).map(|i| i * (len - 1) / count).collect()
+}
+
+/// Decimal places that distinguish ticks across a range of `span`.
+fn decimals_for(span: f64) -> usize {
+    if span >= 10.0 {
+        0
+    } else if span >= 1.0 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Format a tick value, avoiding a negative zero.
+fn format_tick(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+        text[1..].to_string()
+    } else {
+        text
+    }
+}
+
+/// A line with `text` centered in `width` cells.
+fn centered(text: &str, width: usize, style: Style) -> Vec<Segment> {
+    let pad = width.saturating_sub(cell_len(text)) / 2;
+    vec![
+        Segment::text(&" ".repeat(pad)),
+        Segment::styled(text, style),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Display
+// ---------------------------------------------------------------------------
+
+impl fmt::Display for Plot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.width.unwrap_or(80);
+        let lines: Vec<String> = self
+            .render_lines(width)
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_str()).collect())
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Renderable
+// ---------------------------------------------------------------------------
+
+impl Renderable for Plot {
+    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let width = self
+            .width
+            .unwrap_or(options.max_width)
+            .min(options.max_width);
+        let mut segments = Vec::new();
+        for line in self.render_lines(width) {
+            segments.extend(line);
+            segments.push(Segment::line());
+        }
+        segments
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagonal() -> Series {
+        Series::new("up", [(0.0, 0.0), (10.0, 10.0)])
+    }
+
+    #[test]
+    fn test_bounds_fit_data() {
+        let plot = Plot::new()
+            .with_series(diagonal())
+            .with_series(Series::new("", [(-5.0, 3.0), (f64::NAN, 100.0)]));
+        assert_eq!(plot.bounds(), ((-5.0, 10.0), (0.0, 10.0)));
+    }
+
+    #[test]
+    fn test_bounds_fixed_flat_and_empty() {
+        let plot = Plot::new()
+            .with_series(Series::new("flat", [(1.0, 5.0), (2.0, 5.0)]))
+            .with_x_range(0.0, 4.0);
+        assert_eq!(plot.bounds(), ((0.0, 4.0), (4.0, 6.0)));
+        assert_eq!(Plot::new().bounds(), ((0.0, 1.0), (0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_axes_and_tick_labels() {
+        let output = Plot::new()
+            .with_series(diagonal())
+            .with_width(30)
+            .with_height(5)
+            .with_legend(false)
+            .to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert!(lines[0].starts_with("10 ┤"));
+        assert!(lines[1].starts_with("   │"));
+        assert!(lines[4].starts_with(" 0 ┤"));
+        assert!(lines[5].starts_with("   └┬"));
+        assert!(lines[5].ends_with('┬'));
+        assert_eq!(lines[6].split_whitespace().next(), Some("0"));
+        assert!(lines[6].ends_with("10"));
+        assert!(lines.iter().all(|l| cell_len(l) <= 30));
+    }
+
+    #[test]
+    fn test_data_corners_are_plotted() {
+        let output = Plot::new()
+            .with_series(diagonal())
+            .with_width(14)
+            .with_height(3)
+            .to_string();
+        let lines: Vec<Vec<char>> = output.lines().map(|l| l.chars().collect()).collect();
+        let bits = |c: char| c as u32 - 0x2800;
+        // Bottom-left dot of the data area and top-right dot.
+        assert_ne!(bits(lines[2][4]) & 0x40, 0);
+        assert_ne!(bits(*lines[0].last().unwrap()) & 0x08, 0);
+    }
+
+    #[test]
+    fn test_series_styles_and_legend() {
+        let red = Style::parse("red").unwrap();
+        let plot = Plot::new()
+            .with_series(diagonal().with_style(red.clone()))
+            .with_series(Series::new("dots", [(5.0, 5.0)]).with_kind(SeriesKind::Scatter))
+            .with_width(20)
+            .with_height(4);
+        let lines = plot.render_lines(20);
+        let legend = lines.last().unwrap();
+        assert_eq!(legend[1].style, Some(red.clone()));
+        assert_eq!(legend[2].text, " up");
+        assert_eq!(legend[4].style, Some(Style::parse("magenta").unwrap()));
+        assert_eq!(legend[5].text, " dots");
+
+        let data_styles: Vec<&Style> = lines[..4]
+            .iter()
+            .flatten()
+            .filter(|s| {
+                s.text
+                    .chars()
+                    .all(|c| ('\u{2801}'..='\u{28ff}').contains(&c))
+            })
+            .filter_map(|s| s.style.as_ref())
+            .collect();
+        assert!(data_styles.contains(&&red));
+        assert!(data_styles.contains(&&Style::parse("magenta").unwrap()));
+    }
+
+    #[test]
+    fn test_title_and_axis_labels() {
+        let output = Plot::new()
+            .with_series(diagonal())
+            .with_title("Growth")
+            .with_x_label("time")
+            .with_y_label("size")
+            .with_width(30)
+            .with_height(2)
+            .to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim(), "Growth");
+        assert_eq!(lines[1], "size");
+        assert_eq!(lines[lines.len() - 2].trim(), "time");
+        assert!(lines[lines.len() - 1].ends_with("━━ up"));
+    }
+
+    #[test]
+    fn test_format_tick() {
+        assert_eq!(format_tick(-0.001, 2), "0.00");
+        assert_eq!(format_tick(2.5, 1), "2.5");
+        assert_eq!(decimals_for(0.5), 2);
+    }
+
+    #[test]
+    fn test_clip_segment() {
+        let size = (10.0, 10.0);
+        assert_eq!(
+            clip_segment((-5.0, 5.0), (15.0, 5.0), size),
+            Some(((0.0, 5.0), (10.0, 5.0)))
+        );
+        assert_eq!(clip_segment((-5.0, -5.0), (-1.0, 20.0), size), None);
+        assert_eq!(
+            clip_segment((2.0, 3.0), (4.0, 5.0), size),
+            Some(((2.0, 3.0), (4.0, 5.0)))
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_points_are_clipped() {
+        let output = Plot::new()
+            .with_series(Series::new("far", [(0.0, 0.0), (1e12, 1.0), (0.5, 1e12)]))
+            .with_series(
+                Series::new("dots", [(-3.0, 0.5), (0.5, 0.5)]).with_kind(SeriesKind::Scatter),
+            )
+            .with_x_range(0.0, 1.0)
+            .with_y_range(0.0, 1.0)
+            .with_width(20)
+            .with_height(4)
+            .to_string();
+        assert!(output.lines().all(|line| cell_len(line) <= 20));
+        assert!(output.contains(|c: char| ('\u{2801}'..='\u{28ff}').contains(&c)));
+    }
+
+    #[test]
+    fn test_render_fits_console_width() {
+        let console = Console::builder().width(40).build();
+        let plot = Plot::new().with_series(diagonal()).with_width(100);
+        let segments = plot.gilt_console(&console, &console.options());
+        let mut width = 0;
+        for segment in &segments {
+            if segment.text == "\n" {
+                assert!(width <= 40);
+                width = 0;
+            } else {
+                width += segment.cell_length();
+            }
+        }
+        assert_eq!(
+            plot.measure(&console, &console.options()),
+            Measurement::new(10, 40)
+        );
+    }
+}
@@ -8,7 +8,8 @@
 pub use crate::accessibility::{contrast_ratio, meets_aa, meets_aa_large, meets_aaa};
 
 // Core engine
-pub use crate::console::{Console, ConsoleBuilder, ConsoleOptions, Renderable};
+pub use crate::console::{Console, ConsoleBuilder, ConsoleOptions, Renderable, SharedConsole};
+pub use crate::measure::{Measure, Measurement};
 
 // Text and styling
 pub use crate::color::{Color, ColorSystem};
@@ -29,6 +30,8 @@ pub use crate::inspect::Inspect;
 #[cfg(feature = "markdown")]
 pub use crate::markdown::Markdown;
 pub use crate::panel::Panel;
+#[cfg(feature = "rayon")]
+pub use crate::progress::ParallelProgressExt;
 pub use crate::progress::Progress;
 pub use crate::progress::ProgressIteratorExt;
 pub use crate::progress_bar::ProgressBar;
@@ -45,7 +48,7 @@ pub use crate::bar::Bar;
 pub use crate::json::Json;
 pub use crate::layout::Layout;
 pub use crate::live::Live;
-pub use crate::prompt::{MultiSelect, Prompt, Select};
+pub use crate::prompt::{Confirm, MultiSelect, Prompt, Select};
 pub use crate::status::Status;
 
 // Markup
@@ -1,6 +1,7 @@
 //! Progress bar column for progress bars.
 
 use crate::console::{Console, Renderable};
+use crate::progress::task::current_time_secs;
 use crate::progress::{ProgressColumn, Task};
 use crate::progress_bar::ProgressBar;
 use crate::text::Text;
@@ -48,10 +49,34 @@ impl Default for BarColumn {
 
 impl ProgressColumn for BarColumn {
     fn render(&self, task: &Task) -> Text {
+        self.render_at(task, current_time_secs())
+    }
+
+    /// Render the bar at `time`.
+    ///
+    /// Tasks without a total, and tasks that have not started yet, show a
+    /// pulse animation whose phase is derived from `time`, so the same task
+    /// and time always produce the same frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::{BarColumn, ProgressColumn, Task};
+    ///
+    /// let column = BarColumn::new().with_bar_width(Some(10));
+    /// let task = Task::new(0, "waiting", None);
+    /// let a = column.render_at(&task, 0.0);
+    /// let b = column.render_at(&task, 0.2);
+    /// assert_eq!(a, column.render_at(&task, 0.0));
+    /// assert_ne!(a, b);
+    /// ```
+    fn render_at(&self, task: &Task, time: f64) -> Text {
         let bar = ProgressBar::new()
             .with_total(task.total)
             .with_completed(task.completed)
             .with_width(self.bar_width)
+            .with_pulse(!task.started())
+            .with_animation_time(Some(time))
             .with_style(&self.style)
             .with_complete_style(&self.complete_style)
             .with_finished_style(&self.finished_style)
@@ -1,5 +1,6 @@
 //! File size columns for progress bars.
 
+use crate::format_context::FormatContext;
 use crate::progress::{ProgressColumn, Task};
 use crate::style::Style;
 use crate::text::Text;
@@ -24,6 +25,13 @@ impl ProgressColumn for FileSizeColumn {
             Style::parse("progress.filesize").unwrap_or_else(|_| Style::null()),
         )
     }
+
+    fn render_formatted(&self, task: &Task, _time: f64, format: &FormatContext) -> Text {
+        Text::new(
+            &format.format_size(task.completed as u64),
+            Style::parse("progress.filesize").unwrap_or_else(|_| Style::null()),
+        )
+    }
 }
 
 /// A column that shows the total as a human-readable file size.
@@ -45,4 +53,11 @@ impl ProgressColumn for TotalFileSizeColumn {
             Style::parse("progress.filesize.total").unwrap_or_else(|_| Style::null()),
         )
     }
+
+    fn render_formatted(&self, task: &Task, _time: f64, format: &FormatContext) -> Text {
+        Text::new(
+            &format.format_size(task.total.unwrap_or(0.0) as u64),
+            Style::parse("progress.filesize.total").unwrap_or_else(|_| Style::null()),
+        )
+    }
 }
@@ -1,5 +1,6 @@
 //! Spinner column for progress bars.
 
+use crate::progress::task::current_time_secs;
 use crate::progress::{ProgressColumn, Task};
 use crate::status::spinner::Spinner;
 use crate::style::Style;
@@ -52,6 +53,24 @@ impl Default for SpinnerColumn {
 
 impl ProgressColumn for SpinnerColumn {
     fn render(&self, task: &Task) -> Text {
+        self.render_at(task, current_time_secs())
+    }
+
+    /// Render the spinner frame for the task's elapsed time at `time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::{ProgressColumn, SpinnerColumn, Task};
+    ///
+    /// let column = SpinnerColumn::new("line");
+    /// let mut task = Task::new(0, "work", None);
+    /// task.start_time = Some(0.0);
+    /// // The "line" spinner changes frame every 130ms.
+    /// assert_eq!(column.render_at(&task, 0.0).plain(), "-");
+    /// assert_eq!(column.render_at(&task, 0.13).plain(), "\\");
+    /// ```
+    fn render_at(&self, task: &Task, time: f64) -> Text {
         if task.finished() {
             return self.finished_text.clone();
         }
@@ -64,7 +83,10 @@ impl ProgressColumn for SpinnerColumn {
             spinner = spinner.with_style(style.clone());
         }
 
-        let elapsed = task.elapsed().unwrap_or(0.0);
+        // Count frames from the task's start rather than from this
+        // throwaway spinner's first render.
+        spinner.start_time = Some(0.0);
+        let elapsed = task.elapsed_at(time).unwrap_or(0.0);
         spinner.render(elapsed)
     }
 
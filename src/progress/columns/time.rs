@@ -1,5 +1,6 @@
 //! Time-related columns for progress bars.
 
+use crate::progress::task::current_time_secs;
 use crate::progress::{format_time, ProgressColumn, Task};
 use crate::style::Style;
 use crate::text::Text;
@@ -16,7 +17,11 @@ impl Default for TimeElapsedColumn {
 
 impl ProgressColumn for TimeElapsedColumn {
     fn render(&self, task: &Task) -> Text {
-        let elapsed = task.elapsed().unwrap_or(0.0);
+        self.render_at(task, current_time_secs())
+    }
+
+    fn render_at(&self, task: &Task, time: f64) -> Text {
+        let elapsed = task.elapsed_at(time).unwrap_or(0.0);
         let formatted = format_time(elapsed);
         Text::new(
             &formatted,
@@ -53,11 +58,15 @@ impl Default for TimeRemainingColumn {
 
 impl ProgressColumn for TimeRemainingColumn {
     fn render(&self, task: &Task) -> Text {
+        self.render_at(task, current_time_secs())
+    }
+
+    fn render_at(&self, task: &Task, time: f64) -> Text {
         let style = Style::parse("progress.remaining").unwrap_or_else(|_| Style::null());
 
         if task.finished() {
             if self.elapsed_when_finished {
-                let elapsed = task.elapsed().unwrap_or(0.0);
+                let elapsed = task.elapsed_at(time).unwrap_or(0.0);
                 return Text::new(&format_time(elapsed), style);
             }
             return Text::new("0:00", style);
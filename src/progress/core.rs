@@ -1,11 +1,13 @@
 //! Main progress tracking orchestrator.
 
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 
+use crate::clock::Clock;
 use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::format_context::{FormatContext, UnitSystem};
 use crate::live::Live;
 use crate::progress::columns::{BarColumn, TaskProgressColumn, TextColumn, TimeRemainingColumn};
-use crate::progress::task::{current_time_secs, Task, TaskId};
+use crate::progress::task::{current_time_secs, SpeedEstimator, Task, TaskId};
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::table::Table;
@@ -24,6 +26,28 @@ pub trait ProgressColumn: Send + Sync {
     /// Render this column for the given task.
     fn render(&self, task: &Task) -> Text;
 
+    /// Render this column for the given task at `time` (seconds, as reported
+    /// by the progress display's clock).
+    ///
+    /// Animated columns override this so frames follow the display's clock
+    /// rather than the wall clock. The default ignores `time` and calls
+    /// [`render`](Self::render).
+    fn render_at(&self, task: &Task, time: f64) -> Text {
+        let _ = time;
+        self.render(task)
+    }
+
+    /// Render this column for the given task at `time`, following the
+    /// console's locale conventions.
+    ///
+    /// Columns that print numbers or sizes override this to honour the
+    /// separators and unit system in `format`. The default ignores `format`
+    /// and calls [`render_at`](Self::render_at).
+    fn render_formatted(&self, task: &Task, time: f64, format: &FormatContext) -> Text {
+        let _ = format;
+        self.render_at(task, time)
+    }
+
     /// Maximum refresh rate in seconds, or None for unlimited.
     fn max_refresh(&self) -> Option<f64> {
         None
@@ -68,6 +92,16 @@ impl DownloadColumn {
             filesize::decimal(size, 1, " ")
         }
     }
+
+    /// Format a byte count with `format`'s separators. Explicit binary units
+    /// take precedence over the context's unit system.
+    pub(crate) fn format_size_with(&self, size: u64, format: &FormatContext) -> String {
+        if self.binary_units {
+            format.format_size_in(size, UnitSystem::Binary)
+        } else {
+            format.format_size(size)
+        }
+    }
 }
 
 impl Default for DownloadColumn {
@@ -86,6 +120,30 @@ impl ProgressColumn for DownloadColumn {
         let style = Style::parse("progress.download").unwrap_or_else(|_| Style::null());
         Text::new(&format!("{completed}/{total}"), style)
     }
+
+    /// Sizes follow the console's separators and unit system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::format_context::FormatContext;
+    /// use gilt::progress::{DownloadColumn, ProgressColumn, Task};
+    ///
+    /// let mut task = Task::new(0, "fetch", Some(2500.0));
+    /// task.completed = 1500.0;
+    /// let de = FormatContext::for_locale("de");
+    /// let text = DownloadColumn::new().render_formatted(&task, 0.0, &de);
+    /// assert_eq!(text.plain(), "1,5 kB/2,5 kB");
+    /// ```
+    fn render_formatted(&self, task: &Task, _time: f64, format: &FormatContext) -> Text {
+        let completed = self.format_size_with(task.completed as u64, format);
+        let total = match task.total {
+            Some(t) => self.format_size_with(t as u64, format),
+            None => "?".to_string(),
+        };
+        let style = Style::parse("progress.download").unwrap_or_else(|_| Style::null());
+        Text::new(&format!("{completed}/{total}"), style)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -126,6 +184,16 @@ impl TransferSpeedColumn {
             filesize::decimal(size, 1, " ")
         }
     }
+
+    /// Format a byte count with `format`'s separators. Explicit binary units
+    /// take precedence over the context's unit system.
+    pub(crate) fn format_size_with(&self, size: u64, format: &FormatContext) -> String {
+        if self.binary_units {
+            format.format_size_in(size, UnitSystem::Binary)
+        } else {
+            format.format_size(size)
+        }
+    }
 }
 
 impl Default for TransferSpeedColumn {
@@ -137,7 +205,7 @@ impl Default for TransferSpeedColumn {
 impl ProgressColumn for TransferSpeedColumn {
     fn render(&self, task: &Task) -> Text {
         let style = Style::parse("progress.data.speed").unwrap_or_else(|_| Style::null());
-        match task.speed() {
+        match task.speed_estimate() {
             Some(speed) => {
                 let formatted = self.format_size(speed as u64);
                 Text::new(&format!("{formatted}/s"), style)
@@ -145,6 +213,17 @@ impl ProgressColumn for TransferSpeedColumn {
             None => Text::new("?", style),
         }
     }
+
+    fn render_formatted(&self, task: &Task, _time: f64, format: &FormatContext) -> Text {
+        let style = Style::parse("progress.data.speed").unwrap_or_else(|_| Style::null());
+        match task.speed_estimate() {
+            Some(speed) => {
+                let formatted = self.format_size_with(speed as u64, format);
+                Text::new(&format!("{formatted}/s"), style)
+            }
+            None => Text::new("?", style),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -225,6 +304,8 @@ pub struct Progress {
     task_id_counter: usize,
     /// Duration in seconds for the speed estimation sliding window.
     speed_estimate_period: f64,
+    /// Speed estimator given to newly added tasks.
+    speed_estimator: SpeedEstimator,
     /// Function to get the current time (injectable for testing).
     get_time: Box<dyn Fn() -> f64 + Send>,
     /// Whether rendering is disabled.
@@ -244,6 +325,7 @@ impl Progress {
                 .with_refresh_per_second(10.0),
             task_id_counter: 0,
             speed_estimate_period: 30.0,
+            speed_estimator: SpeedEstimator::Window,
             get_time: Box::new(current_time_secs),
             disable: false,
             expand: false,
@@ -298,6 +380,16 @@ impl Progress {
         self
     }
 
+    /// Set the speed estimator used by newly added tasks (builder pattern).
+    ///
+    /// Individual tasks can be changed afterwards through
+    /// [`Task::speed_estimator`].
+    #[must_use]
+    pub fn with_speed_estimator(mut self, estimator: SpeedEstimator) -> Self {
+        self.speed_estimator = estimator;
+        self
+    }
+
     /// Enable or disable progress display (builder pattern).
     #[must_use]
     pub fn with_disable(mut self, disable: bool) -> Self {
@@ -322,6 +414,28 @@ impl Progress {
         self
     }
 
+    /// Read task timings and animation frames from `clock` (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::clock::{Clock, ManualClock};
+    /// use gilt::progress::{Progress, TimeElapsedColumn};
+    /// use std::time::Duration;
+    ///
+    /// let clock = ManualClock::new();
+    /// let mut progress = Progress::new(vec![Box::new(TimeElapsedColumn)])
+    ///     .with_clock(clock.clone());
+    /// let task = progress.add_task("work", None);
+    /// clock.advance(Duration::from_secs(90));
+    /// progress.advance(task, 1.0);
+    /// assert_eq!(progress.get_task(task).unwrap().elapsed_at(clock.now()), Some(90.0));
+    /// ```
+    #[must_use]
+    pub fn with_clock<C: Clock + 'static>(self, clock: C) -> Self {
+        self.with_get_time(move || clock.now())
+    }
+
     // -- Task management ----------------------------------------------------
 
     /// Add a new task and return its ID.
@@ -332,12 +446,107 @@ impl Progress {
         let id = self.task_id_counter;
         self.task_id_counter += 1;
         let mut task = Task::new(id, description, total);
+        task.speed_estimator = self.speed_estimator;
         let now = (self.get_time)();
         task.start_time = Some(now);
         self.tasks.push(task);
         id
     }
 
+    /// Add a child task under `parent` and return its ID.
+    ///
+    /// The parent's progress becomes the roll-up of its children: its total
+    /// is the sum of the child totals and its completed count the sum of the
+    /// completed counts of those same children (of every child when none has
+    /// a total), so updates made directly to a parent are overwritten as soon
+    /// as a child changes. Children are displayed below their parent with a
+    /// tree-style indent. If `parent` does not exist the task is added at the
+    /// top level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::Progress;
+    ///
+    /// let mut progress = Progress::new(Progress::default_columns())
+    ///     .with_disable(true);
+    /// let package = progress.add_task("serde", None);
+    /// let download = progress.add_task_with_parent("download", Some(100.0), package);
+    /// let build = progress.add_task_with_parent("build", Some(300.0), package);
+    ///
+    /// progress.advance(download, 100.0);
+    /// progress.advance(build, 100.0);
+    ///
+    /// let parent = progress.get_task(package).unwrap();
+    /// assert_eq!(parent.total, Some(400.0));
+    /// assert_eq!(parent.percentage(), 50.0);
+    /// assert_eq!(progress.get_task(build).unwrap().depth, 1);
+    /// ```
+    pub fn add_task_with_parent(
+        &mut self,
+        description: &str,
+        total: Option<f64>,
+        parent: TaskId,
+    ) -> TaskId {
+        let Some(parent_index) = self.tasks.iter().position(|t| t.id == parent) else {
+            return self.add_task(description, total);
+        };
+        let id = self.task_id_counter;
+        self.task_id_counter += 1;
+        let mut task = Task::new(id, description, total);
+        task.start_time = Some((self.get_time)());
+        task.speed_estimator = self.speed_estimator;
+        task.parent = Some(parent);
+        task.depth = self.tasks[parent_index].depth + 1;
+
+        // Insert after the parent's last descendant so subtrees stay together.
+        let insert_at = self.tasks[parent_index + 1..]
+            .iter()
+            .position(|t| t.depth <= self.tasks[parent_index].depth)
+            .map_or(self.tasks.len(), |offset| parent_index + 1 + offset);
+        self.tasks.insert(insert_at, task);
+        self.roll_up(parent);
+        id
+    }
+
+    /// Recompute `parent_id` from its children, then continue up the tree.
+    fn roll_up(&mut self, parent_id: TaskId) {
+        let children: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|t| t.parent == Some(parent_id))
+            .collect();
+        if children.is_empty() {
+            return;
+        }
+        let total: Option<f64> = children
+            .iter()
+            .filter_map(|t| t.total)
+            .fold(None, |sum, t| Some(sum.unwrap_or(0.0) + t));
+        // Children without a total only count towards an indeterminate
+        // parent, so a determinate one never passes 100%.
+        let completed: f64 = children
+            .iter()
+            .filter_map(|t| match (total, t.total) {
+                (None, _) => Some(t.completed),
+                (Some(_), Some(child_total)) => Some(t.completed.min(child_total)),
+                (Some(_), None) => None,
+            })
+            .sum();
+
+        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == parent_id) {
+            task.total = total;
+            // A new or reset child re-opens a finished parent.
+            if total.is_none_or(|t| completed < t) {
+                task.finished_time = None;
+                task.finished_speed = None;
+            }
+        }
+        // `update` records the sample, handles completion and rolls up the
+        // grandparent in turn.
+        self.update(parent_id, Some(completed), None, None, None, None);
+    }
+
     /// Update a task with new values.
     ///
     /// Any parameter set to `None` is left unchanged. Use `advance` to
@@ -377,10 +586,14 @@ impl Progress {
             // Check if task just finished.
             if let Some(t) = task.total {
                 if task.completed >= t && task.finished_time.is_none() {
-                    task.finished_speed = task.speed();
+                    task.finished_speed = task.speed_estimate();
                     task.finished_time = Some(now);
                 }
             }
+
+            if let Some(parent) = task.parent {
+                self.roll_up(parent);
+            }
         }
     }
 
@@ -408,8 +621,24 @@ impl Progress {
     }
 
     /// Remove a task from tracking entirely.
+    ///
+    /// Child tasks are removed along with their parent, and the removed
+    /// task's own parent is recomputed from its remaining children.
     pub fn remove_task(&mut self, task_id: TaskId) {
-        self.tasks.retain(|t| t.id != task_id);
+        let parent = self.get_task(task_id).and_then(|t| t.parent);
+        let mut removed = vec![task_id];
+        while let Some(id) = removed.pop() {
+            removed.extend(
+                self.tasks
+                    .iter()
+                    .filter(|t| t.parent == Some(id))
+                    .map(|t| t.id),
+            );
+            self.tasks.retain(|t| t.id != id);
+        }
+        if let Some(parent) = parent {
+            self.roll_up(parent);
+        }
     }
 
     /// Get a reference to a task by ID.
@@ -451,7 +680,10 @@ impl Progress {
             task.stop_time = None;
             task.finished_time = None;
             task.finished_speed = None;
-            task.samples.clear();
+            task.clear_samples();
+        }
+        if let Some(parent) = self.get_task(task_id).and_then(|t| t.parent) {
+            self.roll_up(parent);
         }
     }
 
@@ -467,14 +699,37 @@ impl Progress {
 
     // -- Console convenience ------------------------------------------------
 
-    /// Print a renderable to the underlying console.
+    /// Print a renderable as a permanent line above the progress bars.
+    ///
+    /// While the display is running the bars are erased, the renderable is
+    /// printed and the bars are redrawn below it, so output never corrupts
+    /// the live area.
     pub fn print(&self, renderable: &dyn Renderable) {
-        self.live.console_mut().print(renderable);
+        self.live.print(renderable);
     }
 
-    /// Log a message to the underlying console.
+    /// Print a line of (markup-enabled) text above the progress bars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::Progress;
+    ///
+    /// let mut progress = Progress::new(Progress::default_columns())
+    ///     .with_disable(true);
+    /// let task = progress.add_task("Building", Some(2.0));
+    /// progress.advance(task, 1.0);
+    /// progress.println("[green]✓[/green] compiled core");
+    /// ```
+    pub fn println(&self, message: &str) {
+        let text = self.live.console().render_str(message, None, None, None);
+        self.live.print(&text);
+    }
+
+    /// Log a timestamped message above the progress bars.
+    #[track_caller]
     pub fn log(&self, message: &str) {
-        self.live.console_mut().log(message);
+        self.live.log(message);
     }
 
     // -- Iterator tracking --------------------------------------------------
@@ -483,7 +738,8 @@ impl Progress {
     ///
     /// Creates a task with the given description and optional total,
     /// then returns a [`ProgressTracker`] iterator that advances the
-    /// task by 1.0 on each call to `next()`.
+    /// task by 1.0 on each call to `next()`. When `total` is `None` it is
+    /// inferred from the iterator's [`size_hint`](Iterator::size_hint).
     ///
     /// # Examples
     ///
@@ -504,9 +760,11 @@ impl Progress {
     where
         I: IntoIterator,
     {
+        let inner = iter.into_iter();
+        let total = total.or_else(|| total_from_size_hint(&inner));
         let task_id = self.add_task(description, total);
         ProgressTracker {
-            inner: iter.into_iter(),
+            inner,
             progress: self,
             task_id,
         }
@@ -522,6 +780,16 @@ impl Progress {
         self.live.start();
     }
 
+    /// Start the live display with refreshes driven by a Tokio task instead
+    /// of a background thread. See [`Live::start_async`].
+    #[cfg(feature = "async")]
+    pub fn start_async(&mut self) {
+        if self.disable {
+            return;
+        }
+        self.live.start_async();
+    }
+
     /// Stop the live display.
     pub fn stop(&mut self) {
         if self.disable {
@@ -535,7 +803,8 @@ impl Progress {
         if self.disable {
             return;
         }
-        let table_text = self.render_tasks_text();
+        let format = self.live.console().format_context().clone();
+        let table_text = self.render_tasks_text(&format);
         self.live.update_renderable(table_text, true);
     }
 
@@ -561,18 +830,23 @@ impl Progress {
         }
 
         // Add a row for each visible task.
+        let format = self.live.console().format_context().clone();
+        let now = (self.get_time)();
         for task in &self.tasks {
             if !task.visible {
                 continue;
             }
-            let cells: Vec<String> = self
+            let mut cells: Vec<String> = self
                 .columns
                 .iter()
                 .map(|col| {
-                    let text = col.render(task);
+                    let text = col.render_formatted(task, now, &format);
                     text.plain().to_string()
                 })
                 .collect();
+            if let Some(first) = cells.first_mut() {
+                first.insert_str(0, &tree_prefix(task.depth));
+            }
             let cell_refs: Vec<&str> = cells.iter().map(|s| s.as_str()).collect();
             table.add_row(&cell_refs);
         }
@@ -583,7 +857,7 @@ impl Progress {
     /// Render the tasks table as a single Text for the live display.
     ///
     /// Preserves styled spans from each column render (bar colors, etc.).
-    fn render_tasks_text(&self) -> Text {
+    fn render_tasks_text(&self, format: &FormatContext) -> Text {
         let visible_tasks: Vec<&Task> = self.tasks.iter().filter(|t| t.visible).collect();
         if visible_tasks.is_empty() {
             return Text::empty();
@@ -591,16 +865,23 @@ impl Progress {
 
         let separator = Text::new(" ", Style::null());
         let mut result = Text::empty();
+        let now = (self.get_time)();
 
         for (i, task) in visible_tasks.iter().enumerate() {
             if i > 0 {
                 result.append_str("\n", None);
             }
+            if task.depth > 0 {
+                result.append_str(
+                    &tree_prefix(task.depth),
+                    Some(Style::parse("dim").unwrap_or_else(|_| Style::null())),
+                );
+            }
             for (j, col) in self.columns.iter().enumerate() {
                 if j > 0 {
                     result.append_text(&separator);
                 }
-                let rendered = col.render(task);
+                let rendered = col.render_formatted(task, now, format);
                 result.append_text(&rendered);
             }
         }
@@ -609,9 +890,18 @@ impl Progress {
     }
 }
 
+/// Indentation and tree guide drawn before a task nested `depth` levels deep.
+fn tree_prefix(depth: usize) -> String {
+    if depth == 0 {
+        String::new()
+    } else {
+        format!("{}└─ ", "   ".repeat(depth - 1))
+    }
+}
+
 impl Renderable for Progress {
-    fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
-        let text = self.render_tasks_text();
+    fn gilt_console(&self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let text = self.render_tasks_text(options.format());
         text.render()
     }
 }
@@ -725,6 +1015,10 @@ impl<I> Drop for TrackIterator<I> {
 
 /// Convenience function to wrap an iterator with a progress display.
 ///
+/// When `total` is `None` it is inferred from the iterator's
+/// [`size_hint`](Iterator::size_hint), so ranges, slices and other
+/// [`ExactSizeIterator`]s get a determinate bar without passing a total.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -738,7 +1032,15 @@ pub fn track<I>(iter: I, description: &str, total: Option<f64>) -> TrackIterator
 where
     I: IntoIterator,
 {
-    TrackIterator::new(iter.into_iter(), description, total)
+    let iter = iter.into_iter();
+    let total = total.or_else(|| total_from_size_hint(&iter));
+    TrackIterator::new(iter, description, total)
+}
+
+/// The total to show for an iterator: its upper length bound, which is the
+/// exact length for an [`ExactSizeIterator`].
+pub(crate) fn total_from_size_hint<I: Iterator>(iter: &I) -> Option<f64> {
+    iter.size_hint().1.map(|n| n as f64)
 }
 
 // ---------------------------------------------------------------------------
@@ -750,8 +1052,11 @@ where
 ///
 /// The progress bar total is inferred from
 /// [`size_hint()`](Iterator::size_hint) when an upper bound is available
-/// (e.g. `Vec::iter()`, `Range`). For iterators without a known length the
-/// bar runs in indeterminate mode.
+/// (e.g. `Vec::iter()`, `Range`, or any other [`ExactSizeIterator`]). For
+/// iterators without a known length the bar runs in indeterminate mode.
+///
+/// Use [`progress_with`](ProgressIteratorExt::progress_with) to advance a
+/// task of a [`Progress`] you already display instead of a private bar.
 ///
 /// # Examples
 ///
@@ -778,17 +1083,50 @@ pub trait ProgressIteratorExt: Iterator + Sized {
 
     /// Wrap this iterator with a progress bar, explicitly setting the total.
     fn progress_with_total(self, description: &str, total: f64) -> ProgressIter<Self>;
+
+    /// Advance `task` of an existing [`Progress`] by one per yielded item.
+    ///
+    /// If the task has no total yet, it is set from `size_hint()` when an
+    /// upper bound is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::{Progress, ProgressIteratorExt};
+    ///
+    /// let mut progress = Progress::new(Progress::default_columns()).with_disable(true);
+    /// let task = progress.add_task("Parsing", None);
+    /// let sum: i32 = (1..=4).progress_with(&mut progress, task).sum();
+    /// assert_eq!(sum, 10);
+    /// let task = progress.get_task(task).unwrap();
+    /// assert_eq!((task.completed, task.total), (4.0, Some(4.0)));
+    /// ```
+    fn progress_with(self, progress: &mut Progress, task: TaskId) -> ProgressTracker<'_, Self>;
 }
 
 impl<I: Iterator> ProgressIteratorExt for I {
     fn progress(self, description: &str) -> ProgressIter<Self> {
-        let total = self.size_hint().1.map(|n| n as f64);
+        let total = total_from_size_hint(&self);
         ProgressIter::new(self, description, total)
     }
 
     fn progress_with_total(self, description: &str, total: f64) -> ProgressIter<Self> {
         ProgressIter::new(self, description, Some(total))
     }
+
+    fn progress_with(self, progress: &mut Progress, task: TaskId) -> ProgressTracker<'_, Self> {
+        let has_total = progress.get_task(task).is_some_and(|t| t.total.is_some());
+        if !has_total {
+            if let Some(total) = total_from_size_hint(&self) {
+                progress.update(task, None, Some(total), None, None, None);
+            }
+        }
+        ProgressTracker {
+            inner: self,
+            progress,
+            task_id: task,
+        }
+    }
 }
 
 /// An iterator adapter that displays a live progress bar while yielding
@@ -925,3 +1263,77 @@ impl<R: Read> Read for ProgressReader<R> {
         Ok(n)
     }
 }
+
+// ---------------------------------------------------------------------------
+// ProgressWriter
+// ---------------------------------------------------------------------------
+
+/// A writer wrapper that calls a callback on each write for progress tracking.
+///
+/// The counterpart of [`ProgressReader`] for uploads and copies: it wraps any
+/// [`Write`] implementor and invokes a user-supplied callback with the number
+/// of bytes accepted by each call to [`write`](Write::write). The callback is
+/// typically a closure that calls [`Progress::advance`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::io::Write;
+/// use std::rc::Rc;
+/// use gilt::progress::{Progress, ProgressWriter};
+///
+/// let progress = Rc::new(RefCell::new(
+///     Progress::new(Progress::default_columns()).with_disable(true),
+/// ));
+/// let task = progress.borrow_mut().add_task("Copying", Some(11.0));
+/// let handle = Rc::clone(&progress);
+/// let mut writer = ProgressWriter::new(Vec::new(), move |n| {
+///     handle.borrow_mut().advance(task, n as f64);
+/// });
+/// writer.write_all(b"hello world").unwrap();
+/// assert_eq!(writer.into_inner(), b"hello world");
+/// assert_eq!(progress.borrow().get_task(task).unwrap().completed, 11.0);
+/// ```
+pub struct ProgressWriter<W> {
+    inner: W,
+    callback: Box<dyn FnMut(usize)>,
+    total_written: usize,
+}
+
+impl<W> ProgressWriter<W> {
+    /// Wrap a writer with a progress callback.
+    ///
+    /// The `callback` is invoked after every successful write with the
+    /// number of bytes that were written.
+    pub fn new(inner: W, callback: impl FnMut(usize) + 'static) -> Self {
+        ProgressWriter {
+            inner,
+            callback: Box::new(callback),
+            total_written: 0,
+        }
+    }
+
+    /// Total bytes written so far through this wrapper.
+    pub fn total_written(&self) -> usize {
+        self.total_written
+    }
+
+    /// Consume the wrapper and return the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.total_written += n;
+        (self.callback)(n);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -5,6 +5,8 @@
 //! time, speed), live-updating display, and iterator wrapping.
 
 mod core;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod task;
 
 pub mod columns;
@@ -12,9 +14,12 @@ pub mod columns;
 // Re-export all public types from submodules for backward compatibility
 pub use core::{
     track, DownloadColumn, Progress, ProgressColumn, ProgressIter, ProgressIteratorExt,
-    ProgressReader, ProgressTracker, RenderableColumn, TrackIterator, TransferSpeedColumn,
+    ProgressReader, ProgressTracker, ProgressWriter, RenderableColumn, TrackIterator,
+    TransferSpeedColumn,
 };
-pub use task::{format_time, ProgressSample, Task, TaskId};
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelProgressExt;
+pub use task::{format_time, ProgressSample, SpeedEstimator, Task, TaskId};
 
 // Re-export column types
 pub use columns::{
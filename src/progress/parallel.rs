@@ -0,0 +1,131 @@
+//! Rayon bridge -- `.par_progress()` adapter for parallel iterators.
+//!
+//! Enabled by the `rayon` feature. Items are counted from whichever worker
+//! thread yields them, so the bar tracks the combined throughput of the pool.
+//! Workers only bump an atomic counter; a refresh thread moves the count into
+//! the display, so the bar never serializes the pipeline.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::ParallelIterator;
+
+use crate::progress::Progress;
+
+/// How often the refresh thread updates the bar.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Extension trait that adds [`.par_progress()`](ParallelProgressExt::par_progress)
+/// to any rayon [`ParallelIterator`].
+///
+/// The total is taken from the iterator's length when it is known (e.g.
+/// `into_par_iter()` on a `Vec` or a range); otherwise the bar runs in
+/// indeterminate mode.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::progress::ParallelProgressExt;
+/// use rayon::prelude::*;
+///
+/// let squares: Vec<u64> = (0..1000u64)
+///     .into_par_iter()
+///     .par_progress("Squaring")
+///     .map(|n| n * n)
+///     .collect();
+/// assert_eq!(squares.len(), 1000);
+/// ```
+pub trait ParallelProgressExt: ParallelIterator {
+    /// Wrap this parallel iterator with a live progress bar that advances
+    /// once per item and stops when the iterator is consumed or dropped.
+    fn par_progress(self, description: &str) -> impl ParallelIterator<Item = Self::Item>;
+}
+
+impl<P: ParallelIterator> ParallelProgressExt for P {
+    fn par_progress(self, description: &str) -> impl ParallelIterator<Item = Self::Item> {
+        ParProgress {
+            base: self,
+            description: description.to_string(),
+        }
+    }
+}
+
+/// The adapter returned by `par_progress`. The display runs only while the
+/// iterator is being driven.
+struct ParProgress<P> {
+    base: P,
+    description: String,
+}
+
+impl<P: ParallelIterator> ParallelIterator for ParProgress<P> {
+    type Item = P::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let count = Arc::new(AtomicU64::new(0));
+        let _display = Display::start(&self.description, self.base.opt_len(), Arc::clone(&count));
+        self.base
+            .map(move |item| {
+                count.fetch_add(1, Ordering::Relaxed);
+                item
+            })
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.base.opt_len()
+    }
+}
+
+/// The live display of one `par_progress` run.
+///
+/// A refresh thread owns the [`Progress`] and copies the shared item count
+/// into it on every tick. Dropping the display, when the iteration ends or
+/// unwinds, shows the final count and stops it.
+struct Display {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Display {
+    fn start(description: &str, total: Option<usize>, count: Arc<AtomicU64>) -> Self {
+        let mut progress = Progress::new(Progress::default_columns()).with_auto_refresh(true);
+        let task_id = progress.add_task(description, total.map(|n| n as f64));
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = thread::spawn(move || {
+            progress.start();
+            let mut shown = 0;
+            loop {
+                let tick = stopped.recv_timeout(REFRESH_INTERVAL);
+                let current = count.load(Ordering::Relaxed);
+                progress.advance(task_id, (current - shown) as f64);
+                shown = current;
+                progress.refresh();
+                if !matches!(tick, Err(RecvTimeoutError::Timeout)) {
+                    break;
+                }
+            }
+            progress.stop();
+        });
+        Display {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for Display {
+    fn drop(&mut self) {
+        // Closing the channel wakes the refresh thread for its last update.
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
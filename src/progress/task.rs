@@ -23,6 +23,27 @@ pub struct ProgressSample {
     pub completed: f64,
 }
 
+// ---------------------------------------------------------------------------
+// SpeedEstimator
+// ---------------------------------------------------------------------------
+
+/// Strategy used by [`Task::speed_estimate`] to turn progress samples into a speed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SpeedEstimator {
+    /// Average speed over the sliding sample window (see
+    /// [`Progress::with_speed_estimate_period`](crate::progress::Progress::with_speed_estimate_period)).
+    #[default]
+    Window,
+    /// Exponential moving average of the instantaneous speed. A sample
+    /// `half_life` seconds old carries half the weight of a fresh one, so
+    /// longer half-lives smooth out bursty workloads at the cost of reacting
+    /// more slowly to real changes.
+    Ema {
+        /// Half-life of a sample's weight, in seconds.
+        half_life: f64,
+    },
+}
+
 // ---------------------------------------------------------------------------
 // Task
 // ---------------------------------------------------------------------------
@@ -55,8 +76,18 @@ pub struct Task {
     pub finished_speed: Option<f64>,
     /// Sliding window of samples for speed calculation.
     pub samples: VecDeque<ProgressSample>,
+    /// Parent task whose progress aggregates this task (None for top-level tasks).
+    pub parent: Option<TaskId>,
+    /// Nesting depth: 0 for top-level tasks, parent depth + 1 for children.
+    pub depth: usize,
+    /// Strategy used by [`speed_estimate`](Self::speed_estimate).
+    pub speed_estimator: SpeedEstimator,
     /// All recorded progress samples.
     progress: Vec<ProgressSample>,
+    /// Current exponential moving average of the speed.
+    ema_speed: Option<f64>,
+    /// Sample the moving average was last advanced from.
+    ema_anchor: Option<ProgressSample>,
 }
 
 impl Task {
@@ -74,7 +105,12 @@ impl Task {
             finished_time: None,
             finished_speed: None,
             samples: VecDeque::new(),
+            parent: None,
+            depth: 0,
+            speed_estimator: SpeedEstimator::Window,
             progress: Vec::new(),
+            ema_speed: None,
+            ema_anchor: None,
         }
     }
 
@@ -95,8 +131,14 @@ impl Task {
 
     /// Elapsed time in seconds since the task was started.
     pub fn elapsed(&self) -> Option<f64> {
+        self.elapsed_at(current_time_secs())
+    }
+
+    /// Seconds elapsed between the start of the task and `now` (or the stop
+    /// time, if the task was stopped).
+    pub fn elapsed_at(&self, now: f64) -> Option<f64> {
         self.start_time.map(|start| {
-            let end = self.stop_time.unwrap_or_else(current_time_secs);
+            let end = self.stop_time.unwrap_or(now);
             (end - start).max(0.0)
         })
     }
@@ -130,13 +172,48 @@ impl Task {
         Some(completed_delta / time_delta)
     }
 
-    /// Estimated time remaining in seconds, based on current speed.
+    /// Speed in steps per second according to the task's
+    /// [`speed_estimator`](Self::speed_estimator).
+    ///
+    /// With [`SpeedEstimator::Window`] this is the same as [`speed`](Self::speed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::progress::{Progress, SpeedEstimator};
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let clock = Arc::new(AtomicU64::new(0));
+    /// let time = Arc::clone(&clock);
+    /// let mut progress = Progress::new(Progress::default_columns())
+    ///     .with_get_time(move || time.load(Ordering::SeqCst) as f64)
+    ///     .with_speed_estimator(SpeedEstimator::Ema { half_life: 5.0 });
+    /// let task = progress.add_task("download", Some(100.0));
+    /// for second in 1..=3 {
+    ///     clock.store(second, Ordering::SeqCst);
+    ///     progress.advance(task, 10.0);
+    /// }
+    /// let speed = progress.get_task(task).unwrap().speed_estimate().unwrap();
+    /// assert!((speed - 10.0).abs() < 1e-9);
+    /// ```
+    pub fn speed_estimate(&self) -> Option<f64> {
+        if self.finished() {
+            return self.finished_speed;
+        }
+        match self.speed_estimator {
+            SpeedEstimator::Window => self.speed(),
+            SpeedEstimator::Ema { .. } => self.ema_speed,
+        }
+    }
+
+    /// Estimated time remaining in seconds, based on [`speed_estimate`](Self::speed_estimate).
     pub fn time_remaining(&self) -> Option<f64> {
         if self.finished() {
             return Some(0.0);
         }
         let remaining = self.remaining()?;
-        let speed = self.speed()?;
+        let speed = self.speed_estimate()?;
         if speed <= 0.0 {
             return None;
         }
@@ -148,6 +225,9 @@ impl Task {
     /// Samples older than `speed_estimate_period` seconds are pruned
     /// from the sliding window.
     pub(crate) fn record_sample(&mut self, timestamp: f64, speed_estimate_period: f64) {
+        if let SpeedEstimator::Ema { half_life } = self.speed_estimator {
+            self.update_ema(timestamp, half_life);
+        }
         self.samples.push_back(ProgressSample {
             timestamp,
             completed: self.completed,
@@ -167,6 +247,41 @@ impl Task {
             }
         }
     }
+
+    /// Fold the progress made since the last anchor sample into the moving
+    /// average. Updates sharing a timestamp accumulate until time advances.
+    fn update_ema(&mut self, timestamp: f64, half_life: f64) {
+        let Some(anchor) = &self.ema_anchor else {
+            self.ema_anchor = Some(ProgressSample {
+                timestamp,
+                completed: self.completed,
+            });
+            return;
+        };
+        let time_delta = timestamp - anchor.timestamp;
+        if time_delta <= 0.0 {
+            return;
+        }
+        let instant = (self.completed - anchor.completed) / time_delta;
+        self.ema_speed = Some(match self.ema_speed {
+            Some(ema) if half_life > 0.0 => {
+                let alpha = 1.0 - 0.5_f64.powf(time_delta / half_life);
+                ema + alpha * (instant - ema)
+            }
+            _ => instant,
+        });
+        self.ema_anchor = Some(ProgressSample {
+            timestamp,
+            completed: self.completed,
+        });
+    }
+
+    /// Discard all speed samples, e.g. when the task is reset.
+    pub(crate) fn clear_samples(&mut self) {
+        self.samples.clear();
+        self.ema_speed = None;
+        self.ema_anchor = None;
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -382,6 +382,7 @@ mod tests {
             highlight: None,
             markup: None,
             height: None,
+            format: Default::default(),
         }
     }
 
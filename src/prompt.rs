@@ -1,12 +1,23 @@
 //! Interactive prompt module for styled user input with validation, choices, and defaults.
 //!
 //! Port of Python's rich/prompt.py. Provides `Prompt` for string input,
-//! `confirm()` for yes/no questions, `ask_int()` for integer input, and
-//! `ask_float()` for float input.
+//! `Prompt::<T>::typed` for answers parsed with `FromStr` and checked by
+//! validators, [`Confirm`] and `confirm()` for yes/no questions, `ask_int()`
+//! for integer input, and `ask_float()` for float input.
+//!
+//! [`Select`] and [`MultiSelect`] read a numbered choice by default; with the
+//! `input` feature their `interact()` methods instead show a highlighted list
+//! navigated with the arrow keys and filtered as you type.
 
 use std::io::{self, BufRead, Write as IoWrite};
+use std::str::FromStr;
 
 use crate::console::Console;
+#[cfg(feature = "input")]
+use crate::input::{Key, KeyReader, RawMode};
+#[cfg(feature = "input")]
+use crate::live_render::LiveRender;
+use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::Text;
 
@@ -79,19 +90,38 @@ impl std::error::Error for InvalidResponse {}
 // Prompt
 // ---------------------------------------------------------------------------
 
+/// A check run on a parsed prompt answer; `Err` holds the message shown
+/// before asking again.
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
 /// A styled interactive prompt for user input with validation, choices, and defaults.
 ///
+/// `Prompt` returns the answer as a `String` by default. A typed prompt,
+/// such as `Prompt::<u16>::new("Port?")`, parses the answer with
+/// [`FromStr`] and asks again with a styled error message until it parses
+/// and passes every validator.
+///
 /// # Examples
 ///
 /// ```no_run
 /// use gilt::prompt::Prompt;
 ///
-/// let name = Prompt::new("Enter your name").ask();
-/// let fruit = Prompt::new("Pick a fruit")
+/// let name: String = Prompt::new("Enter your name").ask();
+/// let fruit: String = Prompt::new("Pick a fruit")
 ///     .with_choices(vec!["apple".into(), "orange".into(), "pear".into()])
 ///     .ask();
+/// let port = Prompt::<u16>::new("Port?")
+///     .with_default(8080)
+///     .with_validator(|port| {
+///         if *port >= 1024 {
+///             Ok(())
+///         } else {
+///             Err("Please choose an unprivileged port".into())
+///         }
+///     })
+///     .ask();
 /// ```
-pub struct Prompt {
+pub struct Prompt<T = String> {
     /// The prompt text (parsed from markup).
     pub prompt_text: Text,
     /// Whether to hide input (password mode).
@@ -104,7 +134,7 @@ pub struct Prompt {
     pub show_default: bool,
     /// Whether to display the available choices in the prompt.
     pub show_choices: bool,
-    /// Optional default value returned when the user enters empty input.
+    /// Optional default answer used when the user enters empty input.
     pub default: Option<String>,
     /// Optional list of tab-completion candidates.
     ///
@@ -115,10 +145,14 @@ pub struct Prompt {
     pub completions: Option<Vec<String>>,
     /// The console used for rendering prompt text.
     console: Console,
+    /// Checks run on the parsed answer, in order.
+    validators: Vec<Validator<T>>,
 }
 
-impl Prompt {
-    /// Create a new prompt with the given text.
+impl<T: FromStr> Prompt<T> {
+    /// Create a new prompt with the given text. The answer is parsed as `T`,
+    /// e.g. `Prompt::<u16>::new("Port?")`; annotate `String` where nothing
+    /// else fixes the answer type.
     ///
     /// The prompt string is parsed as Rich markup.
     pub fn new(prompt: &str) -> Self {
@@ -134,6 +168,7 @@ impl Prompt {
             default: None,
             completions: None,
             console: Console::new(),
+            validators: Vec::new(),
         }
     }
 
@@ -158,13 +193,30 @@ impl Prompt {
         self
     }
 
-    /// Set the default value.
+    /// Set the default answer, used when the user enters nothing.
+    ///
+    /// The default is shown in the prompt and goes through the same parsing
+    /// and validation as typed input.
     #[must_use]
-    pub fn with_default(mut self, default: &str) -> Self {
+    pub fn with_default(mut self, default: impl ToString) -> Self {
         self.default = Some(default.to_string());
         self
     }
 
+    /// Add a check on the parsed answer (builder pattern).
+    ///
+    /// When the check returns `Err(message)`, the message is shown in the
+    /// `prompt.invalid` style and the question is asked again. Validators run
+    /// in the order they were added.
+    #[must_use]
+    pub fn with_validator(
+        mut self,
+        validator: impl Fn(&T) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
     /// Set whether choice matching is case-sensitive.
     #[must_use]
     pub fn with_case_sensitive(mut self, case: bool) -> Self {
@@ -265,11 +317,62 @@ impl Prompt {
         }
     }
 
-    /// Read user input from the provided reader, printing the prompt to stdout.
+    /// Turn one line of input into an answer: apply the default, check the
+    /// choices, parse, and run the validators.
     ///
-    /// This method is the testable core of `ask()`. Tests can inject mock input
-    /// via `std::io::Cursor`.
-    pub fn ask_with_input<R: BufRead>(&self, input: &mut R) -> String {
+    /// Input that does not parse as typed is parsed again without surrounding
+    /// whitespace, so `" 42 "` is a valid number.
+    pub fn process_response(&self, value: &str) -> Result<T, InvalidResponse> {
+        let invalid = |message: String| InvalidResponse { message };
+
+        let mut value = value.to_string();
+        let mut from_default = false;
+        if value.trim().is_empty() {
+            if let Some(ref default) = self.default {
+                value = default.clone();
+                from_default = true;
+            }
+        }
+
+        if !from_default && self.choices.is_some() {
+            if !self.check_choice(&value) {
+                return Err(invalid(
+                    "Please select one of the available options".to_string(),
+                ));
+            }
+            value = self.resolve_choice(&value);
+        }
+
+        let parsed = value
+            .parse::<T>()
+            .or_else(|_| value.trim().parse::<T>())
+            .map_err(|_| invalid(format!("Please enter a valid {}", type_label::<T>())))?;
+
+        for validator in &self.validators {
+            validator(&parsed).map_err(invalid)?;
+        }
+        Ok(parsed)
+    }
+
+    /// Show an invalid-response message in the `prompt.invalid` style.
+    fn report(&self, error: &InvalidResponse) {
+        let style = self
+            .console
+            .get_style("prompt.invalid")
+            .unwrap_or_else(|_| Style::null());
+        let message = self
+            .console
+            .render_buffer(&[Segment::styled(&error.message, style)]);
+        eprintln!("{}", message);
+    }
+
+    /// Read an answer from the provided reader, printing the prompt to
+    /// stdout and asking again until the answer is valid.
+    ///
+    /// Returns an error only when the input ends without a valid answer.
+    /// This method is the testable core of `ask()`. Tests can inject mock
+    /// input via `std::io::Cursor`.
+    pub fn try_ask_with_input<R: BufRead>(&self, input: &mut R) -> Result<T, InvalidResponse> {
         loop {
             let prompt = self.make_prompt();
             let prompt_str = prompt.plain().to_string();
@@ -277,46 +380,30 @@ impl Prompt {
             let _ = io::stdout().flush();
 
             let mut line = String::new();
-            match input.read_line(&mut line) {
-                Ok(0) => {
-                    // EOF: if there's a default, return it; otherwise keep the empty string
-                    if let Some(ref default) = self.default {
-                        return default.clone();
-                    }
-                    return String::new();
-                }
-                Ok(_) => {}
-                Err(_) => {
-                    if let Some(ref default) = self.default {
-                        return default.clone();
-                    }
-                    return String::new();
-                }
-            }
-
-            let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
-            let value = trimmed.to_string();
-
-            // Empty input: return default if available
-            if value.trim().is_empty() {
-                if let Some(ref default) = self.default {
-                    return default.clone();
-                }
+            let read = input.read_line(&mut line);
+            if matches!(read, Ok(0) | Err(_)) {
+                // End of input: fall back to the default, or an empty answer.
+                return self.process_response("");
             }
 
-            // Validate against choices
-            if self.choices.is_some() {
-                if !self.check_choice(&value) {
-                    // Invalid choice — print error and loop
-                    eprintln!("Please select one of the available options");
-                    continue;
-                }
-                return self.resolve_choice(&value);
+            let value = line.trim_end_matches('\n').trim_end_matches('\r');
+            match self.process_response(value) {
+                Ok(answer) => return Ok(answer),
+                Err(error) => self.report(&error),
             }
-
-            return value;
         }
     }
+}
+
+impl<T: FromStr + Default> Prompt<T> {
+    /// Read an answer from the provided reader, printing the prompt to stdout.
+    ///
+    /// Like [`try_ask_with_input`](Self::try_ask_with_input), but when the
+    /// input ends without a valid answer, returns `T::default()` (an empty
+    /// string for a `Prompt<String>`).
+    pub fn ask_with_input<R: BufRead>(&self, input: &mut R) -> T {
+        self.try_ask_with_input(input).unwrap_or_default()
+    }
 
     /// Ask the user for input, reading from standard input.
     ///
@@ -325,7 +412,7 @@ impl Prompt {
     /// is not visible on screen. When the `readline` feature is enabled and
     /// [`completions`](Prompt::completions) is set, the prompt uses `rustyline`
     /// to provide interactive tab-completion.
-    pub fn ask(&self) -> String {
+    pub fn ask(&self) -> T {
         #[cfg(feature = "interactive")]
         if self.password {
             return self.ask_password();
@@ -351,7 +438,7 @@ impl Prompt {
 
     /// Readline-based input loop with tab-completion.
     #[cfg(feature = "readline")]
-    fn ask_readline(&self) -> String {
+    fn ask_readline(&self) -> T {
         let candidates = self.completions.clone().unwrap_or_default();
         let helper = ListCompleter { candidates };
         let config = rustyline::Config::builder()
@@ -366,43 +453,17 @@ impl Prompt {
 
             match editor.readline(&prompt_str) {
                 Ok(line) => {
-                    let value = line
-                        .trim_end_matches('\n')
-                        .trim_end_matches('\r')
-                        .to_string();
-
-                    // Empty input: return default if available
-                    if value.trim().is_empty() {
-                        if let Some(ref default) = self.default {
-                            return default.clone();
-                        }
-                    }
-
-                    // Validate against choices
-                    if self.choices.is_some() {
-                        if !self.check_choice(&value) {
-                            eprintln!("Please select one of the available options");
-                            continue;
-                        }
-                        return self.resolve_choice(&value);
-                    }
-
-                    return value;
-                }
-                Err(rustyline::error::ReadlineError::Eof) => {
-                    if let Some(ref default) = self.default {
-                        return default.clone();
+                    let value = line.trim_end_matches('\n').trim_end_matches('\r');
+                    match self.process_response(value) {
+                        Ok(answer) => return answer,
+                        Err(error) => self.report(&error),
                     }
-                    return String::new();
                 }
                 Err(rustyline::error::ReadlineError::Interrupted) => {
-                    return String::new();
+                    return T::default();
                 }
                 Err(_) => {
-                    if let Some(ref default) = self.default {
-                        return default.clone();
-                    }
-                    return String::new();
+                    return self.process_response("").unwrap_or_default();
                 }
             }
         }
@@ -410,7 +471,7 @@ impl Prompt {
 
     /// Password input loop — reads without terminal echo using `rpassword`.
     #[cfg(feature = "interactive")]
-    fn ask_password(&self) -> String {
+    fn ask_password(&self) -> T {
         loop {
             let prompt = self.make_prompt();
             let prompt_str = prompt.plain().to_string();
@@ -419,31 +480,123 @@ impl Prompt {
 
             let value = match rpassword::read_password() {
                 Ok(v) => v,
-                Err(_) => {
-                    if let Some(ref default) = self.default {
-                        return default.clone();
-                    }
-                    return String::new();
-                }
+                Err(_) => return self.process_response("").unwrap_or_default(),
             };
 
-            // Empty input: return default if available
-            if value.trim().is_empty() {
-                if let Some(ref default) = self.default {
-                    return default.clone();
-                }
+            match self.process_response(&value) {
+                Ok(answer) => return answer,
+                Err(error) => self.report(&error),
             }
+        }
+    }
+}
 
-            // Validate against choices
-            if self.choices.is_some() {
-                if !self.check_choice(&value) {
-                    eprintln!("Please select one of the available options");
-                    continue;
-                }
-                return self.resolve_choice(&value);
+/// The name used for `T` in "Please enter a valid ..." messages: the last
+/// path segment of its type name, with integer and float types described in
+/// words as rich does.
+fn type_label<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let short = name.rsplit("::").next().unwrap_or(name);
+    match short {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => "integer number".to_string(),
+        "f32" | "f64" => "number".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Confirm
+// ---------------------------------------------------------------------------
+
+/// A yes/no question with an optional default answer.
+///
+/// Accepts `y`/`yes` and `n`/`no` in any case, and asks again with a styled
+/// error message otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::prompt::Confirm;
+/// use std::io::Cursor;
+///
+/// let overwrite = Confirm::new("Overwrite file?").with_default(false);
+/// assert!(!overwrite.ask_with_input(&mut Cursor::new("\n")));
+/// assert!(overwrite.ask_with_input(&mut Cursor::new("maybe\nYes\n")));
+/// ```
+pub struct Confirm {
+    prompt: Prompt,
+    /// The answer used when the user enters nothing.
+    pub default: Option<bool>,
+}
+
+impl Confirm {
+    /// Create a new confirmation question. The prompt string is parsed as
+    /// Rich markup.
+    pub fn new(prompt: &str) -> Self {
+        Confirm {
+            prompt: Prompt::new(prompt)
+                .with_choices(vec!["y".into(), "n".into()])
+                .with_case_sensitive(false),
+            default: None,
+        }
+    }
+
+    /// Set the answer used when the user enters nothing (builder pattern).
+    #[must_use]
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self.prompt = self.prompt.with_default(if default { "y" } else { "n" });
+        self
+    }
+
+    /// Set the console used to style error messages (builder pattern).
+    #[must_use]
+    pub fn with_console(mut self, console: Console) -> Self {
+        self.prompt = self.prompt.with_console(console);
+        self
+    }
+
+    /// Build the prompt `Text`, e.g. `"Overwrite file? [y/n] (n): "`.
+    pub fn make_prompt(&self) -> Text {
+        self.prompt.make_prompt()
+    }
+
+    /// Turn one line of input into an answer.
+    pub fn process_response(&self, value: &str) -> Result<bool, InvalidResponse> {
+        match value.trim().to_lowercase().as_str() {
+            "y" | "yes" => Ok(true),
+            "n" | "no" => Ok(false),
+            "" if self.default.is_some() => Ok(self.default.unwrap_or(false)),
+            _ => Err(InvalidResponse {
+                message: "Please enter Y or N".to_string(),
+            }),
+        }
+    }
+
+    /// Ask the question, reading from standard input.
+    pub fn ask(&self) -> bool {
+        self.ask_with_input(&mut io::stdin().lock())
+    }
+
+    /// Testable version of `ask()` that reads from a provided input source.
+    ///
+    /// When the input ends, returns the default, or `false` without one.
+    pub fn ask_with_input<R: BufRead>(&self, input: &mut R) -> bool {
+        loop {
+            print!("{}", self.make_prompt().plain());
+            let _ = io::stdout().flush();
+
+            let mut line = String::new();
+            match input.read_line(&mut line) {
+                Ok(0) | Err(_) => return self.default.unwrap_or(false),
+                Ok(_) => {}
             }
 
-            return value;
+            match self.process_response(&line) {
+                Ok(answer) => return answer,
+                Err(error) => self.prompt.report(&error),
+            }
         }
     }
 }
@@ -462,34 +615,7 @@ pub fn confirm(prompt: &str) -> bool {
 
 /// Testable version of `confirm()` that reads from a provided input source.
 pub fn confirm_with_input<R: BufRead>(prompt: &str, input: &mut R) -> bool {
-    let p = Prompt::new(prompt)
-        .with_choices(vec!["y".into(), "n".into()])
-        .with_case_sensitive(false)
-        .with_show_choices(true);
-
-    loop {
-        let prompt_text = p.make_prompt();
-        let prompt_str = prompt_text.plain().to_string();
-        print!("{}", prompt_str);
-        let _ = io::stdout().flush();
-
-        let mut line = String::new();
-        match input.read_line(&mut line) {
-            Ok(0) => return false,
-            Ok(_) => {}
-            Err(_) => return false,
-        }
-
-        let value = line.trim().to_lowercase();
-        match value.as_str() {
-            "y" | "yes" => return true,
-            "n" | "no" => return false,
-            _ => {
-                eprintln!("Please enter Y or N");
-                continue;
-            }
-        }
-    }
+    Confirm::new(prompt).ask_with_input(input)
 }
 
 /// Ask the user for an integer value. Loops until valid input is received.
@@ -500,7 +626,7 @@ pub fn ask_int(prompt: &str) -> i64 {
 /// Testable version of `ask_int()` that reads from a provided input source.
 pub fn ask_int_with_input<R: BufRead>(prompt: &str, input: &mut R) -> i64 {
     loop {
-        let prompt_text = Prompt::new(prompt).make_prompt();
+        let prompt_text = Prompt::<String>::new(prompt).make_prompt();
         let prompt_str = prompt_text.plain().to_string();
         print!("{}", prompt_str);
         let _ = io::stdout().flush();
@@ -536,7 +662,7 @@ pub fn ask_float(prompt: &str) -> f64 {
 /// Testable version of `ask_float()` that reads from a provided input source.
 pub fn ask_float_with_input<R: BufRead>(prompt: &str, input: &mut R) -> f64 {
     loop {
-        let prompt_text = Prompt::new(prompt).make_prompt();
+        let prompt_text = Prompt::<String>::new(prompt).make_prompt();
         let prompt_str = prompt_text.plain().to_string();
         print!("{}", prompt_str);
         let _ = io::stdout().flush();
@@ -783,6 +909,62 @@ impl Select {
         let index = self.ask_with_input(console, input)?;
         Ok(self.choices[index].clone())
     }
+
+    /// Show the choices as a list navigated with the arrow keys and return
+    /// the selected index (0-based).
+    ///
+    /// Typing filters the list to choices containing the typed text
+    /// (case-insensitive) and Backspace removes the last character. Enter
+    /// selects the highlighted choice; Escape or Ctrl+C cancels. The list is
+    /// replaced by a one-line summary of the answer. Requires a terminal.
+    #[cfg(feature = "input")]
+    pub fn interact(&self, console: &mut Console) -> Result<usize, InvalidResponse> {
+        let _raw = RawMode::enable().map_err(|e| InvalidResponse {
+            message: e.to_string(),
+        })?;
+        self.interact_with_keys(console, KeyReader::stdin())
+    }
+
+    /// Testable version of `interact()` that reads from a provided key source.
+    #[cfg(feature = "input")]
+    pub fn interact_with_keys(
+        &self,
+        console: &mut Console,
+        keys: impl IntoIterator<Item = Key>,
+    ) -> Result<usize, InvalidResponse> {
+        if self.choices.is_empty() {
+            return Err(InvalidResponse {
+                message: "No choices provided".to_string(),
+            });
+        }
+        run_list(
+            console,
+            keys,
+            ListState::new(&self.choices, self.default),
+            |state| {
+                state.render(
+                    &self.prompt,
+                    &self.choices,
+                    &self.style,
+                    &self.highlight_style,
+                    |_| "",
+                )
+            },
+            |state, key| match state.handle(key, &self.choices, false) {
+                ListAction::Submit => state.current(&self.choices).map(Ok),
+                ListAction::Cancel => Some(Err(cancelled())),
+                _ => None,
+            },
+            |&index| {
+                answer_summary(
+                    &self.prompt,
+                    &self.style,
+                    &self.highlight_style,
+                    &self.choices[index],
+                )
+            },
+        )
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -1078,6 +1260,265 @@ impl MultiSelect {
         let indices = self.ask_with_input(console, input)?;
         Ok(indices.iter().map(|&i| self.choices[i].clone()).collect())
     }
+
+    /// Show the choices as a checklist navigated with the arrow keys and
+    /// return the selected indices (0-based, in list order).
+    ///
+    /// Space or Tab toggles the highlighted choice, typing filters the list
+    /// as in [`Select::interact`], and Enter submits once the selection
+    /// satisfies the minimum and maximum counts. Escape or Ctrl+C cancels.
+    /// Requires a terminal.
+    #[cfg(feature = "input")]
+    pub fn interact(&self, console: &mut Console) -> Result<Vec<usize>, InvalidResponse> {
+        let _raw = RawMode::enable().map_err(|e| InvalidResponse {
+            message: e.to_string(),
+        })?;
+        self.interact_with_keys(console, KeyReader::stdin())
+    }
+
+    /// Testable version of `interact()` that reads from a provided key source.
+    #[cfg(feature = "input")]
+    pub fn interact_with_keys(
+        &self,
+        console: &mut Console,
+        keys: impl IntoIterator<Item = Key>,
+    ) -> Result<Vec<usize>, InvalidResponse> {
+        if self.choices.is_empty() {
+            return Err(InvalidResponse {
+                message: "No choices provided".to_string(),
+            });
+        }
+        let mut selected = vec![false; self.choices.len()];
+        for &index in &self.defaults {
+            if let Some(flag) = selected.get_mut(index) {
+                *flag = true;
+            }
+        }
+        run_list(
+            console,
+            keys,
+            (ListState::new(&self.choices, None), selected),
+            |(state, selected)| {
+                state.render(
+                    &self.prompt,
+                    &self.choices,
+                    &self.style,
+                    &self.highlight_style,
+                    |index| {
+                        if selected[index] {
+                            "\u{25c9} "
+                        } else {
+                            "\u{25cb} "
+                        }
+                    },
+                )
+            },
+            |(state, selected), key| {
+                let action = state.handle(key, &self.choices, true);
+                state.message = None;
+                match action {
+                    ListAction::Toggle => {
+                        let index = state.current(&self.choices)?;
+                        selected[index] = !selected[index];
+                        let indices: Vec<usize> =
+                            (0..selected.len()).filter(|&i| selected[i]).collect();
+                        if self.max_selections.is_some_and(|max| indices.len() > max) {
+                            // Refuse to go over the maximum and say why.
+                            state.message = self.validate_count(&indices).err().map(|e| e.message);
+                            selected[index] = false;
+                        }
+                        None
+                    }
+                    ListAction::Submit => {
+                        let indices: Vec<usize> =
+                            (0..selected.len()).filter(|&i| selected[i]).collect();
+                        match self.validate_count(&indices) {
+                            Ok(indices) => Some(Ok(indices)),
+                            Err(e) => {
+                                state.message = Some(e.message);
+                                None
+                            }
+                        }
+                    }
+                    ListAction::Cancel => Some(Err(cancelled())),
+                    ListAction::Continue => None,
+                }
+            },
+            |indices| {
+                let values: Vec<&str> = indices.iter().map(|&i| self.choices[i].as_str()).collect();
+                answer_summary(
+                    &self.prompt,
+                    &self.style,
+                    &self.highlight_style,
+                    &values.join(", "),
+                )
+            },
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Interactive list (feature-gated)
+// ---------------------------------------------------------------------------
+
+/// What a key press asks an interactive list to do.
+#[cfg(feature = "input")]
+#[derive(Debug, PartialEq)]
+enum ListAction {
+    Continue,
+    Toggle,
+    Submit,
+    Cancel,
+}
+
+/// Cursor and filter state of an interactive [`Select`] or [`MultiSelect`].
+#[cfg(feature = "input")]
+struct ListState {
+    /// Text typed so far; only choices containing it are shown.
+    filter: String,
+    /// Position of the cursor among the shown choices.
+    cursor: usize,
+    /// Message shown below the list, e.g. a failed validation.
+    message: Option<String>,
+}
+
+#[cfg(feature = "input")]
+impl ListState {
+    fn new(choices: &[String], start: Option<usize>) -> Self {
+        ListState {
+            filter: String::new(),
+            cursor: start.filter(|&i| i < choices.len()).unwrap_or(0),
+            message: None,
+        }
+    }
+
+    /// Indices of the choices that match the filter (case-insensitive).
+    fn matches(&self, choices: &[String]) -> Vec<usize> {
+        let needle = self.filter.to_lowercase();
+        choices
+            .iter()
+            .enumerate()
+            .filter(|(_, choice)| choice.to_lowercase().contains(&needle))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The choice under the cursor, if any choice matches.
+    fn current(&self, choices: &[String]) -> Option<usize> {
+        self.matches(choices).get(self.cursor).copied()
+    }
+
+    /// Apply a key press. Space and Tab toggle when `toggles` is set instead
+    /// of being typed into the filter.
+    fn handle(&mut self, key: &Key, choices: &[String], toggles: bool) -> ListAction {
+        let count = self.matches(choices).len();
+        match key {
+            Key::Up if count > 0 => self.cursor = (self.cursor + count - 1) % count,
+            Key::Down if count > 0 => self.cursor = (self.cursor + 1) % count,
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = count.saturating_sub(1),
+            Key::Enter => return ListAction::Submit,
+            Key::Escape | Key::Ctrl('c') => return ListAction::Cancel,
+            Key::Char(' ') | Key::Tab if toggles => return ListAction::Toggle,
+            Key::Char(c) => {
+                self.filter.push(*c);
+                self.cursor = 0;
+            }
+            Key::Backspace => {
+                self.filter.pop();
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+        ListAction::Continue
+    }
+
+    /// Render the prompt line, the matching choices with the cursor row
+    /// highlighted, and any message.
+    fn render(
+        &self,
+        prompt: &str,
+        choices: &[String],
+        style: &Style,
+        highlight_style: &Style,
+        marker: impl Fn(usize) -> &'static str,
+    ) -> Text {
+        let mut text = Text::empty();
+        text.append_str(&format!("? {}: ", prompt), Some(style.clone()));
+        text.append_str(&self.filter, None);
+        let matches = self.matches(choices);
+        if matches.is_empty() {
+            text.append_str("\n  (no matches)", Style::parse("dim").ok());
+        }
+        for (row, &index) in matches.iter().enumerate() {
+            let line = format!("{}{}", marker(index), choices[index]);
+            if row == self.cursor {
+                text.append_str("\n\u{276f} ", Some(highlight_style.clone()));
+                text.append_str(&line, Some(highlight_style.clone()));
+            } else {
+                text.append_str(&format!("\n  {}", line), None);
+            }
+        }
+        if let Some(ref message) = self.message {
+            text.append_str("\n", None);
+            text.append_str(message, Style::parse("red").ok());
+        }
+        text
+    }
+}
+
+/// Drive an interactive list: draw `frame` of `state` after every key until
+/// `step` returns a result, then replace the list with `summary` of it.
+#[cfg(feature = "input")]
+fn run_list<S, T>(
+    console: &mut Console,
+    keys: impl IntoIterator<Item = Key>,
+    mut state: S,
+    frame: impl Fn(&S) -> Text,
+    mut step: impl FnMut(&mut S, &Key) -> Option<Result<T, InvalidResponse>>,
+    summary: impl Fn(&T) -> Text,
+) -> Result<T, InvalidResponse> {
+    let mut live = LiveRender::new(Text::empty());
+    let mut keys = keys.into_iter();
+    console.show_cursor(false);
+    let result = loop {
+        live.set_renderable(frame(&state));
+        let segments = live.diff_segments(console, &console.options());
+        console.write_segments(&segments);
+        let Some(key) = keys.next() else {
+            break Err(InvalidResponse {
+                message: "No input provided".to_string(),
+            });
+        };
+        if let Some(result) = step(&mut state, &key) {
+            break result;
+        }
+    };
+
+    // Erase the list; the cursor is left at its first line.
+    console.write_segments(&live.position_cursor());
+    console.show_cursor(true);
+    if let Ok(ref value) = result {
+        console.print(&summary(value));
+    }
+    result
+}
+
+/// The error returned when the user cancels an interactive list.
+#[cfg(feature = "input")]
+fn cancelled() -> InvalidResponse {
+    InvalidResponse {
+        message: "Selection cancelled".to_string(),
+    }
+}
+
+/// The line that replaces an interactive list once it is answered.
+#[cfg(feature = "input")]
+fn answer_summary(prompt: &str, style: &Style, highlight_style: &Style, answer: &str) -> Text {
+    let mut text = Text::empty();
+    text.append_str(&format!("? {}: ", prompt), Some(style.clone()));
+    text.append_str(answer, Some(highlight_style.clone()));
+    text
 }
 
 #[cfg(test)]
@@ -1089,7 +1530,7 @@ mod tests {
 
     #[test]
     fn test_simple_prompt_returns_input() {
-        let p = Prompt::new("Enter name");
+        let p: Prompt = Prompt::new("Enter name");
         let mut input = Cursor::new(b"Alice\n" as &[u8]);
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "Alice");
@@ -1099,7 +1540,7 @@ mod tests {
 
     #[test]
     fn test_prompt_with_default_empty_returns_default() {
-        let p = Prompt::new("Enter name").with_default("Bob");
+        let p: Prompt = Prompt::new("Enter name").with_default("Bob");
         let mut input = Cursor::new(b"\n" as &[u8]);
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "Bob");
@@ -1107,7 +1548,7 @@ mod tests {
 
     #[test]
     fn test_prompt_with_default_non_empty_returns_input() {
-        let p = Prompt::new("Enter name").with_default("Bob");
+        let p: Prompt = Prompt::new("Enter name").with_default("Bob");
         let mut input = Cursor::new(b"Charlie\n" as &[u8]);
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "Charlie");
@@ -1117,7 +1558,7 @@ mod tests {
 
     #[test]
     fn test_prompt_with_choices_valid() {
-        let p = Prompt::new("Pick fruit").with_choices(vec![
+        let p: Prompt = Prompt::new("Pick fruit").with_choices(vec![
             "apple".into(),
             "orange".into(),
             "pear".into(),
@@ -1131,7 +1572,7 @@ mod tests {
 
     #[test]
     fn test_prompt_with_choices_invalid_then_valid() {
-        let p = Prompt::new("Pick fruit").with_choices(vec![
+        let p: Prompt = Prompt::new("Pick fruit").with_choices(vec![
             "apple".into(),
             "orange".into(),
             "pear".into(),
@@ -1146,7 +1587,7 @@ mod tests {
 
     #[test]
     fn test_case_insensitive_choices() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_choices(vec!["Apple".into(), "Orange".into()])
             .with_case_sensitive(false);
         let mut input = Cursor::new(b"apple\n" as &[u8]);
@@ -1157,7 +1598,7 @@ mod tests {
 
     #[test]
     fn test_case_sensitive_choices_reject_wrong_case() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_choices(vec!["Apple".into(), "Orange".into()])
             .with_case_sensitive(true);
         // "apple" is wrong case; then "Apple" is correct
@@ -1210,6 +1651,97 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn test_confirm_default_on_empty_input() {
+        let confirm = Confirm::new("Overwrite?").with_default(true);
+        assert_eq!(confirm.make_prompt().plain(), "Overwrite? [y/n] (y): ");
+        assert!(confirm.ask_with_input(&mut Cursor::new(b"\n" as &[u8])));
+        assert!(!confirm.ask_with_input(&mut Cursor::new(b"No\n" as &[u8])));
+        // Without a default, an empty answer is asked again.
+        let confirm = Confirm::new("Overwrite?");
+        assert!(confirm.ask_with_input(&mut Cursor::new(b"\nyes\n" as &[u8])));
+    }
+
+    // -- typed prompts ------------------------------------------------------
+
+    #[test]
+    fn test_typed_prompt_parses_and_uses_default() {
+        let prompt = Prompt::<u16>::new("Port?").with_default(8080);
+        assert_eq!(prompt.make_prompt().plain(), "Port? (8080): ");
+        assert_eq!(
+            prompt.ask_with_input(&mut Cursor::new(b"\n" as &[u8])),
+            8080
+        );
+        assert_eq!(
+            prompt.ask_with_input(&mut Cursor::new(b" 443 \n" as &[u8])),
+            443
+        );
+    }
+
+    #[test]
+    fn test_typed_prompt_reasks_on_parse_failure() {
+        let prompt = Prompt::<u16>::new("Port?");
+        assert_eq!(
+            prompt.process_response("abc").unwrap_err().message,
+            "Please enter a valid integer number"
+        );
+        let mut input = Cursor::new(b"abc\n70000\n22\n" as &[u8]);
+        assert_eq!(prompt.try_ask_with_input(&mut input), Ok(22));
+    }
+
+    #[test]
+    fn test_typed_prompt_validators_run_in_order() {
+        let prompt = Prompt::<u16>::new("Port?")
+            .with_validator(|p| {
+                if *p >= 1024 {
+                    Ok(())
+                } else {
+                    Err("Please choose an unprivileged port".into())
+                }
+            })
+            .with_validator(|p| {
+                if *p % 2 == 0 {
+                    Ok(())
+                } else {
+                    Err("Please choose an even port".into())
+                }
+            });
+        assert_eq!(
+            prompt.process_response("80").unwrap_err().message,
+            "Please choose an unprivileged port"
+        );
+        assert_eq!(
+            prompt.process_response("8081").unwrap_err().message,
+            "Please choose an even port"
+        );
+        let mut input = Cursor::new(b"80\n8081\n8080\n" as &[u8]);
+        assert_eq!(prompt.ask_with_input(&mut input), 8080);
+    }
+
+    #[test]
+    fn test_typed_prompt_eof_without_answer() {
+        let prompt = Prompt::<f64>::new("Ratio?");
+        let mut input = Cursor::new(b"" as &[u8]);
+        assert_eq!(
+            prompt.try_ask_with_input(&mut input).unwrap_err().message,
+            "Please enter a valid number"
+        );
+        assert_eq!(prompt.ask_with_input(&mut Cursor::new(b"" as &[u8])), 0.0);
+    }
+
+    #[test]
+    fn test_string_prompt_validator() {
+        let prompt: Prompt = Prompt::new("Name").with_validator(|name: &String| {
+            if name.is_empty() {
+                Err("Name is required".into())
+            } else {
+                Ok(())
+            }
+        });
+        let mut input = Cursor::new(b"\nAda\n" as &[u8]);
+        assert_eq!(prompt.ask_with_input(&mut input), "Ada");
+    }
+
     // -- ask_int() valid integer --------------------------------------------
 
     #[test]
@@ -1239,9 +1771,9 @@ mod tests {
 
     #[test]
     fn test_ask_float_valid() {
-        let mut input = Cursor::new(b"3.14\n" as &[u8]);
+        let mut input = Cursor::new(b"3.25\n" as &[u8]);
         let result = ask_float_with_input("Enter number", &mut input);
-        assert!((result - 3.14).abs() < f64::EPSILON);
+        assert!((result - 3.25).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -1255,16 +1787,16 @@ mod tests {
 
     #[test]
     fn test_ask_float_invalid_then_valid() {
-        let mut input = Cursor::new(b"xyz\n2.718\n" as &[u8]);
+        let mut input = Cursor::new(b"xyz\n2.5\n" as &[u8]);
         let result = ask_float_with_input("Enter number", &mut input);
-        assert!((result - 2.718).abs() < f64::EPSILON);
+        assert!((result - 2.5).abs() < f64::EPSILON);
     }
 
     // -- Prompt text includes choices when show_choices is true --------------
 
     #[test]
     fn test_prompt_text_includes_choices() {
-        let p = Prompt::new("Pick fruit")
+        let p: Prompt = Prompt::new("Pick fruit")
             .with_choices(vec!["apple".into(), "orange".into()])
             .with_show_choices(true);
         let text = p.make_prompt();
@@ -1274,7 +1806,7 @@ mod tests {
 
     #[test]
     fn test_prompt_text_hides_choices_when_disabled() {
-        let p = Prompt::new("Pick fruit")
+        let p: Prompt = Prompt::new("Pick fruit")
             .with_choices(vec!["apple".into(), "orange".into()])
             .with_show_choices(false);
         let text = p.make_prompt();
@@ -1287,7 +1819,7 @@ mod tests {
 
     #[test]
     fn test_prompt_text_includes_default() {
-        let p = Prompt::new("Enter name")
+        let p: Prompt = Prompt::new("Enter name")
             .with_default("World")
             .with_show_default(true);
         let text = p.make_prompt();
@@ -1297,7 +1829,7 @@ mod tests {
 
     #[test]
     fn test_prompt_text_hides_default_when_disabled() {
-        let p = Prompt::new("Enter name")
+        let p: Prompt = Prompt::new("Enter name")
             .with_default("World")
             .with_show_default(false);
         let text = p.make_prompt();
@@ -1309,10 +1841,10 @@ mod tests {
 
     #[test]
     fn test_password_flag() {
-        let p = Prompt::new("Password").with_password(true);
+        let p: Prompt = Prompt::new("Password").with_password(true);
         assert!(p.password);
 
-        let p2 = Prompt::new("Password").with_password(false);
+        let p2: Prompt = Prompt::new("Password").with_password(false);
         assert!(!p2.password);
     }
 
@@ -1320,43 +1852,43 @@ mod tests {
 
     #[test]
     fn test_builder_with_choices() {
-        let p = Prompt::new("test").with_choices(vec!["a".into(), "b".into()]);
+        let p: Prompt = Prompt::new("test").with_choices(vec!["a".into(), "b".into()]);
         assert_eq!(p.choices, Some(vec!["a".to_string(), "b".to_string()]));
     }
 
     #[test]
     fn test_builder_with_default() {
-        let p = Prompt::new("test").with_default("val");
+        let p: Prompt = Prompt::new("test").with_default("val");
         assert_eq!(p.default, Some("val".to_string()));
     }
 
     #[test]
     fn test_builder_with_case_sensitive() {
-        let p = Prompt::new("test").with_case_sensitive(false);
+        let p: Prompt = Prompt::new("test").with_case_sensitive(false);
         assert!(!p.case_sensitive);
     }
 
     #[test]
     fn test_builder_with_show_default() {
-        let p = Prompt::new("test").with_show_default(false);
+        let p: Prompt = Prompt::new("test").with_show_default(false);
         assert!(!p.show_default);
     }
 
     #[test]
     fn test_builder_with_show_choices() {
-        let p = Prompt::new("test").with_show_choices(false);
+        let p: Prompt = Prompt::new("test").with_show_choices(false);
         assert!(!p.show_choices);
     }
 
     #[test]
     fn test_builder_with_password() {
-        let p = Prompt::new("test").with_password(true);
+        let p: Prompt = Prompt::new("test").with_password(true);
         assert!(p.password);
     }
 
     #[test]
     fn test_builder_with_completions() {
-        let p = Prompt::new("test").with_completions(vec!["foo".into(), "bar".into()]);
+        let p: Prompt = Prompt::new("test").with_completions(vec!["foo".into(), "bar".into()]);
         assert_eq!(
             p.completions,
             Some(vec!["foo".to_string(), "bar".to_string()])
@@ -1365,7 +1897,7 @@ mod tests {
 
     #[test]
     fn test_builder_completions_default_none() {
-        let p = Prompt::new("test");
+        let p: Prompt = Prompt::new("test");
         assert!(p.completions.is_none());
     }
 
@@ -1373,7 +1905,7 @@ mod tests {
 
     #[test]
     fn test_prompt_suffix() {
-        let p = Prompt::new("Enter value");
+        let p: Prompt = Prompt::new("Enter value");
         let text = p.make_prompt();
         let plain = text.plain().to_string();
         assert!(plain.ends_with(": "));
@@ -1383,7 +1915,7 @@ mod tests {
 
     #[test]
     fn test_default_on_eof() {
-        let p = Prompt::new("Enter").with_default("fallback");
+        let p: Prompt = Prompt::new("Enter").with_default("fallback");
         let mut input = Cursor::new(b"" as &[u8]); // EOF immediately
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "fallback");
@@ -1393,7 +1925,7 @@ mod tests {
 
     #[test]
     fn test_no_default_empty_returns_empty() {
-        let p = Prompt::new("Enter");
+        let p: Prompt = Prompt::new("Enter");
         let mut input = Cursor::new(b"\n" as &[u8]);
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "");
@@ -1403,7 +1935,7 @@ mod tests {
 
     #[test]
     fn test_prompt_text_choices_and_default() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_choices(vec!["a".into(), "b".into()])
             .with_default("a")
             .with_show_choices(true)
@@ -1419,7 +1951,7 @@ mod tests {
 
     #[test]
     fn test_choices_default_on_empty() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_choices(vec!["a".into(), "b".into()])
             .with_default("a");
         let mut input = Cursor::new(b"\n" as &[u8]);
@@ -1431,7 +1963,7 @@ mod tests {
 
     #[test]
     fn test_prompt_has_styled_choices_span() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_choices(vec!["x".into(), "y".into()])
             .with_show_choices(true);
         let text = p.make_prompt();
@@ -1441,7 +1973,7 @@ mod tests {
 
     #[test]
     fn test_prompt_has_styled_default_span() {
-        let p = Prompt::new("Pick")
+        let p: Prompt = Prompt::new("Pick")
             .with_default("z")
             .with_show_default(true);
         let text = p.make_prompt();
@@ -1475,7 +2007,7 @@ mod tests {
     fn test_password_ask_with_input_reads_normally() {
         // ask_with_input always reads from the BufRead regardless of password flag.
         // This verifies the password flag doesn't break BufRead-based input.
-        let p = Prompt::new("Password").with_password(true);
+        let p: Prompt = Prompt::new("Password").with_password(true);
         let mut input = Cursor::new(b"secret123\n" as &[u8]);
         let result = p.ask_with_input(&mut input);
         assert_eq!(result, "secret123");
@@ -1483,7 +2015,7 @@ mod tests {
 
     #[test]
     fn test_password_with_default_on_empty() {
-        let p = Prompt::new("Password")
+        let p: Prompt = Prompt::new("Password")
             .with_password(true)
             .with_default("default_pass");
         let mut input = Cursor::new(b"\n" as &[u8]);
@@ -1493,7 +2025,7 @@ mod tests {
 
     #[test]
     fn test_password_with_default_on_eof() {
-        let p = Prompt::new("Password")
+        let p: Prompt = Prompt::new("Password")
             .with_password(true)
             .with_default("fallback");
         let mut input = Cursor::new(b"" as &[u8]);
@@ -1504,8 +2036,8 @@ mod tests {
     #[test]
     fn test_password_prompt_text_unchanged() {
         // Password mode should NOT affect the rendered prompt text
-        let p1 = Prompt::new("Enter password").with_password(true);
-        let p2 = Prompt::new("Enter password").with_password(false);
+        let p1: Prompt = Prompt::new("Enter password").with_password(true);
+        let p2: Prompt = Prompt::new("Enter password").with_password(false);
         let text1 = p1.make_prompt().plain().to_string();
         let text2 = p2.make_prompt().plain().to_string();
         assert_eq!(text1, text2);
@@ -2002,4 +2534,117 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().message.contains("not a valid number"));
     }
+
+    // -- Interactive lists --------------------------------------------------
+
+    #[cfg(feature = "input")]
+    fn colors() -> Vec<String> {
+        vec!["Red".into(), "Green".into(), "Blue".into()]
+    }
+
+    #[cfg(feature = "input")]
+    fn interact_select(
+        select: &Select,
+        keys: Vec<Key>,
+    ) -> (Result<usize, InvalidResponse>, String) {
+        let mut console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        console.begin_capture();
+        let result = select.interact_with_keys(&mut console, keys);
+        (result, console.end_capture())
+    }
+
+    #[cfg(feature = "input")]
+    fn interact_multi(
+        multi: &MultiSelect,
+        keys: Vec<Key>,
+    ) -> (Result<Vec<usize>, InvalidResponse>, String) {
+        let mut console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .force_terminal(true)
+            .build();
+        console.begin_capture();
+        let result = multi.interact_with_keys(&mut console, keys);
+        (result, console.end_capture())
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_select_interact_arrow_keys() {
+        let select = Select::new("Color", colors());
+        let (result, output) = interact_select(&select, vec![Key::Down, Key::Down, Key::Enter]);
+        assert_eq!(result, Ok(2));
+        assert!(output.contains("? Color: Blue"));
+
+        // Up from the first choice wraps to the last.
+        let (result, _) = interact_select(&select, vec![Key::Up, Key::Enter]);
+        assert_eq!(result, Ok(2));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_select_interact_starts_at_default() {
+        let select = Select::new("Color", colors()).with_default(1);
+        let (result, _) = interact_select(&select, vec![Key::Enter]);
+        assert_eq!(result, Ok(1));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_select_interact_filters_as_you_type() {
+        let select = Select::new("Color", colors());
+        let keys = vec![Key::Char('E'), Key::Char('e'), Key::Enter];
+        assert_eq!(interact_select(&select, keys).0, Ok(1));
+
+        let keys = vec![Key::Char('x'), Key::Enter, Key::Backspace, Key::Enter];
+        let (result, output) = interact_select(&select, keys);
+        assert_eq!(result, Ok(0));
+        assert!(output.contains("(no matches)"));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_select_interact_cancel_and_eof() {
+        let select = Select::new("Color", colors());
+        let (result, _) = interact_select(&select, vec![Key::Escape]);
+        assert_eq!(result.unwrap_err().message, "Selection cancelled");
+        let (result, _) = interact_select(&select, vec![Key::Down]);
+        assert_eq!(result.unwrap_err().message, "No input provided");
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_multi_select_interact_toggles() {
+        let multi = MultiSelect::new("Colors", colors()).with_defaults(vec![1]);
+        let keys = vec![
+            Key::Char(' '),
+            Key::Down,
+            Key::Tab,
+            Key::Down,
+            Key::Char(' '),
+            Key::Enter,
+        ];
+        let (result, output) = interact_multi(&multi, keys);
+        assert_eq!(result, Ok(vec![0, 2]));
+        assert!(output.contains("? Colors: Red, Blue"));
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn test_multi_select_interact_enforces_counts() {
+        let multi = MultiSelect::new("Colors", colors()).with_min(1);
+        let (result, output) = interact_multi(&multi, vec![Key::Enter, Key::Char(' '), Key::Enter]);
+        assert_eq!(result, Ok(vec![0]));
+        assert!(output.contains("Please select at least 1 option"));
+
+        let multi = MultiSelect::new("Colors", colors()).with_max(1);
+        let keys = vec![Key::Char(' '), Key::Down, Key::Char(' '), Key::Enter];
+        let (result, output) = interact_multi(&multi, keys);
+        assert_eq!(result, Ok(vec![0]));
+        assert!(output.contains("Please select at most 1 option"));
+    }
 }
@@ -0,0 +1,189 @@
+//! Terminal resize notifications -- keeps live displays in step with the window.
+//!
+//! A background listener counts terminal resizes: on Unix it waits for
+//! `SIGWINCH`, elsewhere (Windows) it polls the console window size. Each
+//! resize bumps a global [`generation`] and wakes every running
+//! [`Live`](crate::live::Live) display, including the one behind a
+//! [`Progress`](crate::progress::Progress), which then calls
+//! [`Console::sync_terminal_size`](crate::console::Console::sync_terminal_size)
+//! and redraws at the new width instead of leaving the terminal to re-wrap
+//! the old frame.
+//!
+//! # Features
+//!
+//! This module requires the `resize` feature to be enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! gilt = { version = "0.9", features = ["resize"] }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gilt::console::Console;
+//!
+//! let mut console = Console::new();
+//! let seen = gilt::resize::generation();
+//! // ... later, e.g. before drawing a frame ...
+//! if gilt::resize::generation() != seen && console.sync_terminal_size() {
+//!     println!("now {} columns wide", console.width());
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, Weak};
+use std::thread;
+#[cfg(not(unix))]
+use std::time::Duration;
+
+/// How often the console size is checked where no resize signal exists.
+#[cfg(not(unix))]
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A display's wake-up pair: the refresh loop waits on the condvar and the
+/// flag tells it whether it was woken to stop.
+pub(crate) type Waker = (Mutex<bool>, Condvar);
+
+// ---------------------------------------------------------------------------
+// Listener
+// ---------------------------------------------------------------------------
+
+/// Process-wide resize state, created with the listener thread on first use.
+struct Listener {
+    generation: AtomicU64,
+    subscribers: Mutex<Vec<Weak<Waker>>>,
+}
+
+impl Listener {
+    /// The listener, starting its thread the first time it is needed.
+    fn get() -> &'static Listener {
+        static LISTENER: OnceLock<Listener> = OnceLock::new();
+        static STARTED: OnceLock<()> = OnceLock::new();
+        let listener = LISTENER.get_or_init(|| Listener {
+            generation: AtomicU64::new(0),
+            subscribers: Mutex::new(Vec::new()),
+        });
+        STARTED.get_or_init(|| {
+            let _ = thread::Builder::new()
+                .name("gilt-resize".into())
+                .spawn(move || listener.run());
+        });
+        listener
+    }
+
+    /// Wait for resize signals for the rest of the process.
+    #[cfg(unix)]
+    fn run(&self) {
+        use signal_hook::consts::SIGWINCH;
+        use signal_hook::iterator::Signals;
+
+        let Ok(mut signals) = Signals::new([SIGWINCH]) else {
+            return;
+        };
+        for _ in signals.forever() {
+            self.resized();
+        }
+    }
+
+    /// Poll the console size, since there is no resize signal to wait for.
+    #[cfg(not(unix))]
+    fn run(&self) {
+        let mut last = terminal_size();
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let size = terminal_size();
+            if size != last {
+                last = size;
+                self.resized();
+            }
+        }
+    }
+
+    /// Wake `waker` on every resize, unless it is already subscribed.
+    fn subscribe(&self, waker: &Arc<Waker>) {
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        if !subscribers
+            .iter()
+            .any(|weak| std::ptr::eq(weak.as_ptr(), Arc::as_ptr(waker)))
+        {
+            subscribers.push(Arc::downgrade(waker));
+        }
+    }
+
+    /// Record a resize and wake the subscribed displays, dropping those
+    /// that have gone away.
+    fn resized(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|weak| match weak.upgrade() {
+            Some(waker) => {
+                waker.1.notify_all();
+                true
+            }
+            None => false,
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// The number of terminal resizes seen so far.
+///
+/// The first call starts the listener; compare two readings to tell whether
+/// the terminal was resized in between.
+pub fn generation() -> u64 {
+    Listener::get().generation.load(Ordering::SeqCst)
+}
+
+/// The current size of the attached terminal as `(width, height)`, or
+/// `None` when no standard stream is a terminal.
+pub fn terminal_size() -> Option<(usize, usize)> {
+    terminal_size::terminal_size()
+        .map(|(width, height)| (usize::from(width.0), usize::from(height.0)))
+        .filter(|&(width, height)| width > 0 && height > 0)
+}
+
+/// Wake `waker`'s condvar on every resize until it is dropped.
+pub(crate) fn subscribe(waker: &Arc<Waker>) {
+    Listener::get().subscribe(waker);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listener() -> Listener {
+        Listener {
+            generation: AtomicU64::new(0),
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[test]
+    fn test_resized_bumps_generation() {
+        let listener = listener();
+        listener.resized();
+        listener.resized();
+        assert_eq!(listener.generation.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_subscribe_once_and_prune_dropped() {
+        let listener = listener();
+        let kept: Arc<Waker> = Arc::new((Mutex::new(false), Condvar::new()));
+        let dropped: Arc<Waker> = Arc::new((Mutex::new(false), Condvar::new()));
+        listener.subscribe(&kept);
+        listener.subscribe(&kept);
+        listener.subscribe(&dropped);
+        assert_eq!(listener.subscribers.lock().unwrap().len(), 2);
+
+        drop(dropped);
+        listener.resized();
+        let subscribers = listener.subscribers.lock().unwrap();
+        assert_eq!(subscribers.len(), 1);
+        assert!(std::ptr::eq(subscribers[0].as_ptr(), Arc::as_ptr(&kept)));
+    }
+}
@@ -4,7 +4,9 @@
 
 use crate::align_widget::HorizontalAlign;
 use crate::cells::{cell_len, set_cell_size};
-use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::console::{format_log_time, Console, ConsoleOptions, Renderable};
+use crate::format_context::FormatContext;
+use crate::measure::Measurement;
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::{OverflowMethod, Text};
@@ -22,10 +24,17 @@ pub struct Rule {
     pub characters: String,
     /// Style for the rule line characters.
     pub style: Style,
+    /// Theme style name, such as `"$accent"`, looked up when the rule is
+    /// rendered; takes precedence over [`style`](Self::style) when it
+    /// resolves.
+    pub style_name: Option<String>,
     /// String appended after the rule (default `"\n"`).
     pub end: String,
     /// Alignment of the title within the rule.
     pub align: HorizontalAlign,
+    /// Optional right-aligned text (e.g. a timestamp). When set, the title
+    /// is drawn on the left and the line fills the space between them.
+    pub right: Option<Text>,
 }
 
 impl Rule {
@@ -36,8 +45,10 @@ impl Rule {
             title: None,
             characters: "\u{2501}".to_string(), // ━ (heavy horizontal)
             style: Style::null(),
+            style_name: None,
             end: "\n".to_string(),
             align: HorizontalAlign::Center,
+            right: None,
         }
     }
 
@@ -48,6 +59,34 @@ impl Rule {
         rule
     }
 
+    /// Create a rule with a title on the left and metadata on the right,
+    /// with the line filling the middle: `left ━━━━━━ right`.
+    pub fn with_left_right(left: &str, right: &str) -> Self {
+        Rule::with_title(left)
+            .with_align(HorizontalAlign::Left)
+            .with_right(right)
+    }
+
+    /// Set the right-aligned text. While set, the title is always drawn on
+    /// the left and [`align`](Self::align) is ignored.
+    #[must_use]
+    pub fn with_right(mut self, right: &str) -> Self {
+        self.right = Some(Text::new(right, Style::null()));
+        self
+    }
+
+    /// Set the right-aligned text to the current UTC time (`HH:MM:SS`),
+    /// taken when this method is called.
+    #[must_use]
+    pub fn with_timestamp(self) -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let stamp = format_log_time("%H:%M:%S", secs, &FormatContext::default());
+        self.with_right(&stamp)
+    }
+
     /// Set the line characters.
     #[must_use]
     pub fn with_characters(mut self, chars: &str) -> Self {
@@ -62,6 +101,14 @@ impl Rule {
         self
     }
 
+    /// Set the rule style by theme name, such as `"$accent"`, resolved
+    /// through the console theme when the rule is rendered.
+    #[must_use]
+    pub fn with_style_name(mut self, name: &str) -> Self {
+        self.style_name = Some(name.to_string());
+        self
+    }
+
     /// Set the alignment.
     #[must_use]
     pub fn with_align(mut self, align: HorizontalAlign) -> Self {
@@ -76,6 +123,11 @@ impl Rule {
         self
     }
 
+    /// Measure the width requirements: a rule always spans the full width.
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        Measurement::new(options.max_width, options.max_width)
+    }
+
     /// Build a line of repeated characters to fill the given width.
     fn rule_line(&self, width: usize) -> String {
         if width == 0 {
@@ -96,6 +148,66 @@ impl Rule {
     }
 }
 
+impl Rule {
+    /// Render `title ━━━━ right`, where `self.characters` is already the
+    /// ASCII-safe set. Returns `None` when `right` does not fit next
+    /// to a minimal line, so the caller can fall back to the plain layout.
+    fn render_left_right(
+        &self,
+        console: &Console,
+        width: usize,
+        right: &Text,
+        rule_style: &Style,
+        title_style: &Style,
+    ) -> Option<Vec<Segment>> {
+        let char_len = cell_len(&self.characters);
+        let right_text = styled_title(right, title_style);
+        let right_width = right_text.cell_len();
+        if char_len == 0 || right_width == 0 || width < right_width + 1 + char_len {
+            return None;
+        }
+        let mut segments = Vec::new();
+        let mut used = right_width + 1;
+
+        if let Some(title) = &self.title {
+            let mut title_text = styled_title(title, title_style);
+            let title_max_width = width.saturating_sub(used + char_len + 1);
+            if title_max_width > 0 && title_text.cell_len() > 0 {
+                title_text.truncate_with_ellipsis(
+                    title_max_width,
+                    Some(OverflowMethod::Ellipsis),
+                    false,
+                    &console.glyphs().ellipsis,
+                );
+                used += title_text.cell_len() + 1;
+                segments.extend(title_text.render().into_iter().filter(|s| s.text != "\n"));
+                segments.push(Segment::new(" ", None, None));
+            }
+        }
+
+        let line_width = width.saturating_sub(used);
+        let line = self.rule_line(line_width);
+        let exact = set_cell_size(&line, line_width);
+        segments.push(Segment::styled(&exact, rule_style.clone()));
+        segments.push(Segment::new(" ", None, None));
+        segments.extend(right_text.render().into_iter().filter(|s| s.text != "\n"));
+        segments.push(Segment::new(&self.end, None, None));
+        Some(segments)
+    }
+}
+
+/// Copy `text`, applying the `rule.text` style over its whole length.
+fn styled_title(text: &Text, style: &Style) -> Text {
+    let mut text = text.clone();
+    if !style.is_null() {
+        let len = text.len();
+        if len > 0 {
+            text.stylize(style.clone(), 0, Some(len));
+        }
+    }
+    text
+}
+
 impl Default for Rule {
     fn default() -> Self {
         Rule::new()
@@ -107,12 +219,11 @@ impl Renderable for Rule {
         let width = options.max_width;
 
         // Resolve the style: try "rule.line" from the console theme, fall back to self.style
-        let rule_style = if self.style.is_null() {
-            console
-                .get_style("rule.line")
-                .unwrap_or_else(|_| self.style.clone())
+        let style = console.widget_style(&self.style, self.style_name.as_deref());
+        let rule_style = if style.is_null() {
+            console.get_style("rule.line").unwrap_or(style)
         } else {
-            self.style.clone()
+            style
         };
 
         // Use ASCII fallback if needed
@@ -127,10 +238,24 @@ impl Renderable for Rule {
             title: self.title.clone(),
             characters: chars,
             style: rule_style.clone(),
+            style_name: None,
             end: self.end.clone(),
             align: self.align,
+            right: None,
         };
 
+        let title_style = console
+            .get_style("rule.text")
+            .unwrap_or_else(|_| Style::null());
+
+        if let Some(right) = &self.right {
+            if let Some(segments) =
+                rule_with_chars.render_left_right(console, width, right, &rule_style, &title_style)
+            {
+                return segments;
+            }
+        }
+
         let mut segments = Vec::new();
 
         match &self.title {
@@ -144,20 +269,7 @@ impl Renderable for Rule {
                 segments.push(Segment::new(&self.end, None, None));
             }
             Some(title) => {
-                let mut title_text = title.clone();
-
-                // Resolve title style
-                let title_style = console
-                    .get_style("rule.text")
-                    .unwrap_or_else(|_| Style::null());
-
-                // Apply title style as a span if it's not null
-                if !title_style.is_null() {
-                    let len = title_text.len();
-                    if len > 0 {
-                        title_text.stylize(title_style, 0, Some(len));
-                    }
-                }
+                let mut title_text = styled_title(title, &title_style);
 
                 let char_len = cell_len(&rule_with_chars.characters);
                 if char_len == 0 {
@@ -187,7 +299,12 @@ impl Renderable for Rule {
                         }
 
                         // Truncate title if necessary
-                        title_text.truncate(title_max_width, Some(OverflowMethod::Ellipsis), false);
+                        title_text.truncate_with_ellipsis(
+                            title_max_width,
+                            Some(OverflowMethod::Ellipsis),
+                            false,
+                            &console.glyphs().ellipsis,
+                        );
 
                         let title_width = title_text.cell_len();
                         let side_width = (width.saturating_sub(title_width + 2)) / 2;
@@ -222,7 +339,12 @@ impl Renderable for Rule {
                             return segments;
                         }
 
-                        title_text.truncate(title_max_width, Some(OverflowMethod::Ellipsis), false);
+                        title_text.truncate_with_ellipsis(
+                            title_max_width,
+                            Some(OverflowMethod::Ellipsis),
+                            false,
+                            &console.glyphs().ellipsis,
+                        );
 
                         let title_width = title_text.cell_len();
                         let rule_width = width.saturating_sub(title_width + 2);
@@ -249,7 +371,12 @@ impl Renderable for Rule {
                             return segments;
                         }
 
-                        title_text.truncate(title_max_width, Some(OverflowMethod::Ellipsis), false);
+                        title_text.truncate_with_ellipsis(
+                            title_max_width,
+                            Some(OverflowMethod::Ellipsis),
+                            false,
+                            &console.glyphs().ellipsis,
+                        );
 
                         let title_width = title_text.cell_len();
                         let rule_width = width.saturating_sub(title_width + 2);
@@ -405,6 +532,56 @@ mod tests {
         assert!(line.ends_with("Right"));
     }
 
+    // -- Left/right title ---------------------------------------------------
+
+    #[test]
+    fn test_left_right() {
+        let console = make_console(30);
+        let rule = Rule::with_left_right("Build", "12:00:00").with_characters("-");
+        let output = render_rule(&console, &rule);
+        let line = output.trim_end_matches('\n');
+        assert_eq!(line, "Build --------------- 12:00:00");
+        assert_eq!(cell_len(line), 30);
+    }
+
+    #[test]
+    fn test_right_only() {
+        let console = make_console(12);
+        let rule = Rule::new().with_characters("-").with_right("done");
+        let output = render_rule(&console, &rule);
+        assert_eq!(output, "------- done\n");
+    }
+
+    #[test]
+    fn test_left_right_truncates_title_first() {
+        let console = make_console(20);
+        let rule =
+            Rule::with_left_right("A very long section title", "09:41:00").with_characters("-");
+        let output = render_rule(&console, &rule);
+        let line = output.trim_end_matches('\n');
+        assert_eq!(cell_len(line), 20);
+        assert!(line.ends_with(" 09:41:00"));
+        assert!(line.contains('-'));
+    }
+
+    #[test]
+    fn test_right_too_wide_falls_back() {
+        let console = make_console(6);
+        let rule = Rule::with_left_right("X", "much too wide").with_characters("-");
+        let output = render_rule(&console, &rule);
+        let line = output.trim_end_matches('\n');
+        assert_eq!(cell_len(line), 6);
+        assert!(!line.contains("wide"));
+    }
+
+    #[test]
+    fn test_with_timestamp() {
+        let rule = Rule::with_title("Log").with_timestamp();
+        let stamp = rule.right.as_ref().unwrap().plain().to_string();
+        assert_eq!(stamp.len(), 8);
+        assert_eq!(stamp.matches(':').count(), 2);
+    }
+
     // -- ASCII fallback -----------------------------------------------------
 
     #[test]
@@ -438,7 +615,7 @@ mod tests {
         // The rule line segment should have a style
         let rule_segs: Vec<&Segment> = segments
             .iter()
-            .filter(|s| s.text.trim().len() > 0 && s.text != "\n")
+            .filter(|s| !s.text.trim().is_empty() && s.text != "\n")
             .collect();
         assert!(!rule_segs.is_empty());
         for seg in &rule_segs {
@@ -446,6 +623,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_style_name_resolves_through_theme() {
+        use crate::theme::Theme;
+        use std::collections::HashMap;
+
+        let mut console = make_console(20);
+        let rule = Rule::new()
+            .with_style(Style::parse("bold").unwrap())
+            .with_style_name("$accent");
+        let line_style = |console: &Console| {
+            rule.gilt_console(console, &console.options())
+                .into_iter()
+                .find(|s| s.text.contains('━'))
+                .and_then(|s| s.style)
+        };
+        // Without an `accent` theme entry the literal style applies.
+        assert_eq!(line_style(&console), Some(Style::parse("bold").unwrap()));
+
+        let styles = HashMap::from([("accent".to_string(), Style::parse("magenta").unwrap())]);
+        console.push_theme(Theme::new(Some(styles), true));
+        assert_eq!(line_style(&console), Some(Style::parse("magenta").unwrap()));
+    }
+
     // -- Default constructor ------------------------------------------------
 
     #[test]
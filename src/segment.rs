@@ -4,7 +4,7 @@
 
 use compact_str::CompactString;
 
-use crate::cells::{cell_len, get_character_cell_size, is_single_cell_widths, set_cell_size};
+use crate::cells::{cell_clusters, cell_len, is_single_cell_widths, set_cell_size};
 use crate::style::Style;
 
 /// Terminal control code types.
@@ -208,9 +208,7 @@ impl Segment {
         // General case: iterate through characters
         let mut cell_pos = 0;
 
-        for (idx, ch) in self.text.char_indices() {
-            let char_width = get_character_cell_size(ch);
-
+        for (idx, cluster, char_width) in cell_clusters(&self.text) {
             if cell_pos == cut {
                 // Exact match
                 return (
@@ -221,7 +219,7 @@ impl Segment {
                 // Would overflow: double-width char straddling the cut
                 // Replace with spaces
                 let before = format!("{} ", &self.text[..idx]);
-                let after = format!(" {}", &self.text[idx + ch.len_utf8()..]);
+                let after = format!(" {}", &self.text[idx + cluster.len()..]);
                 return (
                     Segment::new(&before, self.style.clone(), None),
                     Segment::new(&after, self.style.clone(), None),
@@ -1377,8 +1375,8 @@ mod tests {
         let segments = vec![Segment::text("Hello\nWorld")];
         let lines = Segment::split_lines_terminator(&segments);
         assert_eq!(lines.len(), 2);
-        assert_eq!(lines[0].1, true); // first line has terminator
-        assert_eq!(lines[1].1, false); // last line doesn\'t
+        assert!(lines[0].1); // first line has terminator
+        assert!(!lines[1].1); // last line doesn\'t
         let text0: String = lines[0].0.iter().map(|s| s.text.as_str()).collect();
         assert_eq!(text0, "Hello");
     }
@@ -1388,7 +1386,7 @@ mod tests {
         let segments = vec![Segment::text("Hello")];
         let lines = Segment::split_lines_terminator(&segments);
         assert_eq!(lines.len(), 1);
-        assert_eq!(lines[0].1, false);
+        assert!(!lines[0].1);
     }
 
     #[test]
@@ -1396,6 +1394,6 @@ mod tests {
         let segments = vec![Segment::text("Hello\n")];
         let lines = Segment::split_lines_terminator(&segments);
         assert_eq!(lines.len(), 1);
-        assert_eq!(lines[0].1, true);
+        assert!(lines[0].1);
     }
 }
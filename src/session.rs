@@ -0,0 +1,674 @@
+//! Session recording -- console output with timing, saved and replayed later.
+//!
+//! A console built with `record(true)` timestamps every write. Calling
+//! [`Console::session`] gathers those writes into a [`Session`]: a list of
+//! frames, each holding the segments of one write and the delay since the
+//! previous one. Replaying a session through
+//! [`Console::replay_session`] writes the same segments with the same pacing,
+//! which makes sessions useful both as demos and as golden files for tests.
+//!
+//! With the `json` feature a session serializes to a small JSON Lines format,
+//! similar in spirit to asciinema's: a header line with the terminal size,
+//! then one line per frame.
+//!
+//! ```text
+//! {"height":25,"version":1,"width":80}
+//! {"delay":0.0,"segments":[{"style":"bold","text":"Hello"},{"text":"\n"}]}
+//! {"delay":0.25,"segments":[{"control":[[9,1]],"text":""}]}
+//! ```
+//!
+//! [`Session::screens`] replays the frames onto a virtual terminal and returns
+//! the full screen after each one. Displays that redraw in place, such as
+//! [`Live`](crate::live::Live), write only cursor movements and the lines that
+//! changed, so the screens (not the raw frames) are what an animated
+//! [SVG export](crate::console::Console::export_svg_animation) shows.
+//!
+//! Styles are stored as style definitions (see [`Style::parse`]) and control
+//! codes as `[type, params...]` arrays, so a replay re-renders for the color
+//! system of the replaying console.
+//!
+//! [`Console::session`]: crate::console::Console::session
+//! [`Console::replay_session`]: crate::console::Console::replay_session
+
+use std::time::Duration;
+
+#[cfg(feature = "json")]
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+use crate::cells::cell_clusters;
+use crate::segment::{ControlCode, ControlType, Segment};
+use crate::style::Style;
+
+/// Version written to (and accepted from) the session header line.
+pub const SESSION_FORMAT_VERSION: u64 = 1;
+
+/// Errors that can occur while saving or loading a session.
+#[derive(Error, Debug)]
+pub enum SessionError {
+    /// The session file could not be read or written.
+    #[error("session I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A line of the session file is malformed.
+    #[error("invalid session data on line {line}: {message}")]
+    Format {
+        /// One-based line number in the session file.
+        line: usize,
+        /// What was wrong with the line.
+        message: String,
+    },
+}
+
+// ---------------------------------------------------------------------------
+// SessionFrame
+// ---------------------------------------------------------------------------
+
+/// The segments of one console write and when it happened.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionFrame {
+    /// Time elapsed since the previous frame (zero for the first frame).
+    pub delay: Duration,
+    /// Segments written in this frame.
+    pub segments: Vec<Segment>,
+}
+
+/// The full terminal screen after a frame has been written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionScreen {
+    /// Time elapsed since the previous screen (zero for the first).
+    pub delay: Duration,
+    /// Screen lines, top to bottom, without trailing newlines.
+    pub lines: Vec<Vec<Segment>>,
+}
+
+// ---------------------------------------------------------------------------
+// Session
+// ---------------------------------------------------------------------------
+
+/// A recorded console session: timed frames of segments.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use gilt::console::Console;
+/// use gilt::segment::Segment;
+/// use gilt::session::Session;
+///
+/// let mut session = Session::new(40, 10);
+/// session.push(Duration::ZERO, vec![Segment::text("loading"), Segment::line()]);
+/// session.push(Duration::from_millis(500), vec![Segment::text("done"), Segment::line()]);
+/// assert_eq!(session.duration(), Duration::from_millis(500));
+///
+/// let mut console = Console::builder().width(40).build();
+/// console.begin_capture();
+/// console.replay_session(&session, f64::INFINITY);
+/// assert_eq!(console.end_capture(), "loading\ndone\n");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    /// Terminal width the session was recorded at.
+    pub width: usize,
+    /// Terminal height the session was recorded at.
+    pub height: usize,
+    /// Recorded frames, in order.
+    pub frames: Vec<SessionFrame>,
+}
+
+impl Session {
+    /// Create an empty session for a terminal of the given size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Session {
+            width,
+            height,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Append a frame written `delay` after the previous one.
+    pub fn push(&mut self, delay: Duration, segments: Vec<Segment>) {
+        self.frames.push(SessionFrame { delay, segments });
+    }
+
+    /// Number of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the session has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Total playing time at normal speed.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|f| f.delay).sum()
+    }
+
+    /// All text written during the session, without styles or control codes.
+    ///
+    /// Convenient for golden-file comparisons that should not depend on
+    /// colors or timing.
+    pub fn plain_text(&self) -> String {
+        self.frames
+            .iter()
+            .flat_map(|f| f.segments.iter())
+            .filter(|s| !s.is_control())
+            .map(|s| s.text.as_str())
+            .collect()
+    }
+
+    /// The screen contents after each frame, as a terminal would show them.
+    ///
+    /// Cursor movement, carriage returns and line erasure are applied, so
+    /// a display that redraws in place yields one complete picture per
+    /// refresh. Frames that only move the cursor still produce a screen;
+    /// their delay is carried over to the next one.
+    pub fn screens(&self) -> Vec<SessionScreen> {
+        let mut terminal = VirtualTerminal::default();
+        let mut screens: Vec<SessionScreen> = Vec::new();
+        let mut pending = Duration::ZERO;
+        for frame in &self.frames {
+            pending += frame.delay;
+            let writes_text = frame
+                .segments
+                .iter()
+                .any(|s| !s.is_control() && !s.text.is_empty());
+            for segment in &frame.segments {
+                terminal.write(segment);
+            }
+            if writes_text {
+                screens.push(SessionScreen {
+                    delay: pending,
+                    lines: terminal.lines(),
+                });
+                pending = Duration::ZERO;
+            }
+        }
+        screens
+    }
+
+    /// Serialize the session to its JSON Lines form.
+    #[cfg(feature = "json")]
+    pub fn to_jsonl(&self) -> String {
+        let header = json!({
+            "version": SESSION_FORMAT_VERSION,
+            "width": self.width,
+            "height": self.height,
+        });
+        let mut out = header.to_string();
+        out.push('\n');
+        for frame in &self.frames {
+            let segments: Vec<Value> = frame.segments.iter().map(segment_to_json).collect();
+            let line = json!({
+                "delay": frame.delay.as_secs_f64(),
+                "segments": segments,
+            });
+            out.push_str(&line.to_string());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Parse a session from its JSON Lines form.
+    ///
+    /// Blank lines are ignored.
+    #[cfg(feature = "json")]
+    pub fn from_jsonl(data: &str) -> Result<Self, SessionError> {
+        let mut lines = data
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l))
+            .filter(|(_, l)| !l.trim().is_empty());
+
+        let (header_line, header) = lines.next().ok_or(SessionError::Format {
+            line: 1,
+            message: "missing header".to_string(),
+        })?;
+        let header = parse_object(header_line, header)?;
+        let version = header.get("version").and_then(Value::as_u64);
+        if version != Some(SESSION_FORMAT_VERSION) {
+            return Err(format_error(
+                header_line,
+                format!("unsupported version {:?}", version),
+            ));
+        }
+        let dimension = |key: &str| {
+            header
+                .get(key)
+                .and_then(Value::as_u64)
+                .map(|v| v as usize)
+                .ok_or_else(|| format_error(header_line, format!("missing '{}'", key)))
+        };
+        let mut session = Session::new(dimension("width")?, dimension("height")?);
+
+        for (line, raw) in lines {
+            let frame = parse_object(line, raw)?;
+            let delay = frame
+                .get("delay")
+                .and_then(Value::as_f64)
+                .filter(|d| d.is_finite() && *d >= 0.0)
+                .ok_or_else(|| format_error(line, "missing or negative 'delay'"))?;
+            let segments = frame
+                .get("segments")
+                .and_then(Value::as_array)
+                .ok_or_else(|| format_error(line, "missing 'segments'"))?
+                .iter()
+                .map(|s| segment_from_json(line, s))
+                .collect::<Result<Vec<_>, _>>()?;
+            session.push(Duration::from_secs_f64(delay), segments);
+        }
+        Ok(session)
+    }
+
+    /// Write the session to a file.
+    #[cfg(feature = "json")]
+    pub fn save(&self, path: &str) -> Result<(), SessionError> {
+        std::fs::write(path, self.to_jsonl())?;
+        Ok(())
+    }
+
+    /// Read a session from a file.
+    #[cfg(feature = "json")]
+    pub fn load(path: &str) -> Result<Self, SessionError> {
+        let data = std::fs::read_to_string(path)?;
+        Session::from_jsonl(&data)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// VirtualTerminal
+// ---------------------------------------------------------------------------
+
+/// One terminal cell: a grapheme and its style. The cell to the right of a
+/// double-width grapheme holds an empty string.
+type Cell = (String, Option<Style>);
+
+/// A minimal terminal emulator for the segments gilt itself writes: text,
+/// newlines, relative and absolute cursor movement, and line erasure.
+#[derive(Debug, Default)]
+struct VirtualTerminal {
+    rows: Vec<Vec<Cell>>,
+    row: usize,
+    col: usize,
+}
+
+impl VirtualTerminal {
+    fn write(&mut self, segment: &Segment) {
+        if let Some(codes) = &segment.control {
+            for code in codes {
+                self.apply(code);
+            }
+        }
+        for (_, cluster, width) in cell_clusters(&segment.text) {
+            match cluster {
+                "\n" | "\r\n" => {
+                    self.row += 1;
+                    self.col = 0;
+                }
+                "\r" => self.col = 0,
+                _ if width == 0 => {
+                    // Combining marks attach to the previous cell.
+                    if let Some(cell) = self
+                        .col
+                        .checked_sub(1)
+                        .and_then(|c| self.rows.get_mut(self.row)?.get_mut(c))
+                    {
+                        cell.0.push_str(cluster);
+                    }
+                }
+                _ => {
+                    self.put(cluster, segment.style.clone());
+                    for _ in 1..width {
+                        self.put("", segment.style.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn put(&mut self, text: &str, style: Option<Style>) {
+        if self.rows.len() <= self.row {
+            self.rows.resize_with(self.row + 1, Vec::new);
+        }
+        let line = &mut self.rows[self.row];
+        if line.len() <= self.col {
+            line.resize(self.col + 1, (" ".to_string(), None));
+        }
+        line[self.col] = (text.to_string(), style);
+        self.col += 1;
+    }
+
+    fn apply(&mut self, code: &ControlCode) {
+        let (control_type, n, m) = match code {
+            ControlCode::Simple(t) => (*t, None, None),
+            ControlCode::WithParam(t, n) => (*t, Some(*n), None),
+            ControlCode::WithTwoParams(t, a, b) => (*t, Some(*a), Some(*b)),
+            ControlCode::WithParamStr(..) => return,
+        };
+        let count = n.unwrap_or(1).max(0) as usize;
+        match control_type {
+            ControlType::CarriageReturn => self.col = 0,
+            ControlType::Home => (self.row, self.col) = (0, 0),
+            ControlType::Clear => self.rows.clear(),
+            ControlType::CursorUp => self.row = self.row.saturating_sub(count),
+            ControlType::CursorDown => self.row += count,
+            ControlType::CursorForward => self.col += count,
+            ControlType::CursorBackward => self.col = self.col.saturating_sub(count),
+            ControlType::CursorMoveToColumn => self.col = n.unwrap_or(0).max(0) as usize,
+            ControlType::CursorMoveTo => {
+                self.col = n.unwrap_or(0).max(0) as usize;
+                self.row = m.unwrap_or(0).max(0) as usize;
+            }
+            ControlType::EraseInLine => {
+                let col = self.col;
+                if let Some(line) = self.rows.get_mut(self.row) {
+                    match n.unwrap_or(0) {
+                        0 => line.truncate(col),
+                        1 => {
+                            for cell in line.iter_mut().take(col + 1) {
+                                *cell = (" ".to_string(), None);
+                            }
+                        }
+                        _ => line.clear(),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The screen as lines of segments, merging runs of equal style.
+    fn lines(&self) -> Vec<Vec<Segment>> {
+        let mut lines: Vec<Vec<Segment>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut segments: Vec<Segment> = Vec::new();
+                for (text, style) in row {
+                    match segments.last_mut() {
+                        Some(last) if last.style == *style => last.text.push_str(text),
+                        _ => segments.push(Segment::new(text, style.clone(), None)),
+                    }
+                }
+                segments
+            })
+            .collect();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        lines
+    }
+}
+
+// ---------------------------------------------------------------------------
+// JSON encoding helpers
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "json")]
+fn format_error(line: usize, message: impl Into<String>) -> SessionError {
+    SessionError::Format {
+        line,
+        message: message.into(),
+    }
+}
+
+#[cfg(feature = "json")]
+fn parse_object(line: usize, raw: &str) -> Result<Map<String, Value>, SessionError> {
+    match serde_json::from_str(raw) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(format_error(line, "expected a JSON object")),
+        Err(e) => Err(format_error(line, e.to_string())),
+    }
+}
+
+#[cfg(feature = "json")]
+fn segment_to_json(segment: &Segment) -> Value {
+    let mut map = Map::new();
+    map.insert("text".to_string(), Value::from(segment.text.as_str()));
+    if let Some(style) = segment.style.as_ref().filter(|s| !s.is_null()) {
+        map.insert("style".to_string(), Value::from(style.to_string()));
+    }
+    if let Some(codes) = &segment.control {
+        let codes: Vec<Value> = codes.iter().map(control_to_json).collect();
+        map.insert("control".to_string(), Value::from(codes));
+    }
+    Value::Object(map)
+}
+
+#[cfg(feature = "json")]
+fn segment_from_json(line: usize, value: &Value) -> Result<Segment, SessionError> {
+    let text = value
+        .get("text")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format_error(line, "segment without 'text'"))?;
+    let style = match value.get("style").and_then(Value::as_str) {
+        Some(definition) => {
+            Some(Style::parse(definition).map_err(|e| format_error(line, e.to_string()))?)
+        }
+        None => None,
+    };
+    let control = match value.get("control") {
+        Some(Value::Array(codes)) => Some(
+            codes
+                .iter()
+                .map(|c| control_from_json(line, c))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        Some(_) => return Err(format_error(line, "'control' must be an array")),
+        None => None,
+    };
+    Ok(Segment::new(text, style, control))
+}
+
+#[cfg(feature = "json")]
+fn control_to_json(code: &ControlCode) -> Value {
+    match code {
+        ControlCode::Simple(t) => json!([*t as u8]),
+        ControlCode::WithParam(t, n) => json!([*t as u8, n]),
+        ControlCode::WithParamStr(t, s) => json!([*t as u8, s]),
+        ControlCode::WithTwoParams(t, a, b) => json!([*t as u8, a, b]),
+    }
+}
+
+#[cfg(feature = "json")]
+fn control_from_json(line: usize, value: &Value) -> Result<ControlCode, SessionError> {
+    let invalid = || format_error(line, format!("invalid control code {}", value));
+    let parts = value.as_array().ok_or_else(invalid)?;
+    let control_type = parts
+        .first()
+        .and_then(Value::as_u64)
+        .and_then(control_type_from_u64)
+        .ok_or_else(invalid)?;
+    let int = |v: &Value| v.as_i64().and_then(|n| i32::try_from(n).ok());
+    match &parts[1..] {
+        [] => Ok(ControlCode::Simple(control_type)),
+        [Value::String(s)] => Ok(ControlCode::WithParamStr(control_type, s.clone())),
+        [n] => int(n)
+            .map(|n| ControlCode::WithParam(control_type, n))
+            .ok_or_else(invalid),
+        [a, b] => match (int(a), int(b)) {
+            (Some(a), Some(b)) => Ok(ControlCode::WithTwoParams(control_type, a, b)),
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(feature = "json")]
+fn control_type_from_u64(n: u64) -> Option<ControlType> {
+    use ControlType::*;
+    let control_type = match n {
+        1 => Bell,
+        2 => CarriageReturn,
+        3 => Home,
+        4 => Clear,
+        5 => ShowCursor,
+        6 => HideCursor,
+        7 => EnableAltScreen,
+        8 => DisableAltScreen,
+        9 => CursorUp,
+        10 => CursorDown,
+        11 => CursorForward,
+        12 => CursorBackward,
+        13 => CursorMoveToColumn,
+        14 => CursorMoveTo,
+        15 => EraseInLine,
+        16 => SetWindowTitle,
+        17 => BeginSync,
+        18 => EndSync,
+        19 => SetClipboard,
+        20 => RequestClipboard,
+        _ => return None,
+    };
+    Some(control_type)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Session {
+        let mut session = Session::new(40, 10);
+        session.push(
+            Duration::ZERO,
+            vec![
+                Segment::styled("Hello", Style::parse("bold red").unwrap()),
+                Segment::line(),
+            ],
+        );
+        session.push(
+            Duration::from_millis(250),
+            vec![Segment::new(
+                "",
+                None,
+                Some(vec![
+                    ControlCode::WithParam(ControlType::CursorUp, 1),
+                    ControlCode::WithTwoParams(ControlType::CursorMoveTo, 3, 4),
+                    ControlCode::WithParamStr(ControlType::SetWindowTitle, "t".into()),
+                    ControlCode::Simple(ControlType::EraseInLine),
+                ]),
+            )],
+        );
+        session
+    }
+
+    #[test]
+    fn test_duration_and_plain_text() {
+        let session = sample();
+        assert_eq!(session.len(), 2);
+        assert_eq!(session.duration(), Duration::from_millis(250));
+        assert_eq!(session.plain_text(), "Hello\n");
+    }
+
+    fn screen_text(screen: &SessionScreen) -> Vec<String> {
+        screen
+            .lines
+            .iter()
+            .map(|l| l.iter().map(|s| s.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_screens_apply_cursor_movement() {
+        let mut session = Session::new(20, 5);
+        session.push(Duration::ZERO, vec![Segment::text("frame 1\nline two")]);
+        // Only the cursor moves: no screen of its own, delay carries over.
+        session.push(
+            Duration::from_millis(100),
+            vec![Segment::new(
+                "",
+                None,
+                Some(vec![
+                    ControlCode::Simple(ControlType::CarriageReturn),
+                    ControlCode::WithParam(ControlType::CursorUp, 1),
+                    ControlCode::WithParam(ControlType::EraseInLine, 2),
+                ]),
+            )],
+        );
+        session.push(Duration::from_millis(100), vec![Segment::text("frame 2")]);
+
+        let screens = session.screens();
+        assert_eq!(screens.len(), 2);
+        assert_eq!(screen_text(&screens[0]), ["frame 1", "line two"]);
+        assert_eq!(screens[1].delay, Duration::from_millis(200));
+        assert_eq!(screen_text(&screens[1]), ["frame 2", "line two"]);
+    }
+
+    #[test]
+    fn test_screens_merge_styles_and_wide_chars() {
+        let bold = Style::parse("bold").unwrap();
+        let mut session = Session::new(20, 5);
+        session.push(
+            Duration::ZERO,
+            vec![
+                Segment::styled("ab", bold.clone()),
+                Segment::styled("c", bold.clone()),
+                Segment::text("日本"),
+            ],
+        );
+        let lines = &session.screens()[0].lines;
+        assert_eq!(lines[0].len(), 2);
+        assert_eq!(lines[0][0], Segment::styled("abc", bold));
+        assert_eq!(lines[0][1].text, "日本");
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_jsonl_round_trip() {
+        let session = sample();
+        let data = session.to_jsonl();
+        assert!(data.starts_with("{\"height\":10,\"version\":1,\"width\":40}\n"));
+        assert_eq!(data.lines().count(), 3);
+        assert_eq!(Session::from_jsonl(&data).unwrap(), session);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_unstyled_segments_omit_style() {
+        let mut session = Session::new(10, 2);
+        session.push(Duration::ZERO, vec![Segment::text("x")]);
+        let data = session.to_jsonl();
+        assert!(data.contains("{\"text\":\"x\"}"));
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_from_jsonl_errors() {
+        assert!(matches!(
+            Session::from_jsonl(""),
+            Err(SessionError::Format { line: 1, .. })
+        ));
+        assert!(matches!(
+            Session::from_jsonl("{\"version\":2,\"width\":1,\"height\":1}"),
+            Err(SessionError::Format { line: 1, .. })
+        ));
+        let bad_frame =
+            "{\"version\":1,\"width\":1,\"height\":1}\n\n{\"delay\":-1,\"segments\":[]}";
+        match Session::from_jsonl(bad_frame) {
+            Err(SessionError::Format { line, .. }) => assert_eq!(line, 3),
+            other => panic!("expected format error, got {:?}", other),
+        }
+        let bad_control = "{\"version\":1,\"width\":1,\"height\":1}\n\
+             {\"delay\":0,\"segments\":[{\"text\":\"\",\"control\":[[99]]}]}";
+        assert!(Session::from_jsonl(bad_control).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("gilt_session_{}.jsonl", std::process::id()));
+        let path = path.to_str().unwrap();
+        let session = sample();
+        session.save(path).unwrap();
+        let loaded = Session::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded, session);
+        assert!(matches!(Session::load(path), Err(SessionError::Io(_))));
+    }
+}
@@ -4,6 +4,10 @@
 //! characters (`\u{2581}`..`\u{2588}`), ideal for inline visualisation of
 //! time-series data, CPU usage, stock prices, and similar metrics.
 //!
+//! Points can be colored by a threshold, the maximum and minimum can be
+//! marked with their own styles, and a Braille mode packs two points into
+//! every column for twice the horizontal resolution.
+//!
 //! # Example
 //!
 //! ```
@@ -36,6 +40,22 @@ const BARS: [char; 8] = [
     '\u{2588}', // FULL BLOCK
 ];
 
+/// Braille dots for the left point of a cell, 1..=4 dots filled from the bottom.
+const BRAILLE_LEFT: [u32; 4] = [0x40, 0x44, 0x46, 0x47];
+
+/// Braille dots for the right point of a cell, 1..=4 dots filled from the bottom.
+const BRAILLE_RIGHT: [u32; 4] = [0x80, 0xA0, 0xB0, 0xB8];
+
+/// How a rendered column is styled, in increasing order of precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mark {
+    Plain,
+    Below,
+    Above,
+    Min,
+    Max,
+}
+
 // ---------------------------------------------------------------------------
 // Sparkline
 // ---------------------------------------------------------------------------
@@ -57,6 +77,19 @@ pub struct Sparkline {
     max_value: Option<f64>,
     /// Visual style applied to the sparkline output.
     style: Style,
+    /// Values at or above this are drawn with `above_style`, the rest with
+    /// `below_style`.
+    threshold: Option<f64>,
+    /// Style layered over points at or above the threshold.
+    above_style: Style,
+    /// Style layered over points below the threshold.
+    below_style: Style,
+    /// Style marking the maximum point, if any.
+    max_style: Option<Style>,
+    /// Style marking the minimum point, if any.
+    min_style: Option<Style>,
+    /// Draw two points per column with Braille dots instead of blocks.
+    braille: bool,
 }
 
 impl Sparkline {
@@ -68,6 +101,12 @@ impl Sparkline {
             min_value: None,
             max_value: None,
             style: Style::null(),
+            threshold: None,
+            above_style: Style::null(),
+            below_style: Style::null(),
+            max_style: None,
+            min_style: None,
+            braille: false,
         }
     }
 
@@ -95,6 +134,17 @@ impl Sparkline {
         self
     }
 
+    /// Set a fixed y-range for scaling (builder pattern).
+    ///
+    /// Equivalent to [`with_min`](Self::with_min) plus
+    /// [`with_max`](Self::with_max); values outside the range are clamped.
+    #[must_use]
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min_value = Some(min);
+        self.max_value = Some(max);
+        self
+    }
+
     /// Set the visual style (builder pattern).
     #[must_use]
     pub fn with_style(mut self, style: Style) -> Self {
@@ -102,6 +152,43 @@ impl Sparkline {
         self
     }
 
+    /// Color points relative to a threshold (builder pattern).
+    ///
+    /// Points at or above `threshold` get `above` layered over the base
+    /// style; points below it get `below`.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f64, above: Style, below: Style) -> Self {
+        self.threshold = Some(threshold);
+        self.above_style = above;
+        self.below_style = below;
+        self
+    }
+
+    /// Mark the maximum point with `style` (builder pattern).
+    #[must_use]
+    pub fn with_max_style(mut self, style: Style) -> Self {
+        self.max_style = Some(style);
+        self
+    }
+
+    /// Mark the minimum point with `style` (builder pattern).
+    #[must_use]
+    pub fn with_min_style(mut self, style: Style) -> Self {
+        self.min_style = Some(style);
+        self
+    }
+
+    /// Draw with Braille dots, two points per column (builder pattern).
+    ///
+    /// This doubles the horizontal resolution at the cost of vertical
+    /// resolution: each point is one to four dots tall instead of one of
+    /// eight block heights. A fixed width then holds `2 * width` points.
+    #[must_use]
+    pub fn with_braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+
     // -- internal helpers ---------------------------------------------------
 
     /// Resample `data` to `target_len` points using linear interpolation.
@@ -124,25 +211,36 @@ impl Sparkline {
             .collect()
     }
 
-    /// Render the sparkline data into a `String` of bar characters.
-    fn render_bars(&self) -> String {
+    /// Number of data points drawn in one column.
+    fn points_per_cell(&self) -> usize {
+        if self.braille {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Render the sparkline data into columns of characters and their marks.
+    fn render_cells(&self) -> Vec<(char, Mark)> {
         if self.data.is_empty() {
-            return String::new();
+            return Vec::new();
         }
 
         // Width of zero explicitly produces empty output.
         if self.width == Some(0) {
-            return String::new();
+            return Vec::new();
         }
 
         // Determine the effective data (resample if width differs).
         let effective: Vec<f64> = match self.width {
-            Some(w) if w != self.data.len() => Self::resample(&self.data, w),
+            Some(w) if w * self.points_per_cell() != self.data.len() => {
+                Self::resample(&self.data, w * self.points_per_cell())
+            }
             _ => self.data.clone(),
         };
 
         if effective.is_empty() {
-            return String::new();
+            return Vec::new();
         }
 
         let min = self
@@ -151,29 +249,95 @@ impl Sparkline {
         let max = self
             .max_value
             .unwrap_or_else(|| effective.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+        let flat = (max - min).abs() < f64::EPSILON;
+
+        let levels = if self.braille { 4 } else { BARS.len() };
+        let level = |v: f64| -> usize {
+            if flat {
+                // Single value => full height; all-same => middle height.
+                return if effective.len() == 1 {
+                    levels - 1
+                } else {
+                    levels / 2 - 1
+                };
+            }
+            let clamped = v.clamp(min, max);
+            let idx = ((clamped - min) / (max - min) * (levels - 1) as f64).round() as usize;
+            idx.min(levels - 1)
+        };
 
-        // Edge case: all values identical (or min == max).
-        if (max - min).abs() < f64::EPSILON {
-            // Single value => full block; all-same => middle block.
-            if effective.len() == 1 {
-                return String::from(BARS[7]);
+        // First occurrence of the extremes; a flat line has none.
+        let extreme = |better: fn(f64, f64) -> bool| {
+            (!flat).then(|| {
+                (1..effective.len()).fold(0, |best, i| {
+                    if better(effective[i], effective[best]) {
+                        i
+                    } else {
+                        best
+                    }
+                })
+            })
+        };
+        let max_idx = self.max_style.as_ref().and(extreme(|a, b| a > b));
+        let min_idx = self.min_style.as_ref().and(extreme(|a, b| a < b));
+        let mark = |i: usize| -> Mark {
+            if Some(i) == max_idx {
+                Mark::Max
+            } else if Some(i) == min_idx {
+                Mark::Min
+            } else {
+                match self.threshold {
+                    Some(t) if effective[i] >= t => Mark::Above,
+                    Some(_) => Mark::Below,
+                    None => Mark::Plain,
+                }
             }
-            return std::iter::repeat_n(BARS[3], effective.len()).collect();
+        };
+
+        if !self.braille {
+            return effective
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| (BARS[level(v)], mark(i)))
+                .collect();
         }
 
-        effective
-            .iter()
-            .map(|&v| {
-                let clamped = v.clamp(min, max);
-                let idx = ((clamped - min) / (max - min) * 7.0).round() as usize;
-                BARS[idx.min(7)]
+        (0..effective.len())
+            .step_by(2)
+            .map(|i| {
+                let mut bits = BRAILLE_LEFT[level(effective[i])];
+                let mut cell_mark = mark(i);
+                if let Some(&right) = effective.get(i + 1) {
+                    bits |= BRAILLE_RIGHT[level(right)];
+                    cell_mark = cell_mark.max(mark(i + 1));
+                }
+                let ch = char::from_u32(0x2800 + bits).unwrap_or(' ');
+                (ch, cell_mark)
             })
             .collect()
     }
 
+    /// Render the sparkline data into a `String` of bar characters.
+    fn render_bars(&self) -> String {
+        self.render_cells().into_iter().map(|(ch, _)| ch).collect()
+    }
+
+    /// Style for a column with the given mark.
+    fn mark_style(&self, mark: Mark) -> Style {
+        let extra = match mark {
+            Mark::Plain => return self.style.clone(),
+            Mark::Below => &self.below_style,
+            Mark::Above => &self.above_style,
+            Mark::Min => self.min_style.as_ref().unwrap_or(&self.style),
+            Mark::Max => self.max_style.as_ref().unwrap_or(&self.style),
+        };
+        self.style.clone() + extra.clone()
+    }
+
     /// Effective output width.
     fn effective_width(&self) -> usize {
-        self.width.unwrap_or(self.data.len())
+        self.width
+            .unwrap_or_else(|| self.data.len().div_ceil(self.points_per_cell()))
     }
 }
 
@@ -193,14 +357,26 @@ impl fmt::Display for Sparkline {
 
 impl Renderable for Sparkline {
     fn gilt_console(&self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment> {
-        let text = self.render_bars();
-        if text.is_empty() {
+        let cells = self.render_cells();
+        if cells.is_empty() {
             return vec![Segment::line()];
         }
-        vec![
-            Segment::new(&text, Some(self.style.clone()), None),
-            Segment::line(),
-        ]
+
+        // One segment per run of equally styled columns.
+        let mut segments = Vec::new();
+        let mut run = String::new();
+        let mut run_mark = cells[0].1;
+        for (ch, mark) in cells {
+            if mark != run_mark {
+                segments.push(Segment::new(&run, Some(self.mark_style(run_mark)), None));
+                run.clear();
+                run_mark = mark;
+            }
+            run.push(ch);
+        }
+        segments.push(Segment::new(&run, Some(self.mark_style(run_mark)), None));
+        segments.push(Segment::line());
+        segments
     }
 }
 
@@ -243,6 +419,7 @@ mod tests {
             highlight: None,
             markup: None,
             height: None,
+            format: Default::default(),
         }
     }
 
@@ -456,4 +633,118 @@ mod tests {
             assert_eq!(ch, BARS[0]);
         }
     }
+
+    // 22. Fixed range
+    #[test]
+    fn test_with_range() {
+        let spark = Sparkline::new(&[0.0, 50.0, 100.0, 150.0]).with_range(0.0, 100.0);
+        let text: Vec<char> = spark.to_string().chars().collect();
+        assert_eq!(text[0], BARS[0]);
+        assert_eq!(text[1], BARS[4]);
+        // Values above the range are clamped to the top
+        assert_eq!(text[2], BARS[7]);
+        assert_eq!(text[3], BARS[7]);
+    }
+
+    // 23. Threshold coloring
+    #[test]
+    fn test_threshold_styles() {
+        let above = Style::parse("green").unwrap();
+        let below = Style::parse("red").unwrap();
+        let spark = Sparkline::new(&[1.0, 2.0, 5.0, 6.0, 1.0]).with_threshold(
+            5.0,
+            above.clone(),
+            below.clone(),
+        );
+        let console = Console::new();
+        let segs = spark.gilt_console(&console, &make_options(80));
+        // Runs: [1, 2] below, [5, 6] above, [1] below, newline
+        assert_eq!(segs.len(), 4);
+        assert_eq!(segs[0].text.chars().count(), 2);
+        assert_eq!(segs[0].style, Some(Style::null() + below.clone()));
+        assert_eq!(segs[1].text.chars().count(), 2);
+        assert_eq!(segs[1].style, Some(Style::null() + above));
+        assert_eq!(segs[2].style, Some(Style::null() + below));
+    }
+
+    // 24. Max and min markers
+    #[test]
+    fn test_max_min_markers() {
+        let max_style = Style::parse("bold green").unwrap();
+        let min_style = Style::parse("bold red").unwrap();
+        let spark = Sparkline::new(&[3.0, 9.0, 1.0, 9.0, 4.0])
+            .with_max_style(max_style.clone())
+            .with_min_style(min_style.clone());
+        let cells = spark.render_cells();
+        let marks: Vec<Mark> = cells.iter().map(|(_, m)| *m).collect();
+        // Only the first maximum is marked
+        assert_eq!(
+            marks,
+            vec![Mark::Plain, Mark::Max, Mark::Min, Mark::Plain, Mark::Plain]
+        );
+        assert_eq!(spark.mark_style(Mark::Max), Style::null() + max_style);
+        assert_eq!(spark.mark_style(Mark::Min), Style::null() + min_style);
+    }
+
+    // 25. Markers take precedence over the threshold
+    #[test]
+    fn test_markers_override_threshold() {
+        let spark = Sparkline::new(&[1.0, 5.0, 3.0])
+            .with_threshold(
+                2.0,
+                Style::parse("green").unwrap(),
+                Style::parse("red").unwrap(),
+            )
+            .with_max_style(Style::parse("bold").unwrap());
+        let marks: Vec<Mark> = spark.render_cells().iter().map(|(_, m)| *m).collect();
+        assert_eq!(marks, vec![Mark::Below, Mark::Max, Mark::Above]);
+    }
+
+    // 26. Flat data has no extremes
+    #[test]
+    fn test_flat_data_no_markers() {
+        let spark = Sparkline::new(&[2.0, 2.0, 2.0]).with_max_style(Style::parse("bold").unwrap());
+        assert!(spark.render_cells().iter().all(|(_, m)| *m == Mark::Plain));
+    }
+
+    // 27. Braille mode packs two points per column
+    #[test]
+    fn test_braille_mode() {
+        let spark = Sparkline::new(&[0.0, 1.0, 2.0, 3.0]).with_braille(true);
+        let text: Vec<char> = spark.to_string().chars().collect();
+        assert_eq!(text.len(), 2);
+        // 1 dot left + 2 dots right, then 3 dots left + 4 dots right
+        assert_eq!(text[0], char::from_u32(0x2800 + 0x40 + 0xA0).unwrap());
+        assert_eq!(text[1], char::from_u32(0x2800 + 0x46 + 0xB8).unwrap());
+    }
+
+    // 28. Braille mode with an odd number of points
+    #[test]
+    fn test_braille_odd_length() {
+        let spark = Sparkline::new(&[0.0, 3.0, 3.0]).with_braille(true);
+        let text: Vec<char> = spark.to_string().chars().collect();
+        assert_eq!(text.len(), 2);
+        assert_eq!(text[1], char::from_u32(0x2800 + 0x47).unwrap());
+        let console = Console::new();
+        assert_eq!(spark.measure(&console, &make_options(80)).maximum, 2);
+    }
+
+    // 29. Braille mode with a fixed width resamples to twice the columns
+    #[test]
+    fn test_braille_width() {
+        let data: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let spark = Sparkline::new(&data).with_braille(true).with_width(10);
+        assert_eq!(spark.to_string().chars().count(), 10);
+    }
+
+    // 30. Braille cells take the strongest mark of their two points
+    #[test]
+    fn test_braille_marks() {
+        let spark = Sparkline::new(&[1.0, 9.0, 4.0, 5.0])
+            .with_braille(true)
+            .with_threshold(4.5, Style::null(), Style::null())
+            .with_max_style(Style::parse("bold").unwrap());
+        let marks: Vec<Mark> = spark.render_cells().iter().map(|(_, m)| *m).collect();
+        assert_eq!(marks, vec![Mark::Max, Mark::Above]);
+    }
 }
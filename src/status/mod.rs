@@ -13,13 +13,27 @@
 //! status.update().status("Processing...").apply();
 //! status.stop();
 //! ```
+//!
+//! Multi-phase operations can push sub-status lines under the spinner with
+//! [`Status::push`] / [`Status::pop`], and [`Status::with_elapsed`] appends
+//! the time spent so far to each line:
+//!
+//! ```text
+//! ⠋ Building release (0:12)
+//!   └ compiling gilt (0:03)
+//!     └ codegen (0:01)
+//! ```
 
 pub mod spinner;
 pub mod spinners;
 pub mod toast;
 
+use std::sync::{Arc, Mutex};
+
+use crate::clock::{Clock, SystemClock};
 use crate::console::Console;
 use crate::live::{ConsoleRef, Live};
+use crate::progress::format_time;
 use crate::status::spinner::{Spinner, SpinnerError};
 use crate::style::Style;
 use crate::text::Text;
@@ -123,24 +137,70 @@ impl<'a> StatusUpdate<'a> {
                 .with_text(Text::new(&self.status.status_text, Style::null()))
                 .with_style(self.status.spinner_style.clone())
                 .with_speed(self.status.speed);
-            self.status.spinner = spinner;
+            self.status
+                .edit_spinner(|current| *current = spinner.clone());
 
             // Push the new renderable to the live display.
-            let text = render_spinner_snapshot(&self.status.spinner);
+            let text = self.status.frame();
             self.status.live.update_renderable(text, true);
         } else {
             // Update the existing spinner in place.
-            self.status.spinner.update(
-                Some(Text::new(&self.status.status_text, Style::null())),
-                Some(self.status.spinner_style.clone()),
-                Some(self.status.speed),
-            );
+            let text = Text::new(&self.status.status_text, Style::null());
+            let style = self.status.spinner_style.clone();
+            let speed = self.status.speed;
+            self.status.edit_spinner(|spinner| {
+                spinner.update(Some(text.clone()), Some(style.clone()), Some(speed))
+            });
         }
 
         Ok(())
     }
 }
 
+// ---------------------------------------------------------------------------
+// Nested lines
+// ---------------------------------------------------------------------------
+
+/// Sub-status lines and elapsed-time bookkeeping, shared with the live
+/// display's render callback.
+#[derive(Debug, Default)]
+struct Nested {
+    /// Pushed sub-status lines with the clock time each was pushed at.
+    lines: Vec<(String, f64)>,
+    /// Whether to append the elapsed time to each line.
+    show_elapsed: bool,
+    /// Clock time of the first frame, the origin for the main line's elapsed time.
+    started_at: Option<f64>,
+}
+
+/// Render the spinner line followed by one indented line per nested status.
+fn render_frame(spinner: &Mutex<Spinner>, nested: &Mutex<Nested>, now: f64) -> Text {
+    let mut text = spinner.lock().unwrap().render(now);
+    let mut nested = nested.lock().unwrap();
+    let started_at = *nested.started_at.get_or_insert(now);
+    let dim = Style::parse("dim").unwrap_or_else(|_| Style::null());
+
+    if nested.show_elapsed {
+        text.append_str(
+            &format!(" ({})", format_time(now - started_at)),
+            Some(dim.clone()),
+        );
+    }
+    for (depth, (line, pushed_at)) in nested.lines.iter().enumerate() {
+        text.append_str("\n", None);
+        text.append_str(&"  ".repeat(depth + 1), None);
+        text.append_str("\u{2514} ", Some(dim.clone()));
+        text.append_str(line, None);
+        if nested.show_elapsed {
+            text.append_str(
+                &format!(" ({})", format_time(now - pushed_at)),
+                Some(dim.clone()),
+            );
+        }
+    }
+    text
+}
+
 // ---------------------------------------------------------------------------
 // Status
 // ---------------------------------------------------------------------------
@@ -176,25 +236,19 @@ pub struct Status {
     pub spinner_style: Style,
     /// The speed multiplier for the spinner.
     pub speed: f64,
-    /// The spinner animation.
+    /// The spinner as configured, returned by [`renderable`](Status::renderable).
     spinner: Spinner,
+    /// The animating copy of the spinner, shared with the live display's
+    /// render callback.
+    animation: Arc<Mutex<Spinner>>,
+    /// Nested sub-status lines and elapsed-time state.
+    nested: Arc<Mutex<Nested>>,
+    /// Time source for spinner frames.
+    clock: Arc<dyn Clock>,
     /// The live display that handles in-place terminal rendering.
     live: Live,
 }
 
-/// Render a spinner at time 0 to produce a `Text` snapshot for the live display.
-fn render_spinner_snapshot(spinner: &Spinner) -> Text {
-    let mut spinner_clone = Spinner::new(&spinner.name).unwrap();
-    spinner_clone = spinner_clone.with_speed(spinner.speed);
-    if let Some(ref text) = spinner.text {
-        spinner_clone = spinner_clone.with_text(text.clone());
-    }
-    if let Some(ref style) = spinner.style {
-        spinner_clone = spinner_clone.with_style(style.clone());
-    }
-    spinner_clone.render(0.0)
-}
-
 impl Status {
     /// Create a new `Status` with default settings.
     ///
@@ -227,18 +281,32 @@ impl Status {
             .with_style(spinner_style.clone())
             .with_speed(speed);
 
-        let renderable_text = render_spinner_snapshot(&spinner);
-        let live = Live::new(renderable_text)
+        let live = Live::new(Text::empty())
             .with_refresh_per_second(refresh_per_second)
             .with_transient(true);
 
-        Ok(Status {
+        let mut status = Status {
             status_text: status.to_string(),
             spinner_style,
             speed,
+            animation: Arc::new(Mutex::new(spinner.clone())),
             spinner,
+            nested: Arc::new(Mutex::new(Nested::default())),
+            clock: Arc::new(SystemClock),
             live,
-        })
+        };
+        status.install_frames();
+        Ok(status)
+    }
+
+    /// Point the live display at the shared spinner so every refresh renders
+    /// the frame for the current clock time.
+    fn install_frames(&mut self) {
+        let spinner = Arc::clone(&self.animation);
+        let nested = Arc::clone(&self.nested);
+        let clock = Arc::clone(&self.clock);
+        let live = std::mem::replace(&mut self.live, Live::new(Text::empty()));
+        self.live = live.with_get_renderable(move || render_frame(&spinner, &nested, clock.now()));
     }
 
     /// Builder method: set the spinner animation by name.
@@ -251,9 +319,7 @@ impl Status {
             .with_text(Text::new(&self.status_text, Style::null()))
             .with_style(self.spinner_style.clone())
             .with_speed(self.speed);
-        self.spinner = spinner;
-        let text = render_spinner_snapshot(&self.spinner);
-        self.live.update_renderable(text, false);
+        self.edit_spinner(|current| *current = spinner.clone());
         Ok(self)
     }
 
@@ -261,8 +327,7 @@ impl Status {
     #[must_use]
     pub fn with_spinner_style(mut self, style: Style) -> Self {
         self.spinner_style = style.clone();
-        self.spinner =
-            std::mem::replace(&mut self.spinner, Spinner::new("dots").unwrap()).with_style(style);
+        self.edit_spinner(|spinner| spinner.style = Some(style.clone()));
         self
     }
 
@@ -270,8 +335,7 @@ impl Status {
     #[must_use]
     pub fn with_speed(mut self, speed: f64) -> Self {
         self.speed = speed;
-        self.spinner =
-            std::mem::replace(&mut self.spinner, Spinner::new("dots").unwrap()).with_speed(speed);
+        self.edit_spinner(|spinner| spinner.speed = speed);
         self
     }
 
@@ -279,29 +343,133 @@ impl Status {
     #[must_use]
     pub fn with_console(mut self, console: Console) -> Self {
         // Rebuild live with the new console, preserving other settings.
-        let renderable_text = render_spinner_snapshot(&self.spinner);
-        self.live = Live::new(renderable_text)
+        self.live = Live::new(Text::empty())
             .with_console(console)
             .with_refresh_per_second(self.live.refresh_per_second)
             .with_transient(self.live.transient);
+        self.install_frames();
         self
     }
 
     /// Builder method: set the refresh rate (refreshes per second).
     #[must_use]
     pub fn with_refresh_per_second(mut self, rate: f64) -> Self {
-        let renderable_text = render_spinner_snapshot(&self.spinner);
-        self.live = Live::new(renderable_text)
+        self.live = Live::new(Text::empty())
             .with_refresh_per_second(rate)
             .with_transient(self.live.transient);
+        self.install_frames();
+        self
+    }
+
+    /// Builder method: read spinner frames from `clock` instead of the wall
+    /// clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::clock::ManualClock;
+    /// use gilt::status::Status;
+    /// use std::time::Duration;
+    ///
+    /// let clock = ManualClock::new();
+    /// let status = Status::new("Working").with_clock(clock.clone());
+    /// assert_eq!(status.frame().plain(), "⠋ Working");
+    /// clock.advance(Duration::from_millis(80));
+    /// assert_eq!(status.frame().plain(), "⠙ Working");
+    /// ```
+    #[must_use]
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self.nested.lock().unwrap().started_at = None;
+        self.install_frames();
+        self
+    }
+
+    /// Builder method: append the elapsed time to the status line and to
+    /// each nested line, e.g. `⠋ Working (0:05)`.
+    ///
+    /// The main line counts from the first rendered frame; nested lines
+    /// count from when they were [pushed](Status::push).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::clock::ManualClock;
+    /// use gilt::status::Status;
+    /// use std::time::Duration;
+    ///
+    /// let clock = ManualClock::new();
+    /// let status = Status::new("Working").with_clock(clock.clone()).with_elapsed(true);
+    /// assert_eq!(status.frame().plain(), "⠋ Working (0:00)");
+    /// clock.advance(Duration::from_secs(65));
+    /// assert!(status.frame().plain().ends_with("Working (1:05)"));
+    /// ```
+    #[must_use]
+    pub fn with_elapsed(self, show: bool) -> Self {
+        self.nested.lock().unwrap().show_elapsed = show;
         self
     }
 
+    /// Push a nested sub-status line, shown indented under the current
+    /// innermost line until it is [popped](Status::pop).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::status::Status;
+    ///
+    /// let mut status = Status::new("Building");
+    /// status.push("compiling gilt");
+    /// status.push("codegen");
+    /// assert_eq!(status.depth(), 2);
+    /// assert_eq!(status.pop().as_deref(), Some("codegen"));
+    /// assert_eq!(status.depth(), 1);
+    /// ```
+    pub fn push(&mut self, status: &str) {
+        let now = self.clock.now();
+        self.nested
+            .lock()
+            .unwrap()
+            .lines
+            .push((status.to_string(), now));
+    }
+
+    /// Remove the innermost nested line, returning its text, or `None` if
+    /// no lines are pushed.
+    pub fn pop(&mut self) -> Option<String> {
+        self.nested
+            .lock()
+            .unwrap()
+            .lines
+            .pop()
+            .map(|(line, _)| line)
+    }
+
+    /// Number of nested lines currently pushed.
+    pub fn depth(&self) -> usize {
+        self.nested.lock().unwrap().lines.len()
+    }
+
+    /// Render the spinner frame, and any nested lines, for the clock's
+    /// current time.
+    pub fn frame(&self) -> Text {
+        render_frame(&self.animation, &self.nested, self.clock.now())
+    }
+
     /// Get a reference to the spinner.
+    ///
+    /// This is the spinner as configured; its animation state, such as
+    /// `start_time`, advances on the copy the live display renders.
     pub fn renderable(&self) -> &Spinner {
         &self.spinner
     }
 
+    /// Apply `edit` to the configured spinner and to its animating copy.
+    fn edit_spinner(&mut self, edit: impl Fn(&mut Spinner)) {
+        edit(&mut self.spinner);
+        edit(&mut self.animation.lock().unwrap());
+    }
+
     /// Get a reference to the console (from the live display).
     pub fn console(&self) -> ConsoleRef<'_> {
         self.live.console()
@@ -326,6 +494,23 @@ impl Status {
         self.live.start();
     }
 
+    /// Start the live display with refreshes driven by a Tokio task instead
+    /// of a background thread. See [`Live::start_async`].
+    #[cfg(feature = "async")]
+    pub fn start_async(&mut self) {
+        self.live.start_async();
+    }
+
+    /// Show the status while `future` runs, stopping it once the future
+    /// completes. See [`Live::run_async`].
+    #[cfg(feature = "async")]
+    pub async fn run_async<Fut>(&mut self, future: Fut) -> Fut::Output
+    where
+        Fut: std::future::Future,
+    {
+        self.live.run_async(|_| future).await
+    }
+
     /// Stop the live display.
     pub fn stop(&mut self) {
         self.live.stop();
@@ -368,6 +553,16 @@ mod tests {
         assert_eq!(status.spinner.name, "dots");
     }
 
+    #[test]
+    fn test_renderable_tracks_updates() {
+        let mut status = Status::new("test");
+        status.update().spinner("line").speed(2.0).apply().unwrap();
+        let spinner: &Spinner = status.renderable();
+        assert_eq!(spinner.name, "line");
+        assert_eq!(spinner.speed, 2.0);
+        assert_eq!(status.animation.lock().unwrap().name, "line");
+    }
+
     #[test]
     fn test_default_spinner_has_text() {
         let status = Status::new("Working");
@@ -692,4 +887,84 @@ mod tests {
         assert_eq!(status.spinner.name, "line");
         status.stop();
     }
+
+    // -- Clock ---------------------------------------------------------------
+
+    #[test]
+    fn test_frames_follow_manual_clock() {
+        use crate::clock::ManualClock;
+        use std::time::Duration;
+
+        let clock = ManualClock::new();
+        let status = Status::new("x")
+            .with_spinner("line")
+            .unwrap()
+            .with_clock(clock.clone());
+        let frames: Vec<String> = (0..5)
+            .map(|_| {
+                let frame = status.frame().plain().to_string();
+                clock.advance(Duration::from_millis(130));
+                frame
+            })
+            .collect();
+        assert_eq!(frames, vec!["- x", "\\ x", "| x", "/ x", "- x"]);
+    }
+
+    #[test]
+    fn test_update_keeps_clock() {
+        use crate::clock::ManualClock;
+        use std::time::Duration;
+
+        let clock = ManualClock::new();
+        let mut status = Status::new("a").with_clock(clock.clone());
+        status.frame();
+        clock.advance(Duration::from_millis(80));
+        status.update().status("b").apply().unwrap();
+        assert_eq!(status.frame().plain(), "\u{2819} b");
+    }
+
+    // -- Nested lines and elapsed time -------------------------------------
+
+    #[test]
+    fn test_push_and_pop_nested_lines() {
+        let mut status = Status::new("Building").with_spinner("line").unwrap();
+        status.push("compiling");
+        status.push("codegen");
+        assert_eq!(status.depth(), 2);
+        assert_eq!(
+            status.frame().plain(),
+            "- Building\n  \u{2514} compiling\n    \u{2514} codegen"
+        );
+        assert_eq!(status.pop().as_deref(), Some("codegen"));
+        assert_eq!(status.pop().as_deref(), Some("compiling"));
+        assert_eq!(status.pop(), None);
+        assert_eq!(status.frame().plain(), "- Building");
+    }
+
+    #[test]
+    fn test_elapsed_suffix_per_line() {
+        use crate::clock::ManualClock;
+        use std::time::Duration;
+
+        let clock = ManualClock::new();
+        let mut status = Status::new("Building")
+            .with_spinner("line")
+            .unwrap()
+            .with_clock(clock.clone())
+            .with_elapsed(true);
+        status.frame();
+        clock.advance(Duration::from_secs(10));
+        status.push("linking");
+        clock.advance(Duration::from_millis(3000));
+        let frame = status.frame();
+        let lines: Vec<&str> = frame.plain().lines().collect();
+        assert!(lines[0].ends_with("Building (0:13)"));
+        assert_eq!(lines[1], "  \u{2514} linking (0:03)");
+    }
+
+    #[test]
+    fn test_elapsed_off_by_default() {
+        let status = Status::new("x").with_spinner("line").unwrap();
+        assert_eq!(status.frame().plain(), "- x");
+    }
 }
@@ -8,7 +8,7 @@ use std::fmt;
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::measure::Measurement;
 use crate::segment::Segment;
-use crate::status::spinners::SPINNERS;
+use crate::status::spinners;
 use crate::style::Style;
 use crate::text::{Text, TextPart};
 
@@ -16,7 +16,7 @@ use crate::text::{Text, TextPart};
 // SpinnerError
 // ---------------------------------------------------------------------------
 
-/// Error returned when a spinner name is not found in the SPINNERS map.
+/// Error returned when a spinner name is unknown or a custom spinner is invalid.
 #[derive(Debug, Clone)]
 pub struct SpinnerError(pub String);
 
@@ -62,30 +62,49 @@ pub struct Spinner {
     pub frame_no_offset: f64,
     /// Pending speed update (applied on next render).
     update_speed: f64,
+    /// Whether frames are console markup (see [`spinners::register_markup`]).
+    markup: bool,
 }
 
 impl Spinner {
     /// Create a new spinner by name.
     ///
-    /// Returns `Err(SpinnerError)` if the name is not found in the SPINNERS map.
+    /// Looks up spinners added with [`Spinner::register`] first, then the
+    /// built-in set. Returns `Err(SpinnerError)` if the name is not found.
     pub fn new(name: &str) -> Result<Spinner, SpinnerError> {
-        let spinner_data = SPINNERS
-            .get(name)
+        let (spinner_data, markup) = spinners::lookup(name)
             .ok_or_else(|| SpinnerError(format!("no spinner called {:?}", name)))?;
 
         Ok(Spinner {
             name: name.to_string(),
             text: None,
-            frames: spinner_data.frames.clone(),
+            frames: spinner_data.frames,
             interval: spinner_data.interval,
             start_time: None,
             style: None,
             speed: 1.0,
             frame_no_offset: 0.0,
             update_speed: 0.0,
+            markup,
         })
     }
 
+    /// Register a custom spinner so it can be created by name with
+    /// [`Spinner::new`]. Shorthand for [`spinners::register`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::spinner::Spinner;
+    ///
+    /// Spinner::register("mykit", &["[=  ]", "[ = ]", "[  =]"], 100.0).unwrap();
+    /// let mut spinner = Spinner::new("mykit").unwrap();
+    /// assert_eq!(spinner.render(0.0).plain(), "[=  ]");
+    /// ```
+    pub fn register(name: &str, frames: &[&str], interval: f64) -> Result<(), SpinnerError> {
+        spinners::register(name, frames, interval)
+    }
+
     /// Builder method: set the text displayed alongside the spinner.
     #[must_use]
     pub fn with_text(mut self, text: Text) -> Self {
@@ -121,7 +140,12 @@ impl Spinner {
         let frame_idx = (frame_no as usize) % self.frames.len();
 
         let frame_style = self.style.clone().unwrap_or_else(Style::null);
-        let frame = Text::new(&self.frames[frame_idx], frame_style);
+        let frame = if self.markup {
+            crate::markup::render(&self.frames[frame_idx], frame_style.clone())
+                .unwrap_or_else(|_| Text::new(&self.frames[frame_idx], frame_style))
+        } else {
+            Text::new(&self.frames[frame_idx], frame_style)
+        };
 
         // Apply pending speed update
         if self.update_speed != 0.0 {
@@ -177,6 +201,7 @@ impl Renderable for Spinner {
             speed: self.speed,
             frame_no_offset: self.frame_no_offset,
             update_speed: self.update_speed,
+            markup: self.markup,
         };
         let text = spinner_clone.render(0.0);
         text.render()
@@ -196,6 +221,7 @@ impl Spinner {
             speed: self.speed,
             frame_no_offset: self.frame_no_offset,
             update_speed: self.update_speed,
+            markup: self.markup,
         };
         let text = spinner_clone.render(0.0);
         text.measure()
@@ -478,4 +504,22 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_register_and_create_custom_spinner() {
+        Spinner::register("test_custom", &["<->", ">-<"], 100.0).unwrap();
+        let mut spinner = Spinner::new("test_custom").unwrap();
+        assert_eq!(spinner.frames.len(), 2);
+        assert_eq!(spinner.render(0.0).plain(), "<->");
+        assert_eq!(spinner.render(0.1).plain(), ">-<");
+    }
+
+    #[test]
+    fn test_markup_frames_are_styled() {
+        spinners::register_markup("test_colored", &["[red]●[/red]○"], 100.0).unwrap();
+        let mut spinner = Spinner::new("test_colored").unwrap();
+        let text = spinner.render(0.0);
+        assert_eq!(text.plain(), "●○");
+        assert!(!text.spans().is_empty());
+    }
 }
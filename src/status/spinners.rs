@@ -2,11 +2,35 @@
 //!
 //! Ported from Python rich's `_spinners.py`, which sources data from cli-spinners
 //! (MIT License, Copyright (c) Sindre Sorhus).
+//!
+//! Besides the built-in [`SPINNERS`], spinners can be added at runtime with
+//! [`register`] (plain frames) or [`register_markup`] (frames written in
+//! console markup, e.g. `"[red]●[/red]"`). [`names`] lists every spinner and
+//! [`demo`] builds a table previewing them.
+//!
+//! # Examples
+//!
+//! ```
+//! use gilt::spinner::Spinner;
+//! use gilt::spinners;
+//!
+//! spinners::register("pulse", &["·  ", "·· ", "···"], 120.0).unwrap();
+//! let mut spinner = Spinner::new("pulse").unwrap();
+//! assert_eq!(spinner.render(0.0).plain(), "·  ");
+//! assert!(spinners::names().iter().any(|name| name == "pulse"));
+//! ```
 
 use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, RwLock};
+
+use crate::status::spinner::SpinnerError;
+use crate::style::Style;
+use crate::table::{ColumnOptions, Table};
+use crate::text::{JustifyMethod, OverflowMethod, Text};
+use crate::utils::cells::cell_len;
 
 /// Data for a single spinner animation: the interval between frames and the frames themselves.
+#[derive(Debug, Clone)]
 pub struct SpinnerData {
     /// Milliseconds between frames.
     pub interval: f64,
@@ -971,6 +995,175 @@ pub static SPINNERS: LazyLock<HashMap<&'static str, SpinnerData>> = LazyLock::ne
     m
 });
 
+// ---------------------------------------------------------------------------
+// Runtime registry
+// ---------------------------------------------------------------------------
+
+/// A spinner added at runtime, with whether its frames are console markup.
+struct Registered {
+    data: SpinnerData,
+    markup: bool,
+}
+
+/// Spinners added with [`register`] and [`register_markup`]. They take
+/// precedence over built-in spinners of the same name.
+static REGISTERED: LazyLock<RwLock<HashMap<String, Registered>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Register a spinner under `name`, replacing any earlier spinner of that
+/// name (including a built-in one).
+///
+/// Frames may be several characters wide; narrower frames are padded with
+/// spaces to the widest frame so text after the spinner does not jump.
+///
+/// # Errors
+///
+/// Returns a [`SpinnerError`] if `frames` is empty or `interval` (in
+/// milliseconds) is not positive.
+pub fn register(name: &str, frames: &[&str], interval: f64) -> Result<(), SpinnerError> {
+    insert(name, frames, interval, false)
+}
+
+/// Register a spinner whose frames are console markup, e.g.
+/// `["[red]●[/red]○", "○[red]●[/red]"]`, so each frame can carry its own
+/// colors. Frames are padded like [`register`] by their visible width.
+///
+/// # Errors
+///
+/// Returns a [`SpinnerError`] if `frames` is empty, `interval` is not
+/// positive, or a frame is not valid markup.
+pub fn register_markup(name: &str, frames: &[&str], interval: f64) -> Result<(), SpinnerError> {
+    insert(name, frames, interval, true)
+}
+
+fn insert(name: &str, frames: &[&str], interval: f64, markup: bool) -> Result<(), SpinnerError> {
+    if frames.is_empty() {
+        return Err(SpinnerError(format!("spinner {:?} has no frames", name)));
+    }
+    if interval.is_nan() || interval <= 0.0 {
+        return Err(SpinnerError(format!(
+            "spinner {:?} needs a positive interval, got {}",
+            name, interval
+        )));
+    }
+    let widths = frames
+        .iter()
+        .map(|frame| frame_width(frame, markup))
+        .collect::<Result<Vec<usize>, SpinnerError>>()?;
+    let widest = widths.iter().copied().max().unwrap_or(0);
+    let frames = frames
+        .iter()
+        .zip(&widths)
+        .map(|(frame, width)| format!("{}{}", frame, " ".repeat(widest - width)))
+        .collect();
+    REGISTERED
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(
+            name.to_string(),
+            Registered {
+                data: SpinnerData { interval, frames },
+                markup,
+            },
+        );
+    Ok(())
+}
+
+/// Visible cell width of a frame.
+fn frame_width(frame: &str, markup: bool) -> Result<usize, SpinnerError> {
+    if markup {
+        crate::markup::render(frame, Style::null())
+            .map(|text| text.cell_len())
+            .map_err(|e| SpinnerError(format!("invalid spinner frame {:?}: {}", frame, e)))
+    } else {
+        Ok(cell_len(frame))
+    }
+}
+
+/// Look up a spinner by name: registered spinners first, then built-ins.
+/// Returns the animation data and whether its frames are markup.
+pub(crate) fn lookup(name: &str) -> Option<(SpinnerData, bool)> {
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(found) = registered.get(name) {
+        return Some((found.data.clone(), found.markup));
+    }
+    SPINNERS.get(name).map(|data| (data.clone(), false))
+}
+
+/// Names of all available spinners, built-in and registered, sorted.
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = SPINNERS.keys().map(|name| name.to_string()).collect();
+    for name in REGISTERED.read().unwrap_or_else(|e| e.into_inner()).keys() {
+        if !SPINNERS.contains_key(name.as_str()) {
+            names.push(name.clone());
+        }
+    }
+    names.sort_by_key(|name| name.to_lowercase());
+    names
+}
+
+/// Number of frames shown in each [`demo`] preview.
+const PREVIEW_FRAMES: usize = 8;
+
+/// A table previewing every spinner: its name, its first frames, its frame
+/// count and its interval.
+///
+/// # Examples
+///
+/// ```
+/// let table = gilt::spinners::demo();
+/// assert_eq!(table.row_count(), gilt::spinners::names().len());
+/// ```
+pub fn demo() -> Table {
+    let mut table = Table::new(&[]).with_title("Spinners");
+    table.add_column("Name", "", ColumnOptions::default());
+    table.add_column(
+        "Preview",
+        "",
+        ColumnOptions {
+            no_wrap: true,
+            overflow: Some(OverflowMethod::Ellipsis),
+            ..Default::default()
+        },
+    );
+    for header in ["Frames", "Interval"] {
+        table.add_column(
+            header,
+            "",
+            ColumnOptions {
+                justify: Some(JustifyMethod::Right),
+                ..Default::default()
+            },
+        );
+    }
+
+    for name in names() {
+        let Some((data, markup)) = lookup(&name) else {
+            continue;
+        };
+        let mut preview = Text::empty();
+        for (i, frame) in data.frames.iter().take(PREVIEW_FRAMES).enumerate() {
+            if i > 0 {
+                preview.append_str(" ", None);
+            }
+            let frame = if markup {
+                crate::markup::render(frame, Style::null())
+                    .unwrap_or_else(|_| Text::new(frame, Style::null()))
+            } else {
+                Text::new(frame, Style::null())
+            };
+            preview.append_text(&frame);
+        }
+        table.add_row_text(&[
+            Text::new(&name, Style::null()),
+            preview,
+            Text::new(&data.frames.len().to_string(), Style::null()),
+            Text::new(&format!("{} ms", data.interval), Style::null()),
+        ]);
+    }
+    table
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1168,4 +1361,46 @@ mod tests {
             assert!(SPINNERS.contains_key(name), "missing spinner: {}", name);
         }
     }
+
+    #[test]
+    fn test_register_pads_frames_to_widest() {
+        register("test_pad", &["a", "abc", "ab"], 50.0).unwrap();
+        let (data, markup) = lookup("test_pad").unwrap();
+        assert!(!markup);
+        assert_eq!(data.frames, vec!["a  ", "abc", "ab "]);
+        assert_eq!(data.interval, 50.0);
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_spinners() {
+        assert!(register("test_empty", &[], 50.0).is_err());
+        assert!(register("test_interval", &["x"], 0.0).is_err());
+        assert!(register_markup("test_bad_markup", &["[/red]x"], 50.0).is_err());
+        assert!(lookup("test_empty").is_none());
+    }
+
+    #[test]
+    fn test_register_markup_pads_by_visible_width() {
+        register_markup("test_markup", &["[red]●[/red]", "[red]●●[/red]"], 50.0).unwrap();
+        let (data, markup) = lookup("test_markup").unwrap();
+        assert!(markup);
+        assert_eq!(data.frames[0], "[red]●[/red] ");
+        assert_eq!(data.frames[1], "[red]●●[/red]");
+    }
+
+    #[test]
+    fn test_names_includes_registered_and_builtin() {
+        register("test_listed", &["x"], 50.0).unwrap();
+        let names = names();
+        assert!(names.iter().any(|name| name == "test_listed"));
+        assert!(names.iter().any(|name| name == "dots"));
+        assert_eq!(names.iter().filter(|name| *name == "dots").count(), 1);
+    }
+
+    #[test]
+    fn test_demo_has_a_row_per_spinner() {
+        let table = demo();
+        assert_eq!(table.columns.len(), 4);
+        assert!(table.row_count() >= SPINNERS.len());
+    }
 }
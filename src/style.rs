@@ -4,7 +4,7 @@
 //! of terminal text, including colors, text attributes (bold, italic, etc.),
 //! and hyperlinks.
 
-use crate::color::{blend_rgb, Color, ColorSystem};
+use crate::color::{blend_rgb, Color, ColorSystem, DownsampleStrategy};
 use crate::error::StyleError;
 use crate::terminal_theme::TerminalTheme;
 use std::fmt;
@@ -186,7 +186,8 @@ impl Style {
     /// Internal parsing logic without caching.
     fn parse_internal(definition: &str) -> Result<Self, StyleError> {
         let definition = definition.trim();
-        if definition.is_empty() {
+        // "none" is how a null style displays, so it has to parse back.
+        if definition.is_empty() || definition == "none" {
             return Ok(Style::null());
         }
 
@@ -485,13 +486,14 @@ impl Style {
             });
         }
 
-        // Add color codes
+        // Add color codes, downgraded to what the terminal can show
+        let system = color_system.unwrap_or(ColorSystem::TrueColor);
         if let Some(color) = &self.color {
-            color.write_ansi_codes(true, &mut sgr);
+            write_color_codes(color, system, true, &mut sgr);
         }
 
         if let Some(bgcolor) = &self.bgcolor {
-            bgcolor.write_ansi_codes(false, &mut sgr);
+            write_color_codes(bgcolor, system, false, &mut sgr);
         }
 
         // Underline color (SGR 58;5;N or 58;2;R;G;B)
@@ -540,6 +542,55 @@ impl Style {
         }
     }
 
+    /// Returns a copy of this style with its colors downgraded to what a
+    /// terminal using `system` can show.
+    ///
+    /// [`render`](Self::render) does this on the fly; calling it ahead of
+    /// time lets a library pre-compute styles for constrained terminals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::color::ColorSystem;
+    /// use gilt::style::Style;
+    ///
+    /// let style = Style::parse("bold #ff0000 on #000080").unwrap();
+    /// let downgraded = style.downgrade(ColorSystem::Standard);
+    /// assert_eq!(downgraded.to_string(), "bold color(1) on color(4)");
+    /// ```
+    pub fn downgrade(&self, system: ColorSystem) -> Style {
+        self.downgrade_with(system, &DownsampleStrategy::Auto)
+    }
+
+    /// Like [`downgrade`](Self::downgrade), mapping colors with `strategy`.
+    pub fn downgrade_with(&self, system: ColorSystem, strategy: &DownsampleStrategy) -> Style {
+        let downgrade = |color: &Option<Color>| {
+            color
+                .as_ref()
+                .map(|color| color.downgrade_with(system, strategy))
+        };
+        Style {
+            color: downgrade(&self.color),
+            bgcolor: downgrade(&self.bgcolor),
+            underline_color: downgrade(&self.underline_color),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this style for a monochrome terminal: colors are
+    /// removed and links are underlined so they can still be told apart.
+    pub fn monochrome(&self) -> Style {
+        let mut style = Style {
+            underline_color: None,
+            ..self.without_color()
+        };
+        if style.link.is_some() {
+            style.set_attributes |= UNDERLINE;
+            style.attributes |= UNDERLINE;
+        }
+        style
+    }
+
     /// Returns a style with only the background color.
     pub fn background_style(&self) -> Style {
         Style {
@@ -823,6 +874,16 @@ fn parse_attribute_name(name: &str) -> Option<u16> {
     }
 }
 
+/// Writes the SGR codes for `color`, downgrading it first when the terminal
+/// cannot show it (e.g. a truecolor theme color on a 256-color terminal).
+fn write_color_codes(color: &Color, system: ColorSystem, foreground: bool, sgr: &mut String) {
+    if color.fits(system) {
+        color.write_ansi_codes(foreground, sgr);
+    } else {
+        color.downgrade(system).write_ansi_codes(foreground, sgr);
+    }
+}
+
 /// A stack of styles for managing nested style contexts.
 #[derive(Debug, Clone)]
 pub struct StyleStack {
@@ -860,6 +921,42 @@ impl StyleStack {
     }
 }
 
+// ============================================================================
+// LRU Cache for Style Parsing
+// ============================================================================
+
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Global LRU cache for parsed styles with capacity for 256 entries.
+static STYLE_CACHE: Mutex<Option<LruCache<String, Style>>> = Mutex::new(None);
+
+/// Gets or initializes the style cache.
+fn get_style_cache() -> std::sync::MutexGuard<'static, Option<LruCache<String, Style>>> {
+    let mut cache = STYLE_CACHE.lock().unwrap();
+    if cache.is_none() {
+        *cache = Some(LruCache::new(NonZeroUsize::new(256).unwrap()));
+    }
+    cache
+}
+
+/// Clears the global style cache.
+pub fn clear_style_cache() {
+    if let Ok(mut cache) = STYLE_CACHE.lock() {
+        *cache = None;
+    }
+}
+
+/// Returns the current number of entries in the style cache.
+pub fn style_cache_size() -> usize {
+    if let Ok(cache) = STYLE_CACHE.lock() {
+        cache.as_ref().map(|c| c.len()).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -917,6 +1014,7 @@ mod tests {
     fn test_display_null() {
         let style = Style::null();
         assert_eq!(style.to_string(), "none");
+        assert_eq!(Style::parse("none").unwrap(), style);
     }
 
     #[test]
@@ -1264,6 +1362,41 @@ mod tests {
         assert!(rendered.contains("\x1b[0m"));
     }
 
+    #[test]
+    fn test_render_downgrades_colors_to_the_color_system() {
+        let style = Style::parse("#ff0000 on #000080").unwrap();
+        assert_eq!(
+            style.render("x", Some(ColorSystem::TrueColor)),
+            "\x1b[38;2;255;0;0;48;2;0;0;128mx\x1b[0m"
+        );
+        assert_eq!(
+            style.render("x", Some(ColorSystem::EightBit)),
+            "\x1b[38;5;196;48;5;18mx\x1b[0m"
+        );
+        let standard = style.render("x", Some(ColorSystem::Standard));
+        assert!(!standard.contains("38;2") && !standard.contains("38;5"));
+        assert!(standard.starts_with("\x1b[9") || standard.starts_with("\x1b[3"));
+        // Colors that already fit are written unchanged.
+        let red = Style::parse("red").unwrap();
+        assert_eq!(
+            red.render("x", Some(ColorSystem::Standard)),
+            "\x1b[31mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_downgrade_matches_render() {
+        let style = Style::parse("bold #ff0000 on #000080").unwrap();
+        for system in [ColorSystem::EightBit, ColorSystem::Standard] {
+            let downgraded = style.downgrade(system);
+            assert_eq!(
+                downgraded.render("x", Some(ColorSystem::TrueColor)),
+                style.render("x", Some(system))
+            );
+        }
+        assert_eq!(style.downgrade(ColorSystem::TrueColor), style);
+    }
+
     #[test]
     fn test_render_all_attributes() {
         let style = Style::parse(
@@ -1685,6 +1818,22 @@ mod tests {
         assert_eq!(without.underline_color().unwrap().name, "green");
     }
 
+    #[test]
+    fn test_monochrome_underlines_links() {
+        let mut style = Style::parse("bold red link https://example.com").unwrap();
+        style.set_underline_color(Some(Color::parse("green").unwrap()));
+        let mono = style.monochrome();
+        assert!(mono.color().is_none());
+        assert!(mono.underline_color().is_none());
+        assert_eq!(mono.bold(), Some(true));
+        assert_eq!(mono.underline(), Some(true));
+        assert!(Style::parse("red")
+            .unwrap()
+            .monochrome()
+            .underline()
+            .is_none());
+    }
+
     #[test]
     fn test_background_style_clears_underline() {
         let mut style = Style::parse("bold red on blue").unwrap();
@@ -1730,39 +1879,3 @@ mod tests {
         assert_eq!(style.strike(), Some(true));
     }
 }
-
-// ============================================================================
-// LRU Cache for Style Parsing
-// ============================================================================
-
-use lru::LruCache;
-use std::num::NonZeroUsize;
-use std::sync::Mutex;
-
-/// Global LRU cache for parsed styles with capacity for 256 entries.
-static STYLE_CACHE: Mutex<Option<LruCache<String, Style>>> = Mutex::new(None);
-
-/// Gets or initializes the style cache.
-fn get_style_cache() -> std::sync::MutexGuard<'static, Option<LruCache<String, Style>>> {
-    let mut cache = STYLE_CACHE.lock().unwrap();
-    if cache.is_none() {
-        *cache = Some(LruCache::new(NonZeroUsize::new(256).unwrap()));
-    }
-    cache
-}
-
-/// Clears the global style cache.
-pub fn clear_style_cache() {
-    if let Ok(mut cache) = STYLE_CACHE.lock() {
-        *cache = None;
-    }
-}
-
-/// Returns the current number of entries in the style cache.
-pub fn style_cache_size() -> usize {
-    if let Ok(cache) = STYLE_CACHE.lock() {
-        cache.as_ref().map(|c| c.len()).unwrap_or(0)
-    } else {
-        0
-    }
-}
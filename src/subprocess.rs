@@ -0,0 +1,590 @@
+//! Subprocess capture widget -- runs a command and shows its output live.
+//!
+//! [`CommandOutput`] spawns a [`Command`], reads its stdout and stderr on
+//! background threads, decodes each line through the
+//! [`AnsiDecoder`](crate::ansi::AnsiDecoder) (so the child's colors survive
+//! and its cursor and erase codes do not break the layout), and renders the
+//! most recent lines in a [`Panel`] titled with a spinner and the command
+//! line. Once the command exits, the spinner becomes a check mark or a cross
+//! and the panel's footer shows the exit status and run time. A command can
+//! be stopped early with [`CommandOutput::kill`].
+//!
+//! Many tools only emit color when writing to a terminal; set their usual
+//! switch (for example `CARGO_TERM_COLOR=always` or `--color=always`) on the
+//! [`Command`] to keep it when captured.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::process::Command;
+//! use gilt::subprocess::CommandOutput;
+//!
+//! let mut command = Command::new("cargo");
+//! command.arg("build").env("CARGO_TERM_COLOR", "always");
+//!
+//! let output = CommandOutput::spawn(command).unwrap().with_height(8);
+//! let status = output.run().unwrap();
+//! assert!(status.success());
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::clock::{Clock, SystemClock};
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::live::{render_to_text, Live};
+use crate::panel::Panel;
+use crate::segment::Segment;
+use crate::spinner::Spinner;
+use crate::style::Style;
+use crate::text::{OverflowMethod, Text};
+use crate::utils::ansi::AnsiDecoder;
+
+/// Default number of output lines shown in the panel.
+const DEFAULT_HEIGHT: usize = 10;
+
+/// Default number of lines kept in the buffer.
+const DEFAULT_MAX_LINES: usize = 1000;
+
+/// How often the waiter thread checks whether the child has exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long to wait for the output pipes to close once the child has exited.
+/// A background process the command started can hold them open indefinitely.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+// ---------------------------------------------------------------------------
+// OutputState
+// ---------------------------------------------------------------------------
+
+/// Output and exit status shared with the reader and waiter threads.
+#[derive(Debug)]
+struct OutputState {
+    lines: VecDeque<Text>,
+    max_lines: usize,
+    /// Output streams still being read.
+    open_streams: usize,
+    status: Option<ExitStatus>,
+    /// Error from waiting on the child, if that failed.
+    error: Option<String>,
+    /// Run time, set when the command finishes.
+    elapsed: Option<Duration>,
+}
+
+impl OutputState {
+    fn push(&mut self, line: Text) {
+        self.lines.push_back(line);
+        while self.lines.len() > self.max_lines {
+            self.lines.pop_front();
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.status.is_some() || self.error.is_some()
+    }
+}
+
+/// [`OutputState`] plus the condition variable [`CommandOutput::wait`]
+/// blocks on.
+#[derive(Debug)]
+struct Shared {
+    state: Mutex<OutputState>,
+    finished: Condvar,
+}
+
+/// Read `source` line by line, decoding ANSI codes, until it closes.
+fn spawn_reader<R: Read + Send + 'static>(source: R, shared: Arc<Shared>) {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(source);
+        let mut decoder = AnsiDecoder::new();
+        let mut bytes = Vec::new();
+        loop {
+            bytes.clear();
+            match reader.read_until(b'\n', &mut bytes) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            let line = String::from_utf8_lossy(&bytes);
+            let text = decoder.decode_line(line.trim_end_matches(['\n', '\r']));
+            shared.state.lock().unwrap().push(text);
+        }
+        shared.state.lock().unwrap().open_streams -= 1;
+        shared.finished.notify_all();
+    });
+}
+
+// ---------------------------------------------------------------------------
+// CommandOutput
+// ---------------------------------------------------------------------------
+
+/// A running command whose output is shown in a scrolling [`Panel`].
+///
+/// `CommandOutput` is cheap to clone; clones share the same output, so one
+/// clone can drive a [`Live`] display while another waits for the command.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(unix)] {
+/// use std::process::Command;
+/// use gilt::subprocess::CommandOutput;
+///
+/// let mut command = Command::new("sh");
+/// command.args(["-c", "printf '\\033[32mok\\033[0m\\n'"]);
+///
+/// let output = CommandOutput::spawn(command).unwrap().with_title("check");
+/// assert!(output.wait().unwrap().success());
+/// assert_eq!(output.lines()[0].plain(), "ok");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CommandOutput {
+    shared: Arc<Shared>,
+    started: Instant,
+    title: String,
+    height: usize,
+    width: Option<usize>,
+    spinner: Arc<Mutex<Spinner>>,
+    clock: Arc<dyn Clock>,
+    child: Arc<Mutex<Child>>,
+}
+
+impl CommandOutput {
+    /// Start `command` with its stdout and stderr captured.
+    ///
+    /// Stdin is closed. Lines from both streams are collected in the order
+    /// they arrive; each stream keeps its own ANSI style state.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`Command::spawn`] if the command cannot be
+    /// started.
+    pub fn spawn(mut command: Command) -> io::Result<Self> {
+        let title = command_line(&command);
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let started = Instant::now();
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(OutputState {
+                lines: VecDeque::new(),
+                max_lines: DEFAULT_MAX_LINES,
+                open_streams: 0,
+                status: None,
+                error: None,
+                elapsed: None,
+            }),
+            finished: Condvar::new(),
+        });
+        let streams = [
+            child
+                .stdout
+                .take()
+                .map(|s| Box::new(s) as Box<dyn Read + Send>),
+            child
+                .stderr
+                .take()
+                .map(|s| Box::new(s) as Box<dyn Read + Send>),
+        ];
+        for stream in streams.into_iter().flatten() {
+            shared.state.lock().unwrap().open_streams += 1;
+            spawn_reader(stream, Arc::clone(&shared));
+        }
+
+        // Wait on a thread of its own so a process that outlives the child
+        // and keeps the pipes open cannot hold back the exit status, and poll
+        // so `kill` can reach the child in the meantime.
+        let child = Arc::new(Mutex::new(child));
+        let waiter = Arc::clone(&shared);
+        let waited = Arc::clone(&child);
+        thread::spawn(move || {
+            let result = loop {
+                match waited.lock().unwrap().try_wait() {
+                    Ok(Some(status)) => break Ok(status),
+                    Ok(None) => {}
+                    Err(err) => break Err(err),
+                }
+                thread::sleep(POLL_INTERVAL);
+            };
+            let elapsed = started.elapsed();
+            // Let the readers drain the pipes so the output is complete when
+            // the status is published.
+            let state = waiter.state.lock().unwrap();
+            let (mut state, _) = waiter
+                .finished
+                .wait_timeout_while(state, DRAIN_TIMEOUT, |state| state.open_streams > 0)
+                .unwrap();
+            match result {
+                Ok(status) => state.status = Some(status),
+                Err(err) => state.error = Some(err.to_string()),
+            }
+            state.elapsed = Some(elapsed);
+            waiter.finished.notify_all();
+        });
+
+        Ok(CommandOutput {
+            shared,
+            started,
+            title,
+            height: DEFAULT_HEIGHT,
+            width: None,
+            spinner: Arc::new(Mutex::new(
+                Spinner::new("dots").expect("dots spinner must exist"),
+            )),
+            clock: Arc::new(SystemClock),
+            child,
+        })
+    }
+
+    /// Set the panel title (builder pattern). Defaults to the command line.
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Set how many output lines the panel shows (builder pattern).
+    ///
+    /// The panel always has this many content lines, padding with blank
+    /// lines while output is short, so a live display does not jump.
+    #[must_use]
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// Set how many lines are kept in the buffer (builder pattern).
+    #[must_use]
+    pub fn with_max_lines(self, max_lines: usize) -> Self {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.max_lines = max_lines.max(1);
+            while state.lines.len() > state.max_lines {
+                state.lines.pop_front();
+            }
+        }
+        self
+    }
+
+    /// Set the width [`into_live`](Self::into_live) renders at (builder
+    /// pattern). Defaults to the width of the live display's console.
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the spinner animation by name (builder pattern).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no spinner has that name.
+    pub fn with_spinner(mut self, name: &str) -> Result<Self, crate::spinner::SpinnerError> {
+        self.spinner = Arc::new(Mutex::new(Spinner::new(name)?));
+        Ok(self)
+    }
+
+    /// Read spinner frames from `clock` instead of the wall clock (builder
+    /// pattern).
+    #[must_use]
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// The buffered output lines, oldest first.
+    pub fn lines(&self) -> Vec<Text> {
+        self.shared
+            .state
+            .lock()
+            .unwrap()
+            .lines
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// The exit status, or `None` while the command is running.
+    pub fn status(&self) -> Option<ExitStatus> {
+        self.shared.state.lock().unwrap().status
+    }
+
+    /// Whether the command has exited and all its output has been read.
+    pub fn is_finished(&self) -> bool {
+        self.shared.state.lock().unwrap().is_finished()
+    }
+
+    /// Kill the command. Does nothing if it has already exited.
+    ///
+    /// [`wait`](Self::wait) then returns the status of the killed process,
+    /// and the panel shows it as failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`Child::kill`].
+    pub fn kill(&self) -> io::Result<()> {
+        self.child.lock().unwrap().kill()
+    }
+
+    /// Block until the command exits and its output has been read.
+    ///
+    /// Output still arriving a short while after the exit, from a background
+    /// process holding the pipes open, is not waited for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if waiting on the child process failed.
+    pub fn wait(&self) -> io::Result<ExitStatus> {
+        let mut state = self.shared.state.lock().unwrap();
+        while !state.is_finished() {
+            state = self.shared.finished.wait(state).unwrap();
+        }
+        match (state.status, &state.error) {
+            (Some(status), _) => Ok(status),
+            (None, error) => Err(io::Error::other(error.clone().unwrap_or_default())),
+        }
+    }
+
+    /// Build the panel for the current state of the command.
+    ///
+    /// The title is a spinner frame (or `✔` / `✘` once finished) followed by
+    /// the title; the subtitle shows the elapsed time and, when finished, the
+    /// exit status. The border is green on success and red on failure.
+    pub fn panel(&self) -> Panel {
+        let state = self.shared.state.lock().unwrap();
+        let skip = state.lines.len().saturating_sub(self.height);
+        let mut content = Text::empty();
+        for (i, line) in state.lines.iter().skip(skip).enumerate() {
+            if i > 0 {
+                content.append_str("\n", None);
+            }
+            content.append_text(line);
+        }
+        content.no_wrap = Some(true);
+        content.overflow = Some(OverflowMethod::Ellipsis);
+
+        let (mark, footer, border) = match (&state.status, &state.error) {
+            (Some(status), _) => {
+                let elapsed = format_elapsed(state.elapsed.unwrap_or_default());
+                let (mark, color) = if status.success() {
+                    (
+                        Text::styled("✔", Style::parse("bold green").unwrap()),
+                        "green",
+                    )
+                } else {
+                    (Text::styled("✘", Style::parse("bold red").unwrap()), "red")
+                };
+                let footer = match status.code() {
+                    Some(code) => format!("exit {} · {}", code, elapsed),
+                    None => format!("terminated by signal · {}", elapsed),
+                };
+                (mark, footer, color)
+            }
+            (None, Some(error)) => (
+                Text::styled("✘", Style::parse("bold red").unwrap()),
+                error.clone(),
+                "red",
+            ),
+            (None, None) => (
+                self.spinner.lock().unwrap().render(self.clock.now()),
+                format!("running · {}", format_elapsed(self.started.elapsed())),
+                "blue",
+            ),
+        };
+
+        let mut title = mark;
+        title.append_str(" ", None);
+        title.append_str(&self.title, None);
+        Panel::new(content)
+            .with_title(title)
+            .with_subtitle(Text::styled(&footer, Style::parse("dim").unwrap()))
+            .with_border_style(Style::parse(border).unwrap())
+            .with_height(self.height)
+    }
+
+    /// Render the panel at `width` columns into a single [`Text`], for a
+    /// [`Live`] display.
+    pub fn to_text(&self, width: usize) -> Text {
+        render_to_text(&self.panel(), width)
+    }
+
+    /// Create a [`Live`] display that redraws the panel on every refresh.
+    pub fn into_live(self) -> Live {
+        let live = Live::new(Text::empty());
+        let width = self.width.unwrap_or_else(|| live.console().width());
+        live.with_get_renderable(move || self.to_text(width))
+    }
+
+    /// Show the panel in a [`Live`] display until the command exits, then
+    /// leave the final panel on screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if waiting on the child process failed.
+    pub fn run(self) -> io::Result<ExitStatus> {
+        let output = self.clone();
+        let mut live = self.into_live();
+        live.start();
+        let result = output.wait();
+        live.refresh();
+        live.stop();
+        result
+    }
+}
+
+impl std::fmt::Debug for CommandOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandOutput")
+            .field("title", &self.title)
+            .field("height", &self.height)
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+impl Renderable for CommandOutput {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.panel().gilt_console(console, options)
+    }
+}
+
+/// The command line as it would be typed, for the default title.
+fn command_line(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Format a duration as seconds with one decimal, or minutes and seconds.
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs_f64();
+    if secs < 60.0 {
+        format!("{:.1}s", secs)
+    } else {
+        format!("{}m{:02}s", elapsed.as_secs() / 60, elapsed.as_secs() % 60)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(1234)), "1.2s");
+        assert_eq!(format_elapsed(Duration::from_secs(125)), "2m05s");
+    }
+
+    #[test]
+    fn test_command_line_title() {
+        let mut command = Command::new("cargo");
+        command.args(["build", "--release"]);
+        assert_eq!(command_line(&command), "cargo build --release");
+    }
+
+    #[test]
+    fn test_spawn_missing_program_fails() {
+        assert!(CommandOutput::spawn(Command::new("gilt-no-such-program")).is_err());
+    }
+
+    #[cfg(unix)]
+    fn sh(script: &str) -> CommandOutput {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        CommandOutput::spawn(command).unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_captures_both_streams_with_styles() {
+        let output = sh("printf '\\033[1;31merr\\033[0m\\n' >&2; echo out; printf 'a\\r\\n'");
+        assert!(output.wait().unwrap().success());
+        let mut plain: Vec<String> = output
+            .lines()
+            .iter()
+            .map(|t| t.plain().to_string())
+            .collect();
+        plain.sort();
+        assert_eq!(plain, ["a", "err", "out"]);
+        let err = output
+            .lines()
+            .into_iter()
+            .find(|t| t.plain() == "err")
+            .unwrap();
+        assert_eq!(err.spans()[0].style.bold(), Some(true));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_panel_scrolls_and_shows_exit_status() {
+        let output = sh("for i in 1 2 3 4 5; do echo line $i; done; exit 3")
+            .with_title("count")
+            .with_height(2);
+        assert_eq!(output.wait().unwrap().code(), Some(3));
+        let rendered = crate::testing::render_plain(&output, 30);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("✘ count"));
+        assert!(lines[1].contains("line 4"));
+        assert!(lines[2].contains("line 5"));
+        assert!(lines[3].contains("exit 3 · "));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_to_text_has_fixed_height_and_width() {
+        let output = sh("echo done").with_height(3);
+        output.wait().unwrap();
+        let text = output.to_text(24);
+        let lines: Vec<&str> = text.plain().lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line| crate::cells::cell_len(line) == 24));
+        assert!(lines[0].contains("✔ sh -c echo done"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill() {
+        let output = sh("sleep 30");
+        let started = Instant::now();
+        output.kill().unwrap();
+        let status = output.wait().unwrap();
+        assert!(!status.success());
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(crate::testing::render_plain(&output, 40).contains("terminated by signal"));
+        output.kill().unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_background_process_does_not_block_status() {
+        let output = sh("sleep 30 & echo started");
+        let started = Instant::now();
+        assert!(output.wait().unwrap().success());
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(output.lines()[0].plain(), "started");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_max_lines_bounds_buffer() {
+        let output = sh("seq 1 50").with_max_lines(5);
+        output.wait().unwrap();
+        let lines = output.lines();
+        assert!(lines.len() <= 5);
+        assert_eq!(lines.last().unwrap().plain(), "50");
+    }
+}
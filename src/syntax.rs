@@ -4,15 +4,19 @@
 //! line numbers, themes, word wrap, and padding. Uses `syntect` for syntax
 //! highlighting (analogous to Python rich's use of Pygments).
 
+use std::collections::HashMap;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo};
 use std::path::Path;
 
-use std::sync::LazyLock;
+use std::sync::{LazyLock, RwLock};
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
+use syntect::highlighting::{Color as SyntectColor, Style as SyntectStyle, Theme, ThemeSet};
 use syntect::parsing::SyntaxSet;
+use syntect::LoadingError;
 
 use crate::cells::cell_len;
-use crate::color::Color;
+use crate::color::color_triplet::ColorTriplet;
+use crate::color::{blend_rgb, Color, ColorSystem};
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::measure::Measurement;
 use crate::segment::Segment;
@@ -25,6 +29,10 @@ static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_
 /// Global lazily-initialized theme set.
 static THEME_SET: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
 
+/// Themes loaded with [`Syntax::load_theme_from_path`], by name.
+static CUSTOM_THEMES: LazyLock<RwLock<HashMap<String, Theme>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
 /// Default theme name.
 const DEFAULT_THEME: &str = "base16-ocean.dark";
 
@@ -44,6 +52,108 @@ pub enum SyntaxError {
     /// Could not determine the language for highlighting.
     #[error("unknown language: {0}")]
     UnknownLanguage(String),
+    /// A theme file could not be parsed.
+    #[error("invalid theme: {0}")]
+    InvalidTheme(String),
+}
+
+// ---------------------------------------------------------------------------
+// LineRange
+// ---------------------------------------------------------------------------
+
+/// A 1-based, inclusive range of lines to display.
+///
+/// Converts from `(start, end)` tuples (inclusive) and from Rust ranges, so
+/// `10..40`, `10..=39` and `(10, 39)` all select lines 10 through 39.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    /// First line shown.
+    pub start: usize,
+    /// Last line shown.
+    pub end: usize,
+}
+
+impl From<(usize, usize)> for LineRange {
+    fn from((start, end): (usize, usize)) -> Self {
+        LineRange { start, end }
+    }
+}
+
+impl From<Range<usize>> for LineRange {
+    fn from(range: Range<usize>) -> Self {
+        LineRange {
+            start: range.start,
+            end: range.end.saturating_sub(1),
+        }
+    }
+}
+
+impl From<RangeInclusive<usize>> for LineRange {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        LineRange {
+            start: *range.start(),
+            end: *range.end(),
+        }
+    }
+}
+
+impl From<RangeFrom<usize>> for LineRange {
+    fn from(range: RangeFrom<usize>) -> Self {
+        LineRange {
+            start: range.start,
+            end: usize::MAX,
+        }
+    }
+}
+
+impl From<RangeTo<usize>> for LineRange {
+    fn from(range: RangeTo<usize>) -> Self {
+        LineRange {
+            start: 1,
+            end: range.end.saturating_sub(1),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GutterMarker
+// ---------------------------------------------------------------------------
+
+/// A one-character marker drawn in the gutter before a line number, as in a
+/// diff view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GutterMarker {
+    /// A green `+`.
+    Added,
+    /// A red `-`.
+    Removed,
+    /// A yellow `~`.
+    Changed,
+    /// Any symbol with any style.
+    Custom(char, Style),
+}
+
+impl GutterMarker {
+    /// The symbol drawn in the gutter.
+    pub fn symbol(&self) -> char {
+        match self {
+            GutterMarker::Added => '+',
+            GutterMarker::Removed => '-',
+            GutterMarker::Changed => '~',
+            GutterMarker::Custom(symbol, _) => *symbol,
+        }
+    }
+
+    /// The style of the symbol.
+    pub fn style(&self) -> Style {
+        let color = match self {
+            GutterMarker::Added => "green",
+            GutterMarker::Removed => "red",
+            GutterMarker::Changed => "yellow",
+            GutterMarker::Custom(_, style) => return style.clone(),
+        };
+        Style::parse(&format!("bold {}", color)).unwrap_or_else(|_| Style::null())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -76,6 +186,8 @@ pub struct Syntax {
     pub padding: (usize, usize),
     /// Line numbers to highlight with a special background.
     pub highlight_lines: Vec<usize>,
+    /// Markers drawn in the line number gutter, keyed by line number.
+    pub gutter_markers: HashMap<usize, GutterMarker>,
     /// Optional override for background color (CSS hex like "#282c34").
     pub background_color: Option<String>,
     /// Whether to show indent guides.
@@ -84,6 +196,9 @@ pub struct Syntax {
     pub code_width: Option<usize>,
     /// Whether to auto-dedent code by stripping common leading whitespace.
     pub dedent: bool,
+    /// Color system to map theme colors to, or `None` to emit theme colors
+    /// as-is and let the console downgrade them when printing.
+    pub color_system: Option<ColorSystem>,
     /// Style ranges to apply on top of syntax highlighting.
     /// Each entry is a (style, character_range) pair applied during rendering.
     pub style_ranges: Vec<(Style, std::ops::Range<usize>)>,
@@ -103,10 +218,12 @@ impl Syntax {
             tab_size: 4,
             padding: (0, 0),
             highlight_lines: Vec::new(),
+            gutter_markers: HashMap::new(),
             background_color: None,
             indent_guides: false,
             code_width: None,
             dedent: false,
+            color_system: None,
             style_ranges: Vec::new(),
         }
     }
@@ -141,10 +258,24 @@ impl Syntax {
         self
     }
 
-    /// Set the line range to display (1-based, inclusive).
+    /// Set the lines to display, e.g. `10..40` or `(10, 39)`.
+    ///
+    /// Line numbers are 1-based and count from the start of the code, and
+    /// the displayed numbers keep their position in the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::syntax::Syntax;
+    ///
+    /// let code = "a\nb\nc\nd\n";
+    /// let syntax = Syntax::new(code, "txt").with_line_range(2..4);
+    /// assert_eq!(syntax.line_range, Some((2, 3)));
+    /// ```
     #[must_use]
-    pub fn with_line_range(mut self, range: (usize, usize)) -> Self {
-        self.line_range = Some(range);
+    pub fn with_line_range(mut self, range: impl Into<LineRange>) -> Self {
+        let range = range.into();
+        self.line_range = Some((range.start, range.end));
         self
     }
 
@@ -162,10 +293,36 @@ impl Syntax {
         self
     }
 
-    /// Set which line numbers to highlight.
+    /// Set which line numbers to highlight. Highlighted lines get a lighter
+    /// background (the theme's line highlight color when it has one) and, with
+    /// line numbers on, a pointer in the gutter.
     #[must_use]
-    pub fn with_highlight_lines(mut self, lines: Vec<usize>) -> Self {
-        self.highlight_lines = lines;
+    pub fn with_highlight_lines(mut self, lines: impl Into<Vec<usize>>) -> Self {
+        self.highlight_lines = lines.into();
+        self
+    }
+
+    /// Set markers to draw in the gutter before line numbers, e.g. to show
+    /// added and removed lines. A marker replaces the highlight pointer on
+    /// its line. Markers are only drawn when line numbers are on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::syntax::{GutterMarker, Syntax};
+    ///
+    /// let syntax = Syntax::new("let a = 1;\nlet b = 2;\n", "rs")
+    ///     .with_line_numbers(true)
+    ///     .with_gutter_markers([(1, GutterMarker::Removed), (2, GutterMarker::Added)]);
+    /// let output = format!("{}", syntax);
+    /// assert!(output.starts_with("- 1 let a = 1;"));
+    /// ```
+    #[must_use]
+    pub fn with_gutter_markers(
+        mut self,
+        markers: impl IntoIterator<Item = (usize, GutterMarker)>,
+    ) -> Self {
+        self.gutter_markers = markers.into_iter().collect();
         self
     }
 
@@ -190,6 +347,56 @@ impl Syntax {
         self
     }
 
+    /// Map theme colors to the nearest colors of `color_system`.
+    ///
+    /// Consoles already downgrade colors they cannot show; this makes the
+    /// mapping part of the rendered segments, e.g. for exports or to preview
+    /// how a theme looks on a 16-color terminal.
+    #[must_use]
+    pub fn with_color_system(mut self, color_system: ColorSystem) -> Self {
+        self.color_system = Some(color_system);
+        self
+    }
+
+    // -- Themes -------------------------------------------------------------
+
+    /// Load a TextMate `.tmTheme` file and register it under its file name
+    /// without the extension, so it can be selected with
+    /// [`with_theme`](Self::with_theme). Returns the registered name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SyntaxError::IoError`] if the file cannot be read, or
+    /// [`SyntaxError::InvalidTheme`] if it is not a valid theme.
+    pub fn load_theme_from_path(path: impl AsRef<Path>) -> Result<String, SyntaxError> {
+        let path = path.as_ref();
+        let theme = ThemeSet::get_theme(path).map_err(theme_error)?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| theme.name.clone().unwrap_or_default());
+        CUSTOM_THEMES
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.clone(), theme);
+        Ok(name)
+    }
+
+    /// Names of the available themes: the built-in ones and any loaded with
+    /// [`load_theme_from_path`](Self::load_theme_from_path), sorted.
+    pub fn theme_names() -> Vec<String> {
+        let mut names: Vec<String> = THEME_SET.themes.keys().cloned().collect();
+        let custom = CUSTOM_THEMES.read().unwrap_or_else(|e| e.into_inner());
+        names.extend(
+            custom
+                .keys()
+                .filter(|k| !THEME_SET.themes.contains_key(*k))
+                .cloned(),
+        );
+        names.sort();
+        names
+    }
+
     /// Add a style to apply over a character range of the code.
     ///
     /// The range is in terms of character offsets into the original code string
@@ -201,6 +408,13 @@ impl Syntax {
 
     // -- Internal helpers ---------------------------------------------------
 
+    /// Highlight the code without a background, gutter or padding, for
+    /// callers that lay out the lines themselves.
+    #[cfg(feature = "miette")]
+    pub(crate) fn highlight(&self) -> Text {
+        self.highlight_code(&self.code)
+    }
+
     /// Get the width of the line numbers column (0 if line numbers disabled).
     fn numbers_column_width(&self) -> usize {
         if !self.line_numbers {
@@ -219,8 +433,9 @@ impl Syntax {
         } else {
             format!("{}\n", self.code)
         };
-        let tab_replacement: String = " ".repeat(self.tab_size);
-        processed = processed.replace('\t', &tab_replacement);
+        if processed.contains('\t') {
+            processed = crate::text::expand_tab_stops(&processed, self.tab_size).0;
+        }
 
         // Dedent: strip common leading whitespace from all non-empty lines.
         if self.dedent {
@@ -251,6 +466,25 @@ impl Syntax {
         (ends_on_nl, processed)
     }
 
+    /// Call `f` with the selected theme: a loaded theme of that name, else
+    /// a built-in one, else `None`.
+    fn with_theme_data<R>(&self, f: impl FnOnce(Option<&Theme>) -> R) -> R {
+        let custom = CUSTOM_THEMES.read().unwrap_or_else(|e| e.into_inner());
+        f(custom
+            .get(&self.theme)
+            .or_else(|| THEME_SET.themes.get(&self.theme)))
+    }
+
+    /// Convert a theme color, mapping it to [`color_system`](Self::color_system)
+    /// if one is set.
+    fn theme_color(&self, r: u8, g: u8, b: u8) -> Color {
+        let color = Color::from_rgb(r, g, b);
+        match self.color_system {
+            Some(system) => color.downgrade(system),
+            None => color,
+        }
+    }
+
     /// Highlight the given code and return a `Text` with styled spans.
     fn highlight_code(&self, code: &str) -> Text {
         let ss = &*SYNTAX_SET;
@@ -262,33 +496,38 @@ impl Syntax {
             .or_else(|| ss.find_syntax_by_extension(&self.lexer_name))
             .unwrap_or_else(|| ss.find_syntax_plain_text());
 
-        // Find the theme, fall back to the default
-        let theme = ts
-            .themes
-            .get(&self.theme)
-            .or_else(|| ts.themes.values().next())
-            .expect("at least one theme must be available");
-
-        let mut h = HighlightLines::new(syntax, theme);
-        let mut text = Text::new("", Style::null());
-
-        for line in code.lines() {
-            let line_with_nl = format!("{}\n", line);
-            match h.highlight_line(&line_with_nl, ss) {
-                Ok(ranges) => {
-                    for (style, token) in ranges {
-                        let gilt_style = syntect_to_gilt_style(style);
-                        text.append_str(token, Some(gilt_style));
+        self.with_theme_data(|theme| {
+            // Find the theme, fall back to the default
+            let theme = theme
+                .or_else(|| ts.themes.values().next())
+                .expect("at least one theme must be available");
+
+            let mut h = HighlightLines::new(syntax, theme);
+            let mut text = Text::new("", Style::null());
+
+            for line in code.lines() {
+                let line_with_nl = format!("{}\n", line);
+                match h.highlight_line(&line_with_nl, ss) {
+                    Ok(ranges) => {
+                        for (style, token) in ranges {
+                            let mut gilt_style = syntect_to_gilt_style(style);
+                            if let (Some(system), Some(color)) =
+                                (self.color_system, gilt_style.color())
+                            {
+                                gilt_style = Style::from_color(Some(color.downgrade(system)), None);
+                            }
+                            text.append_str(token, Some(gilt_style));
+                        }
+                    }
+                    Err(_) => {
+                        // Fallback: append unstyled
+                        text.append_str(&line_with_nl, None);
                     }
-                }
-                Err(_) => {
-                    // Fallback: append unstyled
-                    text.append_str(&line_with_nl, None);
                 }
             }
-        }
 
-        text
+            text
+        })
     }
 
     /// Get the background style from the theme.
@@ -298,21 +537,44 @@ impl Syntax {
                 return Style::from_color(None, Some(color));
             }
         }
-        let ts = &*THEME_SET;
-        if let Some(theme) = ts.themes.get(&self.theme) {
-            let bg = theme
-                .settings
-                .background
-                .unwrap_or(syntect::highlighting::Color {
-                    r: 0,
-                    g: 0,
-                    b: 0,
-                    a: 255,
-                });
-            Style::from_color(None, Some(Color::from_rgb(bg.r, bg.g, bg.b)))
-        } else {
-            Style::null()
+        self.with_theme_data(|theme| match theme {
+            Some(theme) => {
+                let bg = theme.settings.background.unwrap_or(SyntectColor::BLACK);
+                Style::from_color(None, Some(self.theme_color(bg.r, bg.g, bg.b)))
+            }
+            None => Style::null(),
+        })
+    }
+
+    /// Get the background style for highlighted lines: the theme's line
+    /// highlight color, or the background lifted towards the opposite end
+    /// of the brightness scale.
+    fn get_highlight_style(&self) -> Style {
+        if self.background_color.is_none() {
+            let line_highlight =
+                self.with_theme_data(|theme| theme.and_then(|t| t.settings.line_highlight));
+            if let Some(c) = line_highlight {
+                return Style::from_color(None, Some(self.theme_color(c.r, c.g, c.b)));
+            }
         }
+        let background = self
+            .get_background_style()
+            .bgcolor()
+            .map(|color| color.get_truecolor(None, false))
+            .unwrap_or_else(|| ColorTriplet::new(0, 0, 0));
+        let luminance = 0.299 * background.red as f64
+            + 0.587 * background.green as f64
+            + 0.114 * background.blue as f64;
+        let target = if luminance > 128.0 {
+            ColorTriplet::new(0, 0, 0)
+        } else {
+            ColorTriplet::new(255, 255, 255)
+        };
+        let highlight = blend_rgb(background, target, 0.12);
+        Style::from_color(
+            None,
+            Some(self.theme_color(highlight.red, highlight.green, highlight.blue)),
+        )
     }
 
     /// Build the rendered segments for this Syntax object.
@@ -342,6 +604,11 @@ impl Syntax {
         };
 
         let background_style = self.get_background_style();
+        let highlight_style = if self.highlight_lines.is_empty() {
+            background_style.clone()
+        } else {
+            self.get_highlight_style()
+        };
 
         // Split text into lines
         let lines = text.split("\n", true, true);
@@ -377,40 +644,54 @@ impl Syntax {
         for (idx, line) in display_lines.iter().enumerate() {
             let line_no = self.start_line + line_offset + idx;
             let is_highlighted = self.highlight_lines.contains(&line_no);
+            let line_style = if is_highlighted {
+                &highlight_style
+            } else {
+                &background_style
+            };
 
             // Line number gutter
             if self.line_numbers {
                 let num_width = numbers_column_width - NUMBERS_COLUMN_DEFAULT_PADDING;
                 let num_str = format!("{:>width$} ", line_no, width = num_width);
 
-                if is_highlighted {
+                let dim_style = Style::new(
+                    None,
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap_or_else(|_| Style::null());
+
+                if let Some(marker) = self.gutter_markers.get(&line_no) {
+                    segments.push(Segment::styled(
+                        &format!("{} ", marker.symbol()),
+                        background_style.clone() + marker.style(),
+                    ));
+                } else if is_highlighted {
                     let pointer_style = Style::from_color(
                         Some(Color::parse("red").unwrap_or_else(|_| Color::from_rgb(255, 0, 0))),
                         None,
                     );
                     segments.push(Segment::styled("> ", pointer_style));
-                    segments.push(Segment::styled(&num_str, background_style.clone()));
                 } else {
-                    let dim_style = Style::new(
-                        None,
-                        None,
-                        None,
-                        Some(true),
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                    )
-                    .unwrap_or_else(|_| Style::null());
                     segments.push(Segment::styled("  ", background_style.clone()));
+                }
+                if is_highlighted {
+                    segments.push(Segment::styled(&num_str, background_style.clone()));
+                } else {
                     segments.push(Segment::styled(
                         &num_str,
                         background_style.clone() + dim_style,
@@ -419,7 +700,7 @@ impl Syntax {
             }
 
             // Render the line content
-            let line_text = line.plain();
+            let line_text = line.plain().trim_end_matches('\n');
             let line_cell_len = cell_len(line_text);
 
             if self.word_wrap && line_cell_len > code_width {
@@ -433,17 +714,18 @@ impl Syntax {
                     }
                     let rendered = wline.render();
                     for seg in &rendered {
-                        if seg.text == "\n" {
+                        let text = seg.text.trim_end_matches('\n');
+                        if text.is_empty() {
                             continue;
                         }
                         let style = seg.style.clone().unwrap_or_else(Style::null);
-                        segments.push(Segment::styled(&seg.text, background_style.clone() + style));
+                        segments.push(Segment::styled(text, line_style.clone() + style));
                     }
                     // Pad to code_width
                     let wline_len = wline.cell_len();
                     if wline_len < code_width {
                         let pad = " ".repeat(code_width - wline_len);
-                        segments.push(Segment::styled(&pad, background_style.clone()));
+                        segments.push(Segment::styled(&pad, line_style.clone()));
                     }
                     segments.push(Segment::line());
                 }
@@ -451,16 +733,17 @@ impl Syntax {
                 // Single line (no wrap)
                 let rendered = line.render();
                 for seg in &rendered {
-                    if seg.text == "\n" {
+                    let text = seg.text.trim_end_matches('\n');
+                    if text.is_empty() {
                         continue;
                     }
                     let style = seg.style.clone().unwrap_or_else(Style::null);
-                    segments.push(Segment::styled(&seg.text, background_style.clone() + style));
+                    segments.push(Segment::styled(text, line_style.clone() + style));
                 }
                 // Pad to code_width
                 if line_cell_len < code_width {
                     let pad = " ".repeat(code_width - line_cell_len);
-                    segments.push(Segment::styled(&pad, background_style.clone()));
+                    segments.push(Segment::styled(&pad, line_style.clone()));
                 }
                 segments.push(Segment::line());
             }
@@ -512,7 +795,7 @@ fn syntect_to_gilt_style(style: SyntectStyle) -> Style {
 }
 
 /// Guess the lexer name from a file path extension.
-fn guess_lexer(path: &str) -> String {
+pub(crate) fn guess_lexer(path: &str) -> String {
     let p = Path::new(path);
     if let Some(ext) = p.extension() {
         let ext_str = ext.to_string_lossy().to_lowercase();
@@ -527,6 +810,18 @@ fn guess_lexer(path: &str) -> String {
     "txt".to_string()
 }
 
+// ---------------------------------------------------------------------------
+// TextMate theme loading
+// ---------------------------------------------------------------------------
+
+/// Convert a syntect theme loading error, keeping I/O errors distinct.
+fn theme_error(error: LoadingError) -> SyntaxError {
+    match error {
+        LoadingError::Io(error) => SyntaxError::IoError(error),
+        other => SyntaxError::InvalidTheme(other.to_string()),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Display
 // ---------------------------------------------------------------------------
@@ -640,6 +935,26 @@ mod tests {
         assert!(!text.contains("line5"));
     }
 
+    #[test]
+    fn test_line_range_from_rust_ranges() {
+        assert_eq!(LineRange::from(10..40), LineRange { start: 10, end: 39 });
+        assert_eq!(LineRange::from(10..=40), LineRange { start: 10, end: 40 });
+        assert_eq!(LineRange::from(..3), LineRange { start: 1, end: 2 });
+        assert_eq!(LineRange::from(4..).end, usize::MAX);
+
+        let code = "line1\nline2\nline3\nline4\nline5\n";
+        let syntax = Syntax::new(code, "txt")
+            .with_line_numbers(true)
+            .with_line_range(2..4);
+        let text: String = syntax
+            .render_syntax(40)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["  2 line2", "  3 line3"]);
+    }
+
     // -- Word wrap ----------------------------------------------------------
 
     #[test]
@@ -717,6 +1032,50 @@ mod tests {
         assert!(text.contains('>'), "expected highlight pointer");
     }
 
+    #[test]
+    fn test_highlight_lines_get_a_different_background() {
+        let syntax = Syntax::new("a\nb\nc\n", "txt").with_highlight_lines([2]);
+        let segments = syntax.render_syntax(10);
+        let lines = Segment::split_lines_terminator(&segments);
+        let background = |line: &[Segment]| {
+            line.iter()
+                .find(|s| !s.text.trim().is_empty())
+                .and_then(|s| s.style.as_ref())
+                .and_then(|s| s.bgcolor().cloned())
+        };
+        let normal = background(&lines[0].0);
+        let highlighted = background(&lines[1].0);
+        assert!(normal.is_some() && highlighted.is_some());
+        assert_ne!(normal, highlighted);
+        assert_eq!(normal, background(&lines[2].0));
+        // The padding after the code is highlighted too.
+        let pad = lines[1].0.last().unwrap();
+        assert_eq!(
+            pad.style.as_ref().and_then(|s| s.bgcolor().cloned()),
+            highlighted
+        );
+    }
+
+    #[test]
+    fn test_gutter_markers() {
+        let syntax = Syntax::new("a\nb\nc\n", "txt")
+            .with_line_numbers(true)
+            .with_highlight_lines(vec![3])
+            .with_gutter_markers([
+                (1, GutterMarker::Removed),
+                (2, GutterMarker::Custom('!', Style::null())),
+            ]);
+        let text: String = syntax
+            .render_syntax(20)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["- 1 a", "! 2 b", "> 3 c"]);
+        assert_eq!(GutterMarker::Added.symbol(), '+');
+        assert!(GutterMarker::Changed.style().color().is_some());
+    }
+
     // -- Unknown language handling ------------------------------------------
 
     #[test]
@@ -882,6 +1241,111 @@ mod tests {
         assert_eq!(color.triplet.unwrap().blue, 0);
     }
 
+    // -- Custom themes and color mapping -----------------------------------
+
+    const TM_THEME: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>Test &amp; Theme</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>background</key>
+                <string>#102030</string>
+                <key>foreground</key>
+                <string>#EEEEEE</string>
+                <key>lineHighlight</key>
+                <string>#405060</string>
+            </dict>
+        </dict>
+        <!-- keywords -->
+        <dict>
+            <key>name</key>
+            <string>Keyword</string>
+            <key>scope</key>
+            <string>keyword, storage</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#FF0000</string>
+                <key>fontStyle</key>
+                <string>bold</string>
+            </dict>
+        </dict>
+    </array>
+    <key>uuid</key>
+    <string>1234</string>
+    <key>semanticClass</key>
+    <true/>
+</dict>
+</plist>"#;
+
+    #[test]
+    fn test_load_theme_rejects_invalid_files() {
+        for (i, source) in [
+            "<plist><dict><key>name</key></dict></plist>",
+            "<plist><dict></dict></plist>",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let path = std::env::temp_dir().join(format!(
+                "gilt-test-invalid-{}-{}.tmTheme",
+                std::process::id(),
+                i
+            ));
+            std::fs::write(&path, source).unwrap();
+            let result = Syntax::load_theme_from_path(&path);
+            std::fs::remove_file(&path).unwrap();
+            assert!(
+                matches!(result, Err(SyntaxError::InvalidTheme(_))),
+                "{source}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_theme_from_path() {
+        let path = std::env::temp_dir().join(format!("gilt-test-{}.tmTheme", std::process::id()));
+        std::fs::write(&path, TM_THEME).unwrap();
+        let name = Syntax::load_theme_from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(Syntax::theme_names().contains(&name));
+
+        let syntax = Syntax::new("fn main() {}\n", "rs").with_theme(&name);
+        let segments = syntax.render_syntax(20);
+        let keyword = segments.iter().find(|s| s.text == "fn").unwrap();
+        let style = keyword.style.as_ref().unwrap();
+        assert_eq!(style.color().unwrap().triplet.unwrap().red, 255);
+        assert_eq!(
+            style.bgcolor().unwrap().triplet.unwrap(),
+            ColorTriplet::new(0x10, 0x20, 0x30)
+        );
+
+        assert!(matches!(
+            Syntax::load_theme_from_path("/nonexistent/theme.tmTheme"),
+            Err(SyntaxError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_color_system_maps_theme_colors() {
+        use crate::color::ColorType;
+
+        let syntax = Syntax::new("let x = 1;\n", "rs").with_color_system(ColorSystem::Standard);
+        for segment in syntax.render_syntax(20) {
+            if let Some(style) = &segment.style {
+                for color in style.color().into_iter().chain(style.bgcolor()) {
+                    assert_eq!(color.color_type, ColorType::Standard);
+                }
+            }
+        }
+    }
+
     // -- guess_lexer test ---------------------------------------------------
 
     #[test]
@@ -1162,4 +1626,22 @@ mod tests {
         assert!(s.contains("fn"));
         assert!(s.contains("main"));
     }
+
+    #[test]
+    fn test_lines_are_padded_to_the_same_width() {
+        let syntax = Syntax::new("    let a = 1;\n    }\n}", "rust").with_line_numbers(true);
+        let segments = syntax.render_syntax(40);
+        let mut widths = Vec::new();
+        let mut width = 0;
+        for seg in &segments {
+            if seg.text == "\n" {
+                widths.push(width);
+                width = 0;
+            } else {
+                assert!(!seg.text.contains('\n'), "newline inside {:?}", seg.text);
+                width += cell_len(&seg.text);
+            }
+        }
+        assert_eq!(widths, vec![40, 40, 40]);
+    }
 }
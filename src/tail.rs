@@ -0,0 +1,430 @@
+//! File tailing widget -- follows a log file like `tail -f`.
+//!
+//! [`TailView`] watches a file with the `notify` crate, appends new lines to a
+//! bounded buffer as they are written, and renders the most recent lines
+//! through the [`LogColorizer`] pipeline. Like `tail -F` it keeps following
+//! the path when the file is rotated (renamed away and recreated) or
+//! truncated.
+//!
+//! # Features
+//!
+//! This module requires the `notify` feature to be enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! gilt = { version = "0.9", features = ["notify"] }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use gilt::live::Live;
+//! use gilt::tail::TailView;
+//! use gilt::text::Text;
+//!
+//! let tail = TailView::open("/var/log/app.log").unwrap();
+//! let view = tail.clone();
+//! let mut live = Live::new(Text::empty())
+//!     .with_get_renderable(move || view.to_text(Some(20)));
+//! live.start();
+//! // ... the display follows the file until stopped ...
+//! live.stop();
+//! ```
+
+use std::collections::VecDeque;
+use std::fs::{File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::highlighter::{Highlighter, LogColorizer};
+use crate::segment::Segment;
+use crate::text::{OverflowMethod, Text};
+
+/// Default number of lines kept in the buffer.
+const DEFAULT_MAX_LINES: usize = 1000;
+
+/// Bytes read from the end of the file when it is first opened.
+const INITIAL_WINDOW: u64 = 1024 * 1024;
+
+/// Identity of the file behind a path, used to notice the path being
+/// rotated to a new file. Only available on Unix; elsewhere rotation is
+/// noticed only when the file shrinks.
+#[cfg(unix)]
+fn file_id(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+// ---------------------------------------------------------------------------
+// TailState
+// ---------------------------------------------------------------------------
+
+/// Buffer and read position shared with the watcher callback.
+#[derive(Debug)]
+struct TailState {
+    lines: VecDeque<String>,
+    max_lines: usize,
+    /// The followed file, or `None` before the first poll.
+    file: Option<File>,
+    /// Identity of `file`, to notice the path being rotated.
+    id: Option<(u64, u64)>,
+    /// Byte offset of the next unread byte in the file.
+    offset: u64,
+    /// Trailing bytes not yet terminated by a newline. Kept undecoded so a
+    /// multibyte character split across writes decodes intact.
+    partial: Vec<u8>,
+    /// Bytes read from the end of the file when it is first opened.
+    window: u64,
+}
+
+impl TailState {
+    fn new() -> Self {
+        TailState {
+            lines: VecDeque::new(),
+            max_lines: DEFAULT_MAX_LINES,
+            file: None,
+            id: None,
+            offset: 0,
+            partial: Vec::new(),
+            window: INITIAL_WINDOW,
+        }
+    }
+
+    /// Read everything appended since the last poll. Returns the number of
+    /// complete lines added to the buffer.
+    ///
+    /// The first poll reads only the last `window` bytes, starting at a line
+    /// boundary. When the path has been rotated to a new file, the rest of
+    /// the old file is read before following the new one from its start.
+    fn poll(&mut self, path: &Path) -> io::Result<usize> {
+        if self.file.is_none() {
+            let len = self.open(path)?;
+            if len > self.window {
+                // Start one byte early to tell whether the window begins
+                // mid-line; everything up to the first newline is dropped.
+                self.offset = len - self.window - 1;
+                self.read_appended()?;
+                match self.partial.iter().position(|&b| b == b'\n') {
+                    Some(pos) => drop(self.partial.drain(..=pos)),
+                    None => self.partial.clear(),
+                }
+            }
+        }
+        self.read_appended()?;
+        let mut added = self.drain_lines();
+
+        let rotated = std::fs::metadata(path)
+            .ok()
+            .and_then(|metadata| file_id(&metadata))
+            .is_some_and(|id| Some(id) != self.id);
+        if rotated {
+            // The old file is finished: its unterminated last line is complete.
+            if !self.partial.is_empty() {
+                let line = String::from_utf8_lossy(&self.partial).into_owned();
+                self.partial.clear();
+                self.push_line(line);
+                added += 1;
+            }
+            self.open(path)?;
+            self.read_appended()?;
+            added += self.drain_lines();
+        }
+        Ok(added)
+    }
+
+    /// Open `path` to be followed from its start. Returns its length.
+    fn open(&mut self, path: &Path) -> io::Result<u64> {
+        let file = File::open(path)?;
+        let metadata = file.metadata()?;
+        self.id = file_id(&metadata);
+        self.file = Some(file);
+        self.offset = 0;
+        self.partial.clear();
+        Ok(metadata.len())
+    }
+
+    /// Append the bytes written since the last read to `partial`.
+    fn read_appended(&mut self) -> io::Result<()> {
+        let Some(file) = self.file.as_mut() else {
+            return Ok(());
+        };
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated: start again from the beginning.
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(());
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let read = file.read_to_end(&mut self.partial)?;
+        self.offset += read as u64;
+        Ok(())
+    }
+
+    /// Move the complete lines in `partial` to the buffer. Returns how many.
+    fn drain_lines(&mut self) -> usize {
+        let mut added = 0;
+        while let Some(pos) = self.partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.push_line(line.trim_end_matches(['\n', '\r']).to_string());
+            added += 1;
+        }
+        added
+    }
+
+    fn push_line(&mut self, line: String) {
+        self.lines.push_back(line);
+        while self.lines.len() > self.max_lines {
+            self.lines.pop_front();
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TailView
+// ---------------------------------------------------------------------------
+
+/// A renderable that follows a file like `tail -f`.
+///
+/// The end of the existing contents (at most the last MiB) is loaded on
+/// [`open`](TailView::open) and new lines are picked up by a file watcher as
+/// they are written. Only the last `max_lines` lines are kept. `TailView` is cheap to clone; clones share the
+/// same buffer, which makes it easy to hand one to a
+/// [`Live`](crate::live::Live) renderable callback.
+#[derive(Debug, Clone)]
+pub struct TailView {
+    path: PathBuf,
+    state: Arc<Mutex<TailState>>,
+    _watcher: Arc<RecommendedWatcher>,
+}
+
+impl TailView {
+    /// Open `path`, load its current contents and start following it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the watcher cannot be
+    /// installed.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let state = Arc::new(Mutex::new(TailState::new()));
+        state.lock().unwrap().poll(&path)?;
+
+        // Watch the directory rather than the file, so a file recreated at
+        // the path after rotation is noticed too.
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let watch_state = Arc::clone(&state);
+        let watch_path = path.clone();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res {
+                let ours = event
+                    .paths
+                    .iter()
+                    .any(|p| p.file_name() == watch_path.file_name());
+                if ours && matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                    let _ = watch_state.lock().unwrap().poll(&watch_path);
+                }
+            }
+        })
+        .map_err(io::Error::other)?;
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(io::Error::other)?;
+
+        Ok(TailView {
+            path,
+            state,
+            _watcher: Arc::new(watcher),
+        })
+    }
+
+    /// Set the maximum number of buffered lines (builder pattern).
+    #[must_use]
+    pub fn with_max_lines(self, max_lines: usize) -> Self {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.max_lines = max_lines.max(1);
+            while state.lines.len() > state.max_lines {
+                state.lines.pop_front();
+            }
+        }
+        self
+    }
+
+    /// The file being followed.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read any new data from the file immediately, without waiting for the
+    /// watcher. Returns the number of complete lines added.
+    pub fn poll(&self) -> io::Result<usize> {
+        self.state.lock().unwrap().poll(&self.path)
+    }
+
+    /// A copy of the buffered lines, oldest first.
+    pub fn lines(&self) -> Vec<String> {
+        self.state.lock().unwrap().lines.iter().cloned().collect()
+    }
+
+    /// Build a colorized [`Text`] of the last `max_lines` buffered lines
+    /// (all buffered lines when `None`).
+    pub fn to_text(&self, max_lines: Option<usize>) -> Text {
+        let state = self.state.lock().unwrap();
+        let skip = max_lines.map_or(0, |n| state.lines.len().saturating_sub(n));
+        let joined = state
+            .lines
+            .iter()
+            .skip(skip)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut text = LogColorizer.apply(&joined);
+        text.no_wrap = Some(true);
+        text.overflow = Some(OverflowMethod::Ellipsis);
+        text
+    }
+}
+
+impl Renderable for TailView {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.to_text(options.height).gilt_console(console, options)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn temp_log(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("gilt-tail-{}-{}.log", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_open_loads_existing_lines() {
+        let path = temp_log("existing", "one\ntwo\nthree\n");
+        let tail = TailView::open(&path).unwrap();
+        assert_eq!(tail.lines(), vec!["one", "two", "three"]);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_poll_picks_up_appended_lines() {
+        let path = temp_log("append", "first\n");
+        let tail = TailView::open(&path).unwrap();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        write!(file, "second\nthi").unwrap();
+        tail.poll().unwrap();
+        assert_eq!(tail.lines(), vec!["first", "second"]);
+        writeln!(file, "rd").unwrap();
+        tail.poll().unwrap();
+        assert_eq!(tail.lines(), vec!["first", "second", "third"]);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_multibyte_char_split_across_polls() {
+        let path = temp_log("multibyte", "");
+        let tail = TailView::open(&path).unwrap();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        let bytes = "caf\u{e9}\n".as_bytes();
+        file.write_all(&bytes[..4]).unwrap();
+        tail.poll().unwrap();
+        assert!(tail.lines().is_empty());
+        file.write_all(&bytes[4..]).unwrap();
+        tail.poll().unwrap();
+        assert_eq!(tail.lines(), vec!["caf\u{e9}"]);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_buffer_is_bounded() {
+        let contents: String = (0..10).map(|i| format!("line {i}\n")).collect();
+        let path = temp_log("bounded", &contents);
+        let tail = TailView::open(&path).unwrap().with_max_lines(3);
+        assert_eq!(tail.lines(), vec!["line 7", "line 8", "line 9"]);
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_truncation_restarts_from_beginning() {
+        let path = temp_log("truncate", "old line one\nold line two\n");
+        let tail = TailView::open(&path).unwrap();
+        std::fs::write(&path, "new\n").unwrap();
+        tail.poll().unwrap();
+        assert_eq!(tail.lines().last().unwrap(), "new");
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_first_poll_reads_only_the_tail_window() {
+        let path = temp_log("window", "line one\nline two\nline three\n");
+        // A window starting mid-line drops the fragment; one starting right
+        // after a newline keeps the whole line.
+        for window in [12, 11] {
+            let mut state = TailState::new();
+            state.window = window;
+            state.poll(&path).unwrap();
+            assert_eq!(state.lines, ["line three"], "window {window}");
+        }
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rotation_by_rename_follows_the_new_file() {
+        let path = temp_log("rotate", "old one\n");
+        let rotated = path.with_extension("log.1");
+        let tail = TailView::open(&path).unwrap();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        write!(file, "old two").unwrap();
+        std::fs::rename(&path, &rotated).unwrap();
+        std::fs::write(&path, "new one\n").unwrap();
+        tail.poll().unwrap();
+        assert_eq!(tail.lines(), vec!["old one", "old two", "new one"]);
+        std::fs::remove_file(path).ok();
+        std::fs::remove_file(rotated).ok();
+    }
+
+    #[test]
+    fn test_to_text_is_colorized_and_limited() {
+        let path = temp_log("text", "INFO a\nERROR b\nWARN c\n");
+        let tail = TailView::open(&path).unwrap();
+        let text = tail.to_text(Some(2));
+        assert_eq!(text.plain(), "ERROR b\nWARN c");
+        assert!(!text.spans().is_empty());
+        std::fs::remove_file(path).ok();
+    }
+}
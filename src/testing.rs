@@ -0,0 +1,295 @@
+//! Test support -- render widgets to plain text and compare them.
+//!
+//! Asserting on a widget's output otherwise means building a capture
+//! console, stripping escape codes and trimming padding in every test.
+//! [`render_plain`] does that in one call, [`assert_renders_to!`] compares
+//! the result with an expected string and prints a line-by-line diff on
+//! failure, and [`snapshot`] wraps it for snapshot-testing tools such as
+//! `insta`.
+//!
+//! Output is normalized the same way on both sides of a comparison: ANSI
+//! escape sequences are removed, trailing whitespace is trimmed from every
+//! line, and trailing blank lines are dropped. A single leading newline in
+//! the expected string is ignored so it can start on the line after `r"`.
+//!
+//! # Examples
+//!
+//! ```
+//! use gilt::assert_renders_to;
+//! use gilt::panel::Panel;
+//! use gilt::text::Text;
+//!
+//! let panel = Panel::fit(Text::from("Hi"));
+//! assert_renders_to!(panel, 20, r"
+//! ╭────╮
+//! │ Hi │
+//! ╰────╯
+//! ");
+//! ```
+
+use std::fmt;
+
+use crate::console::{Console, Renderable};
+use crate::utils::ansi;
+
+/// Width used by [`assert_renders_to!`] when none is given.
+pub const DEFAULT_TEST_WIDTH: usize = 80;
+
+/// Render a widget at `width` columns and return its normalized plain text.
+///
+/// Colors and styles are disabled, escape sequences stripped, trailing
+/// whitespace trimmed from every line and trailing blank lines dropped.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::rule::Rule;
+/// use gilt::testing::render_plain;
+///
+/// assert_eq!(render_plain(&Rule::new(), 5), "━━━━━");
+/// ```
+pub fn render_plain(widget: &dyn Renderable, width: usize) -> String {
+    let mut console = Console::builder()
+        .width(width)
+        .force_terminal(true)
+        .no_color(true)
+        .build();
+    console.begin_capture();
+    console.print(widget);
+    normalize(&console.end_capture())
+}
+
+/// Normalize rendered output for comparison: strip ANSI escape sequences,
+/// trim trailing whitespace from each line and drop trailing blank lines.
+pub fn normalize(output: &str) -> String {
+    let stripped = strip_ansi(output);
+    let mut lines: Vec<&str> = stripped.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Remove ANSI escape sequences (CSI, such as colors and cursor movement,
+/// OSC, such as hyperlinks and window titles, and the rest recognized by
+/// [`AnsiDecoder`](crate::ansi::AnsiDecoder)) from a string.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::testing::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[1;31mred\x1b[0m"), "red");
+/// assert_eq!(strip_ansi("\x1b]8;;https://x.dev\x1b\\link\x1b]8;;\x1b\\"), "link");
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+    ansi::strip_ansi(text)
+}
+
+/// Compare a widget's plain rendering with `expected`, panicking with a
+/// line-by-line diff when they differ.
+///
+/// This is the function behind [`assert_renders_to!`].
+#[track_caller]
+pub fn assert_renders_to(widget: &dyn Renderable, width: usize, expected: &str) {
+    let actual = render_plain(widget, width);
+    let expected = normalize(expected.strip_prefix('\n').unwrap_or(expected));
+    if actual != expected {
+        panic!(
+            "rendered output does not match (width {}, - expected, + actual):\n{}",
+            width,
+            line_diff(&expected, &actual)
+        );
+    }
+}
+
+/// A simple positional diff: matching lines are prefixed with two spaces,
+/// differing ones with `-` (expected) and `+` (actual).
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    out.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Assert that a widget renders to the expected plain text.
+///
+/// Takes the widget (borrowed, not moved), an optional width (default
+/// [`DEFAULT_TEST_WIDTH`]) and the expected output. Both sides are
+/// normalized as described in the [module docs](crate::testing); on
+/// failure the panic message shows a line-by-line diff.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::assert_renders_to;
+/// use gilt::text::Text;
+///
+/// assert_renders_to!(Text::from("hello world"), 5, "hello\nworld");
+/// assert_renders_to!(Text::from("[b]not markup[/b]"), "[b]not markup[/b]");
+/// ```
+#[macro_export]
+macro_rules! assert_renders_to {
+    ($widget:expr, $width:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_renders_to(&$widget, $width, $expected)
+    };
+    ($widget:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_renders_to(&$widget, $crate::testing::DEFAULT_TEST_WIDTH, $expected)
+    };
+}
+
+pub use crate::assert_renders_to;
+
+// ---------------------------------------------------------------------------
+// Snapshots
+// ---------------------------------------------------------------------------
+
+/// A widget's plain rendering, ready for a snapshot-testing tool.
+///
+/// `Display` and `Debug` both print the rendered text verbatim, so
+/// `insta::assert_snapshot!` and `insta::assert_debug_snapshot!` store it as
+/// it appears in a terminal. With the `json` feature it also implements
+/// `serde::Serialize` (as `{ width, lines }`) for the serde-based
+/// snapshot macros.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::testing::snapshot;
+/// use gilt::text::Text;
+///
+/// let snap = snapshot(&Text::from("one two"), 3);
+/// assert_eq!(snap.lines(), ["one", "two"]);
+/// assert_eq!(snap.to_string(), "one\ntwo");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct RenderSnapshot {
+    /// Width the widget was rendered at.
+    pub width: usize,
+    /// Normalized plain output (see [`render_plain`]).
+    pub text: String,
+}
+
+impl RenderSnapshot {
+    /// The rendered output split into lines.
+    pub fn lines(&self) -> Vec<&str> {
+        self.text.lines().collect()
+    }
+}
+
+/// Render a widget at `width` columns into a [`RenderSnapshot`].
+pub fn snapshot(widget: &dyn Renderable, width: usize) -> RenderSnapshot {
+    RenderSnapshot {
+        width,
+        text: render_plain(widget, width),
+    }
+}
+
+impl fmt::Display for RenderSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl fmt::Debug for RenderSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(feature = "json")]
+impl serde::Serialize for RenderSnapshot {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RenderSnapshot", 2)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("lines", &self.lines())?;
+        state.end()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panel::Panel;
+    use crate::style::Style;
+    use crate::text::Text;
+
+    #[test]
+    fn test_render_plain_strips_styles_and_padding() {
+        let text = Text::styled("bold", Style::parse("bold red").unwrap());
+        assert_eq!(render_plain(&text, 20), "bold");
+        let panel = Panel::new(Text::from("x"));
+        let out = render_plain(&panel, 7);
+        assert_eq!(out, "╭─────╮\n│ x   │\n╰─────╯");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a  \n\x1b[1mb\x1b[0m\t\n\n  \n"), "a\nb");
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\n\nx"), "\n\nx");
+    }
+
+    #[test]
+    fn test_strip_ansi_sequences() {
+        assert_eq!(strip_ansi("\x1b[2K\x1b[1A\x1b[38;2;1;2;3mok"), "ok");
+        assert_eq!(strip_ansi("\x1b]0;title\x07text"), "text");
+        assert_eq!(strip_ansi("a\x1b7b"), "ab");
+        assert_eq!(strip_ansi("up\x1bOAdown"), "updown");
+        assert_eq!(strip_ansi("no escapes"), "no escapes");
+    }
+
+    #[test]
+    fn test_assert_renders_to_macro_forms() {
+        let text = Text::from("alpha beta");
+        assert_renders_to!(text, "alpha beta");
+        assert_renders_to!(text, 5, "\nalpha\nbeta\n");
+        assert_renders_to!(text, 6, "alpha   \nbeta",);
+    }
+
+    #[test]
+    fn test_assert_renders_to_reports_diff() {
+        let result = std::panic::catch_unwind(|| {
+            assert_renders_to(&Text::from("one\ntwo"), 10, "one\nthree\nfour");
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("width 10"));
+        assert!(message.contains("  one\n- three\n+ two\n- four\n"));
+    }
+
+    #[test]
+    fn test_snapshot_display_and_debug() {
+        let snap = snapshot(&Text::from("a b"), 1);
+        assert_eq!(snap.width, 1);
+        assert_eq!(format!("{}", snap), "a\nb");
+        assert_eq!(format!("{:?}", snap), "a\nb");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_snapshot_serializes_lines() {
+        let snap = snapshot(&Text::from("a b"), 1);
+        assert_eq!(
+            serde_json::to_string(&snap).unwrap(),
+            r#"{"width":1,"lines":["a","b"]}"#
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Right-to-left support for wrapped text (requires the `bidi` feature).
+//!
+//! Terminals draw cells left to right in the order they receive them, so
+//! Arabic or Hebrew text written in logical order comes out backwards, and
+//! padding meant for the end of a right-to-left line lands on the wrong side.
+//! [`reorder_lines`] runs the Unicode Bidirectional Algorithm over each
+//! source paragraph and rewrites its wrapped lines in visual order, keeping
+//! grapheme clusters (a letter with its combining marks) together.
+
+use unicode_bidi::{BidiInfo, Level, ParagraphBidiInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{JustifyMethod, Span, Text};
+
+/// Reorder the wrapped `lines` of the paragraph `source` from logical to
+/// visual order.
+///
+/// `starts[i]` is the character offset of `lines[i]` in `source`; a line may
+/// be shorter than the gap to the next start if trailing whitespace was
+/// stripped. Returns whether the paragraph direction is right-to-left.
+pub(crate) fn reorder_lines(source: &str, lines: &mut [Text], starts: &[usize]) -> bool {
+    let info = ParagraphBidiInfo::new(source, None);
+    if info.is_pure_ltr {
+        return false;
+    }
+    let byte_offsets: Vec<usize> = source
+        .char_indices()
+        .map(|(byte, _)| byte)
+        .chain(std::iter::once(source.len()))
+        .collect();
+
+    for (line, &start) in lines.iter_mut().zip(starts) {
+        let end = (start + line.len()).min(byte_offsets.len() - 1);
+        let byte_range = byte_offsets[start]..byte_offsets[end];
+        let levels = info.reordered_levels(byte_range.clone());
+        if let Some(order) = visual_order(line.plain(), &levels[byte_range]) {
+            *line = reorder_text(line, &order);
+        }
+    }
+    info.paragraph_level.is_rtl()
+}
+
+/// The justification that places a right-to-left line where `justify`
+/// would place a left-to-right one: lines start at the right edge.
+pub(crate) fn mirror_justify(justify: JustifyMethod) -> JustifyMethod {
+    match justify {
+        JustifyMethod::Default | JustifyMethod::Left => JustifyMethod::Right,
+        JustifyMethod::Right => JustifyMethod::Left,
+        other => other,
+    }
+}
+
+/// Visual order of the characters of `line`, given the resolved bidi level
+/// of each of its bytes: entry `v` is the index of the character shown at
+/// visual position `v` and whether it sits in a right-to-left run. `None`
+/// if the line has no right-to-left run.
+fn visual_order(line: &str, levels: &[Level]) -> Option<Vec<(usize, bool)>> {
+    // Reorder whole grapheme clusters so combining marks stay after their
+    // base character.
+    let mut clusters: Vec<(usize, usize)> = Vec::new();
+    let mut cluster_levels: Vec<Level> = Vec::new();
+    let mut char_index = 0;
+    for (byte, grapheme) in line.grapheme_indices(true) {
+        let count = grapheme.chars().count();
+        clusters.push((char_index, count));
+        cluster_levels.push(levels[byte]);
+        char_index += count;
+    }
+    if cluster_levels.iter().all(|level| level.is_ltr()) {
+        return None;
+    }
+
+    let order = BidiInfo::reorder_visual(&cluster_levels);
+    let mut chars = Vec::with_capacity(char_index);
+    for logical in order {
+        let (start, count) = clusters[logical];
+        let rtl = cluster_levels[logical].is_rtl();
+        chars.extend((start..start + count).map(|index| (index, rtl)));
+    }
+    Some(chars)
+}
+
+/// Mirror a paired bracket drawn inside a right-to-left run.
+fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        other => other,
+    }
+}
+
+/// Rebuild `line` with its characters in `order`, moving each span to the
+/// visual positions of the characters it covered.
+fn reorder_text(line: &Text, order: &[(usize, bool)]) -> Text {
+    let chars: Vec<char> = line.plain().chars().collect();
+    let plain: String = order
+        .iter()
+        .map(|&(index, rtl)| {
+            if rtl {
+                mirror(chars[index])
+            } else {
+                chars[index]
+            }
+        })
+        .collect();
+
+    let mut visual_of = vec![0; chars.len()];
+    for (visual, &(logical, _)) in order.iter().enumerate() {
+        visual_of[logical] = visual;
+    }
+
+    let mut spans = Vec::new();
+    for span in &line.spans {
+        let mut positions: Vec<usize> = (span.start..span.end.min(chars.len()))
+            .map(|logical| visual_of[logical])
+            .collect();
+        positions.sort_unstable();
+        let mut run_start = None;
+        for (i, &position) in positions.iter().enumerate() {
+            let start = *run_start.get_or_insert(position);
+            let next_contiguous = positions.get(i + 1) == Some(&(position + 1));
+            if !next_contiguous {
+                spans.push(Span::new(start, position + 1, span.style.clone()));
+                run_start = None;
+            }
+        }
+    }
+    spans.sort_by_key(|span| span.start);
+
+    let mut reordered = line.clone();
+    reordered.set_plain(&plain);
+    reordered.spans = spans;
+    reordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_ltr_lines_are_untouched() {
+        let mut lines = vec![Text::new("hello world", Style::null())];
+        assert!(!reorder_lines("hello world", &mut lines, &[0]));
+        assert_eq!(lines[0].plain(), "hello world");
+    }
+
+    #[test]
+    fn test_rtl_line_is_reversed_with_marks_attached() {
+        // "shalom" in Hebrew followed by an LTR number; the second letter
+        // carries a combining point.
+        let source = "\u{05E9}\u{05C1}\u{05DC}\u{05D5}\u{05DD} 42";
+        let mut lines = vec![Text::new(source, Style::null())];
+        assert!(reorder_lines(source, &mut lines, &[0]));
+        assert_eq!(
+            lines[0].plain(),
+            "42 \u{05DD}\u{05D5}\u{05DC}\u{05E9}\u{05C1}"
+        );
+    }
+
+    #[test]
+    fn test_spans_follow_their_characters() {
+        let source = "abc \u{05D0}\u{05D1}\u{05D2} def";
+        let mut text = Text::new(source, Style::null());
+        let bold = Style::parse("bold").unwrap();
+        text.stylize(bold.clone(), 4, Some(6)); // first two Hebrew letters
+        let mut lines = vec![text];
+        assert!(!reorder_lines(source, &mut lines, &[0]));
+        assert_eq!(lines[0].plain(), "abc \u{05D2}\u{05D1}\u{05D0} def");
+        assert_eq!(lines[0].spans, vec![Span::new(5, 7, bold)]);
+    }
+
+    #[test]
+    fn test_brackets_are_mirrored_in_rtl_runs() {
+        let source = "\u{05D0}(\u{05D1})";
+        let mut lines = vec![Text::new(source, Style::null())];
+        reorder_lines(source, &mut lines, &[0]);
+        assert_eq!(lines[0].plain(), "(\u{05D1})\u{05D0}");
+    }
+
+    #[test]
+    fn test_wrap_reorders_and_right_aligns_rtl_paragraphs() {
+        // Three Hebrew words: alef-bet, gimel-dalet, he-vav.
+        let text = Text::new(
+            "\u{05D0}\u{05D1} \u{05D2}\u{05D3} \u{05D4}\u{05D5}\nab cd",
+            Style::null(),
+        );
+        let lines = text.wrap(6, Some(JustifyMethod::Left), None, 8, false);
+        let plain: Vec<&str> = lines.iter().map(|line| line.plain()).collect();
+        assert_eq!(
+            plain,
+            vec![
+                " \u{05D3}\u{05D2} \u{05D1}\u{05D0}",
+                "    \u{05D5}\u{05D4}",
+                "ab cd ",
+            ]
+        );
+    }
+}
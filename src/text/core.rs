@@ -16,10 +16,10 @@ use crate::segment::Segment;
 use crate::style::Style;
 use crate::utils::ansi::AnsiDecoder;
 use crate::utils::cells::{cell_len, set_cell_size};
-use crate::wrap::divide_line;
+use crate::wrap::{line_breaks, wrap_units};
 
 use super::{JustifyMethod, Lines, OverflowMethod, Span};
-use crate::text::helpers::{char_slice, gcd, strip_control_codes};
+use crate::text::helpers::{char_slice, expand_tab_stops, gcd, strip_control_codes};
 
 /// A building block for [`Text::assemble`], representing one segment of text.
 pub enum TextPart {
@@ -74,6 +74,10 @@ pub struct Text {
     pub end: String,
     /// Tab stop width override; `None` uses the default of 8.
     pub tab_size: Option<usize>,
+    /// When `Some(min_fragment)`, words that overflow a line while wrapping
+    /// are hyphenated, keeping at least `min_fragment` characters on each
+    /// side of the hyphen.
+    pub hyphenate: Option<usize>,
 }
 
 impl Text {
@@ -103,6 +107,7 @@ impl Text {
             no_wrap: None,
             end: "\n".to_string(),
             tab_size: None,
+            hyphenate: None,
         }
     }
 
@@ -121,6 +126,22 @@ impl Text {
         t
     }
 
+    /// Create Text that is an OSC 8 hyperlink to `url` in terminals that
+    /// support them, and plain text elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::text::Text;
+    ///
+    /// let text = Text::link("docs", "https://docs.rs/gilt");
+    /// assert_eq!(text.plain(), "docs");
+    /// assert_eq!(text.spans()[0].style.link(), Some("https://docs.rs/gilt"));
+    /// ```
+    pub fn link(text: &str, url: &str) -> Self {
+        Text::styled(text, Style::with_link(url))
+    }
+
     /// Assemble a `Text` from a slice of [`TextPart`] segments with a shared base style.
     ///
     /// # Examples
@@ -160,20 +181,37 @@ impl Text {
 
     /// Create a `Text` from a console markup string like `"[bold red]Hello[/bold red] world"`.
     ///
-    /// Delegates to [`crate::markup::render`].
+    /// `:shortcode:` emoji such as `:heart:` are replaced, as in Python rich.
+    /// Delegates to [`crate::markup::render_with_emoji`].
     ///
     /// # Errors
     ///
     /// Returns [`MarkupError`] if the markup contains mismatched closing tags.
     pub fn from_markup(markup: &str) -> Result<Text, MarkupError> {
-        crate::markup::render(markup, Style::null())
+        crate::markup::render_with_emoji(markup, Style::null(), None)
     }
 
-    /// Create a `Text` from a string containing ANSI escape codes.
+    /// Create a `Text` from a string containing ANSI escape codes, such as
+    /// the captured output of a child process.
+    ///
+    /// Each line is decoded with [`AnsiDecoder::decode_line`] (styles carry
+    /// over from one line to the next) and the lines are joined with `\n`,
+    /// so `\r\n` line endings and a trailing newline are handled. Colors,
+    /// attributes and hyperlinks become styles; cursor movement, erase and
+    /// other control sequences are dropped, leaving only printable text
+    /// that measures and wraps correctly inside other widgets.
+    ///
+    /// # Examples
     ///
-    /// Delegates to [`AnsiDecoder::decode_line`].
+    /// ```
+    /// use gilt::text::Text;
+    ///
+    /// let text = Text::from_ansi("\x1b[1mok\x1b[0m\r\n\x1b[2Kdone\n");
+    /// assert_eq!(text.plain(), "ok\ndone");
+    /// ```
     pub fn from_ansi(text: &str) -> Text {
-        AnsiDecoder::new().decode_line(text)
+        let lines = AnsiDecoder::new().decode(text);
+        Text::new("\n", Style::null()).join(&lines)
     }
 
     // -- Properties ---------------------------------------------------------
@@ -200,6 +238,11 @@ impl Text {
         self.text = new_text.into_owned();
     }
 
+    /// Return the base style applied to the whole text.
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
     /// Return the style spans applied to this text.
     pub fn spans(&self) -> &[Span] {
         &self.spans
@@ -231,12 +274,21 @@ impl Text {
     ///
     /// This is the Rust equivalent of Python's `Text.__rich_measure__`.
     pub fn measure(&self) -> Measurement {
+        if self.text.contains('\t') {
+            let mut expanded = self.clone();
+            expanded.expand_tabs(None);
+            return expanded.measure();
+        }
         let text = self.plain();
         if text.is_empty() {
             return Measurement::new(0, 0);
         }
         let max_text_width = text.lines().map(cell_len).max().unwrap_or(0);
-        let min_text_width = text.split_whitespace().map(cell_len).max().unwrap_or(0);
+        let min_text_width = wrap_units(text)
+            .into_iter()
+            .map(|(_, _, unit)| cell_len(unit.trim_end()))
+            .max()
+            .unwrap_or(0);
         Measurement::new(min_text_width, max_text_width)
     }
 
@@ -271,6 +323,7 @@ impl Text {
             no_wrap: self.no_wrap,
             end: self.end.clone(),
             tab_size: self.tab_size,
+            hyphenate: self.hyphenate,
         }
     }
 
@@ -568,15 +621,15 @@ impl Text {
         if start >= end {
             return self.blank_copy("");
         }
-        // Use divide to get the slice
+        // Use divide to get the slice; a leading offset of 0 is deduplicated
+        // away, so the wanted range is then the first line rather than the second.
         let divided = self.divide(&[start, end]);
-        if divided.len() >= 2 {
-            divided.lines[1].clone()
-        } else if divided.len() == 1 {
-            divided.lines[0].clone()
-        } else {
-            self.blank_copy("")
-        }
+        let index = if start == 0 { 0 } else { 1 };
+        divided
+            .lines
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| self.blank_copy(""))
     }
 
     // -- Cropping and padding -----------------------------------------------
@@ -609,6 +662,21 @@ impl Text {
     /// [`OverflowMethod`]). When `pad` is `true` and the text is shorter than
     /// `max_width`, spaces are appended to fill the remaining width.
     pub fn truncate(&mut self, max_width: usize, overflow: Option<OverflowMethod>, pad: bool) {
+        self.truncate_with_ellipsis(max_width, overflow, pad, "\u{2026}");
+    }
+
+    /// Like [`truncate`](Self::truncate), but appends `ellipsis` instead of
+    /// `"…"` when the overflow method is [`OverflowMethod::Ellipsis`].
+    ///
+    /// If `max_width` is too narrow for the ellipsis itself, the text is
+    /// cropped without one.
+    pub fn truncate_with_ellipsis(
+        &mut self,
+        max_width: usize,
+        overflow: Option<OverflowMethod>,
+        pad: bool,
+        ellipsis: &str,
+    ) {
         let current_width = self.cell_len();
         let overflow = overflow.unwrap_or(OverflowMethod::Fold);
 
@@ -625,10 +693,17 @@ impl Text {
                     self.set_plain("");
                     return;
                 }
-                let new_text = set_cell_size(&self.text, max_width.saturating_sub(1)).into_owned();
-                // Count chars of new_text for span adjustment
-                self.set_plain(&new_text);
-                self.append_str("\u{2026}", None); // ellipsis
+                let ellipsis_width = cell_len(ellipsis);
+                if ellipsis_width > max_width {
+                    let new_text = set_cell_size(&self.text, max_width).into_owned();
+                    self.set_plain(&new_text);
+                } else {
+                    let new_text =
+                        set_cell_size(&self.text, max_width - ellipsis_width).into_owned();
+                    // Count chars of new_text for span adjustment
+                    self.set_plain(&new_text);
+                    self.append_str(ellipsis, None);
+                }
             }
             OverflowMethod::Crop | OverflowMethod::Fold => {
                 let new_text = set_cell_size(&self.text, max_width).into_owned();
@@ -829,30 +904,28 @@ impl Text {
 
     /// Replace tab characters with spaces, adjusting span positions accordingly.
     ///
-    /// Uses the given `tab_size`, falling back to [`Text::tab_size`], then to 8.
+    /// Each tab is padded to the next tab stop, every `tab_size` cells from
+    /// the start of its line, as a terminal would. Uses the given `tab_size`,
+    /// falling back to [`Text::tab_size`], then to 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::style::Style;
+    /// use gilt::text::Text;
+    ///
+    /// let mut text = Text::new("a\tbc\tdef\n\tg", Style::null());
+    /// text.expand_tabs(Some(4));
+    /// assert_eq!(text.plain(), "a   bc  def\n    g");
+    /// ```
     pub fn expand_tabs(&mut self, tab_size: Option<usize>) {
         let tab_size = tab_size.unwrap_or(self.tab_size.unwrap_or(8));
         if !self.text.contains('\t') {
             return;
         }
 
-        let old_text = self.text.clone();
-        let spaces: String = std::iter::repeat_n(' ', tab_size).collect();
-        let new_text = old_text.replace('\t', &spaces);
-
-        // Adjust spans: for each tab, chars shift by (tab_size - 1)
-        let old_chars: Vec<char> = old_text.chars().collect();
-        let mut char_offset_map: Vec<usize> = Vec::with_capacity(old_chars.len() + 1);
-        let mut new_pos = 0usize;
-        for &c in &old_chars {
-            char_offset_map.push(new_pos);
-            if c == '\t' {
-                new_pos += tab_size;
-            } else {
-                new_pos += 1;
-            }
-        }
-        char_offset_map.push(new_pos); // end sentinel
+        let (new_text, char_offset_map) = expand_tab_stops(&self.text, tab_size);
+        let new_pos = char_offset_map[char_offset_map.len() - 1];
 
         let mut new_spans = Vec::new();
         for span in &self.spans {
@@ -1164,7 +1237,8 @@ impl Text {
     /// Word-wrap the text to fit within `width` terminal cells, returning [`Lines`].
     ///
     /// The text is first split on newlines, tabs are expanded, and each line is
-    /// wrapped using [`crate::wrap::divide_line`]. Optional justification and
+    /// wrapped using [`crate::wrap::line_breaks`], hyphenating words when
+    /// [`hyphenate`](Self::hyphenate) is set. Optional justification and
     /// overflow truncation are applied afterwards.
     ///
     /// When `no_wrap` is `true`, lines are not wrapped but may still be truncated
@@ -1176,46 +1250,90 @@ impl Text {
         overflow: Option<OverflowMethod>,
         tab_size: usize,
         no_wrap: bool,
+    ) -> Lines {
+        self.wrap_with_ellipsis(width, justify, overflow, tab_size, no_wrap, "\u{2026}")
+    }
+
+    /// [`wrap`](Self::wrap) using `ellipsis` as the truncation marker.
+    pub(crate) fn wrap_with_ellipsis(
+        &self,
+        width: usize,
+        justify: Option<JustifyMethod>,
+        overflow: Option<OverflowMethod>,
+        tab_size: usize,
+        no_wrap: bool,
+        ellipsis: &str,
     ) -> Lines {
         let overflow = overflow.unwrap_or(OverflowMethod::Fold);
 
         // 1. Split on newlines (include_separator=false, matching Python's default)
         let new_lines = self.split("\n", false, true);
         let mut all_lines = Lines::default();
+        // Whether each wrapped line belongs to a right-to-left paragraph.
+        #[cfg(feature = "bidi")]
+        let mut rtl_lines: Vec<bool> = Vec::new();
 
         for mut line in new_lines.lines {
             // 2. Expand tabs
             line.expand_tabs(Some(tab_size));
+            #[cfg(feature = "bidi")]
+            let (first, source) = (all_lines.len(), line.plain().to_string());
 
-            if no_wrap {
-                if overflow != OverflowMethod::Ignore {
-                    // Still keep as single line
-                }
-                all_lines.push(line);
+            let breaks = if no_wrap {
+                Vec::new()
             } else {
                 // 3. Wrap the line
-                let offsets = divide_line(line.plain(), width, true);
-                if offsets.is_empty() {
-                    all_lines.push(line);
-                } else {
-                    let divided = line.divide(&offsets);
-                    for mut dl in divided.lines {
-                        dl.rstrip_end(width);
-                        all_lines.push(dl);
+                line_breaks(line.plain(), width, true, self.hyphenate)
+            };
+            let offsets: Vec<usize> = breaks.iter().map(|b| b.offset).collect();
+            if offsets.is_empty() {
+                all_lines.push(line);
+            } else {
+                let divided = line.divide(&offsets);
+                for (index, mut dl) in divided.lines.into_iter().enumerate() {
+                    if index < breaks.len() && breaks[index].hyphen {
+                        dl.append_str("-", None);
                     }
+                    dl.rstrip_end(width);
+                    all_lines.push(dl);
                 }
             }
+
+            // 3b. Put right-to-left runs in visual order
+            #[cfg(feature = "bidi")]
+            {
+                let starts: Vec<usize> = std::iter::once(0).chain(offsets).collect();
+                let rtl =
+                    super::bidi::reorder_lines(&source, &mut all_lines.lines[first..], &starts);
+                rtl_lines.resize(all_lines.len(), rtl);
+            }
         }
 
         // 4. Justify
         if let Some(j) = justify {
-            all_lines.justify(width, j, overflow);
+            #[cfg(feature = "bidi")]
+            let mirrored = super::bidi::mirror_justify(j);
+            #[cfg(feature = "bidi")]
+            if mirrored != j && rtl_lines.contains(&true) {
+                // Left and right justification treat lines independently,
+                // so right-to-left lines can be justified on their own.
+                for (line, rtl) in all_lines.lines.iter_mut().zip(&rtl_lines) {
+                    let mut single = Lines::new(vec![line.clone()]);
+                    let method = if *rtl { mirrored } else { j };
+                    single.justify_with_ellipsis(width, method, overflow, ellipsis);
+                    *line = single.lines.remove(0);
+                }
+            } else {
+                all_lines.justify_with_ellipsis(width, j, overflow, ellipsis);
+            }
+            #[cfg(not(feature = "bidi"))]
+            all_lines.justify_with_ellipsis(width, j, overflow, ellipsis);
         }
 
         // 5. Truncate each line
         for line in all_lines.iter_mut() {
             if line.cell_len() > width {
-                line.truncate(width, Some(overflow), false);
+                line.truncate_with_ellipsis(width, Some(overflow), false, ellipsis);
             }
         }
 
@@ -0,0 +1,173 @@
+//! Word-level diffing of two [`Text`] values.
+
+use crate::diff::{compute_diff, DiffOp};
+use crate::style::Style;
+
+use super::Text;
+
+/// Split a string into alternating runs of whitespace and non-whitespace,
+/// returning each token with its character length.
+fn word_tokens(s: &str) -> Vec<(&str, usize)> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = 0;
+    let mut in_space: Option<bool> = None;
+    for (byte_idx, c) in s.char_indices() {
+        let space = c.is_whitespace();
+        if in_space.is_some_and(|prev| prev != space) {
+            tokens.push((&s[start..byte_idx], chars));
+            start = byte_idx;
+            chars = 0;
+        }
+        in_space = Some(space);
+        chars += 1;
+    }
+    if start < s.len() {
+        tokens.push((&s[start..], chars));
+    }
+    tokens
+}
+
+impl Text {
+    /// Produce a single [`Text`] showing the word-level changes from `self`
+    /// to `other`.
+    ///
+    /// Unchanged and inserted words keep their styles from `other`, deleted
+    /// words keep their styles from `self`. Inserted words are additionally
+    /// styled green and deleted words red with strikethrough, so the result
+    /// can be dropped inline into a panel or table cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::text::Text;
+    ///
+    /// let old = Text::from("the quick fox");
+    /// let new = Text::from("the slow fox");
+    /// let diff = old.diff_words(&new);
+    /// assert_eq!(diff.plain(), "the quickslow fox");
+    /// ```
+    pub fn diff_words(&self, other: &Text) -> Text {
+        let insert_style = Style::parse("green").unwrap_or_else(|_| Style::null());
+        let delete_style = Style::parse("red strike").unwrap_or_else(|_| Style::null());
+        self.diff_words_with_styles(other, insert_style, delete_style)
+    }
+
+    /// Like [`diff_words`](Text::diff_words), with custom styles layered over
+    /// inserted and deleted words.
+    pub fn diff_words_with_styles(
+        &self,
+        other: &Text,
+        insert_style: Style,
+        delete_style: Style,
+    ) -> Text {
+        let old_tokens = word_tokens(self.plain());
+        let new_tokens = word_tokens(other.plain());
+        let old_words: Vec<&str> = old_tokens.iter().map(|(t, _)| *t).collect();
+        let new_words: Vec<&str> = new_tokens.iter().map(|(t, _)| *t).collect();
+
+        let mut result = other.blank_copy("");
+        let (mut old_index, mut new_index) = (0, 0);
+        let (mut old_offset, mut new_offset) = (0, 0);
+
+        for op in compute_diff(&old_words, &new_words) {
+            match op {
+                DiffOp::Equal(_) => {
+                    let len = new_tokens[new_index].1;
+                    result.append_text(&other.slice(new_offset, new_offset + len));
+                    old_offset += old_tokens[old_index].1;
+                    new_offset += len;
+                    old_index += 1;
+                    new_index += 1;
+                }
+                DiffOp::Insert(_) => {
+                    let len = new_tokens[new_index].1;
+                    let mut piece = other.slice(new_offset, new_offset + len);
+                    piece.stylize(insert_style.clone(), 0, None);
+                    result.append_text(&piece);
+                    new_offset += len;
+                    new_index += 1;
+                }
+                DiffOp::Delete(_) => {
+                    let len = old_tokens[old_index].1;
+                    let mut piece = self.slice(old_offset, old_offset + len);
+                    piece.stylize(delete_style.clone(), 0, None);
+                    result.append_text(&piece);
+                    old_offset += len;
+                    old_index += 1;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style_of(text: &Text, offset: usize) -> Style {
+        text.get_style_at_offset(offset)
+    }
+
+    #[test]
+    fn test_word_tokens() {
+        let tokens: Vec<&str> = word_tokens("a  bc\td").into_iter().map(|t| t.0).collect();
+        assert_eq!(tokens, vec!["a", "  ", "bc", "\t", "d"]);
+        assert!(word_tokens("").is_empty());
+    }
+
+    #[test]
+    fn test_identical_texts_unchanged() {
+        let text = Text::from("same words here");
+        let diff = text.diff_words(&text);
+        assert_eq!(diff.plain(), "same words here");
+        assert!(diff.spans().is_empty());
+    }
+
+    #[test]
+    fn test_replaced_word_marked() {
+        let old = Text::from("the quick fox");
+        let new = Text::from("the slow fox");
+        let diff = old.diff_words(&new);
+        assert_eq!(diff.plain(), "the quickslow fox");
+        assert_eq!(style_of(&diff, 4).strike(), Some(true));
+        assert_eq!(style_of(&diff, 9).strike(), None);
+        assert!(style_of(&diff, 9).color().is_some());
+        assert!(style_of(&diff, 0).color().is_none());
+    }
+
+    #[test]
+    fn test_preserves_original_styles() {
+        let bold = Style::parse("bold").unwrap();
+        let mut old = Text::from("keep gone");
+        old.stylize(bold.clone(), 5, None);
+        let mut new = Text::from("keep");
+        new.stylize(bold.clone(), 0, Some(4));
+        let diff = old.diff_words(&new);
+        assert_eq!(diff.plain(), "keep gone");
+        assert_eq!(style_of(&diff, 0).bold(), Some(true));
+        let deleted = style_of(&diff, 6);
+        assert_eq!(deleted.bold(), Some(true));
+        assert_eq!(deleted.strike(), Some(true));
+    }
+
+    #[test]
+    fn test_custom_styles() {
+        let old = Text::from("a");
+        let new = Text::from("a b");
+        let underline = Style::parse("underline").unwrap();
+        let diff = old.diff_words_with_styles(&new, underline, Style::null());
+        assert_eq!(diff.plain(), "a b");
+        assert_eq!(style_of(&diff, 2).underline(), Some(true));
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        let empty = Text::from("");
+        let words = Text::from("new words");
+        assert_eq!(empty.diff_words(&words).plain(), "new words");
+        assert_eq!(words.diff_words(&empty).plain(), "new words");
+        assert_eq!(empty.diff_words(&empty).plain(), "");
+    }
+}
@@ -17,6 +17,46 @@ pub fn strip_control_codes(text: &str) -> Cow<'_, str> {
     )
 }
 
+/// Replace tabs with spaces up to the next tab stop, every `tab_size` cells
+/// from the start of each line.
+///
+/// Returns the expanded text and, for every char of `text` plus an end
+/// sentinel, its char index in the expanded text. A `tab_size` of 0 removes
+/// tabs.
+pub(crate) fn expand_tab_stops(text: &str, tab_size: usize) -> (String, Vec<usize>) {
+    let mut expanded = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut position = 0;
+    let mut column = 0;
+    for c in text.chars() {
+        offsets.push(position);
+        match c {
+            '\t' => {
+                let spaces = if tab_size == 0 {
+                    0
+                } else {
+                    tab_size - column % tab_size
+                };
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                position += spaces;
+                column += spaces;
+            }
+            '\n' => {
+                expanded.push(c);
+                position += 1;
+                column = 0;
+            }
+            _ => {
+                expanded.push(c);
+                position += 1;
+                column += crate::cells::get_character_cell_size(c);
+            }
+        }
+    }
+    offsets.push(position);
+    (expanded, offsets)
+}
+
 /// Convert a char index to a byte index within a string.
 pub fn char_to_byte_index(s: &str, char_idx: usize) -> usize {
     s.char_indices()
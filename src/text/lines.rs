@@ -57,16 +57,27 @@ impl Lines {
     /// `Full` justification distributes extra space between words on all lines
     /// except the last, which is left-justified.
     pub fn justify(&mut self, width: usize, justify: JustifyMethod, overflow: OverflowMethod) {
+        self.justify_with_ellipsis(width, justify, overflow, "\u{2026}");
+    }
+
+    /// [`justify`](Self::justify) using `ellipsis` as the truncation marker.
+    pub(crate) fn justify_with_ellipsis(
+        &mut self,
+        width: usize,
+        justify: JustifyMethod,
+        overflow: OverflowMethod,
+        ellipsis: &str,
+    ) {
         match justify {
             JustifyMethod::Default | JustifyMethod::Left => {
                 for line in &mut self.lines {
-                    line.truncate(width, Some(overflow), true);
+                    line.truncate_with_ellipsis(width, Some(overflow), true, ellipsis);
                 }
             }
             JustifyMethod::Center => {
                 for line in &mut self.lines {
                     line.rstrip();
-                    line.truncate(width, Some(overflow), false);
+                    line.truncate_with_ellipsis(width, Some(overflow), false, ellipsis);
                     let line_width = line.cell_len();
                     if line_width < width {
                         let left_pad = (width - line_width) / 2;
@@ -79,7 +90,7 @@ impl Lines {
             JustifyMethod::Right => {
                 for line in &mut self.lines {
                     line.rstrip();
-                    line.truncate(width, Some(overflow), false);
+                    line.truncate_with_ellipsis(width, Some(overflow), false, ellipsis);
                     let line_width = line.cell_len();
                     if line_width < width {
                         line.pad_left(width - line_width, ' ');
@@ -91,18 +102,18 @@ impl Lines {
                 for (i, line) in self.lines.iter_mut().enumerate() {
                     if i == line_count - 1 {
                         // Last line: left justify
-                        line.truncate(width, Some(overflow), true);
+                        line.truncate_with_ellipsis(width, Some(overflow), true, ellipsis);
                         continue;
                     }
                     let plain = line.plain().to_string();
                     let words: Vec<&str> = plain.split(' ').collect();
                     if words.len() <= 1 {
-                        line.truncate(width, Some(overflow), true);
+                        line.truncate_with_ellipsis(width, Some(overflow), true, ellipsis);
                         continue;
                     }
                     let text_width = line.cell_len();
                     if text_width >= width {
-                        line.truncate(width, Some(overflow), false);
+                        line.truncate_with_ellipsis(width, Some(overflow), false, ellipsis);
                         continue;
                     }
                     let extra_spaces = width - text_width;
@@ -8,6 +8,8 @@
 pub use enums::{JustifyMethod, OverflowMethod};
 
 // Re-export helper functions
+#[cfg(feature = "syntax")]
+pub(crate) use helpers::expand_tab_stops;
 pub use helpers::strip_control_codes;
 
 // Re-export core types
@@ -15,7 +17,10 @@ pub use core::{Text, TextOrStr, TextPart};
 pub use lines::Lines;
 pub use span::Span;
 
+#[cfg(feature = "bidi")]
+mod bidi;
 mod core;
+mod diff;
 pub mod enums;
 mod helpers;
 mod lines;
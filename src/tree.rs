@@ -74,14 +74,25 @@ pub struct Tree {
     pub label: Text,
     /// Node style.
     pub style: Style,
+    /// Theme style name for the node, such as `"tree"`, looked up when the
+    /// tree is rendered; takes precedence over [`style`](Self::style) when
+    /// it resolves.
+    pub style_name: Option<String>,
     /// Guide line style.
     pub guide_style: Style,
+    /// Theme style name for the guide lines, such as `"tree.line"`, looked
+    /// up when the tree is rendered; takes precedence over
+    /// [`guide_style`](Self::guide_style) when it resolves.
+    pub guide_style_name: Option<String>,
     /// Child nodes.
     pub children: Vec<Tree>,
     /// Whether to show children.
     pub expanded: bool,
     /// Whether to hide the root node.
     pub hide_root: bool,
+    /// Maximum number of children shown per node; the rest are summarised
+    /// by a single "N more" line. `None` shows every child.
+    pub max_children: Option<usize>,
 }
 
 impl Tree {
@@ -90,10 +101,13 @@ impl Tree {
         Tree {
             label,
             style: Style::null(),
+            style_name: None,
             guide_style: Style::null(),
+            guide_style_name: None,
             children: Vec::new(),
             expanded: true,
             hide_root: false,
+            max_children: None,
         }
     }
 
@@ -102,10 +116,13 @@ impl Tree {
         self.children.push(Tree {
             label,
             style: self.style.clone(),
+            style_name: self.style_name.clone(),
             guide_style: self.guide_style.clone(),
+            guide_style_name: self.guide_style_name.clone(),
             children: Vec::new(),
             expanded: true,
             hide_root: false,
+            max_children: None,
         });
         self.children
             .last_mut()
@@ -119,6 +136,14 @@ impl Tree {
         self
     }
 
+    /// Set the node style by theme name, such as `"tree"` or `"$accent"`,
+    /// resolved when the tree is rendered (builder pattern).
+    #[must_use]
+    pub fn with_style_name(mut self, name: &str) -> Self {
+        self.style_name = Some(name.to_string());
+        self
+    }
+
     /// Set the guide style (builder pattern).
     #[must_use]
     pub fn with_guide_style(mut self, style: Style) -> Self {
@@ -126,6 +151,14 @@ impl Tree {
         self
     }
 
+    /// Set the guide style by theme name, such as `"tree.line"`, resolved
+    /// when the tree is rendered (builder pattern).
+    #[must_use]
+    pub fn with_guide_style_name(mut self, name: &str) -> Self {
+        self.guide_style_name = Some(name.to_string());
+        self
+    }
+
     /// Set whether the tree is expanded (builder pattern).
     #[must_use]
     pub fn with_expanded(mut self, expanded: bool) -> Self {
@@ -140,6 +173,17 @@ impl Tree {
         self
     }
 
+    /// Limit the number of children shown per node (builder pattern).
+    ///
+    /// Hidden children are replaced by a line such as `… 3 more`, using the
+    /// console's [`more`](crate::glyphs::Glyphs::more) glyph. The limit
+    /// applies at every level of the tree.
+    #[must_use]
+    pub fn with_max_children(mut self, max_children: usize) -> Self {
+        self.max_children = Some(max_children);
+        self
+    }
+
     // -- Deprecated aliases (old names without `with_` prefix) ----------------
 
     /// Deprecated: use [`with_style`](Self::with_style) instead.
@@ -171,7 +215,7 @@ impl Tree {
     }
 
     /// Measure this tree: compute minimum and maximum widths.
-    pub fn measure(&self, _console: &Console, _options: &ConsoleOptions) -> Measurement {
+    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
         let mut minimum: usize = 0;
         let mut maximum: usize = 0;
 
@@ -188,11 +232,10 @@ impl Tree {
                 level
             };
             let indent = effective_level * 4;
-            let label_width = tree.label.cell_len();
-            let total = label_width + indent;
+            let label = tree.label.measure();
             if !(level == 0 && hide_root) {
-                *min = (*min).max(total);
-                *max = (*max).max(total);
+                *min = (*min).max(label.minimum + indent);
+                *max = (*max).max(label.maximum + indent);
             }
             if tree.expanded {
                 for child in &tree.children {
@@ -202,7 +245,44 @@ impl Tree {
         }
 
         measure_recursive(self, 0, &mut minimum, &mut maximum, self.hide_root);
-        Measurement::new(minimum, maximum)
+        Measurement::new(minimum, maximum).clamp(None, Some(options.max_width))
+    }
+
+    /// The guide style, looked up by name in the console theme when set.
+    fn resolved_guide_style(&self, console: &Console) -> Style {
+        console.widget_style(&self.guide_style, self.guide_style_name.as_deref())
+    }
+
+    /// Copy this tree keeping at most `max` children per node, with a
+    /// trailing "N more" leaf wherever children were dropped.
+    fn limit_children(&self, max: usize, more: &str, console: &Console) -> Tree {
+        let mut children: Vec<Tree> = self
+            .children
+            .iter()
+            .take(max)
+            .map(|child| child.limit_children(max, more, console))
+            .collect();
+        let hidden = self.children.len().saturating_sub(max);
+        if hidden > 0 {
+            let label = Text::new(
+                &format!("{} {} more", more, hidden),
+                self.resolved_guide_style(console),
+            );
+            let mut summary = Tree::new(label).with_guide_style(self.guide_style.clone());
+            summary.guide_style_name = self.guide_style_name.clone();
+            children.push(summary);
+        }
+        Tree {
+            label: self.label.clone(),
+            style: self.style.clone(),
+            style_name: self.style_name.clone(),
+            guide_style: self.guide_style.clone(),
+            guide_style_name: self.guide_style_name.clone(),
+            children,
+            expanded: self.expanded,
+            hide_root: self.hide_root,
+            max_children: None,
+        }
     }
 }
 
@@ -220,6 +300,18 @@ struct StackFrame<'a> {
 
 impl Renderable for Tree {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        match self.max_children {
+            Some(max) => self
+                .limit_children(max, &console.glyphs().more, console)
+                .render_tree(console, options),
+            None => self.render_tree(console, options),
+        }
+    }
+}
+
+impl Tree {
+    /// Render the tree with every child shown.
+    fn render_tree(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let mut segments: Vec<Segment> = Vec::new();
         let ascii_only = options.ascii_only();
         let newline = Segment::line();
@@ -228,7 +320,11 @@ impl Renderable for Tree {
         //
         // `levels` holds the guide segment for each depth level.
         // The stack holds iterators over children at each level.
-        let mut levels: Vec<Segment> = vec![make_guide(CONTINUE, &self.guide_style, ascii_only)];
+        let mut levels: Vec<Segment> = vec![make_guide(
+            CONTINUE,
+            &self.resolved_guide_style(console),
+            ascii_only,
+        )];
         let mut stack: Vec<StackFrame> = Vec::new();
 
         // Push the root as a single-element "children" iterator.
@@ -324,7 +420,7 @@ impl Renderable for Tree {
                 );
 
                 // Add a new level for the children.
-                let child_guide_style = &node.guide_style;
+                let child_guide_style = &node.resolved_guide_style(console);
                 let child_count = node.children.len();
                 let guide_type = if child_count == 1 { END } else { FORK };
                 levels.push(make_guide(guide_type, child_guide_style, ascii_only));
@@ -881,6 +977,59 @@ mod tests {
         assert!(output.contains("level_49"));
     }
 
+    #[test]
+    fn test_guide_style_theme_key() {
+        let mut tree =
+            Tree::new(Text::new("root", Style::null())).with_guide_style_name("tree.line");
+        tree.add(Text::new("child", Style::null()));
+        let mut console = Console::builder().width(20).build();
+        let mut styles = std::collections::HashMap::new();
+        styles.insert("tree.line".to_string(), Style::parse("green").unwrap());
+        console.push_theme(crate::theme::Theme::new(Some(styles), true));
+        let segments = tree.gilt_console(&console, &console.options());
+        let guide = segments.iter().find(|s| s.text.contains('└')).unwrap();
+        assert_eq!(guide.style, Some(Style::parse("green").unwrap()));
+    }
+
+    // -- Max children --------------------------------------------------------
+
+    #[test]
+    fn test_max_children_summarises_hidden() {
+        let mut tree = Tree::new(Text::new("root", Style::null())).with_max_children(2);
+        for i in 0..5 {
+            tree.add(Text::new(&format!("child{}", i), Style::null()));
+        }
+        let output = render_tree(&tree, 40);
+        assert!(output.contains("child1"));
+        assert!(!output.contains("child2"));
+        assert!(output.contains("\u{2026} 3 more"));
+    }
+
+    #[test]
+    fn test_max_children_uses_console_glyph() {
+        let mut tree = Tree::new(Text::new("root", Style::null())).with_max_children(1);
+        tree.add(Text::new("a", Style::null()));
+        tree.add(Text::new("b", Style::null()));
+        let console = Console::builder()
+            .width(40)
+            .no_color(true)
+            .glyphs(crate::glyphs::Glyphs::ascii())
+            .build();
+        let output: String = tree
+            .gilt_console(&console, &console.options())
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert!(output.contains("... 1 more"));
+    }
+
+    #[test]
+    fn test_max_children_not_exceeded_adds_nothing() {
+        let mut tree = Tree::new(Text::new("root", Style::null())).with_max_children(3);
+        tree.add(Text::new("only", Style::null()));
+        assert!(!render_tree(&tree, 40).contains("more"));
+    }
+
     // -- Extreme width boundary tests ---------------------------------------
 
     #[test]
@@ -6,7 +6,7 @@
 use std::fmt;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
-use crate::measure::Measurement;
+use crate::measure::{Measure, Measurement};
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::Text;
@@ -43,15 +43,38 @@ pub enum VerticalAlign {
 
 /// A widget that aligns its content horizontally (and optionally vertically)
 /// within the available console space.
+///
+/// The content defaults to [`Text`] but may be any [`Renderable`], so a
+/// fit-to-content panel can be centered as easily as a line of text.
+/// Vertical alignment applies within [`height`](Self::height) when set, or
+/// otherwise within the height the parent imposes through
+/// [`ConsoleOptions::height`] (as a [`Layout`](crate::layout::Layout) region
+/// or a full-screen [`Live`](crate::live::Live) display does). Content
+/// taller than that height is cropped from the side opposite the alignment.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::align_widget::Align;
+/// use gilt::panel::Panel;
+/// use gilt::text::Text;
+///
+/// // Center a panel both ways within a 5-line, 20-column region.
+/// let splash = Align::middle(Panel::fit(Text::from("Hi"))).with_height(5);
+/// let output = format!("{:20}", splash);
+/// let lines: Vec<&str> = output.lines().collect();
+/// assert_eq!(lines.len(), 5);
+/// assert_eq!(lines[1].trim(), "╭────╮");
+/// ```
 #[derive(Debug, Clone)]
-pub struct Align {
+pub struct Align<R = Text> {
     /// The content to align.
-    pub content: Text,
+    pub content: R,
     /// Horizontal alignment.
     pub align: HorizontalAlign,
     /// Optional style for the padding whitespace.
     pub style: Option<Style>,
-    /// Optional vertical alignment (requires `height`).
+    /// Optional vertical alignment (within `height`, or the parent's height).
     pub vertical: Option<VerticalAlign>,
     /// Whether to pad lines on the right to fill available width.
     pub pad: bool,
@@ -61,10 +84,10 @@ pub struct Align {
     pub height: Option<usize>,
 }
 
-impl Align {
+impl<R: Renderable> Align<R> {
     /// Create a new `Align` widget.
     pub fn new(
-        content: Text,
+        content: R,
         align: HorizontalAlign,
         style: Option<Style>,
         vertical: Option<VerticalAlign>,
@@ -84,12 +107,12 @@ impl Align {
     }
 
     /// Left-align content.
-    pub fn left(content: Text) -> Self {
+    pub fn left(content: R) -> Self {
         Align::new(content, HorizontalAlign::Left, None, None, true, None, None)
     }
 
     /// Center content.
-    pub fn center(content: Text) -> Self {
+    pub fn center(content: R) -> Self {
         Align::new(
             content,
             HorizontalAlign::Center,
@@ -102,7 +125,7 @@ impl Align {
     }
 
     /// Right-align content.
-    pub fn right(content: Text) -> Self {
+    pub fn right(content: R) -> Self {
         Align::new(
             content,
             HorizontalAlign::Right,
@@ -114,22 +137,83 @@ impl Align {
         )
     }
 
+    /// Center content both horizontally and vertically.
+    ///
+    /// Without an explicit [`with_height`](Self::with_height), the content is
+    /// centered within the height provided by the parent, if any.
+    pub fn middle(content: R) -> Self {
+        Align::center(content).with_vertical(VerticalAlign::Middle)
+    }
+
+    /// Set the vertical alignment.
+    #[must_use]
+    pub fn with_vertical(mut self, vertical: VerticalAlign) -> Self {
+        self.vertical = Some(vertical);
+        self
+    }
+
+    /// Set the height to align within.
+    #[must_use]
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width to align within.
+    #[must_use]
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the style of the padding whitespace.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Set whether lines are padded on the right to fill the width.
+    #[must_use]
+    pub fn with_pad(mut self, pad: bool) -> Self {
+        self.pad = pad;
+        self
+    }
+
     /// Measure the minimum and maximum width requirements.
-    pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
-        let content_width = self.content.cell_len();
+    pub fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let lines = console.render_lines(&self.content, Some(options), None, false, false);
+        let content_width = lines
+            .iter()
+            .map(|line| Segment::get_line_length(line))
+            .max()
+            .unwrap_or(0);
         Measurement::new(content_width, options.max_width)
     }
 
-    /// Generate vertically-padded blank lines above or below content.
+    /// The height to align within: explicit, or the parent's when aligning
+    /// vertically.
+    fn target_height(&self, options: &ConsoleOptions) -> Option<usize> {
+        self.height.or_else(|| self.vertical.and(options.height))
+    }
+
+    /// Pad content with blank lines above or below (or crop it) to `height`.
     fn vertical_pad_lines(
         &self,
         lines: Vec<Vec<Segment>>,
         width: usize,
         height: usize,
     ) -> Vec<Vec<Segment>> {
+        let vertical = self.vertical.unwrap_or(VerticalAlign::Top);
         let content_height = lines.len();
         if content_height >= height {
-            return lines;
+            // Keep the part of the content nearest the alignment edge.
+            let skip = match vertical {
+                VerticalAlign::Top => 0,
+                VerticalAlign::Middle => (content_height - height) / 2,
+                VerticalAlign::Bottom => content_height - height,
+            };
+            return lines.into_iter().skip(skip).take(height).collect();
         }
 
         let pad_style = self.style.clone().unwrap_or_else(Style::null);
@@ -137,46 +221,28 @@ impl Align {
         let blank_line = vec![blank_segment];
 
         let excess = height - content_height;
-        match self.vertical.unwrap_or(VerticalAlign::Top) {
-            VerticalAlign::Top => {
-                let mut result = lines;
-                for _ in 0..excess {
-                    result.push(blank_line.clone());
-                }
-                result
-            }
-            VerticalAlign::Middle => {
-                let top = excess / 2;
-                let bottom = excess - top;
-                let mut result = Vec::with_capacity(height);
-                for _ in 0..top {
-                    result.push(blank_line.clone());
-                }
-                result.extend(lines);
-                for _ in 0..bottom {
-                    result.push(blank_line.clone());
-                }
-                result
-            }
-            VerticalAlign::Bottom => {
-                let mut result = Vec::with_capacity(height);
-                for _ in 0..excess {
-                    result.push(blank_line.clone());
-                }
-                result.extend(lines);
-                result
-            }
-        }
+        let top = match vertical {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => excess / 2,
+            VerticalAlign::Bottom => excess,
+        };
+        let mut result = Vec::with_capacity(height);
+        result.extend(std::iter::repeat_n(blank_line.clone(), top));
+        result.extend(lines);
+        result.extend(std::iter::repeat_n(blank_line, excess - top));
+        result
     }
 }
 
-impl Renderable for Align {
+impl<R: Renderable> Renderable for Align<R> {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let width = self.width.unwrap_or(options.max_width);
         let pad_style = self.style.clone().unwrap_or_else(Style::null);
+        let height = self.target_height(options);
 
         // Render content into lines
-        let render_opts = options.update_width(width);
+        let mut render_opts = options.update_width(width);
+        render_opts.height = None;
         let mut rendered_lines =
             console.render_lines(&self.content, Some(&render_opts), None, false, false);
 
@@ -210,8 +276,8 @@ impl Renderable for Align {
             }
         }
 
-        // Apply vertical alignment if height is specified
-        if let Some(height) = self.height {
+        // Apply vertical alignment if a height is known
+        if let Some(height) = height {
             rendered_lines = self.vertical_pad_lines(rendered_lines, width, height);
         }
 
@@ -220,7 +286,7 @@ impl Renderable for Align {
         let line_count = rendered_lines.len();
         for (i, line) in rendered_lines.into_iter().enumerate() {
             segments.extend(line);
-            if i + 1 < line_count || self.height.is_some() {
+            if i + 1 < line_count || height.is_some() {
                 segments.push(Segment::line());
             }
         }
@@ -235,11 +301,28 @@ impl Renderable for Align {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Measure
+// ---------------------------------------------------------------------------
+
+/// Measures content through its own [`Measure`] implementation rather than
+/// by rendering it, so wrapped content reports its natural (unwrapped) width.
+impl<R: Renderable + Measure> Measure for Align<R> {
+    fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let width = self
+            .width
+            .unwrap_or(options.max_width)
+            .min(options.max_width);
+        let content = self.content.measure(console, &options.update_width(width));
+        Measurement::new(content.maximum.min(width), width)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Display
 // ---------------------------------------------------------------------------
 
-impl fmt::Display for Align {
+impl<R: Renderable> fmt::Display for Align<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let w = f.width().unwrap_or(80);
         let mut console = Console::builder()
@@ -536,4 +619,88 @@ mod tests {
             assert!(seg.style.is_some());
         }
     }
+
+    // -- Vertical alignment within a height ---------------------------------
+
+    #[test]
+    fn test_middle_renderable_within_height() {
+        use crate::panel::Panel;
+
+        let align = Align::middle(Panel::fit(Text::new("Hi", Style::null()))).with_height(7);
+        let output = format!("{:12}", align);
+        let lines: Vec<&str> = output.split('\n').collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0].trim(), "");
+        assert_eq!(lines[2], "   ╭────╮   ");
+        assert_eq!(lines[3], "   │ Hi │   ");
+        assert_eq!(lines[4], "   ╰────╯   ");
+        assert_eq!(lines[6].trim(), "");
+    }
+
+    #[test]
+    fn test_vertical_uses_parent_height() {
+        let console = make_console(10);
+        let align = Align::left(Text::new("X", Style::null())).with_vertical(VerticalAlign::Bottom);
+        let mut opts = console.options();
+        opts.height = Some(4);
+        let output = segments_to_text(&align.gilt_console(&console, &opts));
+        let lines: Vec<&str> = output.trim_end_matches('\n').split('\n').collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].starts_with('X'));
+
+        // Without vertical alignment the parent height is ignored
+        let plain = Align::left(Text::new("X", Style::null()));
+        let output = segments_to_text(&plain.gilt_console(&console, &opts));
+        assert_eq!(output.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_vertical_crops_taller_content() {
+        let content = || Text::new("1\n2\n3\n4\n5", Style::null());
+        let first_line = |align: Align| -> String {
+            let output = format!("{:5}", align);
+            assert_eq!(output.lines().count(), 3);
+            output.lines().next().unwrap().trim().to_string()
+        };
+        assert_eq!(
+            first_line(
+                Align::left(content())
+                    .with_height(3)
+                    .with_vertical(VerticalAlign::Top)
+            ),
+            "1"
+        );
+        assert_eq!(first_line(Align::middle(content()).with_height(3)), "2");
+        assert_eq!(
+            first_line(
+                Align::left(content())
+                    .with_height(3)
+                    .with_vertical(VerticalAlign::Bottom)
+            ),
+            "3"
+        );
+    }
+
+    #[test]
+    fn test_builders() {
+        let align = Align::right(Text::from("X"))
+            .with_width(6)
+            .with_pad(false)
+            .with_style(Style::parse("on blue").unwrap());
+        assert_eq!(align.width, Some(6));
+        assert!(!align.pad);
+        assert!(align.style.is_some());
+        assert_eq!(format!("{:20}", align), "     X");
+    }
+
+    #[test]
+    fn test_measure_renderable() {
+        use crate::panel::Panel;
+
+        let console = make_console(40);
+        let align = Align::center(Panel::fit(Text::from("Hello")));
+        let m = align.measure(&console, &console.options());
+        assert_eq!(m.minimum, 9);
+        assert_eq!(m.maximum, 40);
+    }
 }
@@ -1,13 +1,18 @@
 //! ANSI escape code parsing and conversion to styled Text.
 //!
 //! This module parses ANSI escape codes from terminal output and converts them
-//! to styled `Text` objects. It is a port of Python's `rich/ansi.py`.
+//! to styled `Text` objects. It is a port of Python's `rich/ansi.py`, extended
+//! to cover what real child processes emit: 256-color and truecolor SGR in
+//! both the `;` and `:` forms, underline styles and colors, OSC 8 hyperlinks
+//! terminated by either ST or BEL, and cursor, erase and other control
+//! sequences, which are stripped so captured output can be placed inside a
+//! `Panel` or `Table` without corrupting its alignment.
 
 use regex::Regex;
 use std::sync::LazyLock;
 
 use crate::color::Color;
-use crate::style::Style;
+use crate::style::{Style, UnderlineStyle};
 use crate::text::Text;
 
 // ---------------------------------------------------------------------------
@@ -16,11 +21,12 @@ use crate::text::Text;
 
 /// Regex that matches ANSI escape sequences:
 /// - Single-char C0/C1 sequences: `\x1b[0-?]`
-/// - OSC sequences: `\x1b](.*?)\x1b\\`
+/// - OSC sequences: `\x1b](.*?)` terminated by ST (`\x1b\\`) or BEL (`\x07`)
+/// - DCS/SOS/PM/APC strings: `\x1b[PX^_].*?\x1b\\`
 /// - CSI/Fe sequences: `\x1b([(@-Z\\-_]|\[[0-?]*[ -/]*[@-~])`
 static RE_ANSI: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
-        r"(?:\x1b[0-?])|(?:\x1b\](.*?)\x1b\\)|(?:\x1b([(@\x2d-Z\\\x2d_]|\[[0-?]*[ -/]*[@-~]))",
+        r"(?:\x1b[0-?])|(?:\x1b\](.*?)(?:\x1b\\|\x07))|(?:\x1b[PX^_].*?\x1b\\)|(?:\x1b([(@\x2d-Z\\\x2d_]|\[[0-?]*[ -/]*[@-~]))",
     )
     .expect("ANSI regex must compile")
 });
@@ -117,7 +123,8 @@ struct AnsiToken {
 /// - Plain text segments between escape sequences
 /// - SGR codes (CSI sequences ending in `m`)
 /// - OSC sequences (Operating System Commands)
-/// - Character set designation sequences (`(`) are skipped
+/// - Character set designation sequences (`(`) and single shifts (`N`, `O`)
+///   are skipped along with the character they apply to
 fn ansi_tokenize(ansi_text: &str) -> Vec<AnsiToken> {
     let mut tokens = Vec::new();
     let mut position = 0;
@@ -141,9 +148,10 @@ fn ansi_tokenize(ansi_text: &str) -> Vec<AnsiToken> {
         }
 
         if let Some(ref sgr_val) = sgr {
-            if sgr_val == "(" {
-                // Character set designation: skip the next byte too
-                position = end + 1;
+            if matches!(sgr_val.as_str(), "(" | "N" | "O") {
+                // Character set designation or single shift (such as SS3
+                // `ESC O A` from cursor keys): skip the following character
+                position = end + ansi_text[end..].chars().next().map_or(0, char::len_utf8);
                 continue;
             }
             if sgr_val.ends_with('m') {
@@ -186,6 +194,14 @@ fn ansi_tokenize(ansi_text: &str) -> Vec<AnsiToken> {
     tokens
 }
 
+/// Remove ANSI escape sequences from `text`, keeping only the plain text.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    ansi_tokenize(text)
+        .into_iter()
+        .map(|token| token.plain)
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // AnsiDecoder
 // ---------------------------------------------------------------------------
@@ -217,7 +233,21 @@ impl AnsiDecoder {
     /// - Carriage returns (keeps only text after the last `\r`)
     /// - SGR codes for text attributes and colors
     /// - OSC 8 hyperlink sequences
-    /// - 256-color and truecolor foreground/background
+    /// - 256-color and truecolor foreground/background/underline colors
+    ///
+    /// Any other escape sequence (cursor movement, erase, window titles,
+    /// private modes) is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::utils::ansi::AnsiDecoder;
+    ///
+    /// let mut decoder = AnsiDecoder::new();
+    /// let text = decoder.decode_line("\x1b[2K\x1b[38;2;255;0;0mred\x1b[0m ok");
+    /// assert_eq!(text.plain(), "red ok");
+    /// assert_eq!(text.spans()[0].style.to_string(), "#ff0000");
+    /// ```
     pub fn decode_line(&mut self, line: &str) -> Text {
         let mut text = Text::new("", Style::null());
 
@@ -249,75 +279,130 @@ impl AnsiDecoder {
                     }
                 }
             } else if let Some(ref sgr) = token.sgr {
-                // Parse SGR codes
-                let codes: Vec<u8> = sgr
-                    .split(';')
-                    .filter_map(|part| {
-                        if part.is_empty() {
-                            Some(0)
-                        } else if part.chars().all(|c| c.is_ascii_digit()) {
-                            part.parse::<u16>().ok().map(|v| v.min(255) as u8)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+                self.apply_sgr(sgr);
+            }
+        }
 
-                let mut iter = codes.iter().copied();
-                while let Some(code) = iter.next() {
-                    if code == 0 {
-                        self.style = Style::null();
-                    } else if let Some(style_str) = sgr_style(code) {
-                        if let Ok(parsed) = Style::parse(style_str) {
-                            self.style = self.style.clone() + parsed;
-                        }
-                    } else if code == 38 {
-                        // Foreground color
-                        if let Some(color_type) = iter.next() {
-                            if color_type == 5 {
-                                if let Some(n) = iter.next() {
-                                    let color = Color::from_ansi(n);
-                                    self.style =
-                                        self.style.clone() + Style::from_color(Some(color), None);
-                                }
-                            } else if color_type == 2 {
-                                let r = iter.next();
-                                let g = iter.next();
-                                let b = iter.next();
-                                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
-                                    let color = Color::from_rgb(r, g, b);
-                                    self.style =
-                                        self.style.clone() + Style::from_color(Some(color), None);
-                                }
-                            }
-                        }
-                    } else if code == 48 {
-                        // Background color
-                        if let Some(color_type) = iter.next() {
-                            if color_type == 5 {
-                                if let Some(n) = iter.next() {
-                                    let color = Color::from_ansi(n);
-                                    self.style =
-                                        self.style.clone() + Style::from_color(None, Some(color));
-                                }
-                            } else if color_type == 2 {
-                                let r = iter.next();
-                                let g = iter.next();
-                                let b = iter.next();
-                                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
-                                    let color = Color::from_rgb(r, g, b);
-                                    self.style =
-                                        self.style.clone() + Style::from_color(None, Some(color));
-                                }
-                            }
+        text
+    }
+}
+
+impl AnsiDecoder {
+    /// Applies the parameters of one SGR sequence (the text between `ESC [`
+    /// and `m`) to the current style.
+    ///
+    /// Parameters are separated by `;`; a parameter may carry `:`-separated
+    /// sub-parameters (`4:3` for a curly underline, `38:2::255:0:0` for a
+    /// truecolor foreground). Unknown or malformed codes are ignored.
+    fn apply_sgr(&mut self, sgr: &str) {
+        let mut params = sgr.split(';');
+        while let Some(param) = params.next() {
+            if param.contains(':') {
+                let sub: Vec<u16> = param
+                    .split(':')
+                    .map(|part| part.parse().unwrap_or(0))
+                    .collect();
+                match sub[0] {
+                    4 => self.set_underline(sub[1]),
+                    code @ (38 | 48 | 58) => {
+                        // ISO 8613-6 form: `38:2:<colorspace>:r:g:b`; the
+                        // colorspace id is commonly omitted.
+                        let values = match sub.get(1) {
+                            Some(2) if sub.len() >= 6 => &sub[3..6],
+                            _ => &sub[2.min(sub.len())..],
+                        };
+                        if let Some(color) = sub.get(1).and_then(|&k| extended_color(k, values)) {
+                            self.set_extended_color(code, color);
                         }
                     }
-                    // Unknown codes are silently ignored
+                    code => self.apply_code(code),
                 }
+                continue;
+            }
+
+            let code = if param.is_empty() {
+                0
+            } else {
+                match param.parse::<u16>() {
+                    Ok(code) => code,
+                    Err(_) => continue,
+                }
+            };
+            if matches!(code, 38 | 48 | 58) {
+                let kind = params.next().and_then(|p| p.parse::<u16>().ok());
+                let count = match kind {
+                    Some(5) => 1,
+                    Some(2) => 3,
+                    _ => continue,
+                };
+                let values: Vec<u16> = params
+                    .by_ref()
+                    .take(count)
+                    .filter_map(|p| p.parse().ok())
+                    .collect();
+                if let Some(color) = kind.and_then(|k| extended_color(k, &values)) {
+                    self.set_extended_color(code, color);
+                }
+            } else {
+                self.apply_code(code);
             }
         }
+    }
 
-        text
+    /// Applies a single SGR code without sub-parameters.
+    fn apply_code(&mut self, code: u16) {
+        match code {
+            // A reset ends the current attributes but not an open hyperlink,
+            // which only OSC 8 closes.
+            0 => self.style = Style::null().update_link(self.style.link()),
+            24 => self.set_underline(0),
+            59 => self.style.set_underline_color(None),
+            _ => {
+                let style_str = u8::try_from(code).ok().and_then(sgr_style);
+                if let Some(Ok(parsed)) = style_str.map(Style::parse) {
+                    self.style = self.style.clone() + parsed;
+                }
+            }
+        }
+    }
+
+    /// Applies an underline style from `4:<n>` (or `24` for `n == 0`).
+    ///
+    /// Single and double underlines map to the `underline` and `underline2`
+    /// attributes; curly, dotted and dashed become an [`UnderlineStyle`].
+    fn set_underline(&mut self, kind: u16) {
+        let (attributes, underline_style) = match kind {
+            0 => ("not underline not underline2", None),
+            2 => ("underline2", None),
+            3 => ("", Some(UnderlineStyle::Curly)),
+            4 => ("", Some(UnderlineStyle::Dotted)),
+            5 => ("", Some(UnderlineStyle::Dashed)),
+            _ => ("underline", None),
+        };
+        if let Ok(parsed) = Style::parse(attributes) {
+            self.style = self.style.clone() + parsed;
+        }
+        self.style.set_underline_style(underline_style);
+    }
+
+    /// Sets the foreground (38), background (48) or underline (58) color.
+    fn set_extended_color(&mut self, code: u16, color: Color) {
+        match code {
+            38 => self.style = self.style.clone() + Style::from_color(Some(color), None),
+            48 => self.style = self.style.clone() + Style::from_color(None, Some(color)),
+            _ => self.style.set_underline_color(Some(color)),
+        }
+    }
+}
+
+/// Builds a color from an extended color specification: `5` with a palette
+/// index, or `2` with red, green and blue components.
+fn extended_color(kind: u16, values: &[u16]) -> Option<Color> {
+    let byte = |i: usize| values.get(i).and_then(|&v| u8::try_from(v).ok());
+    match kind {
+        5 => byte(0).map(Color::from_ansi),
+        2 => Some(Color::from_rgb(byte(0)?, byte(1)?, byte(2)?)),
+        _ => None,
     }
 }
 
@@ -334,6 +419,8 @@ impl Default for AnsiDecoder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::color::ColorSystem;
+    use crate::color_triplet::ColorTriplet;
 
     // -- SGR_STYLE_MAP tests ------------------------------------------------
 
@@ -750,4 +837,106 @@ mod tests {
         let text = decoder.decode_line("First\rSecond\rThird");
         assert_eq!(text.plain(), "Third");
     }
+
+    #[test]
+    fn test_decode_line_colon_truecolor_and_256() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder.decode_line("\x1b[38:2::10:20:30mA\x1b[38:2:1:2:3;48:5:200mB");
+        assert_eq!(text.plain(), "AB");
+        let a = text.spans()[0].style.color().unwrap();
+        assert_eq!(a.get_truecolor(None, true), ColorTriplet::new(10, 20, 30));
+        let b = &text.spans()[1].style;
+        assert_eq!(
+            b.color().unwrap().get_truecolor(None, true),
+            ColorTriplet::new(1, 2, 3)
+        );
+        assert_eq!(b.bgcolor().unwrap().number, Some(200));
+    }
+
+    #[test]
+    fn test_decode_line_out_of_range_color_ignored() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder.decode_line("\x1b[38;5;300;1mX");
+        assert!(text.spans()[0].style.color().is_none());
+        assert_eq!(text.spans()[0].style.bold(), Some(true));
+    }
+
+    #[test]
+    fn test_decode_line_underline_styles_and_color() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder.decode_line("\x1b[4:3;58;2;255;0;0mwavy\x1b[24;59mplain");
+        let style = &text.spans()[0].style;
+        assert_eq!(style.underline_style(), Some(UnderlineStyle::Curly));
+        assert_eq!(
+            style.underline_color().unwrap().get_truecolor(None, true),
+            ColorTriplet::new(255, 0, 0)
+        );
+        let plain = &text.spans()[1].style;
+        assert_eq!(
+            (plain.underline_style(), plain.underline_color()),
+            (None, None)
+        );
+
+        let text = decoder.decode_line("\x1b[4:2mdouble\x1b[4:0mnone");
+        assert_eq!(text.spans()[0].style.underline2(), Some(true));
+        assert_eq!(text.spans()[1].style.underline2(), Some(false));
+        assert_eq!(text.spans()[1].style.underline(), Some(false));
+    }
+
+    #[test]
+    fn test_decode_line_strips_cursor_and_erase_codes() {
+        let mut decoder = AnsiDecoder::new();
+        let line = "\x1b[2K\x1b[1G\x1b[?25l\x1b[3A\x1b7text\x1b8\x1b[0K\x1b(B!";
+        assert_eq!(decoder.decode_line(line).plain(), "text!");
+    }
+
+    #[test]
+    fn test_decode_line_strips_single_shifts() {
+        let mut decoder = AnsiDecoder::new();
+        assert_eq!(
+            decoder.decode_line("up\x1bOAdown\x1bNx!").plain(),
+            "updown!"
+        );
+    }
+
+    #[test]
+    fn test_decode_line_strips_osc_and_string_sequences() {
+        let mut decoder = AnsiDecoder::new();
+        let line = "\x1b]0;window title\x07a\x1bPq#0;1\x1b\\b\x1b_apc\x1b\\c";
+        let text = decoder.decode_line(line);
+        assert_eq!(text.plain(), "abc");
+        assert!(text.spans().is_empty());
+    }
+
+    #[test]
+    fn test_decode_line_osc8_bel_terminated_survives_reset() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder
+            .decode_line("\x1b]8;id=1;https://gilt.dev\x07\x1b[1mgi\x1b[0mlt\x1b]8;;\x07 after");
+        assert_eq!(text.plain(), "gilt after");
+        let spans = text.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!((spans[0].start, spans[0].end), (0, 2));
+        assert_eq!(spans[0].style.bold(), Some(true));
+        assert_eq!(spans[1].style.link(), Some("https://gilt.dev"));
+        assert_eq!((spans[1].start, spans[1].end), (2, 4));
+    }
+
+    #[test]
+    fn test_decode_round_trips_rendered_styles() {
+        let style = Style::parse("bold italic #123456 on color(42)").unwrap();
+        let rendered = style.render("styled", Some(ColorSystem::TrueColor));
+        let text = AnsiDecoder::new().decode_line(&rendered);
+        assert_eq!(text.plain(), "styled");
+        assert_eq!(text.spans()[0].style, style);
+    }
+
+    #[test]
+    fn test_text_from_ansi_multiline() {
+        let text = Text::from_ansi("\x1b[32mone\r\ntwo\x1b[0m\nthree\n");
+        assert_eq!(text.plain(), "one\ntwo\nthree");
+        assert_eq!(text.spans().len(), 2);
+        assert_eq!(text.spans()[1].start, 4);
+        assert_eq!(text.spans()[1].end, 7);
+    }
 }
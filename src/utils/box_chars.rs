@@ -1,9 +1,15 @@
 //! Box-drawing character sets for tables.
 //!
 //! Port of Python `rich/box.py`. Defines 19 built-in box styles for rendering
-//! table borders and separators.
+//! table borders and separators. Custom sets can be parsed with
+//! [`BoxChars::from_str`](std::str::FromStr) and [`register`]ed under a name,
+//! which [`get`] (and the derive macros' `box_style` attribute) look up.
 
-use std::sync::LazyLock;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+
+use crate::error::BoxCharsError;
 
 /// Which level of row separator to render.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -32,7 +38,7 @@ pub enum RowLevel {
 /// Line 7 (foot):     foot_left, _, foot_vertical, foot_right
 /// Line 8 (bottom):   bottom_left, bottom_char, bottom_divider, bottom_right
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoxChars {
     /// Top-left corner character (e.g. `┌`).
     pub top_left: char,
@@ -117,7 +123,7 @@ impl BoxChars {
         let lines: Vec<&str> = box_str.split('\n').collect();
         assert_eq!(lines.len(), 8, "Box string must have exactly 8 lines");
 
-        let parse_line = |line: &str| -> Vec<char> {
+        let parse_line = |line: &str| -> [char; 4] {
             let chars: Vec<char> = line.chars().collect();
             assert_eq!(
                 chars.len(),
@@ -126,17 +132,19 @@ impl BoxChars {
                 chars.len(),
                 line,
             );
-            chars
+            [chars[0], chars[1], chars[2], chars[3]]
         };
 
-        let top = parse_line(lines[0]);
-        let head = parse_line(lines[1]);
-        let head_row = parse_line(lines[2]);
-        let mid = parse_line(lines[3]);
-        let row = parse_line(lines[4]);
-        let foot_row = parse_line(lines[5]);
-        let foot = parse_line(lines[6]);
-        let bottom = parse_line(lines[7]);
+        let mut rows = [[' '; 4]; 8];
+        for (row, line) in rows.iter_mut().zip(&lines) {
+            *row = parse_line(line);
+        }
+        Self::from_rows(rows, ascii)
+    }
+
+    /// Build a `BoxChars` from the eight 4-character rows of a definition.
+    fn from_rows(rows: [[char; 4]; 8], ascii: bool) -> Self {
+        let [top, head, head_row, mid, row, foot_row, foot, bottom] = rows;
 
         Self {
             top_left: top[0],
@@ -180,6 +188,69 @@ impl BoxChars {
         }
     }
 
+    /// Parse a box definition, the fallible counterpart of
+    /// [`new`](Self::new); also available as [`str::parse`].
+    ///
+    /// Accepts the 8-line layout documented on [`BoxChars`], as used by
+    /// rich, or a compact 4-line layout for boxes whose header, body and
+    /// footer look alike:
+    ///
+    /// ```text
+    /// Line 1 (top):       top_left, top, top_divider, top_right
+    /// Line 2 (cells):     left, _, vertical, right
+    /// Line 3 (separator): left, horizontal, cross, right
+    /// Line 4 (bottom):    bottom_left, bottom_char, bottom_divider, bottom_right
+    /// ```
+    ///
+    /// A single leading and trailing newline are ignored, so definitions can
+    /// be written as multi-line string literals. The box is marked ASCII when
+    /// every character is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BoxCharsError`] if the definition does not have 4 or 8
+    /// lines of exactly 4 characters each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::box_chars::BoxChars;
+    ///
+    /// let dotted: BoxChars = "·┈┬·\n┊ ┊┊\n├┈┼┤\n·┈┴·".parse().unwrap();
+    /// assert_eq!(dotted.get_top(&[3, 2]), "·┈┈┈┬┈┈·");
+    /// assert_eq!(dotted.row_horizontal, '┈');
+    /// assert!("too\nshort".parse::<BoxChars>().is_err());
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self, BoxCharsError> {
+        let spec = spec.strip_prefix('\n').unwrap_or(spec);
+        let spec = spec.strip_suffix('\n').unwrap_or(spec);
+        let lines: Vec<&str> = spec.split('\n').collect();
+        if lines.len() != 4 && lines.len() != 8 {
+            return Err(BoxCharsError::LineCount(lines.len()));
+        }
+        let mut parsed = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            match chars[..] {
+                [a, b, c, d] => parsed.push([a, b, c, d]),
+                _ => {
+                    return Err(BoxCharsError::LineLength {
+                        line: index + 1,
+                        chars: chars.len(),
+                    })
+                }
+            }
+        }
+        let rows = match parsed[..] {
+            [top, cells, separator, bottom] => [
+                top, cells, separator, cells, separator, separator, cells, bottom,
+            ],
+            [a, b, c, d, e, f, g, h] => [a, b, c, d, e, f, g, h],
+            _ => unreachable!("line count checked above"),
+        };
+        Ok(Self::from_rows(rows, spec.is_ascii()))
+    }
+
     /// Build the top border string for columns of given widths.
     ///
     /// Example for widths `[5, 3]` with SQUARE box:
@@ -249,6 +320,58 @@ impl BoxChars {
         s
     }
 
+    /// Pick the character where a separator line of the given `level` meets
+    /// the column dividers, for tables whose cells span columns or rows.
+    ///
+    /// `up` and `down` say whether a divider continues above and below the
+    /// line, `left` and `right` whether the line itself continues on either
+    /// side. With all four set this is the level's cross character.
+    pub fn get_junction(
+        &self,
+        level: RowLevel,
+        up: bool,
+        down: bool,
+        left: bool,
+        right: bool,
+    ) -> char {
+        let (row_left, horizontal, cross, row_right) = match level {
+            RowLevel::Head => (
+                self.head_row_left,
+                self.head_row_horizontal,
+                self.head_row_cross,
+                self.head_row_right,
+            ),
+            RowLevel::Row => (
+                self.row_left,
+                self.row_horizontal,
+                self.row_cross,
+                self.row_right,
+            ),
+            RowLevel::Foot => (
+                self.foot_row_left,
+                self.foot_row_horizontal,
+                self.foot_row_cross,
+                self.foot_row_right,
+            ),
+            RowLevel::Mid => (
+                self.mid_left,
+                self.row_horizontal,
+                self.mid_vertical,
+                self.mid_right,
+            ),
+        };
+        match (up, down, left, right) {
+            (false, false, _, _) => horizontal,
+            (_, _, false, false) => self.mid_vertical,
+            (true, true, true, true) => cross,
+            (true, true, true, false) => row_right,
+            (true, true, false, true) => row_left,
+            _ if level == RowLevel::Mid => cross,
+            (false, true, _, _) => self.top_divider,
+            (true, false, _, _) => self.bottom_divider,
+        }
+    }
+
     /// Build the bottom border string for columns of given widths.
     ///
     /// Example for widths `[5, 3]` with SQUARE box:
@@ -340,6 +463,100 @@ impl BoxChars {
     }
 }
 
+impl FromStr for BoxChars {
+    type Err = BoxCharsError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        BoxChars::parse(spec)
+    }
+}
+
+// ──────────────────────────────────────────────────────────
+// Named boxes
+// ──────────────────────────────────────────────────────────
+
+/// Boxes added at runtime with [`register`].
+#[derive(Default)]
+struct Registry {
+    /// Registered boxes by name.
+    names: HashMap<String, &'static BoxChars>,
+    /// Every box allocated by [`register`], each distinct box once.
+    boxes: Vec<&'static BoxChars>,
+}
+
+static REGISTERED: LazyLock<RwLock<Registry>> = LazyLock::new(|| RwLock::new(Registry::default()));
+
+/// The built-in boxes by the names of their statics.
+fn builtin(name: &str) -> Option<&'static BoxChars> {
+    let chars: &'static LazyLock<BoxChars> = match name {
+        "ASCII" => &ASCII,
+        "ASCII2" => &ASCII2,
+        "ASCII_DOUBLE_HEAD" => &ASCII_DOUBLE_HEAD,
+        "SQUARE" => &SQUARE,
+        "SQUARE_DOUBLE_HEAD" => &SQUARE_DOUBLE_HEAD,
+        "MINIMAL" => &MINIMAL,
+        "MINIMAL_HEAVY_HEAD" => &MINIMAL_HEAVY_HEAD,
+        "MINIMAL_DOUBLE_HEAD" => &MINIMAL_DOUBLE_HEAD,
+        "SIMPLE" => &SIMPLE,
+        "SIMPLE_HEAD" => &SIMPLE_HEAD,
+        "SIMPLE_HEAVY" => &SIMPLE_HEAVY,
+        "HORIZONTALS" => &HORIZONTALS,
+        "ROUNDED" => &ROUNDED,
+        "HEAVY" => &HEAVY,
+        "HEAVY_EDGE" => &HEAVY_EDGE,
+        "HEAVY_HEAD" => &HEAVY_HEAD,
+        "DOUBLE" => &DOUBLE,
+        "DOUBLE_EDGE" => &DOUBLE_EDGE,
+        "MARKDOWN" => &MARKDOWN,
+        _ => return None,
+    };
+    Some(chars)
+}
+
+/// Register a box under `name`, replacing any earlier box of that name
+/// (including a built-in one), and return the registered box.
+///
+/// Registered boxes live for the rest of the program, so they can be used
+/// wherever a `&'static BoxChars` is expected. Each distinct box is
+/// allocated once: registering an equal box again, under any name, reuses
+/// the earlier allocation, so the memory held is bounded by the number of
+/// different boxes registered.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::box_chars::{self, BoxChars};
+///
+/// let dotted: BoxChars = "·┈┬·\n┊ ┊┊\n├┈┼┤\n·┈┴·".parse().unwrap();
+/// box_chars::register("DOTTED", dotted);
+/// assert_eq!(box_chars::get("DOTTED").unwrap().top_left, '·');
+/// ```
+pub fn register(name: &str, chars: BoxChars) -> &'static BoxChars {
+    let mut registry = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    let chars = match registry.boxes.iter().find(|&&existing| *existing == chars) {
+        Some(&existing) => existing,
+        None => {
+            let leaked: &'static BoxChars = Box::leak(Box::new(chars));
+            registry.boxes.push(leaked);
+            leaked
+        }
+    };
+    registry.names.insert(name.to_string(), chars);
+    chars
+}
+
+/// Look up a box by name: registered boxes first, then the built-ins by
+/// the names of their statics (`"ROUNDED"`, `"HEAVY_HEAD"`, ...).
+pub fn get(name: &str) -> Option<&'static BoxChars> {
+    let registered = REGISTERED
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .names
+        .get(name)
+        .copied();
+    registered.or_else(|| builtin(name))
+}
+
 // ──────────────────────────────────────────────────────────
 // Box constant definitions
 // ──────────────────────────────────────────────────────────
@@ -525,6 +742,66 @@ mod tests {
         let _ = &*MARKDOWN;
     }
 
+    #[test]
+    fn test_parse_eight_lines_matches_new() {
+        let spec = "╭─┬╮\n│ ││\n├─┼┤\n│ ││\n├─┼┤\n├─┼┤\n│ ││\n╰─┴╯";
+        let parsed: BoxChars = format!("\n{spec}\n").parse().unwrap();
+        let b = &*ROUNDED;
+        assert_eq!(parsed.get_top(&[2, 1]), b.get_top(&[2, 1]));
+        assert_eq!(
+            parsed.get_row(&[2, 1], RowLevel::Head, true),
+            b.get_row(&[2, 1], RowLevel::Head, true)
+        );
+        assert_eq!(parsed.get_bottom(&[2, 1]), b.get_bottom(&[2, 1]));
+        assert!(!parsed.ascii);
+    }
+
+    #[test]
+    fn test_parse_four_lines() {
+        let b = BoxChars::parse("+=++\n! !!\n+-++\n+=++").unwrap();
+        assert_eq!(b.head_left, '!');
+        assert_eq!(b.foot_vertical, '!');
+        assert_eq!(b.head_row_horizontal, '-');
+        assert_eq!(b.foot_row_cross, '+');
+        assert_eq!(b.bottom_char, '=');
+        assert!(b.ascii);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            BoxChars::parse("+--+\n|  |\n+--+").unwrap_err(),
+            BoxCharsError::LineCount(3)
+        );
+        assert_eq!(
+            BoxChars::parse("+--+\n| |\n+--+\n+--+").unwrap_err(),
+            BoxCharsError::LineLength { line: 2, chars: 3 }
+        );
+    }
+
+    #[test]
+    fn test_get_builtin_and_registered() {
+        assert_eq!(get("HEAVY").unwrap().top_left, '┏');
+        assert!(get("NO_SUCH_BOX").is_none());
+
+        let custom = register(
+            "TEST_BOX_CHARS_CUSTOM",
+            BoxChars::parse("abcd\nefgh\nijkl\nmnop").unwrap(),
+        );
+        assert!(std::ptr::eq(get("TEST_BOX_CHARS_CUSTOM").unwrap(), custom));
+        assert_eq!(custom.bottom_right, 'p');
+    }
+
+    #[test]
+    fn test_register_reuses_equal_boxes() {
+        let spec = "qrst\nuvwx\nyz01\n2345";
+        let first = register("TEST_BOX_CHARS_REUSE_A", BoxChars::parse(spec).unwrap());
+        let again = register("TEST_BOX_CHARS_REUSE_A", BoxChars::parse(spec).unwrap());
+        let other = register("TEST_BOX_CHARS_REUSE_B", BoxChars::parse(spec).unwrap());
+        assert!(std::ptr::eq(first, again));
+        assert!(std::ptr::eq(first, other));
+    }
+
     #[test]
     fn test_ascii_flag() {
         assert!(ASCII.ascii);
@@ -0,0 +1,514 @@
+//! Terminal capability detection.
+//!
+//! [`Capabilities::detect`] gathers what can be learned about the terminal
+//! without writing to it: `COLORTERM`, the compiled terminfo entry for
+//! `TERM`, the terminal program (iTerm2, Kitty, WezTerm, ...) and, on
+//! Windows, whether virtual terminal processing could be enabled for the
+//! console. A [`Console`](crate::console::Console) carries the result as
+//! [`Console::capabilities`](crate::console::Console::capabilities), so
+//! widgets can branch on features such as hyperlinks or inline images.
+//!
+//! # Examples
+//!
+//! ```
+//! use gilt::capabilities::Capabilities;
+//!
+//! let caps = Capabilities::from_env(|name| match name {
+//!     "TERM" => Some("xterm-kitty".to_string()),
+//!     _ => None,
+//! });
+//! assert!(caps.kitty_graphics);
+//! assert!(caps.hyperlinks);
+//! ```
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::color::ColorSystem;
+
+/// A terminal emulator recognised from its environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminalProgram {
+    /// Apple Terminal (`TERM_PROGRAM=Apple_Terminal`).
+    AppleTerminal,
+    /// iTerm2 (`TERM_PROGRAM=iTerm.app` or `ITERM_SESSION_ID`).
+    ITerm2,
+    /// Kitty (`TERM=xterm-kitty` or `KITTY_WINDOW_ID`).
+    Kitty,
+    /// WezTerm (`TERM_PROGRAM=WezTerm` or `WEZTERM_PANE`).
+    WezTerm,
+    /// Ghostty (`TERM_PROGRAM=ghostty` or `TERM=xterm-ghostty`).
+    Ghostty,
+    /// Alacritty (`TERM=alacritty` or `ALACRITTY_WINDOW_ID`).
+    Alacritty,
+    /// foot (`TERM=foot` or `foot-extra`).
+    Foot,
+    /// Konsole (`KONSOLE_VERSION`).
+    Konsole,
+    /// A VTE-based terminal such as GNOME Terminal (`VTE_VERSION`).
+    Vte,
+    /// Windows Terminal (`WT_SESSION`).
+    WindowsTerminal,
+    /// The Visual Studio Code integrated terminal (`TERM_PROGRAM=vscode`).
+    VsCode,
+}
+
+/// What the terminal is known to support.
+///
+/// Fields are conservative: a feature is `false` unless the terminal was
+/// recognised as supporting it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Capabilities {
+    /// The richest color system the terminal supports, or `None` for a
+    /// monochrome or unknown terminal.
+    pub color_system: Option<ColorSystem>,
+    /// The terminal program, if recognised.
+    pub program: Option<TerminalProgram>,
+    /// Whether ANSI escape sequences are interpreted. On Windows this is
+    /// whether virtual terminal processing is enabled for the console.
+    pub vt_processing: bool,
+    /// Whether OSC 8 hyperlinks are shown as links.
+    pub hyperlinks: bool,
+    /// Whether sixel images are drawn.
+    pub sixel: bool,
+    /// Whether the Kitty graphics protocol is supported.
+    pub kitty_graphics: bool,
+    /// Whether iTerm2 inline images (OSC 1337) are supported.
+    pub iterm_images: bool,
+}
+
+impl Capabilities {
+    /// Detect the capabilities of the terminal this process runs in.
+    ///
+    /// Detection runs once per process; later calls return the same result.
+    /// On Windows, this enables virtual terminal processing for the console
+    /// when it is available.
+    pub fn detect() -> Capabilities {
+        static DETECTED: OnceLock<Capabilities> = OnceLock::new();
+        DETECTED
+            .get_or_init(|| {
+                #[allow(unused_mut)]
+                let mut caps = Capabilities::from_env(|name| std::env::var(name).ok());
+                #[cfg(windows)]
+                if std::env::var_os("TERM").is_none() {
+                    caps.vt_processing = enable_vt_processing();
+                    caps.color_system = Some(if caps.vt_processing {
+                        ColorSystem::TrueColor
+                    } else {
+                        ColorSystem::Windows
+                    });
+                }
+                caps
+            })
+            .clone()
+    }
+
+    /// Work out capabilities from environment variables read with `var`,
+    /// consulting the terminfo database for `TERM`.
+    ///
+    /// The terminfo search path follows ncurses: `TERMINFO`, `~/.terminfo`,
+    /// `TERMINFO_DIRS`, then the system directories.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Capabilities {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        let term = var("TERM");
+        let program = detect_program(&var, term.as_deref());
+
+        let colorterm_truecolor = var("COLORTERM")
+            .is_some_and(|value| value.eq_ignore_ascii_case("truecolor") || value == "24bit");
+        // Every recognised program but Apple Terminal draws 24-bit color.
+        let program_truecolor =
+            program.is_some_and(|program| program != TerminalProgram::AppleTerminal);
+        let color_system = if matches!(term.as_deref(), Some("dumb")) {
+            None
+        } else if colorterm_truecolor || program_truecolor {
+            Some(ColorSystem::TrueColor)
+        } else if let Some(term) = &term {
+            terminfo_color_system(term, &terminfo_dirs(&var))
+                .unwrap_or_else(|| color_system_from_name(term, program))
+        } else {
+            None
+        };
+
+        let is = |candidates: &[TerminalProgram]| program.is_some_and(|p| candidates.contains(&p));
+        let vte_version: u32 = var("VTE_VERSION").and_then(|v| v.parse().ok()).unwrap_or(0);
+        use TerminalProgram::*;
+        Capabilities {
+            color_system,
+            program,
+            vt_processing: term.as_deref().is_some_and(|term| term != "dumb"),
+            hyperlinks: is(&[
+                ITerm2,
+                Kitty,
+                WezTerm,
+                Ghostty,
+                Alacritty,
+                Foot,
+                Konsole,
+                WindowsTerminal,
+                VsCode,
+            ]) || (program == Some(Vte) && vte_version >= 5000),
+            sixel: is(&[ITerm2, WezTerm, Foot, Konsole])
+                || term
+                    .as_deref()
+                    .is_some_and(|term| term.starts_with("mlterm")),
+            kitty_graphics: is(&[Kitty, WezTerm, Ghostty]),
+            iterm_images: is(&[ITerm2, WezTerm]),
+        }
+    }
+}
+
+/// Recognise the terminal program from its environment variables.
+fn detect_program(
+    var: &impl Fn(&str) -> Option<String>,
+    term: Option<&str>,
+) -> Option<TerminalProgram> {
+    use TerminalProgram::*;
+    let by_program = var("TERM_PROGRAM").and_then(|program| match program.as_str() {
+        "iTerm.app" => Some(ITerm2),
+        "Apple_Terminal" => Some(AppleTerminal),
+        "WezTerm" => Some(WezTerm),
+        "ghostty" => Some(Ghostty),
+        "vscode" => Some(VsCode),
+        _ => None,
+    });
+    if by_program.is_some() {
+        return by_program;
+    }
+    let by_term = term.and_then(|term| match term {
+        "xterm-kitty" => Some(Kitty),
+        "xterm-ghostty" => Some(Ghostty),
+        "wezterm" => Some(WezTerm),
+        "alacritty" => Some(Alacritty),
+        "foot" | "foot-extra" => Some(Foot),
+        _ => None,
+    });
+    if by_term.is_some() {
+        return by_term;
+    }
+    [
+        ("ITERM_SESSION_ID", ITerm2),
+        ("KITTY_WINDOW_ID", Kitty),
+        ("WEZTERM_PANE", WezTerm),
+        ("ALACRITTY_WINDOW_ID", Alacritty),
+        ("KONSOLE_VERSION", Konsole),
+        ("WT_SESSION", WindowsTerminal),
+        ("VTE_VERSION", Vte),
+    ]
+    .into_iter()
+    .find(|(name, _)| var(name).is_some())
+    .map(|(_, program)| program)
+}
+
+/// Guess the color system from the terminal name when it has no terminfo
+/// entry.
+fn color_system_from_name(term: &str, program: Option<TerminalProgram>) -> Option<ColorSystem> {
+    if term.ends_with("-direct") || term.contains("truecolor") {
+        Some(ColorSystem::TrueColor)
+    } else if term.contains("256color") || program == Some(TerminalProgram::AppleTerminal) {
+        Some(ColorSystem::EightBit)
+    } else {
+        Some(ColorSystem::Standard)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// terminfo
+// ---------------------------------------------------------------------------
+
+/// The directories searched for compiled terminfo entries, in order.
+fn terminfo_dirs(var: &impl Fn(&str) -> Option<String>) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = var("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = var("HOME") {
+        dirs.push(PathBuf::from(home).join(".terminfo"));
+    }
+    if let Some(list) = var("TERMINFO_DIRS") {
+        dirs.extend(list.split(':').filter(|d| !d.is_empty()).map(PathBuf::from));
+    }
+    dirs.extend(
+        ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo"]
+            .into_iter()
+            .map(PathBuf::from),
+    );
+    dirs
+}
+
+/// Look up `term` in the terminfo database and derive its color system
+/// from the `colors` number and the `Tc`/`RGB` extended flags.
+///
+/// Returns `None` if no entry was found or it could not be parsed, and
+/// `Some(None)` for a monochrome entry.
+fn terminfo_color_system(term: &str, dirs: &[PathBuf]) -> Option<Option<ColorSystem>> {
+    let first = term.chars().next()?;
+    if term.contains('/') {
+        return None;
+    }
+    let data = dirs.iter().find_map(|dir| {
+        // Linux uses the first letter as the subdirectory, macOS its hex code.
+        std::fs::read(dir.join(first.to_string()).join(term))
+            .or_else(|_| std::fs::read(dir.join(format!("{:x}", first as u32)).join(term)))
+            .ok()
+    })?;
+    let entry = parse_terminfo(&data)?;
+    Some(match entry.colors {
+        _ if entry.truecolor => Some(ColorSystem::TrueColor),
+        colors if colors >= 1 << 24 => Some(ColorSystem::TrueColor),
+        colors if colors >= 256 => Some(ColorSystem::EightBit),
+        colors if colors >= 8 => Some(ColorSystem::Standard),
+        _ => None,
+    })
+}
+
+/// The parts of a compiled terminfo entry gilt cares about.
+#[derive(Debug, PartialEq, Eq)]
+struct TerminfoEntry {
+    /// The `colors` number (`max_colors`), or 0 if absent.
+    colors: i32,
+    /// Whether the `Tc` or `RGB` extended flag is set.
+    truecolor: bool,
+}
+
+/// Parse a compiled terminfo entry (see `term(5)`), in either the legacy
+/// format with 16-bit numbers or the extended format with 32-bit numbers.
+fn parse_terminfo(data: &[u8]) -> Option<TerminfoEntry> {
+    /// Index of `colors` among the standard numeric capabilities.
+    const MAX_COLORS: usize = 13;
+
+    let short = |offset: usize| -> Option<i32> {
+        let bytes = data.get(offset..offset + 2)?;
+        Some(i16::from_le_bytes([bytes[0], bytes[1]]) as i32)
+    };
+    let count = |offset: usize| short(offset).map(|n| n.max(0) as usize);
+    let number_size = match short(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let number = |offset: usize| -> Option<i32> {
+        if number_size == 2 {
+            short(offset)
+        } else {
+            let bytes = data.get(offset..offset + 4)?;
+            Some(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        }
+    };
+    let even = |offset: usize| offset + offset % 2;
+
+    let (names_size, bool_count, num_count) = (count(2)?, count(4)?, count(6)?);
+    let (str_count, str_table_size) = (count(8)?, count(10)?);
+    let numbers = even(12 + names_size + bool_count);
+    let colors = if num_count > MAX_COLORS {
+        number(numbers + MAX_COLORS * number_size)?.max(0)
+    } else {
+        0
+    };
+
+    // Extended capabilities follow the string table, if present.
+    let extended = even(numbers + num_count * number_size + str_count * 2 + str_table_size);
+    let truecolor = parse_extended_truecolor(data, extended, number_size).unwrap_or(false);
+    Some(TerminfoEntry { colors, truecolor })
+}
+
+/// Whether the extended capabilities starting at `offset` set `Tc` or
+/// `RGB`; `None` if there are none or they are malformed.
+fn parse_extended_truecolor(data: &[u8], offset: usize, number_size: usize) -> Option<bool> {
+    let count = |offset: usize| -> Option<usize> {
+        let bytes = data.get(offset..offset + 2)?;
+        Some(i16::from_le_bytes([bytes[0], bytes[1]]).max(0) as usize)
+    };
+    let (bool_count, num_count, str_count) =
+        (count(offset)?, count(offset + 2)?, count(offset + 4)?);
+    let table_size = count(offset + 8)?;
+    let bools = data.get(offset + 10..offset + 10 + bool_count)?;
+    let numbers = offset + 10 + bool_count + (bool_count % 2);
+    let offsets = numbers + num_count * number_size;
+    // Value offsets for the strings, then name offsets for every capability.
+    let table = offsets + (str_count + bool_count + num_count + str_count) * 2;
+    let table = data.get(table..table + table_size)?;
+
+    // Names are the last NUL-terminated strings in the table, booleans first.
+    let strings: Vec<&[u8]> = table.split(|&b| b == 0).collect();
+    let name_count = bool_count + num_count + str_count;
+    // The table ends with a NUL, leaving an empty final piece.
+    let names = strings.get(strings.len().checked_sub(name_count + 1)?..strings.len() - 1)?;
+    Some(
+        names
+            .iter()
+            .zip(bools)
+            .any(|(name, &set)| set == 1 && matches!(*name, b"Tc" | b"RGB")),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Windows
+// ---------------------------------------------------------------------------
+
+/// Enable virtual terminal processing on the standard output console, so
+/// ANSI escape sequences are interpreted. Returns whether it is enabled.
+#[cfg(windows)]
+fn enable_vt_processing() -> bool {
+    type Handle = *mut std::ffi::c_void;
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> Handle;
+        fn GetConsoleMode(console: Handle, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console: Handle, mode: u32) -> i32;
+    }
+
+    // SAFETY: the handle comes from GetStdHandle and is checked before use;
+    // `mode` outlives the GetConsoleMode call that writes it.
+    unsafe {
+        let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+        if handle.is_null() || handle as isize == -1 {
+            return false;
+        }
+        let mut mode = 0;
+        if GetConsoleMode(handle, &mut mode) == 0 {
+            return false;
+        }
+        mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+            || SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Build a compiled terminfo entry with the given `colors` and extended
+    /// boolean capabilities.
+    fn compiled(colors: i32, extended: &[(&str, bool)], wide: bool) -> Vec<u8> {
+        let shorts =
+            |values: &[i16]| -> Vec<u8> { values.iter().flat_map(|v| v.to_le_bytes()).collect() };
+        let names = b"test|Test Terminal\0";
+        let mut data = shorts(&[if wide { 0o1036 } else { 0o432 }, names.len() as i16]);
+        data.extend(shorts(&[0, 14, 0, 0]));
+        data.extend(names);
+        if data.len() % 2 == 1 {
+            data.push(0);
+        }
+        for index in 0..14 {
+            let value = if index == 13 { colors } else { -1 };
+            if wide {
+                data.extend(value.to_le_bytes());
+            } else {
+                data.extend((value as i16).to_le_bytes());
+            }
+        }
+        if !extended.is_empty() {
+            let table: Vec<u8> = extended
+                .iter()
+                .flat_map(|(name, _)| name.bytes().chain(std::iter::once(0)))
+                .collect();
+            let count = extended.len() as i16;
+            data.extend(shorts(&[count, 0, 0, count, table.len() as i16]));
+            data.extend(extended.iter().map(|&(_, set)| set as u8));
+            if extended.len() % 2 == 1 {
+                data.push(0);
+            }
+            let mut offset = 0;
+            for (name, _) in extended {
+                data.extend(shorts(&[offset]));
+                offset += name.len() as i16 + 1;
+            }
+            data.extend(table);
+        }
+        data
+    }
+
+    fn from_vars(vars: &[(&str, &str)]) -> Capabilities {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Capabilities::from_env(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_parse_terminfo_colors() {
+        let legacy = parse_terminfo(&compiled(256, &[], false)).unwrap();
+        assert_eq!(
+            legacy,
+            TerminfoEntry {
+                colors: 256,
+                truecolor: false
+            }
+        );
+        let wide = parse_terminfo(&compiled(1 << 24, &[], true)).unwrap();
+        assert_eq!(wide.colors, 1 << 24);
+        assert!(parse_terminfo(b"not terminfo").is_none());
+    }
+
+    #[test]
+    fn test_parse_terminfo_extended_truecolor_flags() {
+        let tc = compiled(256, &[("AX", true), ("Tc", true)], false);
+        assert!(parse_terminfo(&tc).unwrap().truecolor);
+        let rgb = compiled(256, &[("RGB", true)], true);
+        assert!(parse_terminfo(&rgb).unwrap().truecolor);
+        let unset = compiled(256, &[("Tc", false), ("XT", true)], false);
+        assert!(!parse_terminfo(&unset).unwrap().truecolor);
+    }
+
+    #[test]
+    fn test_terminfo_lookup() {
+        let dir = std::env::temp_dir().join(format!("gilt-terminfo-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("g")).unwrap();
+        std::fs::create_dir_all(dir.join("67")).unwrap();
+        std::fs::write(dir.join("g/gilt-256"), compiled(256, &[], false)).unwrap();
+        std::fs::write(dir.join("67/gilt-tc"), compiled(8, &[("Tc", true)], false)).unwrap();
+        std::fs::write(dir.join("g/gilt-mono"), compiled(-1, &[], false)).unwrap();
+        let terminfo = dir.to_str().unwrap();
+
+        let lookup = |term| from_vars(&[("TERM", term), ("TERMINFO", terminfo)]).color_system;
+        assert_eq!(lookup("gilt-256"), Some(ColorSystem::EightBit));
+        assert_eq!(lookup("gilt-tc"), Some(ColorSystem::TrueColor));
+        assert_eq!(lookup("gilt-mono"), None);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_color_system_precedence() {
+        let caps = from_vars(&[("TERM", "gilt-unknown"), ("COLORTERM", "truecolor")]);
+        assert_eq!(caps.color_system, Some(ColorSystem::TrueColor));
+        assert_eq!(
+            from_vars(&[("TERM", "gilt-unknown-256color")]).color_system,
+            Some(ColorSystem::EightBit)
+        );
+        assert_eq!(
+            from_vars(&[("TERM", "gilt-unknown")]).color_system,
+            Some(ColorSystem::Standard)
+        );
+        let dumb = from_vars(&[("TERM", "dumb"), ("COLORTERM", "truecolor")]);
+        assert_eq!(dumb.color_system, None);
+        assert!(!dumb.vt_processing);
+        assert_eq!(from_vars(&[]), Capabilities::default());
+    }
+
+    #[test]
+    fn test_terminal_programs() {
+        let iterm = from_vars(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "iTerm.app")]);
+        assert_eq!(iterm.program, Some(TerminalProgram::ITerm2));
+        assert!(iterm.hyperlinks && iterm.iterm_images && iterm.sixel);
+        assert!(!iterm.kitty_graphics);
+        assert_eq!(iterm.color_system, Some(ColorSystem::TrueColor));
+
+        let kitty = from_vars(&[("TERM", "xterm-kitty")]);
+        assert_eq!(kitty.program, Some(TerminalProgram::Kitty));
+        assert!(kitty.kitty_graphics && !kitty.sixel);
+
+        let old_vte = from_vars(&[("TERM", "xterm"), ("VTE_VERSION", "4205")]);
+        assert_eq!(old_vte.program, Some(TerminalProgram::Vte));
+        assert!(!old_vte.hyperlinks);
+        assert!(from_vars(&[("TERM", "xterm"), ("VTE_VERSION", "6003")]).hyperlinks);
+
+        let apple = from_vars(&[("TERM", "gilt-unknown"), ("TERM_PROGRAM", "Apple_Terminal")]);
+        assert_eq!(apple.color_system, Some(ColorSystem::EightBit));
+        assert!(!apple.hyperlinks);
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! This module provides utilities for calculating the visual width of text in terminal cells,
 //! handling single-width (ASCII, box drawing) and double-width (CJK, emoji) characters.
+//!
+//! Widths follow the Unicode East Asian Width and emoji presentation data of
+//! the `unicode-width` crate. Cropping and splitting work on grapheme
+//! clusters (see [`cell_clusters`]), so composed emoji -- ZWJ sequences,
+//! flags, skin tones -- are kept whole and measured as one two-cell glyph.
 
 use std::borrow::Cow;
 
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Get the cell width of a string (how many terminal columns it occupies).
@@ -42,6 +48,40 @@ pub fn get_character_cell_size(c: char) -> usize {
     c.width().unwrap_or(0)
 }
 
+/// Split `text` into grapheme clusters and yield every cluster's byte
+/// offset, text and cell width.
+///
+/// A cluster is what the terminal draws as one glyph: a character with its
+/// combining marks or variation selector, a flag made of two regional
+/// indicators, an emoji with a skin tone modifier, or a ZWJ sequence such as
+/// a family emoji. Code that crops or splits text cell by cell must not
+/// separate them, or the pieces are measured (and drawn) as several glyphs.
+/// The cluster widths add up to [`cell_len`].
+///
+/// # Examples
+///
+/// ```
+/// use gilt::cells::{cell_clusters, cell_len};
+///
+/// let heart = "a\u{2764}\u{FE0F}";
+/// let clusters: Vec<(usize, &str, usize)> = cell_clusters(heart).collect();
+/// assert_eq!(clusters, vec![(0, "a", 1), (1, "\u{2764}\u{FE0F}", 2)]);
+///
+/// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+/// assert_eq!(cell_clusters(family).count(), 1);
+/// assert_eq!(cell_len(family), 2);
+/// ```
+pub fn cell_clusters(text: &str) -> impl Iterator<Item = (usize, &str, usize)> + '_ {
+    text.grapheme_indices(true).map(|(offset, cluster)| {
+        let mut chars = cluster.chars();
+        let width = match (chars.next(), chars.next()) {
+            (Some(c), None) => get_character_cell_size(c),
+            _ => cluster.width(),
+        };
+        (offset, cluster, width)
+    })
+}
+
 /// Crop or pad a string to fit in exactly `total` cells.
 ///
 /// If the string is too long, it will be cropped. If a crop would split a double-width
@@ -83,11 +123,9 @@ pub fn set_cell_size(text: &str, total: usize) -> Cow<'_, str> {
     let mut result = String::with_capacity(text.len());
     let mut cell_position = 0;
 
-    for c in text.chars() {
-        let char_width = get_character_cell_size(c);
-
+    for (_, cluster, char_width) in cell_clusters(text) {
         if cell_position + char_width <= total {
-            result.push(c);
+            result.push_str(cluster);
             cell_position += char_width;
         } else if cell_position < total {
             // We have space left but the character doesn't fit
@@ -124,11 +162,9 @@ pub fn chop_cells(text: &str, width: usize) -> Vec<String> {
     let mut current_line = String::new();
     let mut current_width = 0;
 
-    for c in text.chars() {
-        let char_width = get_character_cell_size(c);
-
+    for (_, cluster, char_width) in cell_clusters(text) {
         if current_width + char_width <= width {
-            current_line.push(c);
+            current_line.push_str(cluster);
             current_width += char_width;
         } else {
             // Start a new line
@@ -136,7 +172,7 @@ pub fn chop_cells(text: &str, width: usize) -> Vec<String> {
                 lines.push(current_line);
                 current_line = String::new();
             }
-            current_line.push(c);
+            current_line.push_str(cluster);
             current_width = char_width;
         }
     }
@@ -171,6 +207,59 @@ pub fn is_single_cell_widths(text: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_composed_emoji_are_one_cluster() {
+        let samples = [
+            ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 2), // family (ZWJ)
+            ("\u{1F1EF}\u{1F1F5}", 2),                          // flag
+            ("\u{1F44D}\u{1F3FD}", 2),                          // skin tone
+            ("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", 2),          // rainbow flag
+            ("#\u{FE0F}\u{20E3}", 2),                           // keycap
+            ("e\u{301}", 1),                                    // combining accent
+            ("\u{1100}\u{1161}", 2),                            // Hangul jamo
+        ];
+        for (text, width) in samples {
+            let clusters: Vec<_> = cell_clusters(text).collect();
+            assert_eq!(clusters, vec![(0, text, width)], "{:?}", text);
+            assert_eq!(cell_len(text), width, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_crop_and_chop_keep_clusters_whole() {
+        let flags = "\u{1F1EF}\u{1F1F5}\u{1F1EB}\u{1F1F7}x";
+        assert_eq!(cell_len(flags), 5);
+        assert_eq!(set_cell_size(flags, 2), "\u{1F1EF}\u{1F1F5}");
+        assert_eq!(set_cell_size(flags, 3), "\u{1F1EF}\u{1F1F5} ");
+        assert_eq!(
+            chop_cells(flags, 3),
+            vec!["\u{1F1EF}\u{1F1F5}", "\u{1F1EB}\u{1F1F7}x"]
+        );
+        let thumbs = "\u{1F44D}\u{1F3FD}\u{1F44D}\u{1F3FD}";
+        assert_eq!(chop_cells(thumbs, 2), vec!["\u{1F44D}\u{1F3FD}"; 2]);
+    }
+
+    #[test]
+    fn test_cluster_widths_sum_to_cell_len() {
+        let text = "a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b\u{1F1EF}\u{1F1F5}\u{308F}\u{2764}\u{FE0E}";
+        let total: usize = cell_clusters(text).map(|(_, _, w)| w).sum();
+        assert_eq!(total, cell_len(text));
+    }
+
+    #[test]
+    fn test_variation_selectors_stay_with_their_character() {
+        let text = "\u{2764}\u{FE0F}\u{2764}\u{FE0E}";
+        assert_eq!(cell_len(text), 3);
+        assert_eq!(set_cell_size(text, 2), "\u{2764}\u{FE0F}");
+        assert_eq!(set_cell_size(text, 1), " ");
+        assert_eq!(
+            chop_cells(text, 2),
+            vec!["\u{2764}\u{FE0F}", "\u{2764}\u{FE0E}"]
+        );
+        let widths: Vec<usize> = cell_clusters(text).map(|(_, _, w)| w).collect();
+        assert_eq!(widths, vec![2, 1]);
+    }
+
     #[test]
     fn test_get_character_cell_size() {
         // Control characters - unicode-width returns Some(0) for C0 and C1 control codes
@@ -242,7 +331,7 @@ mod tests {
         // Control char in middle - may have width
         let a_x01_b_len = cell_len("a\x01b");
         assert!(
-            a_x01_b_len >= 2 && a_x01_b_len <= 3,
+            (2..=3).contains(&a_x01_b_len),
             "Expected a\\x01b width 2-3, got {}",
             a_x01_b_len
         );
@@ -415,7 +504,7 @@ mod tests {
         // Note: unicode-width may count \x00 as width 0 or 1 depending on version
         let nul_a_len = cell_len("\x00a");
         assert!(
-            nul_a_len >= 1 && nul_a_len <= 2,
+            (1..=2).contains(&nul_a_len),
             "Expected \\x00a width 1-2, got {}",
             nul_a_len
         );
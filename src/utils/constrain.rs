@@ -2,7 +2,7 @@
 //!
 //! Port of Python's `rich/constrain.py`.
 
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::fmt;
 
 use crate::console::{Console, ConsoleOptions, Renderable};
@@ -20,12 +20,36 @@ use crate::text::Text;
 /// When `width` is `Some(w)`, the content is rendered with a maximum width of
 /// `min(w, options.max_width)`.  When `width` is `None`, the content passes
 /// through unmodified.
+///
+/// Widths can also be responsive: [`percent`](Self::percent) takes a share of
+/// the available width, and [`min_width`](Self::min_width) keeps that share
+/// from collapsing on narrow terminals. The limits combine — percentage
+/// first, then the `width` cap, then the floor — and the result never exceeds
+/// the available width.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::constrain::Constrain;
+/// use gilt::text::Text;
+///
+/// // 80% of the terminal, but never narrower than 40 or wider than 120 cells.
+/// let c = Constrain::percent(Text::from("Hello"), 80).with_min_width(40).with_width(120);
+/// assert_eq!(c.resolve_width(200), Some(120));
+/// assert_eq!(c.resolve_width(100), Some(80));
+/// assert_eq!(c.resolve_width(45), Some(40));
+/// assert_eq!(c.resolve_width(30), Some(30));
+/// ```
 #[derive(Debug, Clone)]
 pub struct Constrain {
     /// The content to constrain.
     pub renderable: Text,
     /// Maximum width in characters. `None` means no constraint is applied.
     pub width: Option<usize>,
+    /// Width as a percentage (0–100) of the available width.
+    percent: Option<usize>,
+    /// Minimum width in characters, applied after `percent` and `width`.
+    min_width: Option<usize>,
 }
 
 impl Constrain {
@@ -33,7 +57,22 @@ impl Constrain {
     ///
     /// `width` defaults to `Some(80)` following the Python implementation.
     pub fn new(renderable: Text, width: Option<usize>) -> Self {
-        Constrain { renderable, width }
+        Constrain {
+            renderable,
+            width,
+            percent: None,
+            min_width: None,
+        }
+    }
+
+    /// Constrain to `percent` percent of the available width.
+    pub fn percent(renderable: Text, percent: usize) -> Self {
+        Constrain::new(renderable, None).with_percent(percent)
+    }
+
+    /// Constrain to the available width, kept between `min` and `max` cells.
+    pub fn clamp(renderable: Text, min: usize, max: usize) -> Self {
+        Constrain::new(renderable, Some(max)).with_min_width(min)
     }
 
     /// Builder method to set the width.
@@ -43,14 +82,50 @@ impl Constrain {
         self
     }
 
+    /// Builder method to set the width as a percentage of the available width.
+    ///
+    /// Values above 100 are treated as 100.
+    #[must_use]
+    pub fn with_percent(mut self, percent: usize) -> Self {
+        self.percent = Some(percent.min(100));
+        self
+    }
+
+    /// Builder method to set the minimum width.
+    #[must_use]
+    pub fn with_min_width(mut self, min_width: usize) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    /// Resolve the width to render at given `available` cells.
+    ///
+    /// Returns `None` when no constraint is configured.
+    pub fn resolve_width(&self, available: usize) -> Option<usize> {
+        if self.width.is_none() && self.percent.is_none() && self.min_width.is_none() {
+            return None;
+        }
+        let mut width = available;
+        if let Some(percent) = self.percent {
+            width = available * percent / 100;
+        }
+        if let Some(cap) = self.width {
+            width = min(width, cap);
+        }
+        if let Some(floor) = self.min_width {
+            width = max(width, floor);
+        }
+        Some(min(width, available))
+    }
+
     /// Measure the minimum and maximum width requirements of the constrained
     /// content.
     ///
-    /// If `width` is `Some(w)`, the options are constrained to that width
-    /// before measuring.  The resulting measurement is then clamped to the
-    /// constrained width.
+    /// If a constraint is configured, the options are constrained to the
+    /// resolved width before measuring.  The resulting measurement is then
+    /// clamped to the constrained width.
     pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
-        let measurement = if let Some(w) = self.width {
+        let measurement = if let Some(w) = self.resolve_width(options.max_width) {
             let constrained = options.update_width(w);
             self.renderable
                 .measure()
@@ -64,11 +139,10 @@ impl Constrain {
 
 impl Renderable for Constrain {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
-        match self.width {
+        match self.resolve_width(options.max_width) {
             None => self.renderable.gilt_console(console, options),
             Some(w) => {
-                let constrained_width = min(w, options.max_width);
-                let child_options = options.update_width(constrained_width);
+                let child_options = options.update_width(w);
                 self.renderable.gilt_console(console, &child_options)
             }
         }
@@ -102,6 +176,7 @@ impl fmt::Display for Constrain {
 mod tests {
     use super::*;
     use crate::style::Style;
+    use crate::utils::cells::cell_len;
 
     fn make_console(width: usize) -> Console {
         Console::builder()
@@ -370,4 +445,56 @@ mod tests {
         assert_eq!(content_lines.len(), 1);
         assert_eq!(content_lines[0], "Hello");
     }
+
+    // -- Percentage and clamp -----------------------------------------------
+
+    #[test]
+    fn test_percent_of_available_width() {
+        let c = Constrain::percent(Text::from("x"), 50);
+        assert_eq!(c.width, None);
+        assert_eq!(c.percent, Some(50));
+        assert_eq!(c.resolve_width(80), Some(40));
+        assert_eq!(c.resolve_width(33), Some(16));
+        assert_eq!(Constrain::percent(Text::from("x"), 150).percent, Some(100));
+    }
+
+    #[test]
+    fn test_clamp_bounds() {
+        let c = Constrain::clamp(Text::from("x"), 40, 120);
+        assert_eq!(c.resolve_width(200), Some(120));
+        assert_eq!(c.resolve_width(90), Some(90));
+        // The floor never pushes past the available width
+        assert_eq!(c.resolve_width(30), Some(30));
+    }
+
+    #[test]
+    fn test_no_constraint_resolves_none() {
+        assert_eq!(
+            Constrain::new(Text::from("x"), None).resolve_width(80),
+            None
+        );
+    }
+
+    #[test]
+    fn test_percent_render_adapts_to_console() {
+        let text = Text::new("aaaa bbbb cccc dddd", Style::null());
+        let c = Constrain::percent(text, 50);
+        for (console_width, expected_lines) in [(40, 1), (20, 2)] {
+            let console = make_console(console_width);
+            let output = segments_to_text(&c.gilt_console(&console, &console.options()));
+            let lines: Vec<&str> = output.split('\n').filter(|l| !l.is_empty()).collect();
+            assert_eq!(lines.len(), expected_lines);
+            assert!(lines
+                .iter()
+                .all(|l| cell_len(l.trim_end()) <= console_width / 2));
+        }
+    }
+
+    #[test]
+    fn test_measure_with_percent() {
+        let console = make_console(80);
+        let opts = console.options();
+        let c = Constrain::percent(Text::new("x".repeat(60).as_str(), Style::null()), 25);
+        assert_eq!(c.measure(&console, &opts).maximum, 20);
+    }
 }
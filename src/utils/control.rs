@@ -103,6 +103,36 @@ pub fn escape_control_codes(text: &str) -> Cow<'_, str> {
     Cow::Owned(result)
 }
 
+/// Replace control characters with their Unicode Control Pictures, so that
+/// untrusted text shows them instead of the terminal acting on them.
+///
+/// Every C0 control character except newline and tab maps to its picture
+/// in U+2400..U+241F (e.g. carriage return to `␍`, escape to `␛`), and DEL
+/// maps to `␡`. The mapping is one char to one char, so char offsets into
+/// the text stay valid.
+///
+/// # Examples
+/// ```
+/// use gilt::control::visualize_control_codes;
+/// assert_eq!(visualize_control_codes("a\rb\x1b[2J"), "a␍b␛[2J");
+/// assert_eq!(visualize_control_codes("line\n\tindented"), "line\n\tindented");
+/// ```
+pub fn visualize_control_codes(text: &str) -> Cow<'_, str> {
+    let is_visualized = |c: char| c.is_ascii_control() && c != '\n' && c != '\t';
+    if !text.chars().any(is_visualized) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            .map(|c| match c {
+                '\x7F' => '\u{2421}',
+                c if is_visualized(c) => char::from_u32(0x2400 + c as u32).unwrap_or(c),
+                c => c,
+            })
+            .collect(),
+    )
+}
+
 /// Render a single `ControlCode` to its ANSI escape sequence string.
 fn render_code(code: &ControlCode) -> String {
     match code {
@@ -338,6 +368,16 @@ mod tests {
         assert_eq!(strip_control_codes("a\x07b\x08c\x0Bd\x0Ce\rf"), "abcdef");
     }
 
+    #[test]
+    fn test_visualize_control_codes() {
+        assert!(matches!(
+            visualize_control_codes("plain\ttext\n"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(visualize_control_codes("\x00\x07\x08\x1b\x7f"), "␀␇␈␛␡");
+        assert_eq!(visualize_control_codes("a\r\nb").chars().count(), 4);
+    }
+
     #[test]
     fn test_escape_control_codes_empty() {
         assert_eq!(escape_control_codes(""), "");
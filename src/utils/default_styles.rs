@@ -1,8 +1,8 @@
 //! Default style definitions for the gilt library.
 //!
-//! This module provides a comprehensive set of 153 named styles that map to
-//! the default styles in Python's rich library. These styles are used by
-//! various components for consistent terminal formatting.
+//! This module provides a comprehensive set of 168 named styles: the default
+//! styles of Python's rich library plus gilt's own `badge.*` and `clap.*` styles. These
+//! styles are used by various components for consistent terminal formatting.
 
 use std::collections::HashMap;
 
@@ -28,7 +28,7 @@ fn null(m: &mut HashMap<String, Style>, name: &str) {
     m.insert(name.to_string(), Style::null());
 }
 
-/// The complete set of 153 default named styles.
+/// The complete set of 168 default named styles.
 ///
 /// Styles are lazily initialized on first access and cached for the lifetime
 /// of the program.
@@ -181,6 +181,9 @@ pub static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     ins(&mut m, "scope.key.special", "italic dim yellow");
     ins(&mut m, "scope.equals", "red");
 
+    // --- shadow ---
+    ins(&mut m, "shadow", "bright_black");
+
     // --- table.* styles ---
     ins(&mut m, "table.header", "bold");
     ins(&mut m, "table.footer", "bold");
@@ -221,6 +224,22 @@ pub static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     // --- status.* styles ---
     ins(&mut m, "status.spinner", "green");
 
+    // --- badge.* styles ---
+    ins(&mut m, "badge.success", "bold white on green");
+    ins(&mut m, "badge.error", "bold white on red");
+    ins(&mut m, "badge.warning", "bold black on yellow");
+    ins(&mut m, "badge.info", "bold white on blue");
+    ins(&mut m, "badge.neutral", "white on grey50");
+
+    // --- clap.* styles ---
+    ins(&mut m, "clap.header", "bold underline");
+    ins(&mut m, "clap.usage", "bold underline");
+    ins(&mut m, "clap.literal", "bold cyan");
+    ins(&mut m, "clap.placeholder", "cyan");
+    ins(&mut m, "clap.error", "bold red");
+    ins(&mut m, "clap.valid", "bold green");
+    ins(&mut m, "clap.invalid", "bold yellow");
+
     // --- tree styles ---
     null(&mut m, "tree");
     null(&mut m, "tree.line");
@@ -238,6 +257,8 @@ pub static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     null(&mut m, "markdown.item");
     ins(&mut m, "markdown.item.bullet", "bold");
     ins(&mut m, "markdown.item.number", "cyan");
+    ins(&mut m, "markdown.item.checked", "green");
+    ins(&mut m, "markdown.item.unchecked", "dim");
     ins(&mut m, "markdown.hr", "dim");
     null(&mut m, "markdown.h1.border");
     ins(&mut m, "markdown.h1", "bold underline");
@@ -261,6 +282,23 @@ pub static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     m
 });
 
+/// Default glyphs that accessible mode prefixes to text styled with these
+/// keys, so that meanings carried by color survive without it.
+pub static DEFAULT_GLYPHS: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    [
+        ("success", "\u{2714}"),
+        ("error", "\u{2716}"),
+        ("danger", "\u{2716}"),
+        ("warning", "!"),
+        ("logging.level.warning", "!"),
+        ("logging.level.error", "\u{2716}"),
+        ("logging.level.critical", "\u{2716}"),
+    ]
+    .into_iter()
+    .map(|(name, glyph)| (name.to_string(), glyph.to_string()))
+    .collect()
+});
+
 /// Returns a clone of the default styles map.
 pub fn default_styles() -> HashMap<String, Style> {
     DEFAULT_STYLES.clone()
@@ -272,7 +310,7 @@ mod tests {
 
     #[test]
     fn test_default_styles_count() {
-        assert_eq!(DEFAULT_STYLES.len(), 153);
+        assert_eq!(DEFAULT_STYLES.len(), 168);
     }
 
     #[test]
@@ -556,6 +594,8 @@ mod tests {
             "markdown.item",
             "markdown.item.bullet",
             "markdown.item.number",
+            "markdown.item.checked",
+            "markdown.item.unchecked",
             "markdown.hr",
             "markdown.h1.border",
             "markdown.h1",
@@ -581,6 +621,6 @@ mod tests {
                 key
             );
         }
-        assert_eq!(expected_keys.len(), 153);
+        assert_eq!(expected_keys.len(), 155);
     }
 }
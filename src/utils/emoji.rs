@@ -74,12 +74,29 @@ impl Emoji {
     ///
     /// - `"text"` appends U+FE0E (text presentation selector)
     /// - `"emoji"` appends U+FE0F (emoji presentation selector)
+    ///
+    /// A selector already present in the dictionary entry is replaced. The
+    /// selector changes how many cells the emoji occupies, which
+    /// [`cell_len`](crate::cells::cell_len) accounts for:
+    ///
+    /// ```
+    /// use gilt::cells::cell_len;
+    /// use gilt::emoji::Emoji;
+    ///
+    /// let heart = Emoji::new("heart").unwrap();
+    /// assert_eq!(cell_len(&heart.char), 1);
+    /// assert_eq!(cell_len(&heart.with_variant("emoji").char), 2);
+    /// ```
     pub fn with_variant(mut self, variant: &str) -> Self {
-        match variant {
-            "text" => self.char.push('\u{FE0E}'),
-            "emoji" => self.char.push('\u{FE0F}'),
-            _ => {}
+        let selector = match variant {
+            "text" => '\u{FE0E}',
+            "emoji" => '\u{FE0F}',
+            _ => return self,
+        };
+        if self.char.ends_with(['\u{FE0E}', '\u{FE0F}']) {
+            self.char.pop();
         }
+        self.char.push(selector);
         self
     }
 
@@ -202,6 +219,15 @@ mod tests {
         assert_eq!(emoji.char, "\u{2764}\u{FE0F}");
     }
 
+    #[test]
+    fn test_with_variant_replaces_existing_selector() {
+        let emoji = Emoji::new("heart")
+            .unwrap()
+            .with_variant("emoji")
+            .with_variant("text");
+        assert_eq!(emoji.char, "\u{2764}\u{FE0E}");
+    }
+
     #[test]
     fn test_with_variant_unknown() {
         let emoji = Emoji::new("heart").unwrap().with_variant("other");
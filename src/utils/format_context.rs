@@ -0,0 +1,247 @@
+//! Locale-aware formatting of numbers, file sizes and times of day.
+//!
+//! A [`FormatContext`] is stored on the [`Console`](crate::console::Console)
+//! and handed to renderables through
+//! [`ConsoleOptions::format`](crate::console::ConsoleOptions::format), so
+//! widgets that print numbers, sizes or clock times agree on separators, the
+//! 12/24-hour clock and the file-size unit system.
+
+use crate::utils::filesize;
+
+/// How times of day are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// 24-hour clock, e.g. `14:05:09`.
+    #[default]
+    H24,
+    /// 12-hour clock with an AM/PM suffix, e.g. `2:05:09 PM`.
+    H12,
+}
+
+/// Which multiplier file sizes are reported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    /// Powers of 1000 with SI prefixes (`kB`, `MB`, ...).
+    #[default]
+    Decimal,
+    /// Powers of 1024 with IEC prefixes (`KiB`, `MiB`, ...).
+    Binary,
+}
+
+/// Locale, number and date conventions shared by every widget on a console.
+///
+/// The default matches gilt's historical output: `.` as the decimal point,
+/// `,` between thousands, a 24-hour clock and decimal file sizes.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::format_context::{FormatContext, TimeFormat};
+///
+/// let de = FormatContext::for_locale("de-DE");
+/// assert_eq!(de.format_number(1234567.891, 2), "1.234.567,89");
+/// assert_eq!(de.format_size(1500), "1,5 kB");
+///
+/// let us = FormatContext::for_locale("en-US");
+/// assert_eq!(us.time_format, TimeFormat::H12);
+/// assert_eq!(us.format_time_of_day(14, 5, 9), "2:05:09 PM");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatContext {
+    /// BCP 47 language tag the conventions were derived from, e.g. `"en-US"`.
+    pub locale: String,
+    /// Character between the integer and fractional parts of a number.
+    pub decimal_separator: char,
+    /// Character between groups of thousands, or `None` for no grouping.
+    pub thousands_separator: Option<char>,
+    /// 12- or 24-hour clock for times of day.
+    pub time_format: TimeFormat,
+    /// Unit system for file sizes and transfer speeds.
+    pub unit_system: UnitSystem,
+}
+
+impl Default for FormatContext {
+    fn default() -> Self {
+        FormatContext {
+            locale: "en".to_string(),
+            decimal_separator: '.',
+            thousands_separator: Some(','),
+            time_format: TimeFormat::H24,
+            unit_system: UnitSystem::Decimal,
+        }
+    }
+}
+
+impl FormatContext {
+    /// Conventions for a BCP 47 language tag such as `"fr"` or `"en-US"`.
+    ///
+    /// Only the separators and clock style are derived from the tag; unknown
+    /// languages get the defaults.
+    pub fn for_locale(locale: &str) -> Self {
+        let mut tag = locale.split(['-', '_']);
+        let language = tag.next().unwrap_or("").to_ascii_lowercase();
+        let region = tag.next().unwrap_or("").to_ascii_uppercase();
+
+        let (decimal_separator, thousands_separator) = match language.as_str() {
+            "de" | "es" | "it" | "nl" | "pt" | "da" | "id" | "tr" => (',', Some('.')),
+            "fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => (',', Some('\u{a0}')),
+            _ => ('.', Some(',')),
+        };
+        let time_format = match (language.as_str(), region.as_str()) {
+            ("en", "US" | "CA" | "AU" | "PH" | "IN") | ("hi", _) => TimeFormat::H12,
+            _ => TimeFormat::H24,
+        };
+
+        FormatContext {
+            locale: locale.to_string(),
+            decimal_separator,
+            thousands_separator,
+            time_format,
+            unit_system: UnitSystem::Decimal,
+        }
+    }
+
+    /// Set the clock style (builder pattern).
+    #[must_use]
+    pub fn with_time_format(mut self, time_format: TimeFormat) -> Self {
+        self.time_format = time_format;
+        self
+    }
+
+    /// Set the file-size unit system (builder pattern).
+    #[must_use]
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.unit_system = unit_system;
+        self
+    }
+
+    /// Set the decimal and thousands separators (builder pattern).
+    #[must_use]
+    pub fn with_separators(mut self, decimal: char, thousands: Option<char>) -> Self {
+        self.decimal_separator = decimal;
+        self.thousands_separator = thousands;
+        self
+    }
+
+    /// Format `value` with `precision` fractional digits and grouped thousands.
+    pub fn format_number(&self, value: f64, precision: usize) -> String {
+        let formatted = format!("{value:.precision$}");
+        let (sign, digits) = match formatted.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", formatted.as_str()),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let mut result = String::from(sign);
+        result.push_str(&self.group_thousands(integer));
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    /// Format a byte count in the configured unit system, e.g. `"1.5 kB"`.
+    pub fn format_size(&self, size: u64) -> String {
+        self.format_size_in(size, self.unit_system)
+    }
+
+    /// Format a byte count in an explicit unit system, using this context's
+    /// separators.
+    pub fn format_size_in(&self, size: u64, unit_system: UnitSystem) -> String {
+        let formatted = match unit_system {
+            UnitSystem::Decimal => filesize::decimal(size, 1, " "),
+            UnitSystem::Binary => filesize::binary(size, 1, " "),
+        };
+        self.localize(&formatted)
+    }
+
+    /// Format a time of day given as hours (0-23), minutes and seconds.
+    pub fn format_time_of_day(&self, hour: u32, minute: u32, second: u32) -> String {
+        match self.time_format {
+            TimeFormat::H24 => format!("{hour:02}:{minute:02}:{second:02}"),
+            TimeFormat::H12 => {
+                let suffix = if hour < 12 { "AM" } else { "PM" };
+                let hour12 = match hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                format!("{hour12}:{minute:02}:{second:02} {suffix}")
+            }
+        }
+    }
+
+    /// Insert the thousands separator into a run of ASCII digits.
+    fn group_thousands(&self, digits: &str) -> String {
+        let Some(separator) = self.thousands_separator else {
+            return digits.to_string();
+        };
+        let len = digits.len();
+        let mut result = String::with_capacity(len + len / 3);
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (len - i) % 3 == 0 {
+                result.push(separator);
+            }
+            result.push(ch);
+        }
+        result
+    }
+
+    /// Swap the `.` and `,` of an English-formatted number for this
+    /// context's separators.
+    fn localize(&self, formatted: &str) -> String {
+        formatted
+            .chars()
+            .filter_map(|ch| match ch {
+                '.' => Some(self.decimal_separator),
+                ',' => self.thousands_separator,
+                other => Some(other),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_historical_output() {
+        let ctx = FormatContext::default();
+        assert_eq!(ctx.format_size(1500), filesize::decimal(1500, 1, " "));
+        assert_eq!(ctx.format_number(1234.5, 1), "1,234.5");
+        assert_eq!(ctx.format_time_of_day(9, 3, 7), "09:03:07");
+    }
+
+    #[test]
+    fn test_format_number_negative_and_no_grouping() {
+        let ctx = FormatContext::default().with_separators('.', None);
+        assert_eq!(ctx.format_number(-9876543.0, 0), "-9876543");
+        let ctx = FormatContext::for_locale("de");
+        assert_eq!(ctx.format_number(-1000.25, 1), "-1.000,2");
+    }
+
+    #[test]
+    fn test_twelve_hour_clock_edges() {
+        let ctx = FormatContext::default().with_time_format(TimeFormat::H12);
+        assert_eq!(ctx.format_time_of_day(0, 0, 0), "12:00:00 AM");
+        assert_eq!(ctx.format_time_of_day(12, 30, 0), "12:30:00 PM");
+        assert_eq!(ctx.format_time_of_day(23, 59, 59), "11:59:59 PM");
+    }
+
+    #[test]
+    fn test_binary_unit_system() {
+        let ctx = FormatContext::for_locale("fr").with_unit_system(UnitSystem::Binary);
+        assert_eq!(ctx.format_size(1536), "1,5 KiB");
+    }
+
+    #[test]
+    fn test_for_locale_parses_underscores_and_case() {
+        let ctx = FormatContext::for_locale("EN_us");
+        assert_eq!(ctx.time_format, TimeFormat::H12);
+        assert_eq!(ctx.locale, "EN_us");
+    }
+}
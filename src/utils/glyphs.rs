@@ -0,0 +1,83 @@
+//! Glyph sets -- the symbols used to mark truncated or elided content.
+//!
+//! Some terminal fonts lack characters such as `…` or `⋮`, which makes
+//! truncated output look broken. A [`Glyphs`] set is attached to each
+//! [`Console`](crate::console::Console) so these markers can be swapped for
+//! plain ASCII without touching individual widgets.
+
+/// The set of glyphs used to mark truncated, continued, or elided content.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::console::Console;
+/// use gilt::glyphs::Glyphs;
+/// use gilt::text::{OverflowMethod, Text};
+/// use gilt::style::Style;
+///
+/// let mut console = Console::builder()
+///     .width(8)
+///     .no_color(true)
+///     .glyphs(Glyphs::ascii())
+///     .build();
+/// let mut text = Text::new("truncated text", Style::null());
+/// text.overflow = Some(OverflowMethod::Ellipsis);
+/// text.no_wrap = Some(true);
+/// console.begin_capture();
+/// console.print(&text);
+/// assert_eq!(console.end_capture(), "trunc...\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Appended to text cut short by [`OverflowMethod::Ellipsis`](crate::text::OverflowMethod::Ellipsis).
+    pub ellipsis: String,
+    /// Line shown in place of content cut off vertically, e.g. by a live display.
+    pub continuation: String,
+    /// Prefix of the "N more" line that stands in for hidden tree children.
+    pub more: String,
+}
+
+impl Glyphs {
+    /// The default Unicode glyph set.
+    pub fn unicode() -> Self {
+        Glyphs {
+            ellipsis: "\u{2026}".to_string(),
+            continuation: "...".to_string(),
+            more: "\u{2026}".to_string(),
+        }
+    }
+
+    /// An ASCII-only glyph set for terminals and fonts without Unicode symbols.
+    pub fn ascii() -> Self {
+        Glyphs {
+            ellipsis: "...".to_string(),
+            continuation: "...".to_string(),
+            more: "...".to_string(),
+        }
+    }
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Glyphs::unicode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_unicode() {
+        assert_eq!(Glyphs::default(), Glyphs::unicode());
+        assert_eq!(Glyphs::default().ellipsis, "\u{2026}");
+    }
+
+    #[test]
+    fn test_ascii_is_ascii() {
+        let glyphs = Glyphs::ascii();
+        for glyph in [&glyphs.ellipsis, &glyphs.continuation, &glyphs.more] {
+            assert!(glyph.is_ascii());
+        }
+    }
+}
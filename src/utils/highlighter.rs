@@ -12,6 +12,8 @@
 //! - [`ReprHighlighter`] — patterns for repr-style output (numbers, strings, booleans, etc.).
 //! - [`JSONHighlighter`] — patterns for JSON (braces, strings, numbers, keys).
 //! - [`ISO8601Highlighter`] — patterns for ISO 8601 date/time strings.
+//! - [`LogColorizer`] — log levels and timestamps on top of repr highlighting.
+//! - [`PathHighlighter`] — turns absolute file paths into clickable hyperlinks.
 
 use regex::Regex;
 use std::sync::LazyLock;
@@ -136,7 +138,9 @@ static REPR_HIGHLIGHTS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
 });
 
 /// The combined pattern for the repr highlighter (IP addresses, UUIDs, calls,
-/// booleans, numbers, paths, strings, URLs).
+/// booleans, numbers, paths, strings, URLs), following rich's rules and
+/// extended with Rust literals: `0b`/`0o` prefixes, `_` digit separators
+/// and type suffixes such as `8u8` or `1.5f32`.
 ///
 /// Note: Rust's `regex` crate does not support look-behind assertions.
 /// Patterns have been adapted to use word boundaries or other anchoring
@@ -158,16 +162,18 @@ static REPR_COMBINED: LazyLock<String> = LazyLock::new(|| {
         // Booleans and None — supports both Python (True/False) and Rust (true/false)
         r"\b(?P<bool_true>true|True)\b|\b(?P<bool_false>false|False)\b|\b(?P<none>None)\b",
         // Ellipsis
-        r"(?P<ellipsis>\.\.\.)",
+        r"(?P<ellipsis>\.\.\.|\x{2026})",
         // Complex number (use \b instead of look-behind)
         r"(?P<number_complex>\b-?[0-9]+\.?[0-9]*(?:e[-+]?\d+?)?(?:[-+](?:[0-9]+\.?[0-9]*(?:e[-+]?\d+)?))?j)",
-        // Number (int, float, hex) — replaced look-behind with \b
-        r"(?P<number>\b-?[0-9]+\.?[0-9]*(e[-+]?\d+?)?\b|0x[0-9a-fA-F]*)",
+        // Number (int, float, hex, binary, octal, with optional Rust type
+        // suffix) — replaced look-behind with \b
+        r"(?P<number>\b-?(?:0x[0-9a-fA-F_]+|0o[0-7_]+|0b[01_]+|[0-9][0-9_]*\.?[0-9_]*(?:[eE][-+]?\d+)?)(?:[iu](?:8|16|32|64|128|size)|f32|f64)?\b|0x[0-9a-fA-F]*)",
         // Path and filename
         r"(?P<path>\B(/[-\w._+]+)*\/)(?P<filename>[-\w._+]*)?",
-        // Strings (single/double/triple quoted, optional b prefix)
-        // Simplified to avoid look-behind assertions not supported by Rust regex.
-        r#"(?P<str>b?'''[^']*'''|b?'[^']*'|b?"""[^"]*"""|b?"[^"]*")"#,
+        // Strings (single/double/triple quoted, optional b prefix), where a
+        // backslash escapes the next character. Written without the
+        // look-behind assertions rich uses, which Rust regex lacks.
+        r#"(?P<str>b?'''(?:[^'\\]|\\.)*'''|b?'(?:[^'\\]|\\.)*'|b?"""(?:[^"\\]|\\.)*"""|b?"(?:[^"\\]|\\.)*")"#,
         // URLs
         r"(?P<url>(file|https|http|ws|wss)://[-0-9a-zA-Z$_+!`(),.?/;:&=%#~@]*)",
     ])
@@ -510,6 +516,205 @@ impl Highlighter for JSONPathHighlighter {
     }
 }
 
+// ---------------------------------------------------------------------------
+// LogColorizer
+// ---------------------------------------------------------------------------
+
+/// Colorizes lines of application log output.
+///
+/// Applies [`ReprHighlighter`] first, then styles timestamps with `log.time`
+/// and level keywords (`DEBUG`, `INFO`, `WARN`, `ERROR`, ...) with the
+/// matching `logging.level.*` style, so levels always win over repr spans.
+pub struct LogColorizer;
+
+impl LogColorizer {
+    /// Create a new `LogColorizer`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map a level keyword to its `logging.level.*` style name.
+    fn level_style_name(level: &str) -> &'static str {
+        match level.to_ascii_uppercase().as_str() {
+            "TRACE" => "logging.level.notset",
+            "DEBUG" => "logging.level.debug",
+            "INFO" => "logging.level.info",
+            "WARN" | "WARNING" => "logging.level.warning",
+            "ERROR" | "ERR" => "logging.level.error",
+            _ => "logging.level.critical",
+        }
+    }
+}
+
+impl Default for LogColorizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Highlighter for LogColorizer {
+    fn highlight(&self, text: &mut Text) {
+        static TIME_RE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?|\b\d{2}:\d{2}:\d{2}(?:[.,]\d+)?\b",
+            )
+            .unwrap()
+        });
+        static LEVEL_RE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"(?i)\b(?:trace|debug|info|warn|warning|error|err|critical|fatal)\b")
+                .unwrap()
+        });
+
+        ReprHighlighter.highlight(text);
+
+        if let Some(style) = DEFAULT_STYLES.get("log.time") {
+            text.highlight_regex(&TIME_RE, style.clone());
+        }
+
+        let plain = text.plain().to_string();
+        for mat in LEVEL_RE.find_iter(&plain) {
+            // Only treat shouting-case or capitalised words as levels so that
+            // prose such as "no error occurred" is left alone.
+            let word = mat.as_str();
+            let rest = &word[1..];
+            let first_upper = word.starts_with(|c: char| c.is_ascii_uppercase());
+            let shouting = rest.chars().all(|c| c.is_ascii_uppercase());
+            let capitalised = rest.chars().all(|c| c.is_ascii_lowercase());
+            if !first_upper || !(shouting || capitalised) {
+                continue;
+            }
+            if let Some(style) = DEFAULT_STYLES.get(Self::level_style_name(word)) {
+                let start = plain[..mat.start()].chars().count();
+                let end = plain[..mat.end()].chars().count();
+                text.stylize(style.clone(), start, Some(end));
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PathHighlighter
+// ---------------------------------------------------------------------------
+
+/// Turns absolute file paths, optionally followed by `:line` or
+/// `:line:column`, into OSC 8 hyperlinks so compiler and build-tool output
+/// becomes clickable.
+///
+/// Links point at `file://` URLs by default. An editor scheme can be set
+/// with [`with_url_template`](PathHighlighter::with_url_template), where
+/// `{path}`, `{line}` and `{column}` are replaced by the matched location
+/// (line and column default to `1`).
+///
+/// # Examples
+///
+/// ```
+/// use gilt::highlighter::{Highlighter, PathHighlighter};
+///
+/// let text = PathHighlighter::vscode().apply("error at /src/main.rs:12:5");
+/// let link = text.spans()[0].style.link();
+/// assert_eq!(link, Some("vscode://file/src/main.rs:12:5"));
+/// ```
+pub struct PathHighlighter {
+    /// The style applied to matched paths, in addition to the link.
+    pub style: Style,
+    /// The link URL, with `{path}`, `{line}` and `{column}` placeholders.
+    pub url_template: String,
+}
+
+impl PathHighlighter {
+    /// Create a new `PathHighlighter` linking to `file://` URLs.
+    pub fn new() -> Self {
+        Self {
+            style: Style::null(),
+            url_template: "file://{path}".to_string(),
+        }
+    }
+
+    /// Create a `PathHighlighter` that opens locations in Visual Studio Code.
+    pub fn vscode() -> Self {
+        Self::new().with_url_template("vscode://file{path}:{line}:{column}")
+    }
+
+    /// Builder method: set the link URL template, e.g.
+    /// `"idea://open?file={path}&line={line}"`.
+    #[must_use]
+    pub fn with_url_template(mut self, template: &str) -> Self {
+        self.url_template = template.to_string();
+        self
+    }
+
+    /// Builder method: set the style applied to matched paths.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The link URL for a path and optional line and column, with the path
+    /// percent-encoded.
+    pub fn url(&self, path: &str, line: Option<&str>, column: Option<&str>) -> String {
+        // Windows paths become `/C:/dir/file` so they form a valid URL path.
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("/{}", path.replace('\\', "/"))
+        };
+        self.url_template
+            .replace("{path}", &encode_path(&path))
+            .replace("{line}", line.unwrap_or("1"))
+            .replace("{column}", column.unwrap_or("1"))
+    }
+}
+
+/// Percent-encode `path` for use in a URL, keeping `/` and the `:` of a
+/// drive letter so `#`, `?` and `%` in file names cannot change its meaning.
+fn encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+impl Default for PathHighlighter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Highlighter for PathHighlighter {
+    fn highlight(&self, text: &mut Text) {
+        // A path starts the text or follows whitespace, a quote, a bracket
+        // or `=`, and does not end with sentence punctuation.
+        static RE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                r#"(?:^|[\s'"(\[=])(?P<path>(?:/|[A-Za-z]:\\)[^\s:'"()\[\]<>,;]*[^\s:'"()\[\]<>,;.])(?::(?P<line>\d+)(?::(?P<column>\d+))?)?"#,
+            )
+            .unwrap()
+        });
+        let plain = text.plain().to_string();
+        for captures in RE.captures_iter(&plain) {
+            let path = captures.name("path").expect("path group always matches");
+            let line = captures.name("line").map(|m| m.as_str());
+            let column = captures.name("column").map(|m| m.as_str());
+            let end = captures.get(0).map_or(path.end(), |m| m.end());
+            let url = self.url(path.as_str(), line, column);
+            let start = plain[..path.start()].chars().count();
+            let end = plain[..end].chars().count();
+            text.stylize(
+                self.style.clone() + Style::with_link(&url),
+                start,
+                Some(end),
+            );
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -592,7 +797,7 @@ mod tests {
 
     #[test]
     fn test_repr_highlighter_default() {
-        let hl = ReprHighlighter::default();
+        let hl = ReprHighlighter;
         let text = hl.apply("42");
         assert_eq!(text.plain(), "42");
     }
@@ -629,6 +834,41 @@ mod tests {
         assert!(!text.spans().is_empty());
     }
 
+    #[test]
+    fn test_repr_rust_number_literals() {
+        let hl = ReprHighlighter::new();
+        let number = DEFAULT_STYLES.get("repr.number").unwrap();
+        for literal in [
+            "0b1010",
+            "0o755",
+            "1_000_000",
+            "255u8",
+            "1.5f32",
+            "0xFF_u32",
+        ] {
+            let text = hl.apply(&format!("x {} y", literal));
+            let plain = text.plain();
+            assert!(
+                text.spans()
+                    .iter()
+                    .any(|s| span_text(plain, s) == literal && &s.style == number),
+                "expected a number span for {}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn test_repr_strings_with_escaped_quotes() {
+        let hl = ReprHighlighter::new();
+        let text = hl.apply(r#"msg: "say \"hi\"" done"#);
+        let plain = text.plain();
+        assert!(text
+            .spans()
+            .iter()
+            .any(|s| span_text(plain, s) == r#""say \"hi\"""#));
+    }
+
     #[test]
     fn test_repr_urls() {
         let hl = ReprHighlighter::new();
@@ -730,7 +970,7 @@ mod tests {
 
     #[test]
     fn test_json_highlighter_default() {
-        let hl = JSONHighlighter::default();
+        let hl = JSONHighlighter;
         let text = hl.apply("[]");
         assert_eq!(text.plain(), "[]");
     }
@@ -835,7 +1075,7 @@ mod tests {
 
     #[test]
     fn test_iso8601_highlighter_default() {
-        let hl = ISO8601Highlighter::default();
+        let hl = ISO8601Highlighter;
         let text = hl.apply("12:30");
         assert_eq!(text.plain(), "12:30");
     }
@@ -1128,4 +1368,136 @@ mod tests {
         let text = hl.apply(".foo.bar");
         assert!(!text.spans().is_empty());
     }
+
+    // -- LogColorizer -------------------------------------------------------
+
+    #[test]
+    fn test_log_colorizer_levels() {
+        let text = LogColorizer::new().apply("2024-05-01 12:00:01 ERROR disk full");
+        let plain = text.plain();
+        let error_style = DEFAULT_STYLES.get("logging.level.error").unwrap();
+        assert!(text
+            .spans()
+            .iter()
+            .any(|s| span_text(plain, s) == "ERROR" && &s.style == error_style));
+    }
+
+    #[test]
+    fn test_log_colorizer_timestamp() {
+        let text = LogColorizer::new().apply("[10:15:30] Info: started");
+        let plain = text.plain();
+        let time_style = DEFAULT_STYLES.get("log.time").unwrap();
+        assert!(text
+            .spans()
+            .iter()
+            .any(|s| span_text(plain, s) == "10:15:30" && &s.style == time_style));
+        let info_style = DEFAULT_STYLES.get("logging.level.info").unwrap();
+        assert!(text
+            .spans()
+            .iter()
+            .any(|s| span_text(plain, s) == "Info" && &s.style == info_style));
+    }
+
+    #[test]
+    fn test_log_colorizer_ignores_lowercase_prose() {
+        let text = LogColorizer::new().apply("no error occurred");
+        let error_style = DEFAULT_STYLES.get("logging.level.error").unwrap();
+        assert!(text.spans().iter().all(|s| &s.style != error_style));
+    }
+
+    // -- PathHighlighter ------------------------------------------------------
+
+    fn links(text: &Text) -> Vec<(String, String)> {
+        let plain = text.plain();
+        text.spans()
+            .iter()
+            .filter_map(|span| {
+                span.style
+                    .link()
+                    .map(|url| (span_text(plain, span).to_string(), url.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_path_highlighter_file_urls() {
+        let text = PathHighlighter::new().apply("wrote /tmp/out.txt and /var/log/app.log.");
+        assert_eq!(
+            links(&text),
+            vec![
+                (
+                    "/tmp/out.txt".to_string(),
+                    "file:///tmp/out.txt".to_string()
+                ),
+                (
+                    "/var/log/app.log".to_string(),
+                    "file:///var/log/app.log".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_highlighter_line_and_column() {
+        let text = PathHighlighter::vscode().apply("  --> /src/lib.rs:10:4\n(/src/a.rs:7)");
+        assert_eq!(
+            links(&text),
+            vec![
+                (
+                    "/src/lib.rs:10:4".to_string(),
+                    "vscode://file/src/lib.rs:10:4".to_string()
+                ),
+                (
+                    "/src/a.rs:7".to_string(),
+                    "vscode://file/src/a.rs:7:1".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_highlighter_windows_paths() {
+        let text = PathHighlighter::new().apply(r"see C:\work\main.rs:3");
+        assert_eq!(
+            links(&text),
+            vec![(
+                r"C:\work\main.rs:3".to_string(),
+                "file:///C:/work/main.rs".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_path_highlighter_encodes_paths() {
+        let text = PathHighlighter::new().apply("/tmp/50%/a#1?.rs /srv/café");
+        assert_eq!(
+            links(&text),
+            vec![
+                (
+                    "/tmp/50%/a#1?.rs".to_string(),
+                    "file:///tmp/50%25/a%231%3F.rs".to_string()
+                ),
+                ("/srv/café".to_string(), "file:///srv/caf%C3%A9".to_string()),
+            ]
+        );
+        let hl = PathHighlighter::new().with_url_template("idea://open?file={path}&line={line}");
+        assert_eq!(
+            hl.url("/a b/x&y.rs", Some("3"), None),
+            "idea://open?file=/a%20b/x%26y.rs&line=3"
+        );
+    }
+
+    #[test]
+    fn test_path_highlighter_ignores_relative_paths_and_urls() {
+        let text = PathHighlighter::new().apply("src/main.rs https://example.com/a/b a/b");
+        assert!(links(&text).is_empty());
+    }
+
+    #[test]
+    fn test_path_highlighter_keeps_style() {
+        let bold = Style::parse("bold").unwrap();
+        let text = PathHighlighter::new().with_style(bold).apply("/etc/hosts");
+        assert_eq!(text.spans()[0].style.bold(), Some(true));
+        assert_eq!(text.spans()[0].style.link(), Some("file:///etc/hosts"));
+    }
 }
@@ -19,15 +19,145 @@
 //! let output = console.end_capture();
 //! assert!(output.contains("Vec"));
 //! ```
+//!
+//! Types can implement [`InspectInfo`] to add field docs, method signatures
+//! and other metadata to the panel, standing in for the reflection Python's
+//! `rich.inspect(obj, methods=True)` relies on.
 
 use crate::console::{Console, ConsoleOptions, Renderable};
 use crate::highlighter::{Highlighter, ReprHighlighter};
+use crate::measure::Measurement;
 use crate::panel::Panel;
+use crate::pretty::DebugFormatter;
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::Text;
+use crate::utils::cells::cell_len;
 use std::fmt;
 
+// ---------------------------------------------------------------------------
+// InspectInfo
+// ---------------------------------------------------------------------------
+
+/// A field listed by [`InspectInfo::fields`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Field name.
+    pub name: String,
+    /// Field type, as written in the source.
+    pub type_name: String,
+    /// Field documentation.
+    pub doc: Option<String>,
+}
+
+impl FieldInfo {
+    /// Describe a field called `name` of type `type_name`.
+    pub fn new(name: &str, type_name: &str) -> Self {
+        FieldInfo {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            doc: None,
+        }
+    }
+
+    /// Set the field documentation.
+    #[must_use]
+    pub fn with_doc(mut self, doc: &str) -> Self {
+        self.doc = Some(doc.to_string());
+        self
+    }
+}
+
+/// A method listed by [`InspectInfo::methods`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodInfo {
+    /// Method signature, e.g. `fn area(&self) -> f64`.
+    pub signature: String,
+    /// Method documentation.
+    pub doc: Option<String>,
+}
+
+impl MethodInfo {
+    /// Describe a method by its signature.
+    pub fn new(signature: &str) -> Self {
+        MethodInfo {
+            signature: signature.to_string(),
+            doc: None,
+        }
+    }
+
+    /// Set the method documentation.
+    #[must_use]
+    pub fn with_doc(mut self, doc: &str) -> Self {
+        self.doc = Some(doc.to_string());
+        self
+    }
+}
+
+/// Opt-in reflection for [`Inspect`].
+///
+/// Rust has no runtime reflection, so types that want more than their
+/// `Debug` output in an inspect panel describe themselves here. Every method
+/// has an empty default; implement only the parts you have. With the
+/// `derive` feature, `#[derive(DeriveInspectInfo)]` fills in the doc and
+/// fields from the struct's doc comments.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::inspect::{FieldInfo, Inspect, InspectInfo, MethodInfo};
+///
+/// #[derive(Debug)]
+/// struct Circle {
+///     radius: f64,
+/// }
+///
+/// impl InspectInfo for Circle {
+///     fn doc(&self) -> Option<String> {
+///         Some("A circle centred on the origin.".into())
+///     }
+///     fn fields(&self) -> Vec<FieldInfo> {
+///         vec![FieldInfo::new("radius", "f64").with_doc("Distance from the centre.")]
+///     }
+///     fn methods(&self) -> Vec<MethodInfo> {
+///         vec![MethodInfo::new("fn area(&self) -> f64")]
+///     }
+///     fn metadata(&self) -> Vec<(String, String)> {
+///         vec![("area".into(), format!("{:.2}", std::f64::consts::PI * self.radius.powi(2)))]
+///     }
+/// }
+///
+/// let circle = Circle { radius: 1.0 };
+/// let output = format!("{}", Inspect::from_info(&circle).with_methods(true));
+/// assert!(output.contains("A circle centred on the origin."));
+/// assert!(output.contains("radius: f64"));
+/// assert!(output.contains("fn area(&self) -> f64"));
+/// assert!(output.contains("area = 3.14"));
+/// ```
+pub trait InspectInfo {
+    /// Documentation for the value's type.
+    fn doc(&self) -> Option<String> {
+        None
+    }
+
+    /// The value's fields.
+    fn fields(&self) -> Vec<FieldInfo> {
+        Vec::new()
+    }
+
+    /// The methods callable on the value. Only shown when the inspect
+    /// widget is built with [`Inspect::with_methods`].
+    fn methods(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    /// Extra `key = value` facts about the value, such as a length or a
+    /// computed property.
+    fn metadata(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Inspect
 // ---------------------------------------------------------------------------
@@ -39,6 +169,8 @@ use std::fmt;
 /// - The Debug representation (syntax highlighted)
 /// - Optional documentation string
 /// - Optional value label
+/// - Fields, methods and metadata from [`InspectInfo`], when built with
+///   [`Inspect::from_info`]
 pub struct Inspect<'a> {
     /// The value to inspect (as a Debug reference).
     value: &'a dyn fmt::Debug,
@@ -52,6 +184,14 @@ pub struct Inspect<'a> {
     pretty: bool,
     /// Title for the panel.
     title: Option<String>,
+    /// Deepest container shown in full when pretty-printing.
+    max_depth: Option<usize>,
+    /// Maximum number of items shown per container when pretty-printing.
+    max_length: Option<usize>,
+    /// Reflection hooks supplied by the value, if any.
+    info: Option<&'a dyn InspectInfo>,
+    /// Whether to list the methods from [`InspectInfo::methods`].
+    methods: bool,
 }
 
 impl<'a> Inspect<'a> {
@@ -66,9 +206,25 @@ impl<'a> Inspect<'a> {
             doc: None,
             pretty: true,
             title: None,
+            max_depth: None,
+            max_length: None,
+            info: None,
+            methods: false,
         }
     }
 
+    /// Create an Inspect widget for a value that describes itself through
+    /// [`InspectInfo`].
+    ///
+    /// The panel lists the value's fields and metadata, and its methods when
+    /// [`with_methods`](Self::with_methods) is enabled. The type's doc is
+    /// shown unless [`with_doc`](Self::with_doc) overrides it.
+    pub fn from_info<T: fmt::Debug + InspectInfo + 'static>(value: &'a T) -> Self {
+        let mut inspect = Self::new(value);
+        inspect.info = Some(value);
+        inspect
+    }
+
     /// Set a label for the inspected value.
     #[must_use]
     pub fn with_label(mut self, label: &str) -> Self {
@@ -90,6 +246,27 @@ impl<'a> Inspect<'a> {
         self
     }
 
+    /// Fold containers nested deeper than `depth` when pretty-printing.
+    #[must_use]
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Show at most `max_length` items per container when pretty-printing.
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set whether to list methods from [`InspectInfo::methods`].
+    #[must_use]
+    pub fn with_methods(mut self, methods: bool) -> Self {
+        self.methods = methods;
+        self
+    }
+
     /// Set a custom title for the panel.
     #[must_use]
     pub fn with_title(mut self, title: &str) -> Self {
@@ -106,8 +283,9 @@ impl<'a> Inspect<'a> {
         prefix.rsplit("::").next().unwrap_or(full)
     }
 
-    /// Build the text content for the panel.
-    fn build_content(&self) -> Text {
+    /// Build the text content for the panel, laying out the value to fit
+    /// `width` columns.
+    fn build_content(&self, width: usize) -> Text {
         let mut parts = Vec::new();
 
         // Type name header
@@ -122,7 +300,12 @@ impl<'a> Inspect<'a> {
         }
 
         // Documentation
-        if let Some(doc) = &self.doc {
+        let doc = self.doc.clone().or_else(|| {
+            self.info
+                .and_then(|info| info.doc())
+                .map(|doc| crate::markup::escape(&doc))
+        });
+        if let Some(doc) = doc {
             parts.push(format!(
                 "[bold cyan]Doc:[/bold cyan] [dim italic]{}[/dim italic]",
                 doc
@@ -142,7 +325,10 @@ impl<'a> Inspect<'a> {
 
         // Debug representation
         let debug_str = if self.pretty {
-            format!("{:#?}", self.value)
+            let mut formatter = DebugFormatter::new().with_max_width(width);
+            formatter.max_depth = self.max_depth;
+            formatter.max_length = self.max_length;
+            formatter.format(self.value)
         } else {
             format!("{:?}", self.value)
         };
@@ -153,26 +339,97 @@ impl<'a> Inspect<'a> {
         highlighter.highlight(&mut debug_text);
         text.append_text(&debug_text);
 
+        if let Some(info) = self.info {
+            self.append_info(&mut text, info);
+        }
+
         text
     }
+
+    /// Append the fields, metadata and (if enabled) methods of `info`.
+    fn append_info(&self, text: &mut Text, info: &dyn InspectInfo) {
+        let header = Style::parse("bold cyan").unwrap_or_else(|_| Style::null());
+        let name_style = Style::parse("bold").unwrap_or_else(|_| Style::null());
+        let type_style = Style::parse("italic green").unwrap_or_else(|_| Style::null());
+        let doc_style = Style::parse("dim italic").unwrap_or_else(|_| Style::null());
+        let highlighter = ReprHighlighter::new();
+
+        let fields = info.fields();
+        if !fields.is_empty() {
+            text.append_str("\n\nFields:", Some(header.clone()));
+            let width = |f: &FieldInfo| cell_len(&f.name) + cell_len(&f.type_name);
+            let column = fields.iter().map(width).max().unwrap_or(0);
+            for field in &fields {
+                text.append_str("\n  ", None);
+                text.append_str(&field.name, Some(name_style.clone()));
+                text.append_str(": ", None);
+                text.append_str(&field.type_name, Some(type_style.clone()));
+                if let Some(doc) = &field.doc {
+                    let pad = column - width(field) + 2;
+                    text.append_str(&" ".repeat(pad), None);
+                    text.append_str(doc, Some(doc_style.clone()));
+                }
+            }
+        }
+
+        let metadata = info.metadata();
+        if !metadata.is_empty() {
+            text.append_str("\n\nMetadata:", Some(header.clone()));
+            for (key, value) in &metadata {
+                text.append_str("\n  ", None);
+                text.append_str(key, Some(name_style.clone()));
+                text.append_str(" = ", None);
+                text.append_text(&highlighter.apply(value));
+            }
+        }
+
+        let methods = if self.methods {
+            info.methods()
+        } else {
+            Vec::new()
+        };
+        if !methods.is_empty() {
+            text.append_str("\n\nMethods:", Some(header));
+            for method in &methods {
+                text.append_str("\n  ", None);
+                text.append_text(&highlighter.apply(&method.signature));
+                if let Some(doc) = &method.doc {
+                    text.append_str("\n      ", None);
+                    text.append_str(doc, Some(doc_style.clone()));
+                }
+            }
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Renderable
 // ---------------------------------------------------------------------------
 
-impl Renderable for Inspect<'_> {
-    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
-        let content = self.build_content();
+impl Inspect<'_> {
+    /// Build the panel shown for the given available width.
+    fn panel(&self, width: usize) -> Panel {
+        // Leave room for the panel's borders and horizontal padding.
+        let content = self.build_content(width.saturating_sub(4));
         let mut panel = Panel::new(content);
 
         let title_str = self
             .title
             .clone()
             .unwrap_or_else(|| format!("Inspect: {}", self.short_type_name()));
-        panel.title = Some(Text::new(&title_str, Style::null()));
+        panel.title = Some(Text::new(&title_str, Style::null()).into());
+        panel
+    }
 
-        panel.gilt_console(console, options)
+    /// Measure the minimum and maximum width requirements.
+    pub fn measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        self.panel(options.max_width).measure(console, options)
+    }
+}
+
+impl Renderable for Inspect<'_> {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.panel(options.max_width).gilt_console(console, options)
     }
 }
 
@@ -265,6 +522,7 @@ mod tests {
 
     // -- 3. Inspect a struct -------------------------------------------------
 
+    #[allow(dead_code)]
     #[derive(Debug)]
     struct TestPoint {
         x: f64,
@@ -549,4 +807,98 @@ mod tests {
         // The full type name should contain the full path
         assert!(inspect.type_name.contains("String"));
     }
+
+    #[test]
+    fn test_inspect_short_containers_stay_inline() {
+        let data = vec![vec![1, 2], vec![3, 4]];
+        let output = capture_inspect(&Inspect::new(&data));
+        assert!(output.contains("[[1, 2], [3, 4]]"), "{}", output);
+    }
+
+    #[test]
+    fn test_inspect_max_depth_and_length() {
+        let data: Vec<Vec<u32>> = vec![(0..50).collect(); 5];
+        let inspect = Inspect::new(&data).with_max_depth(1).with_max_length(2);
+        let output = capture_inspect(&inspect);
+        assert!(
+            output.contains("[[\u{2026}], [\u{2026}], \u{2026} 3 more]"),
+            "{}",
+            output
+        );
+    }
+
+    // -- InspectInfo ----------------------------------------------------------
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Account {
+        owner: String,
+        balance: i64,
+    }
+
+    impl InspectInfo for Account {
+        fn doc(&self) -> Option<String> {
+            Some("A [ledger] account.".into())
+        }
+
+        fn fields(&self) -> Vec<FieldInfo> {
+            vec![
+                FieldInfo::new("owner", "String").with_doc("Account holder."),
+                FieldInfo::new("balance", "i64"),
+            ]
+        }
+
+        fn methods(&self) -> Vec<MethodInfo> {
+            vec![MethodInfo::new("fn deposit(&mut self, amount: i64)").with_doc("Add funds.")]
+        }
+
+        fn metadata(&self) -> Vec<(String, String)> {
+            vec![("overdrawn".into(), (self.balance < 0).to_string())]
+        }
+    }
+
+    fn account() -> Account {
+        Account {
+            owner: "ada".into(),
+            balance: -5,
+        }
+    }
+
+    #[test]
+    fn test_from_info_lists_fields_and_metadata() {
+        let account = account();
+        let output = capture_inspect(&Inspect::from_info(&account));
+        assert!(output.contains("Doc: A [ledger] account."), "{}", output);
+        assert!(output.contains("Fields:"), "{}", output);
+        assert!(
+            output.contains("owner: String  Account holder."),
+            "{}",
+            output
+        );
+        assert!(output.contains("balance: i64"), "{}", output);
+        assert!(output.contains("overdrawn = false") || output.contains("overdrawn = true"));
+        // Methods are opt-in.
+        assert!(!output.contains("Methods:"), "{}", output);
+    }
+
+    #[test]
+    fn test_from_info_with_methods() {
+        let account = account();
+        let output = capture_inspect(&Inspect::from_info(&account).with_methods(true));
+        assert!(output.contains("Methods:"), "{}", output);
+        assert!(
+            output.contains("fn deposit(&mut self, amount: i64)"),
+            "{}",
+            output
+        );
+        assert!(output.contains("Add funds."), "{}", output);
+    }
+
+    #[test]
+    fn test_with_doc_overrides_info_doc() {
+        let account = account();
+        let output = capture_inspect(&Inspect::from_info(&account).with_doc("Custom"));
+        assert!(output.contains("Doc: Custom"), "{}", output);
+        assert!(!output.contains("ledger"), "{}", output);
+    }
 }
@@ -7,7 +7,9 @@ pub mod align_widget;
 pub mod ansi;
 pub mod bar;
 pub mod box_chars;
+pub mod capabilities;
 pub mod cells;
+pub mod clock;
 pub mod constrain;
 pub mod containers;
 pub mod control;
@@ -17,6 +19,8 @@ pub mod emoji;
 pub mod emoji_codes;
 pub mod emoji_replace;
 pub mod filesize;
+pub mod format_context;
+pub mod glyphs;
 pub mod group;
 pub mod highlighter;
 pub mod inspect;
@@ -26,6 +30,7 @@ pub mod protocol;
 pub mod ratio;
 pub mod region;
 pub mod scope;
+pub mod shadow;
 pub mod styled;
 pub mod styled_str;
 
@@ -39,19 +44,22 @@ pub use box_chars::{
     SIMPLE_HEAD, SIMPLE_HEAVY, SQUARE, SQUARE_DOUBLE_HEAD,
 };
 pub use cells::{cell_len, get_character_cell_size, set_cell_size};
+pub use clock::{Clock, ManualClock, SystemClock};
 pub use constrain::Constrain;
 pub use control::{escape_control_codes, strip_control_codes, Control};
-pub use default_styles::DEFAULT_STYLES;
+pub use default_styles::{DEFAULT_GLYPHS, DEFAULT_STYLES};
 pub use diagnose::{
     print_report, report, ColorSupport, DiagnosticReport, PlatformInfo, TerminalInfo,
     UnicodeSupport,
 };
 pub use emoji::{Emoji, NoEmoji};
 pub use filesize::{binary, decimal, pick_unit_and_suffix};
+pub use format_context::{FormatContext, TimeFormat, UnitSystem};
+pub use glyphs::Glyphs;
 pub use group::Group;
 pub use highlighter::{
-    Highlighter, ISO8601Highlighter, JSONHighlighter, NullHighlighter, RegexHighlighter,
-    ReprHighlighter,
+    Highlighter, ISO8601Highlighter, JSONHighlighter, LogColorizer, NullHighlighter,
+    RegexHighlighter, ReprHighlighter,
 };
 pub use inspect::Inspect;
 pub use padding::Padding;
@@ -61,5 +69,6 @@ pub use protocol::{
 pub use ratio::{ratio_distribute, ratio_reduce, ratio_resolve, Edge};
 pub use region::Region;
 pub use scope::Scope;
+pub use shadow::Shadow;
 pub use styled::Styled;
 pub use styled_str::{StyledStr, Stylize};
@@ -34,11 +34,45 @@ impl PaddingDimensions {
     }
 }
 
+impl From<usize> for PaddingDimensions {
+    fn from(pad: usize) -> Self {
+        PaddingDimensions::Uniform(pad)
+    }
+}
+
+impl From<(usize, usize)> for PaddingDimensions {
+    fn from((vertical, horizontal): (usize, usize)) -> Self {
+        PaddingDimensions::Pair(vertical, horizontal)
+    }
+}
+
+impl From<(usize, usize, usize, usize)> for PaddingDimensions {
+    fn from((top, right, bottom, left): (usize, usize, usize, usize)) -> Self {
+        PaddingDimensions::Full(top, right, bottom, left)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Padding
 // ---------------------------------------------------------------------------
 
 /// A renderable that adds whitespace padding around `Text` content.
+///
+/// Left and right padding may instead be given as percentages of the
+/// available width (see [`Padding::percent`]), so the margins grow and
+/// shrink with the terminal.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::padding::Padding;
+/// use gilt::text::Text;
+///
+/// // A 10% left margin and a 30% right margin.
+/// let padded = Padding::percent(Text::from("Hi"), 10, 30);
+/// assert_eq!(padded.horizontal(40), (4, 12));
+/// assert_eq!(padded.horizontal(120), (12, 36));
+/// ```
 #[derive(Debug, Clone)]
 pub struct Padding {
     /// The inner content to pad.
@@ -55,6 +89,10 @@ pub struct Padding {
     pub style: Style,
     /// If true, expand to fill the available width.
     pub expand: bool,
+    /// Left and right padding as percentages of the available width.
+    ///
+    /// When set, this takes the place of `left` and `right`.
+    percent: Option<(usize, usize)>,
 }
 
 impl Padding {
@@ -69,6 +107,33 @@ impl Padding {
             left,
             style,
             expand,
+            percent: None,
+        }
+    }
+
+    /// Create padding whose left and right sides are percentages of the
+    /// available width.
+    pub fn percent(content: Text, left: usize, right: usize) -> Self {
+        Padding::new(content, PaddingDimensions::Uniform(0), Style::null(), true)
+            .with_percent(left, right)
+    }
+
+    /// Builder: set left and right padding as percentages of the available
+    /// width.
+    ///
+    /// The two sides are capped so that together they never exceed 100%.
+    #[must_use]
+    pub fn with_percent(mut self, left: usize, right: usize) -> Self {
+        let left = left.min(100);
+        self.percent = Some((left, right.min(100 - left)));
+        self
+    }
+
+    /// Left and right padding in cells for the given available width.
+    pub fn horizontal(&self, available: usize) -> (usize, usize) {
+        match self.percent {
+            Some((left, right)) => (available * left / 100, available * right / 100),
+            None => (self.left, self.right),
         }
     }
 
@@ -84,45 +149,44 @@ impl Padding {
 
     /// Measure the minimum and maximum width requirements.
     pub fn measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
-        let max_width = options.max_width.saturating_sub(self.left + self.right);
+        let (left, right) = self.horizontal(options.max_width);
+        let max_width = options.max_width.saturating_sub(left + right);
         let inner_opts = options.update_width(max_width.max(1));
         // For Text, measure is the cell_len of the content
         let content_width = self.content.cell_len();
-        let min_w = content_width + self.left + self.right;
+        let min_w = content_width + left + right;
         let max_w = if self.expand {
             options.max_width
         } else {
             min_w.min(options.max_width)
         };
-        Measurement::new(
-            min_w.min(inner_opts.max_width + self.left + self.right),
-            max_w,
-        )
+        Measurement::new(min_w.min(inner_opts.max_width + left + right), max_w)
     }
 }
 
 impl Renderable for Padding {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
         let mut segments = Vec::new();
+        let (left, right) = self.horizontal(options.max_width);
 
         // Compute the total available width
         let width = if self.expand {
             options.max_width
         } else {
             let content_width = self.content.cell_len();
-            (content_width + self.left + self.right).min(options.max_width)
+            (content_width + left + right).min(options.max_width)
         };
 
         // Compute inner width for the content
-        let inner_width = width.saturating_sub(self.left + self.right).max(1);
+        let inner_width = width.saturating_sub(left + right).max(1);
 
         // Render the content into lines
         let inner_opts = options.update_width(inner_width);
         let lines = console.render_lines(&self.content, Some(&inner_opts), None, true, false);
 
         // Left/right padding strings
-        let left_pad = " ".repeat(self.left);
-        let right_pad_base = self.right;
+        let left_pad = " ".repeat(left);
+        let right_pad_base = right;
 
         // Top blank lines
         let blank_line = " ".repeat(width);
@@ -134,7 +198,7 @@ impl Renderable for Padding {
         // Content lines with left/right padding
         for line in &lines {
             // Left padding
-            if self.left > 0 {
+            if left > 0 {
                 segments.push(Segment::styled(&left_pad, self.style.clone()));
             }
 
@@ -142,7 +206,7 @@ impl Renderable for Padding {
             segments.extend(line.iter().cloned());
 
             // Right padding -- fill remaining space to reach full width
-            let line_len = self.left + Segment::get_line_length(line);
+            let line_len = left + Segment::get_line_length(line);
             let remaining = width.saturating_sub(line_len);
             if remaining > 0 {
                 segments.push(Segment::styled(&" ".repeat(remaining), self.style.clone()));
@@ -192,6 +256,19 @@ mod tests {
         assert_eq!(pd.unpack(), (1, 2, 3, 4));
     }
 
+    #[test]
+    fn test_from_tuples() {
+        assert_eq!(PaddingDimensions::from(2), PaddingDimensions::Uniform(2));
+        assert_eq!(
+            PaddingDimensions::from((1, 3)),
+            PaddingDimensions::Pair(1, 3)
+        );
+        assert_eq!(
+            PaddingDimensions::from((1, 2, 3, 4)),
+            PaddingDimensions::Full(1, 2, 3, 4)
+        );
+    }
+
     #[test]
     fn test_unpack_uniform_zero() {
         let pd = PaddingDimensions::Uniform(0);
@@ -384,4 +461,44 @@ mod tests {
         assert_eq!(PaddingDimensions::Pair(1, 2), PaddingDimensions::Pair(1, 2));
         assert_ne!(PaddingDimensions::Pair(1, 2), PaddingDimensions::Pair(2, 1));
     }
+
+    // -- Percentage padding -------------------------------------------------
+
+    #[test]
+    fn test_percent_horizontal() {
+        let p = Padding::percent(Text::new("x", Style::null()), 25, 10);
+        assert_eq!(p.percent, Some((25, 10)));
+        assert_eq!(p.horizontal(80), (20, 8));
+        assert_eq!(p.horizontal(10), (2, 1));
+        // Fixed sides are ignored while percentages are set
+        assert_eq!((p.left, p.right), (0, 0));
+    }
+
+    #[test]
+    fn test_percent_capped_at_100() {
+        let p = Padding::percent(Text::new("x", Style::null()), 70, 60);
+        assert_eq!(p.percent, Some((70, 30)));
+    }
+
+    #[test]
+    fn test_render_percent_padding() {
+        let console = Console::builder().width(20).markup(false).build();
+        let p = Padding::percent(Text::new("abcdefghij", Style::null()), 20, 30);
+        let opts = console.options();
+        let output: String = p
+            .gilt_console(&console, &opts)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        // 4 cells left, 6 right, leaving 10 for the content
+        assert_eq!(output, "    abcdefghij      \n");
+    }
+
+    #[test]
+    fn test_measure_percent_padding() {
+        let console = Console::builder().width(50).build();
+        let p = Padding::percent(Text::new("Hi", Style::null()), 10, 10);
+        let m = p.measure(&console, &console.options());
+        assert_eq!(m.minimum, 12);
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! Provides the [`Pretty`] renderable widget that pretty-prints strings,
 //! `Debug` values, and `serde_json::Value` objects with syntax highlighting
-//! and optional indent guides.
+//! and optional indent guides, and [`DebugFormatter`], which lays out `Debug`
+//! output compactly with depth and length limits.
 //!
 //! Rust port of Python's `rich/pretty.py`, adapted to use `Debug` and
 //! `serde_json` instead of Python's runtime introspection.
@@ -15,6 +16,7 @@ use crate::measure::Measurement;
 use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::{OverflowMethod, Text};
+use crate::utils::cells::cell_len;
 
 // ---------------------------------------------------------------------------
 // Pretty
@@ -26,6 +28,24 @@ use crate::text::{OverflowMethod, Text};
 /// `Pretty` wraps a [`Text`] object and can be constructed from plain strings,
 /// `Debug` values, or `serde_json::Value` instances. Each constructor applies
 /// the appropriate highlighter automatically.
+///
+/// [`Pretty::new`] keeps the value's `Debug` output and lays it out when
+/// rendered, like rich's `pretty.pprint`: containers stay on one line while
+/// they fit the console width and are expanded otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::pretty::Pretty;
+///
+/// let rows: Vec<Vec<u32>> = (0..3).map(|i| vec![i; 2]).collect();
+/// let pretty = Pretty::new(&rows).with_max_length(2).with_indent_guides(false);
+/// assert_eq!(format!("{pretty}"), "[[0, 0], [1, 1], … 1 more]");
+/// assert_eq!(
+///     format!("{pretty:16}"),
+///     "[\n    [0, 0],\n    [1, 1],\n    … 1 more,\n]"
+/// );
+/// ```
 #[derive(Clone, Debug)]
 pub struct Pretty {
     /// The underlying styled text.
@@ -38,6 +58,9 @@ pub struct Pretty {
     pub indent_guides: bool,
     /// Number of spaces per indent level (default 4).
     pub indent_size: usize,
+    /// Deepest container shown in full by [`Pretty::new`]; deeper ones are
+    /// folded. `None` means no limit.
+    pub max_depth: Option<usize>,
     /// Maximum number of elements shown in a container (array/object).
     /// `None` means show all elements.
     pub max_length: Option<usize>,
@@ -49,11 +72,44 @@ pub struct Pretty {
     pub expand_all: bool,
     /// When `true`, prepend the type name (e.g. `"String"`, `"Object"`) to the output.
     pub type_annotation: bool,
+    /// Compact `Debug` output captured by [`Pretty::new`], and whether it
+    /// was cut short, laid out again for the console width on render.
+    debug_source: Option<(String, bool)>,
 }
 
 impl Pretty {
     // -- Constructors -------------------------------------------------------
 
+    /// Create a `Pretty` from any value implementing [`std::fmt::Debug`],
+    /// laid out for the console width when rendered.
+    ///
+    /// Containers are only expanded to one item per line when they do not
+    /// fit, and the [`with_max_depth`](Self::with_max_depth),
+    /// [`with_max_length`](Self::with_max_length) and
+    /// [`with_max_string`](Self::with_max_string) limits apply.
+    pub fn new<T: std::fmt::Debug + ?Sized>(value: &T) -> Self {
+        use std::fmt::Write;
+
+        let mut writer = GuardedWriter::default();
+        // An error here means the guard cut the output short.
+        let _ = write!(writer, "{:?}", value);
+        let mut pretty = Pretty {
+            text: Text::empty(),
+            no_wrap: false,
+            overflow: None,
+            indent_guides: true,
+            indent_size: 4,
+            max_depth: None,
+            max_length: None,
+            max_string: None,
+            expand_all: false,
+            type_annotation: false,
+            debug_source: Some((writer.buf, writer.truncated)),
+        };
+        pretty.text = pretty.layout_debug(DebugFormatter::default().max_width);
+        pretty
+    }
+
     /// Create a `Pretty` from a plain string.
     ///
     /// Applies [`ReprHighlighter`] to the text and enables indent guides.
@@ -67,10 +123,12 @@ impl Pretty {
             overflow: None,
             indent_guides: true,
             indent_size: 4,
+            max_depth: None,
             max_length: None,
             max_string: None,
             expand_all: false,
             type_annotation: false,
+            debug_source: None,
         }
     }
 
@@ -88,10 +146,35 @@ impl Pretty {
             overflow: None,
             indent_guides: true,
             indent_size: 4,
+            max_depth: None,
             max_length: None,
             max_string: None,
             expand_all: false,
             type_annotation: false,
+            debug_source: None,
+        }
+    }
+
+    /// Create a `Pretty` from a `Debug` value laid out by a [`DebugFormatter`].
+    ///
+    /// Short containers stay on one line and deep or long ones are folded
+    /// according to the formatter's limits.
+    pub fn from_debug_with<T: std::fmt::Debug + ?Sized>(
+        value: &T,
+        formatter: &DebugFormatter,
+    ) -> Self {
+        Pretty {
+            text: formatter.highlight(value),
+            no_wrap: false,
+            overflow: None,
+            indent_guides: true,
+            indent_size: formatter.indent_size,
+            max_depth: formatter.max_depth,
+            max_length: formatter.max_length,
+            max_string: formatter.max_string,
+            expand_all: false,
+            type_annotation: false,
+            debug_source: None,
         }
     }
 
@@ -111,10 +194,12 @@ impl Pretty {
             overflow: None,
             indent_guides: true,
             indent_size: 2, // JSON convention: 2-space indent
+            max_depth: None,
             max_length: None,
             max_string: None,
             expand_all: false,
             type_annotation: false,
+            debug_source: None,
         }
     }
 
@@ -131,7 +216,7 @@ impl Pretty {
     #[must_use]
     pub fn with_indent_size(mut self, size: usize) -> Self {
         self.indent_size = size;
-        self
+        self.relayout()
     }
 
     /// Set whether word-wrapping is disabled.
@@ -148,6 +233,15 @@ impl Pretty {
         self
     }
 
+    /// Fold containers nested deeper than `depth`.
+    ///
+    /// Applies to values created with [`Pretty::new`].
+    #[must_use]
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self.relayout()
+    }
+
     /// Set the maximum number of elements shown in a container.
     ///
     /// When set, arrays and objects in JSON (or collection items in Debug
@@ -156,7 +250,7 @@ impl Pretty {
     #[must_use]
     pub fn with_max_length(mut self, max_length: usize) -> Self {
         self.max_length = Some(max_length);
-        self
+        self.relayout()
     }
 
     /// Set the maximum length of string values before truncation.
@@ -166,7 +260,7 @@ impl Pretty {
     #[must_use]
     pub fn with_max_string(mut self, max_string: usize) -> Self {
         self.max_string = Some(max_string);
-        self
+        self.relayout()
     }
 
     /// Set whether containers are always expanded (one item per line).
@@ -176,7 +270,7 @@ impl Pretty {
     #[must_use]
     pub fn with_expand_all(mut self, expand_all: bool) -> Self {
         self.expand_all = expand_all;
-        self
+        self.relayout()
     }
 
     /// Set whether a type annotation is prepended to the output.
@@ -229,6 +323,33 @@ impl Pretty {
         self
     }
 
+    // -- Debug layout -------------------------------------------------------
+
+    /// Lay out the `Debug` output captured by [`Pretty::new`] for `width`
+    /// columns and highlight it.
+    fn layout_debug(&self, width: usize) -> Text {
+        let Some((debug, truncated)) = &self.debug_source else {
+            return self.text.clone();
+        };
+        let formatter = DebugFormatter {
+            indent_size: self.indent_size,
+            // A zero width breaks every container that can be broken.
+            max_width: if self.expand_all { 0 } else { width },
+            max_depth: self.max_depth,
+            max_length: self.max_length,
+            max_string: self.max_string,
+        };
+        ReprHighlighter::new().apply(&formatter.layout(debug, *truncated))
+    }
+
+    /// Refresh `text` after a layout setting changed.
+    fn relayout(mut self) -> Self {
+        if self.debug_source.is_some() {
+            self.text = self.layout_debug(DebugFormatter::default().max_width);
+        }
+        self
+    }
+
     // -- Indent guides ------------------------------------------------------
 
     /// Apply indent guides to the underlying text.
@@ -236,14 +357,13 @@ impl Pretty {
     /// For each line, leading spaces are inspected. At every `indent_size`
     /// boundary within the leading whitespace, the space character is replaced
     /// with a vertical bar (`│`) styled with dim text.
-    fn apply_indent_guides(&self) -> Text {
+    fn apply_indent_guides(&self, text: Text) -> Text {
         if !self.indent_guides {
-            return self.text.clone();
+            return text;
         }
 
         let guide_style = Style::parse("dim green").unwrap_or_else(|_| Style::null());
-        self.text
-            .with_indent_guides(Some(self.indent_size), '\u{2502}', guide_style)
+        text.with_indent_guides(Some(self.indent_size), '\u{2502}', guide_style)
     }
 
     // -- Measurement --------------------------------------------------------
@@ -258,7 +378,12 @@ impl Pretty {
 
 impl Renderable for Pretty {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
-        let mut text = self.apply_indent_guides();
+        let laid_out = if self.debug_source.is_some() {
+            self.layout_debug(options.max_width)
+        } else {
+            self.text.clone()
+        };
+        let mut text = self.apply_indent_guides(laid_out);
 
         if self.no_wrap {
             text.no_wrap = Some(true);
@@ -674,6 +799,458 @@ fn truncate_multiline_collection(lines: &[&str], max_length: usize) -> String {
     result.join("\n")
 }
 
+// ---------------------------------------------------------------------------
+// DebugFormatter
+// ---------------------------------------------------------------------------
+
+/// Nesting depth at which Debug output is treated as cyclic and cut off.
+const DEBUG_DEPTH_LIMIT: usize = 256;
+
+/// Size in bytes at which Debug output is cut off.
+const DEBUG_SIZE_LIMIT: usize = 1 << 20;
+
+/// Marker for folded or elided content.
+const FOLD: &str = "\u{2026}";
+
+/// A depth-limited pretty-printer for `Debug` output.
+///
+/// Unlike `{:#?}`, which puts every field on its own line, the formatter
+/// parses the compact `{:?}` output into a tree and only breaks a container
+/// across lines when it does not fit in `max_width`. Containers nested deeper
+/// than `max_depth` are folded to `{ … }`, collections longer than
+/// `max_length` end in `… N more`, and strings longer than `max_string` are
+/// truncated.
+///
+/// Values whose `Debug` output nests more than 256 levels deep or exceeds
+/// 1 MiB -- typically reference cycles through `Rc<RefCell<_>>` -- are cut
+/// off with `…` instead of recursing until the stack overflows.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::pretty::DebugFormatter;
+///
+/// let values: Vec<u32> = (1..=1000).collect();
+/// let formatter = DebugFormatter::new().with_max_length(2);
+/// assert_eq!(formatter.format(&values), "[1, 2, … 998 more]");
+///
+/// let nested = vec![vec![vec![1]], vec![vec![2]]];
+/// let formatter = DebugFormatter::new().with_max_depth(2);
+/// assert_eq!(formatter.format(&nested), "[[[…]], [[…]]]");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DebugFormatter {
+    /// Number of spaces per indent level (default 4).
+    pub indent_size: usize,
+    /// Width a container must fit in to stay on one line (default 80).
+    pub max_width: usize,
+    /// Deepest container shown in full; deeper ones are folded.
+    /// `None` means no limit.
+    pub max_depth: Option<usize>,
+    /// Maximum number of items shown per container. `None` means all.
+    pub max_length: Option<usize>,
+    /// Maximum length of string literals. `None` means full strings.
+    pub max_string: Option<usize>,
+}
+
+impl Default for DebugFormatter {
+    fn default() -> Self {
+        DebugFormatter {
+            indent_size: 4,
+            max_width: 80,
+            max_depth: None,
+            max_length: None,
+            max_string: None,
+        }
+    }
+}
+
+impl DebugFormatter {
+    /// Create a formatter with no depth, length or string limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of spaces per indent level.
+    #[must_use]
+    pub fn with_indent_size(mut self, size: usize) -> Self {
+        self.indent_size = size;
+        self
+    }
+
+    /// Set the width a container must fit in to stay on one line.
+    #[must_use]
+    pub fn with_max_width(mut self, width: usize) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Fold containers nested deeper than `depth`.
+    #[must_use]
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Show at most `max_length` items per container.
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Truncate string literals longer than `max_string` characters.
+    #[must_use]
+    pub fn with_max_string(mut self, max_string: usize) -> Self {
+        self.max_string = Some(max_string);
+        self
+    }
+
+    /// Format a value's `Debug` output.
+    pub fn format<T: std::fmt::Debug + ?Sized>(&self, value: &T) -> String {
+        use std::fmt::Write;
+
+        let mut writer = GuardedWriter::default();
+        // An error here means the guard cut the output short; the parser
+        // closes whatever was left open.
+        let _ = write!(writer, "{:?}", value);
+        self.layout(&writer.buf, writer.truncated)
+    }
+
+    /// Re-format an existing `Debug` string, in either `{:?}` or `{:#?}` form.
+    pub fn format_str(&self, debug: &str) -> String {
+        self.layout(debug, false)
+    }
+
+    /// Format a value's `Debug` output and apply [`ReprHighlighter`].
+    pub fn highlight<T: std::fmt::Debug + ?Sized>(&self, value: &T) -> Text {
+        ReprHighlighter::new().apply(&self.format(value))
+    }
+
+    /// Parse `debug` into items and lay them out.
+    fn layout(&self, debug: &str, mut truncated: bool) -> String {
+        let chars: Vec<char> = debug.chars().collect();
+        let mut pos = 0;
+        let items = parse_debug_items(&chars, &mut pos, None, &mut truncated);
+        let mut out = String::new();
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            self.write_item(item, 0, 0, &mut out);
+        }
+        if let Some(max_string) = self.max_string {
+            out = truncate_debug_strings(&out, max_string);
+        }
+        out
+    }
+
+    /// Write `item` at `indent`, breaking it across lines if it does not fit.
+    fn write_item(&self, item: &[DebugPiece], indent: usize, depth: usize, out: &mut String) {
+        let flat = self.flat_item(item, depth);
+        let column = indent * self.indent_size;
+        // Leave room for the trailing comma of an expanded parent.
+        if column + cell_len(&flat) < self.max_width || !item_has_open_group(item, depth, self) {
+            out.push_str(&flat);
+            return;
+        }
+        for piece in item {
+            match piece {
+                DebugPiece::Text(text) => out.push_str(text),
+                DebugPiece::Group {
+                    open,
+                    close,
+                    named,
+                    items,
+                } => {
+                    if items.is_empty() || self.is_folded(depth + 1) {
+                        out.push_str(&self.flat_group(*open, *close, *named, items, depth + 1));
+                        continue;
+                    }
+                    let pad = " ".repeat((indent + 1) * self.indent_size);
+                    out.push(*open);
+                    out.push('\n');
+                    let (visible, more) = self.visible_items(items, *named);
+                    for child in visible {
+                        out.push_str(&pad);
+                        self.write_item(child, indent + 1, depth + 1, out);
+                        out.push_str(",\n");
+                    }
+                    if let Some(more) = more {
+                        out.push_str(&pad);
+                        out.push_str(&more);
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&" ".repeat(column));
+                    out.push(*close);
+                }
+            }
+        }
+    }
+
+    /// Render `item` on a single line.
+    fn flat_item(&self, item: &[DebugPiece], depth: usize) -> String {
+        let mut out = String::new();
+        for piece in item {
+            match piece {
+                DebugPiece::Text(text) => out.push_str(text),
+                DebugPiece::Group {
+                    open,
+                    close,
+                    named,
+                    items,
+                } => {
+                    out.push_str(&self.flat_group(*open, *close, *named, items, depth + 1));
+                }
+            }
+        }
+        out
+    }
+
+    /// Render a container on a single line, folding it if it is too deep.
+    fn flat_group(
+        &self,
+        open: char,
+        close: char,
+        named: bool,
+        items: &[Vec<DebugPiece>],
+        depth: usize,
+    ) -> String {
+        if items.is_empty() {
+            return format!("{open}{close}");
+        }
+        let inner = if self.is_folded(depth) {
+            FOLD.to_string()
+        } else {
+            let (visible, more) = self.visible_items(items, named);
+            visible
+                .iter()
+                .map(|item| self.flat_item(item, depth))
+                .chain(more)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if open == '{' {
+            format!("{{ {inner} }}")
+        } else {
+            format!("{open}{inner}{close}")
+        }
+    }
+
+    /// Whether containers at `depth` are folded by `max_depth`.
+    fn is_folded(&self, depth: usize) -> bool {
+        self.max_depth.is_some_and(|max| depth > max)
+    }
+
+    /// The items of a container after applying `max_length`. Struct fields
+    /// (`named` containers) are never folded.
+    /// Returns the items to show and, when some are hidden, the `… N more`
+    /// marker.
+    fn visible_items<'a>(
+        &self,
+        items: &'a [Vec<DebugPiece>],
+        named: bool,
+    ) -> (&'a [Vec<DebugPiece>], Option<String>) {
+        match self.max_length {
+            Some(max) if !named && items.len() > max => (
+                &items[..max],
+                Some(format!("{FOLD} {} more", items.len() - max)),
+            ),
+            _ => (items, None),
+        }
+    }
+}
+
+/// Whether `item` contains a container that could be broken across lines.
+fn item_has_open_group(item: &[DebugPiece], depth: usize, formatter: &DebugFormatter) -> bool {
+    item.iter().any(|piece| match piece {
+        DebugPiece::Group { items, .. } => !items.is_empty() && !formatter.is_folded(depth + 1),
+        DebugPiece::Text(_) => false,
+    })
+}
+
+/// A run of `Debug` output: plain text or a bracketed container.
+#[derive(Clone, Debug)]
+enum DebugPiece {
+    Text(String),
+    Group {
+        open: char,
+        close: char,
+        /// Whether the container follows a type name (a struct, tuple
+        /// struct or enum variant) rather than being a bare collection.
+        named: bool,
+        items: Vec<Vec<DebugPiece>>,
+    },
+}
+
+/// Parse comma-separated items up to the closing bracket (or end of input).
+///
+/// Each item is a sequence of text runs and nested containers. When the
+/// input was cut short (`truncated`), the innermost container left open
+/// gets a trailing `…` item and the flag is cleared.
+fn parse_debug_items(
+    chars: &[char],
+    pos: &mut usize,
+    close: Option<char>,
+    truncated: &mut bool,
+) -> Vec<Vec<DebugPiece>> {
+    let mut items: Vec<Vec<DebugPiece>> = Vec::new();
+    let mut item: Vec<DebugPiece> = Vec::new();
+    let mut text = String::new();
+    let mut closed = false;
+
+    while *pos < chars.len() {
+        let ch = chars[*pos];
+        match ch {
+            '"' | '\'' => copy_literal(chars, pos, &mut text),
+            '(' | '[' | '{' => {
+                *pos += 1;
+                let closer = match ch {
+                    '(' => ')',
+                    '[' => ']',
+                    _ => '}',
+                };
+                flush_text(&mut text, &mut item);
+                let named = matches!(
+                    item.last(),
+                    Some(DebugPiece::Text(prev)) if prev
+                        .trim_end()
+                        .ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                );
+                let nested = parse_debug_items(chars, pos, Some(closer), truncated);
+                item.push(DebugPiece::Group {
+                    open: ch,
+                    close: closer,
+                    named,
+                    items: nested,
+                });
+            }
+            ')' | ']' | '}' => {
+                *pos += 1;
+                if close.is_some() {
+                    closed = true;
+                    break;
+                }
+                text.push(ch);
+            }
+            ',' => {
+                *pos += 1;
+                flush_text(&mut text, &mut item);
+                push_item(&mut items, std::mem::take(&mut item));
+            }
+            '\n' => {
+                // `{:#?}` line breaks and indentation are re-created on layout.
+                *pos += 1;
+                while *pos < chars.len() && chars[*pos] == ' ' {
+                    *pos += 1;
+                }
+            }
+            _ => {
+                *pos += 1;
+                text.push(ch);
+            }
+        }
+    }
+
+    flush_text(&mut text, &mut item);
+    push_item(&mut items, item);
+    if *truncated && !closed {
+        items.push(vec![DebugPiece::Text(FOLD.to_string())]);
+        *truncated = false;
+    }
+    items
+}
+
+/// Copy a string or char literal starting at `pos`, escapes included.
+fn copy_literal(chars: &[char], pos: &mut usize, text: &mut String) {
+    let quote = chars[*pos];
+    text.push(quote);
+    *pos += 1;
+    while *pos < chars.len() {
+        let ch = chars[*pos];
+        text.push(ch);
+        *pos += 1;
+        if ch == '\\' && *pos < chars.len() {
+            text.push(chars[*pos]);
+            *pos += 1;
+        } else if ch == quote {
+            break;
+        }
+    }
+}
+
+/// Move pending text into `item`, dropping surrounding whitespace at item
+/// boundaries.
+fn flush_text(text: &mut String, item: &mut Vec<DebugPiece>) {
+    let taken = std::mem::take(text);
+    let trimmed = if item.is_empty() {
+        taken.trim_start()
+    } else {
+        taken.as_str()
+    };
+    if !trimmed.is_empty() {
+        item.push(DebugPiece::Text(trimmed.to_string()));
+    }
+}
+
+/// Push a finished item, trimming trailing whitespace and skipping empty
+/// items left by trailing commas.
+fn push_item(items: &mut Vec<Vec<DebugPiece>>, mut item: Vec<DebugPiece>) {
+    if let Some(DebugPiece::Text(text)) = item.last_mut() {
+        let trimmed = text.trim_end().to_string();
+        if trimmed.is_empty() {
+            item.pop();
+        } else {
+            *text = trimmed;
+        }
+    }
+    if !item.is_empty() {
+        items.push(item);
+    }
+}
+
+/// A `fmt::Write` sink that stops `Debug` formatting once the output nests
+/// too deeply or grows too large.
+#[derive(Default)]
+struct GuardedWriter {
+    buf: String,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    truncated: bool,
+}
+
+impl std::fmt::Write for GuardedWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        for ch in s.chars() {
+            // A bracket or quote right after `'` is a char literal.
+            let after_quote = self.buf.ends_with('\'');
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if ch == '\\' {
+                    self.escaped = true;
+                } else if ch == '"' {
+                    self.in_string = false;
+                }
+            } else if !after_quote {
+                match ch {
+                    '"' => self.in_string = true,
+                    '(' | '[' | '{' => self.depth += 1,
+                    ')' | ']' | '}' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            if self.depth > DEBUG_DEPTH_LIMIT || self.buf.len() >= DEBUG_SIZE_LIMIT {
+                self.truncated = true;
+                return Err(std::fmt::Error);
+            }
+            self.buf.push(ch);
+        }
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Display
 // ---------------------------------------------------------------------------
@@ -748,6 +1325,7 @@ mod tests {
 
     #[test]
     fn test_from_debug_struct() {
+        #[allow(dead_code)]
         #[derive(Debug)]
         struct Foo {
             x: i32,
@@ -837,7 +1415,7 @@ mod tests {
     fn test_indent_guides_applied() {
         let input = "root\n    child\n        grandchild";
         let pretty = Pretty::from_str(input).with_indent_size(4);
-        let guided = pretty.apply_indent_guides();
+        let guided = pretty.apply_indent_guides(pretty.text.clone());
         let plain = guided.plain().to_string();
         // Indent guides should insert the vertical bar character
         assert!(
@@ -851,7 +1429,7 @@ mod tests {
     fn test_indent_guides_custom_size() {
         let input = "root\n  child\n    grandchild";
         let pretty = Pretty::from_str(input).with_indent_size(2);
-        let guided = pretty.apply_indent_guides();
+        let guided = pretty.apply_indent_guides(pretty.text.clone());
         let plain = guided.plain().to_string();
         assert!(
             plain.contains('\u{2502}'),
@@ -864,7 +1442,7 @@ mod tests {
     fn test_indent_guides_disabled() {
         let input = "root\n    child\n        grandchild";
         let pretty = Pretty::from_str(input).with_indent_guides(false);
-        let guided = pretty.apply_indent_guides();
+        let guided = pretty.apply_indent_guides(pretty.text.clone());
         let plain = guided.plain().to_string();
         // No indent guide characters should be present
         assert!(
@@ -878,7 +1456,7 @@ mod tests {
     fn test_indent_guides_no_indentation() {
         let input = "line one\nline two\nline three";
         let pretty = Pretty::from_str(input);
-        let guided = pretty.apply_indent_guides();
+        let guided = pretty.apply_indent_guides(pretty.text.clone());
         let plain = guided.plain().to_string();
         // No leading spaces, so no guides
         assert!(
@@ -892,7 +1470,7 @@ mod tests {
     fn test_indent_guides_multi_level() {
         let input = "a\n    b\n        c\n            d";
         let pretty = Pretty::from_str(input).with_indent_size(4);
-        let guided = pretty.apply_indent_guides();
+        let guided = pretty.apply_indent_guides(pretty.text.clone());
         let lines: Vec<&str> = guided.plain().lines().collect();
         // Line "    b" should have 1 guide at position 0
         assert_eq!(
@@ -1301,6 +1879,7 @@ mod tests {
 
     #[test]
     fn test_rebuild_debug_max_string() {
+        #[allow(dead_code)]
         #[derive(Debug)]
         struct Data {
             name: String,
@@ -1463,6 +2042,7 @@ mod tests {
     fn test_type_annotation_for_debug_struct() {
         let console = make_console();
         let opts = console.options();
+        #[allow(dead_code)]
         #[derive(Debug)]
         struct Foo {
             x: i32,
@@ -1521,4 +2101,134 @@ mod tests {
     fn test_infer_type_name_struct() {
         assert_eq!(super::infer_type_name("Foo {\n    x: 42\n}"), "struct");
     }
+
+    // -- DebugFormatter tests -------------------------------------------------
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Config {
+        name: String,
+        ports: Vec<u16>,
+        limits: (u32, u32),
+    }
+
+    fn config() -> Config {
+        Config {
+            name: "server".to_string(),
+            ports: vec![80, 443],
+            limits: (10, 20),
+        }
+    }
+
+    #[test]
+    fn test_debug_formatter_fits_on_one_line() {
+        let out = DebugFormatter::new().format(&config());
+        assert_eq!(
+            out,
+            "Config { name: \"server\", ports: [80, 443], limits: (10, 20) }"
+        );
+    }
+
+    #[test]
+    fn test_debug_formatter_breaks_when_too_wide() {
+        let out = DebugFormatter::new().with_max_width(30).format(&config());
+        assert_eq!(
+            out,
+            "Config {\n    name: \"server\",\n    ports: [80, 443],\n    limits: (10, 20),\n}"
+        );
+    }
+
+    #[test]
+    fn test_debug_formatter_reformats_alternate_output() {
+        let pretty = format!("{:#?}", config());
+        let out = DebugFormatter::new().format_str(&pretty);
+        assert_eq!(out, DebugFormatter::new().format(&config()));
+    }
+
+    #[test]
+    fn test_debug_formatter_folds_depth_and_length() {
+        let out = DebugFormatter::new().with_max_depth(0).format(&config());
+        assert_eq!(out, "Config { … }");
+
+        let out = DebugFormatter::new().with_max_depth(1).format(&config());
+        assert_eq!(out, "Config { name: \"server\", ports: […], limits: (…) }");
+
+        let out = DebugFormatter::new().with_max_length(1).format(&config());
+        assert!(out.contains("ports: [80, … 1 more]"), "{out}");
+    }
+
+    #[test]
+    fn test_debug_formatter_literals_are_opaque() {
+        let value = vec!["a, [b]".to_string()];
+        assert_eq!(DebugFormatter::new().format(&value), "[\"a, [b]\"]");
+        let chars = vec!['(', '"', ','];
+        assert_eq!(DebugFormatter::new().format(&chars), "['(', '\"', ',']");
+    }
+
+    #[test]
+    fn test_debug_formatter_max_string() {
+        let out = DebugFormatter::new()
+            .with_max_string(3)
+            .format(&vec!["abcdef"]);
+        assert_eq!(out, "[\"abc+3\"]");
+    }
+
+    #[test]
+    fn test_debug_formatter_cuts_off_cycles() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        struct Node {
+            next: Option<Rc<RefCell<Node>>>,
+        }
+
+        let node = Rc::new(RefCell::new(Node { next: None }));
+        node.borrow_mut().next = Some(Rc::clone(&node));
+        let out = DebugFormatter::new().with_max_depth(3).format(&node);
+        assert_eq!(out, "RefCell { value: Node { next: Some(RefCell { … }) } }");
+
+        // Without a depth limit the guard cuts the output off.
+        let out = DebugFormatter::new().format(&node);
+        assert!(out.contains("\u{2026}"), "{out}");
+        // Break the cycle so the test does not leak.
+        node.borrow_mut().next = None;
+    }
+
+    #[test]
+    fn test_from_debug_with_highlights() {
+        let pretty = Pretty::from_debug_with(&vec![1, 2], &DebugFormatter::new());
+        assert_eq!(pretty.text.plain(), "[1, 2]");
+        assert!(!pretty.text.spans().is_empty());
+    }
+
+    #[test]
+    fn test_new_lays_out_for_console_width() {
+        let pretty = Pretty::new(&config());
+        assert_eq!(
+            format!("{pretty}"),
+            "Config { name: \"server\", ports: [80, 443], limits: (10, 20) }"
+        );
+        assert_eq!(
+            format!("{pretty:30}"),
+            "Config {\n│   name: \"server\",\n│   ports: [80, 443],\n│   limits: (10, 20),\n}"
+        );
+    }
+
+    #[test]
+    fn test_new_applies_limits() {
+        let pretty = Pretty::new(&config()).with_max_depth(1).with_max_string(3);
+        assert_eq!(
+            pretty.text.plain(),
+            "Config { name: \"ser+3\", ports: […], limits: (…) }"
+        );
+        let pretty = Pretty::new(&vec![vec![1, 2], vec![3]])
+            .with_indent_guides(false)
+            .with_expand_all(true);
+        assert_eq!(
+            format!("{pretty}"),
+            "[\n    [\n        1,\n        2,\n    ],\n    [\n        3,\n    ],\n]"
+        );
+    }
 }
@@ -0,0 +1,216 @@
+//! Shadow widget -- draws a one-cell drop shadow below and to the right of content.
+//!
+//! The shadow is drawn with block characters in the `"shadow"` theme style,
+//! offset one cell right and one row down, which gives panels and tables a
+//! sense of elevation in dashboard layouts.
+
+use crate::console::{Console, ConsoleOptions, Renderable};
+use crate::segment::Segment;
+use crate::style::Style;
+
+/// Character used for the vertical shadow edge (right of the content).
+const SHADOW_RIGHT: &str = "█";
+/// Character used for the horizontal shadow edge (below the content).
+const SHADOW_BOTTOM: &str = "▀";
+
+// ---------------------------------------------------------------------------
+// Shadow
+// ---------------------------------------------------------------------------
+
+/// A renderable that casts a one-cell drop shadow around its content.
+///
+/// The content is rendered one cell narrower than the available width so the
+/// shadow column always fits. Unless overridden with [`Shadow::with_style`],
+/// the shadow uses the `"shadow"` style from the console theme.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::prelude::*;
+/// use gilt::shadow::Shadow;
+///
+/// let panel = Panel::fit(Text::new("Elevated", Style::null()));
+/// let shadowed = Shadow::new(panel);
+/// let output = format!("{}", shadowed);
+/// assert!(output.contains("▀"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Shadow<R: Renderable> {
+    /// The content casting the shadow.
+    pub renderable: R,
+    /// Explicit shadow style; `None` resolves the `"shadow"` theme style.
+    pub style: Option<Style>,
+}
+
+impl<R: Renderable> Shadow<R> {
+    /// Wrap `renderable` with a drop shadow.
+    pub fn new(renderable: R) -> Self {
+        Shadow {
+            renderable,
+            style: None,
+        }
+    }
+
+    /// Override the shadow style instead of using the theme.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl<R: Renderable> Renderable for Shadow<R> {
+    fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        let style = self.style.clone().unwrap_or_else(|| shadow_style(console));
+        render_with_shadow(options, &style, |opts| {
+            self.renderable.gilt_console(console, opts)
+        })
+    }
+}
+
+impl<R: Renderable> std::fmt::Display for Shadow<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut console = Console::builder()
+            .width(f.width().unwrap_or(80))
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(self);
+        let output = console.end_capture();
+        write!(f, "{}", output.trim_end_matches('\n'))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Resolve the `"shadow"` style from the console theme.
+pub(crate) fn shadow_style(console: &Console) -> Style {
+    console
+        .get_style("shadow")
+        .unwrap_or_else(|_| Style::null())
+}
+
+/// Render content one cell narrower than `options` allows and decorate the
+/// result with a drop shadow.
+///
+/// The first line gets a blank cell on the right (the shadow is offset one
+/// row down), every following line gets a shadow cell, and a final line of
+/// half-blocks is added beneath, offset one cell to the right.
+pub(crate) fn render_with_shadow<F>(
+    options: &ConsoleOptions,
+    style: &Style,
+    render: F,
+) -> Vec<Segment>
+where
+    F: FnOnce(&ConsoleOptions) -> Vec<Segment>,
+{
+    if options.max_width < 2 {
+        return render(options);
+    }
+    let inner_width = options.max_width - 1;
+    let segments = render(&options.update_width(inner_width));
+    let lines = Segment::split_and_crop_lines(&segments, inner_width, None, false, false);
+    if lines.is_empty() {
+        return segments;
+    }
+
+    let (width, _) = Segment::get_shape(&lines);
+    let lines = Segment::set_shape(&lines, width, None, None, false);
+
+    let mut result = Vec::new();
+    for (index, line) in lines.into_iter().enumerate() {
+        result.extend(line);
+        if index == 0 {
+            result.push(Segment::text(" "));
+        } else {
+            result.push(Segment::styled(SHADOW_RIGHT, style.clone()));
+        }
+        result.push(Segment::line());
+    }
+    result.push(Segment::text(" "));
+    result.push(Segment::styled(&SHADOW_BOTTOM.repeat(width), style.clone()));
+    result.push(Segment::line());
+    result
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panel::Panel;
+    use crate::text::Text;
+    use crate::utils::cells::cell_len;
+
+    fn render_plain(renderable: &dyn Renderable, width: usize) -> String {
+        let mut console = Console::builder()
+            .width(width)
+            .force_terminal(true)
+            .no_color(true)
+            .build();
+        console.begin_capture();
+        console.print(renderable);
+        console.end_capture()
+    }
+
+    #[test]
+    fn test_shadow_adds_right_and_bottom_edges() {
+        let shadow = Shadow::new(Text::new("ab\ncd", Style::null()));
+        let output = render_plain(&shadow, 20);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "ab ");
+        assert_eq!(lines[1], "cd█");
+        assert_eq!(lines[2], " ▀▀");
+    }
+
+    #[test]
+    fn test_shadow_pads_ragged_lines() {
+        let shadow = Shadow::new(Text::new("abc\nd", Style::null()));
+        let output = render_plain(&shadow, 20);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], "d  █");
+        assert_eq!(lines[2], " ▀▀▀");
+    }
+
+    #[test]
+    fn test_shadow_fits_within_width() {
+        let shadow = Shadow::new(Panel::new(Text::new("hello", Style::null())));
+        let output = render_plain(&shadow, 30);
+        for line in output.lines() {
+            assert_eq!(cell_len(line), 30);
+        }
+    }
+
+    #[test]
+    fn test_shadow_uses_theme_style() {
+        let console = Console::builder().width(20).build();
+        let shadow = Shadow::new(Text::new("x\ny", Style::null()));
+        let segments = shadow.gilt_console(&console, &console.options());
+        let edge = segments.iter().find(|s| s.text == SHADOW_RIGHT).unwrap();
+        assert_eq!(edge.style, Some(shadow_style(&console)));
+    }
+
+    #[test]
+    fn test_shadow_with_style_override() {
+        let console = Console::builder().width(20).build();
+        let style = Style::parse("red").unwrap();
+        let shadow = Shadow::new(Text::new("x\ny", Style::null())).with_style(style.clone());
+        let segments = shadow.gilt_console(&console, &console.options());
+        let edge = segments.iter().find(|s| s.text == SHADOW_BOTTOM).unwrap();
+        assert_eq!(edge.style, Some(style));
+    }
+
+    #[test]
+    fn test_shadow_too_narrow_passes_through() {
+        let console = Console::builder().width(1).build();
+        let shadow = Shadow::new(Text::new("x", Style::null()));
+        let segments = shadow.gilt_console(&console, &console.options());
+        assert!(segments.iter().all(|s| s.text != SHADOW_BOTTOM));
+    }
+}
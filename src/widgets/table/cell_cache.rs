@@ -0,0 +1,159 @@
+//! Render cache shared between identical cells of one table render.
+//!
+//! Status tables often repeat the same few values ("OK", "—") down a column.
+//! Within a render, a cell whose text, style and column width match a cell
+//! already rendered reuses that cell's lines instead of wrapping and
+//! highlighting the text again. Hits and misses are counted process-wide and
+//! reported by [`cell_cache_stats`].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::Text;
+
+static HITS: AtomicU64 = AtomicU64::new(0);
+static MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Counters for the table cell render cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellCacheStats {
+    /// Cells whose lines were reused from an identical cell.
+    pub hits: u64,
+    /// Cells that had to be rendered.
+    pub misses: u64,
+}
+
+impl CellCacheStats {
+    /// The fraction of cells served from the cache, from `0.0` to `1.0`.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Return the cell cache counters accumulated by every table render in
+/// this process.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::table::{cell_cache_stats, Table};
+///
+/// let before = cell_cache_stats();
+/// let mut table = Table::new(&["Service", "Status"]);
+/// for name in ["api", "db", "cache"] {
+///     table.add_row(&[name, "OK"]);
+/// }
+/// let _ = format!("{}", table);
+/// // The second and third "OK" cells reuse the first one's lines.
+/// assert!(cell_cache_stats().hits >= before.hits + 2);
+/// ```
+pub fn cell_cache_stats() -> CellCacheStats {
+    CellCacheStats {
+        hits: HITS.load(Ordering::Relaxed),
+        misses: MISSES.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset the cell cache counters to zero.
+pub fn reset_cell_cache_stats() {
+    HITS.store(0, Ordering::Relaxed);
+    MISSES.store(0, Ordering::Relaxed);
+}
+
+/// Cells rendered so far with the same column, width, style and plain text.
+type Bucket = Vec<(Text, Vec<Vec<Segment>>)>;
+
+/// Rendered lines of the cells seen during one table render.
+#[derive(Default)]
+pub(crate) struct CellCache {
+    entries: HashMap<(usize, usize, Style, String), Bucket>,
+}
+
+impl CellCache {
+    /// Return the lines for `text` in column `column` at `width` with
+    /// `style`, calling `render` only if no identical cell was rendered yet.
+    ///
+    /// The column index stands in for the column's justify, overflow and
+    /// highlight settings, which are the same for every cell in it.
+    pub(crate) fn get_or_render(
+        &mut self,
+        column: usize,
+        width: usize,
+        style: &Style,
+        text: &Text,
+        render: impl FnOnce() -> Vec<Vec<Segment>>,
+    ) -> Vec<Vec<Segment>> {
+        let bucket = self
+            .entries
+            .entry((column, width, style.clone(), text.plain().to_string()))
+            .or_default();
+        if let Some((_, lines)) = bucket.iter().find(|(seen, _)| same_cell(seen, text)) {
+            HITS.fetch_add(1, Ordering::Relaxed);
+            return lines.clone();
+        }
+        MISSES.fetch_add(1, Ordering::Relaxed);
+        let lines = render();
+        bucket.push((text.clone(), lines.clone()));
+        lines
+    }
+}
+
+/// Whether two cells with the same plain text render identically.
+fn same_cell(a: &Text, b: &Text) -> bool {
+    a == b
+        && a.style() == b.style()
+        && a.justify == b.justify
+        && a.overflow == b.overflow
+        && a.no_wrap == b.no_wrap
+        && a.end == b.end
+        && a.tab_size == b.tab_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_count(cache: &mut CellCache, text: &Text, width: usize) -> usize {
+        let mut calls = 0;
+        cache.get_or_render(0, width, &Style::null(), text, || {
+            calls += 1;
+            vec![vec![Segment::text(text.plain())]]
+        });
+        calls
+    }
+
+    #[test]
+    fn test_identical_cells_render_once() {
+        let mut cache = CellCache::default();
+        let ok = Text::new("OK", Style::null());
+        assert_eq!(render_count(&mut cache, &ok, 5), 1);
+        assert_eq!(render_count(&mut cache, &ok, 5), 0);
+    }
+
+    #[test]
+    fn test_width_and_spans_are_part_of_the_key() {
+        let mut cache = CellCache::default();
+        let ok = Text::new("OK", Style::null());
+        render_count(&mut cache, &ok, 5);
+        assert_eq!(render_count(&mut cache, &ok, 6), 1);
+
+        let mut bold = ok.clone();
+        bold.stylize(Style::parse("bold").unwrap(), 0, Some(2));
+        assert_eq!(render_count(&mut cache, &bold, 5), 1);
+        assert_eq!(render_count(&mut cache, &bold, 5), 0);
+    }
+
+    #[test]
+    fn test_hit_rate() {
+        let stats = CellCacheStats { hits: 3, misses: 1 };
+        assert_eq!(stats.hit_rate(), 0.75);
+        assert_eq!(CellCacheStats::default().hit_rate(), 0.0);
+    }
+}
@@ -124,3 +124,17 @@ pub struct ColumnOptions {
     /// Enable syntax highlighting, or `None` to inherit from the table.
     pub highlight: Option<bool>,
 }
+
+/// A super-header shared by adjacent columns, drawn in an extra header row
+/// above their own headers.
+///
+/// Created by [`Table::add_column_group`](crate::table::Table::add_column_group).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroup {
+    /// Group header text (may contain markup).
+    pub title: String,
+    /// Index of the first column in the group.
+    pub first: usize,
+    /// Number of columns in the group.
+    pub count: usize,
+}
@@ -10,9 +10,13 @@ use crate::text::{JustifyMethod, OverflowMethod, Text};
 use crate::utils::align_widget::VerticalAlign;
 use crate::utils::box_chars::{BoxChars, RowLevel, HEAVY_HEAD};
 use crate::utils::ratio::{ratio_distribute, ratio_reduce};
-use crate::widgets::table::{CellContent, Column, ColumnOptions, Row};
+use crate::widgets::table::cell_cache::CellCache;
+use crate::widgets::table::{
+    CellContent, Column, ColumnGroup, ColumnOptions, Row, SortOrder, WidthMemory,
+};
 
 /// A single cell in the table (internal).
+#[derive(Clone)]
 pub(crate) struct CellInfo {
     pub(crate) style: Style,
     pub(crate) renderable: Text,
@@ -39,6 +43,8 @@ pub struct Table {
     pub columns: Vec<Column>,
     /// Row metadata (one per data row, does not include header/footer).
     pub rows: Vec<Row>,
+    /// Super-headers drawn above groups of adjacent columns.
+    pub column_groups: Vec<ColumnGroup>,
     /// Optional title displayed above the table.
     pub title: Option<String>,
     /// Optional caption displayed below the table.
@@ -88,6 +94,13 @@ pub struct Table {
     pub caption_justify: JustifyMethod,
     /// Enable syntax highlighting for cell content.
     pub highlight: bool,
+    /// Column index and direction the rows are sorted by, shown as a
+    /// `▲`/`▼` indicator in that column's header.
+    pub sorted_by: Option<(usize, SortOrder)>,
+    /// If true, cast a one-cell drop shadow in the `"shadow"` theme style.
+    pub shadow: bool,
+    /// Store and id under which negotiated column widths are remembered.
+    pub(crate) width_memory: Option<(WidthMemory, String)>,
 }
 
 impl Table {
@@ -108,6 +121,7 @@ impl Table {
         let mut table = Table {
             columns: Vec::new(),
             rows: Vec::new(),
+            column_groups: Vec::new(),
             title: None,
             caption: None,
             width: None,
@@ -133,6 +147,9 @@ impl Table {
             title_justify: JustifyMethod::Center,
             caption_justify: JustifyMethod::Center,
             highlight: false,
+            sorted_by: None,
+            shadow: false,
+            width_memory: None,
         };
         for header in headers {
             table.add_column(header, "", Default::default());
@@ -158,6 +175,7 @@ impl Table {
         let mut table = Table {
             columns: Vec::new(),
             rows: Vec::new(),
+            column_groups: Vec::new(),
             title: None,
             caption: None,
             width: None,
@@ -183,6 +201,9 @@ impl Table {
             title_justify: JustifyMethod::Center,
             caption_justify: JustifyMethod::Center,
             highlight: false,
+            sorted_by: None,
+            shadow: false,
+            width_memory: None,
         };
         for header in headers {
             table.add_column(header, "", Default::default());
@@ -383,6 +404,13 @@ impl Table {
         self
     }
 
+    /// Set whether to draw a drop shadow around the table (builder pattern).
+    #[must_use]
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     /// Get extra width contributed by box borders (edge + column dividers).
     pub fn extra_width(&self) -> usize {
         let mut w = 0;
@@ -522,7 +550,12 @@ impl Table {
     }
 
     /// Add a row from [`CellContent`] values (internal workhorse).
-    fn add_row_contents(&mut self, cells: &[CellContent], style: Option<&str>, end_section: bool) {
+    pub(crate) fn add_row_contents(
+        &mut self,
+        cells: &[CellContent],
+        style: Option<&str>,
+        end_section: bool,
+    ) {
         let num_columns = self.columns.len();
         let num_cells = cells.len();
 
@@ -555,6 +588,7 @@ impl Table {
         self.rows.push(Row {
             style: style.map(|s| s.to_string()),
             end_section,
+            spans: Vec::new(),
         });
     }
 
@@ -628,7 +662,17 @@ impl Table {
         let mut max_widths: Vec<usize> = Vec::new();
 
         let cells = self.get_cells(console, column.index, column);
-        for cell in &cells {
+        let header_offset = usize::from(self.show_header);
+        let spanning: Vec<usize> = self
+            .multi_column_rows(column.index)
+            .into_iter()
+            .map(|row| row + header_offset)
+            .collect();
+        for (index, cell) in cells.iter().enumerate() {
+            // Cells spanning several columns are fitted by `widen_for_spans`.
+            if spanning.contains(&index) {
+                continue;
+            }
             let measurement = cell.renderable.measure();
             // Add padding width to the measurement
             min_widths.push(measurement.minimum + padding_width);
@@ -646,7 +690,12 @@ impl Table {
     }
 
     /// Get all cells for a column, including header/footer, with styles applied.
-    fn get_cells(&self, console: &Console, column_index: usize, column: &Column) -> Vec<CellInfo> {
+    pub(crate) fn get_cells(
+        &self,
+        console: &Console,
+        column_index: usize,
+        column: &Column,
+    ) -> Vec<CellInfo> {
         let mut cells = Vec::new();
 
         if self.show_header {
@@ -656,7 +705,12 @@ impl Table {
                 + console
                     .get_style(&column.header_style)
                     .unwrap_or_else(|_| Style::null());
-            let text = console.render_str(&column.header, None, None, None);
+            let mut text = console.render_str(&column.header, None, None, None);
+            if let Some((sorted_column, order)) = self.sorted_by {
+                if sorted_column == column_index {
+                    text.append_str(&format!(" {}", order.indicator()), None);
+                }
+            }
             cells.push(CellInfo {
                 style: header_style,
                 renderable: text,
@@ -667,6 +721,7 @@ impl Table {
         let cell_style = console
             .get_style(&column.style)
             .unwrap_or_else(|_| Style::null());
+        let body = cells.len()..cells.len() + column.cells.len();
         for cell_content in &column.cells {
             let text = cell_content.resolve(console);
             cells.push(CellInfo {
@@ -736,6 +791,14 @@ impl Table {
             }
         }
 
+        // The column style sits beneath the cell's own styles, padding
+        // included.
+        if !cell_style.is_null() {
+            for cell in &mut cells[body] {
+                cell.renderable.stylize_before(cell_style.clone(), 0, None);
+            }
+        }
+
         cells
     }
 
@@ -761,6 +824,7 @@ impl Table {
             .iter()
             .map(|r| if r.maximum > 0 { r.maximum } else { 1 })
             .collect();
+        self.widen_for_spans(console, &mut widths, usize::MAX);
 
         let extra_width = self.extra_width();
 
@@ -839,6 +903,7 @@ impl Table {
                 .iter()
                 .map(|r| if r.maximum > 0 { r.maximum } else { 0 })
                 .collect();
+            self.widen_for_spans(console, &mut widths, max_width);
         }
 
         table_width = widths.iter().sum();
@@ -862,6 +927,10 @@ impl Table {
             }
         }
 
+        if let Some((memory, id)) = &self.width_memory {
+            widths = memory.apply(id, widths, max_width);
+        }
+
         widths
     }
 
@@ -917,13 +986,14 @@ impl Table {
         widths
     }
 
-    /// The main rendering method. Produces segments for the table body (borders + cells).
+    /// The main rendering method. Produces segments for the table body (borders + cells),
+    /// along with the number of lines taken by the top edge and header rows.
     pub(crate) fn render_table(
         &self,
         console: &Console,
         options: &ConsoleOptions,
         widths: &[usize],
-    ) -> Vec<Segment> {
+    ) -> (Vec<Segment>, usize) {
         let mut segments: Vec<Segment> = Vec::new();
 
         let table_style = console
@@ -935,13 +1005,30 @@ impl Table {
                 .unwrap_or_else(|_| Style::null());
 
         // Build column cells (each column -> list of cells)
-        let column_cells: Vec<Vec<CellInfo>> = self
+        let mut column_cells: Vec<Vec<CellInfo>> = self
             .columns
             .iter()
             .enumerate()
             .map(|(i, col)| self.get_cells(console, i, col))
             .collect();
 
+        // Column groups add a header row on top. A column outside any group
+        // shows its own header there, spanning both header rows.
+        let groups_shown = self.shows_groups();
+        let mut grouped = vec![false; column_cells.len()];
+        if groups_shown {
+            let mut group_cells: Vec<Option<CellInfo>> = vec![None; column_cells.len()];
+            for (first, count, group) in self.visible_groups() {
+                group_cells[first] = Some(self.group_cell(console, first, count, group));
+                grouped[first..first + count].fill(true);
+            }
+            for (cells, group_cell) in column_cells.iter_mut().zip(group_cells) {
+                let cell = group_cell.unwrap_or_else(|| cells[0].clone());
+                cells.insert(0, cell);
+            }
+        }
+        let header_rows = usize::from(self.show_header) + usize::from(groups_shown);
+
         // Transpose to row_cells: each row -> list of cells (one per column)
         let num_rows = column_cells.iter().map(|c| c.len()).max().unwrap_or(0);
         let num_cols = column_cells.len();
@@ -999,77 +1086,122 @@ impl Table {
             ]
         });
 
-        // Top edge
-        if let Some(b) = the_box {
-            if show_edge {
-                segments.push(Segment::styled(&b.get_top(widths), border_style.clone()));
-                segments.push(new_line.clone());
+        // Which cell covers each position; spanning cells own several.
+        let mut grid = self.span_grid(header_rows, num_rows);
+        if groups_shown {
+            for (first, count, _) in self.visible_groups() {
+                grid.merge(0, first, count, 1);
+            }
+            for (col_index, _) in grouped.iter().enumerate().filter(|(_, &g)| !g) {
+                grid.merge(0, col_index, 1, 2);
             }
         }
+        let divider_width = usize::from(the_box.is_some());
+        let span_width = |col_index: usize, cols: usize| -> usize {
+            (col_index..col_index + cols)
+                .map(|c| widths.get(c).copied().unwrap_or(1))
+                .sum::<usize>()
+                + divider_width * cols.saturating_sub(1)
+        };
+        // Runs of columns covered by the same cell: (first column, count, owner).
+        let pieces = |row_index: usize| -> Vec<(usize, usize, (usize, usize))> {
+            let mut pieces: Vec<(usize, usize, (usize, usize))> = Vec::new();
+            for col_index in 0..num_cols {
+                let owner = grid.owner(row_index, col_index);
+                match pieces.last_mut() {
+                    Some((_, count, last_owner)) if *last_owner == owner => *count += 1,
+                    _ => pieces.push((col_index, 1, owner)),
+                }
+            }
+            pieces
+        };
+        let data_row = |row_index: usize| -> Option<usize> {
+            let header_row = row_index < header_rows;
+            let footer_row = row_index + 1 == num_rows && show_footer;
+            if header_row || footer_row {
+                return None;
+            }
+            let idx = row_index - header_rows;
+            (idx < self.rows.len()).then_some(idx)
+        };
 
-        // Iterate over rows
-        for row_index in 0..num_rows {
-            let first = row_index == 0;
-            let last = row_index == num_rows - 1;
-
-            let header_row = first && show_header;
-            let footer_row = last && show_footer;
-
-            // Determine the data row index (for style lookup)
-            let data_row_index = if header_row || footer_row {
-                None
-            } else {
-                let idx = if show_header {
-                    row_index - 1
-                } else {
-                    row_index
-                };
-                if idx < self.rows.len() {
-                    Some(idx)
-                } else {
-                    None
+        let row_styles: Vec<Style> = (0..num_rows)
+            .map(|row_index| match data_row(row_index) {
+                Some(idx) => {
+                    let style_obj = self.get_row_style(console, idx);
+                    console
+                        .get_style(&style_obj.to_string())
+                        .unwrap_or(style_obj)
                 }
-            };
+                None => Style::null(),
+            })
+            .collect();
 
-            let row_style = if header_row || footer_row {
-                Style::null()
-            } else if let Some(idx) = data_row_index {
-                let style_obj = self.get_row_style(console, idx);
-                console
-                    .get_style(&style_obj.to_string())
-                    .unwrap_or(style_obj)
-            } else {
-                Style::null()
-            };
+        // Separator lines drawn below each row (header, inter-row lines /
+        // leading / end_section, and the footer separator).
+        let separators: Vec<Vec<RowLevel>> = (0..num_rows)
+            .map(|row_index| {
+                let mut levels = Vec::new();
+                if the_box.is_none() {
+                    return levels;
+                }
+                let header_row = row_index < header_rows;
+                if header_row {
+                    // Group titles are underlined within the header.
+                    levels.push(if row_index + 1 == header_rows {
+                        RowLevel::Head
+                    } else {
+                        RowLevel::Row
+                    });
+                }
+                let end_section = data_row(row_index)
+                    .and_then(|idx| self.rows.get(idx))
+                    .is_some_and(|r| r.end_section);
+                // No row line after the header, the last row or before the footer.
+                let skip = header_row
+                    || row_index + 1 == num_rows
+                    || (show_footer && row_index + 2 >= num_rows);
+                if (show_lines || leading > 0 || end_section) && !skip {
+                    if leading > 0 {
+                        levels.extend(std::iter::repeat_n(RowLevel::Mid, leading));
+                    } else {
+                        levels.push(RowLevel::Row);
+                    }
+                }
+                if show_footer && row_index + 2 == num_rows {
+                    levels.push(RowLevel::Foot);
+                }
+                levels
+            })
+            .collect();
+        let separator_count = |row_index: usize| separators.get(row_index).map_or(0, Vec::len);
 
-            // Render all cells for this row
-            let mut rendered_cells: Vec<Vec<Vec<Segment>>> = Vec::with_capacity(num_cols);
-            let mut max_height: usize = 1;
+        // Render every cell that starts a span, at the width it covers.
+        // Identical cells share their rendered lines.
+        let mut cell_cache = CellCache::default();
+        let mut blocks: Vec<Vec<Option<Vec<Vec<Segment>>>>> = vec![vec![None; num_cols]; num_rows];
+        let mut heights: Vec<usize> = vec![1; num_rows];
 
+        for row_index in 0..num_rows {
             for col_index in 0..num_cols {
-                let width = if col_index < widths.len() {
-                    widths[col_index]
-                } else {
-                    1
+                let Some((cols, rows)) = grid.extent(row_index, col_index) else {
+                    continue;
                 };
-
-                let column = &self.columns[col_index];
-
-                let cell = if row_index < column_cells[col_index].len() {
-                    &column_cells[col_index][row_index]
-                } else {
-                    // Shouldn't happen normally, but provide a blank cell
-                    rendered_cells.push(vec![vec![Segment::styled(
-                        &" ".repeat(width),
-                        Style::null(),
-                    )]]);
-                    max_height = max_height.max(1);
+                let Some(cell) = column_cells[col_index].get(row_index) else {
                     continue;
                 };
+                let width = span_width(col_index, cols);
+                let column = &self.columns[col_index];
 
                 let render_options = options.with_updates(&ConsoleOptionsUpdates {
                     width: Some(width),
-                    justify: Some(Some(column.justify)),
+                    justify: Some(Some(
+                        if groups_shown && row_index == 0 && grouped[col_index] {
+                            JustifyMethod::Center
+                        } else {
+                            column.justify
+                        },
+                    )),
                     no_wrap: Some(column.no_wrap),
                     overflow: Some(Some(column.overflow)),
                     height: Some(None),
@@ -1077,105 +1209,172 @@ impl Table {
                     ..Default::default()
                 });
 
-                let cell_combined_style = cell.style.clone() + row_style.clone();
-                let lines = console.render_lines(
+                let cell_combined_style = cell.style.clone() + row_styles[row_index].clone();
+                let lines = cell_cache.get_or_render(
+                    col_index,
+                    width,
+                    &cell_combined_style,
                     &cell.renderable,
-                    Some(&render_options),
-                    Some(&cell_combined_style),
-                    true,
-                    false,
+                    || {
+                        console.render_lines(
+                            &cell.renderable,
+                            Some(&render_options),
+                            Some(&cell_combined_style),
+                            true,
+                            false,
+                        )
+                    },
                 );
 
-                max_height = max_height.max(lines.len());
-                rendered_cells.push(lines);
+                if rows == 1 {
+                    heights[row_index] = heights[row_index].max(lines.len());
+                }
+                blocks[row_index][col_index] = Some(lines);
+            }
+        }
+
+        // A cell spanning rows also runs through the separators between
+        // them; if it is still too tall, its last row grows.
+        for (row_index, row_blocks) in blocks.iter().enumerate() {
+            for (col_index, block) in row_blocks.iter().enumerate() {
+                let Some((_, rows)) = grid.extent(row_index, col_index) else {
+                    continue;
+                };
+                if rows < 2 {
+                    continue;
+                }
+                let needed = block.as_ref().map_or(0, Vec::len);
+                let last_row = row_index + rows - 1;
+                let available = heights[row_index..=last_row].iter().sum::<usize>()
+                    + (row_index..last_row).map(separator_count).sum::<usize>();
+                if needed > available {
+                    heights[last_row] += needed - available;
+                }
             }
+        }
 
-            // Apply vertical alignment and set shape
-            let row_height = rendered_cells.iter().map(|c| c.len()).max().unwrap_or(1);
-            let max_height = row_height.max(max_height);
+        // Output line where each row starts.
+        let mut line_starts: Vec<usize> = Vec::with_capacity(num_rows);
+        let mut line = 0;
+        for (row_index, height) in heights.iter().enumerate() {
+            line_starts.push(line);
+            line += height + separator_count(row_index);
+        }
+        let top_edge = usize::from(the_box.is_some() && show_edge);
+        let header_lines = top_edge + line_starts.get(header_rows).copied().unwrap_or(line);
 
-            let mut shaped_cells: Vec<Vec<Vec<Segment>>> = Vec::with_capacity(num_cols);
+        // Apply vertical alignment and set shape
+        for row_index in 0..num_rows {
             for col_index in 0..num_cols {
-                let width = if col_index < widths.len() {
-                    widths[col_index]
-                } else {
-                    1
+                let Some((cols, rows)) = grid.extent(row_index, col_index) else {
+                    continue;
                 };
+                let width = span_width(col_index, cols);
+                let last_row = row_index + rows - 1;
+                let height = line_starts[last_row] + heights[last_row] - line_starts[row_index];
+                let row_style = &row_styles[row_index];
 
-                let cell_lines = if col_index < rendered_cells.len() {
-                    &rendered_cells[col_index]
-                } else {
-                    shaped_cells.push(vec![vec![Segment::styled(
-                        &" ".repeat(width),
-                        Style::null(),
-                    )]]);
+                let Some(cell_lines) = blocks[row_index][col_index].take() else {
+                    // Shouldn't happen normally, but provide a blank cell
+                    blocks[row_index][col_index] =
+                        Some(vec![
+                            vec![Segment::styled(&" ".repeat(width), Style::null())];
+                            height
+                        ]);
                     continue;
                 };
+                let cell = &column_cells[col_index][row_index];
 
                 // Get vertical alignment
-                let vertical = if header_row {
+                let vertical = if row_index < header_rows {
                     VerticalAlign::Bottom
-                } else if footer_row {
+                } else if row_index + 1 == num_rows && show_footer {
                     VerticalAlign::Top
-                } else if col_index < column_cells.len()
-                    && row_index < column_cells[col_index].len()
-                {
-                    column_cells[col_index][row_index].vertical
                 } else {
-                    VerticalAlign::Top
+                    cell.vertical
                 };
 
-                let cell_style = if col_index < column_cells.len()
-                    && row_index < column_cells[col_index].len()
-                {
-                    column_cells[col_index][row_index].style.clone() + row_style.clone()
-                } else {
-                    row_style.clone()
-                };
+                let cell_style = cell.style.clone() + row_style.clone();
 
                 let aligned = match vertical {
                     VerticalAlign::Top => {
-                        Segment::align_top(cell_lines, width, max_height, &cell_style, false)
+                        Segment::align_top(&cell_lines, width, height, &cell_style, false)
                     }
                     VerticalAlign::Middle => {
-                        Segment::align_middle(cell_lines, width, max_height, &cell_style, false)
+                        Segment::align_middle(&cell_lines, width, height, &cell_style, false)
                     }
                     VerticalAlign::Bottom => {
-                        Segment::align_bottom(cell_lines, width, max_height, &cell_style, false)
+                        Segment::align_bottom(&cell_lines, width, height, &cell_style, false)
                     }
                 };
 
-                let shaped = Segment::set_shape(&aligned, width, Some(max_height), None, false);
-                shaped_cells.push(shaped);
+                blocks[row_index][col_index] = Some(Segment::set_shape(
+                    &aligned,
+                    width,
+                    Some(height),
+                    None,
+                    false,
+                ));
             }
+        }
+        // The line of a spanning cell shown at output line `line`.
+        let cell_line = |owner: (usize, usize), line: usize| -> &[Segment] {
+            blocks[owner.0][owner.1]
+                .as_ref()
+                .and_then(|lines| lines.get(line - line_starts[owner.0]))
+                .map_or(&[], Vec::as_slice)
+        };
 
-            // Footer separator (before footer row)
-            if let Some(b) = the_box {
-                if last && show_footer {
-                    segments.push(Segment::styled(
-                        &b.get_row(widths, RowLevel::Foot, show_edge),
-                        border_style.clone(),
-                    ));
-                    segments.push(new_line.clone());
-                }
+        // Top edge
+        if let Some(b) = the_box {
+            if show_edge {
+                let top_widths: Vec<usize> = if num_rows == 0 {
+                    widths.to_vec()
+                } else {
+                    pieces(0)
+                        .iter()
+                        .map(|&(col_index, cols, _)| span_width(col_index, cols))
+                        .collect()
+                };
+                segments.push(Segment::styled(
+                    &b.get_top(&top_widths),
+                    border_style.clone(),
+                ));
+                segments.push(new_line.clone());
             }
+            // A lone footer row is still set apart from the top edge.
+            if show_footer && !show_header && num_rows == 1 {
+                segments.push(Segment::styled(
+                    &b.get_row(widths, RowLevel::Foot, show_edge),
+                    border_style.clone(),
+                ));
+                segments.push(new_line.clone());
+            }
+        }
+
+        // Iterate over rows
+        for row_index in 0..num_rows {
+            let first = row_index == 0;
+            let last = row_index == num_rows - 1;
+            let row_pieces = pieces(row_index);
 
             // Render the row lines
+            let row_start = line_starts[row_index];
+            let seg_index = if first || row_index < header_rows {
+                0
+            } else if last {
+                2
+            } else {
+                1
+            };
             if let Some(ref bsegs) = box_segments {
-                let seg_index = if first {
-                    0
-                } else if last {
-                    2
-                } else {
-                    1
-                };
                 let left = &bsegs[seg_index].left;
                 let right = &bsegs[seg_index].right;
                 let base_divider = &bsegs[seg_index].divider;
 
                 // If divider is whitespace, apply row background style
                 let divider = if base_divider.text.trim().is_empty() {
-                    let bg_style = row_style.background_style();
+                    let bg_style = row_styles[row_index].background_style();
                     let combined =
                         bg_style + base_divider.style.clone().unwrap_or_else(Style::null);
                     Segment::styled(&base_divider.text, combined)
@@ -1183,18 +1382,15 @@ impl Table {
                     base_divider.clone()
                 };
 
-                for line_no in 0..max_height {
+                for line_no in row_start..row_start + heights[row_index] {
                     if show_edge {
                         segments.push(left.clone());
                     }
-                    for (cell_idx, cell) in shaped_cells.iter().enumerate() {
-                        let last_cell = cell_idx == shaped_cells.len() - 1;
-                        if line_no < cell.len() {
-                            segments.extend(cell[line_no].iter().cloned());
-                        }
-                        if !last_cell {
+                    for (piece_idx, &(_, _, owner)) in row_pieces.iter().enumerate() {
+                        if piece_idx > 0 {
                             segments.push(divider.clone());
                         }
+                        segments.extend(cell_line(owner, line_no).iter().cloned());
                     }
                     if show_edge {
                         segments.push(right.clone());
@@ -1203,66 +1399,108 @@ impl Table {
                 }
             } else {
                 // No box
-                for line_no in 0..max_height {
-                    for cell in &shaped_cells {
-                        if line_no < cell.len() {
-                            segments.extend(cell[line_no].iter().cloned());
-                        }
+                for line_no in row_start..row_start + heights[row_index] {
+                    for &(_, _, owner) in &row_pieces {
+                        segments.extend(cell_line(owner, line_no).iter().cloned());
                     }
                     segments.push(new_line.clone());
                 }
             }
 
-            // Header separator (after header row)
-            if let Some(b) = the_box {
-                if first && show_header {
-                    segments.push(Segment::styled(
-                        &b.get_row(widths, RowLevel::Head, show_edge),
-                        border_style.clone(),
-                    ));
-                    segments.push(new_line.clone());
+            // Separators below the row; cells spanning past it run through.
+            let Some(b) = the_box else {
+                continue;
+            };
+            let continuing = |col_index: usize| {
+                grid.owner(row_index, col_index) == grid.owner(row_index + 1, col_index)
+            };
+            // Verticals of the row's cells that run through the separator.
+            let (edge_left, vertical, edge_right) = match seg_index {
+                0 => (b.head_left, b.head_vertical, b.head_right),
+                2 => (b.foot_left, b.foot_vertical, b.foot_right),
+                _ => (b.mid_left, b.mid_vertical, b.mid_right),
+            };
+            for (offset, &level) in separators
+                .get(row_index)
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .enumerate()
+            {
+                let line_no = row_start + heights[row_index] + offset;
+                let horizontal = b.get_junction(level, false, false, true, true);
+                let mut border = String::new();
+                if show_edge {
+                    border.push(if continuing(0) {
+                        edge_left
+                    } else {
+                        b.get_junction(level, true, true, false, true)
+                    });
                 }
-            }
-
-            // Inter-row lines / leading / end_section
-            let row_ref = data_row_index.and_then(|idx| self.rows.get(idx));
-            let end_section = row_ref.is_some_and(|r| r.end_section);
-
-            if let Some(b) = the_box {
-                if show_lines || leading > 0 || end_section {
-                    // Don't add separator after last row, after header (already done), or before footer
-                    let skip = last
-                        || (show_footer && row_index >= num_rows.saturating_sub(2))
-                        || (show_header && header_row);
-
-                    if !skip {
-                        if leading > 0 {
-                            let row_str = b.get_row(widths, RowLevel::Mid, show_edge);
-                            for _ in 0..leading {
-                                segments.push(Segment::styled(&row_str, border_style.clone()));
-                                segments.push(new_line.clone());
-                            }
+                let mut col_index = 0;
+                while col_index < num_cols {
+                    if continuing(col_index) {
+                        let owner = grid.owner(row_index, col_index);
+                        if !border.is_empty() {
+                            segments.push(Segment::styled(&border, border_style.clone()));
+                            border.clear();
+                        }
+                        segments.extend(cell_line(owner, line_no).iter().cloned());
+                        while col_index < num_cols && grid.owner(row_index, col_index) == owner {
+                            col_index += 1;
+                        }
+                    } else {
+                        let width = widths.get(col_index).copied().unwrap_or(1);
+                        border.extend(std::iter::repeat_n(horizontal, width));
+                        col_index += 1;
+                    }
+                    if col_index < num_cols {
+                        let left = !continuing(col_index - 1);
+                        let right = !continuing(col_index);
+                        if !left && !right {
+                            border.push(vertical);
                         } else {
-                            segments.push(Segment::styled(
-                                &b.get_row(widths, RowLevel::Row, show_edge),
-                                border_style.clone(),
-                            ));
-                            segments.push(new_line.clone());
+                            let up = grid.owner(row_index, col_index - 1)
+                                != grid.owner(row_index, col_index);
+                            let down = grid.owner(row_index + 1, col_index - 1)
+                                != grid.owner(row_index + 1, col_index);
+                            border.push(b.get_junction(level, up, down, left, right));
                         }
                     }
                 }
+                if show_edge {
+                    border.push(if continuing(num_cols - 1) {
+                        edge_right
+                    } else {
+                        b.get_junction(level, true, true, true, false)
+                    });
+                }
+                if !border.is_empty() {
+                    segments.push(Segment::styled(&border, border_style.clone()));
+                }
+                segments.push(new_line.clone());
             }
         }
 
         // Bottom edge
         if let Some(b) = the_box {
             if show_edge {
-                segments.push(Segment::styled(&b.get_bottom(widths), border_style.clone()));
+                let bottom_widths: Vec<usize> = if num_rows == 0 {
+                    widths.to_vec()
+                } else {
+                    pieces(num_rows - 1)
+                        .iter()
+                        .map(|&(col_index, cols, _)| span_width(col_index, cols))
+                        .collect()
+                };
+                segments.push(Segment::styled(
+                    &b.get_bottom(&bottom_widths),
+                    border_style.clone(),
+                ));
                 segments.push(new_line);
             }
         }
 
-        segments
+        (segments, header_lines)
     }
 
     /// Measure the table, returning minimum and maximum widths.
@@ -1288,12 +1526,16 @@ impl Table {
             .map(|col| self.measure_column(console, &options.update_width(total_max), col))
             .collect();
 
+        let mut maximum_widths: Vec<usize> = measurements.iter().map(|m| m.maximum).collect();
+        self.widen_for_spans(console, &mut maximum_widths, usize::MAX);
+
+        let shadow_width = usize::from(self.shadow);
         let minimum_width: usize =
-            measurements.iter().map(|m| m.minimum).sum::<usize>() + extra_width;
+            measurements.iter().map(|m| m.minimum).sum::<usize>() + extra_width + shadow_width;
         let maximum_width: usize = if let Some(w) = self.width {
-            w
+            w + shadow_width
         } else {
-            measurements.iter().map(|m| m.maximum).sum::<usize>() + extra_width
+            maximum_widths.iter().sum::<usize>() + extra_width + shadow_width
         };
 
         let measurement = Measurement::new(minimum_width, maximum_width);
@@ -0,0 +1,140 @@
+//! Column groups -- a second header row with super-headers over several columns.
+
+use crate::console::Console;
+use crate::style::Style;
+use crate::utils::align_widget::VerticalAlign;
+use crate::widgets::table::core::CellInfo;
+use crate::widgets::table::{ColumnGroup, ColumnOptions, Table};
+
+impl Table {
+    /// Add columns with the given headers, grouped under a shared
+    /// super-header.
+    ///
+    /// Groups are drawn in an extra header row above the column headers,
+    /// with the group title merged across its columns and separated from
+    /// them by a line. Headers of columns outside any group fill both
+    /// header rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::Table;
+    ///
+    /// let mut table = Table::new(&["Endpoint"]);
+    /// table.add_column_group("Latency", &["p50", "p95", "p99"]);
+    /// table.add_row(&["/api", "12ms", "40ms", "95ms"]);
+    /// assert_eq!(table.columns.len(), 4);
+    /// assert!(format!("{}", table).contains("Latency"));
+    /// ```
+    pub fn add_column_group(&mut self, title: &str, headers: &[&str]) {
+        self.add_column_group_with(title, headers, ColumnOptions::default());
+    }
+
+    /// Add a group of columns that all share the given [`ColumnOptions`].
+    pub fn add_column_group_with(&mut self, title: &str, headers: &[&str], opts: ColumnOptions) {
+        let first = self.columns.len();
+        for header in headers {
+            self.add_column(header, "", opts.clone());
+        }
+        if !headers.is_empty() {
+            self.column_groups.push(ColumnGroup {
+                title: title.to_string(),
+                first,
+                count: headers.len(),
+            });
+        }
+    }
+
+    /// Whether the group header row is drawn.
+    pub(crate) fn shows_groups(&self) -> bool {
+        self.show_header && !self.column_groups.is_empty()
+    }
+
+    /// The groups that fit within the table's columns, with their extents
+    /// clipped to them.
+    pub(crate) fn visible_groups(&self) -> impl Iterator<Item = (usize, usize, &ColumnGroup)> {
+        let num_cols = self.columns.len();
+        self.column_groups
+            .iter()
+            .filter(move |group| group.first < num_cols && group.count > 0)
+            .map(move |group| {
+                let count = group.count.min(num_cols - group.first);
+                (group.first, count, group)
+            })
+    }
+
+    /// The group title as a padded, header-styled cell.
+    pub(crate) fn group_cell(
+        &self,
+        console: &Console,
+        first: usize,
+        count: usize,
+        group: &ColumnGroup,
+    ) -> CellInfo {
+        let style = console
+            .get_style(&self.header_style)
+            .unwrap_or_else(|_| Style::null());
+        let mut text = console.render_str(&group.title, None, None, None);
+
+        let (_, pad_right, _, pad_left) = self.padding;
+        let mut left = pad_left;
+        let mut right = pad_right;
+        if self.collapse_padding && first > 0 {
+            left = left.saturating_sub(right);
+        }
+        if !self.pad_edge {
+            if first == 0 {
+                left = 0;
+            }
+            if first + count == self.columns.len() {
+                right = 0;
+            }
+        }
+        if left > 0 {
+            text.pad_left(left, ' ');
+        }
+        if right > 0 {
+            text.pad_right(right, ' ');
+        }
+
+        CellInfo {
+            style,
+            renderable: text,
+            vertical: VerticalAlign::Bottom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_column_group_records_extent() {
+        let mut table = Table::new(&["Host"]);
+        table.add_column_group("Latency", &["p50", "p95", "p99"]);
+        table.add_column_group("Empty", &[]);
+        assert_eq!(
+            table.column_groups,
+            vec![ColumnGroup {
+                title: "Latency".to_string(),
+                first: 1,
+                count: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_group_title_widens_its_columns() {
+        let mut table = Table::new(&[]);
+        table.add_column_group("A long group title", &["a", "b"]);
+        table.add_row(&["1", "2"]);
+        let output = format!("{}", table);
+        let title_line = output.lines().nth(1).unwrap();
+        assert!(title_line.contains("A long group title"));
+        assert_eq!(
+            output.lines().map(|l| l.chars().count()).max(),
+            output.lines().map(|l| l.chars().count()).min()
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Semantic HTML output for the table module.
+
+use std::fmt::Write as _;
+
+use crate::console::html_escape;
+use crate::text::{JustifyMethod, Text};
+use crate::widgets::table::{CellContent, Column, SortOrder, Table};
+
+/// Plain text of a markup string such as a header, title or caption.
+fn plain_markup(markup: &str) -> String {
+    Text::from_markup(markup)
+        .map(|t| t.plain().to_string())
+        .unwrap_or_else(|_| markup.to_string())
+}
+
+/// Inline `text-align` for columns that are not left-justified.
+fn align_attr(justify: JustifyMethod) -> &'static str {
+    match justify {
+        JustifyMethod::Center => " style=\"text-align: center\"",
+        JustifyMethod::Right => " style=\"text-align: right\"",
+        _ => "",
+    }
+}
+
+impl Table {
+    /// Render the table as a semantic HTML `<table>`.
+    ///
+    /// Cells hold their plain text (markup and styles are dropped), so the
+    /// result can be themed with CSS and read by screen readers. The title
+    /// becomes the `<caption>` (with the table caption beneath it), headers
+    /// are `<th scope="col">` cells with `aria-sort` on a sorted column, and
+    /// footers go in `<tfoot>` when shown. Right- and center-justified
+    /// columns keep their alignment. Spanning cells carry `colspan` and
+    /// `rowspan` attributes, and column groups add a row of
+    /// `<th scope="colgroup">` headers.
+    ///
+    /// [`Console::print_table`](crate::console::Console::print_table) records
+    /// this alongside the rendered table for
+    /// [`HtmlOptions::semantic_tables`](crate::console::HtmlOptions::semantic_tables).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::Table;
+    ///
+    /// let mut table = Table::new(&["Name", "Qty"]).with_title("Stock");
+    /// table.add_row(&["[bold]Apples[/bold]", "3"]);
+    /// let html = table.to_html();
+    /// assert!(html.starts_with("<table>\n<caption>Stock</caption>"));
+    /// assert!(html.contains("<th scope=\"col\">Name</th>"));
+    /// assert!(html.contains("<td>Apples</td>"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<table>\n");
+
+        let title = self.title.as_deref().map(plain_markup);
+        let caption = self.caption.as_deref().map(plain_markup);
+        match (title, caption) {
+            (Some(title), Some(caption)) => writeln!(
+                html,
+                "<caption>{}<br><small>{}</small></caption>",
+                html_escape(&title),
+                html_escape(&caption)
+            ),
+            (Some(text), None) | (None, Some(text)) => {
+                writeln!(html, "<caption>{}</caption>", html_escape(&text))
+            }
+            (None, None) => Ok(()),
+        }
+        .unwrap();
+
+        if self.show_header {
+            html.push_str("<thead>\n<tr>");
+            // With column groups, headers outside a group span both rows.
+            let mut grouped = vec![false; self.columns.len()];
+            if self.shows_groups() {
+                let mut groups = self.visible_groups().peekable();
+                for (i, column) in self.columns.iter().enumerate() {
+                    if let Some((_, count, group)) = groups.next_if(|&(first, _, _)| first == i) {
+                        grouped[i..i + count].fill(true);
+                        write!(
+                            html,
+                            "<th scope=\"colgroup\" colspan=\"{}\">{}</th>",
+                            count,
+                            html_escape(&plain_markup(&group.title))
+                        )
+                        .unwrap();
+                    } else if !grouped[i] {
+                        self.write_header_cell(&mut html, i, column, " rowspan=\"2\"");
+                    }
+                }
+                html.push_str("</tr>\n<tr>");
+            }
+            for (i, column) in self.columns.iter().enumerate() {
+                if grouped[i] || !self.shows_groups() {
+                    self.write_header_cell(&mut html, i, column, "");
+                }
+            }
+            html.push_str("</tr>\n</thead>\n");
+        }
+
+        html.push_str("<tbody>\n");
+        let grid = self.span_grid(0, self.rows.len());
+        for row in 0..self.rows.len() {
+            html.push_str("<tr>");
+            for (index, column) in self.columns.iter().enumerate() {
+                let Some((cols, rows)) = grid.extent(row, index) else {
+                    continue;
+                };
+                let mut span = String::new();
+                if cols > 1 {
+                    write!(span, " colspan=\"{cols}\"").unwrap();
+                }
+                if rows > 1 {
+                    write!(span, " rowspan=\"{rows}\"").unwrap();
+                }
+                let text = column
+                    .cells
+                    .get(row)
+                    .map(CellContent::plain_text)
+                    .unwrap_or_default();
+                write!(
+                    html,
+                    "<td{}{}>{}</td>",
+                    span,
+                    align_attr(column.justify),
+                    html_escape(&text)
+                )
+                .unwrap();
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n");
+
+        if self.show_footer {
+            html.push_str("<tfoot>\n<tr>");
+            for column in &self.columns {
+                write!(
+                    html,
+                    "<td{}>{}</td>",
+                    align_attr(column.justify),
+                    html_escape(&plain_markup(&column.footer))
+                )
+                .unwrap();
+            }
+            html.push_str("</tr>\n</tfoot>\n");
+        }
+
+        html.push_str("</table>");
+        html
+    }
+
+    /// Write the `<th>` for a column header, with `extra` attributes.
+    fn write_header_cell(&self, html: &mut String, index: usize, column: &Column, extra: &str) {
+        let sort = match self.sorted_by {
+            Some((sorted, SortOrder::Ascending)) if sorted == index => " aria-sort=\"ascending\"",
+            Some((sorted, SortOrder::Descending)) if sorted == index => " aria-sort=\"descending\"",
+            _ => "",
+        };
+        write!(
+            html,
+            "<th scope=\"col\"{}{}{}>{}</th>",
+            extra,
+            sort,
+            align_attr(column.justify),
+            html_escape(&plain_markup(&column.header))
+        )
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::table::{ColumnOptions, Row, SortOrder, Table};
+    use crate::text::JustifyMethod;
+
+    #[test]
+    fn test_to_html_structure() {
+        let mut table = Table::new(&["A", "B"]);
+        table.add_row(&["1", "2"]);
+        table.add_row(&["3"]);
+        assert_eq!(
+            table.to_html(),
+            "<table>\n\
+             <thead>\n<tr><th scope=\"col\">A</th><th scope=\"col\">B</th></tr>\n</thead>\n\
+             <tbody>\n<tr><td>1</td><td>2</td></tr>\n<tr><td>3</td><td></td></tr>\n</tbody>\n\
+             </table>"
+        );
+    }
+
+    #[test]
+    fn test_to_html_escapes_and_strips_markup() {
+        let mut table = Table::new(&["[italic]Expr[/italic]"]);
+        table.add_row(&["a < b && [red]c[/red]"]);
+        let html = table.to_html();
+        assert!(html.contains("<th scope=\"col\">Expr</th>"));
+        assert!(html.contains("<td>a &lt; b &amp;&amp; c</td>"));
+    }
+
+    #[test]
+    fn test_to_html_caption_footer_sort_and_alignment() {
+        let mut table = Table::new(&[])
+            .with_title("Title")
+            .with_caption("Note")
+            .with_show_footer(true);
+        table.add_column(
+            "Price",
+            "Total",
+            ColumnOptions {
+                justify: Some(JustifyMethod::Right),
+                ..Default::default()
+            },
+        );
+        table.add_row(&["9"]);
+        table.sort_by_column(0, SortOrder::Descending);
+        let html = table.to_html();
+        assert!(html.contains("<caption>Title<br><small>Note</small></caption>"));
+        assert!(html.contains(
+            "<th scope=\"col\" aria-sort=\"descending\" style=\"text-align: right\">Price</th>"
+        ));
+        assert!(html.contains("<tfoot>\n<tr><td style=\"text-align: right\">Total</td></tr>"));
+    }
+
+    #[test]
+    fn test_to_html_column_groups() {
+        let mut table = Table::new(&["Endpoint"]);
+        table.add_column_group("Latency", &["p50", "p95"]);
+        assert!(table.to_html().contains(
+            "<thead>\n<tr><th scope=\"col\" rowspan=\"2\">Endpoint</th>\
+             <th scope=\"colgroup\" colspan=\"2\">Latency</th></tr>\n\
+             <tr><th scope=\"col\">p50</th><th scope=\"col\">p95</th></tr>\n</thead>"
+        ));
+    }
+
+    #[test]
+    fn test_to_html_spans() {
+        let mut table = Table::new(&["A", "B", "C"]);
+        table.add_row_spans(&[Row::span("1", 2).with_rows(2), "2".into()]);
+        table.add_row_spans(&["3".into()]);
+        assert!(table.to_html().contains(
+            "<tr><td colspan=\"2\" rowspan=\"2\">1</td><td>2</td></tr>\n<tr><td>3</td></tr>"
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+//! Remembered column widths for tables that are re-rendered repeatedly.
+//!
+//! A table sizes its columns to fit the current data, so in a watch loop or
+//! live display the columns jump around as values change length. Attaching a
+//! [`WidthMemory`] makes the table remember the widths it negotiated and never
+//! shrink a column below its previous width while the table still fits.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::widgets::table::Table;
+
+/// A shared store of negotiated column widths, keyed by table id.
+///
+/// Clones share the same store. [`WidthMemory::global`] is a process-wide
+/// store used by [`Table::with_remembered_widths`]; create a separate store
+/// with [`WidthMemory::new`] to scope widths to one screen or test. The
+/// stored widths can be read with [`get`](WidthMemory::get) and restored with
+/// [`set`](WidthMemory::set) to persist a layout across runs.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::table::{Table, WidthMemory};
+///
+/// let memory = WidthMemory::new();
+/// let render = |value: &str| {
+///     let mut table = Table::new(&["Value"]).with_width_memory(&memory, "stats");
+///     table.add_row(&[value]);
+///     format!("{}", table)
+/// };
+///
+/// let wide = render("a long value");
+/// let narrow = render("short");
+/// // The column keeps the width it needed for the longer value.
+/// assert_eq!(wide.lines().next(), narrow.lines().next());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WidthMemory {
+    widths: Arc<Mutex<HashMap<String, Vec<usize>>>>,
+}
+
+impl WidthMemory {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide store used by [`Table::with_remembered_widths`].
+    pub fn global() -> WidthMemory {
+        static GLOBAL: OnceLock<WidthMemory> = OnceLock::new();
+        GLOBAL.get_or_init(WidthMemory::new).clone()
+    }
+
+    /// The widths remembered for table `id`, one per column.
+    pub fn get(&self, id: &str) -> Option<Vec<usize>> {
+        self.widths.lock().unwrap().get(id).cloned()
+    }
+
+    /// Remember `widths` for table `id`, e.g. to restore a saved layout.
+    pub fn set(&self, id: &str, widths: Vec<usize>) {
+        self.widths.lock().unwrap().insert(id.to_string(), widths);
+    }
+
+    /// Forget the widths of table `id`, so it is sized from scratch.
+    pub fn forget(&self, id: &str) {
+        self.widths.lock().unwrap().remove(id);
+    }
+
+    /// Forget every remembered width.
+    pub fn clear(&self) {
+        self.widths.lock().unwrap().clear();
+    }
+
+    /// Widen `widths` to at least the remembered widths for `id` if the
+    /// result still fits in `max_width`, then remember the outcome.
+    ///
+    /// Remembered widths are ignored when the column count has changed or
+    /// they no longer fit, e.g. after the terminal shrinks.
+    pub(crate) fn apply(&self, id: &str, widths: Vec<usize>, max_width: usize) -> Vec<usize> {
+        let mut store = self.widths.lock().unwrap();
+        let widths = match store.get(id) {
+            Some(previous) if previous.len() == widths.len() => {
+                let merged: Vec<usize> = widths
+                    .iter()
+                    .zip(previous)
+                    .map(|(&now, &before)| now.max(before))
+                    .collect();
+                if merged.iter().sum::<usize>() <= max_width {
+                    merged
+                } else {
+                    widths
+                }
+            }
+            _ => widths,
+        };
+        store.insert(id.to_string(), widths.clone());
+        widths
+    }
+}
+
+impl Table {
+    /// Remember this table's column widths under `id` in the process-wide
+    /// [`WidthMemory`] (builder pattern).
+    ///
+    /// Successive renders of tables with the same `id` never shrink a
+    /// column, which stops live or watch-mode tables from jittering as their
+    /// data changes.
+    #[must_use]
+    pub fn with_remembered_widths(self, id: &str) -> Self {
+        self.with_width_memory(&WidthMemory::global(), id)
+    }
+
+    /// Remember this table's column widths under `id` in `memory`
+    /// (builder pattern).
+    #[must_use]
+    pub fn with_width_memory(mut self, memory: &WidthMemory, id: &str) -> Self {
+        self.width_memory = Some((memory.clone(), id.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_line(table: &Table) -> String {
+        format!("{}", table).lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_columns_do_not_shrink() {
+        let memory = WidthMemory::new();
+        let mut wide = Table::new(&["A", "B"]).with_width_memory(&memory, "t");
+        wide.add_row(&["xxxxxxxx", "yyyyyy"]);
+        let mut narrow = Table::new(&["A", "B"]).with_width_memory(&memory, "t");
+        narrow.add_row(&["x", "y"]);
+        assert_eq!(first_line(&wide), first_line(&narrow));
+    }
+
+    #[test]
+    fn test_columns_still_grow() {
+        let memory = WidthMemory::new();
+        let mut first = Table::new(&["A"]).with_width_memory(&memory, "t");
+        first.add_row(&["x"]);
+        let _ = format!("{}", first);
+        let before = memory.get("t").unwrap();
+        let mut second = Table::new(&["A"]).with_width_memory(&memory, "t");
+        second.add_row(&["much longer"]);
+        let _ = format!("{}", second);
+        assert!(memory.get("t").unwrap()[0] > before[0]);
+    }
+
+    #[test]
+    fn test_remembered_widths_dropped_when_too_wide() {
+        let memory = WidthMemory::new();
+        memory.set("t", vec![50, 50]);
+        let mut table = Table::new(&["A", "B"]).with_width_memory(&memory, "t");
+        table.add_row(&["a", "b"]);
+        let out = format!("{:40}", table);
+        assert!(out.lines().all(|line| line.chars().count() <= 40));
+        assert!(memory.get("t").unwrap().iter().all(|&w| w < 50));
+    }
+
+    #[test]
+    fn test_column_count_change_resets() {
+        let memory = WidthMemory::new();
+        memory.set("t", vec![20]);
+        let mut table = Table::new(&["A", "B"]).with_width_memory(&memory, "t");
+        table.add_row(&["a", "b"]);
+        let _ = format!("{}", table);
+        assert_eq!(memory.get("t").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_forget_and_clear() {
+        let memory = WidthMemory::new();
+        memory.set("a", vec![1]);
+        memory.set("b", vec![2]);
+        memory.forget("a");
+        assert!(memory.get("a").is_none());
+        memory.clear();
+        assert!(memory.get("b").is_none());
+    }
+
+    #[test]
+    fn test_ids_are_independent() {
+        let memory = WidthMemory::new();
+        let mut one = Table::new(&["A"]).with_width_memory(&memory, "one");
+        one.add_row(&["a long value"]);
+        let _ = format!("{}", one);
+        let mut two = Table::new(&["A"]).with_width_memory(&memory, "two");
+        two.add_row(&["b"]);
+        let _ = format!("{}", two);
+        assert!(memory.get("two").unwrap()[0] < memory.get("one").unwrap()[0]);
+    }
+}
@@ -13,12 +13,25 @@
 //! println!("{}", table);
 //! ```
 
+mod cell_cache;
 mod column;
 mod core;
+mod group;
+mod html;
+mod memory;
 mod render;
 mod row;
+#[cfg(feature = "json")]
+mod serialize;
+mod sort;
+mod span;
+mod transpose;
 
 // Re-exports for backward compatibility
-pub use column::{Column, ColumnOptions};
+pub use cell_cache::{cell_cache_stats, reset_cell_cache_stats, CellCacheStats};
+pub use column::{Column, ColumnGroup, ColumnOptions};
 pub use core::Table;
+pub use memory::WidthMemory;
 pub use row::{CellContent, Row};
+pub use sort::SortOrder;
+pub use span::{CellSpan, SpanCell};
@@ -2,13 +2,45 @@
 
 use crate::console::{Console, ConsoleOptions, ConsoleOptionsUpdates, Renderable};
 use crate::segment::Segment;
+use crate::shadow::{render_with_shadow, shadow_style};
 use crate::style::Style;
 use crate::widgets::table::Table;
 
 impl Renderable for Table {
     fn gilt_console(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment> {
+        self.render_sticky(console, options).0
+    }
+
+    /// The title, top edge and header rows stay in view while rows scroll.
+    fn render_sticky(&self, console: &Console, options: &ConsoleOptions) -> (Vec<Segment>, usize) {
+        let (segments, header_lines) = if self.shadow {
+            // The shadow only adds to the right and below, so the header
+            // keeps its height.
+            let style = shadow_style(console);
+            let mut header_lines = 0;
+            let segments = render_with_shadow(options, &style, |opts| {
+                let (segments, lines) = self.render_contents(console, opts);
+                header_lines = lines;
+                segments
+            });
+            (segments, header_lines)
+        } else {
+            self.render_contents(console, options)
+        };
+        (segments, if self.show_header { header_lines } else { 0 })
+    }
+}
+
+impl Table {
+    /// Render the title, body and caption without any shadow decoration,
+    /// along with the number of lines down to the end of the header.
+    fn render_contents(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+    ) -> (Vec<Segment>, usize) {
         if self.columns.is_empty() {
-            return vec![Segment::line()];
+            return (vec![Segment::line()], 0);
         }
 
         let mut max_width = options.max_width;
@@ -64,7 +96,9 @@ impl Renderable for Table {
         }
 
         // Render table body
-        segments.extend(self.render_table(console, &render_options, &widths));
+        let title_lines: usize = segments.iter().map(|s| s.text.matches('\n').count()).sum();
+        let (body, header_lines) = self.render_table(console, &render_options, &widths);
+        segments.extend(body);
 
         // Caption
         if let Some(ref caption) = self.caption {
@@ -96,7 +130,7 @@ impl Renderable for Table {
             }
         }
 
-        segments
+        (segments, title_lines + header_lines)
     }
 }
 
@@ -2,10 +2,12 @@
 
 use crate::console::Console;
 use crate::text::Text;
+use crate::widgets::table::CellSpan;
 
 /// Content of a table cell -- either a plain string (parsed with markup) or
 /// a pre-styled [`Text`] object.
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // boxing `Text` would change the public variant
 pub enum CellContent {
     // Note: PartialEq is implemented manually below (Plain compares string).
     /// A plain string, optionally containing markup tags.
@@ -22,6 +24,16 @@ impl CellContent {
             CellContent::Styled(t) => t.clone(),
         }
     }
+
+    /// The cell's text with any markup tags stripped.
+    pub(crate) fn plain_text(&self) -> String {
+        match self {
+            CellContent::Plain(s) => Text::from_markup(s)
+                .map(|t| t.plain().to_string())
+                .unwrap_or_else(|_| s.clone()),
+            CellContent::Styled(t) => t.plain().to_string(),
+        }
+    }
 }
 
 impl From<&str> for CellContent {
@@ -58,4 +70,6 @@ pub struct Row {
     pub style: Option<String>,
     /// Whether this row ends a section (draws a line after it).
     pub end_section: bool,
+    /// Cells starting in this row that span several columns or rows.
+    pub(crate) spans: Vec<CellSpan>,
 }
@@ -0,0 +1,454 @@
+//! Tables from any `Serialize` slice, for the table module.
+
+use serde::ser::{self, Impossible, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::style::Style;
+use crate::text::{JustifyMethod, Text};
+use crate::widgets::table::{ColumnOptions, Table};
+
+/// A row as (field name, value) pairs in serialization order.
+type Fields = Vec<(String, Value)>;
+
+/// Column name for values that are not structs or maps.
+const VALUE_COLUMN: &str = "value";
+
+impl Table {
+    /// Build a table from a slice of values that implement
+    /// [`serde::Serialize`], without `#[derive(Table)]`.
+    ///
+    /// Struct fields (or map entries) become columns in the order they are
+    /// serialized; a field that only some rows have (e.g. one skipped with
+    /// `skip_serializing_if`) still gets a column, with blank cells where it
+    /// is missing. Renamed fields use their serde name. Strings are shown
+    /// as-is -- they are not parsed as markup -- numbers and booleans as
+    /// written, `None` as a dim `—`, and nested values as compact JSON.
+    /// Columns whose values are all numbers are right-justified. Values that
+    /// are not structs or maps go in a single `value` column.
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of a value's `Serialize` implementation, or a
+    /// [`serde_json::Error`] when a map key cannot be shown as text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::Table;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Release {
+    ///     name: String,
+    ///     downloads: u64,
+    /// }
+    ///
+    /// let releases = [
+    ///     Release { name: "v1.0".into(), downloads: 1200 },
+    ///     Release { name: "v1.1".into(), downloads: 87 },
+    /// ];
+    /// let table = Table::from_serialize(&releases).unwrap();
+    /// assert_eq!(table.columns[0].header, "name");
+    /// assert_eq!(table.columns[1].header, "downloads");
+    /// assert_eq!(table.row_count(), 2);
+    /// ```
+    pub fn from_serialize<T: Serialize>(items: &[T]) -> Result<Table, serde_json::Error> {
+        let rows = items
+            .iter()
+            .map(|item| item.serialize(RowSerializer))
+            .collect::<Result<Vec<Fields>, _>>()?;
+
+        let mut headers: Vec<&str> = Vec::new();
+        for (name, _) in rows.iter().flatten() {
+            if !headers.contains(&name.as_str()) {
+                headers.push(name);
+            }
+        }
+
+        let mut table = Table::new(&[]);
+        for header in &headers {
+            let mut values = rows
+                .iter()
+                .flatten()
+                .filter(|(name, _)| name == header)
+                .map(|(_, value)| value)
+                .filter(|value| !value.is_null())
+                .peekable();
+            let numeric = values.peek().is_some() && values.all(Value::is_number);
+            table.add_column(
+                header,
+                "",
+                ColumnOptions {
+                    justify: numeric.then_some(JustifyMethod::Right),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let dim = Style::parse("dim").unwrap_or_else(|_| Style::null());
+        for row in &rows {
+            let cells: Vec<Text> = headers
+                .iter()
+                .map(|header| match row.iter().find(|(name, _)| name == header) {
+                    Some((_, Value::Null)) => Text::styled("—", dim.clone()),
+                    Some((_, value)) => Text::new(&cell_text(value), Style::null()),
+                    None => Text::empty(),
+                })
+                .collect();
+            table.add_row_text(&cells);
+        }
+        Ok(table)
+    }
+}
+
+/// The text of a cell: strings without quotes, other scalars as written and
+/// arrays and objects as compact JSON.
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RowSerializer
+// ---------------------------------------------------------------------------
+
+/// Serializes one item into its fields, keeping the order in which a struct
+/// serializes them (a `serde_json::Value` would sort them by name).
+struct RowSerializer;
+
+/// A single `value` field holding `value` serialized as JSON.
+fn single<T: Serialize + ?Sized>(value: &T) -> Result<Fields, serde_json::Error> {
+    Ok(vec![(
+        VALUE_COLUMN.to_string(),
+        serde_json::to_value(value)?,
+    )])
+}
+
+macro_rules! serialize_scalars {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Fields, serde_json::Error> {
+                single(&v)
+            }
+        )*
+    };
+}
+
+impl Serializer for RowSerializer {
+    type Ok = Fields;
+    type Error = serde_json::Error;
+    type SerializeSeq = Impossible<Fields, serde_json::Error>;
+    type SerializeTuple = Impossible<Fields, serde_json::Error>;
+    type SerializeTupleStruct = Impossible<Fields, serde_json::Error>;
+    type SerializeTupleVariant = Impossible<Fields, serde_json::Error>;
+    type SerializeMap = FieldCollector;
+    type SerializeStruct = FieldCollector;
+    type SerializeStructVariant = FieldCollector;
+
+    serialize_scalars!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_none(self) -> Result<Fields, serde_json::Error> {
+        single(&())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Fields, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Fields, serde_json::Error> {
+        single(&())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Fields, serde_json::Error> {
+        single(&())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Fields, serde_json::Error> {
+        single(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Fields, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Fields, serde_json::Error> {
+        Ok(vec![(variant.to_string(), serde_json::to_value(value)?)])
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, serde_json::Error> {
+        Err(ser::Error::custom(SEQUENCE))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, serde_json::Error> {
+        Err(ser::Error::custom(SEQUENCE))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, serde_json::Error> {
+        Err(ser::Error::custom(SEQUENCE))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, serde_json::Error> {
+        Err(ser::Error::custom(SEQUENCE))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<FieldCollector, serde_json::Error> {
+        Ok(FieldCollector::new(len.unwrap_or(0)))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<FieldCollector, serde_json::Error> {
+        Ok(FieldCollector::new(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        len: usize,
+    ) -> Result<FieldCollector, serde_json::Error> {
+        Ok(FieldCollector::new(len))
+    }
+}
+
+/// Error for rows that serialize as sequences, which have no field names.
+const SEQUENCE: &str = "Table::from_serialize needs structs or maps, not sequences or tuples";
+
+/// Collects the fields of a struct or the entries of a map.
+struct FieldCollector {
+    fields: Fields,
+    /// Key of the map entry whose value comes next.
+    key: Option<String>,
+}
+
+impl FieldCollector {
+    fn new(len: usize) -> Self {
+        FieldCollector {
+            fields: Vec::with_capacity(len),
+            key: None,
+        }
+    }
+}
+
+impl ser::SerializeStruct for FieldCollector {
+    type Ok = Fields;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.fields
+            .push((key.to_string(), serde_json::to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Fields, serde_json::Error> {
+        Ok(self.fields)
+    }
+}
+
+impl ser::SerializeStructVariant for FieldCollector {
+    type Ok = Fields;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Fields, serde_json::Error> {
+        Ok(self.fields)
+    }
+}
+
+impl ser::SerializeMap for FieldCollector {
+    type Ok = Fields;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), serde_json::Error> {
+        let key = match serde_json::to_value(key)? {
+            Value::String(s) => s,
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            _ => return Err(ser::Error::custom("map keys must be strings or scalars")),
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.fields.push((key, serde_json::to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Fields, serde_json::Error> {
+        Ok(self.fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use crate::table::Table;
+    use crate::testing::render_plain;
+    use crate::text::JustifyMethod;
+
+    #[derive(Serialize)]
+    struct Package {
+        name: &'static str,
+        #[serde(rename = "ver")]
+        version: &'static str,
+        size: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        license: Option<&'static str>,
+        tags: Vec<&'static str>,
+        homepage: Option<&'static str>,
+    }
+
+    fn packages() -> Vec<Package> {
+        vec![
+            Package {
+                name: "zeta",
+                version: "1.0",
+                size: 120,
+                license: None,
+                tags: vec!["cli"],
+                homepage: None,
+            },
+            Package {
+                name: "[bold]alpha[/bold]",
+                version: "0.3",
+                size: 4,
+                license: Some("MIT"),
+                tags: vec![],
+                homepage: Some("https://a.dev"),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_columns_follow_field_order() {
+        let table = Table::from_serialize(&packages()).unwrap();
+        let headers: Vec<&str> = table.columns.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(
+            headers,
+            ["name", "ver", "size", "tags", "homepage", "license"]
+        );
+        assert_eq!(table.row_count(), 2);
+    }
+
+    #[test]
+    fn test_cells_render_plain_values() {
+        let table = Table::from_serialize(&packages()).unwrap();
+        let output = render_plain(&table, 100);
+        assert!(output.contains("│ zeta "));
+        assert!(output.contains("│ [bold]alpha[/bold] "));
+        assert!(output.contains("│ [\"cli\"] "));
+        assert!(output.contains("│ —  "));
+        assert!(output.contains("│ https://a.dev   │ MIT "));
+    }
+
+    #[test]
+    fn test_numeric_columns_right_justified() {
+        let table = Table::from_serialize(&packages()).unwrap();
+        assert_eq!(table.columns[2].justify, JustifyMethod::Right);
+        assert_eq!(table.columns[0].justify, JustifyMethod::Left);
+        // All-null columns are not treated as numeric.
+        #[derive(Serialize)]
+        struct Sparse {
+            n: Option<u8>,
+        }
+        let table = Table::from_serialize(&[Sparse { n: None }]).unwrap();
+        assert_eq!(table.columns[0].justify, JustifyMethod::Left);
+    }
+
+    #[test]
+    fn test_maps_and_scalars() {
+        let mut row = BTreeMap::new();
+        row.insert("a", 1);
+        row.insert("b", 2);
+        let table = Table::from_serialize(&[row]).unwrap();
+        assert_eq!(table.columns.len(), 2);
+        assert_eq!(table.columns[1].header, "b");
+
+        let table = Table::from_serialize(&["x", "y"]).unwrap();
+        assert_eq!(table.columns.len(), 1);
+        assert_eq!(table.columns[0].header, "value");
+        assert_eq!(render_plain(&table, 20).lines().count(), 6);
+    }
+
+    #[test]
+    fn test_sequences_are_rejected() {
+        let err = Table::from_serialize(&[(1, 2)]).unwrap_err();
+        assert!(err.to_string().contains("structs or maps"));
+    }
+
+    #[test]
+    fn test_empty_slice() {
+        let table = Table::from_serialize::<Package>(&[]).unwrap();
+        assert!(table.columns.is_empty());
+        assert_eq!(table.row_count(), 0);
+    }
+}
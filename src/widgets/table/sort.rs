@@ -0,0 +1,257 @@
+//! Row sorting and header sort indicators for the table module.
+
+use std::cmp::Ordering;
+
+use crate::widgets::table::{CellContent, Table};
+
+/// Direction in which a table column is sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest values first; rendered as `▲` in the header.
+    Ascending,
+    /// Largest values first; rendered as `▼` in the header.
+    Descending,
+}
+
+impl SortOrder {
+    /// The glyph appended to a sorted column's header.
+    pub fn indicator(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        }
+    }
+
+    /// The opposite sort direction.
+    #[must_use]
+    pub fn reversed(self) -> SortOrder {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// Parse a cell's plain text as a number, ignoring thousands separators and
+/// a trailing percent sign.
+fn parse_numeric(value: &str) -> Option<f64> {
+    let trimmed = value.trim();
+    let trimmed = trimmed.strip_suffix('%').unwrap_or(trimmed);
+    let cleaned: String = trimmed.chars().filter(|&c| c != ',' && c != '_').collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+    cleaned.parse::<f64>().ok().filter(|n| !n.is_nan())
+}
+
+/// Compare two cell values, numerically when both parse as numbers and
+/// case-insensitively otherwise. Numbers sort before non-numeric text.
+pub(crate) fn compare_values(a: &str, b: &str) -> Ordering {
+    match (parse_numeric(a), parse_numeric(b)) {
+        (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a
+            .trim()
+            .to_lowercase()
+            .cmp(&b.trim().to_lowercase())
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+impl Table {
+    /// Mark a column as sorted without reordering rows (builder pattern).
+    ///
+    /// Use this when the rows were already added in sorted order and only
+    /// the `▲`/`▼` header indicator is wanted.
+    #[must_use]
+    pub fn with_sort_indicator(mut self, column: usize, order: SortOrder) -> Self {
+        self.sorted_by = Some((column, order));
+        self
+    }
+
+    /// Sort the rows by the values in `column` and show a sort indicator in
+    /// that column's header.
+    ///
+    /// Values are compared numerically when both cells parse as numbers and
+    /// as case-insensitive strings otherwise. The sort is stable, and row
+    /// styles and section breaks move with their rows. Rows with no cell in
+    /// `column` sort last in either direction, and columns shorter than the
+    /// row count stay aligned with their rows. Out-of-range column indices
+    /// are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::{SortOrder, Table};
+    ///
+    /// let mut table = Table::new(&["Name", "Age"]);
+    /// table.add_row(&["Alice", "30"]);
+    /// table.add_row(&["Bob", "9"]);
+    /// table.sort_by_column(1, SortOrder::Ascending);
+    /// assert_eq!(table.columns[0].cells[0], "Bob");
+    /// assert!(format!("{}", table).contains("Age ▲"));
+    /// ```
+    pub fn sort_by_column(&mut self, column: usize, order: SortOrder) {
+        if column >= self.columns.len() {
+            return;
+        }
+        let cells = &self.columns[column].cells;
+        let keys: Vec<Option<String>> = (0..self.rows.len())
+            .map(|row| cells.get(row).map(CellContent::plain_text))
+            .collect();
+        let mut permutation: Vec<usize> = (0..self.rows.len()).collect();
+        permutation.sort_by(|&a, &b| match (&keys[a], &keys[b]) {
+            (Some(a), Some(b)) => {
+                let ordering = compare_values(a, b);
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        for col in &mut self.columns {
+            let mut cells: Vec<Option<CellContent>> = col.cells.drain(..).map(Some).collect();
+            // Missing cells become holes so the cells that exist land in
+            // their rows' new positions; trailing holes are dropped again.
+            let mut sorted: Vec<Option<CellContent>> = permutation
+                .iter()
+                .map(|&i| cells.get_mut(i).and_then(Option::take))
+                .collect();
+            while matches!(sorted.last(), Some(None)) {
+                sorted.pop();
+            }
+            col.cells = sorted
+                .into_iter()
+                .map(|cell| cell.unwrap_or_else(|| CellContent::Plain(String::new())))
+                .chain(cells.into_iter().flatten())
+                .collect();
+        }
+        let mut rows: Vec<_> = self.rows.drain(..).map(Some).collect();
+        self.rows = permutation
+            .iter()
+            .filter_map(|&i| rows.get_mut(i).and_then(Option::take))
+            .collect();
+        self.sorted_by = Some((column, order));
+    }
+
+    /// Re-sort by `column`, flipping the direction if the table is already
+    /// sorted by that column and sorting ascending otherwise.
+    pub fn toggle_sort(&mut self, column: usize) {
+        let order = match self.sorted_by {
+            Some((current, order)) if current == column => order.reversed(),
+            _ => SortOrder::Ascending,
+        };
+        self.sort_by_column(column, order);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column_values(table: &Table, column: usize) -> Vec<String> {
+        table.columns[column]
+            .cells
+            .iter()
+            .map(CellContent::plain_text)
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_numeric_aware() {
+        assert_eq!(compare_values("9", "10"), Ordering::Less);
+        assert_eq!(compare_values("1,200", "300"), Ordering::Greater);
+        assert_eq!(compare_values("5%", "50%"), Ordering::Less);
+        assert_eq!(compare_values("-2.5", "1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_compare_strings_case_insensitive() {
+        assert_eq!(compare_values("apple", "Banana"), Ordering::Less);
+        assert_eq!(compare_values("10", "abc"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_by_column_numeric() {
+        let mut table = Table::new(&["Name", "Count"]);
+        table.add_row(&["a", "10"]);
+        table.add_row(&["b", "9"]);
+        table.add_row(&["c", "100"]);
+        table.sort_by_column(1, SortOrder::Ascending);
+        assert_eq!(column_values(&table, 1), vec!["9", "10", "100"]);
+        assert_eq!(column_values(&table, 0), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_sort_descending_moves_row_metadata() {
+        let mut table = Table::new(&["Name"]);
+        table.add_row_styled(&["alpha"], Some("red"), false);
+        table.add_row(&["beta"]);
+        table.sort_by_column(0, SortOrder::Descending);
+        assert_eq!(column_values(&table, 0), vec!["beta", "alpha"]);
+        assert_eq!(table.rows[1].style.as_deref(), Some("red"));
+        assert_eq!(table.sorted_by, Some((0, SortOrder::Descending)));
+    }
+
+    #[test]
+    fn test_sort_ignores_markup() {
+        let mut table = Table::new(&["Name"]);
+        table.add_row(&["[bold]zed[/bold]"]);
+        table.add_row(&["amy"]);
+        table.sort_by_column(0, SortOrder::Ascending);
+        assert_eq!(column_values(&table, 0), vec!["amy", "zed"]);
+    }
+
+    #[test]
+    fn test_sort_with_short_column_keeps_rows_aligned() {
+        let mut table = Table::new(&["Name"]);
+        table.add_row(&["b"]);
+        table.add_row(&["c"]);
+        table.add_row(&["a"]);
+        table.add_column("Note", "", Default::default());
+        table.columns[1].cells.push(CellContent::from("for b"));
+
+        table.sort_by_column(0, SortOrder::Ascending);
+        assert_eq!(column_values(&table, 0), vec!["a", "b", "c"]);
+        assert_eq!(column_values(&table, 1), vec!["", "for b"]);
+
+        table.sort_by_column(1, SortOrder::Descending);
+        assert_eq!(column_values(&table, 0)[0], "b");
+        assert_eq!(column_values(&table, 1), vec!["for b", ""]);
+    }
+
+    #[test]
+    fn test_sort_out_of_range_is_noop() {
+        let mut table = Table::new(&["Name"]);
+        table.add_row(&["b"]);
+        table.add_row(&["a"]);
+        table.sort_by_column(5, SortOrder::Ascending);
+        assert_eq!(column_values(&table, 0), vec!["b", "a"]);
+        assert!(table.sorted_by.is_none());
+    }
+
+    #[test]
+    fn test_toggle_sort() {
+        let mut table = Table::new(&["N"]);
+        table.add_row(&["1"]);
+        table.add_row(&["2"]);
+        table.toggle_sort(0);
+        assert_eq!(table.sorted_by, Some((0, SortOrder::Ascending)));
+        table.toggle_sort(0);
+        assert_eq!(table.sorted_by, Some((0, SortOrder::Descending)));
+        assert_eq!(column_values(&table, 0), vec!["2", "1"]);
+    }
+
+    #[test]
+    fn test_header_indicator_rendered() {
+        let table = Table::new(&["Name", "Age"]).with_sort_indicator(0, SortOrder::Descending);
+        let output = format!("{}", table);
+        assert!(output.contains("Name ▼"));
+        assert!(!output.contains("Age ▼"));
+    }
+}
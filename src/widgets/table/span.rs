@@ -0,0 +1,393 @@
+//! Cell spanning -- cells that cover several columns and/or rows.
+
+use crate::console::Console;
+use crate::text::Text;
+use crate::utils::ratio::ratio_distribute;
+use crate::widgets::table::{CellContent, Row, Table};
+
+/// A cell that covers `cols` columns and `rows` rows, for use with
+/// [`Table::add_row_spans`].
+///
+/// Plain strings and [`Text`] values convert into single cells, so a row can
+/// mix ordinary and spanning cells.
+#[derive(Debug, Clone)]
+pub struct SpanCell {
+    /// The cell content.
+    pub content: CellContent,
+    /// Number of columns covered (at least 1).
+    pub cols: usize,
+    /// Number of rows covered (at least 1).
+    pub rows: usize,
+}
+
+impl SpanCell {
+    /// Create a cell covering a single column and row.
+    pub fn new(content: impl Into<CellContent>) -> Self {
+        SpanCell {
+            content: content.into(),
+            cols: 1,
+            rows: 1,
+        }
+    }
+
+    /// Set the number of columns covered (builder pattern).
+    #[must_use]
+    pub fn with_cols(mut self, cols: usize) -> Self {
+        self.cols = cols.max(1);
+        self
+    }
+
+    /// Set the number of rows covered (builder pattern).
+    #[must_use]
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+}
+
+impl From<&str> for SpanCell {
+    fn from(s: &str) -> Self {
+        SpanCell::new(s)
+    }
+}
+
+impl From<String> for SpanCell {
+    fn from(s: String) -> Self {
+        SpanCell::new(s)
+    }
+}
+
+impl From<Text> for SpanCell {
+    fn from(t: Text) -> Self {
+        SpanCell::new(t)
+    }
+}
+
+impl From<CellContent> for SpanCell {
+    fn from(content: CellContent) -> Self {
+        SpanCell::new(content)
+    }
+}
+
+/// Position and extent of a spanning cell that starts in a [`Row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellSpan {
+    /// Column the cell starts in.
+    pub column: usize,
+    /// Number of columns covered.
+    pub cols: usize,
+    /// Number of rows covered.
+    pub rows: usize,
+}
+
+impl Row {
+    /// Create a cell spanning `cols` columns.
+    ///
+    /// Chain [`SpanCell::with_rows`] to span rows as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::{Row, Table};
+    ///
+    /// let mut table = Table::new(&["Item", "Q1", "Q2"]);
+    /// table.add_row(&["Apples", "3", "4"]);
+    /// table.add_row_spans(&[Row::span("Total: 7", 3)]);
+    /// assert!(format!("{}", table).contains("Total: 7"));
+    /// ```
+    pub fn span(cell: impl Into<CellContent>, cols: usize) -> SpanCell {
+        SpanCell::new(cell).with_cols(cols)
+    }
+
+    /// Cells starting in this row that span several columns or rows.
+    pub fn spans(&self) -> &[CellSpan] {
+        &self.spans
+    }
+}
+
+/// Which cell covers each position of the table (internal).
+///
+/// Every position maps to the `(row, column)` where its cell starts; a cell
+/// that does not span anything owns only itself.
+pub(crate) struct SpanGrid {
+    owners: Vec<Vec<(usize, usize)>>,
+}
+
+impl SpanGrid {
+    /// Let the cell at `(row, column)` cover `cols` columns and `rows` rows.
+    pub(crate) fn merge(&mut self, row: usize, column: usize, cols: usize, rows: usize) {
+        for cells in self.owners.iter_mut().skip(row).take(rows) {
+            for owner in cells.iter_mut().skip(column).take(cols) {
+                *owner = (row, column);
+            }
+        }
+    }
+
+    /// The position of the cell covering `(row, column)`.
+    pub(crate) fn owner(&self, row: usize, column: usize) -> (usize, usize) {
+        self.owners
+            .get(row)
+            .and_then(|cells| cells.get(column))
+            .copied()
+            .unwrap_or((row, column))
+    }
+
+    /// The `(cols, rows)` covered by the cell starting at `(row, column)`, or
+    /// `None` if that position is covered by another cell.
+    pub(crate) fn extent(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        let owner = (row, column);
+        if self.owner(row, column) != owner {
+            return None;
+        }
+        let width = self.owners.get(row).map_or(0, Vec::len);
+        let cols = (column..width)
+            .take_while(|&c| self.owner(row, c) == owner)
+            .count();
+        let rows = (row..self.owners.len())
+            .take_while(|&r| self.owner(r, column) == owner)
+            .count();
+        Some((cols.max(1), rows.max(1)))
+    }
+}
+
+impl Table {
+    /// Add a row whose cells may span several columns or rows.
+    ///
+    /// Cells fill the columns from left to right, skipping positions still
+    /// covered by a cell spanning down from an earlier row. Separators and
+    /// column dividers are omitted inside a spanning cell, and columns are
+    /// widened when a cell does not fit the columns it covers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::{Row, Table};
+    ///
+    /// let mut table = Table::new(&["Region", "Item", "Sold"]);
+    /// table.add_row_spans(&[Row::span("North", 1).with_rows(2), "Apples".into(), "3".into()]);
+    /// table.add_row_spans(&["Pears".into(), "5".into()]);
+    /// assert_eq!(table.columns[1].cells[1], "Pears");
+    /// assert_eq!(table.rows[0].spans()[0].rows, 2);
+    /// ```
+    pub fn add_row_spans(&mut self, cells: &[SpanCell]) {
+        self.add_row_spans_styled(cells, None, false);
+    }
+
+    /// Add a row of spanning cells with an optional style and section break.
+    ///
+    /// Combines [`add_row_spans`](Self::add_row_spans) with the per-row style
+    /// and section-break support of [`add_row_styled`](Self::add_row_styled).
+    pub fn add_row_spans_styled(
+        &mut self,
+        cells: &[SpanCell],
+        style: Option<&str>,
+        end_section: bool,
+    ) {
+        let row_index = self.rows.len();
+        let mut occupied: Vec<bool> = Vec::new();
+        for (index, row) in self.rows.iter().enumerate() {
+            for span in row.spans.iter().filter(|s| index + s.rows > row_index) {
+                let end = span.column + span.cols;
+                if occupied.len() < end {
+                    occupied.resize(end, false);
+                }
+                occupied[span.column..end].fill(true);
+            }
+        }
+        let is_occupied = |column: usize| occupied.get(column).copied().unwrap_or(false);
+
+        let mut contents: Vec<CellContent> = Vec::new();
+        let mut spans = Vec::new();
+        for cell in cells {
+            while is_occupied(contents.len()) {
+                contents.push(CellContent::Plain(String::new()));
+            }
+            let column = contents.len();
+            // A cell stops short of positions claimed by an earlier row span.
+            let cols = (column..column + cell.cols.max(1))
+                .take_while(|&c| !is_occupied(c))
+                .count();
+            let rows = cell.rows.max(1);
+            contents.push(cell.content.clone());
+            contents.extend(std::iter::repeat_n(
+                CellContent::Plain(String::new()),
+                cols - 1,
+            ));
+            if cols > 1 || rows > 1 {
+                spans.push(CellSpan { column, cols, rows });
+            }
+        }
+
+        self.add_row_contents(&contents, style, end_section);
+        if let Some(row) = self.rows.last_mut() {
+            row.spans = spans;
+        }
+    }
+
+    /// Map every cell position to the cell covering it.
+    ///
+    /// Data row `i` is placed at grid row `first_row + i` in a grid of
+    /// `num_rows` rows; the remaining rows (header and footer) never span.
+    /// Spans are clipped to the data rows and columns, and a span that would
+    /// overlap an earlier one is ignored.
+    pub(crate) fn span_grid(&self, first_row: usize, num_rows: usize) -> SpanGrid {
+        let num_cols = self.columns.len();
+        let mut owners: Vec<Vec<(usize, usize)>> = (0..num_rows)
+            .map(|r| (0..num_cols).map(|c| (r, c)).collect())
+            .collect();
+        let data_end = (first_row + self.rows.len()).min(num_rows);
+        let mut claimed = vec![vec![false; num_cols]; num_rows];
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let r0 = first_row + index;
+            for span in &row.spans {
+                let c0 = span.column;
+                let r1 = (r0 + span.rows).min(data_end);
+                let c1 = (c0 + span.cols).min(num_cols);
+                if r0 >= r1 || c0 >= c1 {
+                    continue;
+                }
+                if (r0..r1).any(|r| claimed[r][c0..c1].iter().any(|&taken| taken)) {
+                    continue;
+                }
+                for r in r0..r1 {
+                    for c in c0..c1 {
+                        owners[r][c] = (r0, c0);
+                        claimed[r][c] = true;
+                    }
+                }
+            }
+        }
+
+        SpanGrid { owners }
+    }
+
+    /// Data rows whose cell in `column` starts a span over several columns.
+    ///
+    /// Such cells are left out of the column's own measurement and fitted by
+    /// [`widen_for_spans`](Self::widen_for_spans) instead.
+    pub(crate) fn multi_column_rows(&self, column: usize) -> Vec<usize> {
+        if self.rows.iter().all(|r| r.spans.is_empty()) {
+            return Vec::new();
+        }
+        let grid = self.span_grid(0, self.rows.len());
+        (0..self.rows.len())
+            .filter(|&r| grid.extent(r, column).is_some_and(|(cols, _)| cols > 1))
+            .collect()
+    }
+
+    /// Grow `widths` so every cell spanning several columns (and every column
+    /// group title) fits its content, keeping the total within `limit`.
+    pub(crate) fn widen_for_spans(&self, console: &Console, widths: &mut [usize], limit: usize) {
+        // (first column, columns covered, width needed)
+        let mut needs: Vec<(usize, usize, usize)> = Vec::new();
+        if self.shows_groups() {
+            for (first, count, group) in self.visible_groups() {
+                let cell = self.group_cell(console, first, count, group);
+                needs.push((first, count, cell.renderable.measure().maximum));
+            }
+        }
+        if self.rows.iter().any(|r| !r.spans.is_empty()) {
+            let grid = self.span_grid(0, self.rows.len());
+            let header_offset = usize::from(self.show_header);
+            for (column_index, column) in self.columns.iter().enumerate() {
+                let rows = self.multi_column_rows(column_index);
+                if rows.is_empty() {
+                    continue;
+                }
+                let cells = self.get_cells(console, column_index, column);
+                for row in rows {
+                    if let (Some((cols, _)), Some(cell)) = (
+                        grid.extent(row, column_index),
+                        cells.get(row + header_offset),
+                    ) {
+                        needs.push((column_index, cols, cell.renderable.measure().maximum));
+                    }
+                }
+            }
+        }
+
+        let divider_width = usize::from(self.box_chars.is_some());
+        for (column_index, cols, needed) in needs {
+            let room = limit.saturating_sub(widths.iter().sum());
+            let end = (column_index + cols).min(widths.len());
+            let covered = &mut widths[column_index..end];
+            let available = covered.iter().sum::<usize>() + divider_width * (cols - 1);
+            let deficit = needed.saturating_sub(available).min(room);
+            if deficit == 0 {
+                continue;
+            }
+            let mut ratios: Vec<usize> = self.columns[column_index..end]
+                .iter()
+                .zip(covered.iter())
+                .map(|(col, &w)| if col.width.is_none() { w.max(1) } else { 0 })
+                .collect();
+            if ratios.iter().all(|&r| r == 0) {
+                ratios.fill(1);
+            }
+            for (w, extra) in covered
+                .iter_mut()
+                .zip(ratio_distribute(deficit, &ratios, None))
+            {
+                *w += extra;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_span_clamps_to_one() {
+        let cell = Row::span("x", 0).with_rows(0);
+        assert_eq!((cell.cols, cell.rows), (1, 1));
+    }
+
+    #[test]
+    fn test_add_row_spans_skips_columns_covered_from_above() {
+        let mut table = Table::new(&["A", "B", "C"]);
+        table.add_row_spans(&["a".into(), Row::span("b", 2).with_rows(2)]);
+        table.add_row_spans(&["c".into(), "d".into()]);
+        assert_eq!(table.columns[0].cells[1], "c");
+        assert_eq!(table.columns[1].cells[1], "");
+        assert_eq!(table.columns[2].cells[1], "");
+        // The second cell no longer fits and creates a column of its own.
+        assert_eq!(table.columns[3].cells[1], "d");
+        assert_eq!(
+            table.rows[0].spans,
+            vec![CellSpan {
+                column: 1,
+                cols: 2,
+                rows: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_span_grid_owners_and_extent() {
+        let mut table = Table::new(&["A", "B", "C"]);
+        table.add_row_spans(&[Row::span("a", 2), "b".into()]);
+        table.add_row_spans(&["c".into(), Row::span("d", 2).with_rows(5)]);
+        let grid = table.span_grid(1, 4);
+        assert_eq!(grid.owner(1, 1), (1, 0));
+        assert_eq!(grid.extent(1, 0), Some((2, 1)));
+        assert_eq!(grid.extent(1, 1), None);
+        // The row span is clipped to the data rows, leaving the footer alone.
+        assert_eq!(grid.extent(2, 1), Some((2, 1)));
+        assert_eq!(grid.owner(3, 2), (3, 2));
+    }
+
+    #[test]
+    fn test_widen_for_spans_grows_covered_columns() {
+        let console = Console::builder().width(80).build();
+        let mut table = Table::new(&["A", "B"]);
+        table.add_row_spans(&[Row::span("a rather long summary", 2)]);
+        let mut widths = vec![3, 3];
+        table.widen_for_spans(&console, &mut widths, usize::MAX);
+        // 21 chars + 2 padding, minus the divider between the columns
+        assert_eq!(widths.iter().sum::<usize>(), 22);
+        assert!(table.multi_column_rows(0) == vec![0]);
+    }
+}
@@ -0,0 +1,121 @@
+//! Vertical layout -- one record per column, with field names down the left.
+
+use crate::widgets::table::{CellContent, CellSpan, Column, Row, Table};
+
+impl Table {
+    /// Return a copy of the table laid out vertically: column headers become
+    /// the field names in the first column, and each row becomes a column.
+    ///
+    /// This is the natural layout for records with many fields and few rows,
+    /// in the style of `kubectl describe`. The copy keeps the box, padding,
+    /// title, caption and styles; field names use the header style, and each
+    /// record column takes its row's style. The header and footer rows are
+    /// hidden, row and column spans are swapped, and column groups and sort
+    /// state are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gilt::table::Table;
+    ///
+    /// let mut table = Table::new(&["Name", "Namespace", "Status"]);
+    /// table.add_row(&["web-7d4f", "default", "Running"]);
+    /// let vertical = table.transposed();
+    /// assert_eq!(vertical.columns.len(), 2);
+    /// assert_eq!(vertical.columns[0].cells[1], "Namespace");
+    /// assert_eq!(vertical.columns[1].cells[2], "Running");
+    /// ```
+    pub fn transposed(&self) -> Table {
+        let mut table = self.clone();
+        table.columns.clear();
+        table.rows.clear();
+        table.column_groups.clear();
+        table.row_styles.clear();
+        table.sorted_by = None;
+        table.width_memory = None;
+        table.show_header = false;
+        table.show_footer = false;
+
+        table.columns.push(Column {
+            style: self.header_style.clone(),
+            no_wrap: true,
+            highlight: self.highlight,
+            index: 0,
+            cells: self
+                .columns
+                .iter()
+                .map(|column| CellContent::Plain(column.header.clone()))
+                .collect(),
+            ..Default::default()
+        });
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let row_style = self
+                .row_styles
+                .get(index % self.row_styles.len().max(1))
+                .into_iter()
+                .chain(&row.style)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" ");
+            table.columns.push(Column {
+                style: row_style,
+                highlight: self.highlight,
+                index: index + 1,
+                cells: self
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        column
+                            .cells
+                            .get(index)
+                            .cloned()
+                            .unwrap_or_else(|| CellContent::Plain(String::new()))
+                    })
+                    .collect(),
+                ..Default::default()
+            });
+        }
+
+        table.rows = vec![Row::default(); self.columns.len()];
+        for (index, row) in self.rows.iter().enumerate() {
+            for span in &row.spans {
+                if let Some(target) = table.rows.get_mut(span.column) {
+                    target.spans.push(CellSpan {
+                        column: index + 1,
+                        cols: span.rows,
+                        rows: span.cols,
+                    });
+                }
+            }
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::table::{Row, Table};
+
+    #[test]
+    fn test_transposed_layout() {
+        let mut table = Table::new(&["Name", "Age"]).with_box_chars(None);
+        table.add_row(&["Alice", "30"]);
+        table.add_row(&["Bob", "25"]);
+        let output = format!("{}", table.transposed());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec![" Name    Alice    Bob", " Age     30       25"]);
+    }
+
+    #[test]
+    fn test_transposed_swaps_spans_and_row_styles() {
+        let mut table = Table::new(&["A", "B"]).with_row_styles(vec!["dim".into()]);
+        table.add_row_spans_styled(&[Row::span("wide", 2)], Some("bold"), false);
+        let vertical = table.transposed();
+        assert_eq!(vertical.columns[1].style, "dim bold");
+        assert_eq!(vertical.rows[0].spans[0].column, 1);
+        assert_eq!(vertical.rows[0].spans[0].rows, 2);
+        assert_eq!(vertical.rows[0].spans[0].cols, 1);
+    }
+}
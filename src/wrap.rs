@@ -4,12 +4,19 @@
 //! line break positions for word wrapping, respecting cell widths for CJK and
 //! other double-width characters.
 //!
-//! Port of Python rich's `_wrap.py`.
+//! Port of Python rich's `_wrap.py`, extended with line-breaking rules rich
+//! lacks:
+//!
+//! - Non-breaking spaces (U+00A0, U+2007, U+202F) never start a new line.
+//! - CJK ideographs and kana may break between any two characters, except
+//!   that closing punctuation such as `。` or `」` never starts a line and
+//!   opening punctuation such as `「` never ends one.
+//! - Words can optionally be hyphenated with [`line_breaks`].
 
 use regex::Regex;
 use std::sync::LazyLock;
 
-use crate::cells::{cell_len, chop_cells};
+use crate::cells::{cell_len, chop_cells, get_character_cell_size};
 
 /// Regex matching a "word" — optional leading whitespace, then non-whitespace,
 /// then optional trailing whitespace.
@@ -67,6 +74,109 @@ fn build_byte_to_char_map(text: &str) -> Vec<usize> {
     map
 }
 
+/// Characters that may not start a line: closing brackets and quotes,
+/// CJK stops and commas, small kana and iteration marks.
+const NO_LINE_START: &str = "、。，．：；！？）］｝〕〉》」』】〙〗〟’”｠»ゝゞーァィゥェォッャュョヮヵヶぁぃぅぇぉっゃゅょゎゕゖㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ々〻‐゠–〜・,.:;!?)]}%";
+
+/// Characters that may not end a line: opening brackets and quotes.
+const NO_LINE_END: &str = "（［｛〔〈《「『【〘〖〝‘“｟«([{";
+
+/// Whether `c` is whitespace a line may break at. Non-breaking spaces are
+/// whitespace to Unicode but keep their neighbours on one line.
+fn is_break_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\u{00A0}' | '\u{2007}' | '\u{202F}')
+}
+
+/// Whether `c` belongs to a script written without spaces, where a line may
+/// break between any two characters (CJK ideographs, kana, and CJK symbols
+/// and full-width forms).
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x2E80..=0x2FDF
+            | 0x3000..=0x30FF
+            | 0x3100..=0x31FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x20000..=0x3FFFF
+    )
+}
+
+/// Split text into the units line wrapping moves as a whole, as
+/// `(char_start, char_end, unit_slice)`.
+///
+/// Like [`words`], a unit carries the whitespace that follows it, but
+/// non-breaking spaces join their neighbours, every CJK character is its own
+/// unit, and units are merged where a break would leave closing punctuation
+/// at the start of a line or opening punctuation at the end of one.
+///
+/// # Examples
+///
+/// ```
+/// use gilt::wrap::wrap_units;
+///
+/// let units: Vec<&str> = wrap_units("漢字「かな」です。 ok").iter().map(|u| u.2).collect();
+/// assert_eq!(units, vec!["漢", "字", "「か", "な」", "で", "す。 ", "ok"]);
+/// ```
+pub fn wrap_units(text: &str) -> Vec<(usize, usize, &str)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let n = chars.len();
+    let is_space = |i: usize| is_break_space(chars[i].1);
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let start = i;
+        while i < n && is_space(i) {
+            i += 1;
+        }
+        if i == n {
+            break;
+        }
+        if is_cjk(chars[i].1) {
+            i += 1;
+        } else {
+            while i < n && !is_space(i) && !is_cjk(chars[i].1) {
+                i += 1;
+            }
+        }
+        while i < n && is_space(i) {
+            i += 1;
+        }
+
+        match ranges.last_mut() {
+            Some(prev)
+                if !is_space(prev.1 - 1)
+                    && (NO_LINE_START.contains(chars[start].1)
+                        || NO_LINE_END.contains(chars[prev.1 - 1].1)) =>
+            {
+                prev.1 = i;
+            }
+            _ => ranges.push((start, i)),
+        }
+    }
+
+    let byte = |i: usize| chars.get(i).map_or(text.len(), |&(b, _)| b);
+    ranges
+        .into_iter()
+        .map(|(start, end)| (start, end, &text[byte(start)..byte(end)]))
+        .collect()
+}
+
+/// A line break chosen by [`line_breaks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineBreak {
+    /// Char index where the new line starts.
+    pub offset: usize,
+    /// Whether the break splits a word, so the line before it should end
+    /// with a hyphen.
+    pub hyphen: bool,
+}
+
 /// Given text and a cell width, return char-index offsets where the string
 /// should be split for word wrapping.
 ///
@@ -85,14 +195,73 @@ fn build_byte_to_char_map(text: &str) -> Vec<usize> {
 /// assert_eq!(divide_line("abracadabra", 4, true), vec![4, 8]);
 /// ```
 pub fn divide_line(text: &str, width: usize, fold: bool) -> Vec<usize> {
+    line_breaks(text, width, fold, None)
+        .into_iter()
+        .map(|line_break| line_break.offset)
+        .collect()
+}
+
+/// [`divide_line`], optionally hyphenating words.
+///
+/// With `hyphenate` set to `Some(min_fragment)`, an alphabetic word that
+/// overflows the line is split with a hyphen, keeping at least
+/// `min_fragment` characters on each side; the line before such a break
+/// needs a `-` appended. Words that cannot be hyphenated wrap as in
+/// [`divide_line`].
+///
+/// # Examples
+///
+/// ```
+/// use gilt::wrap::{line_breaks, LineBreak};
+///
+/// let breaks = line_breaks("the wrapping", 8, true, Some(3));
+/// assert_eq!(breaks, vec![LineBreak { offset: 7, hyphen: true }]);
+/// // "the wra-" / "pping"
+/// ```
+pub fn line_breaks(
+    text: &str,
+    width: usize,
+    fold: bool,
+    hyphenate: Option<usize>,
+) -> Vec<LineBreak> {
     if width == 0 {
         return vec![];
     }
 
-    let mut break_positions: Vec<usize> = Vec::new();
+    let mut break_positions: Vec<LineBreak> = Vec::new();
     let mut cell_offset: usize = 0;
+    let push_break = |breaks: &mut Vec<LineBreak>, offset: usize, hyphen: bool| {
+        if offset > 0 && breaks.last().is_none_or(|last| last.offset != offset) {
+            breaks.push(LineBreak { offset, hyphen });
+        }
+    };
+
+    for (start, _end, word) in wrap_units(text) {
+        let (mut start, mut word) = (start, word);
+        if let Some(min_fragment) = hyphenate {
+            // Hyphenate for as long as the word overflows the line.
+            loop {
+                let word_length = cell_len(word.trim_end());
+                let remaining_space = width.saturating_sub(cell_offset);
+                if remaining_space >= word_length {
+                    break;
+                }
+                if let Some(head) = hyphen_point(word, remaining_space, min_fragment) {
+                    push_break(&mut break_positions, start + head, true);
+                    let split = word.char_indices().nth(head).map_or(word.len(), |(b, _)| b);
+                    word = &word[split..];
+                    start += head;
+                    cell_offset = 0;
+                } else if cell_offset > 0 && word_length > width {
+                    // Too little room here; hyphenate from a fresh line.
+                    push_break(&mut break_positions, start, false);
+                    cell_offset = 0;
+                } else {
+                    break;
+                }
+            }
+        }
 
-    for (start, _end, word) in words(text) {
         let word_length = cell_len(word.trim_end());
         let remaining_space = width.saturating_sub(cell_offset);
         let word_fits_remaining_space = remaining_space >= word_length;
@@ -107,28 +276,21 @@ pub fn divide_line(text: &str, width: usize, fold: bool) -> Vec<usize> {
 
                 for (i, line) in folded_word.iter().enumerate() {
                     let is_last = i == num_pieces - 1;
+                    push_break(&mut break_positions, current_start, false);
                     if is_last {
-                        if current_start > 0 {
-                            break_positions.push(current_start);
-                        }
                         cell_offset = cell_len(line);
                     } else {
-                        if current_start > 0 {
-                            break_positions.push(current_start);
-                        }
                         current_start += line.chars().count();
                     }
                 }
             } else {
-                if start > 0 {
-                    break_positions.push(start);
-                }
+                push_break(&mut break_positions, start, false);
                 cell_offset = cell_len(word);
             }
         } else {
             // Word fits on a fresh line but not remaining space.
-            if cell_offset > 0 && start > 0 {
-                break_positions.push(start);
+            if cell_offset > 0 {
+                push_break(&mut break_positions, start, false);
             }
             cell_offset = cell_len(word);
         }
@@ -137,6 +299,29 @@ pub fn divide_line(text: &str, width: usize, fold: bool) -> Vec<usize> {
     break_positions
 }
 
+/// Where to hyphenate `word` so that its head plus a hyphen fits in `space`
+/// cells, as a char count, keeping at least `min_fragment` characters on
+/// each side. Only purely alphabetic, non-CJK words are hyphenated.
+fn hyphen_point(word: &str, space: usize, min_fragment: usize) -> Option<usize> {
+    let core = word.trim_end();
+    if !core.chars().all(|c| c.is_alphabetic() && !is_cjk(c)) {
+        return None;
+    }
+    let min_fragment = min_fragment.max(1);
+    let total = core.chars().count();
+    let mut cells = 1; // the hyphen
+    let mut head = 0;
+    for c in core.chars() {
+        cells += get_character_cell_size(c);
+        if cells > space {
+            break;
+        }
+        head += 1;
+    }
+    let head = head.min(total.saturating_sub(min_fragment));
+    (head >= min_fragment).then_some(head)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -433,4 +618,110 @@ mod tests {
         let map = build_byte_to_char_map("");
         assert_eq!(map, vec![0]);
     }
+
+    // -----------------------------------------------------------------------
+    // wrap_units() and line-breaking rules
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_wrap_units_match_words_for_plain_text() {
+        let text = "  foo bar   baz ";
+        assert_eq!(wrap_units(text), words(text));
+    }
+
+    #[test]
+    fn test_wrap_units_non_breaking_space_joins_words() {
+        assert_eq!(
+            wrap_units("10\u{00A0}km away"),
+            vec![(0, 6, "10\u{00A0}km "), (6, 10, "away")]
+        );
+    }
+
+    #[test]
+    fn test_divide_line_keeps_non_breaking_space_together() {
+        // Without the rule, "km" would wrap away from "10".
+        assert_eq!(divide_line("go 10\u{00A0}km", 6, true), vec![3]);
+    }
+
+    #[test]
+    fn test_divide_line_breaks_between_ideographs() {
+        // "中文" fits after "ab " only partially; it now breaks inside the run.
+        assert_eq!(divide_line("ab 中文字", 5, true), vec![4]);
+    }
+
+    #[test]
+    fn test_divide_line_closing_punctuation_stays_with_previous() {
+        // A break before "。" would start a line with it.
+        assert_eq!(divide_line("中文字。", 6, true), vec![2]);
+        assert_eq!(divide_line("中文「字」", 6, true), vec![2]);
+    }
+
+    #[test]
+    fn test_divide_line_opening_punctuation_stays_with_next() {
+        assert_eq!(divide_line("中文字「本」", 8, true), vec![3]);
+    }
+
+    // -----------------------------------------------------------------------
+    // line_breaks() hyphenation
+    // -----------------------------------------------------------------------
+
+    fn hyphenated(text: &str, width: usize, min_fragment: usize) -> Vec<(usize, bool)> {
+        line_breaks(text, width, true, Some(min_fragment))
+            .into_iter()
+            .map(|b| (b.offset, b.hyphen))
+            .collect()
+    }
+
+    #[test]
+    fn test_line_breaks_without_hyphenation_match_divide_line() {
+        for (text, width) in [
+            ("foo bar baz", 3),
+            ("XX 12345678912", 4),
+            ("ああああああ", 5),
+        ] {
+            let offsets: Vec<usize> = line_breaks(text, width, true, None)
+                .into_iter()
+                .map(|b| b.offset)
+                .collect();
+            assert_eq!(offsets, divide_line(text, width, true));
+        }
+    }
+
+    #[test]
+    fn test_hyphenate_word_into_remaining_space() {
+        // "the wra-" / "pping"
+        assert_eq!(hyphenated("the wrapping", 8, 3), vec![(7, true)]);
+    }
+
+    #[test]
+    fn test_hyphenate_respects_min_fragment() {
+        // Only 2 characters plus a hyphen fit after "the ", so the word
+        // moves to a fresh line: "the" / "wrapp-" / "ing".
+        assert_eq!(
+            hyphenated("the wrapping", 7, 3),
+            vec![(4, false), (9, true)]
+        );
+        // The tail keeps the minimum fragment: "a abc-" / "def".
+        assert_eq!(hyphenated("a abcdef", 7, 3), vec![(5, true)]);
+        assert_eq!(hyphenated("a abcde", 6, 3), vec![(2, false)]);
+    }
+
+    #[test]
+    fn test_hyphenate_folds_long_words() {
+        // "abcd-" / "efgh-" / "ijkl"
+        assert_eq!(hyphenated("abcdefghijkl", 5, 2), vec![(4, true), (8, true)]);
+        // After a short word the long word starts on a fresh line.
+        assert_eq!(
+            hyphenated("x abcdefghijkl", 5, 3),
+            vec![(2, false), (6, true), (10, true)]
+        );
+    }
+
+    #[test]
+    fn test_hyphenate_skips_non_alphabetic_words() {
+        assert_eq!(
+            hyphenated("x 1234567890", 5, 2),
+            vec![(2, false), (7, false)]
+        );
+    }
 }
@@ -0,0 +1,53 @@
+#![cfg(feature = "derive")]
+
+use gilt::inspect::{FieldInfo, InspectInfo, MethodInfo};
+use gilt::DeriveInspectInfo;
+
+/// A point on the
+/// plane.
+#[derive(Debug, DeriveInspectInfo)]
+#[inspect_info(method = "fn norm(&self) -> f64")]
+#[allow(dead_code)]
+struct Point {
+    /// Horizontal position.
+    x: f64,
+    y: f64,
+    #[inspect_info(skip)]
+    cache: Option<Vec<u8>>,
+}
+
+#[derive(Debug, DeriveInspectInfo)]
+#[allow(dead_code)]
+struct Wrapper<T> {
+    items: Vec<T>,
+}
+
+#[test]
+fn test_derive_inspect_info_doc_and_fields() {
+    let point = Point {
+        x: 1.0,
+        y: 2.0,
+        cache: None,
+    };
+    assert_eq!(point.doc().as_deref(), Some("A point on the plane."));
+    assert_eq!(
+        point.fields(),
+        vec![
+            FieldInfo::new("x", "f64").with_doc("Horizontal position."),
+            FieldInfo::new("y", "f64"),
+        ]
+    );
+    assert_eq!(
+        point.methods(),
+        vec![MethodInfo::new("fn norm(&self) -> f64")]
+    );
+    assert!(point.metadata().is_empty());
+}
+
+#[test]
+fn test_derive_inspect_info_generic_struct() {
+    let wrapper = Wrapper { items: vec![1u8] };
+    assert_eq!(wrapper.doc(), None);
+    assert_eq!(wrapper.fields(), vec![FieldInfo::new("items", "Vec<T>")]);
+    assert!(wrapper.methods().is_empty());
+}
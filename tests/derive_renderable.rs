@@ -0,0 +1,115 @@
+#![cfg(feature = "derive")]
+
+use gilt::console::Console;
+use gilt::measure::Measure;
+use gilt::{DeriveColumns, DeriveInspect, DeriveRule, Panel, Renderable, Table};
+
+#[derive(Table, Renderable)]
+#[renderable(via = "table")]
+struct Job {
+    name: String,
+    state: String,
+}
+
+#[derive(DeriveColumns, Renderable)]
+#[renderable(via = "columns")]
+struct Host {
+    name: String,
+}
+
+#[derive(DeriveRule, Renderable)]
+#[renderable(via = "rule")]
+#[rule(characters = "-")]
+struct Section {
+    #[rule(title)]
+    heading: String,
+}
+
+#[derive(Debug, DeriveInspect, Renderable)]
+#[renderable(via = "inspect")]
+#[allow(dead_code)]
+struct Probe {
+    value: u32,
+}
+
+#[derive(Panel)]
+struct Config {
+    port: u16,
+}
+
+#[derive(Renderable)]
+enum Entry {
+    #[renderable(via = "panel")]
+    Config(Config),
+    Job {
+        job: Job,
+    },
+    #[renderable(via = "table")]
+    Raw(Job),
+    Separator,
+}
+
+fn render(renderable: &dyn gilt::console::Renderable, width: usize) -> String {
+    let mut console = Console::builder()
+        .width(width)
+        .force_terminal(true)
+        .no_color(true)
+        .build();
+    console.begin_capture();
+    console.print(renderable);
+    console.end_capture()
+}
+
+fn job() -> Job {
+    Job {
+        name: "build".into(),
+        state: "ok".into(),
+    }
+}
+
+#[test]
+fn test_struct_vias_render() {
+    let out = render(&job(), 40);
+    assert!(out.contains("build") && out.contains("State"), "{out}");
+
+    let out = render(&Host { name: "db1".into() }, 40);
+    assert!(out.contains("db1") && out.contains("╭"), "{out}");
+
+    let out = render(
+        &Section {
+            heading: "Logs".into(),
+        },
+        20,
+    );
+    assert_eq!(out, "------- Logs -------\n");
+
+    let out = render(&Probe { value: 7 }, 40);
+    assert!(out.contains("Probe") && out.contains('7'), "{out}");
+}
+
+#[test]
+fn test_enum_variants_render() {
+    let out = render(&Entry::Config(Config { port: 8080 }), 40);
+    assert!(out.contains("8080"), "{out}");
+
+    let out = render(&Entry::Job { job: job() }, 40);
+    assert!(out.contains("build"), "{out}");
+
+    let out = render(&Entry::Raw(job()), 40);
+    assert!(out.contains("build"), "{out}");
+
+    assert_eq!(render(&Entry::Separator, 40), "Separator\n");
+}
+
+#[test]
+fn test_enum_measure_delegates() {
+    let console = Console::builder().width(40).build();
+    let options = console.options();
+    let m = Entry::Separator.measure(&console, &options);
+    assert_eq!((m.minimum, m.maximum), (9, 9));
+
+    let section = Section {
+        heading: "x".into(),
+    };
+    assert_eq!(section.measure(&console, &options).maximum, 40);
+}
@@ -89,3 +89,175 @@ fn test_derive_table_snake_case_headers() {
     assert_eq!(table.columns[1].header, "Last Name");
     assert_eq!(table.columns[2].header, "Employee Id");
 }
+
+struct Department {
+    name: String,
+}
+
+struct Staff {
+    name: String,
+    department: usize,
+}
+
+#[derive(Table)]
+#[table(title = "Assignments")]
+struct Assignment<'a>(
+    #[column(header = "Name")] &'a str,
+    #[column(header = "Department", style = "green")] &'a str,
+);
+
+#[test]
+fn test_derive_table_tuple_struct_of_references() {
+    let departments = [
+        Department {
+            name: "Engineering".into(),
+        },
+        Department {
+            name: "Marketing".into(),
+        },
+    ];
+    let staff = [
+        Staff {
+            name: "Alice".into(),
+            department: 0,
+        },
+        Staff {
+            name: "Bob".into(),
+            department: 1,
+        },
+    ];
+    let table = Assignment::to_table_from_iter(
+        staff
+            .iter()
+            .map(|s| Assignment(&s.name, &departments[s.department].name)),
+    );
+    assert_eq!(table.title.as_deref(), Some("Assignments"));
+    assert_eq!(table.columns[0].header, "Name");
+    assert_eq!(table.columns[1].header, "Department");
+    assert_eq!(table.columns[1].style, "green");
+    assert_eq!(table.rows.len(), 2);
+    let rendered = format!("{}", table);
+    assert!(rendered.contains("Bob"));
+    assert!(rendered.contains("Marketing"));
+}
+
+#[derive(Table)]
+struct Pair(u32, #[column(skip)] u32, String);
+
+#[test]
+fn test_derive_table_tuple_struct_default_headers() {
+    let rows = vec![Pair(1, 99, "one".into())];
+    let table = Pair::to_table(&rows);
+    assert_eq!(table.columns.len(), 2);
+    assert_eq!(table.columns[0].header, "Column 1");
+    assert_eq!(table.columns[1].header, "Column 3");
+    assert!(!format!("{}", table).contains(&rows[0].1.to_string()));
+}
+
+#[derive(Table)]
+#[table(border_style = "$border")]
+struct Service {
+    #[column(style = "$accent")]
+    name: String,
+}
+
+#[test]
+fn test_derive_table_theme_tokens_resolve_at_render_time() {
+    use gilt::console::Console;
+    use gilt::style::Style;
+    use gilt::theme::Theme;
+    use std::collections::HashMap;
+
+    let table = Service::to_table(&[Service { name: "api".into() }]);
+    assert_eq!(table.columns[0].style, "$accent");
+
+    let render_with = |accent: &str| {
+        let styles = HashMap::from([
+            ("accent".to_string(), Style::parse(accent).unwrap()),
+            ("border".to_string(), Style::parse("blue").unwrap()),
+        ]);
+        let mut console = Console::builder()
+            .width(20)
+            .color_system("standard")
+            .force_terminal(true)
+            .build();
+        console.push_theme(Theme::new(Some(styles), true));
+        console.begin_capture();
+        console.print(&table);
+        console.end_capture()
+    };
+    assert!(render_with("red").contains("\x1b[31m api"));
+    assert!(render_with("green").contains("\x1b[32m api"));
+    assert!(render_with("green").contains("\x1b[34m┏"));
+}
+
+#[derive(Table)]
+#[table(extra_column(
+    header = "Total",
+    value = "self.price * self.qty as f64",
+    justify = "right"
+))]
+struct OrderLine {
+    #[column(value = "self.item.to_uppercase()")]
+    item: String,
+    price: f64,
+    qty: u32,
+}
+
+#[derive(Table)]
+#[table(extra_column(header = "Sum", value = "self.0 + self.1"))]
+struct Sum(u32, u32);
+
+#[test]
+fn test_derive_table_computed_columns() {
+    let lines = vec![
+        OrderLine {
+            item: "tea".into(),
+            price: 2.5,
+            qty: 4,
+        },
+        OrderLine {
+            item: "cake".into(),
+            price: 3.0,
+            qty: 1,
+        },
+    ];
+    let table = OrderLine::to_table(&lines);
+    assert_eq!(table.columns.len(), 4);
+    assert_eq!(table.columns[3].header, "Total");
+    let output = format!("{}", table);
+    assert!(
+        output.contains("TEA") && output.contains("CAKE"),
+        "{output}"
+    );
+    assert!(output.contains("10") && output.contains(" 3 "), "{output}");
+
+    let output = format!("{}", Sum::to_table(&[Sum(2, 5)]));
+    assert!(output.contains("Sum") && output.contains('7'), "{output}");
+}
+
+#[derive(Table)]
+#[table(box_style = "registered:DERIVE_TEST_DOTTED")]
+struct Dotted {
+    name: String,
+}
+
+#[test]
+fn test_derive_table_registered_box_style() {
+    let dotted: gilt::box_chars::BoxChars = "·┈┬·\n┊ ┊┊\n├┈┼┤\n·┈┴·".parse().unwrap();
+    gilt::box_chars::register("DERIVE_TEST_DOTTED", dotted);
+    let table = Dotted::to_table(&[Dotted { name: "a".into() }]);
+    assert_eq!(table.box_chars.unwrap().top_left, '·');
+}
+
+#[derive(Table)]
+#[table(box_style = "registered:DERIVE_TEST_NEVER_REGISTERED")]
+struct Unregistered {
+    name: String,
+}
+
+#[test]
+#[should_panic(expected = "no box registered")]
+fn test_derive_table_unregistered_box_style_panics() {
+    Unregistered::to_table(&[Unregistered { name: "a".into() }]);
+}
@@ -34,6 +34,38 @@ fn text_from_ansi_roundtrip() {
     assert_eq!(text.plain(), "Bold Normal");
 }
 
+#[test]
+fn text_wrap_keeps_cjk_closing_punctuation_with_previous_line() {
+    // "漢字です" fills the line, but "。" may not start the next one.
+    let text = Text::new("漢字です。", Style::null());
+    let lines = text.wrap(8, None, None, 8, false);
+    let plain: Vec<&str> = lines.iter().map(|line| line.plain()).collect();
+    assert_eq!(plain, vec!["漢字で", "す。"]);
+}
+
+#[test]
+fn text_wrap_does_not_break_at_non_breaking_space() {
+    let text = Text::new("go 10\u{00A0}km", Style::null());
+    let lines = text.wrap(6, None, None, 8, false);
+    let plain: Vec<&str> = lines.iter().map(|line| line.plain().trim_end()).collect();
+    assert_eq!(plain, vec!["go", "10\u{00A0}km"]);
+}
+
+#[test]
+fn text_wrap_hyphenates_when_enabled() {
+    let mut text = Text::new("the hyphenation rules", Style::null());
+    text.hyphenate = Some(3);
+    let lines = text.wrap(10, None, None, 8, false);
+    let plain: Vec<&str> = lines.iter().map(|line| line.plain().trim_end()).collect();
+    assert_eq!(plain, vec!["the hyphe-", "nation", "rules"]);
+}
+
+#[test]
+fn text_measure_allows_breaks_inside_cjk_runs() {
+    let measurement = Text::new("漢字かな", Style::null()).measure();
+    assert_eq!((measurement.minimum, measurement.maximum), (2, 8));
+}
+
 // ---------------------------------------------------------------------------
 // Table
 // ---------------------------------------------------------------------------
@@ -54,6 +86,85 @@ fn table_renders_with_data() {
     assert!(output.contains("25"));
 }
 
+#[test]
+fn table_renders_with_shadow() {
+    let mut table = Table::new(&["Name", "Age"]).with_shadow(true);
+    table.add_row(&["Alice", "30"]);
+
+    let mut c = Console::builder()
+        .width(40)
+        .force_terminal(true)
+        .no_color(true)
+        .build();
+    c.begin_capture();
+    c.print(&table);
+    let output = c.end_capture();
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].ends_with(' '));
+    assert!(lines[1..lines.len() - 1].iter().all(|l| l.ends_with('█')));
+    assert!(lines.last().unwrap().starts_with(" ▀"));
+}
+
+#[test]
+fn table_renders_column_spans() {
+    let mut table =
+        Table::new(&["Item", "Q1", "Q2"]).with_box_chars(Some(&gilt::box_chars::SQUARE));
+    table.add_row(&["Apples", "3", "4"]);
+    table.add_row_spans(&[gilt::table::Row::span("Total for the year: 7", 3)]);
+
+    assert_eq!(
+        format!("{table}"),
+        "┌──────────┬──────┬──────┐\n\
+         │ Item     │ Q1   │ Q2   │\n\
+         ├──────────┼──────┼──────┤\n\
+         │ Apples   │ 3    │ 4    │\n\
+         │ Total for the year: 7  │\n\
+         └────────────────────────┘"
+    );
+}
+
+#[test]
+fn table_renders_column_groups() {
+    let mut table = Table::new(&["Host"]).with_box_chars(Some(&gilt::box_chars::SQUARE));
+    table.add_column_group("Latency", &["p50", "p99"]);
+    table.add_row(&["web", "12ms", "95ms"]);
+
+    assert_eq!(
+        format!("{table}"),
+        "┌────────┬─────────────────┐\n\
+         │        │     Latency     │\n\
+         │        ├────────┬────────┤\n\
+         │ Host   │ p50    │ p99    │\n\
+         ├────────┼────────┼────────┤\n\
+         │ web    │ 12ms   │ 95ms   │\n\
+         └────────┴────────┴────────┘"
+    );
+}
+
+#[test]
+fn table_renders_row_spans_through_separators() {
+    let mut table = Table::new(&["Region", "Item", "Sold"])
+        .with_box_chars(Some(&gilt::box_chars::SQUARE))
+        .with_show_lines(true);
+    table.add_row_spans(&[
+        gilt::table::Row::span("North", 1).with_rows(2),
+        "Apples".into(),
+        "3".into(),
+    ]);
+    table.add_row_spans(&["Pears".into(), "5".into()]);
+
+    assert_eq!(
+        format!("{table}"),
+        "┌──────────┬──────────┬────────┐\n\
+         │ Region   │ Item     │ Sold   │\n\
+         ├──────────┼──────────┼────────┤\n\
+         │ North    │ Apples   │ 3      │\n\
+         │          ├──────────┼────────┤\n\
+         │          │ Pears    │ 5      │\n\
+         └──────────┴──────────┴────────┘"
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Panel
 // ---------------------------------------------------------------------------
@@ -287,6 +398,242 @@ fn progress_bar_renders() {
     assert!(!output.is_empty());
 }
 
+// ---------------------------------------------------------------------------
+// Progress with nested tasks
+// ---------------------------------------------------------------------------
+
+#[test]
+fn progress_nested_tasks_roll_up_and_indent() {
+    use gilt::progress::{Progress, TextColumn};
+
+    let mut progress =
+        Progress::new(vec![Box::new(TextColumn::new("{task.description}"))]).with_disable(true);
+    let pipeline = progress.add_task("pipeline", None);
+    let fetch = progress.add_task_with_parent("fetch", Some(10.0), pipeline);
+    let other = progress.add_task("other", None);
+    let unpack = progress.add_task_with_parent("unpack", Some(10.0), fetch);
+
+    progress.advance(unpack, 10.0);
+    assert!(progress.get_task(fetch).unwrap().finished());
+    assert!(progress.get_task(pipeline).unwrap().finished());
+
+    let order: Vec<usize> = progress.tasks().iter().map(|t| t.id).collect();
+    assert_eq!(order, vec![pipeline, fetch, unpack, other]);
+
+    let mut c = Console::builder()
+        .width(40)
+        .no_color(true)
+        .markup(false)
+        .build();
+    c.begin_capture();
+    c.print(&progress);
+    let output = c.end_capture();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1], "└─ fetch");
+    assert_eq!(lines[2], "   └─ unpack");
+
+    progress.remove_task(fetch);
+    assert!(progress.get_task(unpack).is_none());
+    assert!(progress.get_task(pipeline).is_some());
+}
+
+#[test]
+fn progress_roll_up_ignores_children_without_total() {
+    use gilt::progress::{Progress, TextColumn};
+
+    let mut progress =
+        Progress::new(vec![Box::new(TextColumn::new("{task.description}"))]).with_disable(true);
+    let parent = progress.add_task("parent", None);
+    let sized = progress.add_task_with_parent("sized", Some(10.0), parent);
+    let open_ended = progress.add_task_with_parent("open-ended", None, parent);
+
+    progress.advance(sized, 5.0);
+    progress.advance(open_ended, 50.0);
+    let task = progress.get_task(parent).unwrap();
+    assert_eq!(task.total, Some(10.0));
+    assert_eq!(task.completed, 5.0);
+    assert!(!task.finished());
+
+    // With no sized children left the parent counts everything.
+    progress.remove_task(sized);
+    let task = progress.get_task(parent).unwrap();
+    assert_eq!(task.total, None);
+    assert_eq!(task.completed, 50.0);
+}
+
+// ---------------------------------------------------------------------------
+// Progress writer
+// ---------------------------------------------------------------------------
+
+/// A writer that accepts at most `limit` bytes per call and counts flushes.
+struct ShortWriter {
+    data: Vec<u8>,
+    limit: usize,
+    flushes: usize,
+}
+
+impl std::io::Write for ShortWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.limit);
+        self.data.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn progress_writer_advances_task_by_bytes_written() {
+    use gilt::progress::{Progress, ProgressWriter};
+    use std::cell::RefCell;
+    use std::io::Write;
+    use std::rc::Rc;
+
+    let progress = Rc::new(RefCell::new(
+        Progress::new(Progress::default_columns()).with_disable(true),
+    ));
+    let task = progress.borrow_mut().add_task("copy", Some(10.0));
+    let inner = ShortWriter {
+        data: Vec::new(),
+        limit: 4,
+        flushes: 0,
+    };
+    let handle = Rc::clone(&progress);
+    let mut writer = ProgressWriter::new(inner, move |n| {
+        handle.borrow_mut().advance(task, n as f64);
+    });
+    assert_eq!(writer.write(b"abcdef").unwrap(), 4);
+    assert_eq!(writer.total_written(), 4);
+    // The progress stays usable while the writer is alive.
+    assert_eq!(progress.borrow().get_task(task).unwrap().completed, 4.0);
+    writer.write_all(b"ghij").unwrap();
+    assert_eq!(writer.total_written(), 8);
+
+    let progress = progress.borrow();
+    let t = progress.get_task(task).unwrap();
+    assert_eq!(t.completed, 8.0);
+    assert!(!t.finished());
+}
+
+#[test]
+fn progress_writer_passes_writes_and_flushes_through() {
+    use gilt::progress::{Progress, ProgressWriter};
+    use std::cell::RefCell;
+    use std::io::Write;
+    use std::rc::Rc;
+
+    let progress = Rc::new(RefCell::new(
+        Progress::new(Progress::default_columns()).with_disable(true),
+    ));
+    let task = progress.borrow_mut().add_task("copy", None);
+    let inner = ShortWriter {
+        data: Vec::new(),
+        limit: usize::MAX,
+        flushes: 0,
+    };
+    let handle = Rc::clone(&progress);
+    let mut writer = ProgressWriter::new(inner, move |n| {
+        handle.borrow_mut().advance(task, n as f64);
+    });
+    writer.write_all(b"hello ").unwrap();
+    writer.flush().unwrap();
+    writer.write_all(b"world").unwrap();
+    writer.flush().unwrap();
+    let inner = writer.into_inner();
+    assert_eq!(inner.data, b"hello world");
+    assert_eq!(inner.flushes, 2);
+    assert_eq!(progress.borrow().get_task(task).unwrap().completed, 11.0);
+}
+
+// ---------------------------------------------------------------------------
+// Progress speed estimation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn progress_ema_speed_estimate_smooths_bursts() {
+    use gilt::progress::{Progress, SpeedEstimator, TimeRemainingColumn};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    let clock = Arc::new(AtomicU64::new(0));
+    let time = Arc::clone(&clock);
+    let mut progress = Progress::new(vec![Box::new(TimeRemainingColumn::new())])
+        .with_disable(true)
+        .with_get_time(move || time.load(Ordering::SeqCst) as f64)
+        .with_speed_estimator(SpeedEstimator::Ema { half_life: 10.0 });
+    let task = progress.add_task("bursty", Some(1000.0));
+    for (second, step) in [(1, 10.0), (2, 10.0), (3, 100.0)] {
+        clock.store(second, Ordering::SeqCst);
+        progress.advance(task, step);
+    }
+
+    let t = progress.get_task(task).unwrap();
+    let window = t.speed().unwrap();
+    let ema = t.speed_estimate().unwrap();
+    assert!((window - 55.0).abs() < 1e-9);
+    assert!(ema > 10.0 && ema < 20.0, "ema = {ema}");
+    let remaining = t.time_remaining().unwrap();
+    assert!((remaining - 880.0 / ema).abs() < 1e-9);
+
+    progress.reset(task);
+    assert!(progress.get_task(task).unwrap().speed_estimate().is_none());
+}
+
+#[test]
+fn progress_indeterminate_bar_pulses_with_clock() {
+    use gilt::progress::{BarColumn, Progress};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    let millis = Arc::new(AtomicU64::new(0));
+    let time = Arc::clone(&millis);
+    let mut progress = Progress::new(vec![Box::new(BarColumn::new().with_bar_width(Some(20)))])
+        .with_disable(true)
+        .with_get_time(move || time.load(Ordering::SeqCst) as f64 / 1000.0);
+    progress.add_task("unknown size", None);
+
+    let console = Console::builder()
+        .width(40)
+        .color_system("truecolor")
+        .build();
+    let frame = |progress: &Progress| progress.gilt_console(&console, &console.options());
+
+    let first = frame(&progress);
+    assert_eq!(first, frame(&progress));
+    millis.store(200, Ordering::SeqCst);
+    assert_ne!(first, frame(&progress));
+}
+
+#[test]
+fn progress_spinner_frames_follow_injected_clock() {
+    use gilt::clock::ManualClock;
+    use gilt::progress::{Progress, SpinnerColumn};
+    use std::time::Duration;
+
+    let clock = ManualClock::new();
+    let mut progress = Progress::new(vec![Box::new(SpinnerColumn::new("dots"))])
+        .with_disable(true)
+        .with_clock(clock.clone());
+    progress.add_task("spin", None);
+
+    let console = Console::builder().width(20).no_color(true).build();
+    let frame = |progress: &Progress| -> String {
+        progress
+            .gilt_console(&console, &console.options())
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect()
+    };
+    assert_eq!(frame(&progress), "\u{280b}\n");
+    clock.advance(Duration::from_millis(80));
+    assert_eq!(frame(&progress), "\u{2819}\n");
+    clock.advance(Duration::from_millis(80));
+    assert_eq!(frame(&progress), "\u{2839}\n");
+}
+
 // ---------------------------------------------------------------------------
 // Global convenience functions
 // ---------------------------------------------------------------------------
@@ -412,6 +412,20 @@ fn test_truncate_ellipsis_pad() {
     assert_eq!(text.plain(), "Hello     ");
 }
 
+#[test]
+fn test_truncate_with_custom_ellipsis() {
+    let mut text = Text::new("Hello, World!", Style::null());
+    text.truncate_with_ellipsis(10, Some(OverflowMethod::Ellipsis), false, "...");
+    assert_eq!(text.plain(), "Hello, ...");
+}
+
+#[test]
+fn test_truncate_ellipsis_wider_than_width() {
+    let mut text = Text::new("Hello", Style::null());
+    text.truncate_with_ellipsis(2, Some(OverflowMethod::Ellipsis), false, "...");
+    assert_eq!(text.plain(), "He");
+}
+
 // -- Fit test -----------------------------------------------------------
 
 #[test]
@@ -429,7 +443,7 @@ fn test_fit() {
 fn test_tabs_to_spaces() {
     let mut text = Text::new("Hello\tWorld", Style::null());
     text.expand_tabs(Some(4));
-    assert_eq!(text.plain(), "Hello    World");
+    assert_eq!(text.plain(), "Hello   World");
 }
 
 // -- Strip control codes test -------------------------------------------